@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 use crate::error::{Error, Result};
 use crate::serialization::Format;
@@ -18,6 +19,23 @@ pub struct ZenohConfig {
     /// Endpoints to listen on (for peer/router mode).
     #[serde(default)]
     pub listen: Vec<String>,
+
+    /// Discover peers by UDP multicast scouting (#426). Turn off on networks
+    /// that block multicast and rely on `connect` endpoints instead.
+    #[serde(default = "default_true")]
+    pub multicast_scouting: bool,
+
+    /// Learn of further peers through gossip from connected ones.
+    #[serde(default = "default_true")]
+    pub gossip_scouting: bool,
+
+    /// Deployment/tenant namespace (#446), e.g. `"tenantA"`. Zenoh prepends
+    /// it to every key this session puts, subscribes to, queries or declares
+    /// liveliness on (`tenantA/zensight/...`) and strips it from what it
+    /// receives, so deployments sharing a fabric never see each other's keys.
+    /// Bridges and the frontend must use the same namespace.
+    #[serde(default)]
+    pub namespace: Option<String>,
 }
 
 fn default_mode() -> String {
@@ -30,15 +48,20 @@ impl Default for ZenohConfig {
             mode: default_mode(),
             connect: Vec::new(),
             listen: Vec::new(),
+            multicast_scouting: true,
+            gossip_scouting: true,
+            namespace: None,
         }
     }
 }
 
 impl ZenohConfig {
-    /// Apply `ZENSIGHT_ZENOH_{MODE,CONNECT,LISTEN}` environment overrides.
+    /// Apply `ZENSIGHT_ZENOH_{MODE,CONNECT,LISTEN,NAMESPACE}` environment
+    /// overrides.
     ///
-    /// `CONNECT`/`LISTEN` are comma-separated endpoint lists. Unset variables
-    /// leave the field untouched. This lets a launcher (e.g. `just run`) pin
+    /// `CONNECT`/`LISTEN` are comma-separated endpoint lists; an empty
+    /// `NAMESPACE` clears the namespace. Unset variables leave the field
+    /// untouched. This lets a launcher (e.g. `just run`) pin
     /// explicit local endpoints so the GUI and sensors connect reliably without
     /// depending on multicast peer discovery (which is unreliable on hosts with
     /// a VPN or multiple interfaces, e.g. tailscale/docker).
@@ -64,8 +87,19 @@ impl ZenohConfig {
         if let Some(l) = get("ZENSIGHT_ZENOH_LISTEN") {
             self.listen = parse(l);
         }
+        if let Some(ns) = get("ZENSIGHT_ZENOH_NAMESPACE") {
+            self.namespace = Some(ns.trim().to_string()).filter(|ns| !ns.is_empty());
+        }
         self
     }
+
+    /// The namespace, if set to something non-empty (#446).
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace
+            .as_deref()
+            .map(|ns| ns.trim_matches('/'))
+            .filter(|ns| !ns.is_empty())
+    }
 }
 
 #[cfg(test)]
@@ -87,6 +121,9 @@ mod zenoh_env_tests {
             mode: "peer".into(),
             connect: vec!["tcp/a:1".into()],
             listen: vec![],
+            multicast_scouting: true,
+            gossip_scouting: true,
+            namespace: Some("tenantA".into()),
         };
         assert_eq!(over(base.clone(), &[]), base);
     }
@@ -105,6 +142,24 @@ mod zenoh_env_tests {
         assert_eq!(out.connect, vec!["tcp/127.0.0.1:7447", "tcp/h:2"]); // trimmed, empties dropped
         assert_eq!(out.listen, vec!["tcp/0.0.0.0:7448"]);
     }
+
+    #[test]
+    fn namespace_override_sets_or_clears() {
+        let out = over(
+            ZenohConfig::default(),
+            &[("ZENSIGHT_ZENOH_NAMESPACE", " tenantB ")],
+        );
+        assert_eq!(out.namespace(), Some("tenantB"));
+
+        let base = ZenohConfig {
+            namespace: Some("tenantA".into()),
+            ..ZenohConfig::default()
+        };
+        assert_eq!(
+            over(base, &[("ZENSIGHT_ZENOH_NAMESPACE", "")]).namespace,
+            None
+        );
+    }
 }
 
 /// Log output format.
@@ -128,6 +183,15 @@ pub struct LoggingConfig {
     /// Log output format: "text" or "json".
     #[serde(default)]
     pub format: LogFormat,
+
+    /// Optional size-rotated log file (#402). Unset logs to stdout only.
+    #[serde(default)]
+    pub file: Option<LogFileConfig>,
+
+    /// Per-module level overrides on top of `level` (#403), e.g.
+    /// `{ zenoh: "warn", "zensight_sensor_snmp::poller": "debug" }`.
+    #[serde(default)]
+    pub module_levels: HashMap<String, String>,
 }
 
 fn default_log_level() -> String {
@@ -139,6 +203,139 @@ impl Default for LoggingConfig {
         Self {
             level: default_log_level(),
             format: LogFormat::default(),
+            file: None,
+            module_levels: HashMap::new(),
+        }
+    }
+}
+
+impl LoggingConfig {
+    /// `EnvFilter` directive string: the default `level`, then each module
+    /// override as `module=level`, sorted so the result is stable.
+    pub fn filter_directives(&self) -> String {
+        let mut modules: Vec<_> = self.module_levels.iter().collect();
+        modules.sort();
+        std::iter::once(self.level.clone())
+            .chain(
+                modules
+                    .into_iter()
+                    .map(|(module, level)| format!("{module}={level}")),
+            )
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// Log file output with size-based rotation (#402).
+///
+/// ```json5
+/// logging: {
+///   level: "info",
+///   file: { path: "/var/log/zensight/snmp.log", max_size_bytes: 10485760, max_files: 5 },
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogFileConfig {
+    /// Path of the live log file; rotated generations get `.1`, `.2`, ….
+    pub path: PathBuf,
+
+    /// Rotate once the file would grow past this many bytes (0 = never).
+    #[serde(default = "default_log_max_size_bytes")]
+    pub max_size_bytes: u64,
+
+    /// Rotated files to keep alongside the live one.
+    #[serde(default = "default_log_max_files")]
+    pub max_files: usize,
+
+    /// Keep logging to stdout as well as the file.
+    #[serde(default = "default_true")]
+    pub stdout: bool,
+}
+
+fn default_log_max_size_bytes() -> u64 {
+    10 * 1024 * 1024
+}
+
+fn default_log_max_files() -> usize {
+    5
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Which clock stamps `TelemetryPoint.timestamp` (#406).
+///
+/// Bridges whose source carries its own event time (syslog RFC 5424, netflow
+/// flow end, gNMI update timestamps) use it by default so points line up with
+/// when things happened, not when they arrived. `receipt` opts back into the
+/// bridge's clock, e.g. for devices with a badly skewed clock.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimestampSource {
+    /// The timestamp embedded in the message, falling back to receipt time
+    /// when the message has none (default).
+    #[default]
+    Message,
+    /// When the bridge received the message.
+    Receipt,
+}
+
+impl TimestampSource {
+    /// Choose between a message's own timestamp and the receipt time (both
+    /// epoch milliseconds).
+    pub fn pick(self, message_ms: Option<i64>, receipt_ms: i64) -> i64 {
+        match self {
+            TimestampSource::Message => message_ms.unwrap_or(receipt_ms),
+            TimestampSource::Receipt => receipt_ms,
+        }
+    }
+}
+
+/// Clock-skew detection for message-embedded timestamps (#407).
+///
+/// With `timestamp_source: message`, a device whose clock is wrong puts its
+/// points in the wrong place on every chart. Bridges compare each embedded
+/// timestamp to receipt time and warn (at most once per device per
+/// `warn_interval_secs`) when they differ by more than `threshold_secs`.
+///
+/// ```json5
+/// clock_skew: { threshold_secs: 300, warn_interval_secs: 600, publish_errors: true },
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClockSkewConfig {
+    /// Check embedded timestamps at all.
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Skew, either direction, beyond which a device is reported.
+    #[serde(default = "default_skew_threshold_secs")]
+    pub threshold_secs: u64,
+
+    /// Minimum time between two warnings for the same device.
+    #[serde(default = "default_skew_warn_interval_secs")]
+    pub warn_interval_secs: u64,
+
+    /// Also publish each warning as an `ErrorReport` on `@/errors`.
+    #[serde(default)]
+    pub publish_errors: bool,
+}
+
+fn default_skew_threshold_secs() -> u64 {
+    300
+}
+
+fn default_skew_warn_interval_secs() -> u64 {
+    600
+}
+
+impl Default for ClockSkewConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            threshold_secs: default_skew_threshold_secs(),
+            warn_interval_secs: default_skew_warn_interval_secs(),
+            publish_errors: false,
         }
     }
 }
@@ -309,20 +506,71 @@ pub fn load_config<T: for<'de> Deserialize<'de>>(path: impl AsRef<Path>) -> Resu
         Error::Config(format!(
             "Failed to parse config file '{}': {}",
             path.display(),
-            e
+            describe_json5_error(&content, &e)
         ))
     })
 }
 
 /// Load a configuration from a JSON5 string.
 pub fn parse_config<T: for<'de> Deserialize<'de>>(content: &str) -> Result<T> {
-    json5::from_str(content).map_err(|e| Error::Config(format!("Failed to parse config: {}", e)))
+    json5::from_str(content).map_err(|e| {
+        Error::Config(format!(
+            "Failed to parse config: {}",
+            describe_json5_error(content, &e)
+        ))
+    })
+}
+
+/// Render a JSON5 error against the `content` it came from (#448): the
+/// reason, then `line L, column C` and the offending line with a caret under
+/// the column, when json5 knows the location.
+pub fn describe_json5_error(content: &str, err: &json5::Error) -> String {
+    let json5::Error::Message { msg, location } = err;
+    // Syntax errors arrive as pest's multi-line report; keep just its
+    // "= expected ..." reason, since the snippet is rendered below.
+    let reason = msg
+        .lines()
+        .rev()
+        .find_map(|l| l.trim().strip_prefix("= "))
+        .unwrap_or_else(|| msg.trim());
+    let Some(loc) = location else {
+        return reason.to_string();
+    };
+    let mut out = format!("{reason} at line {}, column {}", loc.line, loc.column);
+    if let Some(text) = content.lines().nth(loc.line.saturating_sub(1)) {
+        let gutter = loc.line.to_string().len();
+        let caret = " ".repeat(text.chars().take(loc.column.saturating_sub(1)).count());
+        out.push_str(&format!(
+            "\n{:gutter$} |\n{} | {}\n{:gutter$} | {caret}^",
+            "", loc.line, text, ""
+        ));
+    }
+    out
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn parse_errors_point_at_the_offending_line() {
+        // The `zenoh` object is never closed.
+        let content = "{\n  zenoh: {\n    mode: \"peer\"\n  serialization: \"json\"\n}\n";
+        let err = parse_config::<BaseConfig>(content).unwrap_err().to_string();
+        assert!(err.contains("at line 4, column "), "{err}");
+        assert!(err.contains("4 |   serialization: \"json\""), "{err}");
+        assert!(err.trim_end().ends_with('^'), "{err}");
+    }
+
+    #[test]
+    fn type_mismatches_carry_a_location() {
+        let content = "{\n  zenoh: {\n    multicast_scouting: \"yes\",\n  },\n}\n";
+        let err = parse_config::<BaseConfig>(content).unwrap_err().to_string();
+        assert!(err.contains("invalid type"), "{err}");
+        assert!(err.contains("at line 3, column "), "{err}");
+        assert!(err.contains("multicast_scouting: \"yes\""), "{err}");
+    }
+
     #[test]
     fn test_parse_base_config() {
         let json5 = r#"
@@ -4,7 +4,7 @@
 //! for deserialization in the frontend without requiring the full framework.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Sensor health status.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -82,6 +82,31 @@ pub struct HealthSnapshot {
     pub errors_last_hour: u64,
     /// Total metrics published.
     pub metrics_published: u64,
+    /// Sensor-specific named counters (#411).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub counters: BTreeMap<String, u64>,
+    /// Sensor-specific named gauges (#411).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub gauges: BTreeMap<String, f64>,
+    /// Times the sensor process has been restarted (#435).
+    #[serde(default)]
+    pub restart_count: u64,
+    /// Restarts in the last hour (#435).
+    #[serde(default)]
+    pub restarts_last_hour: u64,
+    /// When the current sensor process started (millis since epoch) (#435).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_start_ms: Option<i64>,
+}
+
+/// Restarts within an hour at which a sensor counts as crash-looping (#435).
+pub const CRASH_LOOP_RESTARTS: u64 = 3;
+
+impl HealthSnapshot {
+    /// Whether the sensor keeps restarting (see [`CRASH_LOOP_RESTARTS`]).
+    pub fn is_crash_looping(&self) -> bool {
+        self.restarts_last_hour >= CRASH_LOOP_RESTARTS
+    }
 }
 
 /// Device liveness information.
@@ -139,6 +164,61 @@ pub struct ErrorReport {
     pub retryable: bool,
 }
 
+/// What a sensor discovered (#436).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscoveryKind {
+    /// A device (source) the sensor had not heard from.
+    Device,
+    /// A new interface on a known device.
+    Interface,
+    /// A new row in a polled table.
+    TableRow,
+}
+
+impl std::fmt::Display for DiscoveryKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiscoveryKind::Device => write!(f, "device"),
+            DiscoveryKind::Interface => write!(f, "interface"),
+            DiscoveryKind::TableRow => write!(f, "table row"),
+        }
+    }
+}
+
+/// A previously unseen source, published once on `zensight/<protocol>/@/discovery`
+/// (#436).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiscoveryEvent {
+    /// Device the discovery belongs to (the discovered device itself for
+    /// [`DiscoveryKind::Device`]).
+    pub device: String,
+    /// What was discovered.
+    pub kind: DiscoveryKind,
+    /// The item within the device, e.g. `if/3` for an interface.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item: Option<String>,
+    /// Human-readable name, e.g. the interface's `ifName`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// When the sensor first saw it (millis since epoch).
+    pub first_seen: i64,
+}
+
+impl DiscoveryEvent {
+    /// One-line description for notifications, e.g.
+    /// `New interface on router01: eth0 (if/3)`.
+    pub fn summary(&self) -> String {
+        let what = match (&self.label, &self.item) {
+            (Some(label), Some(item)) => format!("{label} ({item})"),
+            (Some(label), None) => label.clone(),
+            (None, Some(item)) => item.clone(),
+            (None, None) => return format!("New {} detected: {}", self.kind, self.device),
+        };
+        format!("New {} on {}: {what}", self.kind, self.device)
+    }
+}
+
 /// Correlation entry from cross-sensor device correlation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CorrelationEntry {
@@ -208,6 +288,54 @@ mod tests {
         assert_eq!(snapshot.sensor, "snmp");
         assert_eq!(snapshot.status, HealthStatus::Healthy);
         assert_eq!(snapshot.devices_total, 10);
+        // Sensors predating restart tracking (#435).
+        assert_eq!(snapshot.restart_count, 0);
+        assert!(snapshot.last_start_ms.is_none());
+        assert!(!snapshot.is_crash_looping());
+    }
+
+    #[test]
+    fn test_health_snapshot_restarts() {
+        let json = r#"{
+            "sensor": "snmp",
+            "status": "healthy",
+            "uptime_secs": 12,
+            "devices_total": 1,
+            "devices_responding": 1,
+            "devices_failed": 0,
+            "last_poll_duration_ms": 10,
+            "errors_last_hour": 0,
+            "metrics_published": 3,
+            "restart_count": 7,
+            "restarts_last_hour": 4,
+            "last_start_ms": 1703500000000
+        }"#;
+
+        let snapshot: HealthSnapshot = serde_json::from_str(json).unwrap();
+        assert_eq!(snapshot.restart_count, 7);
+        assert_eq!(snapshot.last_start_ms, Some(1703500000000));
+        assert!(snapshot.is_crash_looping());
+    }
+
+    #[test]
+    fn test_discovery_event_roundtrip() {
+        let event = DiscoveryEvent {
+            device: "router01".into(),
+            kind: DiscoveryKind::Interface,
+            item: Some("if/3".into()),
+            label: Some("eth0".into()),
+            first_seen: 1703500000000,
+        };
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains(r#""kind":"interface""#));
+        let back: DiscoveryEvent = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, event);
+        assert_eq!(back.summary(), "New interface on router01: eth0 (if/3)");
+
+        let device: DiscoveryEvent =
+            serde_json::from_str(r#"{"device": "10.0.0.9", "kind": "device", "first_seen": 1}"#)
+                .unwrap();
+        assert_eq!(device.summary(), "New device detected: 10.0.0.9");
     }
 
     #[test]
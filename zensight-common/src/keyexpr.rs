@@ -202,6 +202,20 @@ pub fn all_errors_wildcard() -> String {
     format!("{}/*/@/errors", KEY_PREFIX)
 }
 
+/// Build a wildcard key expression for all sensor discovery events (#436).
+///
+/// Matches: `zensight/<protocol>/@/discovery`
+///
+/// # Example
+/// ```
+/// use zensight_common::keyexpr::all_discovery_wildcard;
+///
+/// assert_eq!(all_discovery_wildcard(), "zensight/*/@/discovery");
+/// ```
+pub fn all_discovery_wildcard() -> String {
+    format!("{}/*/@/discovery", KEY_PREFIX)
+}
+
 /// Build a wildcard key expression for all correlation data.
 ///
 /// Matches: `zensight/_meta/correlation/<ip>`
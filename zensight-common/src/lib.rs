@@ -5,8 +5,10 @@
 //! - [`telemetry`] - Common telemetry data model (`TelemetryPoint`, `TelemetryValue`, `Protocol`)
 //! - [`serialization`] - JSON/CBOR encoding and decoding
 //! - [`config`] - Configuration loading (JSON5 format)
+//! - [`logging`] - Size-rotated log file output
 //! - [`session`] - Zenoh session management
 //! - [`keyexpr`] - Key expression builders and parsers
+//! - [`provenance`] - Per-sample bridge metadata carried as a Zenoh attachment
 //! - [`error`] - Error types
 
 pub mod alert;
@@ -16,6 +18,8 @@ pub mod config;
 pub mod error;
 pub mod health;
 pub mod keyexpr;
+pub mod logging;
+pub mod provenance;
 pub mod query_detail;
 pub mod report;
 pub mod semconv;
@@ -33,32 +37,39 @@ pub use command::{
 };
 pub use comparison::ComparisonOp;
 pub use config::{
-    BaseConfig, LogFormat, LoggingConfig, ReportLimits, SnapshotDir, SnapshotLimits, ZenohConfig,
-    load_config, parse_config,
+    BaseConfig, ClockSkewConfig, LogFileConfig, LogFormat, LoggingConfig, ReportLimits,
+    SnapshotDir, SnapshotLimits, TimestampSource, ZenohConfig, describe_json5_error, load_config,
+    parse_config,
 };
 pub use error::{Error, Result};
 pub use health::{
-    CorrelationEntry, DeviceLiveness, DeviceStatus, ErrorReport, ErrorType, HealthSnapshot,
-    HealthStatus, SensorInfo,
+    CorrelationEntry, DeviceLiveness, DeviceStatus, DiscoveryEvent, DiscoveryKind, ErrorReport,
+    ErrorType, HealthSnapshot, HealthStatus, SensorInfo,
 };
 pub use keyexpr::{
     KEY_PREFIX, KeyExprBuilder, ParseError, ParsedKeyExpr, all_alerts_wildcard,
-    all_correlation_wildcard, all_errors_wildcard, all_health_wildcard, all_liveness_wildcard,
-    all_sensors_wildcard, all_telemetry_wildcard, parse_key_expr,
+    all_correlation_wildcard, all_discovery_wildcard, all_errors_wildcard, all_health_wildcard,
+    all_liveness_wildcard, all_sensors_wildcard, all_telemetry_wildcard, parse_key_expr,
 };
+pub use provenance::SampleMetadata;
 pub use query_detail::{
     AssetRecord, CgroupNode, CgroupPid, DnsRecord, ElephantRecord, FlowRecord, HttpHostRecord,
     Ja4hRecord, MatrixRecord, NeighborRecord, ProcessRecord, QuicRecord, RouteRecord, SocketRecord,
     SshRecord, TalkerRecord, TimerRecord, TlsRecord, UnitDetail, UnitRecord,
 };
 pub use report::{Manifest, ReportKind, ReportOptions, ReportRequest, ReportState, ReportStatus};
-pub use serialization::{Format, decode, decode_auto, encode};
-pub use session::connect;
+pub use serialization::{
+    DecodeStream, Format, decode, decode_auto, decode_stream, encode, encode_tagged,
+};
+pub use session::{connect, session_config};
 pub use snapshot::{
     Entry, SnapshotDirInfo, SnapshotOptions, SnapshotRequest, SnapshotState, SnapshotStatus,
     SnapshotSummary, TreeIndex,
 };
-pub use telemetry::{Protocol, TelemetryPoint, TelemetryValue, current_timestamp_millis};
+pub use telemetry::{
+    InvalidValue, MissingField, Protocol, TelemetryPoint, TelemetryPointBuilder, TelemetryValue,
+    current_timestamp_millis,
+};
 
 /// Initialize tracing with the given configuration.
 ///
@@ -66,6 +77,10 @@ pub use telemetry::{Protocol, TelemetryPoint, TelemetryValue, current_timestamp_
 /// - `LogFormat::Text` (default): Human-readable text format
 /// - `LogFormat::Json`: Structured JSON format for log aggregation systems
 ///
+/// With `file` set, events also go to a size-rotated log file (#402), and
+/// `file.stdout: false` silences the console entirely. `module_levels`
+/// overrides `level` per module (#403); `RUST_LOG`, when set, replaces both.
+///
 /// # Example
 ///
 /// ```ignore
@@ -74,31 +89,99 @@ pub use telemetry::{Protocol, TelemetryPoint, TelemetryValue, current_timestamp_
 /// let config = LoggingConfig {
 ///     level: "info".to_string(),
 ///     format: LogFormat::Json,
+///     ..Default::default()
 /// };
 /// init_tracing(&config)?;
 /// ```
 pub fn init_tracing(config: &LoggingConfig) -> Result<()> {
-    use tracing_subscriber::{EnvFilter, fmt, prelude::*};
+    use tracing_subscriber::util::SubscriberInitExt;
 
-    let filter =
-        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(&config.level));
+    let (subscriber, filter) = build_subscriber(config)?;
+    subscriber
+        .try_init()
+        .map_err(|e| Error::Config(format!("Failed to initialize tracing: {}", e)))?;
+    // Only the first successful init installs a subscriber, so only its
+    // handle is worth keeping.
+    let _ = LOG_FILTER.set(filter);
+    Ok(())
+}
 
-    match config.format {
-        LogFormat::Text => {
-            tracing_subscriber::registry()
-                .with(fmt::layer())
-                .with(filter)
-                .try_init()
-                .map_err(|e| Error::Config(format!("Failed to initialize tracing: {}", e)))?;
-        }
-        LogFormat::Json => {
-            tracing_subscriber::registry()
-                .with(fmt::layer().json())
-                .with(filter)
-                .try_init()
-                .map_err(|e| Error::Config(format!("Failed to initialize tracing: {}", e)))?;
+/// Replace the active log filter at runtime (#445), e.g. `"debug"` or
+/// `"info,zenoh=warn"`. Fails on invalid directives, or when tracing wasn't
+/// set up by [`init_tracing`].
+pub fn set_log_filter(directives: &str) -> Result<()> {
+    let filter = tracing_subscriber::EnvFilter::try_new(directives).map_err(|e| {
+        Error::Config(format!(
+            "Invalid log level directives {:?}: {}",
+            directives, e
+        ))
+    })?;
+    LOG_FILTER
+        .get()
+        .ok_or_else(|| Error::Config("Tracing is not initialized".to_string()))?
+        .reload(filter)
+        .map_err(|e| Error::Config(format!("Failed to reload log filter: {}", e)))
+}
+
+type BoxedLayer = Box<dyn tracing_subscriber::Layer<tracing_subscriber::Registry> + Send + Sync>;
+
+/// Handle to the filter [`init_tracing`] installed, for [`set_log_filter`].
+type FilterHandle = tracing_subscriber::reload::Handle<
+    tracing_subscriber::EnvFilter,
+    tracing_subscriber::layer::Layered<Vec<BoxedLayer>, tracing_subscriber::Registry>,
+>;
+
+static LOG_FILTER: std::sync::OnceLock<FilterHandle> = std::sync::OnceLock::new();
+
+/// Build the subscriber [`init_tracing`] installs, without installing it,
+/// along with a handle to swap its filter.
+fn build_subscriber(
+    config: &LoggingConfig,
+) -> Result<(impl tracing::Subscriber + Send + Sync + use<>, FilterHandle)> {
+    use tracing_subscriber::fmt::MakeWriter;
+    use tracing_subscriber::{EnvFilter, Layer, fmt, prelude::*, reload};
+
+    fn output<W>(format: LogFormat, writer: W, ansi: bool) -> BoxedLayer
+    where
+        W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+    {
+        match format {
+            LogFormat::Text => fmt::layer().with_writer(writer).with_ansi(ansi).boxed(),
+            LogFormat::Json => fmt::layer().json().with_writer(writer).boxed(),
         }
     }
 
-    Ok(())
+    let filter = match EnvFilter::try_from_default_env() {
+        Ok(filter) => filter,
+        Err(_) => EnvFilter::try_new(config.filter_directives()).map_err(|e| {
+            Error::Config(format!(
+                "Invalid log level directives {:?}: {}",
+                config.filter_directives(),
+                e
+            ))
+        })?,
+    };
+
+    let mut layers: Vec<BoxedLayer> = Vec::new();
+    if config.file.as_ref().is_none_or(|file| file.stdout) {
+        layers.push(output(config.format, std::io::stdout, true));
+    }
+    if let Some(file) = &config.file {
+        let writer =
+            logging::RollingFileWriter::new(&file.path, file.max_size_bytes, file.max_files)
+                .map_err(|e| {
+                    Error::Config(format!(
+                        "Failed to open log file {}: {}",
+                        file.path.display(),
+                        e
+                    ))
+                })?;
+        layers.push(output(config.format, writer, false));
+    }
+
+    let (filter, handle) = reload::Layer::new(filter);
+    Ok((
+        tracing_subscriber::registry().with(layers).with(filter),
+        handle,
+    ))
 }
@@ -0,0 +1,299 @@
+//! Size-rotated log file output for [`crate::init_tracing`] (#402).
+//!
+//! Long-running bridges on headless hosts have nobody watching stdout, so
+//! [`LoggingConfig::file`](crate::LoggingConfig::file) can send log lines to a
+//! file instead of (or as well as) the console. The file is rotated when the
+//! next write would push it past `max_size_bytes`: `app.log` becomes
+//! `app.log.1`, older generations shift up, and anything beyond `max_files`
+//! is deleted.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+use tracing_subscriber::fmt::MakeWriter;
+
+/// A log file that rotates itself by size.
+///
+/// Implements [`MakeWriter`], so it plugs straight into a
+/// `tracing_subscriber::fmt` layer. The formatter writes each event with a
+/// single `write_all`, and rotation only happens between writes, so a line is
+/// never split across two files.
+#[derive(Debug)]
+pub struct RollingFileWriter {
+    inner: Mutex<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    path: PathBuf,
+    file: File,
+    /// Bytes in the current file, including what was there at open.
+    written: u64,
+    max_size_bytes: u64,
+    max_files: usize,
+}
+
+impl RollingFileWriter {
+    /// Open (appending to) `path`, creating parent directories as needed.
+    ///
+    /// A `max_size_bytes` of 0 disables rotation. `max_files` is how many
+    /// rotated generations to keep next to the live file; with 0 the file is
+    /// simply truncated when full.
+    pub fn new(
+        path: impl Into<PathBuf>,
+        max_size_bytes: u64,
+        max_files: usize,
+    ) -> io::Result<Self> {
+        let path = path.into();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        let file = open_append(&path)?;
+        let written = file.metadata()?.len();
+        Ok(Self {
+            inner: Mutex::new(Inner {
+                path,
+                file,
+                written,
+                max_size_bytes,
+                max_files,
+            }),
+        })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        // A panic mid-write leaves the file usable; keep logging.
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn open_append(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+/// Path of rotated generation `n` (`app.log` → `app.log.n`).
+fn generation(path: &Path, n: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{n}"));
+    PathBuf::from(name)
+}
+
+impl Inner {
+    /// Rotate if writing `len` more bytes would overflow the size cap. A
+    /// write into an empty file always goes through, however large.
+    fn make_room(&mut self, len: usize) -> io::Result<()> {
+        if self.max_size_bytes > 0
+            && self.written > 0
+            && self.written + len as u64 > self.max_size_bytes
+        {
+            self.rotate()?;
+        }
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        if self.max_files == 0 {
+            self.file = File::create(&self.path)?;
+        } else {
+            // Drop the oldest, shift the rest up by one, then move the live
+            // file into slot 1.
+            let oldest = generation(&self.path, self.max_files);
+            if oldest.exists() {
+                fs::remove_file(&oldest)?;
+            }
+            for n in (1..self.max_files).rev() {
+                let from = generation(&self.path, n);
+                if from.exists() {
+                    fs::rename(&from, generation(&self.path, n + 1))?;
+                }
+            }
+            fs::rename(&self.path, generation(&self.path, 1))?;
+            self.file = open_append(&self.path)?;
+        }
+        self.written = 0;
+        Ok(())
+    }
+}
+
+impl Write for &RollingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut inner = self.lock();
+        inner.make_room(buf.len())?;
+        let n = inner.file.write(buf)?;
+        inner.written += n as u64;
+        Ok(n)
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        // Hold the lock for the whole event so rotation can't land mid-line.
+        let mut inner = self.lock();
+        inner.make_room(buf.len())?;
+        inner.file.write_all(buf)?;
+        inner.written += buf.len() as u64;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.lock().file.flush()
+    }
+}
+
+impl<'a> MakeWriter<'a> for RollingFileWriter {
+    type Writer = &'a RollingFileWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "zensight-logging-{name}-{}-{}",
+            std::process::id(),
+            crate::current_timestamp_millis()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn rotates_at_configured_size_and_keeps_max_files() {
+        let dir = scratch_dir("rotate");
+        let path = dir.join("bridge.log");
+        let writer = RollingFileWriter::new(&path, 32, 2).unwrap();
+
+        let line = b"0123456789abcdef\n"; // 17 bytes
+        (&writer).write_all(line).unwrap();
+        assert!(!generation(&path, 1).exists());
+        // A second line would make 34 > 32 bytes: rotate first.
+        (&writer).write_all(line).unwrap();
+        assert!(generation(&path, 1).exists());
+        assert_eq!(fs::read(&path).unwrap(), line);
+        assert_eq!(fs::read(generation(&path, 1)).unwrap(), line);
+
+        // Two more rotations: only `max_files` generations survive.
+        (&writer).write_all(b"second\n").unwrap();
+        (&writer).write_all(b"third-line-that-overflows\n").unwrap();
+        (&writer)
+            .write_all(b"fourth-line-that-overflows\n")
+            .unwrap();
+        assert!(generation(&path, 2).exists());
+        assert!(!generation(&path, 3).exists());
+        assert_eq!(fs::read(&path).unwrap(), b"fourth-line-that-overflows\n");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn file_config_receives_tracing_output() {
+        let dir = scratch_dir("output");
+        let path = dir.join("sensor.log");
+        let config = crate::LoggingConfig {
+            level: "info".to_string(),
+            file: Some(crate::config::LogFileConfig {
+                path: path.clone(),
+                max_size_bytes: 1024 * 1024,
+                max_files: 3,
+                stdout: false,
+            }),
+            ..Default::default()
+        };
+
+        let (subscriber, _) = crate::build_subscriber(&config).unwrap();
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!("bridge started");
+            tracing::debug!("filtered out by level");
+        });
+
+        let log = fs::read_to_string(&path).unwrap();
+        assert!(log.contains("bridge started"));
+        assert!(!log.contains("filtered out by level"));
+        // Files never carry terminal color codes.
+        assert!(!log.contains('\u{1b}'));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn module_levels_override_the_default_level() {
+        let dir = scratch_dir("modules");
+        let path = dir.join("sensor.log");
+        let mut config = crate::LoggingConfig {
+            level: "info".to_string(),
+            file: Some(crate::config::LogFileConfig {
+                path: path.clone(),
+                max_size_bytes: 0,
+                max_files: 0,
+                stdout: false,
+            }),
+            ..Default::default()
+        };
+        config
+            .module_levels
+            .insert("zenoh".to_string(), "warn".to_string());
+        config.module_levels.insert(
+            "zensight_sensor_snmp::poller".to_string(),
+            "debug".to_string(),
+        );
+        assert_eq!(
+            config.filter_directives(),
+            "info,zenoh=warn,zensight_sensor_snmp::poller=debug"
+        );
+
+        let (subscriber, _) = crate::build_subscriber(&config).unwrap();
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(target: "zenoh::net", "zenoh chatter");
+            tracing::warn!(target: "zenoh::net", "zenoh warning");
+            tracing::debug!(target: "zensight_sensor_snmp::poller", "poll detail");
+            tracing::debug!(target: "zensight_sensor_snmp::trap", "trap detail");
+            tracing::info!(target: "zensight_sensor_snmp::trap", "trap summary");
+        });
+
+        let log = fs::read_to_string(&path).unwrap();
+        assert!(!log.contains("zenoh chatter"));
+        assert!(log.contains("zenoh warning"));
+        assert!(log.contains("poll detail"));
+        assert!(!log.contains("trap detail"));
+        assert!(log.contains("trap summary"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn filter_handle_changes_the_level_at_runtime() {
+        let dir = scratch_dir("reload");
+        let path = dir.join("sensor.log");
+        let config = crate::LoggingConfig {
+            level: "info".to_string(),
+            file: Some(crate::config::LogFileConfig {
+                path: path.clone(),
+                max_size_bytes: 0,
+                max_files: 0,
+                stdout: false,
+            }),
+            ..Default::default()
+        };
+
+        let (subscriber, filter) = crate::build_subscriber(&config).unwrap();
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::debug!("before reload");
+            filter
+                .reload(tracing_subscriber::EnvFilter::new("debug"))
+                .unwrap();
+            tracing::debug!("after reload");
+        });
+
+        let log = fs::read_to_string(&path).unwrap();
+        assert!(!log.contains("before reload"));
+        assert!(log.contains("after reload"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,72 @@
+//! Per-sample provenance carried as a Zenoh attachment (#444).
+//!
+//! A bridge with `attach_metadata` enabled attaches a [`SampleMetadata`] to
+//! every telemetry sample it publishes, so the frontend can tell which bridge
+//! and version produced a metric and how its payload is encoded. The
+//! attachment is a small JSON object, independent of the payload format.
+
+use serde::{Deserialize, Serialize};
+
+use crate::serialization::Format;
+
+/// Who produced a sample, and how its payload is encoded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SampleMetadata {
+    /// Bridge name (e.g. "snmp").
+    pub bridge: String,
+    /// Bridge version.
+    pub version: String,
+    /// Payload serialization format.
+    pub encoding: Format,
+}
+
+impl SampleMetadata {
+    /// Create metadata for `bridge` at `version` publishing `encoding`.
+    pub fn new(bridge: impl Into<String>, version: impl Into<String>, encoding: Format) -> Self {
+        Self {
+            bridge: bridge.into(),
+            version: version.into(),
+            encoding,
+        }
+    }
+
+    /// Encode as attachment bytes.
+    pub fn to_attachment(&self) -> Vec<u8> {
+        serde_json::to_vec(self).unwrap_or_default()
+    }
+
+    /// Decode attachment bytes; `None` when they aren't sample metadata
+    /// (another producer's attachment, or none at all).
+    pub fn from_attachment(bytes: &[u8]) -> Option<Self> {
+        serde_json::from_slice(bytes).ok()
+    }
+}
+
+impl std::fmt::Display for SampleMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let encoding = match self.encoding {
+            Format::Json => "json",
+            Format::Cbor => "cbor",
+        };
+        write!(f, "{} v{} ({encoding})", self.bridge, self.version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attachment_round_trips() {
+        let meta = SampleMetadata::new("snmp", "0.4.2", Format::Cbor);
+        let bytes = meta.to_attachment();
+        assert_eq!(SampleMetadata::from_attachment(&bytes), Some(meta.clone()));
+        assert_eq!(meta.to_string(), "snmp v0.4.2 (cbor)");
+    }
+
+    #[test]
+    fn foreign_attachments_are_ignored() {
+        assert_eq!(SampleMetadata::from_attachment(b"trace-id=42"), None);
+        assert_eq!(SampleMetadata::from_attachment(b""), None);
+    }
+}
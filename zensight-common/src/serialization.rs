@@ -1,3 +1,5 @@
+use std::marker::PhantomData;
+
 use serde::{Serialize, de::DeserializeOwned};
 
 use crate::error::{Error, Result};
@@ -22,16 +24,53 @@ impl Format {
             Format::Cbor => "application/cbor",
         }
     }
+
+    /// Discriminator byte [`encode_tagged`] prepends for this format (#408).
+    ///
+    /// Both are ASCII control characters that are neither JSON whitespace nor
+    /// a well-formed CBOR initial byte (additional info 28–30 is reserved),
+    /// so a tagged payload can never be mistaken for a legacy one.
+    pub const fn tag(self) -> u8 {
+        match self {
+            Format::Json => 0x1c,
+            Format::Cbor => 0x1d,
+        }
+    }
+
+    /// The format a discriminator byte stands for, if it is one.
+    pub const fn from_tag(byte: u8) -> Option<Format> {
+        match byte {
+            0x1c => Some(Format::Json),
+            0x1d => Some(Format::Cbor),
+            _ => None,
+        }
+    }
 }
 
 /// Encode a value to bytes using the specified format.
 pub fn encode<T: Serialize>(value: &T, format: Format) -> Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    encode_into(value, format, &mut buf)?;
+    Ok(buf)
+}
+
+/// Encode a value prefixed with its format's [`Format::tag`] byte (#408).
+///
+/// [`decode_auto`] reads the tag instead of guessing, so tagged payloads
+/// decode in one pass whatever their content. Plain [`decode`] does not
+/// understand the tag; only use this where readers go through `decode_auto`.
+pub fn encode_tagged<T: Serialize>(value: &T, format: Format) -> Result<Vec<u8>> {
+    let mut buf = vec![format.tag()];
+    encode_into(value, format, &mut buf)?;
+    Ok(buf)
+}
+
+fn encode_into<T: Serialize>(value: &T, format: Format, buf: &mut Vec<u8>) -> Result<()> {
     match format {
-        Format::Json => serde_json::to_vec(value).map_err(Error::from),
+        Format::Json => serde_json::to_writer(buf, value).map_err(Error::from),
         Format::Cbor => {
-            let mut buf = Vec::new();
-            ciborium::into_writer(value, &mut buf)?;
-            Ok(buf)
+            ciborium::into_writer(value, buf)?;
+            Ok(())
         }
     }
 }
@@ -46,8 +85,12 @@ pub fn decode<T: DeserializeOwned>(data: &[u8], format: Format) -> Result<T> {
 
 /// Try to auto-detect the format from the data.
 ///
-/// Returns `Json` if the data starts with `{` or `[`, otherwise `Cbor`.
+/// A leading [`Format::tag`] byte wins. Untagged (legacy) payloads are
+/// `Json` if the first byte is `{` or `[`, otherwise `Cbor`, as before.
 pub fn detect_format(data: &[u8]) -> Format {
+    if let Some(format) = data.first().and_then(|b| Format::from_tag(*b)) {
+        return format;
+    }
     match data.first() {
         Some(b'{') | Some(b'[') => Format::Json,
         _ => Format::Cbor,
@@ -55,9 +98,163 @@ pub fn detect_format(data: &[u8]) -> Format {
 }
 
 /// Decode bytes, auto-detecting the format.
+///
+/// Tagged payloads from [`encode_tagged`] are decoded with the tagged format
+/// and the tag stripped; anything else falls back to [`detect_format`]. Either
+/// way the payload is parsed exactly once.
 pub fn decode_auto<T: DeserializeOwned>(data: &[u8]) -> Result<T> {
-    let format = detect_format(data);
-    decode(data, format)
+    if let Some((&tag, rest)) = data.split_first()
+        && let Some(format) = Format::from_tag(tag)
+    {
+        return decode(rest, format);
+    }
+    decode(data, detect_format(data))
+}
+
+/// Decode a top-level array one element at a time (#409).
+///
+/// For batched payloads (`Vec<T>` encoded with [`encode`]) this yields each
+/// element as it is parsed instead of materializing the whole `Vec`, so peak
+/// memory is one element rather than the batch. The array header is checked
+/// up front; element errors are yielded in place and end the stream.
+pub fn decode_stream<T: DeserializeOwned>(
+    data: &[u8],
+    format: Format,
+) -> Result<DecodeStream<'_, T>> {
+    let (rest, state) = match format {
+        Format::Json => {
+            let rest = data.trim_ascii_start();
+            match rest.split_first() {
+                Some((b'[', rest)) => (rest, StreamState::JsonFirst),
+                _ => return Err(json_syntax("expected a JSON array")),
+            }
+        }
+        Format::Cbor => cbor_array_header(data)?,
+    };
+    Ok(DecodeStream {
+        rest,
+        format,
+        state,
+        _marker: PhantomData,
+    })
+}
+
+/// Iterator returned by [`decode_stream`].
+#[derive(Debug)]
+pub struct DecodeStream<'a, T> {
+    rest: &'a [u8],
+    format: Format,
+    state: StreamState,
+    _marker: PhantomData<fn() -> T>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StreamState {
+    /// JSON, before the first element (no `,` expected).
+    JsonFirst,
+    /// JSON, after an element (`,` or `]` next).
+    JsonNext,
+    /// CBOR definite-length array with this many elements left.
+    CborCount(u64),
+    /// CBOR indefinite-length array, terminated by a `0xff` break.
+    CborIndefinite,
+    Done,
+}
+
+fn json_syntax(msg: &str) -> Error {
+    Error::Json(serde::de::Error::custom(msg))
+}
+
+/// Parse a CBOR array header (major type 4), returning the bytes after it.
+fn cbor_array_header(data: &[u8]) -> Result<(&[u8], StreamState)> {
+    let Some((&initial, rest)) = data.split_first() else {
+        return Err(Error::Cbor("empty payload".to_string()));
+    };
+    let width = match initial {
+        0x80..=0x97 => return Ok((rest, StreamState::CborCount(u64::from(initial & 0x1f)))),
+        0x9f => return Ok((rest, StreamState::CborIndefinite)),
+        0x98 => 1,
+        0x99 => 2,
+        0x9a => 4,
+        0x9b => 8,
+        _ => return Err(Error::Cbor("expected a CBOR array".to_string())),
+    };
+    if rest.len() < width {
+        return Err(Error::Cbor("truncated array header".to_string()));
+    }
+    let (len, rest) = rest.split_at(width);
+    let count = len.iter().fold(0u64, |n, b| (n << 8) | u64::from(*b));
+    Ok((rest, StreamState::CborCount(count)))
+}
+
+impl<T: DeserializeOwned> DecodeStream<'_, T> {
+    fn next_json(&mut self) -> Option<Result<T>> {
+        self.rest = self.rest.trim_ascii_start();
+        if let Some((b']', _)) = self.rest.split_first() {
+            self.state = StreamState::Done;
+            return None;
+        }
+        if self.state == StreamState::JsonNext {
+            match self.rest.split_first() {
+                Some((b',', rest)) => self.rest = rest,
+                _ => {
+                    self.state = StreamState::Done;
+                    return Some(Err(json_syntax("expected `,` or `]` in JSON array")));
+                }
+            }
+        }
+        let mut values = serde_json::Deserializer::from_slice(self.rest).into_iter::<T>();
+        let item = values.next();
+        let consumed = values.byte_offset();
+        self.rest = &self.rest[consumed..];
+        self.state = StreamState::JsonNext;
+        match item {
+            Some(Ok(value)) => Some(Ok(value)),
+            Some(Err(e)) => {
+                self.state = StreamState::Done;
+                Some(Err(e.into()))
+            }
+            None => {
+                self.state = StreamState::Done;
+                Some(Err(json_syntax("unterminated JSON array")))
+            }
+        }
+    }
+
+    fn next_cbor(&mut self) -> Option<Result<T>> {
+        match self.state {
+            StreamState::CborCount(0) => {
+                self.state = StreamState::Done;
+                return None;
+            }
+            StreamState::CborCount(n) => self.state = StreamState::CborCount(n - 1),
+            StreamState::CborIndefinite if self.rest.first() == Some(&0xff) => {
+                self.state = StreamState::Done;
+                return None;
+            }
+            _ => {}
+        }
+        // `&mut &[u8]` is a `Read` that advances past exactly one item.
+        match ciborium::from_reader(&mut self.rest) {
+            Ok(value) => Some(Ok(value)),
+            Err(e) => {
+                self.state = StreamState::Done;
+                Some(Err(e.into()))
+            }
+        }
+    }
+}
+
+impl<T: DeserializeOwned> Iterator for DecodeStream<'_, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.state, self.format) {
+            (StreamState::Done, _) => None,
+            (_, Format::Json) => self.next_json(),
+            (_, Format::Cbor) => self.next_cbor(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -142,4 +339,194 @@ mod tests {
         let decoded: TelemetryPoint = decode_auto(&cbor).unwrap();
         assert_eq!(point.source, decoded.source);
     }
+
+    #[test]
+    fn test_tagged_payloads_decode() {
+        let point = TelemetryPoint::new(
+            "router01",
+            Protocol::Snmp,
+            "test",
+            TelemetryValue::Counter(42),
+        );
+
+        for format in [Format::Json, Format::Cbor] {
+            let tagged = encode_tagged(&point, format).unwrap();
+            assert_eq!(tagged[0], format.tag());
+            assert_eq!(&tagged[1..], encode(&point, format).unwrap());
+            assert_eq!(detect_format(&tagged), format);
+
+            let decoded: TelemetryPoint = decode_auto(&tagged).unwrap();
+            assert_eq!(point.value, decoded.value);
+        }
+    }
+
+    #[test]
+    fn test_legacy_payloads_still_decode() {
+        let point = TelemetryPoint::new(
+            "router01",
+            Protocol::Snmp,
+            "test",
+            TelemetryValue::Counter(42),
+        );
+
+        // Untagged CBOR and JSON.
+        let cbor = encode(&point, Format::Cbor).unwrap();
+        let decoded: TelemetryPoint = decode_auto(&cbor).unwrap();
+        assert_eq!(point.value, decoded.value);
+
+        let json = encode(&point, Format::Json).unwrap();
+        assert_eq!(detect_format(&json), Format::Json);
+        let decoded: TelemetryPoint = decode_auto(&json).unwrap();
+        assert_eq!(point.value, decoded.value);
+
+        // Legacy detection still looks at the first byte only: 0x20 is CBOR
+        // -1 (and ASCII space), so `{` behind it doesn't make it JSON.
+        assert_eq!(detect_format(b" {}"), Format::Cbor);
+        assert_eq!(
+            decode_auto::<i64>(&encode(&-1i64, Format::Cbor).unwrap()).unwrap(),
+            -1
+        );
+
+        // A tag byte with garbage behind it is an error, not a fallback guess.
+        assert!(decode_auto::<TelemetryPoint>(&[Format::Json.tag(), 0xff]).is_err());
+        assert!(decode_auto::<TelemetryPoint>(&[]).is_err());
+    }
+
+    #[test]
+    fn test_stream_decode_matches_eager_decode() {
+        let batch: Vec<TelemetryPoint> = (0..1000)
+            .map(|i| {
+                TelemetryPoint::new(
+                    format!("host{}", i % 7),
+                    Protocol::Sysinfo,
+                    format!("cpu/{i}"),
+                    TelemetryValue::Gauge(i as f64 / 3.0),
+                )
+            })
+            .collect();
+
+        for format in [Format::Json, Format::Cbor] {
+            let encoded = encode(&batch, format).unwrap();
+            let eager: Vec<TelemetryPoint> = decode(&encoded, format).unwrap();
+            let streamed: Vec<TelemetryPoint> = decode_stream(&encoded, format)
+                .unwrap()
+                .collect::<Result<_>>()
+                .unwrap();
+
+            assert_eq!(streamed.len(), 1000);
+            for (a, b) in streamed.iter().zip(&eager) {
+                assert_eq!(
+                    (&a.source, &a.metric, &a.value, a.timestamp),
+                    (&b.source, &b.metric, &b.value, b.timestamp)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_stream_decode_edges() {
+        // Empty arrays, and CBOR's indefinite-length form.
+        assert_eq!(
+            decode_stream::<u32>(b" [ ] ", Format::Json)
+                .unwrap()
+                .count(),
+            0
+        );
+        assert_eq!(
+            decode_stream::<u32>(&[0x80], Format::Cbor).unwrap().count(),
+            0
+        );
+        let indefinite = [0x9f, 0x01, 0x02, 0xff];
+        let values: Vec<u32> = decode_stream(&indefinite, Format::Cbor)
+            .unwrap()
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(values, [1, 2]);
+
+        // Not an array, or broken mid-way.
+        assert!(decode_stream::<u32>(b"{}", Format::Json).is_err());
+        assert!(decode_stream::<u32>(&[0xa0], Format::Cbor).is_err());
+        let items: Vec<_> = decode_stream::<u32>(b"[1, 2 3]", Format::Json)
+            .unwrap()
+            .collect();
+        assert_eq!(items.len(), 3);
+        assert!(items[2].is_err());
+    }
+
+    #[test]
+    fn test_histogram_roundtrip() {
+        let point = TelemetryPoint::new(
+            "opcua-gw",
+            Protocol::Opcua,
+            "line1/cycle_time",
+            TelemetryValue::Histogram {
+                buckets: vec![(0.1, 4), (0.5, 9), (1.0, 12)],
+                sum: 6.25,
+                count: 13,
+            },
+        );
+
+        for format in [Format::Json, Format::Cbor] {
+            let encoded = encode(&point, format).unwrap();
+            let decoded: TelemetryPoint = decode_auto(&encoded).unwrap();
+            assert_eq!(point.value, decoded.value, "{format:?}");
+        }
+
+        // Wire shape follows the other adjacently-tagged variants.
+        let json: serde_json::Value = serde_json::to_value(&point.value).unwrap();
+        assert_eq!(json["type"], "histogram");
+        assert_eq!(json["value"]["buckets"][1], serde_json::json!([0.5, 9]));
+        assert_eq!(json["value"]["count"], 13);
+    }
+
+    #[test]
+    fn test_existing_variants_decode_unchanged() {
+        // Payloads as older sensors publish them still decode after #404.
+        let counter: TelemetryValue = decode_auto(br#"{"type":"counter","value":7}"#).unwrap();
+        assert_eq!(counter, TelemetryValue::Counter(7));
+        let binary: TelemetryValue = decode_auto(br#"{"type":"binary","value":[1,2]}"#).unwrap();
+        assert_eq!(binary, TelemetryValue::Binary(vec![1, 2]));
+    }
+
+    #[test]
+    fn test_bytes_json_is_base64() {
+        let point = TelemetryPoint::new(
+            "switch01",
+            Protocol::Gnmi,
+            "interfaces/eth0/hw-addr",
+            TelemetryValue::Bytes(vec![0xde, 0xad, 0xbe, 0xef, 0x00, 0xff]),
+        );
+
+        let json = encode(&point, Format::Json).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(value["value"]["type"], "bytes");
+        assert_eq!(value["value"]["value"], "3q2+7wD/");
+
+        let decoded: TelemetryPoint = decode_auto(&json).unwrap();
+        assert_eq!(decoded.value, point.value);
+    }
+
+    #[test]
+    fn test_bytes_cbor_is_raw() {
+        let payload: Vec<u8> = (0..=255).collect();
+        let point = TelemetryPoint::new(
+            "router01",
+            Protocol::Snmp,
+            "opaque",
+            TelemetryValue::Bytes(payload.clone()),
+        );
+
+        let cbor = encode(&point, Format::Cbor).unwrap();
+        // A CBOR byte string (major type 2, 2-byte length 256) followed by the
+        // payload verbatim — not an array of integers or a base64 text.
+        let header = [0x59, 0x01, 0x00];
+        let at = cbor
+            .windows(header.len())
+            .position(|w| w == header)
+            .expect("byte string header");
+        assert_eq!(&cbor[at + 3..at + 3 + 256], payload.as_slice());
+
+        let decoded: TelemetryPoint = decode_auto(&cbor).unwrap();
+        assert_eq!(decoded.value, TelemetryValue::Bytes(payload));
+    }
 }
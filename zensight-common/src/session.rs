@@ -8,6 +8,29 @@ pub async fn connect(config: &ZenohConfig) -> Result<Session> {
     // Honor ZENSIGHT_ZENOH_* env overrides (e.g. set by `just run` to pin a
     // local rendezvous endpoint instead of relying on multicast discovery).
     let config = &config.clone().with_env_overrides();
+    let zenoh_config = session_config(config)?;
+
+    tracing::info!(
+        mode = %config.mode,
+        connect = ?config.connect,
+        listen = ?config.listen,
+        multicast_scouting = config.multicast_scouting,
+        gossip_scouting = config.gossip_scouting,
+        namespace = ?config.namespace(),
+        "Connecting to Zenoh"
+    );
+
+    let session = zenoh::open(zenoh_config).await?;
+
+    tracing::info!(zid = %session.zid(), "Connected to Zenoh");
+
+    Ok(session)
+}
+
+/// Build the native Zenoh config a session for `config` opens with: mode,
+/// endpoints, scouting (#426) and namespace (#446). Env overrides are not
+/// applied here.
+pub fn session_config(config: &ZenohConfig) -> Result<zenoh::Config> {
     let mut zenoh_config = zenoh::Config::default();
 
     // Set mode
@@ -45,16 +68,74 @@ pub async fn connect(config: &ZenohConfig) -> Result<Session> {
             .map_err(|e| Error::Config(format!("Failed to set listen endpoints: {}", e)))?;
     }
 
-    tracing::info!(
-        mode = %config.mode,
-        connect = ?config.connect,
-        listen = ?config.listen,
-        "Connecting to Zenoh"
-    );
+    // Scouting: multicast discovery and peer gossip
+    zenoh_config
+        .insert_json5(
+            "scouting/multicast/enabled",
+            &config.multicast_scouting.to_string(),
+        )
+        .map_err(|e| Error::Config(format!("Failed to set multicast scouting: {}", e)))?;
+    zenoh_config
+        .insert_json5(
+            "scouting/gossip/enabled",
+            &config.gossip_scouting.to_string(),
+        )
+        .map_err(|e| Error::Config(format!("Failed to set gossip scouting: {}", e)))?;
 
-    let session = zenoh::open(zenoh_config).await?;
+    // Namespace: Zenoh prefixes every outgoing key and strips incoming ones,
+    // so the `zensight/...` keys built everywhere stay as they are.
+    if let Some(namespace) = config.namespace() {
+        let namespace_json = serde_json::to_string(namespace)
+            .map_err(|e| Error::Config(format!("Failed to serialize namespace: {}", e)))?;
+        zenoh_config
+            .insert_json5("namespace", &namespace_json)
+            .map_err(|e| Error::Config(format!("Invalid namespace '{}': {}", namespace, e)))?;
+    }
 
-    tracing::info!(zid = %session.zid(), "Connected to Zenoh");
+    Ok(zenoh_config)
+}
 
-    Ok(session)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scouting_settings_reach_the_session_config() {
+        let config = ZenohConfig {
+            multicast_scouting: false,
+            ..ZenohConfig::default()
+        };
+        let zenoh_config = session_config(&config).unwrap();
+        assert_eq!(
+            zenoh_config.get_json("scouting/multicast/enabled").unwrap(),
+            "false"
+        );
+        assert_eq!(
+            zenoh_config.get_json("scouting/gossip/enabled").unwrap(),
+            "true"
+        );
+
+        let bad = ZenohConfig {
+            mode: "mesh".into(),
+            ..ZenohConfig::default()
+        };
+        assert!(session_config(&bad).is_err());
+    }
+
+    #[test]
+    fn namespace_reaches_the_session_config() {
+        let config = ZenohConfig {
+            namespace: Some("/tenantA/".into()),
+            ..ZenohConfig::default()
+        };
+        let zenoh_config = session_config(&config).unwrap();
+        assert_eq!(zenoh_config.get_json("namespace").unwrap(), "\"tenantA\"");
+
+        // Wildcards can't name a namespace.
+        let bad = ZenohConfig {
+            namespace: Some("tenant*".into()),
+            ..ZenohConfig::default()
+        };
+        assert!(session_config(&bad).is_err());
+    }
 }
@@ -43,6 +43,25 @@ impl TelemetryPoint {
         }
     }
 
+    /// Start building a point field by field (#462).
+    ///
+    /// ```
+    /// use zensight_common::{Protocol, TelemetryPoint, TelemetryValue};
+    ///
+    /// let point = TelemetryPoint::builder()
+    ///     .source("router01")
+    ///     .protocol(Protocol::Snmp)
+    ///     .metric("if/1/ifInOctets")
+    ///     .value(TelemetryValue::Counter(42))
+    ///     .label("interface", "eth0")
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(point.labels["interface"], "eth0");
+    /// ```
+    pub fn builder() -> TelemetryPointBuilder {
+        TelemetryPointBuilder::default()
+    }
+
     /// Add a label to this telemetry point.
     pub fn with_label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
         self.labels.insert(key.into(), value.into());
@@ -76,9 +95,167 @@ pub enum TelemetryValue {
     #[serde(rename = "boolean")]
     Boolean(bool),
 
-    /// Binary data.
+    /// Binary data, encoded as an array of numbers. Kept for compatibility
+    /// with existing payloads; new producers use [`TelemetryValue::Bytes`].
     #[serde(rename = "binary")]
     Binary(Vec<u8>),
+
+    /// Opaque binary payload (#405), e.g. gNMI `bytes_val` or an SNMP Opaque.
+    /// A base64 string in JSON, a raw byte string in CBOR.
+    #[serde(rename = "bytes", with = "bytes_encoding")]
+    Bytes(Vec<u8>),
+
+    /// Distribution (#404), Prometheus-style: cumulative buckets as
+    /// `(upper_bound, count)` sorted by bound. The `+Inf` bucket is implicit —
+    /// it always equals `count` — so the value stays encodable as JSON.
+    #[serde(rename = "histogram")]
+    Histogram {
+        buckets: Vec<(f64, u64)>,
+        sum: f64,
+        count: u64,
+    },
+}
+
+/// Fluent constructor for a [`TelemetryPoint`] (#462); see
+/// [`TelemetryPoint::builder`]. The timestamp defaults to now.
+#[derive(Debug, Clone, Default)]
+pub struct TelemetryPointBuilder {
+    timestamp: Option<i64>,
+    source: Option<String>,
+    protocol: Option<Protocol>,
+    metric: Option<String>,
+    value: Option<TelemetryValue>,
+    labels: HashMap<String, String>,
+}
+
+/// A required [`TelemetryPointBuilder`] field that was never set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("telemetry point is missing its {0}")]
+pub struct MissingField(pub &'static str);
+
+impl TelemetryPointBuilder {
+    /// Measurement time in Unix epoch milliseconds.
+    pub fn timestamp(mut self, timestamp: i64) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Device/host identifier.
+    pub fn source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Origin protocol.
+    pub fn protocol(mut self, protocol: Protocol) -> Self {
+        self.protocol = Some(protocol);
+        self
+    }
+
+    /// Metric name/path.
+    pub fn metric(mut self, metric: impl Into<String>) -> Self {
+        self.metric = Some(metric.into());
+        self
+    }
+
+    /// The measured value.
+    pub fn value(mut self, value: impl Into<TelemetryValue>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Add one label, replacing any earlier value for `key`.
+    pub fn label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels.insert(key.into(), value.into());
+        self
+    }
+
+    /// Add several labels.
+    pub fn labels<K, V>(mut self, labels: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.labels
+            .extend(labels.into_iter().map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+
+    /// The point, or the first required field (source, protocol, metric,
+    /// value) left unset.
+    pub fn build(self) -> Result<TelemetryPoint, MissingField> {
+        Ok(TelemetryPoint {
+            timestamp: self.timestamp.unwrap_or_else(current_timestamp_millis),
+            source: self.source.ok_or(MissingField("source"))?,
+            protocol: self.protocol.ok_or(MissingField("protocol"))?,
+            metric: self.metric.ok_or(MissingField("metric"))?,
+            value: self.value.ok_or(MissingField("value"))?,
+            labels: self.labels,
+        })
+    }
+}
+
+/// Why a [`TelemetryValue`] failed validation (#458).
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum InvalidValue {
+    /// A gauge that is NaN or infinite.
+    #[error("gauge value {0} is not finite")]
+    NonFiniteGauge(f64),
+
+    /// A negative reading for a counter.
+    #[error("counter value {0} is negative")]
+    NegativeCounter(i64),
+
+    /// A histogram with a non-finite bound or sum, unsorted bounds, or
+    /// cumulative counts that decrease or exceed `count`.
+    #[error("malformed histogram: {0}")]
+    MalformedHistogram(&'static str),
+}
+
+impl TelemetryValue {
+    /// A counter from a signed reading (#458): negative values are flagged
+    /// rather than wrapped or turned into a gauge.
+    pub fn counter_from_signed(v: i64) -> Result<Self, InvalidValue> {
+        u64::try_from(v)
+            .map(TelemetryValue::Counter)
+            .map_err(|_| InvalidValue::NegativeCounter(v))
+    }
+
+    /// Check the value is safe to chart and evaluate (#458): gauges must be
+    /// finite and histograms well-formed. Other variants are always valid.
+    /// Bridges don't call this themselves; the sensor publisher does.
+    pub fn validate(&self) -> Result<(), InvalidValue> {
+        match self {
+            TelemetryValue::Gauge(v) if !v.is_finite() => Err(InvalidValue::NonFiniteGauge(*v)),
+            TelemetryValue::Histogram {
+                buckets,
+                sum,
+                count,
+            } => {
+                if !sum.is_finite() {
+                    return Err(InvalidValue::MalformedHistogram("sum is not finite"));
+                }
+                if buckets.iter().any(|(bound, _)| bound.is_nan()) {
+                    return Err(InvalidValue::MalformedHistogram("bucket bound is NaN"));
+                }
+                for pair in buckets.windows(2) {
+                    if pair[1].0 <= pair[0].0 {
+                        return Err(InvalidValue::MalformedHistogram("bounds are not sorted"));
+                    }
+                    if pair[1].1 < pair[0].1 {
+                        return Err(InvalidValue::MalformedHistogram("bucket counts decrease"));
+                    }
+                }
+                if buckets.last().is_some_and(|(_, c)| c > count) {
+                    return Err(InvalidValue::MalformedHistogram(
+                        "bucket count exceeds total",
+                    ));
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 impl From<u64> for TelemetryValue {
@@ -127,6 +304,58 @@ impl From<Vec<u8>> for TelemetryValue {
     }
 }
 
+/// Serde for [`TelemetryValue::Bytes`] (#405): base64 for human-readable
+/// formats (JSON), a native byte string otherwise (CBOR). Decoding accepts
+/// either form, plus a plain number array.
+mod bytes_encoding {
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD;
+    use serde::de::{self, SeqAccess, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&STANDARD.encode(bytes))
+        } else {
+            serializer.serialize_bytes(bytes)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        deserializer.deserialize_any(BytesVisitor)
+    }
+
+    struct BytesVisitor;
+
+    impl<'de> Visitor<'de> for BytesVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str("a base64 string or a byte string")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Vec<u8>, E> {
+            STANDARD.decode(v).map_err(E::custom)
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Vec<u8>, E> {
+            Ok(v.to_vec())
+        }
+
+        fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Vec<u8>, E> {
+            Ok(v)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+            let mut out = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(b) = seq.next_element()? {
+                out.push(b);
+            }
+            Ok(out)
+        }
+    }
+}
+
 /// Protocol identifier for telemetry sources.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -301,4 +530,108 @@ mod tests {
         // Zero is non-negative, becomes Counter
         assert_eq!(TelemetryValue::from(0i64), TelemetryValue::Counter(0));
     }
+
+    #[test]
+    fn builder_matches_manual_construction() {
+        let manual = TelemetryPoint {
+            timestamp: 1_700_000_000_000,
+            source: "plc01".into(),
+            protocol: Protocol::Modbus,
+            metric: "holding/temperature".into(),
+            value: TelemetryValue::Gauge(21.5),
+            labels: HashMap::from([
+                ("address".to_string(), "40001".to_string()),
+                ("unit".to_string(), "C".to_string()),
+                ("register_type".to_string(), "holding".to_string()),
+            ]),
+        };
+        let built = TelemetryPoint::builder()
+            .timestamp(1_700_000_000_000)
+            .source("plc01")
+            .protocol(Protocol::Modbus)
+            .metric("holding/temperature")
+            .value(21.5)
+            .label("address", "40001")
+            .labels([("unit", "C"), ("register_type", "holding")])
+            .build()
+            .unwrap();
+
+        assert_eq!(built.timestamp, manual.timestamp);
+        assert_eq!(built.source, manual.source);
+        assert_eq!(built.protocol, manual.protocol);
+        assert_eq!(built.metric, manual.metric);
+        assert_eq!(built.value, manual.value);
+        assert_eq!(built.labels, manual.labels);
+    }
+
+    #[test]
+    fn builder_reports_missing_fields() {
+        let err = TelemetryPoint::builder()
+            .source("r1")
+            .protocol(Protocol::Snmp)
+            .value(1u64)
+            .build()
+            .unwrap_err();
+        assert_eq!(err, MissingField("metric"));
+
+        // The timestamp defaults to now.
+        let before = current_timestamp_millis();
+        let point = TelemetryPoint::builder()
+            .source("r1")
+            .protocol(Protocol::Snmp)
+            .metric("m")
+            .value(true)
+            .build()
+            .unwrap();
+        assert!(point.timestamp >= before);
+        assert!(point.labels.is_empty());
+    }
+
+    #[test]
+    fn validate_rejects_non_finite_gauges() {
+        for v in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let err = TelemetryValue::Gauge(v).validate().unwrap_err();
+            assert!(matches!(err, InvalidValue::NonFiniteGauge(_)));
+        }
+    }
+
+    #[test]
+    fn counter_from_signed_flags_negative_readings() {
+        assert_eq!(
+            TelemetryValue::counter_from_signed(7),
+            Ok(TelemetryValue::Counter(7))
+        );
+        assert_eq!(
+            TelemetryValue::counter_from_signed(0),
+            Ok(TelemetryValue::Counter(0))
+        );
+        assert_eq!(
+            TelemetryValue::counter_from_signed(-3),
+            Err(InvalidValue::NegativeCounter(-3))
+        );
+    }
+
+    #[test]
+    fn validate_accepts_valid_values() {
+        let valid = [
+            TelemetryValue::Gauge(-40.5),
+            TelemetryValue::Counter(0),
+            TelemetryValue::Text(String::new()),
+            TelemetryValue::Histogram {
+                buckets: vec![(0.1, 2), (1.0, 5), (f64::INFINITY, 5)],
+                sum: 3.2,
+                count: 6,
+            },
+        ];
+        for value in valid {
+            assert_eq!(value.validate(), Ok(()));
+        }
+
+        let decreasing = TelemetryValue::Histogram {
+            buckets: vec![(0.1, 5), (1.0, 2)],
+            sum: 1.0,
+            count: 5,
+        };
+        assert!(decreasing.validate().is_err());
+    }
 }
@@ -106,6 +106,15 @@ fn test_all_telemetry_value_types() {
         (TelemetryValue::Text("hello".to_string()), "text"),
         (TelemetryValue::Boolean(true), "boolean"),
         (TelemetryValue::Binary(vec![0x01, 0x02, 0x03]), "binary"),
+        (TelemetryValue::Bytes(vec![0x00, 0xfe, 0xff]), "bytes"),
+        (
+            TelemetryValue::Histogram {
+                buckets: vec![(0.5, 1), (2.0, 4)],
+                sum: 5.5,
+                count: 5,
+            },
+            "histogram",
+        ),
     ];
 
     for (value, _type_name) in values {
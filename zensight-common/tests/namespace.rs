@@ -0,0 +1,91 @@
+//! Integration test for the deployment namespace (#446): a namespaced session
+//! talks to the fabric under `<namespace>/zensight/...` while its own keys stay
+//! `zensight/...`.
+
+use std::time::Duration;
+
+use zensight_common::{
+    KeyExprBuilder, Protocol, ZenohConfig, all_telemetry_wildcard, connect, parse_key_expr,
+};
+
+/// A localhost port that was free a moment ago.
+fn free_port() -> u16 {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    listener.local_addr().unwrap().port()
+}
+
+/// A plain, un-namespaced peer listening on `port`: it sees raw fabric keys.
+async fn observer(port: u16) -> zenoh::Session {
+    let mut config = zenoh::Config::default();
+    config
+        .insert_json5("listen/endpoints", &format!("[\"tcp/127.0.0.1:{port}\"]"))
+        .unwrap();
+    config
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    config
+        .insert_json5("scouting/gossip/enabled", "false")
+        .unwrap();
+    zenoh::open(config).await.unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn namespace_prefixes_published_keys_and_subscriptions() {
+    let port = free_port();
+    let fabric = observer(port).await;
+    let tenant = connect(&ZenohConfig {
+        connect: vec![format!("tcp/127.0.0.1:{port}")],
+        multicast_scouting: false,
+        gossip_scouting: false,
+        namespace: Some("tenantA".into()),
+        ..ZenohConfig::default()
+    })
+    .await
+    .unwrap();
+
+    let fabric_sub = fabric
+        .declare_subscriber("tenantA/zensight/**")
+        .await
+        .unwrap();
+    // What the GUI subscribes to.
+    let gui_sub = tenant
+        .declare_subscriber(all_telemetry_wildcard())
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    // A bridge's key reaches the fabric under the namespace.
+    let key = KeyExprBuilder::new(Protocol::Snmp).build("router01", "system/sysUpTime");
+    tenant.put(&key, "1").await.unwrap();
+    let sample = tokio::time::timeout(Duration::from_secs(5), fabric_sub.recv_async())
+        .await
+        .expect("fabric recv timed out")
+        .unwrap();
+    assert_eq!(
+        sample.key_expr().as_str(),
+        "tenantA/zensight/snmp/router01/system/sysUpTime"
+    );
+
+    // The GUI's subscription only matches its own namespace, and sees keys
+    // without it.
+    fabric
+        .put("zensight/snmp/other-tenant/system/sysUpTime", "2")
+        .await
+        .unwrap();
+    fabric
+        .put("tenantA/zensight/snmp/router02/system/sysUpTime", "3")
+        .await
+        .unwrap();
+    let mut received = Vec::new();
+    while let Ok(Ok(sample)) =
+        tokio::time::timeout(Duration::from_millis(500), gui_sub.recv_async()).await
+    {
+        received.push(sample.key_expr().to_string());
+    }
+    assert!(received.iter().all(|k| !k.contains("other-tenant")));
+    let from_fabric = received
+        .iter()
+        .find(|k| k.contains("router02"))
+        .expect("namespaced put reaches the GUI");
+    assert_eq!(parse_key_expr(from_fabric).unwrap().source, "router02");
+}
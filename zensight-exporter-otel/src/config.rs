@@ -89,6 +89,34 @@ pub struct OtelConfig {
     /// Service version for OTEL resource.
     #[serde(default)]
     pub service_version: Option<String>,
+
+    /// Gauge streams to export sliding-window quantiles for (#467). The first
+    /// rule whose pattern matches a metric wins.
+    #[serde(default)]
+    pub quantiles: Vec<QuantileRule>,
+}
+
+/// Quantile pre-computation for a set of gauge metrics (#467).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuantileRule {
+    /// Glob pattern on the metric path (e.g. `**/latency`).
+    pub metric: String,
+
+    /// Samples kept per series (default: 120).
+    #[serde(default = "default_quantile_window")]
+    pub window: usize,
+
+    /// Quantiles to export, each in `0.0..=1.0` (default: p50/p95/p99).
+    #[serde(default = "default_quantiles")]
+    pub quantiles: Vec<f64>,
+}
+
+fn default_quantile_window() -> usize {
+    120
+}
+
+fn default_quantiles() -> Vec<f64> {
+    vec![0.5, 0.95, 0.99]
 }
 
 fn default_endpoint() -> String {
@@ -129,6 +157,7 @@ impl Default for OtelConfig {
             resource: HashMap::new(),
             service_name: default_service_name(),
             service_version: None,
+            quantiles: Vec::new(),
         }
     }
 }
@@ -256,6 +285,28 @@ impl ExporterConfig {
             ));
         }
 
+        for rule in &self.opentelemetry.quantiles {
+            if glob::Pattern::new(&rule.metric).is_err() {
+                return Err(ConfigError::Validation(format!(
+                    "Invalid quantile metric pattern: {}",
+                    rule.metric
+                )));
+            }
+            if rule.window == 0 {
+                return Err(ConfigError::Validation(format!(
+                    "Quantile window for {} must be > 0",
+                    rule.metric
+                )));
+            }
+            if rule.quantiles.is_empty() || rule.quantiles.iter().any(|q| !(0.0..=1.0).contains(q))
+            {
+                return Err(ConfigError::Validation(format!(
+                    "Quantiles for {} must be non-empty and within 0..=1",
+                    rule.metric
+                )));
+            }
+        }
+
         Ok(())
     }
 }
@@ -393,4 +444,28 @@ mod tests {
         let config = ExporterConfig::parse(json).unwrap();
         assert_eq!(config.opentelemetry.protocol, OtlpProtocol::Http);
     }
+
+    #[test]
+    fn test_quantile_rules() {
+        let json = r#"{
+            opentelemetry: {
+                quantiles: [
+                    { metric: "**/latency" },
+                    { metric: "rtt/*", window: 30, quantiles: [0.9] }
+                ]
+            }
+        }"#;
+
+        let config = ExporterConfig::parse(json).unwrap();
+        let rules = &config.opentelemetry.quantiles;
+        assert_eq!(rules[0].window, 120);
+        assert_eq!(rules[0].quantiles, vec![0.5, 0.95, 0.99]);
+        assert_eq!(rules[1].window, 30);
+
+        let json = r#"{
+            opentelemetry: { quantiles: [{ metric: "rtt/*", quantiles: [95] }] }
+        }"#;
+        let result = ExporterConfig::parse(json);
+        assert!(result.unwrap_err().to_string().contains("within 0..=1"));
+    }
 }
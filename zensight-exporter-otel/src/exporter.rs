@@ -5,7 +5,7 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use opentelemetry::logs::{LogRecord as _, Logger, LoggerProvider as _, Severity};
-use opentelemetry::metrics::{Meter, MeterProvider as _};
+use opentelemetry::metrics::{Histogram, Meter, MeterProvider as _};
 use opentelemetry_otlp::{LogExporter, MetricExporter, WithExportConfig};
 use opentelemetry_sdk::Resource;
 use opentelemetry_sdk::logs::{SdkLogger, SdkLoggerProvider};
@@ -13,14 +13,16 @@ use opentelemetry_sdk::metrics::{PeriodicReader, SdkMeterProvider};
 use parking_lot::RwLock;
 use tracing::{error, info, trace, warn};
 use zensight_common::alert::{Alert, AlertSeverity, AlertState};
-use zensight_common::telemetry::TelemetryPoint;
+use zensight_common::telemetry::{TelemetryPoint, TelemetryValue};
 
 use crate::config::{FilterConfig, OtelConfig, OtlpProtocol};
 use crate::logs::LogRecord;
 use crate::metrics::{
     OtelMetricType, build_metric_attributes, build_metric_name, build_resource_attributes,
-    extract_value, is_log_exportable, is_metric_exportable,
+    cumulative_counts, extract_value, histogram_observations, is_log_exportable,
+    is_metric_exportable,
 };
+use crate::quantiles::QuantileTracker;
 
 /// Filter for telemetry points.
 pub struct TelemetryFilter {
@@ -116,6 +118,14 @@ struct GaugeEntry {
     last_updated: Instant,
 }
 
+/// Last cumulative counts seen for a histogram series (#404), so each new
+/// snapshot only replays what changed.
+#[derive(Debug, Clone)]
+struct HistogramEntry {
+    counts: Vec<u64>,
+    last_updated: Instant,
+}
+
 /// OpenTelemetry exporter that receives telemetry and exports via OTLP.
 pub struct OtelExporter {
     /// Meter provider for metrics.
@@ -140,6 +150,13 @@ pub struct OtelExporter {
     stats: RwLock<ExporterStats>,
     /// Registered gauges for updating, with staleness tracking.
     gauges: RwLock<HashMap<String, GaugeEntry>>,
+    /// Histogram series baselines, keyed like `gauges`.
+    histograms: RwLock<HashMap<String, HistogramEntry>>,
+    /// Histogram instruments by metric name, built once with the bucket
+    /// bounds of the first snapshot seen; capped like the series.
+    histogram_instruments: RwLock<HashMap<String, Histogram<f64>>>,
+    /// Sliding windows behind the quantile gauges (#467), keyed like `gauges`.
+    quantiles: RwLock<QuantileTracker>,
     /// Maximum number of gauge series to store.
     max_gauge_series: usize,
 }
@@ -208,6 +225,9 @@ impl OtelExporter {
             filter: TelemetryFilter::new(filter_config),
             stats: RwLock::new(ExporterStats::default()),
             gauges: RwLock::new(HashMap::new()),
+            histograms: RwLock::new(HashMap::new()),
+            histogram_instruments: RwLock::new(HashMap::new()),
+            quantiles: RwLock::new(QuantileTracker::new(&otel_config.quantiles, 100_000)),
             max_gauge_series: 100_000,
         })
     }
@@ -360,7 +380,7 @@ impl OtelExporter {
                     return;
                 }
                 gauges.insert(
-                    key,
+                    key.clone(),
                     GaugeEntry {
                         value,
                         last_updated: Instant::now(),
@@ -371,6 +391,15 @@ impl OtelExporter {
                 let gauge = meter.f64_gauge(metric_name.clone()).build();
                 gauge.record(value, &attributes);
 
+                // Pre-computed quantiles for configured gauge streams (#467).
+                let quantiles = self.quantiles.write().observe(&point.metric, &key, value);
+                for (suffix, quantile) in quantiles {
+                    meter
+                        .f64_gauge(format!("{metric_name}.{suffix}"))
+                        .build()
+                        .record(quantile, &attributes);
+                }
+
                 trace!(
                     metric = %metric_name,
                     value = value,
@@ -380,6 +409,77 @@ impl OtelExporter {
                 let mut stats = self.stats.write();
                 stats.metrics_exported += 1;
             }
+            OtelMetricType::Histogram => {
+                let TelemetryValue::Histogram { buckets, count, .. } = &point.value else {
+                    return;
+                };
+                let bounds: Vec<f64> = buckets.iter().map(|&(le, _)| le).collect();
+                let current = cumulative_counts(buckets, *count);
+                let key = build_gauge_key(&metric_name, &attributes);
+
+                let cached = self.histogram_instruments.read().get(&metric_name).cloned();
+                let histogram = match cached {
+                    Some(histogram) => histogram,
+                    None => {
+                        let mut instruments = self.histogram_instruments.write();
+                        if !instruments.contains_key(&metric_name)
+                            && instruments.len() >= self.max_gauge_series
+                        {
+                            warn!(
+                                max = self.max_gauge_series,
+                                "Max histogram instrument limit reached, dropping new histogram"
+                            );
+                            let mut stats = self.stats.write();
+                            stats.metrics_failed += 1;
+                            return;
+                        }
+                        instruments
+                            .entry(metric_name.clone())
+                            .or_insert_with(|| {
+                                meter
+                                    .f64_histogram(metric_name.clone())
+                                    .with_boundaries(bounds.clone())
+                                    .build()
+                            })
+                            .clone()
+                    }
+                };
+
+                let mut histograms = self.histograms.write();
+                if !histograms.contains_key(&key) && histograms.len() >= self.max_gauge_series {
+                    warn!(
+                        max = self.max_gauge_series,
+                        "Max histogram series limit reached, dropping new histogram"
+                    );
+                    let mut stats = self.stats.write();
+                    stats.metrics_failed += 1;
+                    return;
+                }
+                let previous = histograms.insert(
+                    key,
+                    HistogramEntry {
+                        counts: current.clone(),
+                        last_updated: Instant::now(),
+                    },
+                );
+                drop(histograms);
+
+                let observations = histogram_observations(
+                    &bounds,
+                    &current,
+                    previous.as_ref().map(|entry| entry.counts.as_slice()),
+                );
+                for (value, times) in observations {
+                    for _ in 0..times {
+                        histogram.record(value, &attributes);
+                    }
+                }
+
+                trace!(metric = %metric_name, count = *count, "Recorded histogram");
+
+                let mut stats = self.stats.write();
+                stats.metrics_exported += 1;
+            }
             OtelMetricType::NotExportable => {}
         }
     }
@@ -484,7 +584,13 @@ impl OtelExporter {
     }
 
     /// Remove stale gauge entries that haven't been updated within the given duration.
+    /// Stale histogram baselines (#404) and quantile windows (#467) are dropped too.
     pub fn cleanup_stale_gauges(&self, max_age: Duration) -> usize {
+        self.histograms
+            .write()
+            .retain(|_, entry| entry.last_updated.elapsed() < max_age);
+        self.quantiles.write().cleanup_stale(max_age);
+
         let mut gauges = self.gauges.write();
         let before = gauges.len();
         gauges.retain(|_, entry| entry.last_updated.elapsed() < max_age);
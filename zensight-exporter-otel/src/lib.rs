@@ -14,7 +14,8 @@
 //!
 //! # Supported Signals
 //!
-//! - **Metrics**: Counter and Gauge telemetry values are exported as OTEL metrics
+//! - **Metrics**: Counter and Gauge telemetry values are exported as OTEL metrics,
+//!   optionally with sliding-window quantile gauges (see [`quantiles`])
 //! - **Logs**: Syslog text messages are exported as OTEL log records
 //! - **Alerts**: sensor alerts (`@/alerts/*`) are exported as OTEL log events on
 //!   the `zensight.alerts` scope (severity-mapped, with `alert.*` attributes)
@@ -35,6 +36,7 @@ pub mod config;
 pub mod exporter;
 pub mod logs;
 pub mod metrics;
+pub mod quantiles;
 pub mod subscriber;
 
 pub use config::ExporterConfig;
@@ -73,6 +73,8 @@ pub enum OtelMetricType {
     Counter,
     /// Point-in-time gauge value.
     Gauge,
+    /// Explicit-bucket histogram (#404).
+    Histogram,
     /// Not exportable as a metric.
     NotExportable,
 }
@@ -85,7 +87,8 @@ impl OtelMetricType {
             TelemetryValue::Gauge(_) => OtelMetricType::Gauge,
             TelemetryValue::Boolean(_) => OtelMetricType::Gauge,
             TelemetryValue::Text(_) => OtelMetricType::NotExportable,
-            TelemetryValue::Binary(_) => OtelMetricType::NotExportable,
+            TelemetryValue::Binary(_) | TelemetryValue::Bytes(_) => OtelMetricType::NotExportable,
+            TelemetryValue::Histogram { .. } => OtelMetricType::Histogram,
         }
     }
 }
@@ -97,13 +100,65 @@ pub fn extract_value(value: &TelemetryValue) -> Option<f64> {
         TelemetryValue::Gauge(v) => Some(*v),
         TelemetryValue::Boolean(v) => Some(if *v { 1.0 } else { 0.0 }),
         TelemetryValue::Text(_) => None,
-        TelemetryValue::Binary(_) => None,
+        TelemetryValue::Binary(_) | TelemetryValue::Bytes(_) => None,
+        TelemetryValue::Histogram { .. } => None,
     }
 }
 
+/// Cumulative bucket counts of a [`TelemetryValue::Histogram`], with the
+/// implicit `+Inf` bucket (`count`) appended (#404).
+pub fn cumulative_counts(buckets: &[(f64, u64)], count: u64) -> Vec<u64> {
+    buckets
+        .iter()
+        .map(|&(_, n)| n)
+        .chain(std::iter::once(count))
+        .collect()
+}
+
+/// Observations to replay into an OTel histogram instrument with explicit
+/// `bounds` so it reproduces a source histogram (#404).
+///
+/// The OTel API only records individual values, so each new sample in bucket
+/// `i` since the `previous` snapshot (both as [`cumulative_counts`]) is
+/// replayed once at that bucket's upper bound — `+Inf` samples just above the
+/// last bound. Bucket counts come out exact; the exported sum is approximate.
+/// Returns `(value, times)` pairs. A missing or reshaped previous snapshot,
+/// or one with a larger total count (the source restarted), replays the
+/// whole distribution. The sum isn't consulted: negative observations can
+/// legitimately shrink it.
+pub fn histogram_observations(
+    bounds: &[f64],
+    current: &[u64],
+    previous: Option<&[u64]>,
+) -> Vec<(f64, u64)> {
+    let baseline =
+        previous.filter(|prev| prev.len() == current.len() && prev.last() <= current.last());
+    let overflow = bounds.last().map_or(1.0, |&last| last + last.abs() + 1.0);
+
+    let mut observations = Vec::new();
+    let mut below = 0u64;
+    let mut below_prev = 0u64;
+    for (i, &cum) in current.iter().enumerate() {
+        let prev_cum = baseline.map_or(0, |prev| prev[i]);
+        // Per-bucket counts from cumulative ones, then the delta since last time.
+        let new = cum
+            .saturating_sub(below)
+            .saturating_sub(prev_cum.saturating_sub(below_prev));
+        if new > 0 {
+            observations.push((bounds.get(i).copied().unwrap_or(overflow), new));
+        }
+        below = cum;
+        below_prev = prev_cum;
+    }
+    observations
+}
+
 /// Check if a TelemetryValue can be exported as an OTEL metric.
 pub fn is_metric_exportable(value: &TelemetryValue) -> bool {
-    !matches!(value, TelemetryValue::Text(_) | TelemetryValue::Binary(_))
+    !matches!(
+        value,
+        TelemetryValue::Text(_) | TelemetryValue::Binary(_) | TelemetryValue::Bytes(_)
+    )
 }
 
 /// Check if a TelemetryValue can be exported as an OTEL log.
@@ -232,6 +287,7 @@ mod tests {
         assert!(is_metric_exportable(&TelemetryValue::Boolean(true)));
         assert!(!is_metric_exportable(&TelemetryValue::Text("hello".into())));
         assert!(!is_metric_exportable(&TelemetryValue::Binary(vec![1])));
+        assert!(!is_metric_exportable(&TelemetryValue::Bytes(vec![1])));
     }
 
     #[test]
@@ -244,4 +300,42 @@ mod tests {
         assert!(!is_log_exportable(&text, Protocol::Snmp));
         assert!(!is_log_exportable(&gauge, Protocol::Logs));
     }
+
+    #[test]
+    fn test_histogram_replays_bucket_deltas() {
+        let value = TelemetryValue::Histogram {
+            buckets: vec![(0.1, 2), (1.0, 5)],
+            sum: 3.0,
+            count: 6,
+        };
+        assert_eq!(
+            OtelMetricType::from_telemetry_value(&value),
+            OtelMetricType::Histogram
+        );
+        assert!(is_metric_exportable(&value));
+        assert_eq!(extract_value(&value), None);
+
+        let bounds = [0.1, 1.0];
+        let first = cumulative_counts(&[(0.1, 2), (1.0, 5)], 6);
+        assert_eq!(first, vec![2, 5, 6]);
+        // First snapshot: the whole distribution, one value per bucket.
+        assert_eq!(
+            histogram_observations(&bounds, &first, None),
+            vec![(0.1, 2), (1.0, 3), (3.0, 1)]
+        );
+
+        // Next snapshot: only what changed (+1 in 0.1, +2 in +Inf).
+        let second = cumulative_counts(&[(0.1, 3), (1.0, 6)], 9);
+        assert_eq!(
+            histogram_observations(&bounds, &second, Some(&first)),
+            vec![(0.1, 1), (3.0, 2)]
+        );
+
+        // A source restart (total count went down) replays from scratch.
+        let reset = cumulative_counts(&[(0.1, 1), (1.0, 1)], 1);
+        assert_eq!(
+            histogram_observations(&bounds, &reset, Some(&second)),
+            vec![(0.1, 1)]
+        );
+    }
 }
@@ -0,0 +1,210 @@
+//! Sliding-window quantiles for gauge streams (#467).
+//!
+//! Latency-style gauges are often more useful as percentiles than as their
+//! latest value. For metrics matching a configured [`QuantileRule`], the
+//! exporter keeps the last `window` samples of each series and, on every new
+//! sample, exports one extra gauge per quantile named
+//! `<metric_name>.p<percent>` (e.g. `zensight.gnmi.rpc.latency.p95`). The
+//! collector then only has to forward gauges instead of doing the math.
+//!
+//! Quantiles use linear interpolation between the closest ranks, so the p50
+//! of an even-sized window is the mean of its two middle samples.
+
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use crate::config::QuantileRule;
+
+/// The last `capacity` samples of one series.
+#[derive(Debug, Clone)]
+pub struct QuantileWindow {
+    samples: VecDeque<f64>,
+    capacity: usize,
+    last_updated: Instant,
+}
+
+impl QuantileWindow {
+    /// An empty window keeping up to `capacity` samples.
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+            last_updated: Instant::now(),
+        }
+    }
+
+    /// Append `value`, evicting the oldest sample when full. NaN is ignored.
+    pub fn push(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(value);
+        self.last_updated = Instant::now();
+    }
+
+    /// Number of samples in the window.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Whether the window holds no samples.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// The `q`-quantile (`0.0..=1.0`) of the window, `None` when empty.
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        let mut sorted: Vec<f64> = self.samples.iter().copied().collect();
+        sorted.sort_by(f64::total_cmp);
+        quantile_of_sorted(&sorted, q)
+    }
+
+    /// Every quantile in `qs`, sorting the window once.
+    fn quantiles(&self, qs: &[f64]) -> Vec<(f64, f64)> {
+        let mut sorted: Vec<f64> = self.samples.iter().copied().collect();
+        sorted.sort_by(f64::total_cmp);
+        qs.iter()
+            .filter_map(|&q| Some((q, quantile_of_sorted(&sorted, q)?)))
+            .collect()
+    }
+}
+
+fn quantile_of_sorted(sorted: &[f64], q: f64) -> Option<f64> {
+    let last = sorted.len().checked_sub(1)?;
+    let rank = q.clamp(0.0, 1.0) * last as f64;
+    let lo = rank.floor() as usize;
+    let hi = rank.ceil() as usize;
+    Some(sorted[lo] + (sorted[hi] - sorted[lo]) * (rank - lo as f64))
+}
+
+/// Gauge-name suffix for a quantile: `0.95` → `p95`, `0.999` → `p99.9`.
+pub fn quantile_suffix(q: f64) -> String {
+    let percent = (q * 1000.0).round() / 10.0;
+    if percent.fract() == 0.0 {
+        format!("p{}", percent as u64)
+    } else {
+        format!("p{percent}")
+    }
+}
+
+/// Per-series windows for the metrics covered by a quantile rule.
+#[derive(Debug, Default)]
+pub struct QuantileTracker {
+    rules: Vec<(glob::Pattern, QuantileRule)>,
+    windows: HashMap<String, QuantileWindow>,
+    max_series: usize,
+}
+
+impl QuantileTracker {
+    /// Compile `rules` (invalid patterns are skipped; config validation
+    /// rejects them up front), tracking at most `max_series` windows.
+    pub fn new(rules: &[QuantileRule], max_series: usize) -> Self {
+        Self {
+            rules: rules
+                .iter()
+                .filter_map(|r| Some((glob::Pattern::new(&r.metric).ok()?, r.clone())))
+                .collect(),
+            windows: HashMap::new(),
+            max_series,
+        }
+    }
+
+    /// Whether any rule is configured.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Add `value` to the window of `series` if `metric` (the ZenSight metric
+    /// path) matches a rule, returning `(suffix, value)` for every configured
+    /// quantile. Empty when no rule matches or the series cap is reached.
+    pub fn observe(&mut self, metric: &str, series: &str, value: f64) -> Vec<(String, f64)> {
+        let Some((_, rule)) = self.rules.iter().find(|(p, _)| p.matches(metric)) else {
+            return Vec::new();
+        };
+        if !self.windows.contains_key(series) && self.windows.len() >= self.max_series {
+            return Vec::new();
+        }
+        let window = self
+            .windows
+            .entry(series.to_string())
+            .or_insert_with(|| QuantileWindow::new(rule.window));
+        window.push(value);
+        window
+            .quantiles(&rule.quantiles)
+            .into_iter()
+            .map(|(q, v)| (quantile_suffix(q), v))
+            .collect()
+    }
+
+    /// Drop windows not updated within `max_age`.
+    pub fn cleanup_stale(&mut self, max_age: Duration) -> usize {
+        let before = self.windows.len();
+        self.windows
+            .retain(|_, w| w.last_updated.elapsed() < max_age);
+        before - self.windows.len()
+    }
+
+    /// Number of tracked series.
+    pub fn series_count(&self) -> usize {
+        self.windows.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(metric: &str, window: usize) -> QuantileRule {
+        QuantileRule {
+            metric: metric.to_string(),
+            window,
+            quantiles: vec![0.5, 0.95, 0.99],
+        }
+    }
+
+    #[test]
+    fn known_window_produces_expected_p95() {
+        let mut tracker = QuantileTracker::new(&[rule("**/latency", 100)], 10);
+        let mut last = Vec::new();
+        // 1..=100 in shuffled order: p95 = 1 + 0.95 * 99.
+        for i in 0..100u32 {
+            last = tracker.observe("rpc/latency", "s1", ((i * 37) % 100 + 1) as f64);
+        }
+        let p95 = last.iter().find(|(s, _)| s == "p95").unwrap().1;
+        assert!((p95 - 95.05).abs() < 1e-9, "p95 = {p95}");
+        let p50 = last.iter().find(|(s, _)| s == "p50").unwrap().1;
+        assert!((p50 - 50.5).abs() < 1e-9, "p50 = {p50}");
+
+        // The window slides: after 100 more samples of 1000, all quantiles are 1000.
+        for _ in 0..100 {
+            last = tracker.observe("rpc/latency", "s1", 1000.0);
+        }
+        assert!(last.iter().all(|(_, v)| *v == 1000.0));
+
+        // Unmatched metrics aren't tracked.
+        assert!(tracker.observe("cpu/usage", "s2", 1.0).is_empty());
+        assert_eq!(tracker.series_count(), 1);
+    }
+
+    #[test]
+    fn window_edges_and_suffixes() {
+        let mut window = QuantileWindow::new(3);
+        assert_eq!(window.quantile(0.5), None);
+        for v in [5.0, f64::NAN, 1.0, 3.0, 2.0] {
+            window.push(v);
+        }
+        // Holds 1, 3, 2 — the 5 was evicted and NaN ignored.
+        assert_eq!(window.len(), 3);
+        assert_eq!(window.quantile(0.0), Some(1.0));
+        assert_eq!(window.quantile(0.5), Some(2.0));
+        assert_eq!(window.quantile(1.0), Some(3.0));
+
+        assert_eq!(quantile_suffix(0.5), "p50");
+        assert_eq!(quantile_suffix(0.95), "p95");
+        assert_eq!(quantile_suffix(0.999), "p99.9");
+    }
+}
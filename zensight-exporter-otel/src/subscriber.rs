@@ -58,43 +58,9 @@ impl TelemetrySubscriber {
     pub async fn run(self, mut shutdown: watch::Receiver<bool>) -> anyhow::Result<()> {
         info!("Connecting to Zenoh...");
 
-        // Build Zenoh config
-        let mut config = zenoh::Config::default();
-
-        // Set mode
-        match self.zenoh_config.mode.as_str() {
-            "client" => {
-                config
-                    .insert_json5("mode", "\"client\"")
-                    .map_err(|e| anyhow::anyhow!("Failed to set mode: {}", e))?;
-            }
-            "router" => {
-                config
-                    .insert_json5("mode", "\"router\"")
-                    .map_err(|e| anyhow::anyhow!("Failed to set mode: {}", e))?;
-            }
-            _ => {
-                config
-                    .insert_json5("mode", "\"peer\"")
-                    .map_err(|e| anyhow::anyhow!("Failed to set mode: {}", e))?;
-            }
-        }
-
-        // Set connect endpoints
-        if !self.zenoh_config.connect.is_empty() {
-            let endpoints_json = serde_json::to_string(&self.zenoh_config.connect)?;
-            config
-                .insert_json5("connect/endpoints", &endpoints_json)
-                .map_err(|e| anyhow::anyhow!("Failed to set connect endpoints: {}", e))?;
-        }
-
-        // Set listen endpoints
-        if !self.zenoh_config.listen.is_empty() {
-            let endpoints_json = serde_json::to_string(&self.zenoh_config.listen)?;
-            config
-                .insert_json5("listen/endpoints", &endpoints_json)
-                .map_err(|e| anyhow::anyhow!("Failed to set listen endpoints: {}", e))?;
-        }
+        // Same session settings as the sensors and the GUI: mode, endpoints,
+        // scouting and the tenant namespace (#446).
+        let config = zensight_common::session_config(&self.zenoh_config)?;
 
         // Open session
         let session = zenoh::open(config)
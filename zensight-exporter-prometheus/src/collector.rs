@@ -3,13 +3,14 @@
 use std::collections::HashMap;
 use std::io::Write;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 
 use parking_lot::RwLock;
 use tracing::{debug, trace, warn};
 use zensight_common::telemetry::{Protocol, TelemetryPoint, TelemetryValue};
 
-use crate::config::{AggregationConfig, FilterConfig, PrometheusConfig};
+use crate::config::{AggregationConfig, AggregationFunction, FilterConfig, PrometheusConfig};
 use crate::mapping::{
     PrometheusType, build_metric_name, extract_numeric_value, is_exportable, sanitize_label_name,
 };
@@ -89,6 +90,17 @@ impl SeriesKey {
     }
 }
 
+/// A histogram sample as stored for rendering (#404).
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistogramValue {
+    /// Cumulative `(upper_bound, count)` buckets; `+Inf` is implied by `count`.
+    pub buckets: Vec<(f64, u64)>,
+    /// Sum of all observations.
+    pub sum: f64,
+    /// Total number of observations.
+    pub count: u64,
+}
+
 /// A stored metric value with metadata.
 #[derive(Debug, Clone)]
 pub struct StoredMetric {
@@ -100,10 +112,56 @@ pub struct StoredMetric {
     pub value: Option<f64>,
     /// Text value (for info metrics).
     pub text_value: Option<String>,
+    /// Cumulative buckets, sum and count (for histogram metrics, #404).
+    pub histogram: Option<HistogramValue>,
     /// When this metric was last updated.
     pub last_updated: Instant,
     /// Original timestamp from the telemetry point.
     pub timestamp_ms: i64,
+    /// Samples folded since the last scrape, for aggregated metrics (#466).
+    window: Option<Window>,
+}
+
+/// Running aggregate of the samples a series received within one scrape
+/// window (#466).
+#[derive(Debug, Clone, Copy)]
+struct Window {
+    /// Scrape epoch the window belongs to.
+    epoch: u64,
+    count: u64,
+    sum: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Window {
+    fn start(epoch: u64, value: f64) -> Self {
+        Self {
+            epoch,
+            count: 1,
+            sum: value,
+            min: value,
+            max: value,
+        }
+    }
+
+    fn push(&mut self, value: f64) {
+        self.count += 1;
+        self.sum += value;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    /// The value `function` exposes; `last` is the newest sample.
+    fn value(&self, function: AggregationFunction, last: f64) -> f64 {
+        match function {
+            AggregationFunction::Last => last,
+            AggregationFunction::Avg => self.sum / self.count as f64,
+            AggregationFunction::Max => self.max,
+            AggregationFunction::Min => self.min,
+            AggregationFunction::Sum => self.sum,
+        }
+    }
 }
 
 impl StoredMetric {
@@ -131,14 +189,28 @@ impl StoredMetric {
             TelemetryValue::Text(s) => Some(s.clone()),
             _ => None,
         };
+        let histogram = match &point.value {
+            TelemetryValue::Histogram {
+                buckets,
+                sum,
+                count,
+            } => Some(HistogramValue {
+                buckets: buckets.clone(),
+                sum: *sum,
+                count: *count,
+            }),
+            _ => None,
+        };
 
         Some(Self {
             key,
             metric_type,
             value,
             text_value,
+            histogram,
             last_updated: Instant::now(),
             timestamp_ms: point.timestamp,
+            window: None,
         })
     }
 
@@ -244,6 +316,11 @@ pub struct MetricCollector {
     aggregation_config: AggregationConfig,
     /// Metric filter.
     filter: MetricFilter,
+    /// Compiled per-metric aggregation rules (#466), in config order.
+    aggregations: Vec<(glob::Pattern, AggregationFunction)>,
+    /// Incremented on every scrape; samples recorded under the same epoch
+    /// share an aggregation window (#466).
+    scrape_epoch: AtomicU64,
     /// Currently-firing sensor alerts (rendered as a `<prefix>_alert` gauge).
     alerts: crate::alerts::AlertStore,
     /// Statistics.
@@ -276,11 +353,18 @@ impl MetricCollector {
         aggregation_config: AggregationConfig,
         filter_config: FilterConfig,
     ) -> Self {
+        let aggregations = aggregation_config
+            .functions
+            .iter()
+            .filter_map(|r| Some((glob::Pattern::new(&r.metric).ok()?, r.function)))
+            .collect();
         Self {
             metrics: RwLock::new(HashMap::new()),
             prometheus_config,
             aggregation_config,
             filter: MetricFilter::new(&filter_config),
+            aggregations,
+            scrape_epoch: AtomicU64::new(0),
             alerts: crate::alerts::AlertStore::new(),
             stats: RwLock::new(CollectorStats::default()),
         }
@@ -333,7 +417,7 @@ impl MetricCollector {
         }
 
         // Try to convert to stored metric
-        let stored = match StoredMetric::from_telemetry(
+        let mut stored = match StoredMetric::from_telemetry(
             point,
             &self.prometheus_config.prefix,
             &self.prometheus_config.default_labels,
@@ -368,6 +452,23 @@ impl MetricCollector {
             return;
         }
 
+        // Fold the sample into this scrape's window for aggregated metrics.
+        if let Some(value) = stored.value
+            && stored.histogram.is_none()
+            && let Some(function) = self.aggregation_for(&point.metric)
+        {
+            let epoch = self.scrape_epoch.load(Ordering::Relaxed);
+            let window = match metrics.get(&key).and_then(|m| m.window) {
+                Some(mut window) if window.epoch == epoch => {
+                    window.push(value);
+                    window
+                }
+                _ => Window::start(epoch, value),
+            };
+            stored.value = Some(window.value(function, value));
+            stored.window = Some(window);
+        }
+
         metrics.insert(key, stored);
         drop(metrics);
 
@@ -375,6 +476,15 @@ impl MetricCollector {
         stats.points_accepted += 1;
     }
 
+    /// The aggregation function configured for `metric`, if it isn't `last`.
+    fn aggregation_for(&self, metric: &str) -> Option<AggregationFunction> {
+        self.aggregations
+            .iter()
+            .find(|(pattern, _)| pattern.matches(metric))
+            .map(|(_, function)| *function)
+            .filter(|f| *f != AggregationFunction::Last)
+    }
+
     /// Remove stale metrics.
     pub fn cleanup_stale(&self) -> usize {
         let timeout = Duration::from_secs(self.aggregation_config.stale_timeout_secs);
@@ -418,6 +528,8 @@ impl MetricCollector {
     /// Render metrics in Prometheus exposition format.
     pub fn render(&self) -> String {
         let metrics = self.metrics.read();
+        // Samples recorded after this snapshot start a fresh window (#466).
+        self.scrape_epoch.fetch_add(1, Ordering::Relaxed);
         let mut output = Vec::with_capacity(metrics.len() * 100);
         let mut render_errors = 0u64;
 
@@ -467,6 +579,13 @@ impl MetricCollector {
                             write_or_count!(output, "{}{} 1", metric.key.name, label_str);
                         }
                     }
+                    PrometheusType::Histogram => {
+                        if let Some(hist) = &metric.histogram {
+                            for line in render_histogram(&metric.key, hist) {
+                                write_or_count!(output, "{}", line);
+                            }
+                        }
+                    }
                     _ => {
                         if let Some(value) = metric.value {
                             write_or_count!(
@@ -585,6 +704,36 @@ fn format_value(value: f64) -> String {
     }
 }
 
+/// Exposition lines for one histogram series: a cumulative `_bucket` per bound
+/// with an `le` label, the `+Inf` bucket, then `_sum` and `_count`.
+fn render_histogram(key: &SeriesKey, hist: &HistogramValue) -> Vec<String> {
+    let bucket = |le: String, count: u64| {
+        let mut labels = key.labels.clone();
+        labels.push(("le".to_string(), le));
+        format!("{}_bucket{} {}", key.name, format_labels(&labels), count)
+    };
+
+    let mut lines: Vec<String> = hist
+        .buckets
+        .iter()
+        .map(|&(le, count)| bucket(format_value(le), count))
+        .collect();
+    lines.push(bucket("+Inf".to_string(), hist.count));
+    lines.push(format!(
+        "{}_sum{} {}",
+        key.name,
+        key.format_labels(),
+        format_value(hist.sum)
+    ));
+    lines.push(format!(
+        "{}_count{} {}",
+        key.name,
+        key.format_labels(),
+        hist.count
+    ));
+    lines
+}
+
 /// Format labels for Prometheus exposition format.
 fn format_labels(labels: &[(String, String)]) -> String {
     if labels.is_empty() {
@@ -835,6 +984,46 @@ mod tests {
         assert!(output.contains("12345"));
     }
 
+    #[test]
+    fn test_collector_renders_native_histogram() {
+        let collector = MetricCollector::new(
+            PrometheusConfig::default(),
+            AggregationConfig::default(),
+            FilterConfig::default(),
+        );
+
+        let point = make_point(
+            "gw01",
+            Protocol::Gnmi,
+            "rpc/latency",
+            TelemetryValue::Histogram {
+                buckets: vec![(0.05, 3), (0.25, 8), (1.0, 11)],
+                sum: 2.5,
+                count: 12,
+            },
+        );
+        collector.record(&point);
+
+        let output = collector.render();
+        let labels = r#"protocol="gnmi",source="gw01""#;
+        assert!(output.contains("# TYPE zensight_gnmi_rpc_latency histogram\n"));
+        for line in [
+            format!(r#"zensight_gnmi_rpc_latency_bucket{{{labels},le="0.05"}} 3"#),
+            format!(r#"zensight_gnmi_rpc_latency_bucket{{{labels},le="0.25"}} 8"#),
+            format!(r#"zensight_gnmi_rpc_latency_bucket{{{labels},le="1"}} 11"#),
+            format!(r#"zensight_gnmi_rpc_latency_bucket{{{labels},le="+Inf"}} 12"#),
+            format!("zensight_gnmi_rpc_latency_sum{{{labels}}} 2.5"),
+            format!("zensight_gnmi_rpc_latency_count{{{labels}}} 12"),
+        ] {
+            assert!(
+                output.contains(&format!("{line}\n")),
+                "missing {line}\n{output}"
+            );
+        }
+        // No bare sample under the family name itself.
+        assert!(!output.contains("zensight_gnmi_rpc_latency{"));
+    }
+
     #[test]
     fn test_collector_max_series_limit() {
         let collector = MetricCollector::new(
@@ -860,6 +1049,58 @@ mod tests {
         assert_eq!(collector.stats().points_dropped_max_series, 3);
     }
 
+    fn aggregating_collector(function: AggregationFunction) -> MetricCollector {
+        MetricCollector::new(
+            PrometheusConfig::default(),
+            AggregationConfig {
+                functions: vec![crate::config::AggregationRule {
+                    metric: "holding/*".to_string(),
+                    function,
+                }],
+                ..Default::default()
+            },
+            FilterConfig::default(),
+        )
+    }
+
+    fn record_gauges(collector: &MetricCollector, metric: &str, values: &[f64]) {
+        for value in values {
+            collector.record(&make_point(
+                "plc01",
+                Protocol::Modbus,
+                metric,
+                TelemetryValue::Gauge(*value),
+            ));
+        }
+    }
+
+    #[test]
+    fn test_collector_aggregates_within_scrape_window() {
+        let sample = r#"zensight_modbus_holding_temperature{protocol="modbus",source="plc01"}"#;
+
+        let max = aggregating_collector(AggregationFunction::Max);
+        record_gauges(&max, "holding/temperature", &[3.0, 7.0, 5.0]);
+        assert!(max.render().contains(&format!("{sample} 7\n")));
+
+        let avg = aggregating_collector(AggregationFunction::Avg);
+        record_gauges(&avg, "holding/temperature", &[3.0, 7.0, 5.0]);
+        let output = avg.render();
+        assert!(output.contains(&format!("{sample} 5\n")), "{output}");
+
+        // A scrape closes the window: the next samples aggregate afresh.
+        record_gauges(&avg, "holding/temperature", &[1.0, 3.0]);
+        assert!(avg.render().contains(&format!("{sample} 2\n")));
+        // With no new samples, the last aggregate stays exposed.
+        assert!(avg.render().contains(&format!("{sample} 2\n")));
+
+        // Metrics no rule matches expose the last sample.
+        record_gauges(&max, "input/pressure", &[9.0, 4.0]);
+        assert!(
+            max.render()
+                .contains(r#"zensight_modbus_input_pressure{protocol="modbus",source="plc01"} 4"#)
+        );
+    }
+
     #[test]
     fn test_escape_label_value() {
         assert_eq!(escape_label_value("simple"), "simple");
@@ -112,6 +112,38 @@ pub struct AggregationConfig {
     /// How often to run cleanup of stale metrics (seconds).
     #[serde(default = "default_cleanup_interval")]
     pub cleanup_interval_secs: u64,
+
+    /// Per-metric aggregation of samples arriving between scrapes (#466).
+    /// The first rule whose pattern matches a metric wins; unmatched metrics
+    /// expose the last sample.
+    #[serde(default)]
+    pub functions: Vec<AggregationRule>,
+}
+
+/// How one set of metrics is aggregated within a scrape window (#466).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggregationRule {
+    /// Glob pattern on the metric name (e.g. `cpu/*`).
+    pub metric: String,
+    /// Function applied to the samples seen since the previous scrape.
+    pub function: AggregationFunction,
+}
+
+/// Aggregation function applied between scrapes (#466).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AggregationFunction {
+    /// The most recent sample.
+    #[default]
+    Last,
+    /// Mean of the samples.
+    Avg,
+    /// Largest sample.
+    Max,
+    /// Smallest sample.
+    Min,
+    /// Sum of the samples.
+    Sum,
 }
 
 fn default_stale_timeout() -> u64 {
@@ -132,6 +164,7 @@ impl Default for AggregationConfig {
             stale_timeout_secs: default_stale_timeout(),
             max_series: default_max_series(),
             cleanup_interval_secs: default_cleanup_interval(),
+            functions: Vec::new(),
         }
     }
 }
@@ -234,6 +267,18 @@ impl ExporterConfig {
             ));
         }
 
+        if let Some(rule) = self
+            .aggregation
+            .functions
+            .iter()
+            .find(|r| glob::Pattern::new(&r.metric).is_err())
+        {
+            return Err(ConfigError::Validation(format!(
+                "Invalid aggregation metric pattern: {}",
+                rule.metric
+            )));
+        }
+
         // Validate listen address format
         if self
             .prometheus
@@ -293,7 +338,11 @@ mod tests {
             aggregation: {
                 stale_timeout_secs: 600,
                 max_series: 50000,
-                cleanup_interval_secs: 30
+                cleanup_interval_secs: 30,
+                functions: [
+                    { metric: "cpu/*", function: "avg" },
+                    { metric: "**/errors", function: "sum" }
+                ]
             },
             filters: {
                 include_protocols: ["snmp", "sysinfo"],
@@ -318,6 +367,11 @@ mod tests {
         );
         assert_eq!(config.aggregation.stale_timeout_secs, 600);
         assert_eq!(config.aggregation.max_series, 50000);
+        assert_eq!(config.aggregation.functions.len(), 2);
+        assert_eq!(
+            config.aggregation.functions[0].function,
+            AggregationFunction::Avg
+        );
         assert_eq!(config.filters.include_protocols, vec!["snmp", "sysinfo"]);
         assert_eq!(config.logging.level, "debug");
         assert_eq!(config.logging.format, LogFormat::Json);
@@ -374,4 +428,19 @@ mod tests {
         let result = ExporterConfig::parse(json);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_validate_invalid_aggregation_pattern() {
+        let json = r#"{
+            aggregation: { functions: [{ metric: "cpu/[", function: "max" }] }
+        }"#;
+
+        let result = ExporterConfig::parse(json);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Invalid aggregation metric pattern")
+        );
+    }
 }
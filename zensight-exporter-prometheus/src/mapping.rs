@@ -131,6 +131,8 @@ pub enum PrometheusType {
     Counter,
     Gauge,
     Info,
+    /// Native histogram (#404): `_bucket`/`_sum`/`_count` series.
+    Histogram,
     Untyped,
 }
 
@@ -142,7 +144,8 @@ impl PrometheusType {
             TelemetryValue::Gauge(_) => PrometheusType::Gauge,
             TelemetryValue::Boolean(_) => PrometheusType::Gauge,
             TelemetryValue::Text(_) => PrometheusType::Info,
-            TelemetryValue::Binary(_) => PrometheusType::Untyped,
+            TelemetryValue::Binary(_) | TelemetryValue::Bytes(_) => PrometheusType::Untyped,
+            TelemetryValue::Histogram { .. } => PrometheusType::Histogram,
         }
     }
 
@@ -152,6 +155,7 @@ impl PrometheusType {
             PrometheusType::Counter => "counter",
             PrometheusType::Gauge => "gauge",
             PrometheusType::Info => "info",
+            PrometheusType::Histogram => "histogram",
             PrometheusType::Untyped => "untyped",
         }
     }
@@ -159,20 +163,22 @@ impl PrometheusType {
 
 /// Extract a numeric value from TelemetryValue for Prometheus.
 ///
-/// Returns None for values that can't be represented as numbers (Text, Binary).
+/// Returns None for values that can't be represented as a single number
+/// (Text, Binary, Histogram).
 pub fn extract_numeric_value(value: &TelemetryValue) -> Option<f64> {
     match value {
         TelemetryValue::Counter(v) => Some(*v as f64),
         TelemetryValue::Gauge(v) => Some(*v),
         TelemetryValue::Boolean(v) => Some(if *v { 1.0 } else { 0.0 }),
         TelemetryValue::Text(_) => None,
-        TelemetryValue::Binary(_) => None,
+        TelemetryValue::Binary(_) | TelemetryValue::Bytes(_) => None,
+        TelemetryValue::Histogram { .. } => None,
     }
 }
 
 /// Check if a TelemetryValue can be exported as a Prometheus metric.
 pub fn is_exportable(value: &TelemetryValue) -> bool {
-    !matches!(value, TelemetryValue::Binary(_))
+    !matches!(value, TelemetryValue::Binary(_) | TelemetryValue::Bytes(_))
 }
 
 #[cfg(test)]
@@ -329,5 +335,6 @@ mod tests {
         assert!(is_exportable(&TelemetryValue::Boolean(true)));
         assert!(is_exportable(&TelemetryValue::Text("hello".into())));
         assert!(!is_exportable(&TelemetryValue::Binary(vec![1, 2, 3])));
+        assert!(!is_exportable(&TelemetryValue::Bytes(vec![1, 2, 3])));
     }
 }
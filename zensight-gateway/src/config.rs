@@ -0,0 +1,185 @@
+//! Configuration for the telemetry gateway.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use thiserror::Error;
+use zensight_common::config::ZenohConfig;
+
+/// Configuration errors.
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("Failed to read config file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Failed to parse config: {0}")]
+    Parse(#[from] json5::Error),
+    #[error("Validation error: {0}")]
+    Validation(String),
+}
+
+/// Complete gateway configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct GatewayConfig {
+    /// Zenoh connection settings.
+    #[serde(default)]
+    pub zenoh: ZenohConfig,
+
+    /// HTTP/WebSocket settings.
+    #[serde(default)]
+    pub gateway: GatewaySettings,
+
+    /// Logging configuration.
+    #[serde(default)]
+    pub logging: LoggingConfig,
+}
+
+/// Gateway endpoint configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GatewaySettings {
+    /// Address to listen on (default: "0.0.0.0:7449", next to Zenoh's 7447 and
+    /// clear of the Prometheus exporter ports such as node_exporter's 9100).
+    #[serde(default = "default_listen")]
+    pub listen: String,
+
+    /// Key expression subscribed on Zenoh (default: "zensight/**"). Clients
+    /// can only narrow it.
+    #[serde(default = "default_key_expr")]
+    pub key_expr: String,
+
+    /// Points buffered per client before the slowest clients start skipping
+    /// (default: 1024).
+    #[serde(default = "default_buffer")]
+    pub buffer: usize,
+
+    /// Maximum key patterns one client may subscribe to (default: 32).
+    #[serde(default = "default_max_patterns")]
+    pub max_patterns_per_client: usize,
+}
+
+fn default_listen() -> String {
+    "0.0.0.0:7449".to_string()
+}
+
+fn default_key_expr() -> String {
+    "zensight/**".to_string()
+}
+
+fn default_buffer() -> usize {
+    1024
+}
+
+fn default_max_patterns() -> usize {
+    32
+}
+
+impl Default for GatewaySettings {
+    fn default() -> Self {
+        Self {
+            listen: default_listen(),
+            key_expr: default_key_expr(),
+            buffer: default_buffer(),
+            max_patterns_per_client: default_max_patterns(),
+        }
+    }
+}
+
+/// Logging configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoggingConfig {
+    /// Log level: "trace", "debug", "info", "warn", "error".
+    #[serde(default = "default_log_level")]
+    pub level: String,
+
+    /// Log output format: "text" or "json".
+    #[serde(default)]
+    pub format: LogFormat,
+}
+
+fn default_log_level() -> String {
+    "info".to_string()
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            level: default_log_level(),
+            format: LogFormat::default(),
+        }
+    }
+}
+
+/// Log output format.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl GatewayConfig {
+    /// Load configuration from a JSON5 file.
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        let content = std::fs::read_to_string(path)?;
+        Self::parse(&content)
+    }
+
+    /// Parse configuration from a JSON5 string.
+    pub fn parse(content: &str) -> Result<Self, ConfigError> {
+        let config: GatewayConfig = json5::from_str(content)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Validate the configuration.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.gateway.listen.parse::<std::net::SocketAddr>().is_err() {
+            return Err(ConfigError::Validation(format!(
+                "Invalid listen address: {}",
+                self.gateway.listen
+            )));
+        }
+
+        if zenoh::key_expr::KeyExpr::new(self.gateway.key_expr.as_str()).is_err() {
+            return Err(ConfigError::Validation(format!(
+                "Invalid key expression: {}",
+                self.gateway.key_expr
+            )));
+        }
+
+        if self.gateway.buffer == 0 {
+            return Err(ConfigError::Validation("buffer must be > 0".to_string()));
+        }
+
+        if self.gateway.max_patterns_per_client == 0 {
+            return Err(ConfigError::Validation(
+                "max_patterns_per_client must be > 0".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults() {
+        let config = GatewayConfig::parse("{}").unwrap();
+        assert_eq!(config.gateway.listen, "0.0.0.0:7449");
+        assert_eq!(config.gateway.key_expr, "zensight/**");
+        assert_eq!(config.gateway.buffer, 1024);
+    }
+
+    #[test]
+    fn test_validation() {
+        assert!(GatewayConfig::parse(r#"{ gateway: { listen: "nope" } }"#).is_err());
+        assert!(GatewayConfig::parse(r#"{ gateway: { key_expr: "a//b" } }"#).is_err());
+        assert!(GatewayConfig::parse(r#"{ gateway: { buffer: 0 } }"#).is_err());
+        GatewayConfig::parse(
+            r#"{ gateway: { listen: "127.0.0.1:8080", key_expr: "zensight/snmp/**" } }"#,
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,109 @@
+//! Per-client key-pattern filtering.
+
+use zenoh::key_expr::OwnedKeyExpr;
+
+/// The key patterns one client subscribed to. A point is forwarded when its
+/// key is included in any of them (Zenoh `*`/`**` semantics).
+#[derive(Debug, Default, Clone)]
+pub struct KeyFilter {
+    patterns: Vec<OwnedKeyExpr>,
+}
+
+/// Why a pattern was refused.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FilterError {
+    #[error("invalid key pattern `{0}`")]
+    Invalid(String),
+    #[error("at most {0} key patterns per client")]
+    TooMany(usize),
+}
+
+impl KeyFilter {
+    /// Add patterns, skipping ones already present.
+    pub fn add<I, S>(&mut self, patterns: I, max: usize) -> Result<(), FilterError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for pattern in patterns {
+            let pattern = pattern.as_ref().trim();
+            let key = OwnedKeyExpr::autocanonize(pattern.to_string())
+                .map_err(|_| FilterError::Invalid(pattern.to_string()))?;
+            if self.patterns.contains(&key) {
+                continue;
+            }
+            if self.patterns.len() >= max {
+                return Err(FilterError::TooMany(max));
+            }
+            self.patterns.push(key);
+        }
+        Ok(())
+    }
+
+    /// Remove patterns (compared after canonicalization).
+    pub fn remove<I, S>(&mut self, patterns: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for pattern in patterns {
+            if let Ok(key) = OwnedKeyExpr::autocanonize(pattern.as_ref().trim().to_string()) {
+                self.patterns.retain(|p| *p != key);
+            }
+        }
+    }
+
+    /// Whether `key` matches any pattern.
+    pub fn matches(&self, key: &str) -> bool {
+        let Ok(key) = zenoh::key_expr::keyexpr::new(key) else {
+            return false;
+        };
+        self.patterns.iter().any(|p| p.includes(key))
+    }
+
+    /// The current patterns, in subscription order.
+    pub fn patterns(&self) -> Vec<String> {
+        self.patterns.iter().map(|p| p.to_string()).collect()
+    }
+
+    /// Whether no pattern is set.
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_with_zenoh_wildcards() {
+        let mut filter = KeyFilter::default();
+        filter
+            .add(
+                ["zensight/snmp/*/if/**", "zensight/sysinfo/web01/cpu/usage"],
+                8,
+            )
+            .unwrap();
+        assert!(filter.matches("zensight/snmp/router01/if/1/ifInOctets"));
+        assert!(filter.matches("zensight/sysinfo/web01/cpu/usage"));
+        assert!(!filter.matches("zensight/snmp/router01/system/sysUpTime"));
+        assert!(!filter.matches("zensight/sysinfo/web02/cpu/usage"));
+
+        filter.remove(["zensight/snmp/*/if/**"]);
+        assert!(!filter.matches("zensight/snmp/router01/if/1/ifInOctets"));
+        assert_eq!(filter.patterns(), vec!["zensight/sysinfo/web01/cpu/usage"]);
+    }
+
+    #[test]
+    fn rejects_invalid_and_excess_patterns() {
+        let mut filter = KeyFilter::default();
+        assert_eq!(
+            filter.add(["zensight//snmp"], 8),
+            Err(FilterError::Invalid("zensight//snmp".into()))
+        );
+        filter.add(["a/**", "b/**", "a/**"], 2).unwrap();
+        assert_eq!(filter.add(["c/**"], 2), Err(FilterError::TooMany(2)));
+        assert!(!filter.is_empty());
+    }
+}
@@ -0,0 +1,316 @@
+//! HTTP server exposing telemetry over WebSocket (`/ws`) and Server-Sent
+//! Events (`/sse`).
+//!
+//! Both endpoints take an optional `keys` query parameter: comma-separated
+//! Zenoh key patterns (`/ws?keys=zensight/snmp/**,zensight/sysinfo/*/cpu/**`).
+//! WebSocket clients can change their patterns later with
+//! [`ClientMessage`]s; SSE is one-way, so an SSE client without `keys` gets a
+//! `400`.
+
+use std::convert::Infallible;
+use std::net::SocketAddr;
+
+use axum::Router;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use futures_util::{SinkExt, Stream, StreamExt};
+use serde::Deserialize;
+use tokio::net::TcpListener;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::watch;
+use tower_http::cors::CorsLayer;
+use tracing::{debug, info};
+
+use crate::filter::KeyFilter;
+use crate::hub::{ClientHandle, SharedHub};
+use crate::message::{ClientMessage, ServerMessage};
+
+/// Application state shared across handlers.
+#[derive(Clone)]
+struct AppState {
+    hub: SharedHub,
+    max_patterns: usize,
+}
+
+/// Query parameters accepted by `/ws` and `/sse`.
+#[derive(Debug, Default, Deserialize)]
+struct KeysQuery {
+    keys: Option<String>,
+}
+
+impl KeysQuery {
+    fn patterns(&self) -> Vec<String> {
+        self.keys
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|k| !k.is_empty())
+            .map(String::from)
+            .collect()
+    }
+}
+
+/// Create the HTTP router.
+fn create_router(hub: SharedHub, max_patterns: usize) -> Router {
+    let state = AppState { hub, max_patterns };
+
+    Router::new()
+        .route("/ws", get(ws_handler))
+        .route("/sse", get(sse_handler))
+        .route("/health", get(health_handler))
+        .layer(CorsLayer::permissive())
+        .with_state(state)
+}
+
+/// Handler for the /health endpoint.
+async fn health_handler(State(state): State<AppState>) -> Response {
+    let stats = state.hub.stats();
+    (
+        StatusCode::OK,
+        format!(
+            "healthy\nclients {}\npoints_received {}\n",
+            stats.clients, stats.points_received
+        ),
+    )
+        .into_response()
+}
+
+/// Handler for the /ws endpoint.
+async fn ws_handler(
+    ws: WebSocketUpgrade,
+    State(state): State<AppState>,
+    Query(query): Query<KeysQuery>,
+) -> Response {
+    let initial = query.patterns();
+    ws.on_upgrade(move |socket| handle_socket(socket, state, initial))
+}
+
+/// Handler for the /sse endpoint.
+async fn sse_handler(State(state): State<AppState>, Query(query): Query<KeysQuery>) -> Response {
+    let mut filter = KeyFilter::default();
+    if let Err(e) = filter.add(query.patterns(), state.max_patterns) {
+        return (StatusCode::BAD_REQUEST, format!("{e}\n")).into_response();
+    }
+    if filter.is_empty() {
+        return (
+            StatusCode::BAD_REQUEST,
+            "the `keys` query parameter is required\n",
+        )
+            .into_response();
+    }
+
+    Sse::new(sse_stream(state.hub.connect(), filter))
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+/// Matching points (and lag notices) as SSE events.
+fn sse_stream(
+    client: ClientHandle,
+    filter: KeyFilter,
+) -> impl Stream<Item = Result<Event, Infallible>> {
+    futures_util::stream::unfold((client, filter), |(mut client, filter)| async move {
+        let message = next_message(&mut client, &filter).await?;
+        Some((
+            Ok(Event::default().data(message.to_json())),
+            (client, filter),
+        ))
+    })
+}
+
+/// Wait for the next message for a client: a point matching its filter, or a
+/// lag notice. `None` once the hub is gone.
+async fn next_message(client: &mut ClientHandle, filter: &KeyFilter) -> Option<ServerMessage> {
+    loop {
+        match client.receiver.recv().await {
+            Ok(keyed) if filter.matches(&keyed.key) => {
+                return Some(ServerMessage::Telemetry {
+                    key: keyed.key.clone(),
+                    point: keyed.point.clone(),
+                });
+            }
+            Ok(_) => {}
+            Err(RecvError::Lagged(skipped)) => return Some(ServerMessage::Lagged { skipped }),
+            Err(RecvError::Closed) => return None,
+        }
+    }
+}
+
+/// Apply a client request to its filter and build the reply.
+fn apply(filter: &mut KeyFilter, request: ClientMessage, max_patterns: usize) -> ServerMessage {
+    match request {
+        ClientMessage::Subscribe { keys } => {
+            if let Err(e) = filter.add(keys, max_patterns) {
+                return ServerMessage::Error {
+                    message: e.to_string(),
+                };
+            }
+        }
+        ClientMessage::Unsubscribe { keys } => filter.remove(keys),
+    }
+    ServerMessage::Subscribed {
+        keys: filter.patterns(),
+    }
+}
+
+/// Serve one WebSocket client until it disconnects.
+async fn handle_socket(socket: WebSocket, state: AppState, initial: Vec<String>) {
+    let (mut sink, mut stream) = socket.split();
+    let mut client = state.hub.connect();
+    let mut filter = KeyFilter::default();
+
+    // Always acknowledge, so a client knows it is registered before it
+    // expects points.
+    let ack = apply(
+        &mut filter,
+        ClientMessage::Subscribe { keys: initial },
+        state.max_patterns,
+    );
+    if sink
+        .send(Message::Text(ack.to_json().into()))
+        .await
+        .is_err()
+    {
+        return;
+    }
+
+    loop {
+        let reply = tokio::select! {
+            incoming = stream.next() => match incoming {
+                Some(Ok(Message::Text(text))) => {
+                    match serde_json::from_str::<ClientMessage>(text.as_str()) {
+                        Ok(request) => apply(&mut filter, request, state.max_patterns),
+                        Err(e) => ServerMessage::Error {
+                            message: format!("invalid request: {e}"),
+                        },
+                    }
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                // Pings are answered by axum; binary frames are ignored.
+                Some(Ok(_)) => continue,
+            },
+            message = next_message(&mut client, &filter) => match message {
+                Some(message) => message,
+                None => break,
+            },
+        };
+        if sink
+            .send(Message::Text(reply.to_json().into()))
+            .await
+            .is_err()
+        {
+            break;
+        }
+    }
+    debug!("WebSocket client disconnected");
+}
+
+/// HTTP server configuration.
+pub struct HttpServer {
+    hub: SharedHub,
+    listen_addr: SocketAddr,
+    max_patterns: usize,
+}
+
+impl HttpServer {
+    /// Create a new HTTP server.
+    pub fn new(hub: SharedHub, listen_addr: SocketAddr, max_patterns: usize) -> Self {
+        Self {
+            hub,
+            listen_addr,
+            max_patterns,
+        }
+    }
+
+    /// Bind the listen address and serve until the shutdown signal is
+    /// received.
+    pub async fn run(self, shutdown: watch::Receiver<bool>) -> anyhow::Result<()> {
+        let listener = TcpListener::bind(self.listen_addr)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to bind to {}: {}", self.listen_addr, e))?;
+        self.serve(listener, shutdown).await
+    }
+
+    /// Serve on an already-bound listener until the shutdown signal is
+    /// received.
+    pub async fn serve(
+        self,
+        listener: TcpListener,
+        mut shutdown: watch::Receiver<bool>,
+    ) -> anyhow::Result<()> {
+        let router = create_router(self.hub, self.max_patterns);
+        info!(addr = ?listener.local_addr().ok(), "Gateway listening (/ws, /sse)");
+
+        axum::serve(listener, router)
+            .with_graceful_shutdown(async move {
+                loop {
+                    if shutdown.changed().await.is_err() {
+                        break;
+                    }
+                    if *shutdown.borrow() {
+                        break;
+                    }
+                }
+                info!("HTTP server shutting down");
+            })
+            .await
+            .map_err(|e| anyhow::anyhow!("HTTP server error: {}", e))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_query_splits_on_commas() {
+        let query = KeysQuery {
+            keys: Some("zensight/snmp/**, zensight/sysinfo/*/cpu/**,".into()),
+        };
+        assert_eq!(
+            query.patterns(),
+            vec!["zensight/snmp/**", "zensight/sysinfo/*/cpu/**"]
+        );
+        assert!(KeysQuery::default().patterns().is_empty());
+    }
+
+    #[test]
+    fn apply_reports_the_resulting_patterns_or_the_error() {
+        let mut filter = KeyFilter::default();
+        let reply = apply(
+            &mut filter,
+            ClientMessage::Subscribe {
+                keys: vec!["zensight/snmp/**".into()],
+            },
+            4,
+        );
+        assert!(
+            matches!(reply, ServerMessage::Subscribed { keys } if keys == ["zensight/snmp/**"])
+        );
+
+        let reply = apply(
+            &mut filter,
+            ClientMessage::Subscribe {
+                keys: vec!["a//b".into()],
+            },
+            4,
+        );
+        assert!(matches!(reply, ServerMessage::Error { .. }));
+
+        let reply = apply(
+            &mut filter,
+            ClientMessage::Unsubscribe {
+                keys: vec!["zensight/snmp/**".into()],
+            },
+            4,
+        );
+        assert!(matches!(reply, ServerMessage::Subscribed { keys } if keys.is_empty()));
+    }
+}
@@ -0,0 +1,112 @@
+//! Fan-out of received telemetry to connected clients.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use tokio::sync::broadcast;
+use zensight_common::telemetry::TelemetryPoint;
+
+/// A telemetry point and the Zenoh key it arrived on.
+#[derive(Debug, Clone)]
+pub struct KeyedPoint {
+    pub key: String,
+    pub point: TelemetryPoint,
+}
+
+/// Shared hub handle.
+pub type SharedHub = Arc<TelemetryHub>;
+
+/// Broadcasts every received point to all connected clients; each client
+/// filters by its own key patterns.
+pub struct TelemetryHub {
+    sender: broadcast::Sender<Arc<KeyedPoint>>,
+    points_received: AtomicU64,
+    clients: AtomicU64,
+}
+
+/// Hub statistics.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HubStats {
+    pub points_received: u64,
+    pub clients: u64,
+}
+
+impl TelemetryHub {
+    /// Create a hub buffering up to `capacity` points per client.
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity.max(1));
+        Self {
+            sender,
+            points_received: AtomicU64::new(0),
+            clients: AtomicU64::new(0),
+        }
+    }
+
+    /// Hand a point to every connected client.
+    pub fn publish(&self, key: impl Into<String>, point: TelemetryPoint) {
+        self.points_received.fetch_add(1, Ordering::Relaxed);
+        // No receivers just means no client is connected.
+        let _ = self.sender.send(Arc::new(KeyedPoint {
+            key: key.into(),
+            point,
+        }));
+    }
+
+    /// Register a client. The returned guard keeps the client count until it
+    /// is dropped.
+    pub fn connect(self: &Arc<Self>) -> ClientHandle {
+        self.clients.fetch_add(1, Ordering::Relaxed);
+        ClientHandle {
+            receiver: self.sender.subscribe(),
+            hub: self.clone(),
+        }
+    }
+
+    /// Current statistics.
+    pub fn stats(&self) -> HubStats {
+        HubStats {
+            points_received: self.points_received.load(Ordering::Relaxed),
+            clients: self.clients.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// A connected client's view of the hub.
+pub struct ClientHandle {
+    pub receiver: broadcast::Receiver<Arc<KeyedPoint>>,
+    hub: SharedHub,
+}
+
+impl Drop for ClientHandle {
+    fn drop(&mut self) {
+        self.hub.clients.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zensight_common::telemetry::{Protocol, TelemetryValue};
+
+    #[tokio::test]
+    async fn clients_receive_points_published_after_connecting() {
+        let hub = Arc::new(TelemetryHub::new(8));
+        hub.publish(
+            "zensight/sysinfo/h/cpu",
+            TelemetryPoint::new("h", Protocol::Sysinfo, "cpu", TelemetryValue::Gauge(1.0)),
+        );
+
+        let mut client = hub.connect();
+        assert_eq!(hub.stats().clients, 1);
+        hub.publish(
+            "zensight/sysinfo/h/mem",
+            TelemetryPoint::new("h", Protocol::Sysinfo, "mem", TelemetryValue::Gauge(2.0)),
+        );
+        let got = client.receiver.recv().await.unwrap();
+        assert_eq!(got.key, "zensight/sysinfo/h/mem");
+
+        drop(client);
+        assert_eq!(hub.stats().clients, 0);
+        assert_eq!(hub.stats().points_received, 2);
+    }
+}
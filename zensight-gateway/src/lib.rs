@@ -0,0 +1,31 @@
+//! WebSocket/SSE gateway for ZenSight telemetry.
+//!
+//! This crate subscribes to ZenSight telemetry over Zenoh and re-exposes it
+//! to browser dashboards as JSON over WebSocket and Server-Sent Events. Each
+//! client chooses the Zenoh key patterns it wants.
+//!
+//! # Architecture
+//!
+//! ```text
+//! ┌─────────────────┐     ┌─────────────────┐     ┌─────────────────┐
+//! │  Zenoh Network  │────>│       Hub       │────>│   HTTP Server   │
+//! │  (zensight/**)  │     │   (broadcast)   │     │  (/ws, /sse)    │
+//! └─────────────────┘     └─────────────────┘     └─────────────────┘
+//! ```
+//!
+//! See [`message`] for the wire format and [`config::GatewayConfig`] for
+//! configuration options.
+
+pub mod config;
+pub mod filter;
+pub mod http;
+pub mod hub;
+pub mod message;
+pub mod subscriber;
+
+pub use config::GatewayConfig;
+pub use filter::KeyFilter;
+pub use http::HttpServer;
+pub use hub::{SharedHub, TelemetryHub};
+pub use message::{ClientMessage, ServerMessage};
+pub use subscriber::TelemetrySubscriber;
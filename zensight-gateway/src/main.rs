@@ -0,0 +1,142 @@
+//! WebSocket/SSE gateway for ZenSight telemetry.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use clap::Parser;
+use tokio::sync::watch;
+use tracing::{Level, error, info};
+use tracing_subscriber::EnvFilter;
+
+use zensight_gateway::{GatewayConfig, HttpServer, TelemetryHub, TelemetrySubscriber};
+
+/// WebSocket/SSE gateway for ZenSight telemetry.
+#[derive(Parser, Debug)]
+#[command(name = "zensight-gateway")]
+#[command(about = "Expose ZenSight telemetry to browsers over WebSocket/SSE")]
+#[command(version)]
+struct Args {
+    /// Path to configuration file (JSON5 format).
+    #[arg(short, long)]
+    config: Option<String>,
+
+    /// HTTP listen address (overrides config).
+    #[arg(long)]
+    listen: Option<String>,
+
+    /// Log level (trace, debug, info, warn, error).
+    #[arg(long, default_value = "info")]
+    log_level: String,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    // Load configuration
+    let mut config = if let Some(config_path) = &args.config {
+        GatewayConfig::load_from_file(config_path)?
+    } else {
+        GatewayConfig::default()
+    };
+
+    // Override listen address from CLI
+    if let Some(listen) = args.listen {
+        config.gateway.listen = listen;
+    }
+
+    // Initialize logging
+    let log_level = args.log_level.parse().unwrap_or(Level::INFO);
+    let filter = EnvFilter::from_default_env()
+        .add_directive(format!("zensight_gateway={}", log_level).parse()?)
+        .add_directive(format!("zenoh={}", Level::WARN).parse()?);
+
+    match config.logging.format {
+        zensight_gateway::config::LogFormat::Json => {
+            tracing_subscriber::fmt()
+                .with_env_filter(filter)
+                .json()
+                .init();
+        }
+        zensight_gateway::config::LogFormat::Text => {
+            tracing_subscriber::fmt().with_env_filter(filter).init();
+        }
+    }
+
+    info!("Starting ZenSight gateway");
+
+    // Create shutdown signal
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+
+    let hub = Arc::new(TelemetryHub::new(config.gateway.buffer));
+
+    // Parse listen address
+    let listen_addr = config
+        .gateway
+        .listen
+        .parse()
+        .map_err(|e| anyhow::anyhow!("Invalid listen address: {}", e))?;
+
+    // Create components
+    let subscriber = TelemetrySubscriber::new(hub.clone(), config.zenoh.clone())
+        .with_key_expr(config.gateway.key_expr.clone());
+    let http_server = HttpServer::new(
+        hub.clone(),
+        listen_addr,
+        config.gateway.max_patterns_per_client,
+    );
+
+    // Start subscriber
+    let subscriber_shutdown = shutdown_rx.clone();
+    let subscriber_task = tokio::spawn(async move {
+        if let Err(e) = subscriber.run(subscriber_shutdown).await {
+            error!("Subscriber error: {}", e);
+        }
+    });
+
+    // Start HTTP server
+    let http_shutdown = shutdown_rx.clone();
+    let http_task = tokio::spawn(async move {
+        if let Err(e) = http_server.run(http_shutdown).await {
+            error!("HTTP server error: {}", e);
+        }
+    });
+
+    // Wait for shutdown signal
+    tokio::select! {
+        _ = tokio::signal::ctrl_c() => {
+            info!("Received Ctrl+C, shutting down...");
+        }
+        _ = async {
+            #[cfg(unix)]
+            {
+                let mut sigterm = tokio::signal::unix::signal(
+                    tokio::signal::unix::SignalKind::terminate()
+                ).unwrap();
+                sigterm.recv().await;
+            }
+            #[cfg(not(unix))]
+            {
+                std::future::pending::<()>().await;
+            }
+        } => {
+            info!("Received SIGTERM, shutting down...");
+        }
+    }
+
+    // Signal shutdown
+    shutdown_tx.send(true)?;
+
+    // Wait for tasks to complete
+    let _ = tokio::time::timeout(Duration::from_secs(5), async {
+        let _ = subscriber_task.await;
+        let _ = http_task.await;
+    })
+    .await;
+
+    let stats = hub.stats();
+    info!(points_received = stats.points_received, "Final statistics");
+
+    info!("Gateway stopped");
+    Ok(())
+}
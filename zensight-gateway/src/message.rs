@@ -0,0 +1,52 @@
+//! JSON messages exchanged with browser clients.
+//!
+//! Server → client, one JSON object per WebSocket text frame or SSE event:
+//!
+//! ```json
+//! {"type":"subscribed","keys":["zensight/snmp/**"]}
+//! {"type":"telemetry","key":"zensight/snmp/r1/if/1/ifInOctets","point":{...}}
+//! {"type":"lagged","skipped":42}
+//! {"type":"error","message":"invalid key pattern `a//b`"}
+//! ```
+//!
+//! Client → server (WebSocket only):
+//!
+//! ```json
+//! {"type":"subscribe","keys":["zensight/sysinfo/*/cpu/**"]}
+//! {"type":"unsubscribe","keys":["zensight/snmp/**"]}
+//! ```
+
+use serde::{Deserialize, Serialize};
+use zensight_common::telemetry::TelemetryPoint;
+
+/// A message sent to a client.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    /// The client's key patterns after a (un)subscribe.
+    Subscribed { keys: Vec<String> },
+    /// A telemetry point matching one of the client's patterns.
+    Telemetry { key: String, point: TelemetryPoint },
+    /// The client fell behind and `skipped` points were dropped for it.
+    Lagged { skipped: u64 },
+    /// A request was refused.
+    Error { message: String },
+}
+
+/// A request from a WebSocket client.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    /// Add key patterns.
+    Subscribe { keys: Vec<String> },
+    /// Remove key patterns.
+    Unsubscribe { keys: Vec<String> },
+}
+
+impl ServerMessage {
+    /// Serialize to the JSON text sent on the wire.
+    pub fn to_json(&self) -> String {
+        // Only string keys and plain data: serialization cannot fail.
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
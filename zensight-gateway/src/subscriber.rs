@@ -0,0 +1,157 @@
+//! Zenoh subscriber feeding received telemetry to the hub.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use tokio::sync::watch;
+use tracing::{info, trace, warn};
+use zenoh::sample::SampleKind;
+use zensight_common::config::ZenohConfig;
+use zensight_common::serialization::decode_auto;
+use zensight_common::telemetry::TelemetryPoint;
+
+use crate::hub::SharedHub;
+
+/// Default key expression to subscribe to.
+pub const DEFAULT_KEY_EXPR: &str = "zensight/**";
+
+/// Whether a key carries a [`TelemetryPoint`]. Control/metadata channels
+/// (`.../@/...` health/liveness/errors/alerts and `zensight/_meta/...`) do not.
+pub(crate) fn is_telemetry_key(key: &str) -> bool {
+    !key.contains("/@/") && !key.starts_with("zensight/_meta/")
+}
+
+/// Statistics for the subscriber.
+#[derive(Debug, Default)]
+pub struct SubscriberStats {
+    pub samples_received: AtomicU64,
+    pub samples_decoded: AtomicU64,
+    pub decode_failures: AtomicU64,
+}
+
+/// Zenoh subscriber that feeds telemetry to the hub.
+pub struct TelemetrySubscriber {
+    hub: SharedHub,
+    zenoh_config: ZenohConfig,
+    key_expr: String,
+    stats: SubscriberStats,
+}
+
+impl TelemetrySubscriber {
+    /// Create a new subscriber.
+    pub fn new(hub: SharedHub, zenoh_config: ZenohConfig) -> Self {
+        Self {
+            hub,
+            zenoh_config,
+            key_expr: DEFAULT_KEY_EXPR.to_string(),
+            stats: SubscriberStats::default(),
+        }
+    }
+
+    /// Set a custom key expression to subscribe to.
+    pub fn with_key_expr(mut self, key_expr: impl Into<String>) -> Self {
+        self.key_expr = key_expr.into();
+        self
+    }
+
+    /// Open a Zenoh session and run until the shutdown signal is received.
+    pub async fn run(self, shutdown: watch::Receiver<bool>) -> anyhow::Result<()> {
+        let session = zensight_common::session::connect(&self.zenoh_config)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to open Zenoh session: {}", e))?;
+        let session = Arc::new(session);
+        self.run_on(session.clone(), shutdown).await?;
+        session
+            .close()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to close session: {}", e))?;
+        Ok(())
+    }
+
+    /// Run on an existing session until the shutdown signal is received.
+    pub async fn run_on(
+        self,
+        session: Arc<zenoh::Session>,
+        mut shutdown: watch::Receiver<bool>,
+    ) -> anyhow::Result<()> {
+        info!(key_expr = %self.key_expr, "Subscribing to telemetry");
+        let subscriber = session
+            .declare_subscriber(&self.key_expr)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to create subscriber: {}", e))?;
+
+        loop {
+            tokio::select! {
+                _ = shutdown.changed() => {
+                    if *shutdown.borrow() {
+                        info!("Shutdown signal received, stopping subscriber");
+                        break;
+                    }
+                }
+
+                sample = subscriber.recv_async() => {
+                    let sample = match sample {
+                        Ok(sample) => sample,
+                        Err(e) => {
+                            warn!("Error receiving sample: {}", e);
+                            continue;
+                        }
+                    };
+                    let key = sample.key_expr().as_str();
+                    if sample.kind() == SampleKind::Delete || !is_telemetry_key(key) {
+                        trace!(key = %key, "Ignoring non-telemetry sample");
+                        continue;
+                    }
+
+                    self.stats.samples_received.fetch_add(1, Ordering::Relaxed);
+                    let payload = sample.payload().to_bytes();
+                    match decode_auto::<TelemetryPoint>(&payload) {
+                        Ok(point) => {
+                            self.stats.samples_decoded.fetch_add(1, Ordering::Relaxed);
+                            self.hub.publish(key, point);
+                        }
+                        Err(e) => {
+                            self.stats.decode_failures.fetch_add(1, Ordering::Relaxed);
+                            warn!(
+                                key = %key,
+                                payload_len = payload.len(),
+                                error = %e,
+                                "Failed to decode telemetry point"
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        subscriber
+            .undeclare()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to undeclare subscriber: {}", e))?;
+        info!("Subscriber stopped");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hub::TelemetryHub;
+
+    #[test]
+    fn test_subscriber_custom_key_expr() {
+        let hub = Arc::new(TelemetryHub::new(8));
+        let subscriber = TelemetrySubscriber::new(hub.clone(), ZenohConfig::default());
+        assert_eq!(subscriber.key_expr, DEFAULT_KEY_EXPR);
+        let subscriber =
+            TelemetrySubscriber::new(hub, ZenohConfig::default()).with_key_expr("custom/**");
+        assert_eq!(subscriber.key_expr, "custom/**");
+    }
+
+    #[test]
+    fn telemetry_key_guard() {
+        assert!(is_telemetry_key("zensight/snmp/r1/if/1/ifInOctets"));
+        assert!(!is_telemetry_key("zensight/snmp/@/health"));
+        assert!(!is_telemetry_key("zensight/_meta/sensors/snmp"));
+    }
+}
@@ -0,0 +1,106 @@
+//! Integration test for the WebSocket gateway over an in-process Zenoh peer.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use tokio::net::TcpListener;
+use tokio::sync::watch;
+use tokio_tungstenite::tungstenite::Message;
+use zensight_common::telemetry::{Protocol, TelemetryPoint, TelemetryValue};
+use zensight_gateway::{HttpServer, ServerMessage, TelemetryHub, TelemetrySubscriber};
+
+/// A standalone Zenoh config: scouting disabled so concurrent test peers don't
+/// discover each other. Local pub/sub within one session still works.
+fn isolated_config() -> zenoh::Config {
+    let mut config = zenoh::Config::default();
+    config
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    config
+        .insert_json5("scouting/gossip/enabled", "false")
+        .unwrap();
+    config
+}
+
+fn unique_source() -> String {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    format!("host_{}", nanos)
+}
+
+type Ws =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn next_message(ws: &mut Ws) -> ServerMessage {
+    loop {
+        let frame = tokio::time::timeout(Duration::from_secs(5), ws.next())
+            .await
+            .expect("recv timed out")
+            .expect("socket closed")
+            .expect("recv");
+        if let Message::Text(text) = frame {
+            return serde_json::from_str(&text).expect("server message");
+        }
+    }
+}
+
+async fn publish(session: &zenoh::Session, source: &str, metric: &str, value: f64) {
+    let point = TelemetryPoint::new(
+        source,
+        Protocol::Sysinfo,
+        metric,
+        TelemetryValue::Gauge(value),
+    );
+    session
+        .put(
+            format!("zensight/sysinfo/{source}/{metric}"),
+            serde_json::to_vec(&point).unwrap(),
+        )
+        .await
+        .expect("put");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn ws_client_receives_points_matching_its_key_pattern() {
+    let session = Arc::new(zenoh::open(isolated_config()).await.expect("open zenoh"));
+    let source = unique_source();
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+
+    let hub = Arc::new(TelemetryHub::new(64));
+    let subscriber = TelemetrySubscriber::new(hub.clone(), Default::default());
+    tokio::spawn(subscriber.run_on(session.clone(), shutdown_rx.clone()));
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(HttpServer::new(hub, addr, 8).serve(listener, shutdown_rx));
+    tokio::time::sleep(Duration::from_millis(150)).await;
+
+    let pattern = format!("zensight/sysinfo/{source}/cpu/**");
+    let (mut ws, _) = tokio_tungstenite::connect_async(format!("ws://{addr}/ws?keys={pattern}"))
+        .await
+        .expect("connect");
+    match next_message(&mut ws).await {
+        ServerMessage::Subscribed { keys } => assert_eq!(keys, vec![pattern]),
+        other => panic!("expected subscription ack, got {other:?}"),
+    }
+
+    // Not matched by the pattern, then matched.
+    publish(&session, &source, "memory/used", 1.0).await;
+    publish(&session, &source, "cpu/usage", 42.5).await;
+
+    match next_message(&mut ws).await {
+        ServerMessage::Telemetry { key, point } => {
+            assert_eq!(key, format!("zensight/sysinfo/{source}/cpu/usage"));
+            assert_eq!(point.source, source);
+            assert_eq!(point.metric, "cpu/usage");
+            assert_eq!(point.value, TelemetryValue::Gauge(42.5));
+        }
+        other => panic!("expected telemetry, got {other:?}"),
+    }
+
+    shutdown_tx.send(true).unwrap();
+}
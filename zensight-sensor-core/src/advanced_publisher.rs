@@ -35,6 +35,7 @@ use zenoh_ext::{AdvancedPublisher, AdvancedPublisherBuilderExt, CacheConfig, Mis
 use zensight_common::{Format, TelemetryPoint, encode};
 
 use crate::error::{Result, SensorError};
+use crate::qos::PublishQosConfig;
 
 /// Configuration for advanced publishers.
 #[derive(Debug, Clone)]
@@ -102,6 +103,10 @@ pub struct AdvancedPublisherRegistry {
     config: AdvancedPublisherConfig,
     /// Serialization format.
     format: Format,
+    /// QoS applied to each publisher at declaration (#410).
+    qos: PublishQosConfig,
+    /// Attachment added to every put, e.g. encoded sample metadata (#444).
+    attachment: Option<Vec<u8>>,
     /// Cached publishers by key expression.
     publishers: RwLock<HashMap<String, AdvancedPublisher<'static>>>,
 }
@@ -129,10 +134,25 @@ impl AdvancedPublisherRegistry {
             key_prefix: key_prefix.into(),
             config,
             format,
+            qos: PublishQosConfig::default(),
+            attachment: None,
             publishers: RwLock::new(HashMap::new()),
         }
     }
 
+    /// Declare publishers with the congestion control and priority `qos`
+    /// maps their key to.
+    pub fn with_qos(mut self, qos: PublishQosConfig) -> Self {
+        self.qos = qos;
+        self
+    }
+
+    /// Attach `attachment` to every sample put through this registry (#444).
+    pub fn with_attachment(mut self, attachment: Option<Vec<u8>>) -> Self {
+        self.attachment = attachment;
+        self
+    }
+
     /// Get the key prefix.
     pub fn key_prefix(&self) -> &str {
         &self.key_prefix
@@ -165,9 +185,12 @@ impl AdvancedPublisherRegistry {
         // This avoids needing unsafe transmute since String -> KeyExpr<'_> produces
         // KeyExpr<'static> via TryFrom<String>.
         let owned_key = key.to_string();
+        let options = self.qos.options_for(key);
         let publisher: AdvancedPublisher<'static> = self
             .session
             .declare_publisher(owned_key)
+            .congestion_control(options.congestion_control.to_zenoh())
+            .priority(options.priority.to_zenoh())
             .cache(CacheConfig::default().max_samples(self.config.cache_size))
             .sample_miss_detection(
                 MissDetectionConfig::default().heartbeat(self.config.heartbeat_interval),
@@ -191,44 +214,33 @@ impl AdvancedPublisherRegistry {
     /// The publisher for this key is created on first use and cached.
     pub async fn publish(&self, key_suffix: &str, point: &TelemetryPoint) -> Result<()> {
         let key = self.build_key(key_suffix);
-
-        // Ensure publisher exists
-        self.get_or_create_publisher(&key).await?;
-
-        // Encode the payload
-        let payload =
-            encode(point, self.format).map_err(|e| SensorError::Serialization(e.to_string()))?;
-
-        // Publish through the cached publisher
-        let publishers = self.publishers.read().await;
-        if let Some(publisher) = publishers.get(&key) {
-            publisher
-                .put(payload)
-                .await
-                .map_err(|e| SensorError::Publish {
-                    key: key.clone(),
-                    message: e.to_string(),
-                })?;
-        }
-
-        Ok(())
+        self.publish_to_key(&key, point).await
     }
 
     /// Publish a telemetry point to a full key (bypassing the prefix), via an
     /// advanced publisher created on first use for that key.
     pub async fn publish_to_key(&self, key: &str, point: &TelemetryPoint) -> Result<()> {
-        self.get_or_create_publisher(key).await?;
         let payload =
             encode(point, self.format).map_err(|e| SensorError::Serialization(e.to_string()))?;
+        self.put_encoded(key, payload).await
+    }
+
+    /// Put an already-encoded payload to a full key through its advanced
+    /// publisher. Lets [`Publisher`](crate::Publisher) replay queued payloads
+    /// without re-encoding them.
+    pub(crate) async fn put_encoded(&self, key: &str, payload: Vec<u8>) -> Result<()> {
+        self.get_or_create_publisher(key).await?;
         let publishers = self.publishers.read().await;
         if let Some(publisher) = publishers.get(key) {
-            publisher
-                .put(payload)
-                .await
-                .map_err(|e| SensorError::Publish {
-                    key: key.to_string(),
-                    message: e.to_string(),
-                })?;
+            let put = publisher.put(payload);
+            match &self.attachment {
+                Some(attachment) => put.attachment(attachment.clone()).await,
+                None => put.await,
+            }
+            .map_err(|e| SensorError::Publish {
+                key: key.to_string(),
+                message: e.to_string(),
+            })?;
         }
         Ok(())
     }
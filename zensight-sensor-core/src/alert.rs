@@ -23,6 +23,7 @@ use zensight_common::{Alert, AlertSeverity, Format, Protocol, encode};
 
 use crate::error::Result;
 use crate::publisher::Publisher;
+use crate::qos::PublishOptions;
 
 /// Internal state for a single tracked alert.
 struct ActiveAlert {
@@ -212,7 +213,16 @@ impl AlertReporter {
         let key = self.alert_key_expr(&alert.alert_key());
         let payload = encode(alert, self.format)
             .map_err(|e| crate::error::SensorError::Serialization(e.to_string()))?;
-        self.publisher.publish_raw(&key, payload).await
+        // Alert transitions must not be dropped under congestion (#410);
+        // an explicit `publish_qos` rule for the key still wins.
+        let options = self
+            .publisher
+            .qos()
+            .rule_options(&key)
+            .unwrap_or(PublishOptions::urgent());
+        self.publisher
+            .publish_raw_with(&key, payload, options)
+            .await
     }
 }
 
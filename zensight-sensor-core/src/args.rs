@@ -15,6 +15,10 @@ pub struct SensorArgs {
     /// Override log level (trace, debug, info, warn, error).
     #[arg(long)]
     pub log_level: Option<String>,
+
+    /// Print the sensor's commented default configuration and exit.
+    #[arg(long)]
+    pub print_default_config: bool,
 }
 
 impl SensorArgs {
@@ -46,6 +50,7 @@ mod tests {
         let args = SensorArgs {
             config: PathBuf::from("test.json5"),
             log_level: Some("debug".to_string()),
+            print_default_config: false,
         };
         assert_eq!(args.config, PathBuf::from("test.json5"));
         assert_eq!(args.log_level, Some("debug".to_string()));
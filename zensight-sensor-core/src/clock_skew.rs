@@ -0,0 +1,176 @@
+//! Clock-skew detection for message-embedded timestamps (#407).
+//!
+//! Bridges running with `timestamp_source: message` trust the device's clock.
+//! [`ClockSkewDetector`] compares each embedded timestamp with receipt time
+//! and, when they drift apart by more than the configured threshold, logs a
+//! warning — rate-limited per device so a skewed box doesn't flood the log —
+//! and optionally publishes an [`ErrorReport`] on `@/errors`.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use zensight_common::{ClockSkewConfig, TimestampSource};
+
+use crate::health::{ErrorReport, ErrorType, SensorHealth};
+
+/// Per-device, rate-limited skew warnings.
+#[derive(Debug)]
+pub struct ClockSkewDetector {
+    enabled: bool,
+    threshold_ms: i64,
+    warn_interval: Duration,
+    publish_errors: bool,
+    health: Option<Arc<SensorHealth>>,
+    last_warned: Mutex<HashMap<String, Instant>>,
+}
+
+impl ClockSkewDetector {
+    /// Build a detector for a bridge stamping points per `source`. Receipt
+    /// time can't be skewed against itself, so with
+    /// [`TimestampSource::Receipt`] every check is a no-op.
+    pub fn new(source: TimestampSource, config: &ClockSkewConfig) -> Self {
+        Self {
+            enabled: config.enabled && source == TimestampSource::Message,
+            threshold_ms: i64::try_from(config.threshold_secs.saturating_mul(1000))
+                .unwrap_or(i64::MAX),
+            warn_interval: Duration::from_secs(config.warn_interval_secs),
+            publish_errors: config.publish_errors,
+            health: None,
+            last_warned: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Publish warnings through `health` when `publish_errors` is set.
+    pub fn with_health(mut self, health: Arc<SensorHealth>) -> Self {
+        self.health = Some(health);
+        self
+    }
+
+    /// Compare `message_ms` with `receipt_ms` for `device`. When the skew is
+    /// over the threshold and `device` hasn't been warned about recently,
+    /// logs a warning and returns the matching report.
+    pub fn check(&self, device: &str, message_ms: i64, receipt_ms: i64) -> Option<ErrorReport> {
+        self.check_at(device, message_ms, receipt_ms, Instant::now())
+    }
+
+    fn check_at(
+        &self,
+        device: &str,
+        message_ms: i64,
+        receipt_ms: i64,
+        now: Instant,
+    ) -> Option<ErrorReport> {
+        if !self.enabled {
+            return None;
+        }
+        let skew_ms = message_ms.saturating_sub(receipt_ms);
+        if skew_ms.unsigned_abs() <= self.threshold_ms.unsigned_abs() {
+            return None;
+        }
+
+        {
+            let mut last_warned = self.last_warned.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(at) = last_warned.get(device)
+                && now.duration_since(*at) < self.warn_interval
+            {
+                return None;
+            }
+            // Entries past the interval no longer suppress anything; drop
+            // them so devices that went away don't accumulate.
+            let interval = self.warn_interval;
+            last_warned.retain(|_, at| now.duration_since(*at) < interval);
+            last_warned.insert(device.to_string(), now);
+        }
+
+        let direction = if skew_ms > 0 { "ahead of" } else { "behind" };
+        let skew_secs = skew_ms.unsigned_abs() / 1000;
+        tracing::warn!(
+            device,
+            skew_ms,
+            "clock skew: {device} timestamps are {skew_secs}s {direction} receipt time; \
+             fix the device clock or set timestamp_source: receipt"
+        );
+        Some(
+            ErrorReport::new(
+                ErrorType::Other,
+                format!("clock skew: timestamps are {skew_secs}s {direction} receipt time"),
+            )
+            .with_device(device)
+            .non_retryable(),
+        )
+    }
+
+    /// [`check`](Self::check), then publish the report if configured to.
+    pub async fn observe(&self, device: &str, message_ms: i64, receipt_ms: i64) {
+        let Some(report) = self.check(device, message_ms, receipt_ms) else {
+            return;
+        };
+        if self.publish_errors
+            && let Some(health) = &self.health
+            && let Err(e) = health.publish_error(&report).await
+        {
+            tracing::warn!(error = %e, "failed to publish clock-skew ErrorReport");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR_MS: i64 = 3_600_000;
+
+    #[test]
+    fn hour_off_timestamp_triggers_skew_warning() {
+        let detector = ClockSkewDetector::new(TimestampSource::Message, &Default::default());
+        let now_ms = 1_700_000_000_000;
+
+        // Within the 5-minute default threshold: quiet.
+        assert!(detector.check("router1", now_ms - 60_000, now_ms).is_none());
+
+        let report = detector
+            .check("router1", now_ms - HOUR_MS, now_ms)
+            .expect("an hour of skew should warn");
+        assert_eq!(report.device.as_deref(), Some("router1"));
+        assert!(report.message.contains("3600s behind"));
+    }
+
+    #[test]
+    fn warnings_are_rate_limited_per_device() {
+        let detector = ClockSkewDetector::new(TimestampSource::Message, &Default::default());
+        let start = Instant::now();
+        let skewed = |d: &ClockSkewDetector, device, at| d.check_at(device, HOUR_MS, 0, at);
+
+        assert!(skewed(&detector, "a", start).is_some());
+        assert!(skewed(&detector, "a", start + Duration::from_secs(60)).is_none());
+        // Other devices have their own budget.
+        assert!(skewed(&detector, "b", start + Duration::from_secs(60)).is_some());
+        // Past the 10-minute interval, warn again.
+        assert!(skewed(&detector, "a", start + Duration::from_secs(601)).is_some());
+    }
+
+    #[test]
+    fn expired_devices_are_pruned() {
+        let detector = ClockSkewDetector::new(TimestampSource::Message, &Default::default());
+        let start = Instant::now();
+        for device in ["a", "b", "c"] {
+            assert!(detector.check_at(device, HOUR_MS, 0, start).is_some());
+        }
+        assert_eq!(detector.last_warned.lock().unwrap().len(), 3);
+
+        assert!(
+            detector
+                .check_at("d", HOUR_MS, 0, start + Duration::from_secs(601))
+                .is_some()
+        );
+        let last_warned = detector.last_warned.lock().unwrap();
+        assert_eq!(last_warned.keys().collect::<Vec<_>>(), ["d"]);
+    }
+
+    #[test]
+    fn receipt_source_never_warns() {
+        let detector = ClockSkewDetector::new(TimestampSource::Receipt, &Default::default());
+        assert!(detector.check("router1", 0, HOUR_MS).is_none());
+    }
+}
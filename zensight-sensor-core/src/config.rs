@@ -1,12 +1,14 @@
 //! Configuration traits and utilities.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
+use serde::Serialize;
 use serde::de::DeserializeOwned;
 
 use crate::error::{Result, SensorError};
-use crate::{LoggingConfig, ZenohConfig};
-use zensight_common::{ReportLimits, SnapshotLimits};
+use crate::{LoggingConfig, MirrorRule, PublishQosConfig, PublishRetryConfig, ZenohConfig};
+use zensight_common::{ClockSkewConfig, ReportLimits, SnapshotLimits, TimestampSource};
 
 /// Trait for sensor configuration types.
 ///
@@ -39,6 +41,10 @@ use zensight_common::{ReportLimits, SnapshotLimits};
 ///         &self.my_protocol.key_prefix
 ///     }
 ///
+///     fn default_config_json5() -> &'static str {
+///         include_str!("../mysensor.json5")
+///     }
+///
 ///     fn validate(&self) -> Result<()> {
 ///         if self.my_protocol.devices.is_empty() {
 ///             return Err(SensorError::validation("At least one device required"));
@@ -57,6 +63,11 @@ pub trait SensorConfig: Sized + DeserializeOwned {
     /// Get the key expression prefix for this sensor.
     fn key_prefix(&self) -> &str;
 
+    /// The sensor's fully-commented default configuration (#449), printed by
+    /// `--print-default-config`. Bridges embed the file they ship to
+    /// `/etc/zensight`, so the output stays in step with the packaged config.
+    fn default_config_json5() -> &'static str;
+
     /// Debug-report limits/policy. Defaults to disabled; a sensor opts in by
     /// overriding this to return its configured [`ReportLimits`] (and enabling
     /// `with_report` in `main`).
@@ -71,6 +82,53 @@ pub trait SensorConfig: Sized + DeserializeOwned {
         SnapshotLimits::default()
     }
 
+    /// Which clock stamps published points (#406). Only meaningful for
+    /// bridges whose messages carry their own time; a sensor exposes it by
+    /// overriding this to return its configured `timestamp_source`.
+    fn timestamp_source(&self) -> TimestampSource {
+        TimestampSource::default()
+    }
+
+    /// Congestion control / priority mapping for this sensor's puts (#410).
+    fn publish_qos(&self) -> PublishQosConfig {
+        PublishQosConfig::default()
+    }
+
+    /// Queueing and replay of failed publishes (#412). Disabled by default.
+    fn publish_retry(&self) -> PublishRetryConfig {
+        PublishRetryConfig::default()
+    }
+
+    /// Re-publish matching telemetry under alternate key prefixes (#447).
+    /// None by default.
+    fn publish_mirror(&self) -> Vec<MirrorRule> {
+        Vec::new()
+    }
+
+    /// Attach bridge name, version and payload encoding to every telemetry
+    /// sample as a Zenoh attachment (#444). Off by default.
+    fn attach_metadata(&self) -> bool {
+        false
+    }
+
+    /// Unix socket for runtime control commands (#445); see
+    /// [`ControlServer`](crate::ControlServer). Disabled by default.
+    fn control_socket(&self) -> Option<PathBuf> {
+        None
+    }
+
+    /// How long shutdown waits for queued publishes to flush before closing
+    /// the session (#461). Two seconds by default.
+    fn shutdown_drain(&self) -> Duration {
+        Duration::from_secs(2)
+    }
+
+    /// Skew detection for message-embedded timestamps (#407); see
+    /// [`crate::ClockSkewDetector`].
+    fn clock_skew(&self) -> ClockSkewConfig {
+        ClockSkewConfig::default()
+    }
+
     /// Validate the configuration.
     ///
     /// Called automatically after loading. Override to add custom validation.
@@ -91,7 +149,13 @@ pub trait SensorConfig: Sized + DeserializeOwned {
         }
 
         let content = std::fs::read_to_string(path)?;
-        let config: Self = json5::from_str(&content)?;
+        let config: Self = json5::from_str(&content).map_err(|e| {
+            SensorError::ConfigParse(format!(
+                "{}: {}",
+                path.display(),
+                zensight_common::describe_json5_error(&content, &e)
+            ))
+        })?;
 
         config.validate()?;
 
@@ -99,12 +163,57 @@ pub trait SensorConfig: Sized + DeserializeOwned {
     }
 }
 
+/// Config fields the printed default of `C` doesn't mention (#449), as dotted
+/// paths. The default is parsed back and serialized; every field of the
+/// result, nested sections included, should appear in the text as `name:`,
+/// either set or commented out. Lists and their items aren't descended into.
+pub fn undocumented_fields<C: SensorConfig + Serialize>() -> Result<Vec<String>> {
+    let text = C::default_config_json5();
+    let config: C = json5::from_str(text)?;
+    let mut missing = Vec::new();
+    collect_undocumented(&serde_json::to_value(&config)?, "", text, &mut missing);
+    Ok(missing)
+}
+
+fn collect_undocumented(
+    value: &serde_json::Value,
+    path: &str,
+    text: &str,
+    missing: &mut Vec<String>,
+) {
+    let serde_json::Value::Object(fields) = value else {
+        return;
+    };
+    for (name, field) in fields {
+        let path = if path.is_empty() {
+            name.clone()
+        } else {
+            format!("{path}.{name}")
+        };
+        if mentions_field(text, name) {
+            collect_undocumented(field, &path, text, missing);
+        } else {
+            missing.push(path);
+        }
+    }
+}
+
+/// Whether `text` has `name:` (or `"name":`) as a whole word.
+fn mentions_field(text: &str, name: &str) -> bool {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    text.match_indices(name).any(|(at, _)| {
+        let before = text[..at].trim_end_matches('"');
+        let after = text[at + name.len()..].trim_start_matches('"');
+        !before.ends_with(is_word) && after.trim_start().starts_with(':')
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use serde::Deserialize;
 
-    #[derive(Debug, Deserialize)]
+    #[derive(Debug, Deserialize, Serialize)]
     struct TestConfig {
         zenoh: ZenohConfig,
         logging: LoggingConfig,
@@ -123,6 +232,28 @@ mod tests {
         fn key_prefix(&self) -> &str {
             &self.key_prefix
         }
+
+        fn default_config_json5() -> &'static str {
+            "{ zenoh: {}, logging: {}, key_prefix: \"zensight/test\" }"
+        }
+    }
+
+    #[test]
+    fn undocumented_fields_lists_what_the_default_leaves_out() {
+        let missing = undocumented_fields::<TestConfig>().unwrap();
+        assert!(missing.contains(&"zenoh.mode".to_string()));
+        assert!(missing.contains(&"logging.level".to_string()));
+        assert!(!missing.contains(&"key_prefix".to_string()));
+        assert!(
+            !missing
+                .iter()
+                .any(|path| path == "zenoh" || path == "logging")
+        );
+
+        assert!(mentions_field("  // namespace: \"a\",", "namespace"));
+        assert!(mentions_field("{ \"max_files\": 5 }", "max_files"));
+        assert!(!mentions_field("snapshot_max_files: 5", "max_files"));
+        assert!(!mentions_field("max_files are capped", "max_files"));
     }
 
     #[test]
@@ -0,0 +1,330 @@
+//! Local runtime-control socket (#445).
+//!
+//! A bridge with `control_socket` configured listens on that Unix socket for
+//! newline-delimited JSON commands and answers each with one JSON line:
+//!
+//! ```text
+//! → {"command": "set-log-level", "args": {"level": "debug"}}
+//! ← {"ok": true, "result": {"level": "debug"}}
+//! ```
+//!
+//! The framework registers `dump-stats` (the current [`BridgeStatus`]),
+//! `set-log-level`, `reload` and `help`; bridges add their own commands, or
+//! replace `reload` with one that actually reloads, through
+//! [`ControlRegistry::register`]. The socket is local-only and created
+//! owner-only (`0600`), so nothing here is exposed over Zenoh.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+
+use crate::error::Result;
+use crate::status::BridgeStatus;
+
+/// One command read from the socket.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ControlRequest {
+    /// Command name, e.g. `"dump-stats"`.
+    pub command: String,
+    /// Command arguments; `null` when omitted.
+    #[serde(default)]
+    pub args: Value,
+}
+
+/// The reply to a [`ControlRequest`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ControlResponse {
+    /// Whether the command succeeded.
+    pub ok: bool,
+    /// The command's result, on success.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    /// What went wrong, on failure.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl ControlResponse {
+    fn from_result(result: std::result::Result<Value, String>) -> Self {
+        match result {
+            Ok(value) => Self {
+                ok: true,
+                result: Some(value),
+                error: None,
+            },
+            Err(error) => Self {
+                ok: false,
+                result: None,
+                error: Some(error),
+            },
+        }
+    }
+}
+
+/// A command handler: takes the request's `args`, returns the result or an
+/// error message.
+pub type ControlHandler = Arc<dyn Fn(&Value) -> std::result::Result<Value, String> + Send + Sync>;
+
+/// Named command handlers, shared by clones so a bridge can register commands
+/// after the server has started.
+#[derive(Clone, Default)]
+pub struct ControlRegistry {
+    handlers: Arc<RwLock<BTreeMap<String, ControlHandler>>>,
+}
+
+impl ControlRegistry {
+    /// An empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A registry with the framework commands:
+    ///
+    /// - `dump-stats`: the [`BridgeStatus`] `status` returns;
+    /// - `set-log-level`: swap the log filter (`args.level`, any `RUST_LOG`-style
+    ///   directives);
+    /// - `reload`: fails with "not supported" until the bridge registers its
+    ///   own `reload` handler, so a caller never gets a success nobody acted on;
+    /// - `help`: the registered command names.
+    pub fn with_builtins<F>(status: F) -> Self
+    where
+        F: Fn() -> BridgeStatus + Send + Sync + 'static,
+    {
+        let registry = Self::new();
+        registry.register("dump-stats", move |_| {
+            serde_json::to_value(status()).map_err(|e| e.to_string())
+        });
+        registry.register("set-log-level", |args| {
+            let level = args
+                .get("level")
+                .and_then(Value::as_str)
+                .ok_or("missing string argument `level`")?;
+            zensight_common::set_log_filter(level).map_err(|e| e.to_string())?;
+            tracing::info!(level, "Log level changed via control socket");
+            Ok(json!({ "level": level }))
+        });
+        registry.register("reload", |_| {
+            Err("reload is not supported by this bridge".to_string())
+        });
+        // Weak, so the registry doesn't keep itself alive.
+        let handlers = Arc::downgrade(&registry.handlers);
+        registry.register("help", move |_| {
+            let handlers = handlers.upgrade().ok_or("registry dropped")?;
+            Ok(json!(Self { handlers }.commands()))
+        });
+        registry
+    }
+
+    /// Register `handler` for `command`, replacing any previous handler.
+    pub fn register<F>(&self, command: impl Into<String>, handler: F)
+    where
+        F: Fn(&Value) -> std::result::Result<Value, String> + Send + Sync + 'static,
+    {
+        self.handlers
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(command.into(), Arc::new(handler));
+    }
+
+    /// Registered command names, sorted.
+    pub fn commands(&self) -> Vec<String> {
+        self.handlers
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    /// Run `request` through its handler.
+    pub fn dispatch(&self, request: &ControlRequest) -> ControlResponse {
+        // Clone the handler out so it runs without holding the lock (`help`
+        // reads the registry itself).
+        let handler = self
+            .handlers
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&request.command)
+            .cloned();
+        ControlResponse::from_result(match handler {
+            Some(handler) => handler(&request.args),
+            None => Err(format!("unknown command `{}`", request.command)),
+        })
+    }
+
+    /// Parse one request line and dispatch it; malformed JSON gets an error
+    /// response rather than closing the connection.
+    pub fn handle_line(&self, line: &str) -> ControlResponse {
+        match serde_json::from_str::<ControlRequest>(line) {
+            Ok(request) => self.dispatch(&request),
+            Err(e) => ControlResponse::from_result(Err(format!("invalid request: {e}"))),
+        }
+    }
+}
+
+/// Serves a [`ControlRegistry`] on a Unix socket.
+pub struct ControlServer {
+    path: PathBuf,
+    listener: UnixListener,
+    registry: ControlRegistry,
+}
+
+impl ControlServer {
+    /// Bind `path`, replacing a stale socket left by a previous run. Anything
+    /// else already at `path` is left alone and binding fails. The socket is
+    /// restricted to its owner (`0600`). Must be called within a Tokio runtime.
+    pub fn bind(path: impl AsRef<Path>, registry: ControlRegistry) -> Result<Self> {
+        use std::os::unix::fs::{FileTypeExt, PermissionsExt};
+
+        let path = path.as_ref().to_path_buf();
+        match std::fs::symlink_metadata(&path) {
+            Ok(meta) if meta.file_type().is_socket() => std::fs::remove_file(&path)?,
+            Ok(_) => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::AlreadyExists,
+                    format!("{} exists and is not a socket", path.display()),
+                )
+                .into());
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+        if let Some(dir) = path.parent()
+            && !dir.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(dir)?;
+        }
+        let listener = UnixListener::bind(&path)?;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600))?;
+        Ok(Self {
+            path,
+            listener,
+            registry,
+        })
+    }
+
+    /// The socket path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Accept connections until the task is aborted, serving each on its own
+    /// task.
+    pub async fn run(self) {
+        tracing::info!(path = %self.path.display(), "Control socket listening");
+        loop {
+            match self.listener.accept().await {
+                Ok((stream, _)) => {
+                    let registry = self.registry.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = serve_connection(stream, registry).await {
+                            tracing::debug!(error = %e, "Control connection closed");
+                        }
+                    });
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "Control socket accept failed");
+                }
+            }
+        }
+    }
+}
+
+impl Drop for ControlServer {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+async fn serve_connection(stream: UnixStream, registry: ControlRegistry) -> std::io::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = registry.handle_line(&line);
+        let mut out = serde_json::to_vec(&response).map_err(std::io::Error::other)?;
+        out.push(b'\n');
+        writer.write_all(&out).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> ControlRegistry {
+        let health = crate::SensorHealth::new("snmp");
+        ControlRegistry::with_builtins(move || BridgeStatus {
+            sensor: "snmp".to_string(),
+            version: "0.0.0".to_string(),
+            key_prefix: "zensight/snmp".to_string(),
+            queued_retries: 0,
+            health: health.snapshot(),
+        })
+    }
+
+    #[test]
+    fn unknown_commands_and_bad_json_are_errors() {
+        let registry = registry();
+        let response = registry.handle_line(r#"{"command":"explode"}"#);
+        assert!(!response.ok);
+        assert_eq!(response.error.as_deref(), Some("unknown command `explode`"));
+
+        let response = registry.handle_line("not json");
+        assert!(!response.ok);
+        assert!(response.error.unwrap().starts_with("invalid request"));
+    }
+
+    #[test]
+    fn bridges_extend_the_builtins() {
+        let registry = registry();
+        registry.register("flush-cache", |args| Ok(json!({ "flushed": args["n"] })));
+
+        let response = registry.handle_line(r#"{"command":"flush-cache","args":{"n":3}}"#);
+        assert_eq!(response.result, Some(json!({ "flushed": 3 })));
+
+        let help = registry.handle_line(r#"{"command":"help"}"#);
+        assert_eq!(
+            help.result,
+            Some(json!([
+                "dump-stats",
+                "flush-cache",
+                "help",
+                "reload",
+                "set-log-level"
+            ]))
+        );
+    }
+
+    #[test]
+    fn reload_is_unsupported_until_a_bridge_registers_it() {
+        let registry = registry();
+        let response = registry.handle_line(r#"{"command":"reload"}"#);
+        assert!(!response.ok);
+        assert_eq!(
+            response.error.as_deref(),
+            Some("reload is not supported by this bridge")
+        );
+
+        registry.register("reload", |_| Ok(json!({ "reloaded": true })));
+        let response = registry.handle_line(r#"{"command":"reload"}"#);
+        assert_eq!(response.result, Some(json!({ "reloaded": true })));
+    }
+
+    #[test]
+    fn set_log_level_requires_a_level() {
+        let response = registry().handle_line(r#"{"command":"set-log-level"}"#);
+        assert_eq!(
+            response.error.as_deref(),
+            Some("missing string argument `level`")
+        );
+    }
+}
@@ -48,6 +48,15 @@ pub enum SensorError {
     #[error("Failed to publish to {key}: {message}")]
     Publish { key: String, message: String },
 
+    /// A telemetry value that would poison charts and alert rules (#458);
+    /// it is dropped instead of published.
+    #[error("Invalid telemetry value for {key}: {source}")]
+    InvalidValue {
+        key: String,
+        #[source]
+        source: zensight_common::InvalidValue,
+    },
+
     /// Liveliness token error.
     #[error("Liveliness error: {0}")]
     Liveliness(String),
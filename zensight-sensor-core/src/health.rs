@@ -5,16 +5,65 @@
 //! - [`DeviceLiveness`] for tracking per-device availability
 //! - [`SensorError`] for unified error reporting
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::atomic::{AtomicI64, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex, RwLock};
 use std::time::Instant;
 
 use serde::{Deserialize, Serialize};
+use zensight_common::{DiscoveryEvent, DiscoveryKind};
 
 use crate::Result;
 use crate::liveliness::LivelinessManager;
+use crate::metrics::SensorMetrics;
 use crate::publisher::Publisher;
+use crate::restarts::{RESTART_WINDOW_MS, RestartState};
+
+/// Most sources [`SensorHealth::discover`] remembers before it forgets the
+/// least recently seen ones (#436). A forgotten source is announced again
+/// the next time it shows up.
+pub const MAX_DISCOVERED_SOURCES: usize = 16_384;
+
+type SourceKey = (DiscoveryKind, String, Option<String>);
+
+/// Sources already announced, with the tick each was last seen at, bounded
+/// to `cap` entries so a bridge fed by arbitrary senders cannot grow it
+/// without limit (#436).
+struct SeenSources {
+    last_seen: HashMap<SourceKey, u64>,
+    clock: u64,
+    cap: usize,
+}
+
+impl SeenSources {
+    fn new(cap: usize) -> Self {
+        Self {
+            last_seen: HashMap::new(),
+            clock: 0,
+            cap: cap.max(1),
+        }
+    }
+
+    /// Mark `key` as seen now. Returns `true` if it was not already known.
+    fn touch(&mut self, key: SourceKey) -> bool {
+        self.clock += 1;
+        let new = self.last_seen.insert(key, self.clock).is_none();
+        if new && self.last_seen.len() > self.cap {
+            self.evict_oldest();
+        }
+        new
+    }
+
+    /// Forget the least recently seen eighth, so eviction runs once per
+    /// `cap / 8` new sources rather than on every insert.
+    fn evict_oldest(&mut self) {
+        let drop = (self.last_seen.len() / 8).max(1);
+        let mut ticks: Vec<u64> = self.last_seen.values().copied().collect();
+        let (_, cutoff, _) = ticks.select_nth_unstable(drop - 1);
+        let cutoff = *cutoff;
+        self.last_seen.retain(|_, seen| *seen > cutoff);
+    }
+}
 
 /// Rolling window error counter with 1-minute buckets over the last hour.
 struct RollingErrorCounter {
@@ -117,6 +166,12 @@ pub struct SensorHealth {
     publisher: Option<Publisher>,
     /// Liveliness manager for Zenoh presence tokens.
     liveliness_manager: Option<Arc<LivelinessManager>>,
+    /// Sensor-specific named counters and gauges.
+    metrics: SensorMetrics,
+    /// Start history persisted across process restarts (#435).
+    restarts: Option<RestartState>,
+    /// Sources already announced on `@/discovery` (#436).
+    discovered: Mutex<SeenSources>,
 }
 
 /// Device state for liveness tracking.
@@ -181,6 +236,24 @@ pub struct HealthSnapshot {
     pub errors_last_hour: u64,
     /// Total metrics published.
     pub metrics_published: u64,
+    /// Sensor-specific counters from [`SensorHealth::metrics`] (#411).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub counters: BTreeMap<String, u64>,
+    /// Sensor-specific gauges from [`SensorHealth::metrics`] (#411).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub gauges: BTreeMap<String, f64>,
+    /// Times the process has been restarted, from the persisted start
+    /// history (#435).
+    #[serde(default)]
+    pub restart_count: u64,
+    /// Restarts in the last hour; a high value means the bridge is
+    /// crash-looping (#435).
+    #[serde(default)]
+    pub restarts_last_hour: u64,
+    /// When the current process started (millis since epoch), if start
+    /// tracking is available (#435).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_start_ms: Option<i64>,
 }
 
 /// Device liveness information for serialization.
@@ -253,15 +326,29 @@ impl SensorHealth {
             device_liveness: Arc::new(RwLock::new(HashMap::new())),
             publisher: None,
             liveliness_manager: None,
+            metrics: SensorMetrics::new(),
+            restarts: None,
+            discovered: Mutex::new(SeenSources::new(MAX_DISCOVERED_SOURCES)),
         }
     }
 
+    /// Named counters and gauges reported in every health snapshot.
+    pub fn metrics(&self) -> &SensorMetrics {
+        &self.metrics
+    }
+
     /// Set the publisher for health metrics.
     pub fn with_publisher(mut self, publisher: Publisher) -> Self {
         self.publisher = Some(publisher);
         self
     }
 
+    /// Set the persisted start history reported in snapshots (#435).
+    pub fn with_restart_state(mut self, restarts: RestartState) -> Self {
+        self.restarts = Some(restarts);
+        self
+    }
+
     /// Set the liveliness manager for Zenoh presence tokens.
     ///
     /// When set, device success/failure will automatically declare/undeclare
@@ -461,9 +548,45 @@ impl SensorHealth {
             last_poll_duration_ms: self.last_poll_duration_ms.load(Ordering::SeqCst),
             errors_last_hour: self.errors_last_hour.count(),
             metrics_published: self.metrics_published.load(Ordering::SeqCst),
+            counters: self.metrics.counters(),
+            gauges: self.metrics.gauges(),
+            restart_count: self
+                .restarts
+                .as_ref()
+                .map_or(0, RestartState::restart_count),
+            restarts_last_hour: self.restarts.as_ref().map_or(0, |r| {
+                r.restarts_within(RESTART_WINDOW_MS, chrono::Utc::now().timestamp_millis())
+            }),
+            last_start_ms: self.restarts.as_ref().map(|r| r.last_start_ms),
         }
     }
 
+    /// Note that `device` (or `item` within it) was seen. Returns the
+    /// discovery event the first time this source is seen and `None` after,
+    /// so each one is announced once per process (#436). At most
+    /// [`MAX_DISCOVERED_SOURCES`] are remembered; the least recently seen
+    /// are forgotten past that and announced again if they come back.
+    pub fn discover(
+        &self,
+        kind: DiscoveryKind,
+        device: &str,
+        item: Option<&str>,
+    ) -> Option<DiscoveryEvent> {
+        let key = (kind, device.to_string(), item.map(str::to_string));
+        let new = self
+            .discovered
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .touch(key);
+        new.then(|| DiscoveryEvent {
+            device: device.to_string(),
+            kind,
+            item: item.map(str::to_string),
+            label: None,
+            first_seen: chrono::Utc::now().timestamp_millis(),
+        })
+    }
+
     /// Get liveness info for a specific device.
     pub fn device_liveness(&self, device_id: &str) -> Option<DeviceLiveness> {
         let devices = self
@@ -526,6 +649,28 @@ impl SensorHealth {
         Ok(())
     }
 
+    /// Publish a discovery event to `<prefix>/@/discovery` (#436).
+    pub async fn publish_discovery(&self, event: &DiscoveryEvent) -> Result<()> {
+        let Some(ref publisher) = self.publisher else {
+            return Ok(());
+        };
+
+        let key = format!("{}/@/discovery", publisher.key_prefix());
+        publisher.publish_json(&key, event).await
+    }
+
+    /// Announce `device` on `@/discovery` the first time this sensor sees
+    /// it (#436). Best-effort: a failed publish is logged and dropped.
+    pub async fn announce_device(&self, device: &str) {
+        let Some(event) = self.discover(DiscoveryKind::Device, device, None) else {
+            return;
+        };
+        tracing::info!(device = %device, "Discovered new device");
+        if let Err(e) = self.publish_discovery(&event).await {
+            tracing::debug!(device = %device, error = %e, "Failed to publish discovery event");
+        }
+    }
+
     /// Publish an error report to Zenoh.
     pub async fn publish_error(&self, report: &ErrorReport) -> Result<()> {
         let Some(ref publisher) = self.publisher else {
@@ -612,6 +757,50 @@ mod tests {
         assert!(liveness.last_error.is_none());
     }
 
+    #[test]
+    fn test_discover_reports_each_source_once() {
+        let health = SensorHealth::new("test");
+
+        let event = health
+            .discover(DiscoveryKind::Interface, "router01", Some("if/3"))
+            .unwrap();
+        assert_eq!(event.device, "router01");
+        assert_eq!(event.item.as_deref(), Some("if/3"));
+        assert!(event.first_seen > 0);
+
+        assert!(
+            health
+                .discover(DiscoveryKind::Interface, "router01", Some("if/3"))
+                .is_none()
+        );
+        // Same item on another device, or another kind, is a new source.
+        assert!(
+            health
+                .discover(DiscoveryKind::Interface, "router02", Some("if/3"))
+                .is_some()
+        );
+        assert!(
+            health
+                .discover(DiscoveryKind::Device, "router01", None)
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_seen_sources_forget_the_least_recently_seen() {
+        let key = |n: &str| (DiscoveryKind::Device, n.to_string(), None);
+        let mut seen = SeenSources::new(8);
+        for i in 0..8 {
+            assert!(seen.touch(key(&format!("d{i}"))));
+        }
+        // Seeing d0 again refreshes it, so d1 is now the oldest.
+        assert!(!seen.touch(key("d0")));
+        assert!(seen.touch(key("d8")));
+        assert_eq!(seen.last_seen.len(), 8);
+        assert!(seen.touch(key("d1")), "evicted source is new again");
+        assert!(!seen.touch(key("d0")));
+    }
+
     #[test]
     fn test_device_failure() {
         let health = SensorHealth::new("test");
@@ -10,6 +10,8 @@
 //! - [`Publisher`] for publishing telemetry to Zenoh with automatic serialization
 //! - [`SensorArgs`] for common CLI argument parsing
 //! - [`SensorStatus`] for standardized status reporting
+//! - [`ControlRegistry`] / [`ControlServer`] for a local JSON command socket
+//! - [`lint_metric_name`] / [`warn_metric_names`] for metric naming conventions
 //!
 //! # Example
 //!
@@ -34,13 +36,22 @@
 mod advanced_publisher;
 mod alert;
 mod args;
+mod clock_skew;
 mod config;
+#[cfg(unix)]
+mod control;
 mod correlation;
 mod error;
 mod health;
 mod liveliness;
+mod metric_lint;
+mod metrics;
+mod mirror;
 mod publisher;
+mod qos;
 pub mod report;
+mod restarts;
+mod retry;
 mod runner;
 pub mod snapshot;
 mod status;
@@ -48,21 +59,33 @@ mod status;
 pub use advanced_publisher::{AdvancedPublisherConfig, AdvancedPublisherRegistry};
 pub use alert::{AlertReporter, serve_alerts_query};
 pub use args::SensorArgs;
-pub use config::SensorConfig;
+pub use clock_skew::ClockSkewDetector;
+pub use config::{SensorConfig, undocumented_fields};
+#[cfg(unix)]
+pub use control::{
+    ControlHandler, ControlRegistry, ControlRequest, ControlResponse, ControlServer,
+};
 pub use correlation::{CorrelationEntry, CorrelationRegistry, DeviceIdentity, SensorInfo};
 pub use error::{Result, SensorError};
 pub use health::{
     DeviceLiveness, DeviceStatus, ErrorReport, ErrorType, HealthSnapshot, SensorHealth,
 };
 pub use liveliness::LivelinessManager;
+pub use metric_lint::{MetricNameIssue, lint_metric_name, warn_metric_names};
+pub use metrics::{Counter, Gauge, SensorMetrics};
+pub use mirror::MirrorRule;
 pub use publisher::Publisher;
+pub use qos::{Congestion, PublishOptions, PublishPriority, PublishQosConfig, QosRule};
 pub use report::{DebugBundleSource, ReportChannel, SimpleBundleSource, redact};
+pub use restarts::RestartState;
+pub use retry::PublishRetryConfig;
 pub use runner::SensorRunner;
 pub use snapshot::SnapshotChannel;
-pub use status::SensorStatus;
+pub use status::{BridgeStatus, SensorStatus};
 
 // Re-export commonly used types from zensight-common
 pub use zensight_common::{
-    Alert, AlertKind, AlertSeverity, AlertState, Format, LogFormat, LoggingConfig, Protocol,
-    ReportLimits, SnapshotDir, SnapshotLimits, TelemetryPoint, TelemetryValue, ZenohConfig,
+    Alert, AlertKind, AlertSeverity, AlertState, ClockSkewConfig, DiscoveryEvent, DiscoveryKind,
+    Format, LogFormat, LoggingConfig, Protocol, ReportLimits, SampleMetadata, SnapshotDir,
+    SnapshotLimits, TelemetryPoint, TelemetryValue, TimestampSource, ZenohConfig,
 };
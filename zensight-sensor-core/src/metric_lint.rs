@@ -0,0 +1,177 @@
+//! Metric naming-convention checks (#459).
+//!
+//! Metric names become the tail of a Zenoh key
+//! (`zensight/<protocol>/<source>/<metric>`), so they must be valid key
+//! segments, and dashboards are easier to build when every bridge names things
+//! the same way. The rules:
+//!
+//! - no leading or trailing `/`, and no empty segments (`a//b`);
+//! - no characters Zenoh reserves in key expressions (`*`, `$`, `?`, `#`) or
+//!   whitespace;
+//! - each segment is either `snake_case` or `camelCase`, and one name doesn't
+//!   mix the two (`if/1/ifInOctets` and `cpu/usage_percent` are fine,
+//!   `cpu/usagePercent_avg` and `net/rx_bytes/ifInOctets` are not).
+//!
+//! Bridges call [`warn_metric_names`] at startup on the names they know up
+//! front (configured register names, OID aliases, …); tests call
+//! [`lint_metric_name`] directly.
+
+use std::fmt;
+
+/// Characters Zenoh reserves in key expressions.
+const RESERVED: [char; 4] = ['*', '$', '?', '#'];
+
+/// One naming-rule violation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetricNameIssue {
+    /// The name is empty.
+    Empty,
+    /// The name starts with `/`.
+    LeadingSlash,
+    /// The name ends with `/`.
+    TrailingSlash,
+    /// Two consecutive `/`.
+    EmptySegment,
+    /// A reserved key-expression character or whitespace.
+    ReservedChar(char),
+    /// A segment mixing `_` and upper-case letters.
+    MixedCaseSegment(String),
+    /// `snake_case` and `camelCase` segments in the same name.
+    InconsistentCasing,
+}
+
+impl fmt::Display for MetricNameIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MetricNameIssue::Empty => f.write_str("name is empty"),
+            MetricNameIssue::LeadingSlash => f.write_str("leading '/'"),
+            MetricNameIssue::TrailingSlash => f.write_str("trailing '/'"),
+            MetricNameIssue::EmptySegment => f.write_str("empty segment ('//')"),
+            MetricNameIssue::ReservedChar(c) => write!(f, "reserved character {c:?}"),
+            MetricNameIssue::MixedCaseSegment(s) => {
+                write!(f, "segment `{s}` mixes snake_case and camelCase")
+            }
+            MetricNameIssue::InconsistentCasing => {
+                f.write_str("mixes snake_case and camelCase segments")
+            }
+        }
+    }
+}
+
+/// Check `name` against the naming rules; empty when it complies.
+pub fn lint_metric_name(name: &str) -> Vec<MetricNameIssue> {
+    if name.is_empty() {
+        return vec![MetricNameIssue::Empty];
+    }
+
+    let mut issues = Vec::new();
+    if name.starts_with('/') {
+        issues.push(MetricNameIssue::LeadingSlash);
+    }
+    if name.ends_with('/') {
+        issues.push(MetricNameIssue::TrailingSlash);
+    }
+    if name.contains("//") {
+        issues.push(MetricNameIssue::EmptySegment);
+    }
+    let mut reserved: Vec<char> = name
+        .chars()
+        .filter(|c| RESERVED.contains(c) || c.is_whitespace())
+        .collect();
+    reserved.dedup();
+    issues.extend(reserved.into_iter().map(MetricNameIssue::ReservedChar));
+
+    let (mut snake, mut camel) = (false, false);
+    for segment in name.split('/').filter(|s| !s.is_empty()) {
+        let underscore = segment.contains('_');
+        let upper = segment.chars().any(|c| c.is_ascii_uppercase());
+        match (underscore, upper) {
+            (true, true) => issues.push(MetricNameIssue::MixedCaseSegment(segment.to_string())),
+            (true, false) => snake = true,
+            (false, true) => camel = true,
+            (false, false) => {}
+        }
+    }
+    if snake && camel {
+        issues.push(MetricNameIssue::InconsistentCasing);
+    }
+    issues
+}
+
+/// Log a warning for every name in `names` that breaks the naming rules, and
+/// return how many did.
+pub fn warn_metric_names<'a>(sensor: &str, names: impl IntoIterator<Item = &'a str>) -> usize {
+    let mut flagged = 0;
+    for name in names {
+        let issues = lint_metric_name(name);
+        if issues.is_empty() {
+            continue;
+        }
+        flagged += 1;
+        let issues: Vec<String> = issues.iter().map(ToString::to_string).collect();
+        tracing::warn!(
+            sensor,
+            metric = name,
+            issues = %issues.join("; "),
+            "Metric name breaks naming conventions"
+        );
+    }
+    flagged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conventional_names_pass() {
+        for name in [
+            "system/sysUpTime",
+            "if/1/ifInOctets",
+            "cpu/usage_percent",
+            "holding/tank-level",
+            "disk/sda1/read_bytes",
+        ] {
+            assert!(lint_metric_name(name).is_empty(), "{name} flagged");
+        }
+    }
+
+    #[test]
+    fn leading_slash_and_reserved_characters_are_flagged() {
+        assert_eq!(
+            lint_metric_name("/cpu/usage"),
+            vec![MetricNameIssue::LeadingSlash]
+        );
+        assert_eq!(
+            lint_metric_name("cpu/*/usage"),
+            vec![MetricNameIssue::ReservedChar('*')]
+        );
+        assert_eq!(
+            lint_metric_name("tank level$"),
+            vec![
+                MetricNameIssue::ReservedChar(' '),
+                MetricNameIssue::ReservedChar('$')
+            ]
+        );
+        assert_eq!(
+            lint_metric_name("cpu//usage/"),
+            vec![
+                MetricNameIssue::TrailingSlash,
+                MetricNameIssue::EmptySegment
+            ]
+        );
+    }
+
+    #[test]
+    fn casing_must_be_consistent() {
+        assert_eq!(
+            lint_metric_name("cpu/usagePercent_avg"),
+            vec![MetricNameIssue::MixedCaseSegment("usagePercent_avg".into())]
+        );
+        assert_eq!(
+            lint_metric_name("net/rx_bytes/ifInOctets"),
+            vec![MetricNameIssue::InconsistentCasing]
+        );
+        assert_eq!(warn_metric_names("test", ["ok/name", "/bad", "x//y"]), 2);
+    }
+}
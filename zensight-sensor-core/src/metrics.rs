@@ -0,0 +1,153 @@
+//! In-process sensor metrics registry (#411).
+//!
+//! Sensors used to keep ad-hoc counters in their main loops (flows seen,
+//! points dropped, …) that only ever reached a log line. [`SensorMetrics`]
+//! gives them named counters and gauges instead; [`SensorHealth`] owns one,
+//! and every [`HealthSnapshot`] published to `@/health` carries its current
+//! values, so all sensors report the same way.
+//!
+//! [`SensorHealth`]: crate::SensorHealth
+//! [`HealthSnapshot`]: crate::HealthSnapshot
+
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+
+/// Registry of named counters and gauges. Cheap to clone; clones share state.
+#[derive(Debug, Clone, Default)]
+pub struct SensorMetrics {
+    inner: Arc<RwLock<Registry>>,
+}
+
+#[derive(Debug, Default)]
+struct Registry {
+    counters: BTreeMap<String, Counter>,
+    gauges: BTreeMap<String, Gauge>,
+}
+
+/// Monotonic counter handle. Grab it once and increment from the hot path;
+/// no registry lookup per update.
+#[derive(Debug, Clone, Default)]
+pub struct Counter(Arc<AtomicU64>);
+
+impl Counter {
+    /// Add one.
+    pub fn inc(&self) {
+        self.add(1);
+    }
+
+    /// Add `n`.
+    pub fn add(&self, n: u64) {
+        self.0.fetch_add(n, Ordering::Relaxed);
+    }
+
+    /// Current value.
+    pub fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Point-in-time value handle (queue depth, active sessions, …).
+#[derive(Debug, Clone, Default)]
+pub struct Gauge(Arc<AtomicU64>);
+
+impl Gauge {
+    /// Set the current value.
+    pub fn set(&self, value: f64) {
+        self.0.store(value.to_bits(), Ordering::Relaxed);
+    }
+
+    /// Current value.
+    pub fn get(&self) -> f64 {
+        f64::from_bits(self.0.load(Ordering::Relaxed))
+    }
+}
+
+impl SensorMetrics {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The counter registered as `name`, registering it (at 0) on first use.
+    pub fn counter(&self, name: &str) -> Counter {
+        if let Some(c) = self.read().counters.get(name) {
+            return c.clone();
+        }
+        self.write()
+            .counters
+            .entry(name.to_string())
+            .or_default()
+            .clone()
+    }
+
+    /// The gauge registered as `name`, registering it (at 0.0) on first use.
+    pub fn gauge(&self, name: &str) -> Gauge {
+        if let Some(g) = self.read().gauges.get(name) {
+            return g.clone();
+        }
+        self.write()
+            .gauges
+            .entry(name.to_string())
+            .or_default()
+            .clone()
+    }
+
+    /// Current counter values, by name.
+    pub fn counters(&self) -> BTreeMap<String, u64> {
+        self.read()
+            .counters
+            .iter()
+            .map(|(name, c)| (name.clone(), c.get()))
+            .collect()
+    }
+
+    /// Current gauge values, by name.
+    pub fn gauges(&self) -> BTreeMap<String, f64> {
+        self.read()
+            .gauges
+            .iter()
+            .map(|(name, g)| (name.clone(), g.get()))
+            .collect()
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, Registry> {
+        self.inner.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, Registry> {
+        self.inner.write().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SensorHealth;
+
+    #[test]
+    fn registered_counter_shows_in_health_snapshot() {
+        let health = SensorHealth::new("netflow");
+        let flows = health.metrics().counter("flows_received");
+        let depth = health.metrics().gauge("queue_depth");
+
+        flows.inc();
+        flows.add(4);
+        depth.set(12.5);
+
+        let snapshot = health.snapshot();
+        assert_eq!(snapshot.counters.get("flows_received"), Some(&5));
+        assert_eq!(snapshot.gauges.get("queue_depth"), Some(&12.5));
+
+        // Same name, same counter.
+        health.metrics().counter("flows_received").inc();
+        assert_eq!(health.snapshot().counters["flows_received"], 6);
+    }
+
+    #[test]
+    fn empty_registry_stays_out_of_the_wire_format() {
+        let json = serde_json::to_value(SensorHealth::new("snmp").snapshot()).unwrap();
+        assert!(json.get("counters").is_none());
+        assert!(json.get("gauges").is_none());
+    }
+}
@@ -0,0 +1,125 @@
+//! Selective re-publish of telemetry under an alternate key prefix (#447).
+//!
+//! For A/B pipelines, a [`Publisher`] with mirror rules also puts every
+//! telemetry point whose key matches a rule under the rule's `prefix`, which
+//! replaces the leading `zensight` segment:
+//!
+//! ```json5
+//! publish_mirror: [
+//!   // zensight/snmp/core-1/if/1/ifInOctets → staging/zensight/snmp/core-1/if/1/ifInOctets
+//!   { key: "zensight/snmp/core-*/**", prefix: "staging/zensight" },
+//! ],
+//! ```
+//!
+//! [`Publisher`]: crate::Publisher
+
+use serde::{Deserialize, Serialize};
+use zenoh::key_expr::keyexpr;
+
+use zensight_common::KEY_PREFIX;
+
+use crate::error::{Result, SensorError};
+
+/// One mirror: telemetry keys included in `key` are re-published under
+/// `prefix`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MirrorRule {
+    /// Zenoh key expression (`*`, `**`) selecting the keys to mirror.
+    pub key: String,
+
+    /// Replaces the key's leading `zensight` segment.
+    pub prefix: String,
+}
+
+impl MirrorRule {
+    /// The mirrored key for `key`, or `None` when the rule doesn't match.
+    pub fn mirror_key(&self, key: &str) -> Option<String> {
+        let (Ok(pattern), Ok(candidate)) = (keyexpr::new(self.key.as_str()), keyexpr::new(key))
+        else {
+            return None;
+        };
+        if !pattern.includes(candidate) {
+            return None;
+        }
+        let rest = key
+            .strip_prefix(KEY_PREFIX)
+            .and_then(|r| r.strip_prefix('/'))
+            .unwrap_or(key);
+        Some(format!("{}/{}", self.prefix.trim_end_matches('/'), rest))
+    }
+
+    /// Reject patterns Zenoh wouldn't accept and prefixes that aren't a
+    /// concrete key, so a typo doesn't silently never mirror.
+    pub fn validate(&self) -> Result<()> {
+        keyexpr::new(self.key.as_str())
+            .map_err(|e| SensorError::config(format!("publish_mirror key `{}`: {e}", self.key)))?;
+        let prefix = self.prefix.trim_end_matches('/');
+        match keyexpr::new(prefix) {
+            Ok(k) if !k.is_wild() => Ok(()),
+            Ok(_) => Err(SensorError::config(format!(
+                "publish_mirror prefix `{prefix}` must not contain wildcards"
+            ))),
+            Err(e) => Err(SensorError::config(format!(
+                "publish_mirror prefix `{prefix}`: {e}"
+            ))),
+        }
+    }
+}
+
+/// Mirrored keys for `key` under every matching rule, without duplicates or
+/// the primary key itself.
+pub(crate) fn mirror_keys(rules: &[MirrorRule], key: &str) -> Vec<String> {
+    let mut keys: Vec<String> = Vec::new();
+    for mirrored in rules.iter().filter_map(|r| r.mirror_key(key)) {
+        if mirrored != key && !keys.contains(&mirrored) {
+            keys.push(mirrored);
+        }
+    }
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(key: &str, prefix: &str) -> MirrorRule {
+        MirrorRule {
+            key: key.into(),
+            prefix: prefix.into(),
+        }
+    }
+
+    #[test]
+    fn matching_keys_get_the_alternate_prefix() {
+        let r = rule("zensight/snmp/core-*/**", "staging/zensight");
+        assert_eq!(
+            r.mirror_key("zensight/snmp/core-1/if/1/ifInOctets")
+                .as_deref(),
+            Some("staging/zensight/snmp/core-1/if/1/ifInOctets")
+        );
+        assert_eq!(r.mirror_key("zensight/snmp/edge-1/if/1/ifInOctets"), None);
+        assert_eq!(r.mirror_key("zensight/netflow/core-1/flows"), None);
+    }
+
+    #[test]
+    fn mirror_keys_skip_duplicates_and_the_primary() {
+        let rules = [
+            rule("zensight/snmp/**", "b"),
+            rule("zensight/**", "b/"),
+            rule("zensight/**", "zensight"),
+        ];
+        assert_eq!(
+            mirror_keys(&rules, "zensight/snmp/r1/cpu"),
+            vec!["b/snmp/r1/cpu"]
+        );
+    }
+
+    #[test]
+    fn validate_rejects_bad_patterns_and_wild_prefixes() {
+        rule("zensight/snmp/**", "staging/zensight")
+            .validate()
+            .unwrap();
+        assert!(rule("zensight//snmp", "b").validate().is_err());
+        assert!(rule("zensight/**", "b/*").validate().is_err());
+    }
+}
@@ -1,11 +1,16 @@
 //! Telemetry publisher for Zenoh.
 
 use std::sync::Arc;
+use std::time::Duration;
 
-use zensight_common::{Format, TelemetryPoint};
+use zensight_common::{Format, SampleMetadata, TelemetryPoint, encode};
 
 use crate::advanced_publisher::{AdvancedPublisherConfig, AdvancedPublisherRegistry};
 use crate::error::{Result, SensorError};
+use crate::metrics::SensorMetrics;
+use crate::mirror::{MirrorRule, mirror_keys};
+use crate::qos::{PublishOptions, PublishQosConfig, QosPutter};
+use crate::retry::{Pending, PublishRetryConfig, RetryQueue, Route};
 
 /// Publisher for sending telemetry to Zenoh.
 ///
@@ -16,9 +21,25 @@ use crate::error::{Result, SensorError};
 /// through [`AdvancedPublisherRegistry`] zenoh-ext **advanced** publishers
 /// (per-key cache + miss/publisher detection) so it always matches the GUI's
 /// `AdvancedSubscriber` on `zensight/**` — that pairing is required for reliable
-/// delivery + late-joiner history/recovery. **Control-plane** writes
+/// delivery + late-joiner history/recovery. One-off **events**
+/// (`publish_event`, for keys unique to a log line or flow) are plain puts, so
+/// they don't declare a publisher per key. **Control-plane** writes
 /// (`publish_raw` / `publish_json` / `delete`, for `@/…` keys the GUI reads with
 /// a plain subscriber) stay plain `put`/`delete`.
+///
+/// Every put carries the congestion control and priority that
+/// [`PublishQosConfig`] maps its key to (#410); advanced publishers get theirs
+/// when declared, since Zenoh fixes QoS per publisher.
+///
+/// With [`with_retry`](Self::with_retry), failed puts are queued and replayed
+/// by [`flush_retries`](Self::flush_retries) instead of being lost (#412).
+///
+/// With [`with_metadata`](Self::with_metadata), every telemetry sample carries
+/// a [`SampleMetadata`] attachment naming the bridge, its version and the
+/// payload encoding (#444).
+///
+/// With [`with_mirrors`](Self::with_mirrors), telemetry matching a
+/// [`MirrorRule`] is also published under the rule's alternate prefix (#447).
 #[derive(Clone, Debug)]
 pub struct Publisher {
     session: Arc<zenoh::Session>,
@@ -27,6 +48,15 @@ pub struct Publisher {
     /// Shared advanced-publisher registry backing the telemetry path. Shared
     /// across clones so the per-key publisher cache persists.
     registry: Arc<AdvancedPublisherRegistry>,
+    /// Control-plane puts, with QoS applied.
+    putter: QosPutter,
+    /// Failed publishes awaiting a retry, shared across clones. `None` when
+    /// retry is disabled.
+    retry: Option<Arc<RetryQueue>>,
+    /// Provenance attached to telemetry samples, if enabled.
+    metadata: Option<SampleMetadata>,
+    /// Alternate prefixes matching telemetry is re-published under.
+    mirrors: Vec<MirrorRule>,
 }
 
 impl Publisher {
@@ -43,14 +73,166 @@ impl Publisher {
             format,
             AdvancedPublisherConfig::default(),
         ));
+        let putter = QosPutter::new(session.clone(), PublishQosConfig::default());
         Self {
             session,
             key_prefix,
             format,
             registry,
+            putter,
+            retry: None,
+            metadata: None,
+            mirrors: Vec::new(),
+        }
+    }
+
+    /// Apply a QoS mapping to every put from this publisher.
+    ///
+    /// Call before publishing: the advanced-publisher cache is rebuilt.
+    pub fn with_qos(mut self, qos: PublishQosConfig) -> Self {
+        self.putter = QosPutter::new(self.session.clone(), qos);
+        self.rebuild_registry();
+        self
+    }
+
+    /// Attach provenance to every telemetry sample (#444): `bridge` and
+    /// `version`, plus this publisher's encoding.
+    ///
+    /// Call before publishing: the advanced-publisher cache is rebuilt.
+    pub fn with_metadata(mut self, bridge: impl Into<String>, version: impl Into<String>) -> Self {
+        self.metadata = Some(SampleMetadata::new(bridge, version, self.format));
+        self.rebuild_registry();
+        self
+    }
+
+    /// Also publish telemetry matching any of `rules` under its alternate
+    /// prefix (#447).
+    pub fn with_mirrors(mut self, rules: Vec<MirrorRule>) -> Self {
+        self.mirrors = rules;
+        self
+    }
+
+    /// The mirror rules applied to telemetry.
+    pub fn mirrors(&self) -> &[MirrorRule] {
+        &self.mirrors
+    }
+
+    /// The provenance attached to telemetry samples, if enabled.
+    pub fn metadata(&self) -> Option<&SampleMetadata> {
+        self.metadata.as_ref()
+    }
+
+    /// Re-declare the advanced-publisher registry with the current QoS and
+    /// attachment.
+    fn rebuild_registry(&mut self) {
+        self.registry = Arc::new(
+            AdvancedPublisherRegistry::new(
+                self.session.clone(),
+                self.key_prefix.clone(),
+                self.format,
+                AdvancedPublisherConfig::default(),
+            )
+            .with_qos(self.putter.qos().clone())
+            .with_attachment(self.metadata.as_ref().map(SampleMetadata::to_attachment)),
+        );
+    }
+
+    /// Queue failed publishes for retry, per `config`. Queue depth, drops and
+    /// replays are recorded in `metrics`. No-op when `config` is disabled.
+    pub fn with_retry(mut self, config: &PublishRetryConfig, metrics: &SensorMetrics) -> Self {
+        self.retry = config
+            .enabled
+            .then(|| Arc::new(RetryQueue::new(config, metrics)));
+        self
+    }
+
+    /// Share `other`'s retry queue, e.g. when rebuilding a publisher with a
+    /// different format or prefix.
+    pub(crate) fn with_retry_from(mut self, other: &Publisher) -> Self {
+        self.retry = other.retry.clone();
+        self
+    }
+
+    /// How often [`flush_retries`](Self::flush_retries) should run, or `None`
+    /// when retry is disabled.
+    pub fn retry_interval(&self) -> Option<Duration> {
+        self.retry.as_ref().map(|q| q.interval())
+    }
+
+    /// Replay queued publishes, oldest first, until one fails again. Returns
+    /// how many went out.
+    pub async fn flush_retries(&self) -> usize {
+        match &self.retry {
+            Some(queue) => queue.drain(|pending| self.try_send(pending)).await,
+            None => 0,
+        }
+    }
+
+    /// Replay queued publishes until none are left or `window` runs out
+    /// (#461). The runner calls this on shutdown, before closing the session.
+    /// Returns how many went out.
+    pub async fn drain_retries(&self, window: Duration) -> usize {
+        match &self.retry {
+            Some(queue) => {
+                queue
+                    .drain_within(window, |pending| self.try_send(pending))
+                    .await
+            }
+            None => 0,
+        }
+    }
+
+    /// Publishes waiting for a retry.
+    pub fn queued_retries(&self) -> usize {
+        self.retry.as_ref().map_or(0, |q| q.len())
+    }
+
+    /// One attempt at `pending`, handing it back on failure.
+    async fn try_send(&self, pending: Pending) -> std::result::Result<(), Pending> {
+        match self.send(&pending).await {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                tracing::debug!(key = %pending.key, error = %e, "Publish failed; queued for retry");
+                Err(pending)
+            }
         }
     }
 
+    async fn send(&self, pending: &Pending) -> Result<()> {
+        let payload = pending.payload.clone();
+        match pending.route {
+            Route::Telemetry => self.registry.put_encoded(&pending.key, payload).await,
+            Route::Event => {
+                let attachment = self.metadata.as_ref().map(SampleMetadata::to_attachment);
+                self.putter
+                    .put_attached(&pending.key, payload, attachment)
+                    .await
+            }
+            Route::Raw(Some(options)) => self.putter.put_with(&pending.key, payload, options).await,
+            Route::Raw(None) => self.putter.put(&pending.key, payload).await,
+        }
+    }
+
+    /// Send `pending`. With retry enabled, anything already queued is
+    /// replayed first and `pending` waits behind it if that fails, so
+    /// per-key order survives an outage; a failed send is queued.
+    async fn send_or_queue(&self, pending: Pending) -> Result<()> {
+        match &self.retry {
+            Some(queue) => {
+                queue
+                    .send_in_order(pending, |pending| self.try_send(pending))
+                    .await;
+                Ok(())
+            }
+            None => self.send(&pending).await,
+        }
+    }
+
+    /// The QoS mapping applied to puts.
+    pub fn qos(&self) -> &PublishQosConfig {
+        self.putter.qos()
+    }
+
     /// Get the key prefix.
     pub fn key_prefix(&self) -> &str {
         &self.key_prefix
@@ -83,13 +265,61 @@ impl Publisher {
     ///
     /// The key is constructed by appending `key_suffix` to the publisher's prefix.
     pub async fn publish(&self, key_suffix: &str, point: &TelemetryPoint) -> Result<()> {
-        self.registry.publish(key_suffix, point).await
+        self.publish_to_key(&self.build_key(key_suffix), point)
+            .await
     }
 
     /// Publish a telemetry point with a full key (not using prefix), via an
     /// advanced publisher.
+    ///
+    /// Mirrored keys (#447) are published too; a failed mirror is logged and
+    /// doesn't fail the primary publish.
     pub async fn publish_to_key(&self, key: &str, point: &TelemetryPoint) -> Result<()> {
-        self.registry.publish_to_key(key, point).await
+        self.publish_routed(key, point, Route::Telemetry).await
+    }
+
+    /// Publish a one-off telemetry event (a log line, a flow record) to a full
+    /// key with a plain put. Such keys are rarely reused, so declaring an
+    /// advanced publisher for each would only grow the registry.
+    ///
+    /// Sample metadata, mirrors and retry apply as for
+    /// [`publish_to_key`](Self::publish_to_key).
+    pub async fn publish_event(&self, key: &str, point: &TelemetryPoint) -> Result<()> {
+        self.publish_routed(key, point, Route::Event).await
+    }
+
+    /// Encode `point` and send it to `key` and its mirrors over `route`.
+    ///
+    /// This is where every bridge's values are validated (#458): a NaN or
+    /// infinite gauge or a malformed histogram is rejected with
+    /// [`SensorError::InvalidValue`] and nothing is sent.
+    async fn publish_routed(&self, key: &str, point: &TelemetryPoint, route: Route) -> Result<()> {
+        point
+            .value
+            .validate()
+            .map_err(|source| SensorError::InvalidValue {
+                key: key.to_string(),
+                source,
+            })?;
+        let payload =
+            encode(point, self.format).map_err(|e| SensorError::Serialization(e.to_string()))?;
+        for mirror in mirror_keys(&self.mirrors, key) {
+            let pending = Pending {
+                key: mirror,
+                payload: payload.clone(),
+                route,
+            };
+            let mirror = pending.key.clone();
+            if let Err(e) = self.send_or_queue(pending).await {
+                tracing::warn!(key = %mirror, error = %e, "Failed to publish telemetry mirror");
+            }
+        }
+        self.send_or_queue(Pending {
+            key: key.to_string(),
+            payload,
+            route,
+        })
+        .await
     }
 
     /// Publish a batch of telemetry points.
@@ -115,16 +345,31 @@ impl Publisher {
     }
 
     /// Publish raw bytes to a key (for status messages, etc.).
+    ///
+    /// QoS comes from the configured mapping for `key`.
     pub async fn publish_raw(&self, key: &str, payload: Vec<u8>) -> Result<()> {
-        self.session
-            .put(key, payload)
-            .await
-            .map_err(|e| SensorError::Publish {
-                key: key.to_string(),
-                message: e.to_string(),
-            })?;
+        self.send_or_queue(Pending {
+            key: key.to_string(),
+            payload,
+            route: Route::Raw(None),
+        })
+        .await
+    }
 
-        Ok(())
+    /// Publish raw bytes to a key with explicit congestion control and
+    /// priority, ignoring the configured mapping.
+    pub async fn publish_raw_with(
+        &self,
+        key: &str,
+        payload: Vec<u8>,
+        options: PublishOptions,
+    ) -> Result<()> {
+        self.send_or_queue(Pending {
+            key: key.to_string(),
+            payload,
+            route: Route::Raw(Some(options)),
+        })
+        .await
     }
 
     /// Publish a JSON value to a key.
@@ -0,0 +1,383 @@
+//! Per-publish Zenoh QoS: congestion control and priority (#410).
+//!
+//! Not all telemetry is equally urgent. An alert transition should block
+//! rather than be dropped under congestion, while bulk counters can be
+//! dropped and yield to everything else. [`PublishQosConfig`] maps keys to
+//! [`PublishOptions`], by protocol or by key expression, and [`Publisher`]
+//! applies them to every put.
+//!
+//! ```json5
+//! publish_qos: {
+//!   default: { congestion_control: "drop", priority: "data" },
+//!   rules: [
+//!     { key: "zensight/*/@/alerts/**", congestion_control: "block", priority: "interactive_high" },
+//!     { protocol: "netflow", priority: "data_low" },
+//!   ],
+//! }
+//! ```
+//!
+//! [`Publisher`]: crate::Publisher
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use zenoh::key_expr::keyexpr;
+use zenoh::qos::{CongestionControl, Priority};
+
+use zensight_common::Protocol;
+
+use crate::error::{Result, SensorError};
+
+/// What a put does when the network can't keep up.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Congestion {
+    /// Wait for room; nothing is lost, but the publisher can stall.
+    Block,
+    /// Drop the sample (Zenoh's default for puts).
+    #[default]
+    Drop,
+}
+
+impl Congestion {
+    /// The Zenoh equivalent.
+    pub fn to_zenoh(self) -> CongestionControl {
+        match self {
+            Congestion::Block => CongestionControl::Block,
+            Congestion::Drop => CongestionControl::Drop,
+        }
+    }
+}
+
+/// Zenoh priority, highest first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PublishPriority {
+    RealTime,
+    InteractiveHigh,
+    InteractiveLow,
+    DataHigh,
+    /// Zenoh's default.
+    #[default]
+    Data,
+    DataLow,
+    Background,
+}
+
+impl PublishPriority {
+    /// The Zenoh equivalent.
+    pub fn to_zenoh(self) -> Priority {
+        match self {
+            PublishPriority::RealTime => Priority::RealTime,
+            PublishPriority::InteractiveHigh => Priority::InteractiveHigh,
+            PublishPriority::InteractiveLow => Priority::InteractiveLow,
+            PublishPriority::DataHigh => Priority::DataHigh,
+            PublishPriority::Data => Priority::Data,
+            PublishPriority::DataLow => Priority::DataLow,
+            PublishPriority::Background => Priority::Background,
+        }
+    }
+}
+
+/// QoS for one put.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublishOptions {
+    /// Block or drop under congestion.
+    #[serde(default)]
+    pub congestion_control: Congestion,
+
+    /// Zenoh priority.
+    #[serde(default)]
+    pub priority: PublishPriority,
+}
+
+impl PublishOptions {
+    /// Block under congestion, at interactive-high priority: for state that
+    /// must not be lost, such as alert transitions.
+    pub fn urgent() -> Self {
+        Self {
+            congestion_control: Congestion::Block,
+            priority: PublishPriority::InteractiveHigh,
+        }
+    }
+}
+
+/// One override in [`PublishQosConfig::rules`]. A rule applies when every
+/// matcher it sets matches; the options it sets replace the default.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QosRule {
+    /// Match keys under `zensight/<protocol>/`.
+    #[serde(default)]
+    pub protocol: Option<Protocol>,
+
+    /// Match keys included in this Zenoh key expression (`*`, `**`).
+    #[serde(default)]
+    pub key: Option<String>,
+
+    /// Congestion control for matching keys.
+    #[serde(default)]
+    pub congestion_control: Option<Congestion>,
+
+    /// Priority for matching keys.
+    #[serde(default)]
+    pub priority: Option<PublishPriority>,
+}
+
+impl QosRule {
+    fn matches(&self, key: &str) -> bool {
+        if let Some(protocol) = self.protocol
+            && key.split('/').nth(1) != Some(protocol.as_str())
+        {
+            return false;
+        }
+        match &self.key {
+            Some(pattern) => match (keyexpr::new(pattern.as_str()), keyexpr::new(key)) {
+                (Ok(pattern), Ok(key)) => pattern.includes(key),
+                _ => false,
+            },
+            None => true,
+        }
+    }
+}
+
+/// Publish QoS mapping for a sensor. Rules are tried in order; the first
+/// match wins, and keys no rule matches get `default`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublishQosConfig {
+    /// Options for keys no rule matches.
+    #[serde(default)]
+    pub default: PublishOptions,
+
+    /// Per-protocol / per-key-expression overrides.
+    #[serde(default)]
+    pub rules: Vec<QosRule>,
+}
+
+impl PublishQosConfig {
+    /// Options for a put to the full key `key`.
+    pub fn options_for(&self, key: &str) -> PublishOptions {
+        self.rule_options(key).unwrap_or(self.default)
+    }
+
+    /// Options from the first rule matching `key`, if any. Lets a caller with
+    /// its own default (alerts use [`PublishOptions::urgent`]) still honour
+    /// explicit rules.
+    pub fn rule_options(&self, key: &str) -> Option<PublishOptions> {
+        let rule = self.rules.iter().find(|r| r.matches(key))?;
+        Some(PublishOptions {
+            congestion_control: rule
+                .congestion_control
+                .unwrap_or(self.default.congestion_control),
+            priority: rule.priority.unwrap_or(self.default.priority),
+        })
+    }
+
+    /// Reject key patterns Zenoh wouldn't accept, so a typo doesn't silently
+    /// never match.
+    pub fn validate(&self) -> Result<()> {
+        for rule in &self.rules {
+            if let Some(pattern) = &rule.key {
+                keyexpr::new(pattern.as_str()).map_err(|e| {
+                    SensorError::config(format!("publish_qos rule key `{pattern}`: {e}"))
+                })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Boxed future returned by [`PutSink::put`].
+pub(crate) type PutFuture<'a> = Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>;
+
+/// The `put` half of a Zenoh session. [`Publisher`](crate::Publisher) puts
+/// through this so tests can observe the QoS each put carried.
+pub(crate) trait PutSink: Send + Sync {
+    fn put<'a>(
+        &'a self,
+        key: &'a str,
+        payload: Vec<u8>,
+        options: PublishOptions,
+        attachment: Option<Vec<u8>>,
+    ) -> PutFuture<'a>;
+}
+
+impl PutSink for zenoh::Session {
+    fn put<'a>(
+        &'a self,
+        key: &'a str,
+        payload: Vec<u8>,
+        options: PublishOptions,
+        attachment: Option<Vec<u8>>,
+    ) -> PutFuture<'a> {
+        Box::pin(async move {
+            let put = zenoh::Session::put(self, key, payload)
+                .congestion_control(options.congestion_control.to_zenoh())
+                .priority(options.priority.to_zenoh());
+            match attachment {
+                Some(attachment) => put.attachment(attachment).await,
+                None => put.await,
+            }
+            .map_err(|e| SensorError::Publish {
+                key: key.to_string(),
+                message: e.to_string(),
+            })
+        })
+    }
+}
+
+/// Puts raw payloads with the QoS [`PublishQosConfig`] maps their key to.
+#[derive(Clone)]
+pub(crate) struct QosPutter {
+    sink: Arc<dyn PutSink>,
+    qos: PublishQosConfig,
+}
+
+impl std::fmt::Debug for QosPutter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QosPutter")
+            .field("qos", &self.qos)
+            .finish_non_exhaustive()
+    }
+}
+
+impl QosPutter {
+    pub(crate) fn new(sink: Arc<dyn PutSink>, qos: PublishQosConfig) -> Self {
+        Self { sink, qos }
+    }
+
+    pub(crate) fn qos(&self) -> &PublishQosConfig {
+        &self.qos
+    }
+
+    /// Put with the configured options for `key`.
+    pub(crate) async fn put(&self, key: &str, payload: Vec<u8>) -> Result<()> {
+        self.put_with(key, payload, self.qos.options_for(key)).await
+    }
+
+    /// Put with explicit options, ignoring the mapping.
+    pub(crate) async fn put_with(
+        &self,
+        key: &str,
+        payload: Vec<u8>,
+        options: PublishOptions,
+    ) -> Result<()> {
+        self.sink.put(key, payload, options, None).await
+    }
+
+    /// Put with the configured options for `key`, carrying `attachment`
+    /// (#444).
+    pub(crate) async fn put_attached(
+        &self,
+        key: &str,
+        payload: Vec<u8>,
+        attachment: Option<Vec<u8>>,
+    ) -> Result<()> {
+        self.sink
+            .put(key, payload, self.qos.options_for(key), attachment)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    /// Records every put instead of sending it.
+    #[derive(Default)]
+    struct PutSpy {
+        puts: Mutex<Vec<(String, PublishOptions)>>,
+    }
+
+    impl PutSink for PutSpy {
+        fn put<'a>(
+            &'a self,
+            key: &'a str,
+            _payload: Vec<u8>,
+            options: PublishOptions,
+            _attachment: Option<Vec<u8>>,
+        ) -> PutFuture<'a> {
+            self.puts.lock().unwrap().push((key.to_string(), options));
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    fn config() -> PublishQosConfig {
+        json5::from_str(
+            r#"{
+                rules: [
+                    { key: "zensight/*/@/alerts/**", congestion_control: "block", priority: "interactive_high" },
+                    { protocol: "netflow", priority: "data_low" },
+                ],
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn rules_match_by_key_expression_then_protocol() {
+        let qos = config();
+        qos.validate().unwrap();
+
+        assert_eq!(
+            qos.options_for("zensight/snmp/@/alerts/router1/cpu"),
+            PublishOptions::urgent()
+        );
+        assert_eq!(
+            qos.options_for("zensight/netflow/10.0.0.1/flows"),
+            PublishOptions {
+                congestion_control: Congestion::Drop,
+                priority: PublishPriority::DataLow,
+            }
+        );
+        assert_eq!(
+            qos.options_for("zensight/snmp/router1/ifInOctets"),
+            PublishOptions::default()
+        );
+    }
+
+    #[tokio::test]
+    async fn configured_qos_is_applied_to_the_put() {
+        let spy = Arc::new(PutSpy::default());
+        let putter = QosPutter::new(spy.clone(), config());
+
+        putter
+            .put("zensight/snmp/@/alerts/router1/cpu", b"{}".to_vec())
+            .await
+            .unwrap();
+        putter
+            .put("zensight/netflow/10.0.0.1/flows", b"{}".to_vec())
+            .await
+            .unwrap();
+        putter
+            .put_with(
+                "zensight/snmp/router1/ifInOctets",
+                b"{}".to_vec(),
+                PublishOptions::urgent(),
+            )
+            .await
+            .unwrap();
+
+        let puts = spy.puts.lock().unwrap();
+        assert_eq!(puts[0].1, PublishOptions::urgent());
+        assert_eq!(puts[1].1.priority, PublishPriority::DataLow);
+        assert_eq!(puts[1].1.congestion_control, Congestion::Drop);
+        // Explicit options bypass the mapping.
+        assert_eq!(puts[2].1, PublishOptions::urgent());
+    }
+
+    #[test]
+    fn invalid_key_pattern_is_rejected() {
+        let qos = PublishQosConfig {
+            rules: vec![QosRule {
+                key: Some("zensight//bad".to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        assert!(qos.validate().is_err());
+    }
+}
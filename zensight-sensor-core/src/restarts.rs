@@ -0,0 +1,175 @@
+//! Process start tracking across restarts (#435).
+//!
+//! `uptime_secs` resets on every start, so on its own it can't tell a healthy
+//! bridge from one systemd keeps restarting. Each start is recorded in a small
+//! JSON state file (`<state dir>/<sensor>.restarts.json`); the counts are
+//! surfaced in the [`HealthSnapshot`](crate::HealthSnapshot) so the frontend can
+//! flag crash-looping bridges.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// Start timestamps kept for the "recent restarts" window.
+const MAX_RECENT_STARTS: usize = 16;
+
+/// Window for [`RestartState::restarts_within`] as reported in health.
+pub const RESTART_WINDOW_MS: i64 = 60 * 60 * 1000;
+
+/// Persisted start history for one sensor.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RestartState {
+    /// Number of times the process has started, this one included.
+    pub start_count: u64,
+    /// When the current process started (millis since epoch).
+    pub last_start_ms: i64,
+    /// Most recent start times, oldest first (bounded).
+    #[serde(default)]
+    pub recent_starts: Vec<i64>,
+}
+
+impl RestartState {
+    /// Record a process start at `now_ms` in the state file at `path`.
+    ///
+    /// A missing or unreadable file starts a fresh history rather than
+    /// failing; only the write can error.
+    pub fn record_start(path: &Path, now_ms: i64) -> std::io::Result<Self> {
+        let mut state: Self = std::fs::read_to_string(path)
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+
+        state.start_count += 1;
+        state.last_start_ms = now_ms;
+        state.recent_starts.push(now_ms);
+        if state.recent_starts.len() > MAX_RECENT_STARTS {
+            let excess = state.recent_starts.len() - MAX_RECENT_STARTS;
+            state.recent_starts.drain(..excess);
+        }
+
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string(&state).map_err(std::io::Error::other)?;
+        std::fs::write(path, json)?;
+        Ok(state)
+    }
+
+    /// Restarts (starts after the first) in the current process's history.
+    pub fn restart_count(&self) -> u64 {
+        self.start_count.saturating_sub(1)
+    }
+
+    /// Restarts that happened within `window_ms` before `now_ms`. The first
+    /// start in the history is not a restart, so it isn't counted.
+    pub fn restarts_within(&self, window_ms: i64, now_ms: i64) -> u64 {
+        let recent = self
+            .recent_starts
+            .iter()
+            .filter(|&&t| now_ms - t <= window_ms)
+            .count() as u64;
+        recent.min(self.restart_count())
+    }
+}
+
+/// Where `sensor`'s restart state lives: systemd's `$STATE_DIRECTORY`, else
+/// `$XDG_STATE_HOME/zensight`, else `~/.local/state/zensight`. `None` when no
+/// candidate is available (tracking is then skipped).
+pub fn state_path(sensor: &str) -> Option<PathBuf> {
+    state_path_from(sensor, |k| std::env::var_os(k).map(PathBuf::from))
+}
+
+fn state_path_from(sensor: &str, var: impl Fn(&str) -> Option<PathBuf>) -> Option<PathBuf> {
+    let dir = var("STATE_DIRECTORY")
+        // systemd passes a colon-separated list when several are configured.
+        .and_then(|dirs| {
+            dirs.to_str()
+                .and_then(|s| s.split(':').next())
+                .map(PathBuf::from)
+        })
+        .or_else(|| var("XDG_STATE_HOME").map(|d| d.join("zensight")))
+        .or_else(|| var("HOME").map(|d| d.join(".local/state/zensight")))?;
+    Some(dir.join(format!("{sensor}.restarts.json")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SensorHealth;
+
+    #[test]
+    fn restart_counter_increments_across_starts_and_reaches_the_snapshot() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("snmp.restarts.json");
+        let hour = RESTART_WINDOW_MS;
+
+        // Three simulated process starts: one long ago, two recent.
+        let first = RestartState::record_start(&path, 0).unwrap();
+        assert_eq!(first.start_count, 1);
+        assert_eq!(first.restart_count(), 0);
+
+        RestartState::record_start(&path, 10 * hour).unwrap();
+        let third = RestartState::record_start(&path, 10 * hour + 60_000).unwrap();
+        assert_eq!(third.start_count, 3);
+        assert_eq!(third.restart_count(), 2);
+        assert_eq!(third.last_start_ms, 10 * hour + 60_000);
+        assert_eq!(third.restarts_within(hour, 10 * hour + 120_000), 2);
+
+        let health = SensorHealth::new("snmp").with_restart_state(third);
+        let snap = health.snapshot();
+        assert_eq!(snap.restart_count, 2);
+        assert_eq!(snap.last_start_ms, Some(10 * hour + 60_000));
+    }
+
+    #[test]
+    fn corrupt_state_file_starts_fresh() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested/logs.restarts.json");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "not json").unwrap();
+
+        let state = RestartState::record_start(&path, 5).unwrap();
+        assert_eq!(state.start_count, 1);
+        assert_eq!(state.recent_starts, vec![5]);
+    }
+
+    #[test]
+    fn recent_starts_are_bounded() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("x.restarts.json");
+        let mut state = RestartState::default();
+        for t in 0..40 {
+            state = RestartState::record_start(&path, t).unwrap();
+        }
+        assert_eq!(state.start_count, 40);
+        assert_eq!(state.recent_starts.len(), MAX_RECENT_STARTS);
+        assert_eq!(state.recent_starts[0], 40 - MAX_RECENT_STARTS as i64);
+    }
+
+    #[test]
+    fn state_path_prefers_systemd_state_directory() {
+        let env = |pairs: &'static [(&'static str, &'static str)]| {
+            move |k: &str| {
+                pairs
+                    .iter()
+                    .find(|(name, _)| *name == k)
+                    .map(|(_, v)| PathBuf::from(v))
+            }
+        };
+        assert_eq!(
+            state_path_from(
+                "snmp",
+                env(&[
+                    ("STATE_DIRECTORY", "/var/lib/zensight:/other"),
+                    ("HOME", "/h")
+                ])
+            ),
+            Some(PathBuf::from("/var/lib/zensight/snmp.restarts.json"))
+        );
+        assert_eq!(
+            state_path_from("snmp", env(&[("HOME", "/h")])),
+            Some(PathBuf::from("/h/.local/state/zensight/snmp.restarts.json"))
+        );
+        assert_eq!(state_path_from("snmp", env(&[])), None);
+    }
+}
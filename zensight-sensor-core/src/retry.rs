@@ -0,0 +1,349 @@
+//! Bounded retry queue for failed publishes (#412).
+//!
+//! When a put fails — typically a brief Zenoh session hiccup — a
+//! [`Publisher`](crate::Publisher) with retry enabled parks the encoded
+//! payload here instead of dropping it, and the runner replays the queue on a
+//! timer. New publishes replay the queue first and wait behind it while it
+//! is non-empty, so each key's values arrive in order. The queue is bounded: when full, the oldest entry goes, so a long
+//! outage costs the stalest data first. Queue depth, drops and replays are
+//! reported through [`SensorMetrics`]. On shutdown the runner drains what is
+//! left for a bounded window before closing the session (#461).
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::metrics::{Counter, Gauge, SensorMetrics};
+use crate::qos::PublishOptions;
+
+/// Retry settings for a sensor's publisher. Disabled by default.
+///
+/// ```json5
+/// publish_retry: { enabled: true, max_queued: 1000, retry_interval_ms: 1000 },
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublishRetryConfig {
+    /// Queue failed publishes for retry instead of dropping them.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Most payloads held at once; the oldest is dropped to make room.
+    #[serde(default = "default_max_queued")]
+    pub max_queued: usize,
+
+    /// How often the queue is replayed.
+    #[serde(default = "default_retry_interval_ms")]
+    pub retry_interval_ms: u64,
+}
+
+fn default_max_queued() -> usize {
+    1000
+}
+
+fn default_retry_interval_ms() -> u64 {
+    1000
+}
+
+impl Default for PublishRetryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_queued: default_max_queued(),
+            retry_interval_ms: default_retry_interval_ms(),
+        }
+    }
+}
+
+/// Which publish path a queued payload goes back through.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Route {
+    /// The advanced-publisher telemetry path.
+    Telemetry,
+    /// A plain put of a one-off telemetry event, with the sample attachment.
+    Event,
+    /// A plain put, with explicit options or (`None`) the configured mapping.
+    Raw(Option<PublishOptions>),
+}
+
+/// An encoded publish waiting for a retry.
+#[derive(Debug)]
+pub(crate) struct Pending {
+    pub(crate) key: String,
+    pub(crate) payload: Vec<u8>,
+    pub(crate) route: Route,
+}
+
+/// FIFO of failed publishes, capped at `max_queued`.
+#[derive(Debug)]
+pub(crate) struct RetryQueue {
+    pending: Mutex<VecDeque<Pending>>,
+    max_queued: usize,
+    interval: Duration,
+    queued: Gauge,
+    dropped: Counter,
+    replayed: Counter,
+}
+
+impl RetryQueue {
+    pub(crate) fn new(config: &PublishRetryConfig, metrics: &SensorMetrics) -> Self {
+        Self {
+            pending: Mutex::new(VecDeque::new()),
+            max_queued: config.max_queued,
+            interval: Duration::from_millis(config.retry_interval_ms.max(1)),
+            queued: metrics.gauge("publish_retry_queued"),
+            dropped: metrics.counter("publish_retry_dropped"),
+            replayed: metrics.counter("publish_retry_replayed"),
+        }
+    }
+
+    pub(crate) fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, VecDeque<Pending>> {
+        self.pending.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Queue a failed publish, dropping the oldest entries to stay in bounds.
+    pub(crate) fn push(&self, pending: Pending) {
+        let mut queue = self.lock();
+        queue.push_back(pending);
+        while queue.len() > self.max_queued {
+            queue.pop_front();
+            self.dropped.inc();
+        }
+        self.queued.set(queue.len() as f64);
+    }
+
+    /// Replay queued publishes oldest-first through `send`, stopping at the
+    /// first failure (which goes back to the head of the queue). `send` hands
+    /// the entry back on failure. Returns how many were delivered.
+    pub(crate) async fn drain<F, Fut>(&self, mut send: F) -> usize
+    where
+        F: FnMut(Pending) -> Fut,
+        Fut: Future<Output = std::result::Result<(), Pending>>,
+    {
+        let mut delivered = 0;
+        loop {
+            // Never hold the lock across the send.
+            let Some(next) = self.lock().pop_front() else {
+                break;
+            };
+            match send(next).await {
+                Ok(()) => {
+                    delivered += 1;
+                    self.replayed.inc();
+                }
+                Err(failed) => {
+                    let mut queue = self.lock();
+                    // Publishes that failed during the send may have filled
+                    // the queue; the returned entry is the oldest, so it goes.
+                    if queue.len() < self.max_queued {
+                        queue.push_front(failed);
+                    } else {
+                        self.dropped.inc();
+                    }
+                    break;
+                }
+            }
+        }
+        self.queued.set(self.lock().len() as f64);
+        delivered
+    }
+
+    /// Send `pending` behind anything already queued: replay the queue
+    /// first and, if entries are still waiting, queue `pending` after them
+    /// rather than sending it, so a key's values never go out of order.
+    /// A failed send is queued too. Returns whether `pending` went out now.
+    pub(crate) async fn send_in_order<F, Fut>(&self, pending: Pending, mut send: F) -> bool
+    where
+        F: FnMut(Pending) -> Fut,
+        Fut: Future<Output = std::result::Result<(), Pending>>,
+    {
+        if self.len() > 0 {
+            self.drain(&mut send).await;
+            if self.len() > 0 {
+                self.push(pending);
+                return false;
+            }
+        }
+        match send(pending).await {
+            Ok(()) => true,
+            Err(failed) => {
+                self.push(failed);
+                false
+            }
+        }
+    }
+
+    /// Keep replaying until the queue is empty or `window` has passed,
+    /// pausing one retry interval after each failed round. Returns how many
+    /// were delivered.
+    pub(crate) async fn drain_within<F, Fut>(&self, window: Duration, mut send: F) -> usize
+    where
+        F: FnMut(Pending) -> Fut,
+        Fut: Future<Output = std::result::Result<(), Pending>>,
+    {
+        let deadline = tokio::time::Instant::now() + window;
+        let mut delivered = 0;
+        loop {
+            delivered += self.drain(&mut send).await;
+            let now = tokio::time::Instant::now();
+            if self.len() == 0 || now >= deadline {
+                break;
+            }
+            tokio::time::sleep(self.interval.min(deadline - now)).await;
+        }
+        delivered
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.lock().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use super::*;
+
+    fn pending(key: &str) -> Pending {
+        Pending {
+            key: key.to_string(),
+            payload: b"{}".to_vec(),
+            route: Route::Telemetry,
+        }
+    }
+
+    #[tokio::test]
+    async fn failed_publishes_replay_after_recovery_up_to_cap() {
+        let metrics = SensorMetrics::new();
+        let config = PublishRetryConfig {
+            enabled: true,
+            max_queued: 3,
+            retry_interval_ms: 10,
+        };
+        let queue = RetryQueue::new(&config, &metrics);
+        let connected = AtomicBool::new(false);
+        let delivered = Mutex::new(Vec::new());
+        let send = |p: Pending| {
+            let ok = connected.load(Ordering::SeqCst);
+            let delivered = &delivered;
+            async move {
+                if ok {
+                    delivered.lock().unwrap().push(p.key);
+                    Ok(())
+                } else {
+                    Err(p)
+                }
+            }
+        };
+
+        // Five publishes fail while disconnected; only the newest three fit.
+        for i in 0..5 {
+            queue.push(pending(&format!("zensight/snmp/r1/m{i}")));
+        }
+        assert_eq!(queue.len(), 3);
+
+        // Still down: the retry tick delivers nothing and keeps the queue.
+        assert_eq!(queue.drain(send).await, 0);
+        assert_eq!(queue.len(), 3);
+
+        connected.store(true, Ordering::SeqCst);
+        assert_eq!(queue.drain(send).await, 3);
+        assert_eq!(queue.len(), 0);
+        assert_eq!(
+            *delivered.lock().unwrap(),
+            [
+                "zensight/snmp/r1/m2",
+                "zensight/snmp/r1/m3",
+                "zensight/snmp/r1/m4"
+            ]
+        );
+
+        let counters = metrics.counters();
+        assert_eq!(counters["publish_retry_dropped"], 2);
+        assert_eq!(counters["publish_retry_replayed"], 3);
+        assert_eq!(metrics.gauges()["publish_retry_queued"], 0.0);
+    }
+
+    #[tokio::test]
+    async fn new_publishes_wait_behind_queued_ones() {
+        let metrics = SensorMetrics::new();
+        let config = PublishRetryConfig {
+            enabled: true,
+            max_queued: 10,
+            retry_interval_ms: 10,
+        };
+        let queue = RetryQueue::new(&config, &metrics);
+        let connected = AtomicBool::new(false);
+        let delivered = Mutex::new(Vec::new());
+        let send = |p: Pending| {
+            let ok = connected.load(Ordering::SeqCst);
+            let delivered = &delivered;
+            async move {
+                if ok {
+                    delivered.lock().unwrap().push(p.key);
+                    Ok(())
+                } else {
+                    Err(p)
+                }
+            }
+        };
+
+        assert!(!queue.send_in_order(pending("k/1"), send).await);
+        assert!(!queue.send_in_order(pending("k/2"), send).await);
+        assert_eq!(queue.len(), 2);
+
+        // Back up: the queued values go out before the new one.
+        connected.store(true, Ordering::SeqCst);
+        assert!(queue.send_in_order(pending("k/3"), send).await);
+        assert_eq!(queue.len(), 0);
+        assert_eq!(*delivered.lock().unwrap(), ["k/1", "k/2", "k/3"]);
+    }
+
+    #[tokio::test]
+    async fn shutdown_drain_flushes_queued_points_within_the_window() {
+        let metrics = SensorMetrics::new();
+        let config = PublishRetryConfig {
+            enabled: true,
+            max_queued: 10,
+            retry_interval_ms: 10,
+        };
+        let queue = RetryQueue::new(&config, &metrics);
+        for i in 0..4 {
+            queue.push(pending(&format!("zensight/snmp/r1/m{i}")));
+        }
+
+        // The session comes back on the third attempt.
+        let attempts = Mutex::new(0);
+        let started = tokio::time::Instant::now();
+        let delivered = queue
+            .drain_within(Duration::from_secs(2), |p| {
+                let up = {
+                    let mut n = attempts.lock().unwrap();
+                    *n += 1;
+                    *n > 2
+                };
+                async move { if up { Ok(()) } else { Err(p) } }
+            })
+            .await;
+        assert_eq!(delivered, 4);
+        assert_eq!(queue.len(), 0);
+        assert!(started.elapsed() < Duration::from_secs(1));
+
+        // Still down at the deadline: give up and keep what is left.
+        queue.push(pending("zensight/snmp/r1/late"));
+        let started = tokio::time::Instant::now();
+        let delivered = queue
+            .drain_within(Duration::from_millis(50), |p| async move { Err(p) })
+            .await;
+        assert_eq!(delivered, 0);
+        assert_eq!(queue.len(), 1);
+        assert!(started.elapsed() >= Duration::from_millis(50));
+    }
+}
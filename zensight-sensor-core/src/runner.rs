@@ -2,8 +2,10 @@
 
 use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 
 use tokio::signal;
+use tokio::sync::watch;
 use tokio::task::JoinHandle;
 
 use zensight_common::{Format, LoggingConfig, connect, init_tracing};
@@ -13,6 +15,7 @@ use crate::config::SensorConfig;
 use crate::error::{Result, SensorError};
 use crate::liveliness::LivelinessManager;
 use crate::publisher::Publisher;
+use crate::restarts::RestartState;
 use crate::status::StatusPublisher;
 
 /// Sensor runner that manages the lifecycle of a protocol sensor.
@@ -65,6 +68,14 @@ pub struct SensorRunner<C: SensorConfig> {
     /// the frontend's Sensors view / health bar populate. Sensors may update it
     /// (device counts, poll durations) via [`Self::health`].
     health: Arc<crate::health::SensorHealth>,
+    /// Commands served on the config's `control_socket` (#445).
+    #[cfg(unix)]
+    control: crate::control::ControlRegistry,
+    /// Flipped to `true` on shutdown so periodic tasks stop between ticks.
+    shutdown: watch::Sender<bool>,
+    /// Workers that stop on their own once `shutdown` flips; awaited for the
+    /// drain window before being aborted (#461).
+    draining: Vec<JoinHandle<()>>,
     /// Spawned tasks.
     tasks: Vec<JoinHandle<()>>,
 }
@@ -94,8 +105,8 @@ impl<C: SensorConfig> SensorRunner<C> {
             if let Some(ref level) = args.log_level {
                 LoggingConfig {
                     level: level.clone(),
-                    // Preserve format from config, only override level from CLI
-                    format: config.logging().format,
+                    // Preserve format/file from config, only override level from CLI
+                    ..config.logging().clone()
                 }
             } else {
                 config.logging().clone()
@@ -117,19 +128,52 @@ impl<C: SensorConfig> SensorRunner<C> {
 
         tracing::info!(zid = %session.zid(), "Connected to Zenoh");
 
-        // Create publisher
-        let publisher = Publisher::new(
+        // Create publisher. Its retry queue reports into the health metrics.
+        let mut health = crate::health::SensorHealth::new(name.clone());
+        if let Some(path) = crate::restarts::state_path(&name) {
+            match RestartState::record_start(&path, chrono::Utc::now().timestamp_millis()) {
+                Ok(restarts) => {
+                    if restarts.restart_count() > 0 {
+                        tracing::info!(restarts = restarts.restart_count(), "Sensor restarted");
+                    }
+                    health = health.with_restart_state(restarts);
+                }
+                Err(e) => {
+                    tracing::warn!(path = %path.display(), error = %e, "Failed to record sensor start; restart tracking disabled");
+                }
+            }
+        }
+        let qos = config.publish_qos();
+        qos.validate()?;
+        let mut publisher = Publisher::new(
             session.clone(),
             config.key_prefix(),
             Format::Json, // Default to JSON, can be overridden
-        );
+        )
+        .with_qos(qos)
+        .with_retry(&config.publish_retry(), health.metrics());
+        if config.attach_metadata() {
+            publisher = publisher.with_metadata(&name, &version);
+        }
+        let mirrors = config.publish_mirror();
+        for rule in &mirrors {
+            rule.validate()?;
+        }
+        publisher = publisher.with_mirrors(mirrors);
 
         // Health tracker publishes JSON to `<prefix>/@/health` (publish_health
         // ignores the publisher's format, so the initial publisher is fine even
         // if `with_format` later changes telemetry encoding).
-        let health = Arc::new(
-            crate::health::SensorHealth::new(name.clone()).with_publisher(publisher.clone()),
-        );
+        let health = Arc::new(health.with_publisher(publisher.clone()));
+
+        #[cfg(unix)]
+        let control = {
+            let (name, version) = (name.clone(), version.clone());
+            let (publisher, health) = (publisher.clone(), health.clone());
+            crate::control::ControlRegistry::with_builtins(move || {
+                crate::status::BridgeStatus::collect(&name, &version, &publisher, &health)
+            })
+        };
 
         Ok(Self {
             name,
@@ -139,7 +183,11 @@ impl<C: SensorConfig> SensorRunner<C> {
             publisher,
             status_publisher: None,
             liveliness: None,
+            #[cfg(unix)]
+            control,
             health,
+            shutdown: watch::Sender::new(false),
+            draining: Vec::new(),
             tasks: Vec::new(),
         })
     }
@@ -218,7 +266,7 @@ impl<C: SensorConfig> SensorRunner<C> {
 
     /// Set a custom serialization format for the publisher.
     pub fn with_format(mut self, format: Format) -> Self {
-        self.publisher = Publisher::new(self.session.clone(), self.config.key_prefix(), format);
+        self.publisher = self.rebuilt_publisher(self.config.key_prefix().to_string(), format);
         // Recreate status publisher with new publisher
         if self.status_publisher.is_some() {
             self.status_publisher = Some(StatusPublisher::new(
@@ -261,6 +309,20 @@ impl<C: SensorConfig> SensorRunner<C> {
         self.health.clone()
     }
 
+    /// A clock-skew detector (#407) built from the config's
+    /// [`timestamp_source`](SensorConfig::timestamp_source) and
+    /// [`clock_skew`](SensorConfig::clock_skew), reporting through
+    /// [`health`](Self::health).
+    pub fn clock_skew_detector(&self) -> crate::ClockSkewDetector {
+        crate::ClockSkewDetector::new(self.config.timestamp_source(), &self.config.clock_skew())
+            .with_health(self.health())
+    }
+
+    /// The sensor's named counters and gauges, published with its health.
+    pub fn metrics(&self) -> crate::SensorMetrics {
+        self.health.metrics().clone()
+    }
+
     /// Get a reference to the liveliness manager.
     ///
     /// Returns `None` if liveliness was not enabled via [`Self::with_liveliness`].
@@ -268,9 +330,29 @@ impl<C: SensorConfig> SensorRunner<C> {
         self.liveliness.as_ref()
     }
 
+    /// The control-socket command registry (#445), to add bridge-specific
+    /// commands (or a working `reload`) before [`run`](Self::run).
+    #[cfg(unix)]
+    pub fn control(&self) -> &crate::control::ControlRegistry {
+        &self.control
+    }
+
     /// Create a publisher with a different key prefix.
     pub fn publisher_with_prefix(&self, prefix: impl Into<String>) -> Publisher {
-        Publisher::new(self.session.clone(), prefix, self.publisher.format())
+        self.rebuilt_publisher(prefix.into(), self.publisher.format())
+    }
+
+    /// A publisher like the current one (QoS, retry queue, sample metadata,
+    /// mirrors) with a different prefix or format.
+    fn rebuilt_publisher(&self, prefix: String, format: Format) -> Publisher {
+        let publisher = Publisher::new(self.session.clone(), prefix, format)
+            .with_qos(self.publisher.qos().clone())
+            .with_retry_from(&self.publisher)
+            .with_mirrors(self.publisher.mirrors().to_vec());
+        match self.publisher.metadata() {
+            Some(meta) => publisher.with_metadata(&meta.bridge, &meta.version),
+            None => publisher,
+        }
     }
 
     /// Spawn a worker task.
@@ -284,6 +366,40 @@ impl<C: SensorConfig> SensorRunner<C> {
         self.tasks.push(handle);
     }
 
+    /// Spawn a poll loop (#460): `task` runs immediately and then every
+    /// `interval`, never overlapping itself (a slow run delays the next tick
+    /// rather than bunching them).
+    ///
+    /// On shutdown the loop stops before its next tick, and an in-flight run
+    /// gets up to [`SensorConfig::shutdown_drain`] to finish (#461).
+    pub fn spawn_periodic<F, Fut>(&mut self, interval: Duration, task: F)
+    where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let handle = tokio::spawn(run_periodic(interval, self.shutdown.subscribe(), task));
+        self.draining.push(handle);
+    }
+
+    /// Spawn a worker that returns by itself once
+    /// [`shutdown_signal`](Self::shutdown_signal) flips, e.g. a listener loop
+    /// that stops reading and publishes what it has buffered (#461). Shutdown
+    /// waits up to [`SensorConfig::shutdown_drain`] for it before aborting it.
+    pub fn spawn_draining<F>(&mut self, future: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let handle = tokio::spawn(future);
+        self.draining.push(handle);
+    }
+
+    /// Flips to `true` when shutdown begins, so workers can stop taking new
+    /// work (#461); [`spawn_periodic`](Self::spawn_periodic) loops watch it
+    /// already.
+    pub fn shutdown_signal(&self) -> watch::Receiver<bool> {
+        self.shutdown.subscribe()
+    }
+
     /// Spawn a worker task that returns a Result.
     ///
     /// Errors are logged automatically.
@@ -305,7 +421,10 @@ impl<C: SensorConfig> SensorRunner<C> {
     /// This will:
     /// 1. Publish "running" status (if enabled)
     /// 2. Wait for a shutdown signal (Ctrl+C / SIGINT or, on Unix, SIGTERM)
-    /// 3. Abort all spawned tasks
+    /// 3. Signal workers to stop, wait for the ones that drain (see
+    ///    [`spawn_draining`](Self::spawn_draining)) and flush queued publishes,
+    ///    all within [`SensorConfig::shutdown_drain`], then abort all spawned
+    ///    tasks
     /// 4. Publish "offline" status (if enabled)
     /// 5. Close the Zenoh session
     pub async fn run(self) -> Result<()> {
@@ -313,7 +432,17 @@ impl<C: SensorConfig> SensorRunner<C> {
     }
 
     /// Run the sensor with custom status metadata.
-    pub async fn run_with_metadata(mut self, metadata: Option<serde_json::Value>) -> Result<()> {
+    pub async fn run_with_metadata(self, metadata: Option<serde_json::Value>) -> Result<()> {
+        self.run_until(wait_for_shutdown(), metadata).await
+    }
+
+    /// Run the sensor until `signal` resolves instead of an OS signal, then
+    /// shut down as [`run`](Self::run) does.
+    pub async fn run_until(
+        mut self,
+        signal: impl Future<Output = ()>,
+        metadata: Option<serde_json::Value>,
+    ) -> Result<()> {
         // Publish running status
         if let Some(ref status_pub) = self.status_publisher
             && let Err(e) = status_pub.publish_running(metadata).await
@@ -338,28 +467,82 @@ impl<C: SensorConfig> SensorRunner<C> {
             self.tasks.push(task);
         }
 
+        // Replay publishes that failed while the session was down (#412).
+        if let Some(interval) = self.publisher.retry_interval() {
+            let publisher = self.publisher.clone();
+            let task = tokio::spawn(async move {
+                let mut tick = tokio::time::interval(interval);
+                loop {
+                    tick.tick().await;
+                    let replayed = publisher.flush_retries().await;
+                    if replayed > 0 {
+                        tracing::info!(replayed, "Replayed queued publishes");
+                    }
+                }
+            });
+            self.tasks.push(task);
+        }
+
+        // Serve runtime control commands on the local socket (#445).
+        #[cfg(unix)]
+        if let Some(path) = self.config.control_socket() {
+            match crate::control::ControlServer::bind(&path, self.control.clone()) {
+                Ok(server) => self.tasks.push(tokio::spawn(server.run())),
+                Err(e) => {
+                    tracing::warn!(path = %path.display(), error = %e, "Failed to bind control socket");
+                }
+            }
+        }
+
         tracing::info!(
             sensor = %self.name,
             tasks = self.tasks.len(),
             "Sensor running. Press Ctrl+C or send SIGTERM to stop."
         );
 
-        // Wait for a shutdown signal. Catch both Ctrl+C (SIGINT) and SIGTERM:
-        // systemd `stop` and `docker stop` send SIGTERM, and if we only awaited
-        // Ctrl+C we'd be SIGKILLed after the stop timeout — never reaching the
-        // graceful path below (offline status + alert tombstones).
-        wait_for_shutdown().await;
+        // Wait for a shutdown signal. `run` catches both Ctrl+C (SIGINT) and
+        // SIGTERM: systemd `stop` and `docker stop` send SIGTERM, and if we
+        // only awaited Ctrl+C we'd be SIGKILLed after the stop timeout — never
+        // reaching the graceful path below (offline status + alert tombstones).
+        signal.await;
 
         tracing::info!(sensor = %self.name, "Received shutdown signal");
 
+        // Stop the workers and let the draining ones publish what they have in
+        // flight while the session is still open (#461)
+        self.shutdown.send_replace(true);
+        let deadline = tokio::time::Instant::now() + self.config.shutdown_drain();
+        let mut draining = std::mem::take(&mut self.draining);
+        let drained = tokio::time::timeout_at(deadline, async {
+            for worker in &mut draining {
+                let _ = worker.await;
+            }
+        })
+        .await;
+        if drained.is_err() {
+            tracing::warn!("Workers still busy after the shutdown drain window; aborting them");
+        }
+        self.tasks.extend(draining);
+
+        // Flush publishes still queued for retry in what is left of the window
+        let queued = self.publisher.queued_retries();
+        if queued > 0 {
+            let flushed = self
+                .publisher
+                .drain_retries(deadline.saturating_duration_since(tokio::time::Instant::now()))
+                .await;
+            tracing::info!(
+                flushed,
+                dropped = self.publisher.queued_retries(),
+                "Drained queued publishes"
+            );
+        }
+
         // Abort all tasks
         for task in &self.tasks {
             task.abort();
         }
 
-        // Wait briefly for tasks to clean up
-        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
-
         // Publish offline status
         if let Some(ref status_pub) = self.status_publisher
             && let Err(e) = status_pub.publish_offline().await
@@ -378,6 +561,24 @@ impl<C: SensorConfig> SensorRunner<C> {
     }
 }
 
+/// Run `task` every `interval` until `shutdown` turns `true` (or its sender
+/// is dropped).
+async fn run_periodic<F, Fut>(interval: Duration, mut shutdown: watch::Receiver<bool>, mut task: F)
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let mut tick = tokio::time::interval(interval);
+    tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        tokio::select! {
+            biased;
+            _ = shutdown.wait_for(|&stop| stop) => break,
+            _ = tick.tick() => task().await,
+        }
+    }
+}
+
 /// Wait for an OS shutdown signal: Ctrl+C (SIGINT) or, on Unix, SIGTERM.
 ///
 /// systemd and Docker stop a process with SIGTERM, so handling only Ctrl+C
@@ -419,4 +620,103 @@ async fn wait_for_shutdown() {
 mod tests {
     // Runner tests require a Zenoh session, which we can't easily mock.
     // Integration tests should cover the runner functionality.
+
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use serde::Deserialize;
+    use zensight_common::{Protocol, TelemetryPoint, TelemetryValue, ZenohConfig};
+
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct TestConfig {
+        zenoh: ZenohConfig,
+        #[serde(default)]
+        logging: LoggingConfig,
+        key_prefix: String,
+    }
+
+    impl SensorConfig for TestConfig {
+        fn zenoh(&self) -> &ZenohConfig {
+            &self.zenoh
+        }
+
+        fn logging(&self) -> &LoggingConfig {
+            &self.logging
+        }
+
+        fn key_prefix(&self) -> &str {
+            &self.key_prefix
+        }
+
+        fn default_config_json5() -> &'static str {
+            "{ zenoh: {}, key_prefix: \"zensight/test\" }"
+        }
+    }
+
+    #[tokio::test]
+    async fn periodic_task_fires_each_interval_and_stops_on_shutdown() {
+        let (shutdown, rx) = watch::channel(false);
+        let runs = Arc::new(AtomicU32::new(0));
+        let counter = runs.clone();
+        let handle = tokio::spawn(run_periodic(Duration::from_millis(50), rx, move || {
+            let counter = counter.clone();
+            async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+        }));
+
+        // Ticks at 0, 50, ..., 250 ms.
+        tokio::time::sleep(Duration::from_millis(275)).await;
+        let fired = runs.load(Ordering::SeqCst);
+        assert!((5..=7).contains(&fired), "fired {fired} times");
+
+        shutdown.send_replace(true);
+        tokio::time::timeout(Duration::from_secs(1), handle)
+            .await
+            .expect("periodic task did not stop")
+            .unwrap();
+        let stopped_at = runs.load(Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(120)).await;
+        assert_eq!(runs.load(Ordering::SeqCst), stopped_at);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn in_flight_publish_reaches_the_session_on_shutdown() {
+        let config: TestConfig = json5::from_str(
+            r#"{
+                zenoh: { mode: "peer", multicast_scouting: false, gossip_scouting: false },
+                key_prefix: "zensight/drain",
+            }"#,
+        )
+        .unwrap();
+        let mut runner = SensorRunner::new("drain", config).await.unwrap();
+        let subscriber = runner
+            .session()
+            .declare_subscriber("zensight/drain/**")
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(150)).await;
+
+        // A listener that is still publishing its buffer well after the
+        // signal, but within the default two-second drain window.
+        let publisher = runner.publisher();
+        let mut shutdown = runner.shutdown_signal();
+        runner.spawn_draining(async move {
+            let _ = shutdown.wait_for(|&stop| stop).await;
+            tokio::time::sleep(Duration::from_millis(500)).await;
+            let point = TelemetryPoint::new(
+                "h1",
+                Protocol::Sysinfo,
+                "cpu/usage",
+                TelemetryValue::Gauge(1.0),
+            );
+            publisher.publish("h1/cpu/usage", &point).await.unwrap();
+        });
+
+        runner.run_until(async {}, None).await.unwrap();
+
+        let sample = subscriber.try_recv().unwrap().expect("publish was lost");
+        assert_eq!(sample.key_expr().as_str(), "zensight/drain/h1/cpu/usage");
+    }
 }
@@ -3,6 +3,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::Result;
+use crate::health::{HealthSnapshot, SensorHealth};
 use crate::publisher::Publisher;
 
 /// Sensor status information.
@@ -69,6 +70,40 @@ impl SensorStatus {
     }
 }
 
+/// A bridge's state as the control socket's `dump-stats` reports it (#445):
+/// which bridge this is, its health counters and its retry backlog.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BridgeStatus {
+    /// Sensor name (e.g., "snmp", "syslog").
+    pub sensor: String,
+    /// Sensor version.
+    pub version: String,
+    /// Key prefix the bridge publishes under.
+    pub key_prefix: String,
+    /// Publishes waiting in the retry queue (#412).
+    pub queued_retries: usize,
+    /// Current health counters.
+    pub health: HealthSnapshot,
+}
+
+impl BridgeStatus {
+    /// The current status of the bridge publishing through `publisher`.
+    pub fn collect(
+        sensor: impl Into<String>,
+        version: impl Into<String>,
+        publisher: &Publisher,
+        health: &SensorHealth,
+    ) -> Self {
+        Self {
+            sensor: sensor.into(),
+            version: version.into(),
+            key_prefix: publisher.key_prefix().to_string(),
+            queued_retries: publisher.queued_retries(),
+            health: health.snapshot(),
+        }
+    }
+}
+
 /// Helper to publish sensor status on startup and shutdown.
 pub struct StatusPublisher {
     publisher: Publisher,
@@ -0,0 +1,86 @@
+//! Integration test for the runtime-control socket (#445).
+#![cfg(unix)]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::UnixStream;
+use zensight_sensor_core::{
+    BridgeStatus, ControlRegistry, ControlResponse, ControlServer, SensorHealth,
+};
+
+fn registry(health: Arc<SensorHealth>) -> ControlRegistry {
+    ControlRegistry::with_builtins(move || BridgeStatus {
+        sensor: "snmp".to_string(),
+        version: "1.2.3".to_string(),
+        key_prefix: "zensight/snmp".to_string(),
+        queued_retries: 7,
+        health: health.snapshot(),
+    })
+}
+
+#[tokio::test]
+async fn dump_stats_returns_the_bridge_status() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("snmp.sock");
+
+    let health = Arc::new(SensorHealth::new("snmp"));
+    health.set_devices_total(3);
+    health.record_metrics_published(42);
+    let server = ControlServer::bind(&path, registry(health)).unwrap();
+    let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+    let task = tokio::spawn(server.run());
+
+    let stream = UnixStream::connect(&path).await.unwrap();
+    let (reader, mut writer) = stream.into_split();
+    writer
+        .write_all(b"{\"command\":\"dump-stats\"}\n")
+        .await
+        .unwrap();
+    let mut lines = BufReader::new(reader).lines();
+    let line = tokio::time::timeout(Duration::from_secs(5), lines.next_line())
+        .await
+        .expect("reply timed out")
+        .unwrap()
+        .expect("a reply line");
+
+    let response: ControlResponse = serde_json::from_str(&line).unwrap();
+    assert!(response.ok, "{:?}", response.error);
+    let status: BridgeStatus = serde_json::from_value(response.result.unwrap()).unwrap();
+    assert_eq!(status.sensor, "snmp");
+    assert_eq!(status.version, "1.2.3");
+    assert_eq!(status.key_prefix, "zensight/snmp");
+    assert_eq!(status.queued_retries, 7);
+    assert_eq!(status.health.devices_total, 3);
+    assert_eq!(status.health.metrics_published, 42);
+
+    // Stopping the server removes its socket file.
+    task.abort();
+    let _ = task.await;
+    assert!(!path.exists());
+}
+
+#[tokio::test]
+async fn bind_replaces_a_stale_socket_but_not_a_regular_file() {
+    let dir = tempfile::tempdir().unwrap();
+
+    // A socket left behind by a previous run (the listener is gone, the file
+    // stays) is replaced.
+    let stale = dir.path().join("stale.sock");
+    drop(std::os::unix::net::UnixListener::bind(&stale).unwrap());
+    assert!(stale.exists());
+    let registry = registry(Arc::new(SensorHealth::new("logs")));
+    let server = ControlServer::bind(&stale, registry.clone()).unwrap();
+    UnixStream::connect(&stale).await.unwrap();
+    drop(server);
+
+    // A mistyped path pointing at a real file must not delete it.
+    let file = dir.path().join("logs.json5");
+    std::fs::write(&file, "{}").unwrap();
+    assert!(ControlServer::bind(&file, registry).is_err());
+    assert_eq!(std::fs::read_to_string(&file).unwrap(), "{}");
+}
@@ -0,0 +1,103 @@
+//! Integration test for telemetry mirroring (#447) over an in-process Zenoh
+//! peer.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use zensight_common::{Format, Protocol, TelemetryPoint, TelemetryValue, decode_auto};
+use zensight_sensor_core::{MirrorRule, Publisher};
+
+/// A standalone Zenoh config: scouting disabled so concurrent test peers don't
+/// discover each other. Local pub/sub within one session still works.
+fn isolated_config() -> zenoh::Config {
+    let mut config = zenoh::Config::default();
+    config
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    config
+        .insert_json5("scouting/gossip/enabled", "false")
+        .unwrap();
+    config
+}
+
+fn unique_source() -> String {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    format!("host_{}", nanos)
+}
+
+type Sub = zenoh::pubsub::Subscriber<zenoh::handlers::FifoChannelHandler<zenoh::sample::Sample>>;
+
+async fn next(sub: &Sub) -> zenoh::sample::Sample {
+    tokio::time::timeout(Duration::from_secs(5), sub.recv_async())
+        .await
+        .expect("recv timed out")
+        .expect("recv")
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn matching_points_are_published_under_both_prefixes() {
+    let session = Arc::new(zenoh::open(isolated_config()).await.expect("open zenoh"));
+    let source = unique_source();
+    let primary = session
+        .declare_subscriber(format!("zensight/snmp/{source}/**"))
+        .await
+        .expect("subscriber");
+    let mirror = session
+        .declare_subscriber(format!("staging/zensight/snmp/{source}/**"))
+        .await
+        .expect("subscriber");
+    tokio::time::sleep(Duration::from_millis(150)).await;
+
+    let publisher =
+        Publisher::new(session.clone(), "zensight/snmp", Format::Json).with_mirrors(vec![
+            MirrorRule {
+                key: format!("zensight/snmp/{source}/if/**"),
+                prefix: "staging/zensight".into(),
+            },
+        ]);
+    let point = |metric: &str| {
+        TelemetryPoint::new(&source, Protocol::Snmp, metric, TelemetryValue::Counter(7))
+    };
+
+    // Not matched by the rule: primary only.
+    publisher
+        .publish(
+            &format!("{source}/system/sysUpTime"),
+            &point("system/sysUpTime"),
+        )
+        .await
+        .expect("publish");
+    // Matched: primary and mirror.
+    publisher
+        .publish(
+            &format!("{source}/if/1/ifInOctets"),
+            &point("if/1/ifInOctets"),
+        )
+        .await
+        .expect("publish");
+
+    let first = next(&primary).await;
+    assert_eq!(
+        first.key_expr().as_str(),
+        format!("zensight/snmp/{source}/system/sysUpTime")
+    );
+    let second = next(&primary).await;
+    assert_eq!(
+        second.key_expr().as_str(),
+        format!("zensight/snmp/{source}/if/1/ifInOctets")
+    );
+
+    let mirrored = next(&mirror).await;
+    assert_eq!(
+        mirrored.key_expr().as_str(),
+        format!("staging/zensight/snmp/{source}/if/1/ifInOctets")
+    );
+    let got: TelemetryPoint = decode_auto(&mirrored.payload().to_bytes()).expect("decode");
+    assert_eq!(got.metric, "if/1/ifInOctets");
+    // Only the matching point was mirrored.
+    assert!(mirror.try_recv().ok().flatten().is_none());
+}
@@ -0,0 +1,110 @@
+//! Integration test for sample-metadata attachments (#444) over an in-process
+//! Zenoh peer.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use zensight_common::{Format, Protocol, SampleMetadata, TelemetryPoint, TelemetryValue};
+use zensight_sensor_core::Publisher;
+
+/// A standalone Zenoh config: scouting disabled so concurrent test peers don't
+/// discover each other. Local pub/sub within one session still works.
+fn isolated_config() -> zenoh::Config {
+    let mut config = zenoh::Config::default();
+    config
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    config
+        .insert_json5("scouting/gossip/enabled", "false")
+        .unwrap();
+    config
+}
+
+fn unique_source() -> String {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    format!("host_{}", nanos)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn attachment_carries_bridge_metadata() {
+    let session = Arc::new(zenoh::open(isolated_config()).await.expect("open zenoh"));
+    let source = unique_source();
+    let sub = session
+        .declare_subscriber(format!("zensight/snmp/{source}/**"))
+        .await
+        .expect("subscriber");
+    tokio::time::sleep(Duration::from_millis(150)).await;
+
+    let publisher = Publisher::new(session.clone(), "zensight/snmp", Format::Cbor)
+        .with_metadata("snmp", "1.2.3");
+    let point = TelemetryPoint::new(
+        &source,
+        Protocol::Snmp,
+        "system/sysUpTime",
+        TelemetryValue::Counter(42),
+    );
+    publisher
+        .publish(&format!("{source}/system/sysUpTime"), &point)
+        .await
+        .expect("publish");
+
+    let s = tokio::time::timeout(Duration::from_secs(5), sub.recv_async())
+        .await
+        .expect("recv timed out")
+        .expect("recv");
+    let attachment = s.attachment().expect("sample has an attachment");
+    assert_eq!(
+        SampleMetadata::from_attachment(&attachment.to_bytes()),
+        Some(SampleMetadata::new("snmp", "1.2.3", Format::Cbor))
+    );
+
+    // Without metadata, samples carry no attachment.
+    let plain = Publisher::new(session.clone(), "zensight/snmp", Format::Cbor);
+    plain
+        .publish(&format!("{source}/system/sysUpTime"), &point)
+        .await
+        .expect("publish");
+    let s = tokio::time::timeout(Duration::from_secs(5), sub.recv_async())
+        .await
+        .expect("recv timed out")
+        .expect("recv");
+    assert!(s.attachment().is_none());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn events_carry_bridge_metadata() {
+    let session = Arc::new(zenoh::open(isolated_config()).await.expect("open zenoh"));
+    let source = unique_source();
+    let sub = session
+        .declare_subscriber(format!("zensight/logs/{source}/**"))
+        .await
+        .expect("subscriber");
+    tokio::time::sleep(Duration::from_millis(150)).await;
+
+    let publisher = Publisher::new(session.clone(), "zensight/logs", Format::Json)
+        .with_metadata("logs", "1.2.3");
+    let point = TelemetryPoint::new(
+        &source,
+        Protocol::Logs,
+        "logs/events/1",
+        TelemetryValue::Text("disk full".into()),
+    );
+    publisher
+        .publish_event(&format!("zensight/logs/{source}/events/1"), &point)
+        .await
+        .expect("publish");
+
+    let s = tokio::time::timeout(Duration::from_secs(5), sub.recv_async())
+        .await
+        .expect("recv timed out")
+        .expect("recv");
+    let attachment = s.attachment().expect("event has an attachment");
+    assert_eq!(
+        SampleMetadata::from_attachment(&attachment.to_bytes()),
+        Some(SampleMetadata::new("logs", "1.2.3", Format::Json))
+    );
+}
@@ -0,0 +1,64 @@
+//! Integration test for publish-time value validation (#458) over an
+//! in-process Zenoh peer.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use zensight_common::{Format, Protocol, TelemetryPoint, TelemetryValue, decode_auto};
+use zensight_sensor_core::{Publisher, SensorError};
+
+/// A standalone Zenoh config: scouting disabled so concurrent test peers don't
+/// discover each other. Local pub/sub within one session still works.
+fn isolated_config() -> zenoh::Config {
+    let mut config = zenoh::Config::default();
+    config
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    config
+        .insert_json5("scouting/gossip/enabled", "false")
+        .unwrap();
+    config
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn invalid_values_are_rejected_before_the_wire() {
+    let session = Arc::new(zenoh::open(isolated_config()).await.expect("open zenoh"));
+    let sub = session
+        .declare_subscriber("zensight/modbus/plc01/**")
+        .await
+        .expect("subscriber");
+    tokio::time::sleep(Duration::from_millis(150)).await;
+
+    let publisher = Publisher::new(session.clone(), "zensight/modbus", Format::Json);
+    let point = |value| TelemetryPoint::new("plc01", Protocol::Modbus, "temp", value);
+
+    for value in [
+        TelemetryValue::Gauge(f64::NAN),
+        TelemetryValue::Gauge(f64::INFINITY),
+    ] {
+        let err = publisher
+            .publish("plc01/temp", &point(value.clone()))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SensorError::InvalidValue { .. }), "{err}");
+        let err = publisher
+            .publish_event("zensight/modbus/plc01/temp", &point(value))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SensorError::InvalidValue { .. }), "{err}");
+    }
+
+    // A valid value goes through, and is the only sample on the wire.
+    publisher
+        .publish("plc01/temp", &point(TelemetryValue::Gauge(21.5)))
+        .await
+        .expect("publish");
+    let sample = tokio::time::timeout(Duration::from_secs(5), sub.recv_async())
+        .await
+        .expect("recv timed out")
+        .expect("recv");
+    let received: TelemetryPoint = decode_auto(&sample.payload().to_bytes()).expect("decode");
+    assert_eq!(received.value, TelemetryValue::Gauge(21.5));
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert!(sub.try_recv().ok().flatten().is_none());
+}
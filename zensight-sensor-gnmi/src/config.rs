@@ -1,7 +1,7 @@
 //! gNMI sensor configuration
 
 use serde::{Deserialize, Serialize};
-use zensight_common::ZenohConfig;
+use zensight_common::{ClockSkewConfig, TimestampSource, ZenohConfig};
 
 // Re-export LoggingConfig from the framework for compatibility
 pub use zensight_sensor_core::LoggingConfig;
@@ -29,6 +29,35 @@ pub struct GnmiConfig {
     /// `#[serde(default)]` required for the same reason as `report` above.
     #[serde(default)]
     pub snapshot: zensight_sensor_core::SnapshotLimits,
+
+    /// Zenoh congestion control / priority per key or protocol (#410).
+    #[serde(default)]
+    pub publish_qos: zensight_sensor_core::PublishQosConfig,
+
+    /// Bounded queue + timed replay for publishes that fail (#412).
+    #[serde(default)]
+    pub publish_retry: zensight_sensor_core::PublishRetryConfig,
+
+    /// Re-publish matching telemetry under alternate prefixes (#447).
+    #[serde(default)]
+    pub publish_mirror: Vec<zensight_sensor_core::MirrorRule>,
+
+    /// Attach bridge name/version/encoding to each sample (#444).
+    #[serde(default)]
+    pub attach_metadata: bool,
+
+    /// Unix socket for runtime control commands (#445); off when unset.
+    #[serde(default)]
+    pub control_socket: Option<std::path::PathBuf>,
+
+    /// Stamp points with the notification's update timestamp (`message`,
+    /// default) or with the time it was received (`receipt`) (#406).
+    #[serde(default)]
+    pub timestamp_source: TimestampSource,
+
+    /// Warn when update timestamps drift from receipt time (#407).
+    #[serde(default)]
+    pub clock_skew: ClockSkewConfig,
 }
 
 /// gNMI-specific settings
@@ -170,6 +199,15 @@ pub enum SerializationFormat {
     Cbor,
 }
 
+impl From<SerializationFormat> for zensight_common::Format {
+    fn from(format: SerializationFormat) -> Self {
+        match format {
+            SerializationFormat::Json => Self::Json,
+            SerializationFormat::Cbor => Self::Cbor,
+        }
+    }
+}
+
 fn default_key_prefix() -> String {
     "zensight/gnmi".to_string()
 }
@@ -188,6 +226,10 @@ impl GnmiConfig {
 }
 
 impl zensight_sensor_core::SensorConfig for GnmiConfig {
+    fn default_config_json5() -> &'static str {
+        include_str!("../../configs/gnmi.json5")
+    }
+
     fn zenoh(&self) -> &ZenohConfig {
         &self.zenoh
     }
@@ -229,6 +271,34 @@ impl zensight_sensor_core::SensorConfig for GnmiConfig {
     fn snapshot_limits(&self) -> zensight_sensor_core::SnapshotLimits {
         self.snapshot.clone()
     }
+
+    fn publish_qos(&self) -> zensight_sensor_core::PublishQosConfig {
+        self.publish_qos.clone()
+    }
+
+    fn publish_retry(&self) -> zensight_sensor_core::PublishRetryConfig {
+        self.publish_retry.clone()
+    }
+
+    fn publish_mirror(&self) -> Vec<zensight_sensor_core::MirrorRule> {
+        self.publish_mirror.clone()
+    }
+
+    fn attach_metadata(&self) -> bool {
+        self.attach_metadata
+    }
+
+    fn control_socket(&self) -> Option<std::path::PathBuf> {
+        self.control_socket.clone()
+    }
+
+    fn timestamp_source(&self) -> TimestampSource {
+        self.timestamp_source
+    }
+
+    fn clock_skew(&self) -> ClockSkewConfig {
+        self.clock_skew.clone()
+    }
 }
 
 impl GnmiEncoding {
@@ -308,4 +378,12 @@ mod tests {
         assert!(!tls.skip_verify);
         assert!(tls.ca_cert.is_none());
     }
+
+    #[test]
+    fn printed_default_config_documents_every_field() {
+        assert_eq!(
+            zensight_sensor_core::undocumented_fields::<GnmiConfig>().unwrap(),
+            Vec::<String>::new()
+        );
+    }
 }
@@ -4,13 +4,17 @@
 
 use tracing::{error, info};
 
-use zensight_sensor_core::{SensorArgs, SensorRunner};
+use zensight_sensor_core::{SensorArgs, SensorConfig, SensorRunner};
 use zensight_sensor_gnmi::{GnmiConfig, GnmiSubscriber};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     // Parse CLI arguments
     let args = SensorArgs::parse_with_default("gnmi.json5");
+    if args.print_default_config {
+        print!("{}", GnmiConfig::default_config_json5());
+        return Ok(());
+    }
 
     // Load configuration
     let config = GnmiConfig::load_from_file(&args.config)?;
@@ -38,28 +42,32 @@ async fn main() -> anyhow::Result<()> {
         runner.health(),
     ));
     // Tier-2 directory snapshots (`@/snapshot`). No-op unless `snapshot.enabled`.
-    let mut runner = runner.with_report(report_source).with_snapshot(report_host);
+    let runner = runner.with_report(report_source).with_snapshot(report_host);
 
-    // Get session and config
-    let session = runner.session().clone();
+    // Subscribers publish through the runner's publisher so sample metadata,
+    // mirrors and retry apply to them (#444)
     let gnmi_config = runner.config().gnmi.clone();
+    let mut runner = runner.with_format(gnmi_config.serialization.into());
+    let publisher = runner.publisher();
 
     info!(
         "Starting gNMI sensor with {} targets",
         gnmi_config.targets.len()
     );
 
+    // One detector shared by all targets; it rate-limits per target name.
+    let clock_skew = std::sync::Arc::new(runner.clock_skew_detector());
+
     // Create subscriber tasks for each target
     for target in gnmi_config.targets {
-        let subscriber = GnmiSubscriber::new(
-            target.clone(),
-            gnmi_config.key_prefix.clone(),
-            gnmi_config.serialization,
-        );
-        let session = session.clone();
+        let subscriber = GnmiSubscriber::new(target.clone(), gnmi_config.key_prefix.clone())
+            .with_timestamp_source(runner.config().timestamp_source)
+            .with_clock_skew(clock_skew.clone());
+        let publisher = publisher.clone();
+        let shutdown = runner.shutdown_signal();
 
-        runner.spawn(async move {
-            if let Err(e) = subscriber.run(session).await {
+        runner.spawn_draining(async move {
+            if let Err(e) = subscriber.run(publisher, shutdown).await {
                 error!("Subscriber for {} failed: {}", target.name, e);
             }
         });
@@ -4,13 +4,15 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
+use tokio::sync::watch;
 use tonic::Request;
 use tonic::transport::{Channel, ClientTlsConfig, Endpoint};
 use tracing::{debug, error, info, warn};
 
-use zensight_common::{Protocol, TelemetryPoint, TelemetryValue};
+use zensight_common::{Protocol, TelemetryPoint, TelemetryValue, TimestampSource};
+use zensight_sensor_core::{ClockSkewDetector, Publisher, SensorError};
 
-use crate::config::{GnmiTarget, SerializationFormat, Subscription, SubscriptionMode};
+use crate::config::{GnmiTarget, Subscription, SubscriptionMode};
 use crate::gnmi::{
     self, Path, PathElem, SubscribeRequest, SubscriptionList, SubscriptionMode as ProtoSubMode,
     g_nmi_client::GNmiClient,
@@ -20,21 +22,62 @@ use crate::gnmi::{
 pub struct GnmiSubscriber {
     target: GnmiTarget,
     key_prefix: String,
-    serialization: SerializationFormat,
+    timestamp_source: TimestampSource,
+    clock_skew: Option<Arc<ClockSkewDetector>>,
+}
+
+/// Sleep for `backoff`, or return `true` as soon as `shutdown` flips.
+async fn stopped_during(shutdown: &mut watch::Receiver<bool>, backoff: Duration) -> bool {
+    tokio::select! {
+        _ = shutdown.wait_for(|&stop| stop) => true,
+        _ = tokio::time::sleep(backoff) => false,
+    }
+}
+
+/// Publish timestamp for a notification (#406). gNMI stamps updates in
+/// nanoseconds since the epoch; 0 means the target didn't set one, which
+/// falls back to `receipt_ms` like any other missing message time.
+pub fn notification_timestamp_ms(
+    timestamp_ns: i64,
+    source: TimestampSource,
+    receipt_ms: i64,
+) -> i64 {
+    let message_ms = (timestamp_ns > 0).then(|| timestamp_ns / 1_000_000);
+    source.pick(message_ms, receipt_ms)
 }
 
 impl GnmiSubscriber {
     /// Create a new gNMI subscriber
-    pub fn new(target: GnmiTarget, key_prefix: String, serialization: SerializationFormat) -> Self {
+    pub fn new(target: GnmiTarget, key_prefix: String) -> Self {
         Self {
             target,
             key_prefix,
-            serialization,
+            timestamp_source: TimestampSource::default(),
+            clock_skew: None,
         }
     }
 
-    /// Run the subscriber, publishing telemetry to Zenoh
-    pub async fn run(&self, session: Arc<zenoh::Session>) -> anyhow::Result<()> {
+    /// Choose which clock stamps published points (#406).
+    pub fn with_timestamp_source(mut self, timestamp_source: TimestampSource) -> Self {
+        self.timestamp_source = timestamp_source;
+        self
+    }
+
+    /// Check update timestamps against receipt time (#407).
+    pub fn with_clock_skew(mut self, detector: Arc<ClockSkewDetector>) -> Self {
+        self.clock_skew = Some(detector);
+        self
+    }
+
+    /// Run the subscriber, publishing telemetry through `publisher` (the
+    /// runner's, so sample metadata, mirrors and retry apply; #444), until
+    /// `shutdown` flips. A notification being published then is finished
+    /// first (#461).
+    pub async fn run(
+        &self,
+        publisher: Publisher,
+        mut shutdown: watch::Receiver<bool>,
+    ) -> anyhow::Result<()> {
         info!(
             "Starting gNMI subscriber for {} at {}",
             self.target.name, self.target.address
@@ -53,7 +96,7 @@ impl GnmiSubscriber {
                 "Connecting to gNMI target"
             );
 
-            match self.subscribe_loop(&session).await {
+            match self.subscribe_loop(&publisher, &mut shutdown).await {
                 Ok(()) => {
                     info!("Subscription completed normally for {}", self.target.name);
                     // Reset on successful connection
@@ -68,16 +111,24 @@ impl GnmiSubscriber {
                         target = %self.target.name,
                         "gNMI connection failed"
                     );
-                    tokio::time::sleep(backoff).await;
+                    if stopped_during(&mut shutdown, backoff).await {
+                        return Ok(());
+                    }
                     backoff = (backoff * 2).min(max_backoff);
                     continue;
                 }
             }
-            tokio::time::sleep(backoff).await;
+            if stopped_during(&mut shutdown, backoff).await {
+                return Ok(());
+            }
         }
     }
 
-    async fn subscribe_loop(&self, session: &Arc<zenoh::Session>) -> anyhow::Result<()> {
+    async fn subscribe_loop(
+        &self,
+        publisher: &Publisher,
+        shutdown: &mut watch::Receiver<bool>,
+    ) -> anyhow::Result<()> {
         let channel = self.connect().await?;
         let mut client = GNmiClient::new(channel);
 
@@ -100,11 +151,16 @@ impl GnmiSubscriber {
 
         info!("gNMI subscription established for {}", self.target.name);
 
-        while let Some(msg) = stream.message().await? {
+        loop {
+            let msg = tokio::select! {
+                _ = shutdown.wait_for(|&stop| stop) => return Ok(()),
+                msg = stream.message() => msg?,
+            };
+            let Some(msg) = msg else { break };
             if let Some(response) = msg.response {
                 match response {
                     gnmi::subscribe_response::Response::Update(notification) => {
-                        self.process_notification(session, notification).await?;
+                        self.process_notification(publisher, notification).await?;
                     }
                     gnmi::subscribe_response::Response::SyncResponse(sync) => {
                         debug!("Received sync response: {}", sync);
@@ -238,10 +294,23 @@ impl GnmiSubscriber {
 
     async fn process_notification(
         &self,
-        session: &Arc<zenoh::Session>,
+        publisher: &Publisher,
         notification: gnmi::Notification,
     ) -> anyhow::Result<()> {
-        let timestamp = notification.timestamp.checked_div(1_000_000).unwrap_or(0); // Convert nanoseconds to milliseconds
+        let receipt_ms = zensight_common::current_timestamp_millis();
+        if let Some(detector) = &self.clock_skew
+            && notification.timestamp > 0
+        {
+            detector
+                .observe(
+                    &self.target.name,
+                    notification.timestamp / 1_000_000,
+                    receipt_ms,
+                )
+                .await;
+        }
+        let timestamp =
+            notification_timestamp_ms(notification.timestamp, self.timestamp_source, receipt_ms);
         let prefix_path = notification.prefix.as_ref().map(|p| self.path_to_string(p));
 
         for update in notification.update {
@@ -254,31 +323,24 @@ impl GnmiSubscriber {
 
                 let value = self.extract_value(&update);
 
-                let point = TelemetryPoint {
-                    timestamp,
-                    source: self.target.name.clone(),
-                    protocol: Protocol::Gnmi,
-                    metric: full_path.clone(),
-                    value,
-                    labels: HashMap::new(),
-                };
+                let point = TelemetryPoint::builder()
+                    .timestamp(timestamp)
+                    .source(&self.target.name)
+                    .protocol(Protocol::Gnmi)
+                    .metric(&full_path)
+                    .value(value)
+                    .build()?;
 
                 let key = format!("{}/{}/{}", self.key_prefix, self.target.name, full_path);
 
-                let payload = match self.serialization {
-                    SerializationFormat::Json => serde_json::to_vec(&point)?,
-                    SerializationFormat::Cbor => {
-                        let mut buf = Vec::new();
-                        ciborium::into_writer(&point, &mut buf)?;
-                        buf
+                match publisher.publish_to_key(&key, &point).await {
+                    Ok(()) => debug!("Published telemetry to {}", key),
+                    // A NaN/Inf value is dropped, not a reason to resubscribe (#458).
+                    Err(e @ SensorError::InvalidValue { .. }) => {
+                        debug!(error = %e, "Dropping invalid gNMI value")
                     }
-                };
-
-                session
-                    .put(&key, payload)
-                    .await
-                    .map_err(|e| anyhow::anyhow!("Zenoh put failed: {}", e))?;
-                debug!("Published telemetry to {}", key);
+                    Err(e) => return Err(anyhow::anyhow!("Zenoh put failed: {}", e)),
+                }
             }
         }
 
@@ -311,7 +373,7 @@ impl GnmiSubscriber {
             #[allow(deprecated)]
             if let Some(val) = &update.value {
                 // Deprecated field, but some implementations still use it
-                TelemetryValue::Binary(val.value.clone())
+                TelemetryValue::Bytes(val.value.clone())
             } else {
                 TelemetryValue::Text("".to_string())
             }
@@ -326,7 +388,7 @@ impl GnmiSubscriber {
             Some(Value::IntVal(i)) => TelemetryValue::Gauge(*i as f64),
             Some(Value::UintVal(u)) => TelemetryValue::Counter(*u),
             Some(Value::BoolVal(b)) => TelemetryValue::Boolean(*b),
-            Some(Value::BytesVal(b)) => TelemetryValue::Binary(b.clone()),
+            Some(Value::BytesVal(b)) => TelemetryValue::Bytes(b.clone()),
             Some(Value::FloatVal(f)) => TelemetryValue::Gauge(*f as f64),
             Some(Value::DoubleVal(d)) => TelemetryValue::Gauge(*d),
             Some(Value::DecimalVal(d)) => {
@@ -341,13 +403,13 @@ impl GnmiSubscriber {
                     .collect();
                 TelemetryValue::Text(format!("[{}]", values.join(",")))
             }
-            Some(Value::AnyVal(any)) => TelemetryValue::Binary(any.value.clone()),
+            Some(Value::AnyVal(any)) => TelemetryValue::Bytes(any.value.clone()),
             Some(Value::JsonVal(j)) => TelemetryValue::Text(String::from_utf8_lossy(j).to_string()),
             Some(Value::JsonIetfVal(j)) => {
                 TelemetryValue::Text(String::from_utf8_lossy(j).to_string())
             }
             Some(Value::AsciiVal(a)) => TelemetryValue::Text(a.clone()),
-            Some(Value::ProtoBytes(p)) => TelemetryValue::Binary(p.clone()),
+            Some(Value::ProtoBytes(p)) => TelemetryValue::Bytes(p.clone()),
             None => TelemetryValue::Text("".to_string()),
         }
     }
@@ -383,11 +445,7 @@ mod tests {
             encoding: GnmiEncoding::Json,
         };
 
-        let subscriber = GnmiSubscriber::new(
-            target,
-            "zensight/gnmi".to_string(),
-            SerializationFormat::Json,
-        );
+        let subscriber = GnmiSubscriber::new(target, "zensight/gnmi".to_string());
 
         let path = subscriber.parse_path("/interfaces/interface/state");
         assert_eq!(path.elem.len(), 3);
@@ -407,11 +465,7 @@ mod tests {
             encoding: GnmiEncoding::Json,
         };
 
-        let subscriber = GnmiSubscriber::new(
-            target,
-            "zensight/gnmi".to_string(),
-            SerializationFormat::Json,
-        );
+        let subscriber = GnmiSubscriber::new(target, "zensight/gnmi".to_string());
 
         let path = subscriber.parse_path("/interfaces/interface[name=eth0]/state");
         assert_eq!(path.elem.len(), 3);
@@ -430,11 +484,7 @@ mod tests {
             encoding: GnmiEncoding::Json,
         };
 
-        let subscriber = GnmiSubscriber::new(
-            target,
-            "zensight/gnmi".to_string(),
-            SerializationFormat::Json,
-        );
+        let subscriber = GnmiSubscriber::new(target, "zensight/gnmi".to_string());
 
         let mut path = Path::default();
         path.elem.push(PathElem {
@@ -472,11 +522,27 @@ mod tests {
             subscriptions: vec![],
             encoding: GnmiEncoding::Json,
         };
-        GnmiSubscriber::new(
-            target,
-            "zensight/gnmi".to_string(),
-            SerializationFormat::Json,
-        )
+        GnmiSubscriber::new(target, "zensight/gnmi".to_string())
+    }
+
+    #[test]
+    fn test_notification_timestamp_source() {
+        let ns = 1_700_000_000_123_456_789;
+        let now = 1_700_000_060_000;
+
+        assert_eq!(
+            notification_timestamp_ms(ns, TimestampSource::Message, now),
+            1_700_000_000_123
+        );
+        assert_eq!(
+            notification_timestamp_ms(ns, TimestampSource::Receipt, now),
+            now
+        );
+        // Unset update timestamp: fall back to receipt.
+        assert_eq!(
+            notification_timestamp_ms(0, TimestampSource::Message, now),
+            now
+        );
     }
 
     fn typed(value: gnmi::typed_value::Value) -> gnmi::TypedValue {
@@ -529,7 +595,7 @@ mod tests {
         );
         assert_eq!(
             sub.typed_value_to_telemetry(&typed(Value::BytesVal(vec![1, 2, 3]))),
-            TelemetryValue::Binary(vec![1, 2, 3])
+            TelemetryValue::Bytes(vec![1, 2, 3])
         );
     }
 
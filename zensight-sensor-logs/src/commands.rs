@@ -2,8 +2,10 @@
 //!
 //! Enables runtime filter updates via Zenoh pub/sub.
 
-use crate::filter::{DynamicFilterInfo, FilterStatsSnapshot, SyslogFilterConfig};
+use crate::config::SyslogSensorConfig;
+use crate::filter::{DynamicFilterInfo, FilterManager, FilterStatsSnapshot, SyslogFilterConfig};
 use serde::{Deserialize, Serialize};
+use std::path::Path;
 
 /// Topic for syslog filter commands/status on the shared control channel.
 pub const FILTER_TOPIC: &str = "filter";
@@ -53,6 +55,21 @@ pub struct FilterStatus {
     pub stats: FilterStatsSnapshot,
 }
 
+/// Control-socket `reload` (#445): re-read `config_path` and swap in its
+/// `syslog.filter` as the base filter. An unreadable or invalid file leaves
+/// the running filter untouched.
+pub fn reload_base_filter(
+    config_path: &Path,
+    filter_manager: &FilterManager,
+) -> Result<serde_json::Value, String> {
+    let config = SyslogSensorConfig::load_from_file(config_path).map_err(|e| e.to_string())?;
+    filter_manager
+        .set_base(&config.syslog.filter)
+        .map_err(|e| e.to_string())?;
+    tracing::info!(path = %config_path.display(), "Base filter reloaded via control socket");
+    Ok(serde_json::json!({ "base_filter": config.syslog.filter }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,4 +124,30 @@ mod tests {
         let cmd: FilterCommand = serde_json::from_str(json).unwrap();
         assert!(matches!(cmd, FilterCommand::GetStatus));
     }
+
+    #[test]
+    fn reload_swaps_the_base_filter_from_the_config_file() {
+        let path =
+            std::env::temp_dir().join(format!("zensight-logs-reload-{}.json5", std::process::id()));
+        let write = |filter: &str| {
+            let config = format!(
+                r#"{{ zenoh: {{ mode: "peer" }}, syslog: {{ listeners: [{{ protocol: "udp", bind: "127.0.0.1:1514" }}], filter: {filter} }} }}"#
+            );
+            std::fs::write(&path, config).unwrap();
+        };
+        let filters = FilterManager::new(&SyslogFilterConfig::default()).unwrap();
+
+        write(r#"{ min_severity: 3, exclude_facilities: ["mail"] }"#);
+        let result = reload_base_filter(&path, &filters).unwrap();
+        assert_eq!(result["base_filter"]["min_severity"], 3);
+        assert_eq!(filters.base_config().min_severity, Some(3));
+        assert_eq!(filters.base_config().exclude_facilities, ["mail"]);
+
+        // A bad pattern is rejected and the running filter stays.
+        write(r#"{ include_message_patterns: [{ pattern: "(", pattern_type: "regex" }] }"#);
+        assert!(reload_base_filter(&path, &filters).is_err());
+        assert_eq!(filters.base_config().min_severity, Some(3));
+
+        std::fs::remove_file(&path).unwrap();
+    }
 }
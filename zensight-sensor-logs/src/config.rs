@@ -3,7 +3,7 @@
 use crate::filter::SyslogFilterConfig;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
-use zensight_common::config::ZenohConfig;
+use zensight_common::config::{ClockSkewConfig, TimestampSource, ZenohConfig};
 
 // Re-export LoggingConfig from the framework for compatibility
 pub use zensight_sensor_core::LoggingConfig;
@@ -28,6 +28,35 @@ pub struct SyslogSensorConfig {
     /// Tier-2 directory-snapshot (`@/snapshot`) limits. Disabled by default.
     #[serde(default)]
     pub snapshot: zensight_sensor_core::SnapshotLimits,
+
+    /// Zenoh congestion control / priority per key or protocol (#410).
+    #[serde(default)]
+    pub publish_qos: zensight_sensor_core::PublishQosConfig,
+
+    /// Bounded queue + timed replay for publishes that fail (#412).
+    #[serde(default)]
+    pub publish_retry: zensight_sensor_core::PublishRetryConfig,
+
+    /// Re-publish matching telemetry under alternate prefixes (#447).
+    #[serde(default)]
+    pub publish_mirror: Vec<zensight_sensor_core::MirrorRule>,
+
+    /// Attach bridge name/version/encoding to each sample (#444).
+    #[serde(default)]
+    pub attach_metadata: bool,
+
+    /// Unix socket for runtime control commands (#445); off when unset.
+    #[serde(default)]
+    pub control_socket: Option<std::path::PathBuf>,
+
+    /// Stamp points with the message's own timestamp (`message`, default) or
+    /// with the time the line was received (`receipt`) (#406).
+    #[serde(default)]
+    pub timestamp_source: TimestampSource,
+
+    /// Warn when message timestamps drift from receipt time (#407).
+    #[serde(default)]
+    pub clock_skew: ClockSkewConfig,
 }
 
 /// Syslog receiver configuration.
@@ -116,11 +145,37 @@ pub struct SyslogConfig {
     /// one record. journald is unaffected (already one record per entry).
     #[serde(default)]
     pub multiline: MultilineConfig,
+
+    /// Upstream syslog collectors every received message is relayed to, raw,
+    /// alongside Zenoh publishing (#434) — e.g. while migrating off them.
+    /// Forwarding is best-effort: a slow or down upstream drops its copies and
+    /// never holds up publishing.
+    #[serde(default)]
+    pub forward_to: Vec<ForwardTarget>,
+}
+
+/// An upstream syslog collector to relay raw messages to (#434).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForwardTarget {
+    /// Protocol: "udp" or "tcp" (octet-counted framing, RFC 6587).
+    pub protocol: ListenerProtocol,
+
+    /// Collector address, e.g. "collector.example.com:514".
+    pub address: String,
+
+    /// Messages queued per target before new ones are dropped. Default 10000.
+    #[serde(default = "default_forward_queue")]
+    pub queue: usize,
+}
+
+fn default_forward_queue() -> usize {
+    10_000
 }
 
 /// Multiline / stacktrace joining configuration (#107, C6).
 ///
-/// Applies to the stream (TCP/Unix) listeners only. Continuation lines (indented
+/// Applies to the stream (TCP/Unix) listeners, and per source address to UDP
+/// when `udp` is set (#433). Continuation lines (indented
 /// stack frames, `Caused by:`, `...`, `Traceback …`) are folded into the
 /// preceding record; the record is emitted when the next real syslog line
 /// (`<PRI>…`) arrives or `flush_timeout_ms` elapses with no new frame.
@@ -144,6 +199,29 @@ pub struct MultilineConfig {
     /// Hard cap on bytes in one joined record. Default 65536.
     #[serde(default = "default_multiline_max_bytes")]
     pub max_bytes: usize,
+
+    /// Which lines count as continuations (#433). Default `markers`.
+    #[serde(default)]
+    pub continuation: ContinuationMode,
+
+    /// Also join UDP datagrams, per source address, within the
+    /// `flush_timeout_ms` window (#433). Off by default: most UDP senders
+    /// already put a whole record in one datagram.
+    #[serde(default)]
+    pub udp: bool,
+}
+
+/// How [`crate::multiline::MultilineJoiner`] recognises continuation lines.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContinuationMode {
+    /// Only clear stack-trace shapes: indented frames, `Caused by:`, `...`,
+    /// `Traceback …`.
+    #[default]
+    Markers,
+    /// Any line that doesn't open with a syslog `<PRI>` or a timestamp, e.g.
+    /// the unindented `ValueError: …` closing a Python traceback.
+    Header,
 }
 
 fn default_multiline_flush_ms() -> u64 {
@@ -163,6 +241,8 @@ impl Default for MultilineConfig {
             flush_timeout_ms: default_multiline_flush_ms(),
             max_lines: default_multiline_max_lines(),
             max_bytes: default_multiline_max_bytes(),
+            continuation: ContinuationMode::default(),
+            udp: false,
         }
     }
 }
@@ -658,7 +738,9 @@ pub struct ListenerConfig {
     /// - For Unix: "/var/run/syslog.sock"
     pub bind: String,
 
-    /// Maximum message size in bytes (UDP only).
+    /// Maximum message size in bytes: the UDP receive buffer, and the frame
+    /// limit for TCP/Unix. A UDP datagram that fills the buffer exactly is
+    /// flagged `truncated` (#432).
     #[serde(default = "default_max_message_size")]
     pub max_message_size: usize,
 
@@ -744,6 +826,9 @@ impl SyslogSensorConfig {
             if listener.bind.is_empty() {
                 anyhow::bail!("Listener {} has empty bind address", i);
             }
+            if listener.max_message_size == 0 {
+                anyhow::bail!("Listener {} has a zero max_message_size", i);
+            }
 
             match listener.protocol {
                 ListenerProtocol::Udp | ListenerProtocol::Tcp => {
@@ -762,11 +847,30 @@ impl SyslogSensorConfig {
             }
         }
 
+        for (i, target) in self.syslog.forward_to.iter().enumerate() {
+            if target.protocol == ListenerProtocol::Unix {
+                anyhow::bail!("forward_to {} must use udp or tcp", i);
+            }
+            if !target.address.contains(':') {
+                anyhow::bail!(
+                    "forward_to {} address must include port (e.g., 'collector:514')",
+                    i
+                );
+            }
+            if target.queue == 0 {
+                anyhow::bail!("forward_to {} has a zero queue", i);
+            }
+        }
+
         Ok(())
     }
 }
 
 impl zensight_sensor_core::SensorConfig for SyslogSensorConfig {
+    fn default_config_json5() -> &'static str {
+        include_str!("../../configs/syslog.json5")
+    }
+
     fn zenoh(&self) -> &ZenohConfig {
         &self.zenoh
     }
@@ -787,6 +891,34 @@ impl zensight_sensor_core::SensorConfig for SyslogSensorConfig {
         self.snapshot.clone()
     }
 
+    fn publish_qos(&self) -> zensight_sensor_core::PublishQosConfig {
+        self.publish_qos.clone()
+    }
+
+    fn publish_retry(&self) -> zensight_sensor_core::PublishRetryConfig {
+        self.publish_retry.clone()
+    }
+
+    fn publish_mirror(&self) -> Vec<zensight_sensor_core::MirrorRule> {
+        self.publish_mirror.clone()
+    }
+
+    fn attach_metadata(&self) -> bool {
+        self.attach_metadata
+    }
+
+    fn control_socket(&self) -> Option<std::path::PathBuf> {
+        self.control_socket.clone()
+    }
+
+    fn timestamp_source(&self) -> TimestampSource {
+        self.timestamp_source
+    }
+
+    fn clock_skew(&self) -> ClockSkewConfig {
+        self.clock_skew.clone()
+    }
+
     fn validate(&self) -> zensight_sensor_core::Result<()> {
         self.validate_config()
             .map_err(|e| zensight_sensor_core::SensorError::config(e.to_string()))
@@ -820,6 +952,7 @@ impl Default for SyslogConfig {
             novelty: NoveltyConfig::default(),
             ingest: IngestConfig::default(),
             multiline: MultilineConfig::default(),
+            forward_to: Vec::new(),
         }
     }
 }
@@ -1142,4 +1275,12 @@ mod tests {
         let config: SyslogSensorConfig = json5::from_str(json).unwrap();
         assert!(config.validate_config().is_ok());
     }
+
+    #[test]
+    fn printed_default_config_documents_every_field() {
+        assert_eq!(
+            zensight_sensor_core::undocumented_fields::<SyslogSensorConfig>().unwrap(),
+            Vec::<String>::new()
+        );
+    }
 }
@@ -390,8 +390,8 @@ pub struct DynamicFilterInfo {
 
 /// Thread-safe filter manager with base and dynamic filters.
 pub struct FilterManager {
-    /// Base filter from configuration.
-    base_filter: CompiledSyslogFilter,
+    /// Base filter from configuration, replaced on `reload` (#445).
+    base_filter: std::sync::RwLock<Arc<CompiledSyslogFilter>>,
     /// Dynamic filters added at runtime.
     dynamic_filters: Arc<RwLock<HashMap<String, CompiledSyslogFilter>>>,
     /// Filter statistics.
@@ -404,7 +404,7 @@ impl FilterManager {
         let base_filter = CompiledSyslogFilter::compile(base_config)?;
 
         Ok(Self {
-            base_filter,
+            base_filter: std::sync::RwLock::new(Arc::new(base_filter)),
             dynamic_filters: Arc::new(RwLock::new(HashMap::new())),
             stats: Arc::new(FilterStats::default()),
         })
@@ -414,7 +414,7 @@ impl FilterManager {
     #[allow(dead_code)]
     pub fn pass_all() -> Self {
         Self {
-            base_filter: CompiledSyslogFilter::pass_all(),
+            base_filter: std::sync::RwLock::new(Arc::new(CompiledSyslogFilter::pass_all())),
             dynamic_filters: Arc::new(RwLock::new(HashMap::new())),
             stats: Arc::new(FilterStats::default()),
         }
@@ -425,7 +425,7 @@ impl FilterManager {
     /// The message must pass the base filter AND all dynamic filters.
     pub async fn matches(&self, msg: &SyslogMessage, hostname: &str) -> bool {
         // Check base filter first
-        if !self.base_filter.matches(msg, hostname) {
+        if !self.base().matches(msg, hostname) {
             self.stats.record_filtered();
             return false;
         }
@@ -468,8 +468,23 @@ impl FilterManager {
     }
 
     /// Get the base filter configuration.
-    pub fn base_config(&self) -> &SyslogFilterConfig {
-        self.base_filter.config()
+    pub fn base_config(&self) -> SyslogFilterConfig {
+        self.base().config().clone()
+    }
+
+    /// Replace the base filter, e.g. after the config file changed (#445).
+    /// On a compile error the current filter stays in place.
+    pub fn set_base(&self, config: &SyslogFilterConfig) -> Result<(), FilterCompileError> {
+        let compiled = Arc::new(CompiledSyslogFilter::compile(config)?);
+        *self.base_filter.write().unwrap_or_else(|e| e.into_inner()) = compiled;
+        Ok(())
+    }
+
+    fn base(&self) -> Arc<CompiledSyslogFilter> {
+        self.base_filter
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
     }
 
     /// Get information about all dynamic filters.
@@ -0,0 +1,238 @@
+//! Raw syslog relay to upstream collectors (#434).
+//!
+//! During a migration the bridge keeps feeding the old collector: every
+//! message a network or Unix listener receives is sent onward, as the bytes
+//! that arrived, to each `forward_to` target in addition to being published
+//! on Zenoh — including ones that fail to parse or are filtered out.
+//! journald entries are not syslog on the wire and are not relayed.
+//!
+//! Each target gets its own bounded queue and task. [`Forwarder::forward`]
+//! only `try_send`s, so a slow or unreachable upstream drops (and counts) its
+//! copies instead of back-pressuring the publish loop. UDP targets get one
+//! datagram per message; TCP targets use RFC 6587 octet-counting so
+//! multi-line records survive the stream, and reconnect on the next message
+//! after a failure.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use tokio::io::AsyncWriteExt;
+use tokio::net::{TcpStream, UdpSocket};
+use tokio::sync::mpsc;
+
+use crate::config::{ForwardTarget, ListenerProtocol};
+
+/// How long a TCP (re)connect may take before the message is dropped.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Relays raw messages to the configured upstream collectors.
+#[derive(Debug, Default)]
+pub struct Forwarder {
+    targets: Vec<TargetQueue>,
+}
+
+#[derive(Debug)]
+struct TargetQueue {
+    address: String,
+    tx: mpsc::Sender<Arc<[u8]>>,
+    /// Messages dropped because the target's queue was full.
+    dropped: AtomicU64,
+}
+
+impl Forwarder {
+    /// Spawn one relay task per target. Must be called within a tokio runtime.
+    pub fn spawn(targets: &[ForwardTarget]) -> Self {
+        let targets = targets
+            .iter()
+            .map(|target| {
+                let (tx, rx) = mpsc::channel(target.queue.max(1));
+                let address = target.address.clone();
+                match target.protocol {
+                    ListenerProtocol::Tcp => tokio::spawn(run_tcp(address.clone(), rx)),
+                    _ => tokio::spawn(run_udp(address.clone(), rx)),
+                };
+                tracing::info!(protocol = %target.protocol, address = %address, "syslog forwarding enabled");
+                TargetQueue {
+                    address,
+                    tx,
+                    dropped: AtomicU64::new(0),
+                }
+            })
+            .collect();
+        Self { targets }
+    }
+
+    /// Queue `raw` for every target. Never blocks: a full queue drops the
+    /// message for that target only.
+    pub fn forward(&self, raw: &[u8]) {
+        if self.targets.is_empty() {
+            return;
+        }
+        let bytes: Arc<[u8]> = Arc::from(raw);
+        for target in &self.targets {
+            if target.tx.try_send(bytes.clone()).is_err() {
+                let dropped = target.dropped.fetch_add(1, Ordering::Relaxed) + 1;
+                if dropped.is_power_of_two() {
+                    tracing::warn!(
+                        address = %target.address,
+                        dropped,
+                        "syslog forward queue full, dropping messages"
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Relay to a UDP collector: one datagram per message.
+async fn run_udp(address: String, mut rx: mpsc::Receiver<Arc<[u8]>>) {
+    let mut socket: Option<UdpSocket> = None;
+    let mut healthy = true;
+    while let Some(raw) = rx.recv().await {
+        if socket.is_none() {
+            socket = connect_udp(&address).await;
+        }
+        let sent = match &socket {
+            Some(s) => s.send(&raw).await.map(|_| ()),
+            None => Err(std::io::Error::other("unresolved")),
+        };
+        if let Err(e) = sent {
+            socket = None;
+            if healthy {
+                tracing::warn!(address = %address, error = %e, "syslog forward failed");
+            }
+        } else if !healthy {
+            tracing::info!(address = %address, "syslog forward recovered");
+        }
+        healthy = socket.is_some();
+    }
+}
+
+async fn connect_udp(address: &str) -> Option<UdpSocket> {
+    let addr = tokio::net::lookup_host(address).await.ok()?.next()?;
+    let bind = if addr.is_ipv4() {
+        "0.0.0.0:0"
+    } else {
+        "[::]:0"
+    };
+    let socket = UdpSocket::bind(bind).await.ok()?;
+    socket.connect(addr).await.ok()?;
+    Some(socket)
+}
+
+/// Relay to a TCP collector with octet-counted framing (`LEN SP MSG`).
+async fn run_tcp(address: String, mut rx: mpsc::Receiver<Arc<[u8]>>) {
+    let mut stream: Option<TcpStream> = None;
+    let mut healthy = true;
+    while let Some(raw) = rx.recv().await {
+        if stream.is_none() {
+            stream = match tokio::time::timeout(CONNECT_TIMEOUT, TcpStream::connect(&address)).await
+            {
+                Ok(Ok(s)) => Some(s),
+                Ok(Err(e)) => {
+                    if healthy {
+                        tracing::warn!(address = %address, error = %e, "syslog forward connect failed");
+                    }
+                    healthy = false;
+                    continue;
+                }
+                Err(_) => {
+                    if healthy {
+                        tracing::warn!(address = %address, "syslog forward connect timed out");
+                    }
+                    healthy = false;
+                    continue;
+                }
+            };
+        }
+        let Some(s) = stream.as_mut() else { continue };
+
+        let mut frame = format!("{} ", raw.len()).into_bytes();
+        frame.extend_from_slice(&raw);
+        match s.write_all(&frame).await {
+            Ok(()) => {
+                if !healthy {
+                    tracing::info!(address = %address, "syslog forward recovered");
+                }
+                healthy = true;
+            }
+            Err(e) => {
+                if healthy {
+                    tracing::warn!(address = %address, error = %e, "syslog forward failed");
+                }
+                healthy = false;
+                stream = None;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncReadExt;
+    use tokio::net::TcpListener;
+
+    fn target(protocol: ListenerProtocol, address: String) -> ForwardTarget {
+        ForwardTarget {
+            protocol,
+            address,
+            queue: 16,
+        }
+    }
+
+    #[tokio::test]
+    async fn forwards_raw_message_to_udp_upstream() {
+        let upstream = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let addr = upstream.local_addr().unwrap().to_string();
+        let forwarder = Forwarder::spawn(&[target(ListenerProtocol::Udp, addr)]);
+
+        let raw = "<34>Jan  5 14:30:00 myhost sshd[1234]: Connection from 10.0.0.1";
+        forwarder.forward(raw.as_bytes());
+
+        let mut buf = [0u8; 512];
+        let len = tokio::time::timeout(Duration::from_secs(5), upstream.recv(&mut buf))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(&buf[..len], raw.as_bytes());
+    }
+
+    #[tokio::test]
+    async fn forwards_octet_counted_frames_to_tcp_upstream() {
+        let upstream = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = upstream.local_addr().unwrap().to_string();
+        let forwarder = Forwarder::spawn(&[target(ListenerProtocol::Tcp, addr)]);
+
+        forwarder.forward(b"<14>one");
+        forwarder.forward(b"<11>two\n\tat Foo.bar");
+
+        let (mut conn, _) = upstream.accept().await.unwrap();
+        let expected = "7 <14>one19 <11>two\n\tat Foo.bar";
+        let mut buf = vec![0u8; expected.len()];
+        tokio::time::timeout(Duration::from_secs(5), conn.read_exact(&mut buf))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(String::from_utf8(buf).unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn unreachable_upstream_never_blocks_and_counts_drops() {
+        // Nothing listens here and the one-slot queue can't drain between
+        // these synchronous calls: `forward` drops instead of waiting.
+        let closed = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = closed.local_addr().unwrap().to_string();
+        drop(closed);
+        let forwarder = Forwarder::spawn(&[ForwardTarget {
+            queue: 1,
+            ..target(ListenerProtocol::Tcp, addr)
+        }]);
+
+        for _ in 0..100 {
+            forwarder.forward(b"<14>dropped");
+        }
+        assert!(forwarder.targets[0].dropped.load(Ordering::Relaxed) > 0);
+    }
+}
@@ -645,6 +645,7 @@ mod tests {
             message: crate::parser::parse(&format!("<14>{body}")).unwrap(),
             source: MessageSource::Unix,
             resolved_hostname: "h".into(),
+            truncated: false,
         }
     }
 
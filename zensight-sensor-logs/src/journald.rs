@@ -240,6 +240,7 @@ fn run(
                 message,
                 source: MessageSource::Journald,
                 resolved_hostname,
+                truncated: false,
             };
             // Overflow policy (#62): block (backpressure) vs drop_newest (shed +
             // count). Either way a closed channel means shutdown.
@@ -673,6 +674,7 @@ mod tests {
             message: crate::parser::parse(&format!("<14>{body}")).unwrap(),
             source: MessageSource::Journald,
             resolved_hostname: "h".into(),
+            truncated: false,
         };
         // First send fills the capacity-1 channel.
         assert!(matches!(
@@ -700,6 +702,7 @@ mod tests {
             message: crate::parser::parse("<14>x").unwrap(),
             source: MessageSource::Journald,
             resolved_hostname: "h".into(),
+            truncated: false,
         };
         assert!(matches!(
             send_entry(&tx, msg, OverflowPolicy::DropNewest, &stats),
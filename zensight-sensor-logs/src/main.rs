@@ -9,6 +9,7 @@ mod config;
 mod derived;
 mod events;
 mod filter;
+mod forward;
 mod ingest;
 #[cfg(feature = "journald")]
 mod journald;
@@ -24,9 +25,11 @@ use config::SyslogSensorConfig;
 use events::EventDetector;
 use filter::FilterManager;
 use std::sync::Arc;
-use zensight_common::serialization::{Format, encode};
+use zensight_common::serialization::Format;
 use zensight_common::telemetry::Protocol;
-use zensight_sensor_core::{AlertReporter, SensorArgs, SensorRunner, serve_alerts_query};
+use zensight_sensor_core::{
+    AlertReporter, SensorArgs, SensorConfig, SensorRunner, serve_alerts_query,
+};
 
 /// Process-wide monotonic sequence that disambiguates per-line log event uids
 /// (#104) when multiple lines share a millisecond timestamp.
@@ -36,6 +39,10 @@ static LOG_EVENT_SEQ: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU6
 async fn main() -> Result<()> {
     // Parse CLI arguments
     let args = SensorArgs::parse_with_default("syslog.json5");
+    if args.print_default_config {
+        print!("{}", SyslogSensorConfig::default_config_json5());
+        return Ok(());
+    }
 
     // Load configuration
     let config = SyslogSensorConfig::load_from_file(&args.config)?;
@@ -70,6 +77,10 @@ async fn main() -> Result<()> {
     // Determine serialization format (default to JSON)
     let format = Format::Json;
 
+    // Telemetry goes out through the runner's publisher (JSON) so sample
+    // metadata, mirrors and retry apply to it (#444)
+    let publisher = runner.publisher();
+
     // Create filter manager
     let filter_manager = Arc::new(
         FilterManager::new(&syslog_config.filter)
@@ -92,6 +103,8 @@ async fn main() -> Result<()> {
     // Process incoming messages
     let key_prefix = syslog_config.key_prefix.clone();
     let include_raw = syslog_config.include_raw_message;
+    let timestamp_source = runner.config().timestamp_source;
+    let clock_skew = runner.clock_skew_detector();
     let enable_dynamic_filters = syslog_config.enable_dynamic_filters;
 
     // Build status metadata
@@ -111,6 +124,17 @@ async fn main() -> Result<()> {
 
     let mut runner = runner;
 
+    // `reload` on the control socket re-reads the config file and applies the
+    // base filter (#445); other settings still need a restart.
+    #[cfg(unix)]
+    {
+        let config_path = args.config.clone();
+        let filter_manager = filter_manager.clone();
+        runner.control().register("reload", move |_| {
+            commands::reload_base_filter(&config_path, &filter_manager)
+        });
+    }
+
     if enable_dynamic_filters {
         let command_key = commands::command_key(&key_prefix);
         let status_key = commands::status_key(&key_prefix);
@@ -281,7 +305,7 @@ async fn main() -> Result<()> {
     if !syslog_config.listeners.is_empty() {
         let stats = ingest_stats.clone();
         let health = runner.health();
-        let session_tick = session.clone();
+        let publisher_tick = publisher.clone();
         let key_prefix_tick = key_prefix.clone();
         let interval_secs = syslog_config.derived_interval_secs.max(1);
         let drop_alert_ratio = syslog_config.ingest.drop_alert_ratio;
@@ -301,13 +325,8 @@ async fn main() -> Result<()> {
                 // Publish the ingest counters as telemetry.
                 for point in cur.to_points(&source) {
                     let key = format!("{}/{}/{}", key_prefix_tick, point.source, point.metric);
-                    match encode(&point, format) {
-                        Ok(payload) => {
-                            if let Err(e) = session_tick.put(&key, payload).await {
-                                tracing::warn!(error = %e, key, "failed to publish ingest metric");
-                            }
-                        }
-                        Err(e) => tracing::warn!(error = %e, "failed to encode ingest metric"),
+                    if let Err(e) = publisher_tick.publish_to_key(&key, &point).await {
+                        tracing::warn!(error = %e, key, "failed to publish ingest metric");
                     }
                 }
 
@@ -362,7 +381,7 @@ async fn main() -> Result<()> {
         Arc::new(derived::LogAggregator::new(syslog_config.top_units).with_budget(budget))
     });
     if let Some(agg) = aggregator.clone() {
-        let session_tick = session.clone();
+        let publisher_tick = publisher.clone();
         let key_prefix_tick = key_prefix.clone();
         let interval_secs = syslog_config.derived_interval_secs.max(1);
         let stats_tick = journald_stats.clone();
@@ -403,13 +422,8 @@ async fn main() -> Result<()> {
 
                 for point in points {
                     let key = format!("{}/{}/{}", key_prefix_tick, point.source, point.metric);
-                    match encode(&point, format) {
-                        Ok(payload) => {
-                            if let Err(e) = session_tick.put(&key, payload).await {
-                                tracing::warn!(error = %e, key, "failed to publish derived metric");
-                            }
-                        }
-                        Err(e) => tracing::warn!(error = %e, "failed to encode derived metric"),
+                    if let Err(e) = publisher_tick.publish_to_key(&key, &point).await {
+                        tracing::warn!(error = %e, key, "failed to publish derived metric");
                     }
                 }
             }
@@ -432,7 +446,7 @@ async fn main() -> Result<()> {
         Arc::new(template::TemplateAggregator::new(params, t.top_templates))
     });
     if let Some(tagg) = template_agg.clone() {
-        let session_tick = session.clone();
+        let publisher_tick = publisher.clone();
         let key_prefix_tick = key_prefix.clone();
         let interval_secs = syslog_config.derived_interval_secs.max(1);
         let source = hostname::get()
@@ -446,13 +460,8 @@ async fn main() -> Result<()> {
                 tick.tick().await;
                 for point in tagg.emit(&source) {
                     let key = format!("{}/{}/{}", key_prefix_tick, point.source, point.metric);
-                    match encode(&point, format) {
-                        Ok(payload) => {
-                            if let Err(e) = session_tick.put(&key, payload).await {
-                                tracing::warn!(error = %e, key, "failed to publish template metric");
-                            }
-                        }
-                        Err(e) => tracing::warn!(error = %e, "failed to encode template metric"),
+                    if let Err(e) = publisher_tick.publish_to_key(&key, &point).await {
+                        tracing::warn!(error = %e, key, "failed to publish template metric");
                     }
                 }
             }
@@ -524,16 +533,22 @@ async fn main() -> Result<()> {
     }
 
     // Spawn the message processing task
-    let session_clone = session.clone();
     let publish_health = runner.health();
     let aggregator_loop = aggregator.clone();
     let template_loop = template_agg.clone();
     let novelty_loop = novelty.clone();
     let novelty_reporter = novelty.is_some().then(|| alert_reporter.clone()).flatten();
-    runner.spawn(async move {
+    // On shutdown, stop taking lines and publish the ones already queued
+    // (#461)
+    let mut shutdown = runner.shutdown_signal();
+    runner.spawn_draining(async move {
         loop {
             tokio::select! {
+                _ = shutdown.wait_for(|&stop| stop), if !rx.is_closed() => rx.close(),
                 Some(received) = rx.recv() => {
+                    // Every sending host counts, filtered or not (#436).
+                    publish_health.announce_device(&received.resolved_hostname).await;
+
                     // Known-event detection runs before filtering so a coredump
                     // or unit failure still alerts even if it's filtered from the
                     // telemetry stream.
@@ -560,16 +575,20 @@ async fn main() -> Result<()> {
                     // Per-line event uid (#104): timestamp-prefixed + monotonic
                     // sequence, so each log line gets a unique, time-sortable key
                     // (`events/<uid>`) instead of last-writer-wins facility/severity.
-                    let ts_ms = received
-                        .message
-                        .timestamp
-                        .map(|dt| dt.timestamp_millis())
-                        .unwrap_or_else(|| chrono::Utc::now().timestamp_millis());
+                    let now_ms = chrono::Utc::now().timestamp_millis();
+                    if let Some(dt) = received.message.timestamp {
+                        clock_skew
+                            .observe(&received.resolved_hostname, dt.timestamp_millis(), now_ms)
+                            .await;
+                    }
+                    let ts_ms =
+                        receiver::event_timestamp_ms(&received.message, timestamp_source, now_ms);
                     let seq = LOG_EVENT_SEQ.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
                     let uid = receiver::make_log_uid(ts_ms, seq);
 
                     // Convert to telemetry point
-                    let mut point = receiver::to_telemetry_point(&received, include_raw, &uid);
+                    let mut point =
+                        receiver::to_telemetry_point(&received, include_raw, &uid, ts_ms);
 
                     // Log-template mining (#102): mine the message text and
                     // attach the stable template id + masked template as labels.
@@ -604,26 +623,19 @@ async fn main() -> Result<()> {
                     // Build key expression
                     let key = receiver::build_key_expr(&key_prefix, &received, &uid);
 
-                    // Serialize and publish
-                    match encode(&point, format) {
-                        Ok(payload) => {
-                            if let Err(e) = session_clone.put(&key, payload).await {
-                                tracing::error!("Failed to publish to {}: {}", key, e);
-                            } else {
-                                // Count published telemetry so the Sensors view
-                                // reflects this sensor's throughput (#62).
-                                publish_health.record_metrics_published(1);
-                                tracing::debug!(
-                                    "Published: {} from {} [{}]",
-                                    key,
-                                    received.resolved_hostname,
-                                    received.message.severity.as_str()
-                                );
-                            }
-                        }
-                        Err(e) => {
-                            tracing::error!("Failed to serialize telemetry: {}", e);
-                        }
+                    // Each line is a one-off event under its own key
+                    if let Err(e) = publisher.publish_event(&key, &point).await {
+                        tracing::error!("Failed to publish to {}: {}", key, e);
+                    } else {
+                        // Count published telemetry so the Sensors view
+                        // reflects this sensor's throughput (#62).
+                        publish_health.record_metrics_published(1);
+                        tracing::debug!(
+                            "Published: {} from {} [{}]",
+                            key,
+                            received.resolved_hostname,
+                            received.message.severity.as_str()
+                        );
                     }
                 }
                 else => break,
@@ -673,7 +685,7 @@ async fn handle_filter_command(filter_manager: &FilterManager, cmd: FilterComman
 /// Build filter status response.
 async fn build_filter_status(filter_manager: &FilterManager) -> FilterStatus {
     FilterStatus {
-        base_filter: filter_manager.base_config().clone(),
+        base_filter: filter_manager.base_config(),
         dynamic_filters: filter_manager.dynamic_filter_info().await,
         stats: filter_manager.stats(),
     }
@@ -3,7 +3,8 @@
 //! LF framing shatters a Java/Python/Go traceback into one syslog record per
 //! line: the stack frames lose their head, the per-line event store fills with
 //! orphaned fragments, and template mining sees garbage. journald is already
-//! one-record-per-entry, so this is **network-stream only**.
+//! one-record-per-entry, so this is **network only**: the stream listeners
+//! always, and UDP per source address when `multiline.udp` is set (#433).
 //!
 //! [`MultilineJoiner`] sits between [`crate::ingest::FrameReader`] and the
 //! parser: it buffers a head line and folds following **continuation** lines
@@ -16,7 +17,7 @@
 //! The `push`/`flush` core is pure (no clock, no I/O) so the join decisions are
 //! unit-testable; the listener drives the flush timeout with `tokio::select!`.
 
-use crate::config::MultilineConfig;
+use crate::config::{ContinuationMode, MultilineConfig};
 
 /// Stateful joiner: feed it raw frames in order; it yields completed (possibly
 /// multi-line) raw records. Bounded by `max_lines` / `max_bytes` so a runaway
@@ -24,6 +25,7 @@ use crate::config::MultilineConfig;
 #[derive(Debug)]
 pub struct MultilineJoiner {
     enabled: bool,
+    continuation: ContinuationMode,
     max_lines: usize,
     max_bytes: usize,
     /// The record currently being accumulated (head + folded continuations).
@@ -35,6 +37,7 @@ impl MultilineJoiner {
     pub fn new(cfg: &MultilineConfig) -> Self {
         Self {
             enabled: cfg.enabled,
+            continuation: cfg.continuation,
             max_lines: cfg.max_lines.max(1),
             max_bytes: cfg.max_bytes.max(1),
             pending: None,
@@ -61,7 +64,11 @@ impl MultilineJoiner {
             return None;
         };
 
-        if is_continuation(&raw) {
+        let continues = match self.continuation {
+            ContinuationMode::Markers => is_continuation(&raw),
+            ContinuationMode::Header => !starts_record(&raw),
+        };
+        if continues {
             // Appending keeps the same record — unless it would breach a cap, in
             // which case flush what we have and start a fresh record with this
             // line so neither memory nor a single event grows without bound.
@@ -118,6 +125,49 @@ pub fn is_continuation(line: &str) -> bool {
     MARKERS.iter().any(|m| t.starts_with(m))
 }
 
+/// Does this raw frame open a new record — a syslog `<PRI>` or a leading
+/// timestamp (`Jan  5 14:30:00`, `2024-01-05T…`)? Used by
+/// [`ContinuationMode::Header`], where everything else is a continuation.
+pub fn starts_record(line: &str) -> bool {
+    let b = line.as_bytes();
+
+    // <PRI>: 1-3 digits in angle brackets.
+    if let Some(rest) = line.strip_prefix('<')
+        && let Some(end) = rest.find('>')
+        && (1..=3).contains(&end)
+        && rest[..end].bytes().all(|c| c.is_ascii_digit())
+    {
+        return true;
+    }
+
+    // ISO 8601 date: YYYY-MM-DD.
+    let iso = b.len() >= 10
+        && b[..4].iter().all(u8::is_ascii_digit)
+        && b[4] == b'-'
+        && b[5..7].iter().all(u8::is_ascii_digit)
+        && b[7] == b'-'
+        && b[8..10].iter().all(u8::is_ascii_digit);
+    if iso {
+        return true;
+    }
+
+    // RFC 3164 date: "Mmm dd hh:mm:ss" (day space-padded).
+    const MONTHS: &[&str] = &[
+        "Jan ", "Feb ", "Mar ", "Apr ", "May ", "Jun ", "Jul ", "Aug ", "Sep ", "Oct ", "Nov ",
+        "Dec ",
+    ];
+    MONTHS.iter().any(|m| line.starts_with(m))
+        && line[4..]
+            .trim_start()
+            .split_once(' ')
+            .is_some_and(|(day, time)| {
+                day.bytes().all(|c| c.is_ascii_digit())
+                    && time.len() >= 8
+                    && time.as_bytes()[2] == b':'
+                    && time.as_bytes()[5] == b':'
+            })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,6 +178,8 @@ mod tests {
             flush_timeout_ms: 200,
             max_lines: 500,
             max_bytes: 65536,
+            continuation: ContinuationMode::Markers,
+            udp: false,
         }
     }
 
@@ -201,6 +253,58 @@ mod tests {
         assert_eq!(j.flush(), Some("  world".into()));
     }
 
+    #[test]
+    fn header_mode_reassembles_a_traceback_into_one_point() {
+        use crate::receiver::{MessageSource, ReceivedMessage, to_telemetry_point};
+        use zensight_common::telemetry::TelemetryValue;
+
+        let mut c = cfg(true);
+        c.continuation = ContinuationMode::Header;
+        let mut j = MultilineJoiner::new(&c);
+
+        // The unindented `ValueError:` line only joins in header mode.
+        let lines = [
+            "<11>Jan  5 14:30:00 web app[42]: Traceback (most recent call last):",
+            "  File \"app.py\", line 9, in <module>",
+            "    main()",
+            "  File \"app.py\", line 5, in main",
+            "ValueError: bad input",
+        ];
+        for line in lines {
+            assert_eq!(j.push(line.into()), None);
+        }
+        let raw = j
+            .push("<14>Jan  5 14:30:01 web app[42]: next".into())
+            .unwrap();
+
+        let received = ReceivedMessage {
+            message: crate::parser::parse(&raw).unwrap(),
+            source: MessageSource::Unix,
+            resolved_hostname: "web".into(),
+            truncated: false,
+        };
+        let point = to_telemetry_point(&received, false, "0000000000000000000000001", 0);
+        let TelemetryValue::Text(text) = point.value else {
+            panic!("expected a text value");
+        };
+        assert_eq!(text.lines().count(), 5);
+        assert!(text.starts_with("Traceback"));
+        assert!(text.ends_with("ValueError: bad input"));
+        assert_eq!(point.labels.get("app"), Some(&"app".to_string()));
+    }
+
+    #[test]
+    fn record_start_detection() {
+        assert!(starts_record("<14>anything"));
+        assert!(starts_record("Jan  5 14:30:00 host app: x"));
+        assert!(starts_record("Dec 25 01:02:03 host app: x"));
+        assert!(starts_record("2024-01-05T14:30:00Z host app: x"));
+        assert!(!starts_record("ValueError: bad input"));
+        assert!(!starts_record("<script>"));
+        assert!(!starts_record("Jan is a month"));
+        assert!(!starts_record("  File \"x.py\""));
+    }
+
     #[test]
     fn continuation_detection() {
         assert!(is_continuation("    at com.Foo.bar(Foo.java:1)"));
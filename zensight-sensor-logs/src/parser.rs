@@ -208,19 +208,20 @@ pub enum SyslogVersion {
 
 // RFC 5424 pattern: <PRI>VERSION TIMESTAMP HOSTNAME APP-NAME PROCID MSGID STRUCTURED-DATA MSG
 static RFC5424_REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"^<(\d{1,3})>(\d+) (\S+) (\S+) (\S+) (\S+) (\S+) (\[.*?\]|-|\s*) ?(.*)$").unwrap()
+    Regex::new(r"^<(\d{1,3})>(\d+) (\S+) (\S+) (\S+) (\S+) (\S+) (\[.*?\]|-|\s*) ?((?s:.*))$")
+        .unwrap()
 });
 
 // RFC 3164 pattern: <PRI>TIMESTAMP HOSTNAME TAG: MSG
 // Timestamp formats: "Mmm dd hh:mm:ss" or "Mmm  d hh:mm:ss"
 static RFC3164_REGEX: Lazy<Regex> = Lazy::new(|| {
     Regex::new(
-        r"^<(\d{1,3})>([A-Za-z]{3}\s+\d{1,2}\s+\d{2}:\d{2}:\d{2})\s+(\S+)\s+(\S+?)(?:\[(\d+)\])?:\s*(.*)$"
+        r"^<(\d{1,3})>([A-Za-z]{3}\s+\d{1,2}\s+\d{2}:\d{2}:\d{2})\s+(\S+)\s+(\S+?)(?:\[(\d+)\])?:\s*((?s:.*))$"
     ).unwrap()
 });
 
 // Fallback pattern for messages with just PRI
-static SIMPLE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^<(\d{1,3})>(.*)$").unwrap());
+static SIMPLE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^<(\d{1,3})>((?s:.*))$").unwrap());
 
 // RFC 5424 structured data pattern
 // SD-ELEMENT = "[" SD-ID *(SP SD-PARAM) "]"
@@ -3,6 +3,7 @@
 use crate::config::{
     ListenerConfig, ListenerProtocol, MultilineConfig, OverflowPolicy, SyslogConfig,
 };
+use crate::forward::Forwarder;
 use crate::ingest::{FrameReader, IngestStats, SharedRateLimiter, forward_parsed};
 use crate::multiline::MultilineJoiner;
 use crate::parser::{self, SyslogMessage};
@@ -16,6 +17,7 @@ use std::time::Instant;
 use tokio::net::{TcpListener, UdpSocket, UnixListener};
 use tokio::sync::mpsc;
 use tokio::time::Duration;
+use zensight_common::TimestampSource;
 use zensight_common::telemetry::{Protocol, TelemetryPoint, TelemetryValue};
 
 /// Received syslog message with source information.
@@ -27,6 +29,9 @@ pub struct ReceivedMessage {
     pub source: MessageSource,
     /// Resolved hostname (from aliases or reverse DNS).
     pub resolved_hostname: String,
+    /// The UDP datagram filled the receive buffer, so its tail was most likely
+    /// cut off (#432).
+    pub truncated: bool,
 }
 
 /// Source of a received message.
@@ -128,6 +133,8 @@ struct IngestCtx {
     /// Multiline-join settings for the stream paths (#107). Copied per
     /// connection into a fresh [`MultilineJoiner`].
     multiline: MultilineConfig,
+    /// Raw relay to upstream collectors (#434), fed the bytes as received.
+    forwarder: Arc<Forwarder>,
 }
 
 /// Start all configured listeners and return the message channel, the shared
@@ -156,6 +163,7 @@ pub async fn start_listeners(
         )),
         overflow: config.ingest.overflow,
         multiline: config.multiline,
+        forwarder: Arc::new(Forwarder::spawn(&config.forward_to)),
     };
 
     for listener_config in &config.listeners {
@@ -235,50 +243,134 @@ async fn run_udp_listener(
 
     let mut buf = vec![0u8; config.max_message_size];
 
-    loop {
-        match socket.recv_from(&mut buf).await {
-            Ok((len, addr)) => {
-                let data = &buf[..len];
-
-                // Try to parse as UTF-8
-                let text = match std::str::from_utf8(data) {
-                    Ok(s) => s,
-                    Err(_) => {
-                        // Try lossy conversion for non-UTF8 messages
-                        &String::from_utf8_lossy(data)
-                    }
-                };
-
-                IngestStats::inc(&ctx.stats.received);
-                if let Some(message) = parser::parse(text) {
-                    IngestStats::inc(&ctx.stats.parsed);
-                    let resolved_hostname = resolve_hostname_network(&addr, &message, &aliases);
+    // Stacktrace joining for UDP (#433): one joiner per source address, each
+    // flushed once its sender has been quiet for `flush_timeout_ms`.
+    let join = ctx.multiline.enabled && ctx.multiline.udp;
+    let flush_after = Duration::from_millis(ctx.multiline.flush_timeout_ms.max(1));
+    let mut joiners: HashMap<SocketAddr, (MultilineJoiner, Instant)> = HashMap::new();
 
-                    let received = ReceivedMessage {
-                        message,
-                        source: MessageSource::Network(addr),
-                        resolved_hostname,
-                    };
+    loop {
+        let next_flush = joiners.values().map(|(_, last)| *last + flush_after).min();
+        let datagram = match next_flush {
+            Some(deadline) => tokio::select! {
+                r = socket.recv_from(&mut buf) => Some(r),
+                _ = tokio::time::sleep_until(deadline.into()) => None,
+            },
+            None => Some(socket.recv_from(&mut buf).await),
+        };
 
-                    if !forward_parsed(received, &tx, &ctx.stats, &ctx.limiter, ctx.overflow).await
+        let (len, addr) = match datagram {
+            // Idle flush: emit every record whose window has elapsed.
+            None => {
+                let now = Instant::now();
+                let idle: Vec<SocketAddr> = joiners
+                    .iter()
+                    .filter(|(_, (_, last))| now >= *last + flush_after)
+                    .map(|(addr, _)| *addr)
+                    .collect();
+                for addr in idle {
+                    if let Some((mut joiner, _)) = joiners.remove(&addr)
+                        && let Some(raw) = joiner.flush()
+                        && !process_record(raw, &MessageSource::Network(addr), &tx, &aliases, &ctx)
+                            .await
                     {
                         tracing::warn!("Receiver channel closed");
-                        break;
+                        return Ok(());
                     }
-                } else {
-                    IngestStats::inc(&ctx.stats.parse_failed);
-                    tracing::debug!("Failed to parse syslog message from {}: {:?}", addr, text);
                 }
+                continue;
             }
-            Err(e) => {
+            Some(Ok(received)) => received,
+            Some(Err(e)) => {
                 tracing::error!("UDP receive error: {}", e);
+                continue;
+            }
+        };
+
+        let data = &buf[..len];
+        let truncated = datagram_truncated(len, buf.len());
+        if truncated {
+            tracing::warn!(
+                "UDP datagram from {} filled the {}-byte receive buffer and was likely \
+                 truncated; raise max_message_size",
+                addr,
+                buf.len()
+            );
+        }
+
+        IngestStats::inc(&ctx.stats.received);
+        // Relay the datagram verbatim, before parsing or filtering, so the
+        // upstream collector keeps receiving everything during a migration.
+        ctx.forwarder.forward(data);
+
+        if join {
+            let source = MessageSource::Network(addr);
+            if truncated {
+                // Keep the truncated flag: emit anything pending for this
+                // source first, then the datagram on its own.
+                if let Some((mut joiner, _)) = joiners.remove(&addr)
+                    && let Some(raw) = joiner.flush()
+                    && !process_record(raw, &source, &tx, &aliases, &ctx).await
+                {
+                    tracing::warn!("Receiver channel closed");
+                    break;
+                }
+            } else {
+                let (joiner, last) = joiners
+                    .entry(addr)
+                    .or_insert_with(|| (MultilineJoiner::new(&ctx.multiline), Instant::now()));
+                *last = Instant::now();
+                let text = String::from_utf8_lossy(data).into_owned();
+                if let Some(raw) = joiner.push(text)
+                    && !process_record(raw, &source, &tx, &aliases, &ctx).await
+                {
+                    tracing::warn!("Receiver channel closed");
+                    break;
+                }
+                continue;
+            }
+        }
+
+        // Try to parse as UTF-8
+        let text = match std::str::from_utf8(data) {
+            Ok(s) => s,
+            Err(_) => {
+                // Try lossy conversion for non-UTF8 messages
+                &String::from_utf8_lossy(data)
+            }
+        };
+
+        if let Some(message) = parser::parse(text) {
+            IngestStats::inc(&ctx.stats.parsed);
+            let resolved_hostname = resolve_hostname_network(&addr, &message, &aliases);
+
+            let received = ReceivedMessage {
+                message,
+                source: MessageSource::Network(addr),
+                resolved_hostname,
+                truncated,
+            };
+
+            if !forward_parsed(received, &tx, &ctx.stats, &ctx.limiter, ctx.overflow).await {
+                tracing::warn!("Receiver channel closed");
+                break;
             }
+        } else {
+            IngestStats::inc(&ctx.stats.parse_failed);
+            tracing::debug!("Failed to parse syslog message from {}: {:?}", addr, text);
         }
     }
 
     Ok(())
 }
 
+/// Whether a `len`-byte datagram received into a `buf_len`-byte buffer was
+/// likely truncated (#432). The kernel silently discards the excess of an
+/// oversized datagram, so one that fills the buffer exactly is the only sign.
+fn datagram_truncated(len: usize, buf_len: usize) -> bool {
+    len >= buf_len
+}
+
 /// Run a TCP syslog listener.
 async fn run_tcp_listener(
     config: &ListenerConfig,
@@ -384,6 +476,8 @@ where
             }
             Some(Ok(Some(bytes))) => {
                 IngestStats::inc(&ctx.stats.received);
+                // The frame as received, relayed before parsing (#434).
+                ctx.forwarder.forward(&bytes);
                 let text = String::from_utf8_lossy(&bytes).into_owned();
                 if let Some(raw) = joiner.push(text)
                     && !process_record(raw, &source, tx, aliases, ctx).await
@@ -429,6 +523,7 @@ async fn process_record(
         message,
         source: source.clone(),
         resolved_hostname,
+        truncated: false,
     };
     forward_parsed(received, tx, &ctx.stats, &ctx.limiter, ctx.overflow).await
 }
@@ -567,16 +662,25 @@ pub fn make_log_uid(timestamp_ms: i64, seq: u64) -> String {
     format!("{:013}{:012}", timestamp_ms.max(0), seq)
 }
 
+/// The timestamp a log line is published under (#406): the message's own
+/// timestamp (RFC 5424 / BSD header, or the journald realtime stamp) unless
+/// `source` is `Receipt` or the line carries none, in which case `now_ms`.
+pub fn event_timestamp_ms(msg: &SyslogMessage, source: TimestampSource, now_ms: i64) -> i64 {
+    source.pick(msg.timestamp.map(|dt| dt.timestamp_millis()), now_ms)
+}
+
 /// Convert a syslog message to a per-line event TelemetryPoint (#104).
 ///
 /// The metric is `events/<uid>` (unique per line — no last-writer-wins), the value
 /// is the message text, and the labels carry the OpenTelemetry logs data model
 /// (`severity_number` 1–24, `severity_text`, `log.record.uid`, and — when
 /// `include_raw` — `log.record.original`) alongside facility/severity/app/etc.
+/// `timestamp_ms` comes from [`event_timestamp_ms`], shared with the uid.
 pub fn to_telemetry_point(
     received: &ReceivedMessage,
     include_raw: bool,
     uid: &str,
+    timestamp_ms: i64,
 ) -> TelemetryPoint {
     let msg = &received.message;
 
@@ -622,19 +726,18 @@ pub fn to_telemetry_point(
     // Add source information
     labels.insert("source_type".to_string(), received.source.to_string());
 
+    if received.truncated {
+        labels.insert("truncated".to_string(), "true".to_string());
+    }
+
     // Add raw message if configured. This doubles as OTel `log.record.original`.
     if include_raw {
         labels.insert("raw".to_string(), msg.raw.clone());
         labels.insert("log.record.original".to_string(), msg.raw.clone());
     }
 
-    let timestamp = msg
-        .timestamp
-        .map(|dt| dt.timestamp_millis())
-        .unwrap_or_else(|| chrono::Utc::now().timestamp_millis());
-
     TelemetryPoint {
-        timestamp,
+        timestamp: timestamp_ms,
         source: received.resolved_hostname.clone(),
         protocol: Protocol::Logs,
         // Per-line event key (#104): unique uid kills last-writer-wins so every
@@ -744,10 +847,11 @@ mod tests {
             message: msg,
             source: MessageSource::Network(addr),
             resolved_hostname: "myhost".to_string(),
+            truncated: false,
         };
 
         let uid = make_log_uid(point_ts(&received), 7);
-        let point = to_telemetry_point(&received, false, &uid);
+        let point = to_telemetry_point(&received, false, &uid, point_ts(&received));
 
         assert_eq!(point.source, "myhost");
         assert_eq!(point.protocol, Protocol::Logs);
@@ -766,13 +870,104 @@ mod tests {
         assert_eq!(point.labels.get("log.record.uid"), Some(&uid));
     }
 
-    // Mirror the timestamp logic in `to_telemetry_point` for deterministic uid tests.
+    // Message-sourced timestamp with a fixed fallback, for deterministic uid tests.
     fn point_ts(received: &ReceivedMessage) -> i64 {
-        received
-            .message
-            .timestamp
-            .map(|dt| dt.timestamp_millis())
-            .unwrap_or(0)
+        event_timestamp_ms(&received.message, TimestampSource::Message, 0)
+    }
+
+    #[test]
+    fn event_timestamp_honours_timestamp_source() {
+        let msg = parser::parse(
+            "<34>1 2024-01-15T10:30:00.000Z myhost sshd 1234 ID47 - Connection accepted",
+        )
+        .unwrap();
+        let embedded = msg.timestamp.unwrap().timestamp_millis();
+        let now = embedded + 90_000;
+
+        assert_eq!(
+            event_timestamp_ms(&msg, TimestampSource::Message, now),
+            embedded
+        );
+        assert_eq!(event_timestamp_ms(&msg, TimestampSource::Receipt, now), now);
+
+        // No embedded time: `message` falls back to receipt.
+        let mut bare = msg.clone();
+        bare.timestamp = None;
+        assert_eq!(
+            event_timestamp_ms(&bare, TimestampSource::Message, now),
+            now
+        );
+    }
+
+    #[tokio::test]
+    async fn datagram_at_buffer_boundary_is_flagged_truncated() {
+        let receiver = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let sender = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let addr = receiver.local_addr().unwrap();
+        let mut buf = vec![0u8; 64];
+
+        // One byte short of the buffer: intact.
+        sender.send_to(&[b'a'; 63], addr).await.unwrap();
+        let (len, _) = receiver.recv_from(&mut buf).await.unwrap();
+        assert_eq!(len, 63);
+        assert!(!datagram_truncated(len, buf.len()));
+
+        // Exactly the buffer size, and larger (cut to the buffer): flagged.
+        for size in [64, 200] {
+            sender.send_to(&vec![b'a'; size], addr).await.unwrap();
+            let (len, _) = receiver.recv_from(&mut buf).await.unwrap();
+            assert_eq!(len, 64);
+            assert!(datagram_truncated(len, buf.len()));
+        }
+
+        // The flag surfaces as a label on the point.
+        let received = ReceivedMessage {
+            message: parser::parse("<14>Jan  5 14:30:00 host app: cut").unwrap(),
+            source: MessageSource::Network(addr),
+            resolved_hostname: "host".to_string(),
+            truncated: true,
+        };
+        let point = to_telemetry_point(&received, false, "0000000000000000000000001", 0);
+        assert_eq!(point.labels.get("truncated"), Some(&"true".to_string()));
+    }
+
+    #[tokio::test]
+    async fn received_datagram_is_published_and_forwarded_verbatim() {
+        let upstream = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let free = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let listen = free.local_addr().unwrap();
+        drop(free);
+
+        let mut config = SyslogConfig::default();
+        config.listeners[0].bind = listen.to_string();
+        config.forward_to = vec![crate::config::ForwardTarget {
+            protocol: ListenerProtocol::Udp,
+            address: upstream.local_addr().unwrap().to_string(),
+            queue: 16,
+        }];
+        let (mut rx, _, _) = start_listeners(&config).await.unwrap();
+
+        // Not valid UTF-8: the parsed message only has a lossy copy.
+        let raw: &[u8] = b"<34>Jan  5 14:30:00 myhost sshd[1234]: bad byte \xff here";
+        let sender = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        // The listener binds in the background; resend until it's up.
+        let received = loop {
+            sender.send_to(raw, listen).await.unwrap();
+            if let Ok(Some(received)) =
+                tokio::time::timeout(Duration::from_millis(200), rx.recv()).await
+            {
+                break received;
+            }
+        };
+        assert!(received.message.raw.contains("sshd"));
+        assert_ne!(received.message.raw.as_bytes(), raw);
+
+        let mut buf = [0u8; 512];
+        let len = tokio::time::timeout(Duration::from_secs(5), upstream.recv(&mut buf))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(&buf[..len], raw);
     }
 
     #[test]
@@ -783,9 +978,10 @@ mod tests {
             message: msg,
             source: MessageSource::Unix,
             resolved_hostname: "localhost".to_string(),
+            truncated: false,
         };
 
-        let point = to_telemetry_point(&received, false, "0000000000000000000000001");
+        let point = to_telemetry_point(&received, false, "0000000000000000000000001", 0);
 
         assert_eq!(point.source, "localhost");
         assert_eq!(point.labels.get("source_type"), Some(&"unix".to_string()));
@@ -800,6 +996,7 @@ mod tests {
             message: msg,
             source: MessageSource::Network(addr),
             resolved_hostname: "myhost".to_string(),
+            truncated: false,
         };
 
         let key = build_key_expr("zensight/logs", &received, "0000000000123000000000045");
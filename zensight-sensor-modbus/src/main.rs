@@ -5,8 +5,7 @@
 
 use anyhow::Result;
 use tracing::info;
-use zensight_common::serialization::Format;
-use zensight_sensor_core::{SensorArgs, SensorRunner};
+use zensight_sensor_core::{SensorArgs, SensorConfig, SensorRunner};
 use zensight_sensor_modbus::config::ModbusSensorConfig;
 use zensight_sensor_modbus::poller::ModbusPoller;
 
@@ -14,6 +13,10 @@ use zensight_sensor_modbus::poller::ModbusPoller;
 async fn main() -> Result<()> {
     // Parse CLI arguments
     let args = SensorArgs::parse_with_default("modbus.json5");
+    if args.print_default_config {
+        print!("{}", ModbusSensorConfig::default_config_json5());
+        return Ok(());
+    }
 
     // Load configuration
     let config = ModbusSensorConfig::load_from_file(&args.config)?;
@@ -41,24 +44,38 @@ async fn main() -> Result<()> {
     // Tier-2 directory snapshots (`@/snapshot`). No-op unless `snapshot.enabled`.
     let mut runner = runner.with_report(report_source).with_snapshot(report_host);
 
-    // Get session and config
-    let session = runner.session().clone();
+    // Pollers publish through the runner's publisher (JSON) so sample
+    // metadata, mirrors and retry apply to them (#444)
+    let publisher = runner.publisher();
     let modbus_config = runner.config().modbus.clone();
 
-    // Serialization format (default to JSON)
-    let format = Format::Json;
+    // Configured register names become metric names: flag bad ones up front
+    // (#459).
+    let register_names = modbus_config
+        .devices
+        .iter()
+        .flat_map(|d| d.all_registers(&modbus_config.register_groups))
+        .filter_map(|r| r.name)
+        .chain(modbus_config.register_names.values().cloned())
+        .collect::<Vec<_>>();
+    zensight_sensor_core::warn_metric_names("modbus", register_names.iter().map(String::as_str));
 
     // Start pollers for each device
     for device in &modbus_config.devices {
-        let poller = ModbusPoller::new(device.clone(), &modbus_config, session.clone(), format);
+        let poller = ModbusPoller::new(device.clone(), &modbus_config, publisher.clone());
 
         info!(
             "Starting poller for device '{}' ({:?})",
             device.name, device.connection
         );
 
-        runner.spawn(async move {
-            poller.run().await;
+        // Poll on the runner's periodic loop (#460), which stops on shutdown.
+        poller.start();
+        let interval = poller.interval();
+        let poller = std::sync::Arc::new(poller);
+        runner.spawn_periodic(interval, move || {
+            let poller = poller.clone();
+            async move { poller.poll().await }
         });
     }
 
@@ -5,14 +5,12 @@ use crate::config::{
 };
 use std::collections::HashMap;
 use std::net::SocketAddr;
-use std::sync::Arc;
 use std::time::Duration;
 use tokio_modbus::client::{Context, Reader};
 use tokio_modbus::prelude::*;
 use tracing::{debug, error, info, warn};
-use zenoh::Session;
-use zensight_common::serialization::{Format, encode};
 use zensight_common::telemetry::{Protocol, TelemetryPoint, TelemetryValue};
+use zensight_sensor_core::Publisher;
 
 /// Error type for polling operations.
 #[derive(Debug, thiserror::Error)]
@@ -31,18 +29,14 @@ pub struct ModbusPoller {
     registers: Vec<RegisterConfig>,
     key_prefix: String,
     register_names: HashMap<String, String>,
-    session: Arc<Session>,
-    format: Format,
+    /// The runner's publisher, so sample metadata, mirrors and retry apply
+    /// (#444).
+    publisher: Publisher,
 }
 
 impl ModbusPoller {
     /// Create a new poller for a device.
-    pub fn new(
-        device: DeviceConfig,
-        config: &ModbusConfig,
-        session: Arc<Session>,
-        format: Format,
-    ) -> Self {
+    pub fn new(device: DeviceConfig, config: &ModbusConfig, publisher: Publisher) -> Self {
         let registers = device.all_registers(&config.register_groups);
 
         Self {
@@ -50,35 +44,36 @@ impl ModbusPoller {
             registers,
             key_prefix: config.key_prefix.clone(),
             register_names: config.register_names.clone(),
-            session,
-            format,
+            publisher,
         }
     }
 
-    /// Run the polling loop.
-    pub async fn run(self) {
-        let interval = Duration::from_secs(self.device.poll_interval_secs);
-        let device_name = self.device.name.clone();
+    /// The configured poll interval.
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(self.device.poll_interval_secs)
+    }
 
+    /// Log the start of polling. Call once before the first
+    /// [`poll`](Self::poll); the runner's periodic loop (#460) drives the rest.
+    pub fn start(&self) {
         info!(
             "Starting Modbus poller for device '{}' (interval: {}s)",
-            device_name, self.device.poll_interval_secs
+            self.device.name, self.device.poll_interval_secs
         );
+    }
 
-        loop {
-            match self.poll_once().await {
-                Ok(count) => {
-                    debug!(
-                        "Device '{}': published {} telemetry points",
-                        device_name, count
-                    );
-                }
-                Err(e) => {
-                    error!("Device '{}': polling error: {}", device_name, e);
-                }
+    /// Poll once, logging the outcome.
+    pub async fn poll(&self) {
+        match self.poll_once().await {
+            Ok(count) => {
+                debug!(
+                    "Device '{}': published {} telemetry points",
+                    self.device.name, count
+                );
+            }
+            Err(e) => {
+                error!("Device '{}': polling error: {}", self.device.name, e);
             }
-
-            tokio::time::sleep(interval).await;
         }
     }
 
@@ -330,17 +325,10 @@ impl ModbusPoller {
             labels,
         };
 
-        match encode(&point, self.format) {
-            Ok(payload) => {
-                if let Err(e) = self.session.put(&key, payload).await {
-                    warn!("Failed to publish to '{}': {}", key, e);
-                } else {
-                    debug!("Published: {} = {:?}", key, point.value);
-                }
-            }
-            Err(e) => {
-                warn!("Failed to encode telemetry: {}", e);
-            }
+        if let Err(e) = self.publisher.publish_to_key(&key, &point).await {
+            warn!("Failed to publish to '{}': {}", key, e);
+        } else {
+            debug!("Published: {} = {:?}", key, point.value);
         }
     }
 
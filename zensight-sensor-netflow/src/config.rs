@@ -1,8 +1,8 @@
 //! NetFlow/IPFIX sensor configuration.
 
 use serde::{Deserialize, Serialize};
-use std::path::Path;
-use zensight_common::config::ZenohConfig;
+use std::path::{Path, PathBuf};
+use zensight_common::config::{TimestampSource, ZenohConfig};
 
 // Re-export LoggingConfig from the framework for compatibility
 pub use zensight_sensor_core::LoggingConfig;
@@ -27,6 +27,31 @@ pub struct NetFlowSensorConfig {
     /// Tier-2 directory-snapshot (`@/snapshot`) limits. Disabled by default.
     #[serde(default)]
     pub snapshot: zensight_sensor_core::SnapshotLimits,
+
+    /// Zenoh congestion control / priority per key or protocol (#410).
+    #[serde(default)]
+    pub publish_qos: zensight_sensor_core::PublishQosConfig,
+
+    /// Bounded queue + timed replay for publishes that fail (#412).
+    #[serde(default)]
+    pub publish_retry: zensight_sensor_core::PublishRetryConfig,
+
+    /// Re-publish matching telemetry under alternate prefixes (#447).
+    #[serde(default)]
+    pub publish_mirror: Vec<zensight_sensor_core::MirrorRule>,
+
+    /// Attach bridge name/version/encoding to each sample (#444).
+    #[serde(default)]
+    pub attach_metadata: bool,
+
+    /// Unix socket for runtime control commands (#445); off when unset.
+    #[serde(default)]
+    pub control_socket: Option<std::path::PathBuf>,
+
+    /// Stamp flow points with the flow's end time (`message`, default) or
+    /// with the time the export packet arrived (`receipt`) (#406).
+    #[serde(default)]
+    pub timestamp_source: TimestampSource,
 }
 
 /// NetFlow receiver configuration.
@@ -55,6 +80,35 @@ pub struct NetFlowConfig {
     /// Flow aggregation interval in seconds (0 = no aggregation).
     #[serde(default)]
     pub aggregation_interval_secs: u64,
+
+    /// Exporters whose v9/IPFIX templates each listener keeps (#481); the
+    /// least recently heard-from one is dropped beyond this. This bounds the
+    /// number of exporters; `max_templates_per_exporter` bounds each one.
+    #[serde(default = "default_max_exporters")]
+    pub max_exporters: usize,
+
+    /// Templates kept for a single exporter (#481); announcing more drops
+    /// the least recently announced one.
+    #[serde(default = "default_max_templates_per_exporter")]
+    pub max_templates_per_exporter: usize,
+
+    /// Country/ASN labels for public flow endpoints (#478); off when unset.
+    #[serde(default)]
+    pub geoip: Option<GeoIpConfig>,
+}
+
+/// MaxMind database paths for GeoIP enrichment (#478). Either may be left
+/// out to skip that label family.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GeoIpConfig {
+    /// GeoLite2-Country (or GeoIP2-Country/City) database, for
+    /// `src_country`/`dst_country`.
+    #[serde(default)]
+    pub country_db: Option<PathBuf>,
+
+    /// GeoLite2-ASN database, for `src_asn`/`dst_asn`.
+    #[serde(default)]
+    pub asn_db: Option<PathBuf>,
 }
 
 fn default_key_prefix() -> String {
@@ -65,6 +119,14 @@ fn default_true() -> bool {
     true
 }
 
+fn default_max_exporters() -> usize {
+    crate::template_store::DEFAULT_MAX_EXPORTERS
+}
+
+fn default_max_templates_per_exporter() -> usize {
+    crate::template_store::DEFAULT_MAX_TEMPLATES_PER_EXPORTER
+}
+
 /// Individual listener configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ListenerConfig {
@@ -108,11 +170,30 @@ impl NetFlowSensorConfig {
             }
         }
 
+        if self.netflow.max_exporters == 0 {
+            anyhow::bail!("max_exporters must be at least 1");
+        }
+
+        if self.netflow.max_templates_per_exporter == 0 {
+            anyhow::bail!("max_templates_per_exporter must be at least 1");
+        }
+
+        if let Some(geoip) = &self.netflow.geoip
+            && geoip.country_db.is_none()
+            && geoip.asn_db.is_none()
+        {
+            anyhow::bail!("geoip needs a country_db or an asn_db");
+        }
+
         Ok(())
     }
 }
 
 impl zensight_sensor_core::SensorConfig for NetFlowSensorConfig {
+    fn default_config_json5() -> &'static str {
+        include_str!("../../configs/netflow.json5")
+    }
+
     fn zenoh(&self) -> &ZenohConfig {
         &self.zenoh
     }
@@ -133,6 +214,30 @@ impl zensight_sensor_core::SensorConfig for NetFlowSensorConfig {
         self.snapshot.clone()
     }
 
+    fn publish_qos(&self) -> zensight_sensor_core::PublishQosConfig {
+        self.publish_qos.clone()
+    }
+
+    fn publish_retry(&self) -> zensight_sensor_core::PublishRetryConfig {
+        self.publish_retry.clone()
+    }
+
+    fn publish_mirror(&self) -> Vec<zensight_sensor_core::MirrorRule> {
+        self.publish_mirror.clone()
+    }
+
+    fn attach_metadata(&self) -> bool {
+        self.attach_metadata
+    }
+
+    fn control_socket(&self) -> Option<std::path::PathBuf> {
+        self.control_socket.clone()
+    }
+
+    fn timestamp_source(&self) -> TimestampSource {
+        self.timestamp_source
+    }
+
     fn validate(&self) -> zensight_sensor_core::Result<()> {
         self.validate_config()
             .map_err(|e| zensight_sensor_core::SensorError::config(e.to_string()))
@@ -151,6 +256,9 @@ impl Default for NetFlowConfig {
             publish_flows: true,
             publish_stats: true,
             aggregation_interval_secs: 0,
+            max_exporters: default_max_exporters(),
+            max_templates_per_exporter: default_max_templates_per_exporter(),
+            geoip: None,
         }
     }
 }
@@ -175,6 +283,8 @@ mod tests {
         assert_eq!(config.netflow.listeners.len(), 1);
         assert!(config.netflow.publish_flows);
         assert!(config.netflow.publish_stats);
+        assert_eq!(config.netflow.max_exporters, 1024);
+        assert_eq!(config.netflow.max_templates_per_exporter, 256);
     }
 
     #[test]
@@ -242,4 +352,31 @@ mod tests {
         let config: NetFlowSensorConfig = json5::from_str(json).unwrap();
         assert!(config.validate_config().is_err());
     }
+
+    #[test]
+    fn test_geoip_config() {
+        let json = r#"{
+            zenoh: { mode: "peer" },
+            netflow: {
+                listeners: [{ bind: "0.0.0.0:2055" }],
+                geoip: { country_db: "/var/lib/GeoIP/GeoLite2-Country.mmdb" }
+            }
+        }"#;
+        let mut config: NetFlowSensorConfig = json5::from_str(json).unwrap();
+        let geoip = config.netflow.geoip.as_ref().unwrap();
+        assert!(geoip.country_db.is_some());
+        assert!(geoip.asn_db.is_none());
+        assert!(config.validate_config().is_ok());
+
+        config.netflow.geoip = Some(GeoIpConfig::default());
+        assert!(config.validate_config().is_err());
+    }
+
+    #[test]
+    fn printed_default_config_documents_every_field() {
+        assert_eq!(
+            zensight_sensor_core::undocumented_fields::<NetFlowSensorConfig>().unwrap(),
+            Vec::<String>::new()
+        );
+    }
 }
@@ -0,0 +1,178 @@
+//! Country/ASN enrichment of flow endpoints from MaxMind databases (#478).
+//!
+//! When `netflow.geoip` points at a GeoLite2-Country and/or GeoLite2-ASN
+//! database, every public `src_addr`/`dst_addr` of a flow gets
+//! `src_country`/`dst_country` (ISO code) and `src_asn`/`dst_asn` labels.
+//! Private, loopback, link-local and other non-routable addresses are never
+//! looked up, so internal traffic stays unlabeled.
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::path::Path;
+
+use anyhow::Context;
+use maxminddb::{Reader, geoip2};
+
+use crate::config::GeoIpConfig;
+use crate::receiver::{IpScope, ip_scope};
+
+/// Looks up flow endpoints in the configured MaxMind databases.
+pub struct GeoIpEnricher {
+    country: Option<Reader<Vec<u8>>>,
+    asn: Option<Reader<Vec<u8>>>,
+}
+
+impl GeoIpEnricher {
+    /// Open the databases named in `config`.
+    pub fn open(config: &GeoIpConfig) -> anyhow::Result<Self> {
+        Ok(Self {
+            country: config.country_db.as_deref().map(open_db).transpose()?,
+            asn: config.asn_db.as_deref().map(open_db).transpose()?,
+        })
+    }
+
+    /// Add country/ASN labels for the public endpoints among the flow's
+    /// `src_addr`/`dst_addr` labels.
+    pub fn enrich(&self, labels: &mut HashMap<String, String>) {
+        for side in ["src", "dst"] {
+            let Some(ip) = labels
+                .get(&format!("{side}_addr"))
+                .and_then(|addr| addr.parse::<IpAddr>().ok())
+                .filter(|ip| is_public(*ip))
+            else {
+                continue;
+            };
+            if let Some(iso) = self.country_code(ip) {
+                labels.insert(format!("{side}_country"), iso);
+            }
+            if let Some(asn) = self.asn(ip) {
+                labels.insert(format!("{side}_asn"), asn.to_string());
+            }
+        }
+    }
+
+    fn country_code(&self, ip: IpAddr) -> Option<String> {
+        let record: geoip2::Country = self.country.as_ref()?.lookup(ip).ok()??;
+        record.country?.iso_code.map(str::to_string)
+    }
+
+    fn asn(&self, ip: IpAddr) -> Option<u32> {
+        let record: geoip2::Asn = self.asn.as_ref()?.lookup(ip).ok()??;
+        record.autonomous_system_number
+    }
+}
+
+fn open_db(path: &Path) -> anyhow::Result<Reader<Vec<u8>>> {
+    Reader::open_readfile(path)
+        .with_context(|| format!("Failed to open GeoIP database {}", path.display()))
+}
+
+/// Whether `ip` is a globally routable address worth a GeoIP lookup (its
+/// [`IpScope`] is public, #479).
+pub fn is_public(ip: IpAddr) -> bool {
+    ip_scope(ip) == IpScope::Public
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tiny database from `testdata/make-geoip-fixture.py`: 81.2.69.0/24 is
+    /// GB / AS20712, and 10.0.0.0/8 is listed too (as XX) so a skipped
+    /// private address can't pass by merely missing from the database.
+    const FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/testdata/geoip-test.mmdb");
+
+    fn labels(src: &str, dst: &str) -> HashMap<String, String> {
+        HashMap::from([
+            ("src_addr".to_string(), src.to_string()),
+            ("dst_addr".to_string(), dst.to_string()),
+        ])
+    }
+
+    #[test]
+    fn public_ip_gets_country_and_private_is_skipped() {
+        let enricher = GeoIpEnricher::open(&GeoIpConfig {
+            country_db: Some(FIXTURE.into()),
+            asn_db: Some(FIXTURE.into()),
+        })
+        .unwrap();
+
+        let mut flow = labels("81.2.69.160", "10.1.2.3");
+        enricher.enrich(&mut flow);
+        assert_eq!(flow.get("src_country").map(String::as_str), Some("GB"));
+        assert_eq!(flow.get("src_asn").map(String::as_str), Some("20712"));
+        assert!(!flow.contains_key("dst_country"));
+        assert!(!flow.contains_key("dst_asn"));
+
+        // Public but absent from the database: no labels either.
+        let mut flow = labels("192.168.1.10", "8.8.8.8");
+        enricher.enrich(&mut flow);
+        assert_eq!(flow.len(), 2);
+    }
+
+    /// A v9 record names its addresses `ipv4srcaddr`/`ipv4dstaddr`; after
+    /// normalization the published point is enriched like a v5 one.
+    #[test]
+    fn v9_record_is_enriched() {
+        use crate::receiver::{FlowFieldValue, FlowRecord, normalize_fields, to_telemetry_point};
+
+        let enricher = GeoIpEnricher::open(&GeoIpConfig {
+            country_db: Some(FIXTURE.into()),
+            asn_db: Some(FIXTURE.into()),
+        })
+        .unwrap();
+
+        let mut fields = HashMap::from([
+            (
+                "ipv4srcaddr".to_string(),
+                FlowFieldValue::IpAddr("81.2.69.160".to_string()),
+            ),
+            (
+                "ipv4dstaddr".to_string(),
+                FlowFieldValue::IpAddr("10.1.2.3".to_string()),
+            ),
+        ]);
+        normalize_fields(&mut fields);
+        let record = FlowRecord {
+            exporter_ip: "e".to_string(),
+            exporter_name: "e".to_string(),
+            version: 9,
+            fields,
+            timestamp: 0,
+        };
+
+        let mut point = to_telemetry_point(&record);
+        enricher.enrich(&mut point.labels);
+        assert_eq!(
+            point.labels.get("src_country").map(String::as_str),
+            Some("GB")
+        );
+        assert_eq!(
+            point.labels.get("src_asn").map(String::as_str),
+            Some("20712")
+        );
+        assert!(!point.labels.contains_key("dst_country"));
+    }
+
+    #[test]
+    fn non_routable_addresses_are_not_public() {
+        for ip in [
+            "10.0.0.1",
+            "172.16.5.4",
+            "192.168.1.1",
+            "100.64.0.1",
+            "127.0.0.1",
+            "169.254.1.1",
+            "224.0.0.1",
+            "::1",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:192.168.1.1",
+        ] {
+            assert!(!is_public(ip.parse().unwrap()), "{ip}");
+        }
+        for ip in ["81.2.69.160", "8.8.8.8", "2001:4860:4860::8888"] {
+            assert!(is_public(ip.parse().unwrap()), "{ip}");
+        }
+    }
+}
@@ -22,4 +22,6 @@
 //! ```
 
 pub mod config;
+pub mod geoip;
 pub mod receiver;
+pub mod template_store;
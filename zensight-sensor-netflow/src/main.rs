@@ -4,17 +4,22 @@
 //! parses flow records, and publishes them to Zenoh as TelemetryPoints.
 
 mod config;
+mod geoip;
 mod receiver;
+mod template_store;
 
 use anyhow::Result;
 use config::NetFlowSensorConfig;
-use zensight_common::serialization::{Format, encode};
-use zensight_sensor_core::{SensorArgs, SensorRunner};
+use zensight_sensor_core::{SensorArgs, SensorConfig, SensorRunner};
 
 #[tokio::main]
 async fn main() -> Result<()> {
     // Parse CLI arguments
     let args = SensorArgs::parse_with_default("netflow.json5");
+    if args.print_default_config {
+        print!("{}", NetFlowSensorConfig::default_config_json5());
+        return Ok(());
+    }
 
     // Load configuration
     let config = NetFlowSensorConfig::load_from_file(&args.config)?;
@@ -42,15 +47,13 @@ async fn main() -> Result<()> {
     // Tier-2 directory snapshots (`@/snapshot`). No-op unless `snapshot.enabled`.
     let runner = runner.with_report(report_source).with_snapshot(report_host);
 
-    // Get session and config
-    let session = runner.session().clone();
+    // Flows go out through the runner's publisher (JSON) so sample metadata,
+    // mirrors and retry apply to them (#444)
+    let publisher = runner.publisher();
     let netflow_config = runner.config().netflow.clone();
 
-    // Serialization format (default to JSON)
-    let format = Format::Json;
-
     // Start NetFlow listeners
-    let mut rx = receiver::start_listeners(&netflow_config)
+    let mut rx = receiver::start_listeners(&netflow_config, runner.config().timestamp_source)
         .await
         .map_err(|e| anyhow::anyhow!("Failed to start NetFlow listeners: {}", e))?;
 
@@ -62,6 +65,16 @@ async fn main() -> Result<()> {
     let key_prefix = netflow_config.key_prefix.clone();
     let publish_flows = netflow_config.publish_flows;
 
+    // Optional country/ASN labels on public flow endpoints (#478).
+    let geoip = netflow_config
+        .geoip
+        .as_ref()
+        .map(geoip::GeoIpEnricher::open)
+        .transpose()?;
+    if geoip.is_some() {
+        tracing::info!("GeoIP enrichment enabled");
+    }
+
     // Build status metadata
     let metadata = serde_json::json!({
         "listeners": netflow_config.listeners.iter().map(|l| &l.bind).collect::<Vec<_>>(),
@@ -70,50 +83,56 @@ async fn main() -> Result<()> {
     });
 
     // Spawn the flow processing task
-    let session_clone = session.clone();
     let mut runner = runner;
-    runner.spawn(async move {
-        let mut flow_count: u64 = 0;
+    // Reported in `@/health` alongside the framework counters (#411).
+    let flows_published = runner.metrics().counter("flows_published");
+    let health = runner.health();
+    // On shutdown, stop taking flows and publish the ones already queued
+    // (#461)
+    let mut shutdown = runner.shutdown_signal();
+    runner.spawn_draining(async move {
+        let mut flows_logged: u64 = 0;
         let mut last_stats_time = std::time::Instant::now();
 
         loop {
             tokio::select! {
+                _ = shutdown.wait_for(|&stop| stop), if !rx.is_closed() => rx.close(),
                 Some(record) = rx.recv() => {
+                    // Exporters are found, not configured (#436).
+                    health.announce_device(&record.exporter_name).await;
+
                     if publish_flows {
                         // Convert to telemetry point
-                        let point = receiver::to_telemetry_point(&record);
+                        let mut point = receiver::to_telemetry_point(&record);
+                        if let Some(geoip) = &geoip {
+                            geoip.enrich(&mut point.labels);
+                        }
 
                         // Build key expression
                         let key = receiver::build_key_expr(&key_prefix, &record);
 
-                        // Serialize and publish
-                        match encode(&point, format) {
-                            Ok(payload) => {
-                                if let Err(e) = session_clone.put(&key, payload).await {
-                                    tracing::error!("Failed to publish to {}: {}", key, e);
-                                } else {
-                                    tracing::trace!(
-                                        "Published flow: {} from {} v{}",
-                                        key,
-                                        record.exporter_name,
-                                        record.version
-                                    );
-                                    flow_count += 1;
-                                }
-                            }
-                            Err(e) => {
-                                tracing::error!("Failed to serialize flow: {}", e);
-                            }
+                        // Each flow is a one-off event, not a series
+                        if let Err(e) = publisher.publish_event(&key, &point).await {
+                            tracing::error!("Failed to publish to {}: {}", key, e);
+                        } else {
+                            tracing::trace!(
+                                "Published flow: {} from {} v{}",
+                                key,
+                                record.exporter_name,
+                                record.version
+                            );
+                            flows_published.inc();
                         }
                     }
 
                     // Log statistics periodically
                     if last_stats_time.elapsed().as_secs() >= 60 {
+                        let total = flows_published.get();
                         tracing::info!(
                             "Processed {} flows in the last minute",
-                            flow_count
+                            total - flows_logged
                         );
-                        flow_count = 0;
+                        flows_logged = total;
                         last_stats_time = std::time::Instant::now();
                     }
                 }
@@ -1,6 +1,7 @@
 //! NetFlow/IPFIX packet receiver and parser.
 
 use crate::config::{ListenerConfig, NetFlowConfig};
+use crate::template_store::{TemplateStore, announced_template_ids};
 use anyhow::{Context, Result};
 use netflow_parser::static_versions::v5::FlowSet as V5FlowSet;
 use netflow_parser::static_versions::v7::FlowSet as V7FlowSet;
@@ -14,6 +15,7 @@ use std::sync::Arc;
 use tokio::net::UdpSocket;
 use tokio::sync::Mutex;
 use tokio::sync::mpsc;
+use zensight_common::TimestampSource;
 use zensight_common::telemetry::{Protocol, TelemetryPoint, TelemetryValue};
 
 /// A parsed flow record ready for publishing.
@@ -27,10 +29,65 @@ pub struct FlowRecord {
     pub version: u16,
     /// Flow fields as key-value pairs.
     pub fields: HashMap<String, FlowFieldValue>,
-    /// Unix timestamp in milliseconds.
+    /// Unix timestamp in milliseconds: the flow's end time or the receipt
+    /// time, per the configured [`TimestampSource`] (#406).
     pub timestamp: i64,
 }
 
+/// The exporter's clock as stated in a packet header, used to turn a flow's
+/// end time into epoch milliseconds (#406).
+#[derive(Debug, Clone, Copy)]
+pub struct ExportClock {
+    /// Export time from the header, epoch milliseconds.
+    pub unix_ms: i64,
+    /// Device uptime at export, milliseconds. v5/v7/v9 stamp flows relative
+    /// to it; IPFIX headers don't carry it.
+    pub sys_up_time_ms: Option<u64>,
+}
+
+impl ExportClock {
+    /// NetFlow v5/v7/v9 header clock.
+    pub fn with_uptime(unix_secs: u32, unix_nsecs: u32, sys_up_time_ms: u32) -> Self {
+        Self {
+            unix_ms: i64::from(unix_secs) * 1000 + i64::from(unix_nsecs) / 1_000_000,
+            sys_up_time_ms: Some(sys_up_time_ms.into()),
+        }
+    }
+
+    /// IPFIX header clock (export time only).
+    pub fn export_time(export_secs: u32) -> Self {
+        Self {
+            unix_ms: i64::from(export_secs) * 1000,
+            sys_up_time_ms: None,
+        }
+    }
+}
+
+/// End time of a flow in epoch milliseconds, from whichever field the
+/// record carries: IPFIX absolute `flowEnd{Milli,}Seconds`, or the uptime-
+/// relative v5/v7 `last` / v9 `LastSwitched` anchored on the header clock.
+pub fn flow_end_ms(fields: &HashMap<String, FlowFieldValue>, clock: &ExportClock) -> Option<i64> {
+    let uint = |name: &str| match fields.get(name) {
+        Some(FlowFieldValue::Uint(v)) => i64::try_from(*v).ok(),
+        _ => None,
+    };
+
+    if let Some(ms) = uint("flowendmilliseconds") {
+        return Some(ms);
+    }
+    if let Some(secs) = uint("flowendseconds") {
+        return Some(secs * 1000);
+    }
+    let uptime = i64::try_from(clock.sys_up_time_ms?).ok()?;
+    let last = uint("last").or_else(|| uint("lastswitched"))?;
+    Some(clock.unix_ms - (uptime - last))
+}
+
+/// Stamp a parsed record per `source` (#406).
+fn stamp(record: &mut FlowRecord, clock: &ExportClock, source: TimestampSource, receipt_ms: i64) {
+    record.timestamp = source.pick(flow_end_ms(&record.fields, clock), receipt_ms);
+}
+
 /// A flow field value.
 #[derive(Debug, Clone)]
 pub enum FlowFieldValue {
@@ -66,9 +123,14 @@ impl FlowFieldValue {
 }
 
 /// Start all configured listeners and return a channel for receiving flow records.
-pub async fn start_listeners(config: &NetFlowConfig) -> Result<mpsc::Receiver<FlowRecord>> {
+pub async fn start_listeners(
+    config: &NetFlowConfig,
+    timestamp_source: TimestampSource,
+) -> Result<mpsc::Receiver<FlowRecord>> {
     let (tx, rx) = mpsc::channel(10000);
     let exporter_names = Arc::new(config.exporter_names.clone());
+    let max_exporters = config.max_exporters;
+    let max_templates = config.max_templates_per_exporter;
 
     for listener_config in &config.listeners {
         let tx = tx.clone();
@@ -76,7 +138,16 @@ pub async fn start_listeners(config: &NetFlowConfig) -> Result<mpsc::Receiver<Fl
         let config = listener_config.clone();
 
         tokio::spawn(async move {
-            if let Err(e) = run_listener(&config, tx, names).await {
+            if let Err(e) = run_listener(
+                &config,
+                tx,
+                names,
+                timestamp_source,
+                max_exporters,
+                max_templates,
+            )
+            .await
+            {
                 tracing::error!("NetFlow listener error: {}", e);
             }
         });
@@ -90,6 +161,9 @@ async fn run_listener(
     config: &ListenerConfig,
     tx: mpsc::Sender<FlowRecord>,
     exporter_names: Arc<HashMap<String, String>>,
+    timestamp_source: TimestampSource,
+    max_exporters: usize,
+    max_templates: usize,
 ) -> Result<()> {
     let socket = UdpSocket::bind(&config.bind)
         .await
@@ -100,9 +174,14 @@ async fn run_listener(
     let mut buf = vec![0u8; config.max_packet_size];
 
     // Per-exporter parsers to avoid mutex contention between different exporters.
-    // NetFlow v9/IPFIX parsers maintain template state per exporter.
-    let parsers: Arc<Mutex<HashMap<IpAddr, Arc<Mutex<NetflowParser>>>>> =
-        Arc::new(Mutex::new(HashMap::new()));
+    // NetFlow v9/IPFIX parsers maintain template state per exporter; the store
+    // bounds how many exporters' templates are kept, and each exporter's
+    // template IDs are followed to bound (and log) its own templates (#481).
+    let mut exporters: TemplateStore<IpAddr, ExporterTemplates> = TemplateStore::new(max_exporters);
+    // Evictions so far; logged at debug, with a warning when the count
+    // reaches a power of two so churn stays visible without flooding.
+    let mut evictions: u64 = 0;
+    let mut template_evictions: u64 = 0;
 
     loop {
         match socket.recv_from(&mut buf).await {
@@ -112,16 +191,49 @@ async fn run_listener(
                 let names = exporter_names.clone();
 
                 // Get or create a parser for this exporter
-                let parser = {
-                    let mut map = parsers.lock().await;
-                    map.entry(addr.ip())
-                        .or_insert_with(|| Arc::new(Mutex::new(NetflowParser::default())))
-                        .clone()
-                };
+                let (exporter, evicted) = exporters
+                    .get_or_insert_with(addr.ip(), || ExporterTemplates::new(max_templates));
+                for template_id in announced_template_ids(&data) {
+                    let (_, dropped) = exporter.templates.get_or_insert_with(template_id, || ());
+                    if let Some(dropped) = dropped {
+                        template_evictions += 1;
+                        tracing::debug!(
+                            exporter = %addr.ip(),
+                            template_id = dropped,
+                            max_templates,
+                            "Evicted least recently announced template"
+                        );
+                        if template_evictions.is_power_of_two() {
+                            tracing::warn!(
+                                evictions = template_evictions,
+                                max_templates,
+                                "Exporter templates evicted; consider raising max_templates_per_exporter"
+                            );
+                        }
+                    }
+                }
+                let parser = exporter.parser.clone();
+                if let Some(evicted) = evicted {
+                    evictions += 1;
+                    tracing::debug!(
+                        exporter = %evicted,
+                        max_exporters,
+                        "Evicted least recently used exporter templates"
+                    );
+                    if evictions.is_power_of_two() {
+                        tracing::warn!(
+                            evictions,
+                            max_exporters,
+                            "Exporter template state evicted; consider raising max_exporters"
+                        );
+                    }
+                }
 
                 // Process in a separate task to not block the receiver
                 tokio::spawn(async move {
-                    if let Err(e) = process_packet(&data, addr, tx, names, parser).await {
+                    if let Err(e) =
+                        process_packet(&data, addr, tx, names, parser, timestamp_source).await
+                    {
                         tracing::debug!("Failed to process NetFlow packet from {}: {}", addr, e);
                     }
                 });
@@ -133,6 +245,26 @@ async fn run_listener(
     }
 }
 
+/// An exporter's parser and the template IDs it has announced, both capped
+/// at the same number of templates (#481).
+struct ExporterTemplates {
+    parser: Arc<Mutex<NetflowParser>>,
+    templates: TemplateStore<u16, ()>,
+}
+
+impl ExporterTemplates {
+    fn new(max_templates: usize) -> Self {
+        let parser = NetflowParser::builder()
+            .with_cache_size(max_templates)
+            .build()
+            .expect("template cache size is validated to be non-zero");
+        Self {
+            parser: Arc::new(Mutex::new(parser)),
+            templates: TemplateStore::new(max_templates),
+        }
+    }
+}
+
 /// Process a single NetFlow/IPFIX packet.
 async fn process_packet(
     data: &[u8],
@@ -140,6 +272,7 @@ async fn process_packet(
     tx: mpsc::Sender<FlowRecord>,
     exporter_names: Arc<HashMap<String, String>>,
     parser: Arc<Mutex<NetflowParser>>,
+    timestamp_source: TimestampSource,
 ) -> Result<()> {
     let exporter_ip = addr.ip().to_string();
     let exporter_name = exporter_names
@@ -156,27 +289,36 @@ async fn process_packet(
     for packet in packets {
         match packet {
             NetflowPacket::V5(v5) => {
+                let h = &v5.header;
+                let clock = ExportClock::with_uptime(h.unix_secs, h.unix_nsecs, h.sys_up_time);
                 for flow in &v5.flowsets {
-                    let record = parse_v5_flow(&exporter_ip, &exporter_name, flow, timestamp);
+                    let mut record = parse_v5_flow(&exporter_ip, &exporter_name, flow, timestamp);
+                    stamp(&mut record, &clock, timestamp_source, timestamp);
                     if tx.send(record).await.is_err() {
                         return Ok(());
                     }
                 }
             }
             NetflowPacket::V7(v7) => {
+                let h = &v7.header;
+                let clock = ExportClock::with_uptime(h.unix_secs, h.unix_nsecs, h.sys_up_time);
                 for flow in &v7.flowsets {
-                    let record = parse_v7_flow(&exporter_ip, &exporter_name, flow, timestamp);
+                    let mut record = parse_v7_flow(&exporter_ip, &exporter_name, flow, timestamp);
+                    stamp(&mut record, &clock, timestamp_source, timestamp);
                     if tx.send(record).await.is_err() {
                         return Ok(());
                     }
                 }
             }
             NetflowPacket::V9(v9) => {
+                let h = &v9.header;
+                let clock = ExportClock::with_uptime(h.unix_secs, 0, h.sys_up_time);
                 for flowset in &v9.flowsets {
                     if let V9FlowSetBody::Data(data) = &flowset.body {
                         for flow_record in &data.fields {
-                            let record =
+                            let mut record =
                                 parse_v9_flow(&exporter_ip, &exporter_name, flow_record, timestamp);
+                            stamp(&mut record, &clock, timestamp_source, timestamp);
                             if tx.send(record).await.is_err() {
                                 return Ok(());
                             }
@@ -185,15 +327,17 @@ async fn process_packet(
                 }
             }
             NetflowPacket::IPFix(ipfix) => {
+                let clock = ExportClock::export_time(ipfix.header.export_time);
                 for flowset in &ipfix.flowsets {
                     if let IpFixFlowSetBody::Data(data) = &flowset.body {
                         for flow_record in &data.fields {
-                            let record = parse_ipfix_flow(
+                            let mut record = parse_ipfix_flow(
                                 &exporter_ip,
                                 &exporter_name,
                                 flow_record,
                                 timestamp,
                             );
+                            stamp(&mut record, &clock, timestamp_source, timestamp);
                             if tx.send(record).await.is_err() {
                                 return Ok(());
                             }
@@ -392,6 +536,7 @@ fn parse_v9_flow(
         let value = parse_field_value(field_value);
         fields.insert(field_name, value);
     }
+    normalize_fields(&mut fields);
 
     FlowRecord {
         exporter_ip: exporter_ip.to_string(),
@@ -416,6 +561,7 @@ fn parse_ipfix_flow(
         let value = parse_field_value(field_value);
         fields.insert(field_name, value);
     }
+    normalize_fields(&mut fields);
 
     FlowRecord {
         exporter_ip: exporter_ip.to_string(),
@@ -426,6 +572,46 @@ fn parse_ipfix_flow(
     }
 }
 
+/// Template field names carrying the flow source address, in preference order.
+const SRC_ADDR_FIELDS: [&str; 4] = [
+    "ipv4srcaddr",
+    "ipv6srcaddr",
+    "sourceipv4address",
+    "sourceipv6address",
+];
+
+/// Template field names carrying the flow destination address, in preference order.
+const DST_ADDR_FIELDS: [&str; 4] = [
+    "ipv4dstaddr",
+    "ipv6dstaddr",
+    "destinationipv4address",
+    "destinationipv6address",
+];
+
+/// Copy the v9/IPFIX endpoint addresses onto the v5-style `src_addr` and
+/// `dst_addr` fields, so scope labels, GeoIP enrichment and the key
+/// expression see them regardless of export version (#478, #479). IPFIX's
+/// `protocolIdentifier` becomes `protocol` the same way (#480).
+pub(crate) fn normalize_fields(fields: &mut HashMap<String, FlowFieldValue>) {
+    for (target, candidates) in [("src_addr", SRC_ADDR_FIELDS), ("dst_addr", DST_ADDR_FIELDS)] {
+        if fields.contains_key(target) {
+            continue;
+        }
+        if let Some(addr) = candidates
+            .iter()
+            .find_map(|name| fields.get(*name))
+            .cloned()
+        {
+            fields.insert(target.to_string(), addr);
+        }
+    }
+    if !fields.contains_key("protocol")
+        && let Some(proto) = fields.get("protocolidentifier").cloned()
+    {
+        fields.insert("protocol".to_string(), proto);
+    }
+}
+
 /// Parse a FieldValue to FlowFieldValue.
 fn parse_field_value(field_value: &FieldValue) -> FlowFieldValue {
     match field_value {
@@ -473,6 +659,76 @@ fn parse_field_value(field_value: &FieldValue) -> FlowFieldValue {
     }
 }
 
+/// Reachability class of a flow endpoint (#479), published as the
+/// `src_scope`/`dst_scope` labels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IpScope {
+    /// Globally routable.
+    Public,
+    /// Not routable on the internet: RFC 1918, RFC 4193 unique local,
+    /// link-local, CGNAT shared space, documentation and unspecified.
+    Private,
+    /// 127.0.0.0/8 or ::1.
+    Loopback,
+    /// Multicast or limited broadcast.
+    Multicast,
+}
+
+impl IpScope {
+    /// Label value.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            IpScope::Public => "public",
+            IpScope::Private => "private",
+            IpScope::Loopback => "loopback",
+            IpScope::Multicast => "multicast",
+        }
+    }
+}
+
+/// Classify `ip`; IPv4-mapped IPv6 addresses classify as their IPv4 form.
+pub fn ip_scope(ip: IpAddr) -> IpScope {
+    match ip {
+        IpAddr::V4(v4) => {
+            let [a, b, ..] = v4.octets();
+            if v4.is_loopback() {
+                IpScope::Loopback
+            } else if v4.is_multicast() || v4.is_broadcast() {
+                IpScope::Multicast
+            } else if v4.is_private()
+                || v4.is_link_local()
+                || v4.is_documentation()
+                || v4.is_unspecified()
+                // 100.64.0.0/10 shared address space (RFC 6598)
+                || (a == 100 && (64..128).contains(&b))
+            {
+                IpScope::Private
+            } else {
+                IpScope::Public
+            }
+        }
+        IpAddr::V6(v6) => {
+            if let Some(v4) = v6.to_ipv4_mapped() {
+                return ip_scope(IpAddr::V4(v4));
+            }
+            let first = v6.segments()[0];
+            if v6.is_loopback() {
+                IpScope::Loopback
+            } else if v6.is_multicast() {
+                IpScope::Multicast
+            } else if v6.is_unspecified()
+                // fc00::/7 unique local (RFC 4193), fe80::/10 link-local
+                || (first & 0xfe00) == 0xfc00
+                || (first & 0xffc0) == 0xfe80
+            {
+                IpScope::Private
+            } else {
+                IpScope::Public
+            }
+        }
+    }
+}
+
 /// Convert a FlowRecord to a TelemetryPoint.
 pub fn to_telemetry_point(record: &FlowRecord) -> TelemetryPoint {
     let mut labels = HashMap::new();
@@ -501,6 +757,20 @@ pub fn to_telemetry_point(record: &FlowRecord) -> TelemetryPoint {
         }
     }
 
+    // Protocol name next to the numeric `protocol` field (#480)
+    if let Some(FlowFieldValue::Uint(proto)) = record.fields.get("protocol") {
+        labels.insert("proto".to_string(), protocol_label(*proto as u8));
+    }
+
+    // Internal vs external endpoints (#479)
+    for side in ["src", "dst"] {
+        if let Some(FlowFieldValue::IpAddr(addr)) = record.fields.get(&format!("{side}_addr"))
+            && let Ok(ip) = addr.parse::<IpAddr>()
+        {
+            labels.insert(format!("{side}_scope"), ip_scope(ip).as_str().to_string());
+        }
+    }
+
     // Use bytes as the primary metric value if available
     let value = record
         .fields
@@ -583,19 +853,36 @@ pub fn build_key_expr(prefix: &str, record: &FlowRecord) -> String {
     )
 }
 
+/// Name of a well-known IP protocol number.
+fn protocol_name(proto: u8) -> Option<&'static str> {
+    Some(match proto {
+        1 => "icmp",
+        6 => "tcp",
+        17 => "udp",
+        47 => "gre",
+        50 => "esp",
+        51 => "ah",
+        58 => "icmpv6",
+        89 => "ospf",
+        132 => "sctp",
+        _ => return None,
+    })
+}
+
 /// Convert protocol number to name.
 fn protocol_number_to_name(proto: u8) -> String {
-    match proto {
-        1 => "icmp".to_string(),
-        6 => "tcp".to_string(),
-        17 => "udp".to_string(),
-        47 => "gre".to_string(),
-        50 => "esp".to_string(),
-        51 => "ah".to_string(),
-        58 => "icmpv6".to_string(),
-        89 => "ospf".to_string(),
-        132 => "sctp".to_string(),
-        _ => format!("proto_{}", proto),
+    match protocol_name(proto) {
+        Some(name) => name.to_string(),
+        None => format!("proto_{}", proto),
+    }
+}
+
+/// The `proto` label (#480): the protocol's name, or its number when it has
+/// none. The numeric `protocol` label is kept alongside.
+fn protocol_label(proto: u8) -> String {
+    match protocol_name(proto) {
+        Some(name) => name.to_string(),
+        None => proto.to_string(),
     }
 }
 
@@ -734,6 +1021,136 @@ mod tests {
             Some("172.16.0.1")
         );
         assert_eq!(tp.labels.get("bytes").map(String::as_str), Some("1500"));
+        assert_eq!(tp.labels.get("protocol").map(String::as_str), Some("6"));
+        assert_eq!(tp.labels.get("proto").map(String::as_str), Some("tcp"));
+        assert_eq!(
+            tp.labels.get("src_scope").map(String::as_str),
+            Some("private")
+        );
+        assert_eq!(
+            tp.labels.get("dst_scope").map(String::as_str),
+            Some("private")
+        );
+    }
+
+    /// v9 and IPFIX records name their addresses and protocol after the
+    /// template field; they still get scope labels and the usual metric.
+    #[test]
+    fn test_to_telemetry_point_v9_and_ipfix_addresses() {
+        let record = |version: u16, src: &str, dst: &str, proto: &str| {
+            let mut fields = HashMap::from([
+                (
+                    src.to_string(),
+                    FlowFieldValue::IpAddr("192.168.1.1".to_string()),
+                ),
+                (
+                    dst.to_string(),
+                    FlowFieldValue::IpAddr("8.8.8.8".to_string()),
+                ),
+                (proto.to_string(), FlowFieldValue::Uint(17)),
+            ]);
+            normalize_fields(&mut fields);
+            FlowRecord {
+                exporter_ip: "e".to_string(),
+                exporter_name: "e".to_string(),
+                version,
+                fields,
+                timestamp: 0,
+            }
+        };
+
+        for record in [
+            record(9, "ipv4srcaddr", "ipv4dstaddr", "protocol"),
+            record(
+                10,
+                "sourceipv4address",
+                "destinationipv4address",
+                "protocolidentifier",
+            ),
+        ] {
+            let tp = to_telemetry_point(&record);
+            assert_eq!(tp.metric, "192.168.1.1/8.8.8.8/udp");
+            assert_eq!(
+                tp.labels.get("src_scope").map(String::as_str),
+                Some("private")
+            );
+            assert_eq!(
+                tp.labels.get("dst_scope").map(String::as_str),
+                Some("public")
+            );
+            assert_eq!(
+                tp.labels.get("src_addr").map(String::as_str),
+                Some("192.168.1.1")
+            );
+            assert_eq!(tp.labels.get("proto").map(String::as_str), Some("udp"));
+        }
+
+        // IPv6 IPFIX fields are picked up too.
+        let tp = to_telemetry_point(&record(
+            10,
+            "sourceipv6address",
+            "destinationipv6address",
+            "protocolidentifier",
+        ));
+        assert_eq!(
+            tp.labels.get("dst_scope").map(String::as_str),
+            Some("public")
+        );
+    }
+
+    #[test]
+    fn test_protocol_label_falls_back_to_number() {
+        let point = |proto: u64| {
+            let record = FlowRecord {
+                exporter_ip: "e".to_string(),
+                exporter_name: "e".to_string(),
+                version: 9,
+                fields: HashMap::from([("protocol".to_string(), FlowFieldValue::Uint(proto))]),
+                timestamp: 0,
+            };
+            to_telemetry_point(&record)
+        };
+        assert_eq!(
+            point(6).labels.get("proto").map(String::as_str),
+            Some("tcp")
+        );
+        assert_eq!(
+            point(47).labels.get("proto").map(String::as_str),
+            Some("gre")
+        );
+        // Unassigned numbers keep their numeric value.
+        let unknown = point(253);
+        assert_eq!(unknown.labels.get("proto").map(String::as_str), Some("253"));
+        assert_eq!(
+            unknown.labels.get("protocol").map(String::as_str),
+            Some("253")
+        );
+        // The flow key keeps its `proto_N` form.
+        assert_eq!(unknown.metric, "unknown/unknown/proto_253");
+    }
+
+    #[test]
+    fn test_ip_scope() {
+        let scope = |ip: &str| ip_scope(ip.parse().unwrap());
+        // IPv4 private (RFC 1918) and link-local
+        assert_eq!(scope("10.1.2.3"), IpScope::Private);
+        assert_eq!(scope("172.20.0.1"), IpScope::Private);
+        assert_eq!(scope("192.168.0.10"), IpScope::Private);
+        assert_eq!(scope("169.254.10.1"), IpScope::Private);
+        // IPv4 public, including just outside 172.16.0.0/12
+        assert_eq!(scope("8.8.8.8"), IpScope::Public);
+        assert_eq!(scope("172.32.0.1"), IpScope::Public);
+        // IPv6 unique local (RFC 4193) and link-local vs global
+        assert_eq!(scope("fd12:3456:789a::1"), IpScope::Private);
+        assert_eq!(scope("fe80::1"), IpScope::Private);
+        assert_eq!(scope("2001:4860:4860::8888"), IpScope::Public);
+        // Loopback and multicast
+        assert_eq!(scope("127.0.0.1"), IpScope::Loopback);
+        assert_eq!(scope("::1"), IpScope::Loopback);
+        assert_eq!(scope("::ffff:127.0.0.1"), IpScope::Loopback);
+        assert_eq!(scope("239.1.1.1"), IpScope::Multicast);
+        assert_eq!(scope("ff02::1"), IpScope::Multicast);
+        assert_eq!(IpScope::Loopback.as_str(), "loopback");
     }
 
     /// `packets` is used as the value when no `bytes` field is present.
@@ -827,6 +1244,42 @@ mod tests {
         assert!(saw_flow, "parser did not yield a V5 flow record");
     }
 
+    #[test]
+    fn flow_end_time_follows_timestamp_source() {
+        // v5-style record: `last` is 800 ms before the header's uptime.
+        let clock = ExportClock::with_uptime(1_700_000_000, 0, 1000);
+        let mut fields = HashMap::new();
+        fields.insert("last".to_string(), FlowFieldValue::Uint(200));
+        let mut record = FlowRecord {
+            exporter_ip: "1.2.3.4".to_string(),
+            exporter_name: "exp".to_string(),
+            version: 5,
+            fields,
+            timestamp: 0,
+        };
+        let end = 1_700_000_000_000 - 800;
+        assert_eq!(flow_end_ms(&record.fields, &clock), Some(end));
+
+        stamp(&mut record, &clock, TimestampSource::Message, 42);
+        assert_eq!(record.timestamp, end);
+        stamp(&mut record, &clock, TimestampSource::Receipt, 42);
+        assert_eq!(record.timestamp, 42);
+
+        // IPFIX: absolute end time, no uptime needed.
+        let ipfix_clock = ExportClock::export_time(1_700_000_000);
+        let mut ipfix = HashMap::new();
+        ipfix.insert(
+            "flowendmilliseconds".to_string(),
+            FlowFieldValue::Uint(1_699_999_999_123),
+        );
+        assert_eq!(flow_end_ms(&ipfix, &ipfix_clock), Some(1_699_999_999_123));
+
+        // No end-time field: `message` falls back to receipt.
+        record.fields.clear();
+        stamp(&mut record, &clock, TimestampSource::Message, 42);
+        assert_eq!(record.timestamp, 42);
+    }
+
     /// Garbage / truncated input must not panic the parser path.
     #[test]
     fn test_parse_garbage_does_not_panic() {
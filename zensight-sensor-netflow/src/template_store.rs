@@ -0,0 +1,205 @@
+//! Bounded LRU store for per-exporter template state (#481).
+//!
+//! NetFlow v9 and IPFIX data can only be decoded with the templates the
+//! exporter sent earlier, so each exporter gets its own parser holding them.
+//! A flood of short-lived exporters (or spoofed source addresses) would grow
+//! that set without bound; the store caps it and drops the template state of
+//! the exporter heard from least recently. An evicted exporter that comes back
+//! simply starts over and decodes again once it re-sends its templates.
+//!
+//! The same store bounds the templates of a single exporter: the parser's
+//! template cache is capped, and [`announced_template_ids`] lets the listener
+//! follow announcements so the template pushed out is logged.
+
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+/// Default number of exporters whose template state is kept per listener.
+pub const DEFAULT_MAX_EXPORTERS: usize = 1024;
+
+/// Default number of templates kept for a single exporter.
+pub const DEFAULT_MAX_TEMPLATES_PER_EXPORTER: usize = 256;
+
+/// IDs of the data templates announced in a raw NetFlow v9 or IPFIX packet,
+/// in packet order. Other versions and malformed sets yield nothing.
+pub fn announced_template_ids(packet: &[u8]) -> Vec<u16> {
+    let read_u16 = |at: usize| -> Option<u16> {
+        packet
+            .get(at..at + 2)
+            .map(|b| u16::from_be_bytes([b[0], b[1]]))
+    };
+    // (header length, template set ID, whether field specifiers may carry
+    // an enterprise number)
+    let (mut offset, template_set, enterprise) = match read_u16(0) {
+        Some(9) => (20, 0, false),
+        Some(10) => (16, 2, true),
+        _ => return Vec::new(),
+    };
+
+    let mut ids = Vec::new();
+    while let (Some(set_id), Some(set_len)) = (read_u16(offset), read_u16(offset + 2)) {
+        let set_len = usize::from(set_len);
+        if set_len < 4 || offset + set_len > packet.len() {
+            break;
+        }
+        let set_end = offset + set_len;
+        if set_id == template_set {
+            let mut record = offset + 4;
+            while record + 4 <= set_end {
+                let (Some(id), Some(fields)) = (read_u16(record), read_u16(record + 2)) else {
+                    break;
+                };
+                ids.push(id);
+                record += 4;
+                for _ in 0..fields {
+                    let Some(field_type) = read_u16(record) else {
+                        break;
+                    };
+                    record += if enterprise && field_type & 0x8000 != 0 {
+                        8
+                    } else {
+                        4
+                    };
+                }
+            }
+        }
+        offset = set_end;
+    }
+    ids
+}
+
+/// A map holding at most `capacity` entries, evicting the least recently used.
+#[derive(Debug)]
+pub struct TemplateStore<K, V> {
+    entries: HashMap<K, (V, u64)>,
+    /// Last-use tick → key, oldest first.
+    recency: BTreeMap<u64, K>,
+    tick: u64,
+    capacity: usize,
+}
+
+impl<K: Eq + Hash + Clone, V> TemplateStore<K, V> {
+    /// An empty store keeping at most `capacity` entries (at least one).
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            tick: 0,
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// The entry for `key`, created with `make` when absent, and marked most
+    /// recently used. Returns it with the key evicted to make room, if any.
+    pub fn get_or_insert_with(&mut self, key: K, make: impl FnOnce() -> V) -> (&mut V, Option<K>) {
+        self.tick += 1;
+        let tick = self.tick;
+        let mut evicted = None;
+        if let Some((_, last)) = self.entries.get_mut(&key) {
+            self.recency.remove(last);
+            *last = tick;
+        } else {
+            if self.entries.len() >= self.capacity
+                && let Some((_, oldest)) = self.recency.pop_first()
+            {
+                self.entries.remove(&oldest);
+                evicted = Some(oldest);
+            }
+            self.entries.insert(key.clone(), (make(), tick));
+        }
+        self.recency.insert(tick, key.clone());
+        let (value, _) = self.entries.get_mut(&key).expect("entry just inserted");
+        (value, evicted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inserting_beyond_capacity_evicts_least_recently_used() {
+        let mut store = TemplateStore::new(2);
+        assert_eq!(store.get_or_insert_with("a", || 1).1, None);
+        assert_eq!(store.get_or_insert_with("b", || 2).1, None);
+
+        // "a" is the oldest, so "c" pushes it out.
+        assert_eq!(store.get_or_insert_with("c", || 3).1, Some("a"));
+
+        // Touching "b" keeps its state and makes "c" the oldest.
+        let (b, evicted) = store.get_or_insert_with("b", || unreachable!());
+        assert_eq!((*b, evicted), (2, None));
+        assert_eq!(store.get_or_insert_with("d", || 4).1, Some("c"));
+
+        // An evicted key starts over with fresh state.
+        let (a, evicted) = store.get_or_insert_with("a", || 10);
+        assert_eq!((*a, evicted), (10, Some("b")));
+    }
+
+    /// A v9 packet with one template flowset announcing `ids`, each with a
+    /// single field.
+    fn v9_template_packet(ids: &[u16]) -> Vec<u8> {
+        let mut set = Vec::new();
+        for id in ids {
+            set.extend_from_slice(&id.to_be_bytes());
+            set.extend_from_slice(&1u16.to_be_bytes());
+            // IN_BYTES, 4 bytes.
+            set.extend_from_slice(&[0, 1, 0, 4]);
+        }
+        let mut pkt = vec![0u8; 20];
+        pkt[..2].copy_from_slice(&9u16.to_be_bytes());
+        pkt.extend_from_slice(&0u16.to_be_bytes());
+        pkt.extend_from_slice(&(set.len() as u16 + 4).to_be_bytes());
+        pkt.extend_from_slice(&set);
+        pkt
+    }
+
+    #[test]
+    fn reads_template_ids_from_v9_and_ipfix_packets() {
+        assert_eq!(
+            announced_template_ids(&v9_template_packet(&[256, 300])),
+            vec![256, 300]
+        );
+
+        // IPFIX template set (ID 2) with an enterprise-specific field, whose
+        // specifier is 8 bytes long, followed by a standard one.
+        let mut set = Vec::new();
+        set.extend_from_slice(&400u16.to_be_bytes());
+        set.extend_from_slice(&2u16.to_be_bytes());
+        set.extend_from_slice(&[0x80, 0x01, 0, 4, 0, 0, 0x12, 0x34]);
+        set.extend_from_slice(&[0, 8, 0, 4]);
+        set.extend_from_slice(&401u16.to_be_bytes());
+        set.extend_from_slice(&0u16.to_be_bytes());
+        let mut pkt = vec![0u8; 16];
+        pkt[..2].copy_from_slice(&10u16.to_be_bytes());
+        pkt.extend_from_slice(&2u16.to_be_bytes());
+        pkt.extend_from_slice(&(set.len() as u16 + 4).to_be_bytes());
+        pkt.extend_from_slice(&set);
+        assert_eq!(announced_template_ids(&pkt), vec![400, 401]);
+
+        // v5 has no templates, and a truncated set is ignored.
+        let mut v5 = vec![0u8; 24];
+        v5[1] = 5;
+        assert!(announced_template_ids(&v5).is_empty());
+        let mut truncated = v9_template_packet(&[256]);
+        truncated.truncate(26);
+        assert!(announced_template_ids(&truncated).is_empty());
+    }
+
+    #[test]
+    fn templates_beyond_the_per_exporter_cap_are_evicted() {
+        let mut templates = TemplateStore::new(2);
+        let mut evicted = Vec::new();
+        for packet in [
+            v9_template_packet(&[256, 257]),
+            // Re-announcing 256 refreshes it, so 257 goes first.
+            v9_template_packet(&[256, 258]),
+            v9_template_packet(&[259]),
+        ] {
+            for id in announced_template_ids(&packet) {
+                evicted.extend(templates.get_or_insert_with(id, || ()).1);
+            }
+        }
+        assert_eq!(evicted, vec![257, 256]);
+    }
+}
@@ -0,0 +1,130 @@
+#!/usr/bin/env python3
+"""Write geoip-test.mmdb, the tiny MaxMind DB used by the GeoIP tests (#478).
+
+The database is IPv6 (IPv4 lives under ::/96) with 24-bit records. Each
+network maps to a record carrying both GeoLite2-Country and GeoLite2-ASN
+fields, so the one file stands in for either database:
+
+    81.2.69.0/24  -> GB, AS20712
+    10.0.0.0/8    -> XX, AS64512 (private: the enricher must never look it up)
+
+Run from this directory: ./make-geoip-fixture.py
+"""
+
+import ipaddress
+import struct
+
+NETWORKS = [
+    ("81.2.69.0/24", "GB", "United Kingdom", 20712, "Andrews & Arnold Ltd"),
+    ("10.0.0.0/8", "XX", "Private", 64512, "Private use"),
+]
+
+
+def control(type_id, size):
+    out = bytearray()
+    if size < 29:
+        size_bits, extra = size, b""
+    elif size < 285:
+        size_bits, extra = 29, bytes([size - 29])
+    else:
+        size_bits, extra = 30, struct.pack(">H", size - 285)
+    if type_id <= 7:
+        out.append((type_id << 5) | size_bits)
+    else:
+        out.append(size_bits)
+        out.append(type_id - 7)
+    return bytes(out) + extra
+
+
+def uint(type_id, value):
+    raw = value.to_bytes((value.bit_length() + 7) // 8, "big") if value else b""
+    return control(type_id, len(raw)) + raw
+
+
+def encode(value):
+    if isinstance(value, str):
+        raw = value.encode()
+        return control(2, len(raw)) + raw
+    if isinstance(value, tuple):  # (type id, unsigned int)
+        return uint(*value)
+    if isinstance(value, list):
+        return control(11, len(value)) + b"".join(encode(v) for v in value)
+    if isinstance(value, dict):
+        return control(7, len(value)) + b"".join(
+            encode(k) + encode(v) for k, v in value.items()
+        )
+    raise TypeError(value)
+
+
+def u16(v):
+    return (5, v)
+
+
+def u32(v):
+    return (6, v)
+
+
+def u64(v):
+    return (9, v)
+
+
+def main():
+    data = bytearray()
+    leaves = []
+    for cidr, iso, name, asn, org in NETWORKS:
+        offset = len(data)
+        data += encode(
+            {
+                "country": {"iso_code": iso, "names": {"en": name}},
+                "autonomous_system_number": u32(asn),
+                "autonomous_system_organization": org,
+            }
+        )
+        net = ipaddress.ip_network(cidr)
+        bits = format(int(net.network_address), "032b")[: net.prefixlen]
+        leaves.append(("0" * 96 + bits, offset))
+
+    # Trie of [left, right] records: ("node", i), ("data", offset) or None.
+    nodes = [[None, None]]
+    for bits, offset in leaves:
+        node = 0
+        for i, bit in enumerate(bits):
+            side = int(bit)
+            if i == len(bits) - 1:
+                nodes[node][side] = ("data", offset)
+            else:
+                if nodes[node][side] is None:
+                    nodes.append([None, None])
+                    nodes[node][side] = ("node", len(nodes) - 1)
+                node = nodes[node][side][1]
+
+    node_count = len(nodes)
+
+    def record(r):
+        if r is None:
+            return node_count
+        kind, value = r
+        return value if kind == "node" else node_count + 16 + value
+
+    tree = b"".join(
+        record(l).to_bytes(3, "big") + record(r).to_bytes(3, "big") for l, r in nodes
+    )
+    metadata = encode(
+        {
+            "node_count": u32(node_count),
+            "record_size": u16(24),
+            "ip_version": u16(6),
+            "database_type": "ZenSight-GeoIP-Test",
+            "languages": ["en"],
+            "binary_format_major_version": u16(2),
+            "binary_format_minor_version": u16(0),
+            "build_epoch": u64(1_700_000_000),
+            "description": {"en": "ZenSight NetFlow GeoIP test fixture"},
+        }
+    )
+    with open("geoip-test.mmdb", "wb") as f:
+        f.write(tree + bytes(16) + bytes(data) + b"\xab\xcd\xefMaxMind.com" + metadata)
+
+
+if __name__ == "__main__":
+    main()
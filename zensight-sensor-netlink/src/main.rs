@@ -15,6 +15,10 @@ use zensight_sensor_netlink::config::NetlinkSensorConfig;
 #[tokio::main]
 async fn main() -> Result<()> {
     let args = SensorArgs::parse_with_default("netlink.json5");
+    if args.print_default_config {
+        print!("{}", NetlinkSensorConfig::default_config_json5());
+        return Ok(());
+    }
     let config = NetlinkSensorConfig::load(&args.config).map_err(|e| anyhow::anyhow!("{}", e))?;
 
     let hostname = config.netlink.resolved_hostname();
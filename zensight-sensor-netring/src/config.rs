@@ -29,6 +29,24 @@ pub struct NetringSensorConfig {
     /// Tier-2 directory-snapshot (`@/snapshot`) limits. Disabled by default.
     #[serde(default)]
     pub snapshot: zensight_sensor_core::SnapshotLimits,
+    /// Zenoh congestion control / priority per key or protocol (#410).
+    #[serde(default)]
+    pub publish_qos: zensight_sensor_core::PublishQosConfig,
+    /// Bounded queue + timed replay for publishes that fail (#412).
+    #[serde(default)]
+    pub publish_retry: zensight_sensor_core::PublishRetryConfig,
+
+    /// Re-publish matching telemetry under alternate prefixes (#447).
+    #[serde(default)]
+    pub publish_mirror: Vec<zensight_sensor_core::MirrorRule>,
+
+    /// Attach bridge name/version/encoding to each sample (#444).
+    #[serde(default)]
+    pub attach_metadata: bool,
+
+    /// Unix socket for runtime control commands (#445); off when unset.
+    #[serde(default)]
+    pub control_socket: Option<std::path::PathBuf>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -498,6 +516,10 @@ impl NetringConfig {
 }
 
 impl SensorConfig for NetringSensorConfig {
+    fn default_config_json5() -> &'static str {
+        include_str!("../../configs/netring.json5")
+    }
+
     fn zenoh(&self) -> &ZenohConfig {
         &self.zenoh
     }
@@ -514,6 +536,24 @@ impl SensorConfig for NetringSensorConfig {
     fn snapshot_limits(&self) -> zensight_sensor_core::SnapshotLimits {
         self.snapshot.clone()
     }
+    fn publish_qos(&self) -> zensight_sensor_core::PublishQosConfig {
+        self.publish_qos.clone()
+    }
+    fn publish_retry(&self) -> zensight_sensor_core::PublishRetryConfig {
+        self.publish_retry.clone()
+    }
+
+    fn publish_mirror(&self) -> Vec<zensight_sensor_core::MirrorRule> {
+        self.publish_mirror.clone()
+    }
+
+    fn attach_metadata(&self) -> bool {
+        self.attach_metadata
+    }
+
+    fn control_socket(&self) -> Option<std::path::PathBuf> {
+        self.control_socket.clone()
+    }
     fn validate(&self) -> zensight_sensor_core::Result<()> {
         if self.netring.pcap.is_none() && self.netring.interfaces.is_empty() {
             return Err(zensight_sensor_core::SensorError::config(
@@ -567,4 +607,12 @@ mod tests {
             json5::from_str(r#"{ netring: { pcap: "/tmp/x.pcap" } }"#).unwrap();
         assert!(cfg.validate().is_ok());
     }
+
+    #[test]
+    fn printed_default_config_documents_every_field() {
+        assert_eq!(
+            zensight_sensor_core::undocumented_fields::<NetringSensorConfig>().unwrap(),
+            Vec::<String>::new()
+        );
+    }
 }
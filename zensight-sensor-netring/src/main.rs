@@ -16,6 +16,10 @@ use zensight_sensor_netring::{monitor, publish};
 #[tokio::main]
 async fn main() -> Result<()> {
     let args = SensorArgs::parse_with_default("netring.json5");
+    if args.print_default_config {
+        print!("{}", NetringSensorConfig::default_config_json5());
+        return Ok(());
+    }
     let config = NetringSensorConfig::load(&args.config).map_err(|e| anyhow::anyhow!("{}", e))?;
 
     let sensor_id = config.netring.resolved_sensor_id();
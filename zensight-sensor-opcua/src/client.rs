@@ -0,0 +1,317 @@
+//! OPC-UA session and subscription handling.
+//!
+//! One [`OpcuaClient`] per configured server: it opens a session, creates a
+//! subscription with a monitored item per configured node, and publishes each
+//! value change through the framework [`Publisher`] as
+//! `<key_prefix>/<server>/<metric>`. A lost session is re-established after
+//! `reconnect_delay_secs`.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use opcua::client::{ClientBuilder, DataChangeCallback, IdentityToken};
+use opcua::crypto::SecurityPolicy as CryptoPolicy;
+use opcua::types::{
+    DataValue, MessageSecurityMode, MonitoredItemCreateRequest, NodeId, TimestampsToReturn,
+    UserTokenPolicy, UserTokenType,
+};
+use tokio::sync::mpsc;
+use tracing::{debug, info, warn};
+
+use zensight_common::TimestampSource;
+use zensight_sensor_core::{ClockSkewDetector, Publisher, SensorHealth};
+
+use crate::config::{Auth, OpcuaConfig, SecurityMode, SecurityPolicy, ServerConfig};
+use crate::map::{data_value_point, resolve_node};
+
+/// Value changes buffered between the subscription callback and the
+/// publisher. Past this a slow publisher drops (and counts) new changes
+/// instead of letting the queue grow without bound.
+const CHANGE_QUEUE_CAPACITY: usize = 4096;
+
+/// A subscribed node: its metric name and configured node ID.
+#[derive(Debug, Clone)]
+struct Monitored {
+    metric: String,
+    node_id: String,
+}
+
+/// Subscribes to one OPC-UA server and publishes its value changes.
+pub struct OpcuaClient {
+    server: ServerConfig,
+    pki_dir: std::path::PathBuf,
+    trust_server_certs: bool,
+    reconnect_delay: Duration,
+    publisher: Publisher,
+    health: Arc<SensorHealth>,
+    timestamp_source: TimestampSource,
+    clock_skew: Option<Arc<ClockSkewDetector>>,
+}
+
+impl OpcuaClient {
+    /// Create a client for `server`, publishing through `publisher`.
+    pub fn new(
+        server: ServerConfig,
+        settings: &OpcuaConfig,
+        publisher: Publisher,
+        health: Arc<SensorHealth>,
+    ) -> Self {
+        Self {
+            server,
+            pki_dir: settings.pki_dir.clone(),
+            trust_server_certs: settings.trust_server_certs,
+            reconnect_delay: Duration::from_secs(settings.reconnect_delay_secs.max(1)),
+            publisher,
+            health,
+            timestamp_source: TimestampSource::default(),
+            clock_skew: None,
+        }
+    }
+
+    /// Choose which clock stamps published points (#406).
+    pub fn with_timestamp_source(mut self, timestamp_source: TimestampSource) -> Self {
+        self.timestamp_source = timestamp_source;
+        self
+    }
+
+    /// Check source timestamps against receipt time (#407).
+    pub fn with_clock_skew(mut self, detector: Arc<ClockSkewDetector>) -> Self {
+        self.clock_skew = Some(detector);
+        self
+    }
+
+    /// Run until the task is aborted, reconnecting whenever the session ends.
+    pub async fn run(&self) {
+        loop {
+            match self.run_session().await {
+                Ok(status) => warn!(
+                    server = %self.server.name,
+                    %status,
+                    "OPC-UA session ended"
+                ),
+                Err(e) => {
+                    warn!(
+                        server = %self.server.name,
+                        error = %e,
+                        retry_secs = self.reconnect_delay.as_secs(),
+                        "OPC-UA connection failed"
+                    );
+                    self.health
+                        .record_device_failure_async(&self.server.name, &e.to_string())
+                        .await;
+                }
+            }
+            tokio::time::sleep(self.reconnect_delay).await;
+        }
+    }
+
+    /// One session: connect, subscribe, and publish until the session's
+    /// event loop exits. Returns the status it exited with.
+    async fn run_session(&self) -> anyhow::Result<opcua::types::StatusCode> {
+        let monitored = self.monitored_nodes()?;
+
+        let mut client = ClientBuilder::new()
+            .application_name("ZenSight OPC-UA sensor")
+            .application_uri("urn:zensight:sensor:opcua")
+            .product_uri("urn:zensight:sensor:opcua")
+            .pki_dir(self.pki_dir.clone())
+            .create_sample_keypair(true)
+            .trust_server_certs(self.trust_server_certs)
+            .session_retry_limit(3)
+            .client()
+            .map_err(|e| anyhow::anyhow!("invalid OPC-UA client config: {e:?}"))?;
+
+        let endpoint = (
+            self.server.endpoint_url.as_str(),
+            crypto_policy(self.server.security_policy).to_str(),
+            message_security_mode(self.server.security_mode),
+            user_token_policy(&self.server.auth),
+        );
+        let (session, event_loop) = client
+            .connect_to_matching_endpoint(endpoint, identity_token(&self.server.auth))
+            .await?;
+        let event_loop = event_loop.spawn();
+        session.wait_for_connection().await;
+        info!(
+            server = %self.server.name,
+            endpoint = %self.server.endpoint_url,
+            "OPC-UA session established"
+        );
+
+        // The data-change callback runs on the session's event loop; hand the
+        // changes to a task that can await the publisher.
+        let (tx, rx) = mpsc::channel::<(NodeId, DataValue)>(CHANGE_QUEUE_CAPACITY);
+        let dropped = Arc::new(AtomicU64::new(0));
+        let server_name = self.server.name.clone();
+        let subscription_id = session
+            .create_subscription(
+                Duration::from_millis(self.server.publishing_interval_ms),
+                10,
+                30,
+                0,
+                0,
+                true,
+                DataChangeCallback::new(move |data_value, item| {
+                    let change = (item.item_to_monitor().node_id.clone(), data_value);
+                    if let Err(mpsc::error::TrySendError::Full(_)) = tx.try_send(change) {
+                        // Warn on the 1st, 2nd, 4th, ... drop so a stall is
+                        // visible without a log line per change.
+                        let total = dropped.fetch_add(1, Ordering::Relaxed) + 1;
+                        if total.is_power_of_two() {
+                            warn!(
+                                server = %server_name,
+                                dropped = total,
+                                "OPC-UA change queue full, dropping value changes"
+                            );
+                        }
+                    }
+                }),
+            )
+            .await?;
+
+        let items: Vec<MonitoredItemCreateRequest> = monitored
+            .keys()
+            .map(|node_id| {
+                let mut request: MonitoredItemCreateRequest = node_id.clone().into();
+                request.requested_parameters.sampling_interval =
+                    self.server.sampling_interval_ms as f64;
+                request
+            })
+            .collect();
+        let results = session
+            .create_monitored_items(subscription_id, TimestampsToReturn::Both, items)
+            .await?;
+        for result in &results {
+            if !result.result.status_code.is_good() {
+                warn!(
+                    server = %self.server.name,
+                    status = %result.result.status_code,
+                    "OPC-UA server rejected a monitored item"
+                );
+            }
+        }
+        self.health
+            .record_device_success_async(&self.server.name)
+            .await;
+
+        let forward = tokio::spawn(forward_changes(
+            rx,
+            monitored,
+            self.server.name.clone(),
+            self.publisher.clone(),
+            self.health.clone(),
+            self.timestamp_source,
+            self.clock_skew.clone(),
+        ));
+        let status = event_loop.await;
+        forward.abort();
+        Ok(status?)
+    }
+
+    /// Configured nodes keyed by their parsed node ID.
+    fn monitored_nodes(&self) -> anyhow::Result<HashMap<NodeId, Monitored>> {
+        self.server
+            .nodes
+            .iter()
+            .map(|node| {
+                let (node_id, metric) = resolve_node(node).map_err(|e| anyhow::anyhow!(e))?;
+                Ok((
+                    node_id,
+                    Monitored {
+                        metric,
+                        node_id: node.node_id.clone(),
+                    },
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Publish value changes as they arrive from the subscription callback.
+async fn forward_changes(
+    mut rx: mpsc::Receiver<(NodeId, DataValue)>,
+    monitored: HashMap<NodeId, Monitored>,
+    server: String,
+    publisher: Publisher,
+    health: Arc<SensorHealth>,
+    timestamp_source: TimestampSource,
+    clock_skew: Option<Arc<ClockSkewDetector>>,
+) {
+    while let Some((node_id, data_value)) = rx.recv().await {
+        let Some(node) = monitored.get(&node_id) else {
+            continue;
+        };
+        let receipt_ms = zensight_common::current_timestamp_millis();
+        if let Some(detector) = &clock_skew
+            && let Some(source) = &data_value.source_timestamp
+        {
+            detector
+                .observe(&server, source.as_chrono().timestamp_millis(), receipt_ms)
+                .await;
+        }
+        let Some(point) = data_value_point(
+            &server,
+            &node.metric,
+            &node.node_id,
+            &data_value,
+            timestamp_source,
+            receipt_ms,
+        ) else {
+            debug!(server = %server, node = %node.node_id, "Skipping unusable OPC-UA value");
+            continue;
+        };
+        match publisher
+            .publish(&format!("{}/{}", server, node.metric), &point)
+            .await
+        {
+            Ok(()) => health.record_metrics_published(1),
+            Err(e) => warn!(server = %server, metric = %node.metric, error = %e, "Publish failed"),
+        }
+    }
+}
+
+fn crypto_policy(policy: SecurityPolicy) -> CryptoPolicy {
+    match policy {
+        SecurityPolicy::None => CryptoPolicy::None,
+        SecurityPolicy::Basic128Rsa15 => CryptoPolicy::Basic128Rsa15,
+        SecurityPolicy::Basic256 => CryptoPolicy::Basic256,
+        SecurityPolicy::Basic256Sha256 => CryptoPolicy::Basic256Sha256,
+        SecurityPolicy::Aes128Sha256RsaOaep => CryptoPolicy::Aes128Sha256RsaOaep,
+        SecurityPolicy::Aes256Sha256RsaPss => CryptoPolicy::Aes256Sha256RsaPss,
+    }
+}
+
+fn message_security_mode(mode: SecurityMode) -> MessageSecurityMode {
+    match mode {
+        SecurityMode::None => MessageSecurityMode::None,
+        SecurityMode::Sign => MessageSecurityMode::Sign,
+        SecurityMode::SignAndEncrypt => MessageSecurityMode::SignAndEncrypt,
+    }
+}
+
+fn user_token_policy(auth: &Auth) -> UserTokenPolicy {
+    let token_type = match auth {
+        Auth::Anonymous => return UserTokenPolicy::anonymous(),
+        Auth::Username { .. } => UserTokenType::UserName,
+        Auth::Certificate { .. } => UserTokenType::Certificate,
+    };
+    UserTokenPolicy {
+        token_type,
+        ..Default::default()
+    }
+}
+
+fn identity_token(auth: &Auth) -> IdentityToken {
+    match auth {
+        Auth::Anonymous => IdentityToken::Anonymous,
+        Auth::Username { username, password } => {
+            IdentityToken::UserName(username.clone(), password.clone().into())
+        }
+        Auth::Certificate {
+            cert_path,
+            key_path,
+        } => IdentityToken::X509(cert_path.clone(), key_path.clone()),
+    }
+}
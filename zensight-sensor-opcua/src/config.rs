@@ -0,0 +1,427 @@
+//! Configuration for the OPC-UA sensor.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use zensight_common::{ClockSkewConfig, TimestampSource, ZenohConfig};
+
+use crate::map::resolve_node;
+
+// Re-export LoggingConfig from the framework for compatibility
+pub use zensight_sensor_core::LoggingConfig;
+
+/// Configuration errors.
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("Failed to read config file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Failed to parse config: {0}")]
+    Parse(#[from] json5::Error),
+    #[error("Validation error: {0}")]
+    Validation(String),
+}
+
+/// Complete sensor configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpcuaSensorConfig {
+    /// Zenoh connection settings
+    pub zenoh: ZenohConfig,
+
+    /// OPC-UA-specific settings
+    pub opcua: OpcuaConfig,
+
+    /// Logging configuration
+    #[serde(default)]
+    pub logging: LoggingConfig,
+
+    /// On-demand debug-report (`@/report`) limits. Disabled by default.
+    #[serde(default)]
+    pub report: zensight_sensor_core::ReportLimits,
+
+    /// Tier-2 directory-snapshot (`@/snapshot`) limits. Disabled by default.
+    #[serde(default)]
+    pub snapshot: zensight_sensor_core::SnapshotLimits,
+
+    /// Zenoh congestion control / priority per key or protocol (#410).
+    #[serde(default)]
+    pub publish_qos: zensight_sensor_core::PublishQosConfig,
+
+    /// Bounded queue + timed replay for publishes that fail (#412).
+    #[serde(default)]
+    pub publish_retry: zensight_sensor_core::PublishRetryConfig,
+
+    /// Re-publish matching telemetry under alternate prefixes (#447).
+    #[serde(default)]
+    pub publish_mirror: Vec<zensight_sensor_core::MirrorRule>,
+
+    /// Attach bridge name/version/encoding to each sample (#444).
+    #[serde(default)]
+    pub attach_metadata: bool,
+
+    /// Unix socket for runtime control commands (#445); off when unset.
+    #[serde(default)]
+    pub control_socket: Option<PathBuf>,
+
+    /// Stamp points with the value's source timestamp (`message`, default) or
+    /// with the time it was received (`receipt`) (#406).
+    #[serde(default)]
+    pub timestamp_source: TimestampSource,
+
+    /// Warn when source timestamps drift from receipt time (#407).
+    #[serde(default)]
+    pub clock_skew: ClockSkewConfig,
+}
+
+/// OPC-UA protocol configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpcuaConfig {
+    /// Key expression prefix (default: "zensight/opcua")
+    #[serde(default = "default_key_prefix")]
+    pub key_prefix: String,
+
+    /// Servers to subscribe to
+    pub servers: Vec<ServerConfig>,
+
+    /// Directory holding the client's certificate, key and trusted/rejected
+    /// server certificates. Created on first run.
+    #[serde(default = "default_pki_dir")]
+    pub pki_dir: PathBuf,
+
+    /// Trust server certificates without checking the PKI trust list
+    /// (not recommended outside a lab).
+    #[serde(default)]
+    pub trust_server_certs: bool,
+
+    /// Wait between reconnection attempts after a session is lost
+    #[serde(default = "default_reconnect_delay")]
+    pub reconnect_delay_secs: u64,
+}
+
+fn default_key_prefix() -> String {
+    "zensight/opcua".to_string()
+}
+
+fn default_pki_dir() -> PathBuf {
+    PathBuf::from("pki")
+}
+
+fn default_reconnect_delay() -> u64 {
+    5
+}
+
+/// One OPC-UA server.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerConfig {
+    /// Name used in key expressions
+    pub name: String,
+
+    /// Endpoint URL (e.g., "opc.tcp://192.168.1.50:4840")
+    pub endpoint_url: String,
+
+    /// Security policy for the secure channel
+    #[serde(default)]
+    pub security_policy: SecurityPolicy,
+
+    /// Message security mode for the secure channel
+    #[serde(default)]
+    pub security_mode: SecurityMode,
+
+    /// User authentication
+    #[serde(default)]
+    pub auth: Auth,
+
+    /// How often the server sends batched value changes
+    #[serde(default = "default_publishing_interval")]
+    pub publishing_interval_ms: u64,
+
+    /// How often the server samples each node
+    #[serde(default = "default_sampling_interval")]
+    pub sampling_interval_ms: u64,
+
+    /// Nodes to subscribe to
+    pub nodes: Vec<NodeConfig>,
+}
+
+fn default_publishing_interval() -> u64 {
+    1000
+}
+
+fn default_sampling_interval() -> u64 {
+    500
+}
+
+/// A subscribed node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeConfig {
+    /// Node ID in OPC-UA string form (e.g., "ns=2;s=Line1.Boiler.Temperature")
+    pub node_id: String,
+
+    /// Browse path used as the metric name (e.g., "Line1/Boiler/Temperature").
+    /// Derived from the node ID when unset.
+    #[serde(default)]
+    pub browse_path: Option<String>,
+}
+
+/// Secure-channel security policy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SecurityPolicy {
+    /// No security (the channel is neither signed nor encrypted)
+    #[default]
+    None,
+    /// Deprecated by the OPC Foundation; for old servers only
+    Basic128Rsa15,
+    /// Deprecated by the OPC Foundation; for old servers only
+    Basic256,
+    Basic256Sha256,
+    Aes128Sha256RsaOaep,
+    Aes256Sha256RsaPss,
+}
+
+/// Secure-channel message security mode.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecurityMode {
+    /// Messages are neither signed nor encrypted
+    #[default]
+    None,
+    /// Messages are signed
+    Sign,
+    /// Messages are signed and encrypted
+    SignAndEncrypt,
+}
+
+/// User identity presented when activating the session.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Auth {
+    /// No user identity
+    #[default]
+    Anonymous,
+    /// User name and password
+    Username { username: String, password: String },
+    /// X.509 user certificate and its private key (PEM or DER)
+    Certificate {
+        cert_path: PathBuf,
+        key_path: PathBuf,
+    },
+}
+
+impl OpcuaSensorConfig {
+    /// Load configuration from a JSON5 file.
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        let content = std::fs::read_to_string(path)?;
+        let config: OpcuaSensorConfig = json5::from_str(&content)?;
+        config.validate_config()?;
+        Ok(config)
+    }
+
+    /// Validate the configuration.
+    pub fn validate_config(&self) -> Result<(), ConfigError> {
+        if self.opcua.servers.is_empty() {
+            return Err(ConfigError::Validation(
+                "At least one server must be configured".to_string(),
+            ));
+        }
+
+        for server in &self.opcua.servers {
+            if server.name.is_empty() {
+                return Err(ConfigError::Validation(
+                    "Server name cannot be empty".to_string(),
+                ));
+            }
+
+            if !server.endpoint_url.starts_with("opc.tcp://") {
+                return Err(ConfigError::Validation(format!(
+                    "Server '{}': endpoint_url must start with opc.tcp://",
+                    server.name
+                )));
+            }
+
+            // A secured policy needs a signing mode, and vice versa
+            let secured_policy = server.security_policy != SecurityPolicy::None;
+            let secured_mode = server.security_mode != SecurityMode::None;
+            if secured_policy != secured_mode {
+                return Err(ConfigError::Validation(format!(
+                    "Server '{}': security_policy {:?} does not match security_mode {:?}",
+                    server.name, server.security_policy, server.security_mode
+                )));
+            }
+
+            if let Auth::Username { username, .. } = &server.auth
+                && username.is_empty()
+            {
+                return Err(ConfigError::Validation(format!(
+                    "Server '{}': auth username cannot be empty",
+                    server.name
+                )));
+            }
+
+            if server.nodes.is_empty() {
+                return Err(ConfigError::Validation(format!(
+                    "Server '{}': at least one node must be configured",
+                    server.name
+                )));
+            }
+
+            for node in &server.nodes {
+                resolve_node(node).map_err(|e| {
+                    ConfigError::Validation(format!("Server '{}': {}", server.name, e))
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl zensight_sensor_core::SensorConfig for OpcuaSensorConfig {
+    fn default_config_json5() -> &'static str {
+        include_str!("../../configs/opcua.json5")
+    }
+
+    fn zenoh(&self) -> &ZenohConfig {
+        &self.zenoh
+    }
+
+    fn logging(&self) -> &LoggingConfig {
+        &self.logging
+    }
+
+    fn key_prefix(&self) -> &str {
+        &self.opcua.key_prefix
+    }
+
+    fn report_limits(&self) -> zensight_sensor_core::ReportLimits {
+        self.report.clone()
+    }
+
+    fn snapshot_limits(&self) -> zensight_sensor_core::SnapshotLimits {
+        self.snapshot.clone()
+    }
+
+    fn publish_qos(&self) -> zensight_sensor_core::PublishQosConfig {
+        self.publish_qos.clone()
+    }
+
+    fn publish_retry(&self) -> zensight_sensor_core::PublishRetryConfig {
+        self.publish_retry.clone()
+    }
+
+    fn publish_mirror(&self) -> Vec<zensight_sensor_core::MirrorRule> {
+        self.publish_mirror.clone()
+    }
+
+    fn attach_metadata(&self) -> bool {
+        self.attach_metadata
+    }
+
+    fn control_socket(&self) -> Option<PathBuf> {
+        self.control_socket.clone()
+    }
+
+    fn timestamp_source(&self) -> TimestampSource {
+        self.timestamp_source
+    }
+
+    fn clock_skew(&self) -> ClockSkewConfig {
+        self.clock_skew.clone()
+    }
+
+    fn validate(&self) -> zensight_sensor_core::Result<()> {
+        self.validate_config()
+            .map_err(|e| zensight_sensor_core::SensorError::config(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json: &str) -> OpcuaSensorConfig {
+        json5::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_deserialize_config() {
+        let config = parse(
+            r#"{
+                zenoh: { mode: "peer" },
+                opcua: {
+                    servers: [{
+                        name: "line1",
+                        endpoint_url: "opc.tcp://10.0.0.5:4840",
+                        security_policy: "Basic256Sha256",
+                        security_mode: "sign_and_encrypt",
+                        auth: { type: "username", username: "op", password: "secret" },
+                        nodes: [
+                            { node_id: "ns=2;s=Boiler.Temp", browse_path: "Boiler/Temp" },
+                            { node_id: "i=2258" },
+                        ],
+                    }],
+                },
+            }"#,
+        );
+        let server = &config.opcua.servers[0];
+        assert_eq!(config.opcua.key_prefix, "zensight/opcua");
+        assert_eq!(server.security_policy, SecurityPolicy::Basic256Sha256);
+        assert_eq!(server.security_mode, SecurityMode::SignAndEncrypt);
+        assert_eq!(
+            server.auth,
+            Auth::Username {
+                username: "op".into(),
+                password: "secret".into()
+            }
+        );
+        assert_eq!(server.publishing_interval_ms, 1000);
+        config.validate_config().unwrap();
+    }
+
+    #[test]
+    fn test_validation_rejects_mismatched_security_and_bad_nodes() {
+        let mut config = parse(
+            r#"{
+                zenoh: { mode: "peer" },
+                opcua: {
+                    servers: [{
+                        name: "line1",
+                        endpoint_url: "opc.tcp://10.0.0.5:4840",
+                        nodes: [{ node_id: "ns=2;s=Boiler.Temp" }],
+                    }],
+                },
+            }"#,
+        );
+        config.validate_config().unwrap();
+
+        config.opcua.servers[0].security_policy = SecurityPolicy::Basic256Sha256;
+        assert!(config.validate_config().is_err());
+        config.opcua.servers[0].security_mode = SecurityMode::Sign;
+        config.validate_config().unwrap();
+
+        config.opcua.servers[0].nodes[0].node_id = "ns=2;x=what".into();
+        assert!(config.validate_config().is_err());
+
+        // Rejected by the OPC-UA stack the client parses with, so they must
+        // fail validation rather than loop on reconnect.
+        config.opcua.servers[0].nodes[0].node_id = "ns=3;g=foo".into();
+        assert!(config.validate_config().is_err());
+        config.opcua.servers[0].nodes[0].node_id = "nsu=urn:example;s=Boiler.Temp".into();
+        assert!(config.validate_config().is_err());
+    }
+
+    #[test]
+    fn test_default_config_is_valid() {
+        use zensight_sensor_core::SensorConfig;
+        let config: OpcuaSensorConfig =
+            json5::from_str(OpcuaSensorConfig::default_config_json5()).unwrap();
+        config.validate_config().unwrap();
+    }
+
+    #[test]
+    fn printed_default_config_documents_every_field() {
+        assert_eq!(
+            zensight_sensor_core::undocumented_fields::<OpcuaSensorConfig>().unwrap(),
+            Vec::<String>::new()
+        );
+    }
+}
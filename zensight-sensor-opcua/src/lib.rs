@@ -0,0 +1,11 @@
+//! OPC-UA sensor for ZenSight
+//!
+//! This sensor subscribes to node value changes on OPC-UA servers and
+//! publishes them to Zenoh as telemetry.
+
+pub mod client;
+pub mod config;
+pub mod map;
+
+pub use client::OpcuaClient;
+pub use config::OpcuaSensorConfig;
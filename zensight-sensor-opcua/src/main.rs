@@ -0,0 +1,107 @@
+//! OPC-UA sensor for ZenSight
+//!
+//! Subscribes to node value changes on OPC-UA servers and publishes them to Zenoh.
+
+use tracing::{info, warn};
+
+use zensight_sensor_core::{SensorArgs, SensorConfig, SensorRunner};
+use zensight_sensor_opcua::map::node_metric;
+use zensight_sensor_opcua::{OpcuaClient, OpcuaSensorConfig};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    // Parse CLI arguments
+    let args = SensorArgs::parse_with_default("opcua.json5");
+    if args.print_default_config {
+        print!("{}", OpcuaSensorConfig::default_config_json5());
+        return Ok(());
+    }
+
+    // Load configuration
+    let config = OpcuaSensorConfig::load_from_file(&args.config)?;
+
+    // Create the sensor runner
+    let runner = SensorRunner::new_with_args("opcua", config, Some(&args))
+        .await
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    // Enable status publishing
+    let runner = runner.with_status_publishing();
+
+    // On-demand debug-report (`@/report`): bundle redacted config + health +
+    // counters. No-op unless `report.enabled` is set in the config. The auth
+    // `password` is redacted by default.
+    let report_host = hostname::get()
+        .ok()
+        .and_then(|h| h.into_string().ok())
+        .unwrap_or_else(|| "unknown".to_string());
+    let report_source = std::sync::Arc::new(zensight_sensor_core::SimpleBundleSource::new(
+        "opcua",
+        report_host.clone(),
+        runner.config().clone(),
+        runner.health(),
+    ));
+    // Tier-2 directory snapshots (`@/snapshot`). No-op unless `snapshot.enabled`.
+    let mut runner = runner.with_report(report_source).with_snapshot(report_host);
+
+    let opcua_config = runner.config().opcua.clone();
+
+    // Browse paths and node IDs become metric names: flag bad ones up front
+    // (#459).
+    let metrics = opcua_config
+        .servers
+        .iter()
+        .flat_map(|s| s.nodes.iter())
+        .filter_map(|n| node_metric(n).ok())
+        .collect::<Vec<_>>();
+    zensight_sensor_core::warn_metric_names("opcua", metrics.iter().map(String::as_str));
+
+    if opcua_config.trust_server_certs {
+        warn!("trust_server_certs is set - server certificates are not verified");
+    }
+
+    info!(
+        "Starting OPC-UA sensor with {} server(s)",
+        opcua_config.servers.len()
+    );
+
+    // One detector shared by all servers; it rate-limits per server name.
+    let clock_skew = std::sync::Arc::new(runner.clock_skew_detector());
+    runner
+        .health()
+        .set_devices_total(opcua_config.servers.len() as u64);
+
+    // One client per server
+    for server in &opcua_config.servers {
+        let client = OpcuaClient::new(
+            server.clone(),
+            &opcua_config,
+            runner.publisher(),
+            runner.health(),
+        )
+        .with_timestamp_source(runner.config().timestamp_source)
+        .with_clock_skew(clock_skew.clone());
+
+        info!(
+            "Subscribing to {} node(s) on '{}' ({})",
+            server.nodes.len(),
+            server.name,
+            server.endpoint_url
+        );
+
+        runner.spawn(async move {
+            client.run().await;
+        });
+    }
+
+    // Build status metadata
+    let metadata = serde_json::json!({
+        "servers": opcua_config.servers.iter().map(|s| &s.name).collect::<Vec<_>>(),
+    });
+
+    // Run until Ctrl+C (handles shutdown gracefully)
+    runner
+        .run_with_metadata(Some(metadata))
+        .await
+        .map_err(|e| anyhow::anyhow!("{}", e))
+}
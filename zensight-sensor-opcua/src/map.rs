@@ -0,0 +1,422 @@
+//! Pure mapping from OPC-UA nodes and values to ZenSight telemetry.
+//!
+//! Kept free of I/O so the key and value conversions can be tested against
+//! recorded values without a server.
+
+use std::fmt;
+use std::str::FromStr;
+
+use opcua::types::{DataValue, NodeId, Variant};
+use zensight_common::{Protocol, TelemetryPoint, TelemetryValue, TimestampSource};
+
+use crate::config::NodeConfig;
+
+/// A node ID parsed from its OPC-UA string form (`ns=2;s=Boiler.Temp`,
+/// `i=2258`, `ns=3;g=<guid>`, `ns=1;b=<base64>`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeRef {
+    /// Namespace index (0 when the `ns=` part is omitted)
+    pub namespace: u16,
+    /// The identifier within the namespace
+    pub identifier: Identifier,
+}
+
+/// A node ID's identifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Identifier {
+    /// `i=`
+    Numeric(u32),
+    /// `s=`
+    String(String),
+    /// `g=`
+    Guid(String),
+    /// `b=` (base64)
+    Opaque(String),
+}
+
+impl Identifier {
+    /// The identifier-type letter used in the string form.
+    fn kind(&self) -> &'static str {
+        match self {
+            Identifier::Numeric(_) => "i",
+            Identifier::String(_) => "s",
+            Identifier::Guid(_) => "g",
+            Identifier::Opaque(_) => "b",
+        }
+    }
+}
+
+impl FromStr for NodeRef {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (namespace, rest) = match s.strip_prefix("ns=") {
+            Some(rest) => {
+                let (ns, id) = rest
+                    .split_once(';')
+                    .ok_or_else(|| format!("node id `{s}` has no identifier after `ns=`"))?;
+                let ns = ns
+                    .parse()
+                    .map_err(|_| format!("node id `{s}` has an invalid namespace index"))?;
+                (ns, id)
+            }
+            None => (0, s),
+        };
+        let (kind, value) = rest
+            .split_once('=')
+            .ok_or_else(|| format!("node id `{s}` is not in `ns=<n>;<type>=<id>` form"))?;
+        if value.is_empty() {
+            return Err(format!("node id `{s}` has an empty identifier"));
+        }
+        let identifier = match kind {
+            "i" => Identifier::Numeric(
+                value
+                    .parse()
+                    .map_err(|_| format!("node id `{s}` has a non-numeric `i=` identifier"))?,
+            ),
+            "s" => Identifier::String(value.to_string()),
+            "g" => Identifier::Guid(value.to_string()),
+            "b" => Identifier::Opaque(value.to_string()),
+            _ => {
+                return Err(format!(
+                    "node id `{s}` has unknown identifier type `{kind}`"
+                ));
+            }
+        };
+        Ok(NodeRef {
+            namespace,
+            identifier,
+        })
+    }
+}
+
+impl fmt::Display for NodeRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.namespace != 0 {
+            write!(f, "ns={};", self.namespace)?;
+        }
+        match &self.identifier {
+            Identifier::Numeric(n) => write!(f, "i={n}"),
+            Identifier::String(v) | Identifier::Guid(v) | Identifier::Opaque(v) => {
+                write!(f, "{}={v}", self.identifier.kind())
+            }
+        }
+    }
+}
+
+/// The metric name for a subscribed node: its browse path when configured,
+/// otherwise `ns<n>/<type>/<identifier>`.
+pub fn node_metric(node: &NodeConfig) -> Result<String, String> {
+    if let Some(path) = &node.browse_path {
+        let metric = browse_path_metric(path);
+        if !metric.is_empty() {
+            return Ok(metric);
+        }
+    }
+    Ok(node_id_metric(&node.node_id.parse()?))
+}
+
+/// A configured node as the client subscribes to it: its node ID, parsed by
+/// the OPC-UA stack itself, and its metric. Config validation goes through
+/// this too, so a node that validates is one the client can monitor.
+pub fn resolve_node(node: &NodeConfig) -> Result<(NodeId, String), String> {
+    let node_id = NodeId::from_str(&node.node_id)
+        .map_err(|_| format!("invalid node id `{}`", node.node_id))?;
+    Ok((node_id, node_metric(node)?))
+}
+
+/// A browse path as a metric: `/`-separated, without namespace qualifiers
+/// (`2:Boiler` → `Boiler`) and with characters that aren't valid in a key
+/// segment replaced by `_`.
+pub fn browse_path_metric(path: &str) -> String {
+    path.split('/')
+        .filter(|s| !s.is_empty())
+        .map(|segment| {
+            let name = match segment.split_once(':') {
+                Some((ns, name)) if ns.chars().all(|c| c.is_ascii_digit()) => name,
+                _ => segment,
+            };
+            sanitize_segment(name)
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// A node ID as a metric, e.g. `ns=2;s=Line1.Temp` → `ns2/s/Line1.Temp`.
+pub fn node_id_metric(node: &NodeRef) -> String {
+    let id = match &node.identifier {
+        Identifier::Numeric(n) => n.to_string(),
+        Identifier::String(v) | Identifier::Guid(v) | Identifier::Opaque(v) => sanitize_segment(v),
+    };
+    format!("ns{}/{}/{}", node.namespace, node.identifier.kind(), id)
+}
+
+/// Replace `/`, whitespace and Zenoh's reserved key characters with `_`.
+fn sanitize_segment(segment: &str) -> String {
+    segment
+        .trim()
+        .chars()
+        .map(|c| match c {
+            '/' | '*' | '$' | '?' | '#' => '_',
+            c if c.is_whitespace() => '_',
+            c => c,
+        })
+        .collect()
+}
+
+/// Convert an OPC-UA value to a typed telemetry value, or `None` for an empty
+/// value.
+///
+/// Numbers become gauges: OPC-UA variables are process values, not monotonic
+/// counters. Booleans stay booleans, byte strings become bytes, and anything
+/// else is published as text.
+pub fn variant_to_value(variant: &Variant) -> Option<TelemetryValue> {
+    let value = match variant {
+        Variant::Empty => return None,
+        Variant::Boolean(b) => TelemetryValue::Boolean(*b),
+        Variant::SByte(v) => TelemetryValue::Gauge(*v as f64),
+        Variant::Byte(v) => TelemetryValue::Gauge(*v as f64),
+        Variant::Int16(v) => TelemetryValue::Gauge(*v as f64),
+        Variant::UInt16(v) => TelemetryValue::Gauge(*v as f64),
+        Variant::Int32(v) => TelemetryValue::Gauge(*v as f64),
+        Variant::UInt32(v) => TelemetryValue::Gauge(*v as f64),
+        Variant::Int64(v) => TelemetryValue::Gauge(*v as f64),
+        Variant::UInt64(v) => TelemetryValue::Gauge(*v as f64),
+        Variant::Float(v) => TelemetryValue::Gauge(*v as f64),
+        Variant::Double(v) => TelemetryValue::Gauge(*v),
+        Variant::String(s) => TelemetryValue::Text(s.as_ref().to_string()),
+        Variant::LocalizedText(t) => TelemetryValue::Text(t.text.as_ref().to_string()),
+        Variant::ByteString(b) => TelemetryValue::Bytes(b.value.clone().unwrap_or_default()),
+        Variant::Variant(inner) => return variant_to_value(inner),
+        other => TelemetryValue::Text(variant_text(other)),
+    };
+    Some(value)
+}
+
+/// Text for a value with no closer telemetry type.
+fn variant_text(variant: &Variant) -> String {
+    match variant {
+        Variant::DateTime(dt) => dt.as_chrono().to_rfc3339(),
+        Variant::Array(array) => {
+            let items: Vec<String> = array
+                .values
+                .iter()
+                .map(|v| match variant_to_value(v) {
+                    Some(TelemetryValue::Gauge(g)) => g.to_string(),
+                    Some(TelemetryValue::Boolean(b)) => b.to_string(),
+                    Some(TelemetryValue::Text(t)) => format!("{t:?}"),
+                    _ => "null".to_string(),
+                })
+                .collect();
+            format!("[{}]", items.join(","))
+        }
+        other => format!("{other:?}"),
+    }
+}
+
+/// The telemetry point for a value change on `node_id`, or `None` when the
+/// change carries a bad/uncertain status, no value, or a value that fails
+/// validation (#458).
+///
+/// The point is stamped from the server's source timestamp unless
+/// `timestamp_source` says otherwise (#406).
+pub fn data_value_point(
+    server: &str,
+    metric: &str,
+    node_id: &str,
+    data_value: &DataValue,
+    timestamp_source: TimestampSource,
+    receipt_ms: i64,
+) -> Option<TelemetryPoint> {
+    if let Some(status) = data_value.status
+        && !status.is_good()
+    {
+        return None;
+    }
+    let value = variant_to_value(data_value.value.as_ref()?)?;
+    value.validate().ok()?;
+    let source_ms = data_value
+        .source_timestamp
+        .as_ref()
+        .map(|t| t.as_chrono().timestamp_millis());
+    TelemetryPoint::builder()
+        .timestamp(timestamp_source.pick(source_ms, receipt_ms))
+        .source(server)
+        .protocol(Protocol::Opcua)
+        .metric(metric)
+        .value(value)
+        .label("node_id", node_id)
+        .build()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use opcua::types::{ByteString, DateTime, LocalizedText, StatusCode, UAString};
+
+    use super::*;
+
+    fn node(node_id: &str, browse_path: Option<&str>) -> NodeConfig {
+        NodeConfig {
+            node_id: node_id.into(),
+            browse_path: browse_path.map(Into::into),
+        }
+    }
+
+    #[test]
+    fn node_ids_parse_and_round_trip() {
+        let parsed: NodeRef = "ns=2;s=Line1.Boiler.Temp".parse().unwrap();
+        assert_eq!(parsed.namespace, 2);
+        assert_eq!(
+            parsed.identifier,
+            Identifier::String("Line1.Boiler.Temp".into())
+        );
+        assert_eq!(parsed.to_string(), "ns=2;s=Line1.Boiler.Temp");
+
+        let server_time: NodeRef = "i=2258".parse().unwrap();
+        assert_eq!(server_time.namespace, 0);
+        assert_eq!(server_time.identifier, Identifier::Numeric(2258));
+        assert_eq!(server_time.to_string(), "i=2258");
+
+        assert!("ns=x;i=1".parse::<NodeRef>().is_err());
+        assert!("ns=2;i=abc".parse::<NodeRef>().is_err());
+        assert!("ns=2;q=1".parse::<NodeRef>().is_err());
+        assert!("ns=2;s=".parse::<NodeRef>().is_err());
+        assert!("Boiler".parse::<NodeRef>().is_err());
+    }
+
+    #[test]
+    fn node_ids_map_to_key_safe_metrics() {
+        // A browse path wins, without namespace qualifiers.
+        assert_eq!(
+            node_metric(&node("ns=2;s=B.T", Some("/Objects/2:Line 1/2:Boiler/Temp"))).unwrap(),
+            "Objects/Line_1/Boiler/Temp"
+        );
+        // Otherwise the node ID, with reserved characters replaced.
+        assert_eq!(
+            node_metric(&node("ns=2;s=Line1.Boiler.Temp", None)).unwrap(),
+            "ns2/s/Line1.Boiler.Temp"
+        );
+        assert_eq!(node_metric(&node("i=2258", None)).unwrap(), "ns0/i/2258");
+        assert_eq!(
+            node_metric(&node("ns=3;s=Tank/Level#1 raw", None)).unwrap(),
+            "ns3/s/Tank_Level_1_raw"
+        );
+        // An empty browse path falls back to the node ID.
+        assert_eq!(
+            node_metric(&node("ns=1;i=7", Some("//"))).unwrap(),
+            "ns1/i/7"
+        );
+        assert!(node_metric(&node("garbage", None)).is_err());
+
+        // Mapped names pass the framework's naming rules.
+        for metric in ["Objects/Line_1/Boiler/Temp", "ns3/s/Tank_Level_1_raw"] {
+            let issues = zensight_sensor_core::lint_metric_name(metric);
+            assert!(
+                !issues.iter().any(|i| matches!(
+                    i,
+                    zensight_sensor_core::MetricNameIssue::ReservedChar(_)
+                        | zensight_sensor_core::MetricNameIssue::LeadingSlash
+                        | zensight_sensor_core::MetricNameIssue::EmptySegment
+                )),
+                "{metric}: {issues:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn recorded_values_convert_to_typed_telemetry() {
+        let cases = [
+            (Variant::Double(21.5), Some(TelemetryValue::Gauge(21.5))),
+            (Variant::Float(0.5), Some(TelemetryValue::Gauge(0.5))),
+            (Variant::Int32(-40), Some(TelemetryValue::Gauge(-40.0))),
+            (Variant::UInt16(1200), Some(TelemetryValue::Gauge(1200.0))),
+            (Variant::UInt64(7), Some(TelemetryValue::Gauge(7.0))),
+            (Variant::Boolean(true), Some(TelemetryValue::Boolean(true))),
+            (
+                Variant::String(UAString::from("RUNNING")),
+                Some(TelemetryValue::Text("RUNNING".into())),
+            ),
+            (
+                Variant::LocalizedText(Box::new(LocalizedText::new("en", "Valve open"))),
+                Some(TelemetryValue::Text("Valve open".into())),
+            ),
+            (
+                Variant::ByteString(ByteString::from(vec![0xde, 0xad])),
+                Some(TelemetryValue::Bytes(vec![0xde, 0xad])),
+            ),
+            (
+                Variant::Variant(Box::new(Variant::Byte(3))),
+                Some(TelemetryValue::Gauge(3.0)),
+            ),
+            (Variant::Empty, None),
+        ];
+        for (variant, expected) in cases {
+            assert_eq!(variant_to_value(&variant), expected, "{variant:?}");
+        }
+    }
+
+    #[test]
+    fn recorded_value_changes_become_points() {
+        let source_time =
+            DateTime::from(chrono::DateTime::from_timestamp_millis(1_700_000_000_000).unwrap());
+        let change = DataValue {
+            value: Some(Variant::Double(81.25)),
+            status: Some(StatusCode::Good),
+            source_timestamp: Some(source_time),
+            ..Default::default()
+        };
+        let point = data_value_point(
+            "line1",
+            "Boiler/Temp",
+            "ns=2;s=Boiler.Temp",
+            &change,
+            TimestampSource::Message,
+            1_700_000_005_000,
+        )
+        .unwrap();
+        assert_eq!(point.protocol, Protocol::Opcua);
+        assert_eq!(point.source, "line1");
+        assert_eq!(point.metric, "Boiler/Temp");
+        assert_eq!(point.value, TelemetryValue::Gauge(81.25));
+        assert_eq!(point.timestamp, 1_700_000_000_000);
+        assert_eq!(
+            point.labels.get("node_id").map(String::as_str),
+            Some("ns=2;s=Boiler.Temp")
+        );
+
+        // Receipt time when configured.
+        let point = data_value_point(
+            "line1",
+            "Boiler/Temp",
+            "ns=2;s=Boiler.Temp",
+            &change,
+            TimestampSource::Receipt,
+            1_700_000_005_000,
+        )
+        .unwrap();
+        assert_eq!(point.timestamp, 1_700_000_005_000);
+
+        // Bad status, no value and NaN are dropped.
+        let dropped = [
+            DataValue {
+                status: Some(StatusCode::BadSensorFailure),
+                ..change.clone()
+            },
+            DataValue {
+                value: None,
+                ..change.clone()
+            },
+            DataValue {
+                value: Some(Variant::Double(f64::NAN)),
+                ..change.clone()
+            },
+        ];
+        for data_value in &dropped {
+            assert!(
+                data_value_point("line1", "m", "i=1", data_value, TimestampSource::Message, 0)
+                    .is_none()
+            );
+        }
+    }
+}
@@ -32,6 +32,26 @@ pub struct SnmpSensorConfig {
     /// Tier-2 directory-snapshot (`@/snapshot`) limits. Disabled by default.
     #[serde(default)]
     pub snapshot: zensight_sensor_core::SnapshotLimits,
+
+    /// Zenoh congestion control / priority per key or protocol (#410).
+    #[serde(default)]
+    pub publish_qos: zensight_sensor_core::PublishQosConfig,
+
+    /// Bounded queue + timed replay for publishes that fail (#412).
+    #[serde(default)]
+    pub publish_retry: zensight_sensor_core::PublishRetryConfig,
+
+    /// Re-publish matching telemetry under alternate prefixes (#447).
+    #[serde(default)]
+    pub publish_mirror: Vec<zensight_sensor_core::MirrorRule>,
+
+    /// Attach bridge name/version/encoding to each sample (#444).
+    #[serde(default)]
+    pub attach_metadata: bool,
+
+    /// Unix socket for runtime control commands (#445); off when unset.
+    #[serde(default)]
+    pub control_socket: Option<std::path::PathBuf>,
 }
 
 /// SNMP-specific configuration.
@@ -60,6 +80,11 @@ pub struct SnmpConfig {
     /// MIB configuration.
     #[serde(default)]
     pub mib: MibConfig,
+
+    /// Most devices polled at the same time (#482); further polls queue until
+    /// one finishes. 0 means no limit.
+    #[serde(default)]
+    pub max_concurrent_polls: usize,
 }
 
 /// MIB loading configuration.
@@ -152,6 +177,15 @@ pub struct DeviceConfig {
     /// Reference to a predefined OID group.
     #[serde(default)]
     pub oid_group: Option<String>,
+
+    /// Time to wait for each PDU response, in milliseconds (#430).
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u64,
+
+    /// How many times a timed-out PDU is re-sent before the device is
+    /// marked failed for the cycle (#430). Retries back off exponentially.
+    #[serde(default)]
+    pub retries: u32,
 }
 
 fn default_community() -> String {
@@ -166,6 +200,10 @@ fn default_poll_interval() -> u64 {
     30
 }
 
+fn default_timeout_ms() -> u64 {
+    5000
+}
+
 /// SNMP protocol version.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SnmpVersion {
@@ -217,19 +255,19 @@ pub enum AuthProtocol {
     #[serde(rename = "MD5")]
     Md5,
     /// SHA-1 authentication (RFC 3414).
-    #[serde(rename = "SHA")]
+    #[serde(rename = "SHA", alias = "SHA1", alias = "SHA-1")]
     Sha1,
-    /// SHA-224 authentication (non-standard).
-    #[serde(rename = "SHA224")]
+    /// SHA-224 authentication (RFC 7860).
+    #[serde(rename = "SHA224", alias = "SHA-224")]
     Sha224,
-    /// SHA-256 authentication (non-standard).
-    #[serde(rename = "SHA256")]
+    /// SHA-256 authentication (RFC 7860).
+    #[serde(rename = "SHA256", alias = "SHA-256")]
     Sha256,
-    /// SHA-384 authentication (non-standard).
-    #[serde(rename = "SHA384")]
+    /// SHA-384 authentication (RFC 7860).
+    #[serde(rename = "SHA384", alias = "SHA-384")]
     Sha384,
-    /// SHA-512 authentication (non-standard).
-    #[serde(rename = "SHA512")]
+    /// SHA-512 authentication (RFC 7860).
+    #[serde(rename = "SHA512", alias = "SHA-512")]
     Sha512,
 }
 
@@ -244,16 +282,74 @@ pub enum PrivProtocol {
     #[serde(rename = "DES")]
     Des,
     /// AES-128 encryption (RFC 3826).
-    #[serde(rename = "AES")]
+    #[serde(rename = "AES", alias = "AES128", alias = "AES-128")]
     Aes128,
     /// AES-192 encryption (non-standard).
-    #[serde(rename = "AES192")]
+    #[serde(rename = "AES192", alias = "AES-192")]
     Aes192,
     /// AES-256 encryption (non-standard).
-    #[serde(rename = "AES256")]
+    #[serde(rename = "AES256", alias = "AES-256")]
     Aes256,
 }
 
+/// Shortest USM password accepted; RFC 3414 §11.2 requires at least 8
+/// characters for key localization.
+const MIN_USM_PASSWORD_LEN: usize = 8;
+
+/// `password`, if present and long enough; `kind` names it in the error.
+fn usm_password<'a>(kind: &str, password: &'a Option<String>) -> Result<&'a str, String> {
+    let password = password
+        .as_deref()
+        .ok_or_else(|| format!("{kind} password is required"))?;
+    if password.chars().count() < MIN_USM_PASSWORD_LEN {
+        return Err(format!(
+            "{kind} password must be at least {MIN_USM_PASSWORD_LEN} characters"
+        ));
+    }
+    Ok(password)
+}
+
+/// The USM security level and parameters a [`SnmpV3Security`] resolves to
+/// (#428): what the poller builds its session from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UsmParams<'a> {
+    NoAuthNoPriv,
+    AuthNoPriv {
+        auth: AuthProtocol,
+        auth_password: &'a str,
+    },
+    AuthPriv {
+        auth: AuthProtocol,
+        auth_password: &'a str,
+        privacy: PrivProtocol,
+        priv_password: &'a str,
+    },
+}
+
+impl SnmpV3Security {
+    /// Resolve the configured protocols into USM parameters, rejecting
+    /// combinations SNMPv3 can't express: privacy without authentication, a
+    /// protocol without its password, or a password too short to localize.
+    pub fn usm(&self) -> Result<UsmParams<'_>, String> {
+        match (self.auth_protocol, self.priv_protocol) {
+            (AuthProtocol::None, PrivProtocol::None) => Ok(UsmParams::NoAuthNoPriv),
+            (AuthProtocol::None, privacy) => Err(format!(
+                "privacy protocol {privacy:?} requires an auth_protocol (noAuthPriv is not valid in SNMPv3)"
+            )),
+            (auth, PrivProtocol::None) => Ok(UsmParams::AuthNoPriv {
+                auth,
+                auth_password: usm_password("auth", &self.auth_password)?,
+            }),
+            (auth, privacy) => Ok(UsmParams::AuthPriv {
+                auth,
+                auth_password: usm_password("auth", &self.auth_password)?,
+                privacy,
+                priv_password: usm_password("privacy", &self.priv_password)?,
+            }),
+        }
+    }
+}
+
 /// A group of OIDs that can be referenced by devices.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OidGroup {
@@ -262,8 +358,41 @@ pub struct OidGroup {
     pub oids: Vec<String>,
 
     /// OID subtrees to poll with WALK.
+    ///
+    /// Entries may be plain OID strings or `{ oid, scalar: true }` objects;
+    /// scalar entries are fetched with a single GET instead of a walk (#429).
     #[serde(default)]
-    pub walks: Vec<String>,
+    pub walks: Vec<GroupOid>,
+}
+
+/// An entry in an [`OidGroup`]'s `walks` list.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GroupOid {
+    /// A subtree to walk.
+    Subtree(String),
+    /// An OID with explicit options.
+    Entry {
+        oid: String,
+        /// The OID is a single scalar instance (e.g. `sysUpTime.0`), so a
+        /// GET suffices and no walk is issued.
+        #[serde(default)]
+        scalar: bool,
+    },
+}
+
+impl GroupOid {
+    /// The OID this entry refers to.
+    pub fn oid(&self) -> &str {
+        match self {
+            GroupOid::Subtree(oid) | GroupOid::Entry { oid, .. } => oid,
+        }
+    }
+
+    /// Whether the entry is marked scalar and should be fetched with GET.
+    pub fn is_scalar(&self) -> bool {
+        matches!(self, GroupOid::Entry { scalar: true, .. })
+    }
 }
 
 impl SnmpSensorConfig {
@@ -280,6 +409,10 @@ impl SnmpSensorConfig {
 }
 
 impl zensight_sensor_core::SensorConfig for SnmpSensorConfig {
+    fn default_config_json5() -> &'static str {
+        include_str!("../../configs/snmp.json5")
+    }
+
     fn zenoh(&self) -> &ZenohConfig {
         &self.zenoh
     }
@@ -306,13 +439,27 @@ impl zensight_sensor_core::SensorConfig for SnmpSensorConfig {
                     device.name
                 )));
             }
-            // Validate SNMPv3 security if specified
-            if device.version == SnmpVersion::V3 && device.security.is_none() {
+            if device.timeout_ms == 0 {
                 return Err(zensight_sensor_core::SensorError::config(format!(
-                    "Device '{}' uses SNMPv3 but has no security configuration",
+                    "Device '{}' has a zero timeout_ms",
                     device.name
                 )));
             }
+            // Validate SNMPv3 security if specified
+            if device.version == SnmpVersion::V3 {
+                let Some(security) = &device.security else {
+                    return Err(zensight_sensor_core::SensorError::config(format!(
+                        "Device '{}' uses SNMPv3 but has no security configuration",
+                        device.name
+                    )));
+                };
+                if let Err(e) = security.usm() {
+                    return Err(zensight_sensor_core::SensorError::config(format!(
+                        "Device '{}' has invalid SNMPv3 security: {e}",
+                        device.name
+                    )));
+                }
+            }
         }
         Ok(())
     }
@@ -324,6 +471,26 @@ impl zensight_sensor_core::SensorConfig for SnmpSensorConfig {
     fn snapshot_limits(&self) -> zensight_sensor_core::SnapshotLimits {
         self.snapshot.clone()
     }
+
+    fn publish_qos(&self) -> zensight_sensor_core::PublishQosConfig {
+        self.publish_qos.clone()
+    }
+
+    fn publish_retry(&self) -> zensight_sensor_core::PublishRetryConfig {
+        self.publish_retry.clone()
+    }
+
+    fn publish_mirror(&self) -> Vec<zensight_sensor_core::MirrorRule> {
+        self.publish_mirror.clone()
+    }
+
+    fn attach_metadata(&self) -> bool {
+        self.attach_metadata
+    }
+
+    fn control_socket(&self) -> Option<std::path::PathBuf> {
+        self.control_socket.clone()
+    }
 }
 
 impl DeviceConfig {
@@ -335,6 +502,13 @@ impl DeviceConfig {
             && let Some(group) = groups.get(group_name)
         {
             oids.extend(group.oids.clone());
+            oids.extend(
+                group
+                    .walks
+                    .iter()
+                    .filter(|entry| entry.is_scalar())
+                    .map(|entry| entry.oid().to_string()),
+            );
         }
 
         oids
@@ -347,7 +521,13 @@ impl DeviceConfig {
         if let Some(group_name) = &self.oid_group
             && let Some(group) = groups.get(group_name)
         {
-            walks.extend(group.walks.clone());
+            walks.extend(
+                group
+                    .walks
+                    .iter()
+                    .filter(|entry| !entry.is_scalar())
+                    .map(|entry| entry.oid().to_string()),
+            );
         }
 
         walks
@@ -400,6 +580,8 @@ mod tests {
         assert_eq!(config.snmp.devices.len(), 1);
         assert_eq!(config.snmp.devices[0].name, "router01");
         assert_eq!(config.snmp.devices[0].version, SnmpVersion::V2c);
+        assert_eq!(config.snmp.devices[0].timeout_ms, 5000);
+        assert_eq!(config.snmp.devices[0].retries, 0);
         assert_eq!(config.snmp.oid_groups.len(), 1);
         assert!(config.snmp.oid_groups.contains_key("system_info"));
     }
@@ -411,7 +593,7 @@ mod tests {
             "system_info".to_string(),
             OidGroup {
                 oids: vec!["1.3.6.1.2.1.1.1.0".to_string()],
-                walks: vec!["1.3.6.1.2.1.2.2.1".to_string()],
+                walks: vec![GroupOid::Subtree("1.3.6.1.2.1.2.2.1".to_string())],
             },
         );
 
@@ -425,6 +607,8 @@ mod tests {
             oids: vec!["1.3.6.1.2.1.1.3.0".to_string()],
             walks: vec![],
             oid_group: Some("system_info".to_string()),
+            timeout_ms: 5000,
+            retries: 0,
         };
 
         let all_oids = device.all_oids(&groups);
@@ -434,6 +618,40 @@ mod tests {
         assert_eq!(all_walks.len(), 1);
     }
 
+    #[test]
+    fn scalar_group_entries_are_fetched_with_get() {
+        let json5 = r#"
+        {
+            zenoh: { mode: "peer" },
+            snmp: {
+                devices: [
+                    { name: "r1", address: "10.0.0.1:161", oid_group: "mixed" },
+                ],
+                oid_groups: {
+                    mixed: {
+                        walks: [
+                            "1.3.6.1.2.1.2.2.1",
+                            { oid: "1.3.6.1.2.1.1.3.0", scalar: true },
+                            { oid: "1.3.6.1.2.1.31.1.1.1" },
+                        ],
+                    },
+                },
+            },
+        }
+        "#;
+
+        let config = SnmpSensorConfig::parse(json5).unwrap();
+        let device = &config.snmp.devices[0];
+        let groups = &config.snmp.oid_groups;
+
+        // The scalar sysUpTime.0 becomes a GET; the tables are still walked.
+        assert_eq!(device.all_oids(groups), vec!["1.3.6.1.2.1.1.3.0"]);
+        assert_eq!(
+            device.all_walks(groups),
+            vec!["1.3.6.1.2.1.2.2.1", "1.3.6.1.2.1.31.1.1.1"]
+        );
+    }
+
     #[test]
     fn test_parse_snmpv3_config() {
         let json5 = r#"
@@ -475,6 +693,113 @@ mod tests {
         assert_eq!(security.priv_password, Some("privpass456".to_string()));
     }
 
+    #[test]
+    fn usm_params_per_security_level() {
+        let security = |auth, auth_password: Option<&str>, privacy, priv_password: Option<&str>| {
+            SnmpV3Security {
+                username: "admin".to_string(),
+                auth_protocol: auth,
+                auth_password: auth_password.map(str::to_string),
+                priv_protocol: privacy,
+                priv_password: priv_password.map(str::to_string),
+                engine_id: None,
+            }
+        };
+
+        let none = security(AuthProtocol::None, None, PrivProtocol::None, None);
+        assert_eq!(none.usm(), Ok(UsmParams::NoAuthNoPriv));
+
+        for auth in [
+            AuthProtocol::Md5,
+            AuthProtocol::Sha1,
+            AuthProtocol::Sha224,
+            AuthProtocol::Sha256,
+            AuthProtocol::Sha384,
+            AuthProtocol::Sha512,
+        ] {
+            let s = security(auth, Some("authpass123"), PrivProtocol::None, None);
+            assert_eq!(
+                s.usm(),
+                Ok(UsmParams::AuthNoPriv {
+                    auth,
+                    auth_password: "authpass123"
+                })
+            );
+            for privacy in [
+                PrivProtocol::Des,
+                PrivProtocol::Aes128,
+                PrivProtocol::Aes192,
+                PrivProtocol::Aes256,
+            ] {
+                let s = security(auth, Some("authpass123"), privacy, Some("privpass456"));
+                assert_eq!(
+                    s.usm(),
+                    Ok(UsmParams::AuthPriv {
+                        auth,
+                        auth_password: "authpass123",
+                        privacy,
+                        priv_password: "privpass456"
+                    })
+                );
+            }
+        }
+
+        // Invalid combinations.
+        let no_auth_priv = security(
+            AuthProtocol::None,
+            None,
+            PrivProtocol::Aes256,
+            Some("privpass456"),
+        );
+        assert!(
+            no_auth_priv
+                .usm()
+                .unwrap_err()
+                .contains("requires an auth_protocol")
+        );
+        let missing = security(
+            AuthProtocol::Sha256,
+            Some("authpass123"),
+            PrivProtocol::Aes128,
+            None,
+        );
+        assert!(
+            missing
+                .usm()
+                .unwrap_err()
+                .contains("privacy password is required")
+        );
+        let short = security(AuthProtocol::Md5, Some("short"), PrivProtocol::None, None);
+        assert!(short.usm().unwrap_err().contains("at least 8"));
+    }
+
+    #[test]
+    fn snmpv3_protocol_aliases_and_load_validation() {
+        use zensight_sensor_core::SensorConfig;
+
+        let config = |auth: &str, privacy: &str| {
+            format!(
+                r#"{{
+                zenoh: {{ mode: "peer" }},
+                snmp: {{ devices: [{{
+                    name: "r1", address: "10.0.0.1:161", version: "v3",
+                    security: {{ username: "u", auth_protocol: "{auth}", auth_password: "authpass123",
+                                 priv_protocol: "{privacy}", priv_password: "privpass456" }},
+                }}] }},
+            }}"#
+            )
+        };
+        let parsed = SnmpSensorConfig::parse(&config("SHA-384", "AES-256")).unwrap();
+        let security = parsed.snmp.devices[0].security.as_ref().unwrap();
+        assert_eq!(security.auth_protocol, AuthProtocol::Sha384);
+        assert_eq!(security.priv_protocol, PrivProtocol::Aes256);
+        assert!(parsed.validate().is_ok());
+
+        let invalid = SnmpSensorConfig::parse(&config("none", "AES-128")).unwrap();
+        let err = invalid.validate().unwrap_err().to_string();
+        assert!(err.contains("'r1' has invalid SNMPv3 security"), "{err}");
+    }
+
     #[test]
     fn test_snmpv3_noauth_config() {
         let json5 = r#"
@@ -506,4 +831,12 @@ mod tests {
         assert_eq!(security.auth_protocol, AuthProtocol::None);
         assert_eq!(security.priv_protocol, PrivProtocol::None);
     }
+
+    #[test]
+    fn printed_default_config_documents_every_field() {
+        assert_eq!(
+            zensight_sensor_core::undocumented_fields::<SnmpSensorConfig>().unwrap(),
+            Vec::<String>::new()
+        );
+    }
 }
@@ -0,0 +1,228 @@
+//! Interface up/down event synthesis from polled `ifOperStatus` (#431).
+//!
+//! SNMP agents only expose the current oper-status of each interface. The
+//! [`LinkTracker`] remembers the last value per `ifIndex` so the poller can
+//! publish a discrete `link_up` / `link_down` event on each transition, in
+//! addition to the status gauge itself.
+//!
+//! It also notices interfaces that appear after the first poll (a new VLAN, a
+//! hot-plugged line card) so the poller can announce them as discoveries
+//! (#436).
+
+use std::collections::HashMap;
+
+use zensight_common::TelemetryValue;
+
+/// IF-MIB `ifOperStatus` column (`ifEntry.8`).
+const IF_OPER_STATUS: &str = "1.3.6.1.2.1.2.2.1.8.";
+/// IF-MIB `ifDescr` column (`ifEntry.2`).
+const IF_DESCR: &str = "1.3.6.1.2.1.2.2.1.2.";
+/// IF-MIB `ifName` column (`ifXEntry.1`).
+const IF_NAME: &str = "1.3.6.1.2.1.31.1.1.1.1.";
+
+/// `ifOperStatus` value for `up(1)`.
+const OPER_UP: f64 = 1.0;
+
+/// A link transition detected between two polls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkEvent {
+    /// The interface's `ifIndex`.
+    pub if_index: u32,
+    /// The interface's `ifName` (or `ifDescr`), when it has been polled.
+    pub interface: Option<String>,
+    /// Whether the link came up (`true`) or went down.
+    pub up: bool,
+}
+
+impl LinkEvent {
+    /// Metric name the event is published under.
+    pub fn metric(&self) -> String {
+        format!("if/{}/link_event", self.if_index)
+    }
+
+    /// The event value: `link_up` or `link_down`.
+    pub fn value(&self) -> TelemetryValue {
+        TelemetryValue::Text(if self.up { "link_up" } else { "link_down" }.to_string())
+    }
+}
+
+/// Tracks per-interface oper-status across polls of one device.
+#[derive(Debug, Default)]
+pub struct LinkTracker {
+    /// Last seen "is up" per `ifIndex`.
+    status: HashMap<u32, bool>,
+    /// Interface labels learned from `ifName` / `ifDescr`.
+    names: HashMap<u32, String>,
+    /// Whether a poll has completed, so later first-seen interfaces are new
+    /// rather than part of the initial inventory.
+    baseline: bool,
+    /// Interfaces first seen during the current poll (after the baseline).
+    appeared: Vec<u32>,
+}
+
+/// An interface that appeared between polls (#436).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewInterface {
+    /// The interface's `ifIndex`.
+    pub if_index: u32,
+    /// The interface's `ifName` (or `ifDescr`), when it has been polled.
+    pub interface: Option<String>,
+}
+
+impl NewInterface {
+    /// The discovered item, as in the interface's metric names (`if/<idx>`).
+    pub fn item(&self) -> String {
+        format!("if/{}", self.if_index)
+    }
+}
+
+impl LinkTracker {
+    /// Observe a polled varbind. Returns an event when it is an
+    /// `ifOperStatus` that changed between up and not-up since the last poll;
+    /// the first observation of an interface only establishes its state.
+    pub fn observe(&mut self, oid: &str, value: &TelemetryValue) -> Option<LinkEvent> {
+        if let Some(if_index) = column_index(oid, IF_NAME) {
+            if let TelemetryValue::Text(name) = value {
+                self.names.insert(if_index, name.clone());
+            }
+            return None;
+        }
+        if let Some(if_index) = column_index(oid, IF_DESCR) {
+            // ifName is the better label; only fall back to ifDescr.
+            if let TelemetryValue::Text(descr) = value {
+                self.names.entry(if_index).or_insert_with(|| descr.clone());
+            }
+            return None;
+        }
+
+        let if_index = column_index(oid, IF_OPER_STATUS)?;
+        let up = match value {
+            TelemetryValue::Gauge(status) => *status == OPER_UP,
+            _ => return None,
+        };
+
+        match self.status.insert(if_index, up) {
+            Some(was_up) if was_up != up => Some(LinkEvent {
+                if_index,
+                interface: self.names.get(&if_index).cloned(),
+                up,
+            }),
+            Some(_) => None,
+            None => {
+                if self.baseline {
+                    self.appeared.push(if_index);
+                }
+                None
+            }
+        }
+    }
+
+    /// End a successful poll: returns the interfaces that appeared during it.
+    /// The first poll only establishes the inventory and returns nothing.
+    pub fn finish_poll(&mut self) -> Vec<NewInterface> {
+        self.baseline = true;
+        std::mem::take(&mut self.appeared)
+            .into_iter()
+            .map(|if_index| NewInterface {
+                if_index,
+                interface: self.names.get(&if_index).cloned(),
+            })
+            .collect()
+    }
+}
+
+/// The `ifIndex` of a column instance OID, if it belongs to `column`.
+fn column_index(oid: &str, column: &str) -> Option<u32> {
+    oid.strip_prefix(column)?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn oper_status(if_index: u32) -> String {
+        format!("{IF_OPER_STATUS}{if_index}")
+    }
+
+    #[test]
+    fn status_change_between_polls_emits_one_event() {
+        let mut tracker = LinkTracker::default();
+        let up = TelemetryValue::Gauge(1.0);
+        let down = TelemetryValue::Gauge(2.0);
+
+        // The label is learned from ifName.
+        let name = TelemetryValue::Text("eth0".to_string());
+        assert_eq!(tracker.observe(&format!("{IF_NAME}3"), &name), None);
+
+        // First poll only establishes state.
+        assert_eq!(tracker.observe(&oper_status(3), &up), None);
+        assert_eq!(tracker.observe(&oper_status(4), &up), None);
+
+        // Second poll: ifIndex 3 went down, 4 is unchanged.
+        let event = tracker.observe(&oper_status(3), &down);
+        assert_eq!(
+            event,
+            Some(LinkEvent {
+                if_index: 3,
+                interface: Some("eth0".to_string()),
+                up: false,
+            })
+        );
+        assert_eq!(
+            event.unwrap().value(),
+            TelemetryValue::Text("link_down".to_string())
+        );
+        assert_eq!(tracker.observe(&oper_status(4), &up), None);
+
+        // Still down on the next poll: no repeat.
+        assert_eq!(tracker.observe(&oper_status(3), &down), None);
+
+        // Back up.
+        let event = tracker.observe(&oper_status(3), &up).unwrap();
+        assert!(event.up);
+        assert_eq!(event.metric(), "if/3/link_event");
+    }
+
+    #[test]
+    fn interface_appearing_after_first_poll_is_reported_once() {
+        let mut tracker = LinkTracker::default();
+        let up = TelemetryValue::Gauge(1.0);
+
+        // Initial inventory: not a discovery.
+        tracker.observe(&oper_status(1), &up);
+        assert!(tracker.finish_poll().is_empty());
+
+        // Next poll: ifIndex 7 is new, with its name walked before the
+        // poll ends.
+        tracker.observe(&oper_status(1), &up);
+        tracker.observe(&oper_status(7), &TelemetryValue::Gauge(2.0));
+        let name = TelemetryValue::Text("vlan100".to_string());
+        tracker.observe(&format!("{IF_NAME}7"), &name);
+        let found = tracker.finish_poll();
+        assert_eq!(
+            found,
+            vec![NewInterface {
+                if_index: 7,
+                interface: Some("vlan100".to_string()),
+            }]
+        );
+        assert_eq!(found[0].item(), "if/7");
+
+        // Still there on the following poll: not reported again.
+        tracker.observe(&oper_status(1), &up);
+        tracker.observe(&oper_status(7), &up);
+        assert!(tracker.finish_poll().is_empty());
+    }
+
+    #[test]
+    fn unrelated_oids_are_ignored() {
+        let mut tracker = LinkTracker::default();
+        let value = TelemetryValue::Gauge(2.0);
+        assert_eq!(tracker.observe("1.3.6.1.2.1.2.2.1.7.3", &value), None);
+        assert_eq!(
+            tracker.observe("1.3.6.1.2.1.2.2.1.7.3", &TelemetryValue::Gauge(1.0)),
+            None
+        );
+        assert!(tracker.status.is_empty());
+    }
+}
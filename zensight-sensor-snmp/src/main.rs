@@ -3,6 +3,7 @@
 //! This sensor polls SNMP devices and publishes telemetry to Zenoh.
 
 mod config;
+mod link;
 mod mib;
 mod oid;
 mod poller;
@@ -11,7 +12,7 @@ mod trap;
 use std::sync::Arc;
 
 use anyhow::Result;
-use zensight_sensor_core::{SensorArgs, SensorRunner};
+use zensight_sensor_core::{SensorArgs, SensorConfig, SensorRunner};
 
 use crate::config::SnmpSensorConfig;
 use crate::mib::MibResolver;
@@ -22,6 +23,10 @@ use crate::trap::TrapReceiver;
 async fn main() -> Result<()> {
     // Parse CLI arguments
     let args = SensorArgs::parse_with_default("snmp.json5");
+    if args.print_default_config {
+        print!("{}", SnmpSensorConfig::default_config_json5());
+        return Ok(());
+    }
 
     // Load configuration using the framework's SensorConfig trait
     let config = SnmpSensorConfig::load(&args.config).map_err(|e| anyhow::anyhow!("{}", e))?;
@@ -48,15 +53,17 @@ async fn main() -> Result<()> {
         runner.health(),
     ));
     // Tier-2 directory snapshots (`@/snapshot`). No-op unless `snapshot.enabled`.
-    let mut runner = runner.with_report(report_source).with_snapshot(report_host);
-
-    // Get session for setting up pollers
-    let session = runner.session().clone();
+    let runner = runner.with_report(report_source).with_snapshot(report_host);
 
     // Clone config data we need before spawning tasks
     let snmp_config = runner.config().snmp.clone();
     let serialization = runner.config().serialization;
 
+    // Pollers and the trap receiver publish through the runner's publisher so
+    // sample metadata, mirrors and retry apply to them (#444)
+    let mut runner = runner.with_format(serialization);
+    let publisher = runner.publisher();
+
     // Initialize MIB resolver
     let mut mib_resolver = MibResolver::new();
 
@@ -92,15 +99,25 @@ async fn main() -> Result<()> {
     let mib_resolver = Arc::new(mib_resolver);
 
     // Spawn device pollers
+    let health = runner.health();
+    health.set_devices_total(snmp_config.devices.len() as u64);
+    let poll_limit = (snmp_config.max_concurrent_polls > 0).then(|| {
+        Arc::new(tokio::sync::Semaphore::new(
+            snmp_config.max_concurrent_polls,
+        ))
+    });
     for device in snmp_config.devices.clone() {
         let mut poller = SnmpPoller::new(
             device.clone(),
-            session.clone(),
+            publisher.clone(),
             &snmp_config.key_prefix,
             mib_resolver.clone(),
             &snmp_config.oid_groups,
-            serialization,
-        );
+        )
+        .with_health(health.clone());
+        if let Some(limit) = &poll_limit {
+            poller = poller.with_poll_limit(limit.clone());
+        }
 
         // Initialize poller (required for SNMPv3 to discover engine ID)
         if let Err(e) = poller.init().await {
@@ -112,8 +129,13 @@ async fn main() -> Result<()> {
             continue;
         }
 
-        runner.spawn(async move {
-            poller.run().await;
+        // Poll on the runner's periodic loop (#460), which stops on shutdown.
+        poller.start();
+        let interval = poller.interval();
+        let poller = Arc::new(poller);
+        runner.spawn_periodic(interval, move || {
+            let poller = poller.clone();
+            async move { poller.poll().await }
         });
     }
 
@@ -121,14 +143,15 @@ async fn main() -> Result<()> {
     if snmp_config.trap_listener.enabled {
         let trap_receiver = TrapReceiver::new(
             &snmp_config.trap_listener.bind,
-            session.clone(),
+            publisher.clone(),
             &snmp_config.key_prefix,
             mib_resolver.clone(),
-            serialization,
-        );
+        )
+        .with_health(runner.health());
 
-        runner.spawn(async move {
-            if let Err(e) = trap_receiver.run().await {
+        let shutdown = runner.shutdown_signal();
+        runner.spawn_draining(async move {
+            if let Err(e) = trap_receiver.run(shutdown).await {
                 tracing::error!(error = %e, "Trap receiver failed");
             }
         });
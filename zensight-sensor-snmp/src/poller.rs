@@ -1,44 +1,54 @@
 use std::collections::HashMap;
+use std::future::Future;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result, anyhow};
-use snmp2::{AsyncSession, Value, v3};
-use tokio::sync::Mutex;
-use tokio::time::{interval, timeout};
-use zenoh::Session as ZenohSession;
+use snmp2::{AsyncSession, Oid, Value, v3};
+use tokio::sync::{Mutex, Semaphore};
+use tokio::time::{sleep, timeout};
 
-use zensight_common::{Format, KeyExprBuilder, Protocol, TelemetryPoint, TelemetryValue, encode};
+use zensight_common::{
+    DiscoveryEvent, DiscoveryKind, KeyExprBuilder, Protocol, TelemetryPoint, TelemetryValue,
+};
+use zensight_sensor_core::{Publisher, SensorHealth};
 
 use crate::config::{
-    AuthProtocol, DeviceConfig, OidGroup, PrivProtocol, SnmpV3Security, SnmpVersion,
+    AuthProtocol, DeviceConfig, OidGroup, PrivProtocol, SnmpV3Security, SnmpVersion, UsmParams,
 };
+use crate::link::LinkTracker;
 use crate::mib::MibResolver;
 use crate::oid::{oid_starts_with, oid_to_string, parse_oid};
 
 /// SNMP poller for a single device.
 pub struct SnmpPoller {
     device: DeviceConfig,
-    zenoh: Arc<ZenohSession>,
+    /// The runner's publisher, so sample metadata, mirrors and retry apply
+    /// (#444).
+    publisher: Publisher,
     key_builder: KeyExprBuilder,
     mib_resolver: Arc<MibResolver>,
-    format: Format,
     oids: Vec<String>,
     walks: Vec<String>,
-    request_timeout: Duration,
+    retry: RetryPolicy,
+    health: Arc<SensorHealth>,
+    /// Per-interface oper-status, for link up/down events.
+    links: std::sync::Mutex<LinkTracker>,
     /// Persistent session for SNMPv3 (to maintain engine ID and time sync).
     v3_session: Option<Mutex<AsyncSession>>,
+    /// Sensor-wide cap on devices polling at once (#482), shared by every
+    /// poller; `None` polls whenever the interval fires.
+    poll_limit: Option<Arc<Semaphore>>,
 }
 
 impl SnmpPoller {
     /// Create a new poller for a device.
     pub fn new(
         device: DeviceConfig,
-        zenoh: Arc<ZenohSession>,
+        publisher: Publisher,
         key_prefix: &str,
         mib_resolver: Arc<MibResolver>,
         oid_groups: &HashMap<String, OidGroup>,
-        format: Format,
     ) -> Self {
         let key_builder = KeyExprBuilder::with_prefix(key_prefix, Protocol::Snmp);
 
@@ -46,18 +56,37 @@ impl SnmpPoller {
         let walks = device.all_walks(oid_groups);
 
         Self {
-            device,
-            zenoh,
+            publisher,
             key_builder,
             mib_resolver,
-            format,
             oids,
             walks,
-            request_timeout: Duration::from_secs(5),
+            retry: RetryPolicy {
+                timeout: Duration::from_millis(device.timeout_ms),
+                retries: device.retries,
+            },
+            health: Arc::new(SensorHealth::new("snmp")),
+            device,
+            links: std::sync::Mutex::new(LinkTracker::default()),
             v3_session: None,
+            poll_limit: None,
         }
     }
 
+    /// Use the runner's shared health tracker (so device liveness reaches
+    /// `@/health` and liveliness tokens).
+    pub fn with_health(mut self, health: Arc<SensorHealth>) -> Self {
+        self.health = health;
+        self
+    }
+
+    /// Queue poll cycles behind `limit`, shared with the other pollers, so at
+    /// most its permit count of devices poll at once (#482).
+    pub fn with_poll_limit(mut self, limit: Arc<Semaphore>) -> Self {
+        self.poll_limit = Some(limit);
+        self
+    }
+
     /// Initialize the poller (required for SNMPv3 to discover engine ID).
     pub async fn init(&mut self) -> Result<()> {
         if self.device.version == SnmpVersion::V3 {
@@ -71,11 +100,16 @@ impl SnmpPoller {
         Ok(())
     }
 
-    /// Run the polling loop.
-    pub async fn run(self) {
-        let poll_interval = Duration::from_secs(self.device.poll_interval_secs);
-        let mut ticker = interval(poll_interval);
+    /// The configured poll interval.
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(self.device.poll_interval_secs)
+    }
 
+    /// Log the start of polling. Call once before the first
+    /// [`poll`](Self::poll); the runner's periodic loop (#460) drives the
+    /// rest, and a cycle that overruns delays the next one instead of
+    /// bunching them.
+    pub fn start(&self) {
         tracing::info!(
             device = %self.device.name,
             address = %self.device.address,
@@ -84,31 +118,93 @@ impl SnmpPoller {
             walks = self.walks.len(),
             "Starting SNMP poller"
         );
+    }
 
-        loop {
-            ticker.tick().await;
+    /// Poll once, waiting for a slot under the shared concurrency limit.
+    pub async fn poll(&self) {
+        if let Err(e) = limited(self.poll_limit.as_deref(), self.poll_once()).await {
+            tracing::warn!(
+                device = %self.device.name,
+                error = %e,
+                "SNMP poll failed"
+            );
+        }
+    }
 
-            if let Err(e) = self.poll_once().await {
-                tracing::warn!(
-                    device = %self.device.name,
-                    error = %e,
-                    "SNMP poll failed"
-                );
+    /// Perform a single poll cycle and record the device's health.
+    ///
+    /// A device that exhausts its retry budget aborts the cycle, since the
+    /// rest of its OIDs would only time out too, and is recorded as failed
+    /// so its liveliness reflects it (#430).
+    async fn poll_once(&self) -> Result<()> {
+        let started = Instant::now();
+        let result = self.poll_requests().await;
+        self.health
+            .record_poll_duration(started.elapsed().as_millis() as u64);
+
+        match &result {
+            Ok(()) => {
+                self.health
+                    .record_device_success_async(&self.device.name)
+                    .await;
+                self.announce_new_interfaces().await;
+            }
+            Err(e) => {
+                self.health
+                    .record_device_failure_async(&self.device.name, &e.to_string())
+                    .await
             }
         }
+        if let Err(e) = self.health.publish_device_liveness(&self.device.name).await {
+            tracing::debug!(device = %self.device.name, error = %e, "Failed to publish device liveness");
+        }
+
+        result
     }
 
-    /// Perform a single poll cycle.
-    async fn poll_once(&self) -> Result<()> {
+    /// Publish a discovery event for each interface that appeared in the poll
+    /// just completed (#436).
+    async fn announce_new_interfaces(&self) {
+        let events = {
+            let mut links = self.links.lock().unwrap_or_else(|e| e.into_inner());
+            new_interface_events(&mut links, &self.health, &self.device.name)
+        };
+        for event in events {
+            tracing::info!(
+                device = %self.device.name,
+                item = ?event.item,
+                interface = ?event.label,
+                "New interface discovered"
+            );
+            if let Err(e) = self.health.publish_discovery(&event).await {
+                tracing::debug!(device = %self.device.name, error = %e, "Failed to publish discovery");
+            }
+        }
+    }
+
+    /// Issue every GET and WALK for this device.
+    async fn poll_requests(&self) -> Result<()> {
+        // SNMPv3 keeps its session (engine ID and time sync); v1/v2c open one
+        // per cycle.
+        let cycle_session;
+        let session = match &self.v3_session {
+            Some(session) => session,
+            None => {
+                cycle_session = Mutex::new(self.create_session().await?);
+                &cycle_session
+            }
+        };
+
         // Poll individual OIDs with GET
         for oid_str in &self.oids {
-            match self.snmp_get(oid_str).await {
+            match self.snmp_get(session, oid_str).await {
                 Ok(Some((oid, value))) => {
                     self.publish(&oid, value).await;
                 }
                 Ok(None) => {
                     tracing::debug!(device = %self.device.name, oid = %oid_str, "No value returned");
                 }
+                Err(e) if e.is::<RetriesExhausted>() => return Err(e),
                 Err(e) => {
                     tracing::warn!(device = %self.device.name, oid = %oid_str, error = %e, "GET failed");
                 }
@@ -117,12 +213,13 @@ impl SnmpPoller {
 
         // Walk OID subtrees with GETNEXT
         for subtree in &self.walks {
-            match self.snmp_walk(subtree).await {
+            match self.snmp_walk(session, subtree).await {
                 Ok(entries) => {
                     for (oid, value) in entries {
                         self.publish(&oid, value).await;
                     }
                 }
+                Err(e) if e.is::<RetriesExhausted>() => return Err(e),
                 Err(e) => {
                     tracing::warn!(device = %self.device.name, subtree = %subtree, error = %e, "WALK failed");
                 }
@@ -175,147 +272,237 @@ impl SnmpPoller {
     }
 
     /// Perform an SNMP GET operation.
-    async fn snmp_get(&self, oid_str: &str) -> Result<Option<(String, TelemetryValue)>> {
+    async fn snmp_get(
+        &self,
+        session: &Mutex<AsyncSession>,
+        oid_str: &str,
+    ) -> Result<Option<(String, TelemetryValue)>> {
         let oid = parse_oid(oid_str)?;
+        let varbind = self.request(session, Request::Get, &oid).await?;
+        Ok(varbind.and_then(|varbind| Some((oid_to_string(&varbind.oid), varbind.value?))))
+    }
 
-        if self.device.version == SnmpVersion::V3 {
-            // Use persistent v3 session
-            let session_mutex = self
-                .v3_session
-                .as_ref()
-                .ok_or_else(|| anyhow!("SNMPv3 session not initialized"))?;
-            let mut session = session_mutex.lock().await;
-            let mut response = timeout(self.request_timeout, session.get(&oid))
-                .await
-                .map_err(|_| anyhow!("SNMP GET timeout"))?
-                .context("SNMP GET error")?;
+    /// Perform an SNMP WALK operation (using GETNEXT).
+    async fn snmp_walk(
+        &self,
+        session: &Mutex<AsyncSession>,
+        subtree_str: &str,
+    ) -> Result<Vec<(String, TelemetryValue)>> {
+        let subtree = parse_oid(subtree_str)?;
+        let mut results = Vec::new();
+        let mut current_oid = subtree.clone();
 
-            if let Some((resp_oid, value)) = response.varbinds.next() {
-                let oid_string = oid_to_string(&resp_oid);
-                if let Some(tv) = snmp_value_to_telemetry(&value) {
-                    return Ok(Some((oid_string, tv)));
-                }
+        while let Some(varbind) = self
+            .request(session, Request::GetNext, &current_oid)
+            .await?
+        {
+            if !oid_starts_with(&varbind.oid, &subtree) || varbind.end_of_view {
+                break;
             }
-        } else {
-            let mut session = self.create_session().await?;
-            let mut response = timeout(self.request_timeout, session.get(&oid))
-                .await
-                .map_err(|_| anyhow!("SNMP GET timeout"))?
-                .context("SNMP GET error")?;
 
-            if let Some((resp_oid, value)) = response.varbinds.next() {
-                let oid_string = oid_to_string(&resp_oid);
-                if let Some(tv) = snmp_value_to_telemetry(&value) {
-                    return Ok(Some((oid_string, tv)));
-                }
+            if let Some(tv) = varbind.value {
+                results.push((oid_to_string(&varbind.oid), tv));
             }
+
+            current_oid = varbind.oid;
         }
 
-        Ok(None)
+        Ok(results)
     }
 
-    /// Perform an SNMP WALK operation (using GETNEXT).
-    async fn snmp_walk(&self, subtree_str: &str) -> Result<Vec<(String, TelemetryValue)>> {
-        let subtree = parse_oid(subtree_str)?;
-        let mut results = Vec::new();
-        let mut current_oid = subtree.clone();
-
-        if self.device.version == SnmpVersion::V3 {
-            // Use persistent v3 session
-            let session_mutex = self
-                .v3_session
-                .as_ref()
-                .ok_or_else(|| anyhow!("SNMPv3 session not initialized"))?;
-            let mut session = session_mutex.lock().await;
-
-            loop {
-                let mut response = timeout(self.request_timeout, session.getnext(&current_oid))
-                    .await
-                    .map_err(|_| anyhow!("SNMP GETNEXT timeout"))?
-                    .context("SNMP GETNEXT error")?;
+    /// Send one PDU, re-sending it on timeout within the device's retry
+    /// budget.
+    async fn request(
+        &self,
+        session: &Mutex<AsyncSession>,
+        request: Request,
+        oid: &Oid<'_>,
+    ) -> Result<Option<Varbind>> {
+        with_retries(self.retry, request.name(), move || async move {
+            let mut session = session.lock().await;
+            let mut response = match request {
+                Request::Get => session.get(oid).await,
+                Request::GetNext => session.getnext(oid).await,
+            }
+            .with_context(|| format!("SNMP {} error", request.name()))?;
+
+            Ok(response.varbinds.next().map(|(oid, value)| Varbind {
+                oid: oid.to_owned(),
+                value: snmp_value_to_telemetry(&value),
+                end_of_view: matches!(value, Value::EndOfMibView),
+            }))
+        })
+        .await
+    }
 
-                let Some((resp_oid, value)) = response.varbinds.next() else {
-                    break;
-                };
+    /// Publish a telemetry point to Zenoh, plus a link event when it is an
+    /// `ifOperStatus` transition (#431).
+    async fn publish(&self, oid_str: &str, value: TelemetryValue) {
+        let link_event = self
+            .links
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .observe(oid_str, &value);
 
-                if !oid_starts_with(&resp_oid, &subtree) {
-                    break;
-                }
+        let metric_name = self.mib_resolver.resolve(oid_str);
+        let point = TelemetryPoint::new(&self.device.name, Protocol::Snmp, &metric_name, value)
+            .with_label("oid", oid_str);
+        self.put(&metric_name, &point).await;
 
-                if matches!(value, Value::EndOfMibView) {
-                    break;
-                }
+        if let Some(event) = link_event {
+            tracing::info!(
+                device = %self.device.name,
+                if_index = event.if_index,
+                up = event.up,
+                "Interface link transition"
+            );
+            let metric_name = event.metric();
+            let mut point = TelemetryPoint::new(
+                &self.device.name,
+                Protocol::Snmp,
+                &metric_name,
+                event.value(),
+            )
+            .with_label("oid", oid_str)
+            .with_label("ifIndex", event.if_index.to_string());
+            if let Some(interface) = event.interface {
+                point = point.with_label("interface", interface);
+            }
+            self.put(&metric_name, &point).await;
+        }
+    }
 
-                let oid_string = oid_to_string(&resp_oid);
-                if let Some(tv) = snmp_value_to_telemetry(&value) {
-                    results.push((oid_string, tv));
-                }
+    /// Publish a point under this device's key for `metric_name`.
+    async fn put(&self, metric_name: &str, point: &TelemetryPoint) {
+        let key = self.key_builder.build(&self.device.name, metric_name);
 
-                current_oid = resp_oid.to_owned();
-            }
+        if let Err(e) = self.publisher.publish_to_key(&key, point).await {
+            tracing::error!(key = %key, error = %e, "Failed to publish to Zenoh");
         } else {
-            let mut session = self.create_session().await?;
+            tracing::trace!(key = %key, "Published telemetry");
+        }
+    }
+}
 
-            loop {
-                let mut response = timeout(self.request_timeout, session.getnext(&current_oid))
-                    .await
-                    .map_err(|_| anyhow!("SNMP GETNEXT timeout"))?
-                    .context("SNMP GETNEXT error")?;
+/// Delay before the first retry; doubled for each further one.
+const RETRY_BACKOFF_BASE: Duration = Duration::from_millis(100);
+/// Upper bound on the delay between retries.
+const RETRY_BACKOFF_MAX: Duration = Duration::from_secs(5);
+
+/// Run `poll` once a permit of `limit` is free, holding it until the poll
+/// finishes (#482). Without a limit the poll runs straight away.
+async fn limited<F: Future>(limit: Option<&Semaphore>, poll: F) -> F::Output {
+    // The semaphore is never closed, so acquiring only fails in theory; poll
+    // unthrottled rather than not at all.
+    let _permit = match limit {
+        Some(limit) => limit.acquire().await.ok(),
+        None => None,
+    };
+    poll.await
+}
 
-                let Some((resp_oid, value)) = response.varbinds.next() else {
-                    break;
-                };
+/// Per-device PDU timeout and retry budget (#430).
+#[derive(Debug, Clone, Copy)]
+struct RetryPolicy {
+    timeout: Duration,
+    retries: u32,
+}
 
-                if !oid_starts_with(&resp_oid, &subtree) {
-                    break;
-                }
+impl RetryPolicy {
+    /// Delay before retry number `attempt` (0-based): exponential backoff.
+    fn backoff(&self, attempt: u32) -> Duration {
+        RETRY_BACKOFF_BASE
+            .saturating_mul(1 << attempt.min(16))
+            .min(RETRY_BACKOFF_MAX)
+    }
+}
 
-                if matches!(value, Value::EndOfMibView) {
-                    break;
-                }
+/// A PDU went unanswered through every retry.
+#[derive(Debug)]
+struct RetriesExhausted {
+    request: &'static str,
+    attempts: u32,
+}
 
-                let oid_string = oid_to_string(&resp_oid);
-                if let Some(tv) = snmp_value_to_telemetry(&value) {
-                    results.push((oid_string, tv));
-                }
+impl std::fmt::Display for RetriesExhausted {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SNMP {} timed out after {} attempt(s)",
+            self.request, self.attempts
+        )
+    }
+}
 
-                current_oid = resp_oid.to_owned();
+impl std::error::Error for RetriesExhausted {}
+
+/// Run one PDU exchange under the policy's timeout, re-running it with
+/// exponential backoff while it times out. Errors other than a timeout are
+/// returned immediately.
+async fn with_retries<T, F, Fut>(
+    policy: RetryPolicy,
+    request: &'static str,
+    mut exchange: F,
+) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut attempt = 0;
+    loop {
+        match timeout(policy.timeout, exchange()).await {
+            Ok(result) => return result,
+            Err(_) if attempt < policy.retries => {
+                let backoff = policy.backoff(attempt);
+                tracing::debug!(
+                    request,
+                    attempt = attempt + 1,
+                    ?backoff,
+                    "SNMP request timed out, retrying"
+                );
+                sleep(backoff).await;
+                attempt += 1;
+            }
+            Err(_) => {
+                return Err(RetriesExhausted {
+                    request,
+                    attempts: attempt + 1,
+                }
+                .into());
             }
         }
-
-        Ok(results)
     }
+}
 
-    /// Publish a telemetry point to Zenoh.
-    async fn publish(&self, oid_str: &str, value: TelemetryValue) {
-        let metric_name = self.mib_resolver.resolve(oid_str);
-
-        let point = TelemetryPoint::new(&self.device.name, Protocol::Snmp, &metric_name, value)
-            .with_label("oid", oid_str);
-
-        let key = self.key_builder.build(&self.device.name, &metric_name);
+/// The PDUs the poller sends.
+#[derive(Debug, Clone, Copy)]
+enum Request {
+    Get,
+    GetNext,
+}
 
-        match encode(&point, self.format) {
-            Ok(payload) => {
-                if let Err(e) = self.zenoh.put(&key, payload).await {
-                    tracing::error!(key = %key, error = %e, "Failed to publish to Zenoh");
-                } else {
-                    tracing::trace!(key = %key, "Published telemetry");
-                }
-            }
-            Err(e) => {
-                tracing::error!(error = %e, "Failed to encode telemetry");
-            }
+impl Request {
+    fn name(self) -> &'static str {
+        match self {
+            Request::Get => "GET",
+            Request::GetNext => "GETNEXT",
         }
     }
 }
 
+/// The first varbind of a response, detached from the session's buffer.
+struct Varbind {
+    oid: Oid<'static>,
+    value: Option<TelemetryValue>,
+    end_of_view: bool,
+}
+
 /// Convert an SNMP Value to a TelemetryValue.
 fn snmp_value_to_telemetry(value: &Value) -> Option<TelemetryValue> {
     match value {
         Value::Integer(n) => Some(TelemetryValue::Gauge(*n as f64)),
         Value::OctetString(s) => {
-            // Try to interpret as UTF-8 string, fall back to binary
+            // Try to interpret as UTF-8 string, fall back to raw bytes
             match String::from_utf8(s.to_vec()) {
                 Ok(text)
                     if text
@@ -324,7 +511,7 @@ fn snmp_value_to_telemetry(value: &Value) -> Option<TelemetryValue> {
                 {
                     Some(TelemetryValue::Text(text))
                 }
-                _ => Some(TelemetryValue::Binary(s.to_vec())),
+                _ => Some(TelemetryValue::Bytes(s.to_vec())),
             }
         }
         Value::ObjectIdentifier(oid) => Some(TelemetryValue::Text(oid_to_string(oid))),
@@ -336,6 +523,7 @@ fn snmp_value_to_telemetry(value: &Value) -> Option<TelemetryValue> {
         Value::Unsigned32(n) => Some(TelemetryValue::Counter(*n as u64)),
         Value::Timeticks(n) => Some(TelemetryValue::Counter(*n as u64)),
         Value::Counter64(n) => Some(TelemetryValue::Counter(*n)),
+        Value::Opaque(b) => Some(TelemetryValue::Bytes(b.to_vec())),
         Value::Null | Value::NoSuchObject | Value::NoSuchInstance | Value::EndOfMibView => None,
         _ => None,
     }
@@ -345,68 +533,253 @@ fn snmp_value_to_telemetry(value: &Value) -> Option<TelemetryValue> {
 fn build_v3_security(config: &SnmpV3Security) -> Result<v3::Security> {
     let username = config.username.as_bytes();
 
-    // Determine authentication protocol
-    let auth_protocol = match config.auth_protocol {
-        AuthProtocol::None => None,
-        AuthProtocol::Md5 => Some(v3::AuthProtocol::Md5),
-        AuthProtocol::Sha1 => Some(v3::AuthProtocol::Sha1),
-        AuthProtocol::Sha224 => Some(v3::AuthProtocol::Sha224),
-        AuthProtocol::Sha256 => Some(v3::AuthProtocol::Sha256),
-        AuthProtocol::Sha384 => Some(v3::AuthProtocol::Sha384),
-        AuthProtocol::Sha512 => Some(v3::AuthProtocol::Sha512),
+    // Invalid combinations are normally caught at config load; re-check here
+    // so a malformed config can never crash the poller.
+    let security = match config.usm().map_err(|e| anyhow!(e))? {
+        UsmParams::NoAuthNoPriv => v3::Security::new(username, b""),
+        UsmParams::AuthNoPriv {
+            auth,
+            auth_password,
+        } => v3::Security::new(username, auth_password.as_bytes())
+            .with_auth_protocol(v3_auth_protocol(auth)?),
+        UsmParams::AuthPriv {
+            auth,
+            auth_password,
+            privacy,
+            priv_password,
+        } => v3::Security::new(username, auth_password.as_bytes())
+            .with_auth_protocol(v3_auth_protocol(auth)?)
+            .with_auth(v3::Auth::AuthPriv {
+                cipher: v3_cipher(privacy)?,
+                privacy_password: priv_password.as_bytes().to_vec(),
+            }),
     };
 
-    // Build security based on auth/priv levels
-    let security = match (auth_protocol, config.priv_protocol) {
-        // noAuthNoPriv
-        (None, PrivProtocol::None) => v3::Security::new(username, b""),
-        // authNoPriv
-        (Some(auth_proto), PrivProtocol::None) => {
-            let auth_password = config
-                .auth_password
-                .as_ref()
-                .ok_or_else(|| anyhow!("Authentication password required for auth protocol"))?;
-            v3::Security::new(username, auth_password.as_bytes()).with_auth_protocol(auth_proto)
-        }
-        // authPriv
-        (Some(auth_proto), priv_proto) => {
-            let auth_password = config
-                .auth_password
-                .as_ref()
-                .ok_or_else(|| anyhow!("Authentication password required for auth protocol"))?;
-            let priv_password = config
-                .priv_password
-                .as_ref()
-                .ok_or_else(|| anyhow!("Privacy password required for privacy protocol"))?;
-
-            let cipher = match priv_proto {
-                // The (Some(auth), PrivProtocol::None) case is already handled
-                // by the authNoPriv arm above, so this is logically unreachable.
-                // Return an error rather than panic in case the match arms are
-                // ever reordered — a malformed config must never crash the poller.
-                PrivProtocol::None => {
-                    return Err(anyhow!(
-                        "privacy protocol None is not valid in authPriv mode"
-                    ));
+    Ok(security)
+}
+
+/// The snmp2 authentication protocol for a configured one.
+fn v3_auth_protocol(protocol: AuthProtocol) -> Result<v3::AuthProtocol> {
+    Ok(match protocol {
+        AuthProtocol::None => return Err(anyhow!("no authentication protocol configured")),
+        AuthProtocol::Md5 => v3::AuthProtocol::Md5,
+        AuthProtocol::Sha1 => v3::AuthProtocol::Sha1,
+        AuthProtocol::Sha224 => v3::AuthProtocol::Sha224,
+        AuthProtocol::Sha256 => v3::AuthProtocol::Sha256,
+        AuthProtocol::Sha384 => v3::AuthProtocol::Sha384,
+        AuthProtocol::Sha512 => v3::AuthProtocol::Sha512,
+    })
+}
+
+/// The snmp2 cipher for a configured privacy protocol.
+fn v3_cipher(protocol: PrivProtocol) -> Result<v3::Cipher> {
+    Ok(match protocol {
+        PrivProtocol::None => return Err(anyhow!("no privacy protocol configured")),
+        PrivProtocol::Des => v3::Cipher::Des,
+        PrivProtocol::Aes128 => v3::Cipher::Aes128,
+        PrivProtocol::Aes192 => v3::Cipher::Aes192,
+        PrivProtocol::Aes256 => v3::Cipher::Aes256,
+    })
+}
+
+/// Discovery events for the interfaces `links` saw appear during the poll
+/// that just completed, deduplicated through `health`.
+fn new_interface_events(
+    links: &mut LinkTracker,
+    health: &SensorHealth,
+    device: &str,
+) -> Vec<DiscoveryEvent> {
+    links
+        .finish_poll()
+        .into_iter()
+        .filter_map(|found| {
+            let mut event =
+                health.discover(DiscoveryKind::Interface, device, Some(&found.item()))?;
+            event.label = found.interface;
+            Some(event)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::pin::Pin;
+
+    use super::*;
+
+    /// A mock agent that drops the first `drops` requests and answers the
+    /// rest.
+    fn lossy_agent(drops: u32) -> impl FnMut() -> Pin<Box<dyn Future<Output = Result<u32>>>> {
+        let mut sent = 0;
+        move || {
+            sent += 1;
+            let attempt = sent;
+            Box::pin(async move {
+                if attempt <= drops {
+                    std::future::pending::<()>().await;
                 }
-                PrivProtocol::Des => v3::Cipher::Des,
-                PrivProtocol::Aes128 => v3::Cipher::Aes128,
-                PrivProtocol::Aes192 => v3::Cipher::Aes192,
-                PrivProtocol::Aes256 => v3::Cipher::Aes256,
-            };
-
-            v3::Security::new(username, auth_password.as_bytes())
-                .with_auth_protocol(auth_proto)
-                .with_auth(v3::Auth::AuthPriv {
-                    cipher,
-                    privacy_password: priv_password.as_bytes().to_vec(),
-                })
+                Ok(attempt)
+            })
         }
-        // noAuthPriv is not valid in SNMPv3
-        (None, _) => {
-            return Err(anyhow!("Privacy requires authentication in SNMPv3"));
+    }
+
+    #[tokio::test]
+    async fn dropped_requests_are_retried_within_budget() {
+        let policy = RetryPolicy {
+            timeout: Duration::from_millis(20),
+            retries: 2,
+        };
+
+        // Two drops, answered on the third attempt.
+        let answered = with_retries(policy, "GET", lossy_agent(2)).await.unwrap();
+        assert_eq!(answered, 3);
+
+        // One retry isn't enough for the same agent.
+        let policy = RetryPolicy {
+            retries: 1,
+            ..policy
+        };
+        let err = with_retries(policy, "GET", lossy_agent(2))
+            .await
+            .unwrap_err();
+        let exhausted = err.downcast_ref::<RetriesExhausted>().unwrap();
+        assert_eq!(exhausted.attempts, 2);
+    }
+
+    #[tokio::test]
+    async fn poll_limit_caps_concurrent_polls() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let limit = Arc::new(Semaphore::new(2));
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+
+        // Eight devices whose poll cycle fires at the same instant.
+        let polls: Vec<_> = (0..8)
+            .map(|_| {
+                let (limit, running, peak) = (limit.clone(), running.clone(), peak.clone());
+                tokio::spawn(async move {
+                    limited(Some(&limit), async {
+                        let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                        peak.fetch_max(now, Ordering::SeqCst);
+                        sleep(Duration::from_millis(20)).await;
+                        running.fetch_sub(1, Ordering::SeqCst);
+                    })
+                    .await
+                })
+            })
+            .collect();
+        for poll in polls {
+            poll.await.unwrap();
         }
-    };
 
-    Ok(security)
+        // Queued rather than stampeding, but the limit is actually used.
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+        assert_eq!(running.load(Ordering::SeqCst), 0);
+        assert_eq!(limit.available_permits(), 2);
+    }
+
+    #[test]
+    fn retry_backoff_is_exponential_and_capped() {
+        let policy = RetryPolicy {
+            timeout: Duration::from_secs(1),
+            retries: 10,
+        };
+        assert_eq!(policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff(1), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(800));
+        assert_eq!(policy.backoff(10), RETRY_BACKOFF_MAX);
+    }
+
+    #[test]
+    fn each_protocol_maps_to_its_usm_primitive() {
+        assert!(matches!(
+            v3_auth_protocol(AuthProtocol::Md5),
+            Ok(v3::AuthProtocol::Md5)
+        ));
+        assert!(matches!(
+            v3_auth_protocol(AuthProtocol::Sha1),
+            Ok(v3::AuthProtocol::Sha1)
+        ));
+        assert!(matches!(
+            v3_auth_protocol(AuthProtocol::Sha224),
+            Ok(v3::AuthProtocol::Sha224)
+        ));
+        assert!(matches!(
+            v3_auth_protocol(AuthProtocol::Sha256),
+            Ok(v3::AuthProtocol::Sha256)
+        ));
+        assert!(matches!(
+            v3_auth_protocol(AuthProtocol::Sha384),
+            Ok(v3::AuthProtocol::Sha384)
+        ));
+        assert!(matches!(
+            v3_auth_protocol(AuthProtocol::Sha512),
+            Ok(v3::AuthProtocol::Sha512)
+        ));
+        assert!(v3_auth_protocol(AuthProtocol::None).is_err());
+
+        assert!(matches!(v3_cipher(PrivProtocol::Des), Ok(v3::Cipher::Des)));
+        assert!(matches!(
+            v3_cipher(PrivProtocol::Aes128),
+            Ok(v3::Cipher::Aes128)
+        ));
+        assert!(matches!(
+            v3_cipher(PrivProtocol::Aes192),
+            Ok(v3::Cipher::Aes192)
+        ));
+        assert!(matches!(
+            v3_cipher(PrivProtocol::Aes256),
+            Ok(v3::Cipher::Aes256)
+        ));
+        assert!(v3_cipher(PrivProtocol::None).is_err());
+
+        let security = SnmpV3Security {
+            username: "admin".to_string(),
+            auth_protocol: AuthProtocol::Sha512,
+            auth_password: Some("authpass123".to_string()),
+            priv_protocol: PrivProtocol::Aes256,
+            priv_password: Some("privpass456".to_string()),
+            engine_id: None,
+        };
+        assert!(build_v3_security(&security).is_ok());
+        let invalid = SnmpV3Security {
+            auth_protocol: AuthProtocol::None,
+            ..security
+        };
+        assert!(build_v3_security(&invalid).is_err());
+    }
+
+    #[test]
+    fn newly_appearing_interface_emits_one_discovery_event() {
+        let health = SensorHealth::new("snmp");
+        let mut links = LinkTracker::default();
+        let up = TelemetryValue::Gauge(1.0);
+        let oper_status = |idx: u32| format!("1.3.6.1.2.1.2.2.1.8.{idx}");
+
+        // First poll: the existing interfaces are the inventory.
+        links.observe(&oper_status(1), &up);
+        assert!(new_interface_events(&mut links, &health, "router01").is_empty());
+
+        // ifIndex 5 shows up on the second poll.
+        links.observe(&oper_status(1), &up);
+        links.observe(
+            "1.3.6.1.2.1.2.2.1.2.5",
+            &TelemetryValue::Text("Gi0/5".into()),
+        );
+        links.observe(&oper_status(5), &up);
+        let events = new_interface_events(&mut links, &health, "router01");
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, DiscoveryKind::Interface);
+        assert_eq!(events[0].device, "router01");
+        assert_eq!(events[0].item.as_deref(), Some("if/5"));
+        assert_eq!(events[0].label.as_deref(), Some("Gi0/5"));
+
+        // Subsequent polls don't repeat it.
+        for _ in 0..3 {
+            links.observe(&oper_status(1), &up);
+            links.observe(&oper_status(5), &up);
+            assert!(new_interface_events(&mut links, &health, "router01").is_empty());
+        }
+    }
 }
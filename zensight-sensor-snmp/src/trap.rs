@@ -9,9 +9,10 @@ use rasn_snmp::v1;
 use rasn_snmp::v2;
 use rasn_snmp::v2c;
 use tokio::net::UdpSocket;
-use zenoh::Session as ZenohSession;
+use tokio::sync::watch;
 
-use zensight_common::{Format, KeyExprBuilder, Protocol, TelemetryPoint, TelemetryValue, encode};
+use zensight_common::{KeyExprBuilder, Protocol, TelemetryPoint, TelemetryValue};
+use zensight_sensor_core::{Publisher, SensorHealth};
 
 use crate::mib::MibResolver;
 
@@ -87,32 +88,39 @@ pub struct VarBind {
 /// SNMP trap receiver.
 pub struct TrapReceiver {
     bind_addr: String,
-    zenoh: Arc<ZenohSession>,
+    publisher: Publisher,
     key_builder: KeyExprBuilder,
     mib_resolver: Arc<MibResolver>,
-    format: Format,
+    health: Arc<SensorHealth>,
 }
 
 impl TrapReceiver {
     /// Create a new trap receiver.
     pub fn new(
         bind_addr: &str,
-        zenoh: Arc<ZenohSession>,
+        publisher: Publisher,
         key_prefix: &str,
         mib_resolver: Arc<MibResolver>,
-        format: Format,
     ) -> Self {
         Self {
             bind_addr: bind_addr.to_string(),
-            zenoh,
+            publisher,
             key_builder: KeyExprBuilder::with_prefix(key_prefix, Protocol::Snmp),
             mib_resolver,
-            format,
+            health: Arc::new(SensorHealth::new("snmp")),
         }
     }
 
-    /// Bind and run the trap receiver.
-    pub async fn run(self) -> Result<()> {
+    /// Use the sensor's shared health tracker, so trap senders are announced
+    /// on its `@/discovery` (#436).
+    pub fn with_health(mut self, health: Arc<SensorHealth>) -> Self {
+        self.health = health;
+        self
+    }
+
+    /// Bind and run the trap receiver until `shutdown` flips; a trap being
+    /// published when it does is finished first (#461).
+    pub async fn run(self, mut shutdown: watch::Receiver<bool>) -> Result<()> {
         let socket = UdpSocket::bind(&self.bind_addr)
             .await
             .with_context(|| format!("Failed to bind trap listener to {}", self.bind_addr))?;
@@ -122,7 +130,11 @@ impl TrapReceiver {
         let mut buf = vec![0u8; 65535];
 
         loop {
-            match socket.recv_from(&mut buf).await {
+            let received = tokio::select! {
+                _ = shutdown.wait_for(|&stop| stop) => return Ok(()),
+                received = socket.recv_from(&mut buf) => received,
+            };
+            match received {
                 Ok((len, src_addr)) => {
                     let data = &buf[..len];
                     tracing::debug!(
@@ -153,6 +165,7 @@ impl TrapReceiver {
 
         // Try to parse the trap
         let parsed = parse_trap(data, &source_ip_str)?;
+        self.health.announce_device(&source_ip_str).await;
 
         tracing::debug!(
             version = parsed.version,
@@ -224,9 +237,8 @@ impl TrapReceiver {
             labels: labels.clone(),
         };
 
-        let payload = encode(&point, self.format).context("Failed to encode trap")?;
-        self.zenoh
-            .put(&key, payload)
+        self.publisher
+            .publish_to_key(&key, &point)
             .await
             .map_err(|e| anyhow::anyhow!("Failed to publish trap: {}", e))?;
 
@@ -250,10 +262,8 @@ impl TrapReceiver {
                 labels: varbind_labels,
             };
 
-            let varbind_payload =
-                encode(&varbind_point, self.format).context("Failed to encode varbind")?;
-            self.zenoh
-                .put(&varbind_key, varbind_payload)
+            self.publisher
+                .publish_to_key(&varbind_key, &varbind_point)
                 .await
                 .map_err(|e| anyhow::anyhow!("Failed to publish varbind: {}", e))?;
 
@@ -445,7 +455,7 @@ fn v1_application_syntax_to_value(app: &smi_v1::ApplicationSyntax) -> TelemetryV
         smi_v1::ApplicationSyntax::Gauge(g) => TelemetryValue::Gauge(g.0 as f64),
         smi_v1::ApplicationSyntax::Ticks(t) => TelemetryValue::Counter(t.0.into()),
         smi_v1::ApplicationSyntax::Arbitrary(bytes) => {
-            TelemetryValue::Binary(bytes.as_ref().to_vec())
+            TelemetryValue::Bytes(bytes.as_ref().to_vec())
         }
         smi_v1::ApplicationSyntax::Address(addr) => {
             TelemetryValue::Text(network_addr_to_string(addr))
@@ -490,7 +500,7 @@ fn v2_application_syntax_to_value(app: &smi_v2::ApplicationSyntax) -> TelemetryV
         smi_v2::ApplicationSyntax::Unsigned(u) => TelemetryValue::Gauge(u.0 as f64),
         smi_v2::ApplicationSyntax::Ticks(t) => TelemetryValue::Counter(t.0.into()),
         smi_v2::ApplicationSyntax::Arbitrary(bytes) => {
-            TelemetryValue::Binary(bytes.as_ref().to_vec())
+            TelemetryValue::Bytes(bytes.as_ref().to_vec())
         }
         smi_v2::ApplicationSyntax::Address(addr) => TelemetryValue::Text(format!(
             "{}.{}.{}.{}",
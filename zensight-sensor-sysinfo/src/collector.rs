@@ -6,9 +6,8 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use sysinfo::{Disks, Networks, System};
 use tracing::{debug, warn};
-use zenoh::Session;
-use zensight_common::serialization::{Format, encode};
 use zensight_common::telemetry::{Protocol, TelemetryPoint, TelemetryValue};
+use zensight_sensor_core::Publisher;
 
 #[cfg(target_os = "linux")]
 use crate::linux::LinuxMetrics;
@@ -21,10 +20,17 @@ pub struct SystemCollector {
     hostname: String,
     key_prefix: String,
     config: SysinfoConfig,
-    session: Arc<Session>,
-    format: Format,
+    /// The runner's publisher, so sample metadata, mirrors and retry apply
+    /// (#444).
+    publisher: Publisher,
     /// Previous network stats for calculating rates
     prev_network: HashMap<String, (u64, u64)>,
+    /// When the previous counter sample (poll or warm-up) was taken.
+    last_sample: Option<std::time::Instant>,
+    /// Seconds between the previous sample and this one, the window every
+    /// rate-derived gauge divides by (#483). The configured interval until a
+    /// second sample exists.
+    rate_window_secs: f64,
     /// Previous RAPL energy readings (zone -> (energy_uj, max_energy_uj)) for
     /// deriving instantaneous watts across ticks (Linux power depth, §G).
     #[cfg(target_os = "linux")]
@@ -45,16 +51,18 @@ pub struct SystemCollector {
     /// Linux-specific metrics collector
     #[cfg(target_os = "linux")]
     linux_metrics: LinuxMetrics,
+    /// NVML handle for NVIDIA GPUs (#452). `None` when `collect.gpu` is off
+    /// or `libnvidia-ml` can't be loaded.
+    #[cfg(feature = "gpu")]
+    nvml: Option<nvml_wrapper::Nvml>,
 }
 
 impl SystemCollector {
     /// Create a new system collector.
-    pub fn new(
-        hostname: String,
-        config: SysinfoConfig,
-        session: Arc<Session>,
-        format: Format,
-    ) -> Self {
+    pub fn new(hostname: String, config: SysinfoConfig, publisher: Publisher) -> Self {
+        let rate_window_secs = config.poll_interval_secs as f64;
+        #[cfg(feature = "gpu")]
+        let nvml = config.collect.gpu.then(crate::gpu::init_nvml).flatten();
         Self {
             system: System::new_all(),
             disks: Disks::new_with_refreshed_list(),
@@ -62,9 +70,10 @@ impl SystemCollector {
             key_prefix: config.key_prefix.clone(),
             hostname,
             config,
-            session,
-            format,
+            publisher,
             prev_network: HashMap::new(),
+            last_sample: None,
+            rate_window_secs,
             #[cfg(target_os = "linux")]
             prev_rapl: HashMap::new(),
             health: Arc::new(zensight_sensor_core::SensorHealth::new("sysinfo")),
@@ -74,6 +83,8 @@ impl SystemCollector {
             prev_pswpin: None,
             #[cfg(target_os = "linux")]
             linux_metrics: LinuxMetrics::new(),
+            #[cfg(feature = "gpu")]
+            nvml,
         }
     }
 
@@ -89,10 +100,14 @@ impl SystemCollector {
         self
     }
 
-    /// Run the collection loop.
-    pub async fn run(mut self) {
-        let interval = std::time::Duration::from_secs(self.config.poll_interval_secs);
+    /// The configured poll interval.
+    pub fn interval(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.config.poll_interval_secs)
+    }
 
+    /// Log the start of collection and register the monitored host. Call once
+    /// before the first [`poll`](Self::poll).
+    pub fn start(&self) {
         tracing::info!(
             "Starting system collector for '{}' (interval: {}s)",
             self.hostname,
@@ -101,20 +116,63 @@ impl SystemCollector {
 
         // This sensor monitors one host (itself).
         self.health.set_devices_total(1);
+    }
 
-        loop {
-            let started = std::time::Instant::now();
-            self.collect_and_publish().await;
-            self.health
-                .record_poll_duration(started.elapsed().as_millis() as u64);
-            self.health.record_device_success(&self.hostname);
-            tokio::time::sleep(interval).await;
+    /// Take the baseline samples the rate-derived gauges delta against (CPU
+    /// usage and times, network and disk I/O rates, RAPL watts), then wait out
+    /// sysinfo's minimum CPU sampling window. Call once before the first
+    /// [`poll`](Self::poll) so it publishes the full metric set instead of
+    /// leaving those gauges out until the second interval (#483).
+    pub async fn warm_up(&mut self) {
+        let collect = &self.config.collect;
+        if collect.cpu {
+            self.system.refresh_cpu_usage();
+        }
+        if collect.network {
+            self.networks.refresh(true);
+            for (name, data) in self.networks.list() {
+                self.prev_network.insert(
+                    name.clone(),
+                    (data.total_received(), data.total_transmitted()),
+                );
+            }
+        }
+        #[cfg(target_os = "linux")]
+        {
+            if collect.cpu_times {
+                self.linux_metrics.collect_cpu_times();
+            }
+            if collect.disk_io {
+                self.linux_metrics.collect_disk_io(self.rate_window_secs);
+            }
+            if collect.power {
+                for d in crate::linux::collect_rapl() {
+                    self.prev_rapl
+                        .insert(d.zone, (d.energy_uj, d.max_energy_uj));
+                }
+            }
+            self.prev_pswpin = crate::linux::collect_vmstat().and_then(|vm| vm.pswpin);
         }
+        self.last_sample = Some(std::time::Instant::now());
+        tokio::time::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL).await;
+    }
+
+    /// Collect and publish once, recording the poll in the sensor health.
+    pub async fn poll(&mut self) {
+        let started = std::time::Instant::now();
+        self.collect_and_publish().await;
+        self.health
+            .record_poll_duration(started.elapsed().as_millis() as u64);
+        self.health.record_device_success(&self.hostname);
     }
 
     /// Collect all metrics and publish to Zenoh.
     async fn collect_and_publish(&mut self) {
         let timestamp = chrono::Utc::now().timestamp_millis();
+        let now = std::time::Instant::now();
+        if let Some(last) = self.last_sample.replace(now) {
+            self.rate_window_secs = now.duration_since(last).as_secs_f64();
+        }
         let mut count = 0;
 
         if self.config.collect.system {
@@ -182,6 +240,12 @@ impl SystemCollector {
             }
             if self.config.collect.cgroups {
                 count += self.collect_cgroups(timestamp).await;
+            } else if self.config.collect.cgroup_limits
+                && let Some(sample) = crate::linux::collect_own_limited_cgroup()
+            {
+                count += self
+                    .publish_metrics(crate::map::map_cgroup(&sample), timestamp)
+                    .await;
             }
             if self.config.collect.power {
                 count += self.collect_power(timestamp).await;
@@ -208,6 +272,11 @@ impl SystemCollector {
             }
         }
 
+        #[cfg(feature = "gpu")]
+        if self.config.collect.gpu {
+            count += self.collect_gpu(timestamp).await;
+        }
+
         // Threshold alerting: evaluate the already-collected saturation data and
         // drive the firing/resolved lifecycle. Additive — never touches the
         // telemetry path above.
@@ -355,7 +424,7 @@ impl SystemCollector {
             }
             // Swap-in rate (pswpin pages/s) derived against the previous tick.
             if let Some(vm) = crate::linux::collect_vmstat() {
-                let interval = self.config.poll_interval_secs as f64;
+                let interval = self.rate_window_secs;
                 if let (Some(prev), Some(cur)) = (self.prev_pswpin, vm.pswpin)
                     && cur >= prev
                     && interval > 0.0
@@ -512,11 +581,33 @@ impl SystemCollector {
         count
     }
 
+    /// Collect GPU readings (#452): DRM cards on Linux, then NVIDIA cards
+    /// through NVML, numbered after them.
+    #[cfg(feature = "gpu")]
+    async fn collect_gpu(&self, timestamp: i64) -> usize {
+        #[cfg(target_os = "linux")]
+        let drm = crate::linux::collect_gpus();
+        #[cfg(not(target_os = "linux"))]
+        let drm = Vec::new();
+        let nvml = self
+            .nvml
+            .as_ref()
+            .map(crate::gpu::collect_nvml_gpus)
+            .unwrap_or_default();
+        let mut count = 0;
+        for gpu in crate::map::merge_gpu_samples(drm, nvml) {
+            count += self
+                .publish_metrics(crate::map::map_gpu(&gpu), timestamp)
+                .await;
+        }
+        count
+    }
+
     /// Collect thermal/power depth: RAPL watts (rate-derived), fan RPM, battery,
     /// entropy (Linux-specific, §G).
     #[cfg(target_os = "linux")]
     async fn collect_power(&mut self, timestamp: i64) -> usize {
-        let interval = self.config.poll_interval_secs as f64;
+        let interval = self.rate_window_secs;
 
         // RAPL: derive watts from the energy counter delta vs the prev tick.
         let domains = crate::linux::collect_rapl();
@@ -996,7 +1087,7 @@ impl SystemCollector {
 
             // Calculate rates if we have previous data
             if let Some((prev_rx, prev_tx)) = self.prev_network.get(name) {
-                let interval = self.config.poll_interval_secs as f64;
+                let interval = self.rate_window_secs;
                 if interval > 0.0 {
                     let rx_rate = (rx_bytes.saturating_sub(*prev_rx)) as f64 / interval;
                     let tx_rate = (tx_bytes.saturating_sub(*prev_tx)) as f64 / interval;
@@ -1062,6 +1153,27 @@ impl SystemCollector {
 
         let top_n = self.config.collect.top_processes;
 
+        if self.config.collect.process_groups {
+            let readings = process_readings(&self.system);
+            let groups = crate::map::group_processes(
+                readings
+                    .iter()
+                    .map(|(n, cpu, mem)| (n.as_str(), *cpu, *mem)),
+            );
+            for group in groups.iter().take(top_n) {
+                for m in crate::map::map_process_group(group) {
+                    let labels = m
+                        .labels
+                        .into_iter()
+                        .map(|(k, v)| (k.to_string(), v))
+                        .collect();
+                    self.publish(&m.metric, m.value, timestamp, labels).await;
+                    count += 1;
+                }
+            }
+            return count;
+        }
+
         // Get processes sorted by CPU usage
         let mut processes: Vec<_> = self.system.processes().values().collect();
         processes.sort_by(|a, b| {
@@ -1209,9 +1321,7 @@ impl SystemCollector {
     #[cfg(target_os = "linux")]
     async fn collect_disk_io(&mut self, timestamp: i64) -> usize {
         let mut count = 0;
-        let interval = self.config.poll_interval_secs as f64;
-
-        let disk_io = self.linux_metrics.collect_disk_io(interval);
+        let disk_io = self.linux_metrics.collect_disk_io(self.rate_window_secs);
 
         // Track the busiest device's %util this pass to feed the saturation score
         // (so we don't re-read /proc/diskstats just for the score).
@@ -1453,6 +1563,9 @@ impl SystemCollector {
         timestamp: i64,
         labels: HashMap<String, String>,
     ) {
+        if !self.config.metrics.allows(metric) {
+            return;
+        }
         self.health.record_metrics_published(1);
         let key = format!("{}/{}/{}", self.key_prefix, self.hostname, metric);
 
@@ -1465,19 +1578,31 @@ impl SystemCollector {
             labels,
         };
 
-        match encode(&point, self.format) {
-            Ok(payload) => {
-                if let Err(e) = self.session.put(&key, payload).await {
-                    warn!("Failed to publish '{}': {}", key, e);
-                }
-            }
-            Err(e) => {
-                warn!("Failed to encode metric '{}': {}", metric, e);
-            }
+        if let Err(e) = self.publisher.publish_to_key(&key, &point).await {
+            warn!("Failed to publish '{}': {}", key, e);
         }
     }
 }
 
+/// `(name, cpu, memory)` of every process for [`crate::map::group_processes`]
+/// (#453). Threads are left out: on Linux sysinfo lists each one next to its
+/// process, reporting the process's memory, so counting them would inflate
+/// both the group's count and its memory.
+fn process_readings(system: &System) -> Vec<(String, f64, u64)> {
+    system
+        .processes()
+        .values()
+        .filter(|p| p.thread_kind().is_none())
+        .map(|p| {
+            (
+                p.name().to_string_lossy().to_string(),
+                p.cpu_usage() as f64,
+                p.memory(),
+            )
+        })
+        .collect()
+}
+
 /// Build a key expression for a sysinfo metric.
 pub fn build_key_expr(prefix: &str, hostname: &str, metric: &str) -> String {
     format!("{}/{}/{}", prefix, hostname, metric)
@@ -1487,6 +1612,11 @@ pub fn build_key_expr(prefix: &str, hostname: &str, metric: &str) -> String {
 mod tests {
     use super::*;
     use crate::config::{DiskConfig, NetworkConfig};
+    use zensight_common::Format;
+
+    fn test_publisher(session: &Arc<zenoh::Session>) -> Publisher {
+        Publisher::new(session.clone(), "zensight/sysinfo", Format::Json)
+    }
 
     #[test]
     fn test_build_key_expr() {
@@ -1496,6 +1626,229 @@ mod tests {
         );
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn excluded_metrics_are_not_published() {
+        let mut zenoh_config = zenoh::Config::default();
+        zenoh_config
+            .insert_json5("scouting/multicast/enabled", "false")
+            .unwrap();
+        let session = Arc::new(zenoh::open(zenoh_config).await.unwrap());
+        let subscriber = session
+            .declare_subscriber("zensight/sysinfo/filter-host/**")
+            .await
+            .unwrap();
+
+        let config: SysinfoConfig =
+            json5::from_str(r#"{ metrics: { exclude: ["cpu/*/times/*"] } }"#).unwrap();
+        let collector =
+            SystemCollector::new("filter-host".into(), config, test_publisher(&session));
+        for metric in [
+            "cpu/0/times/user",
+            "cpu/usage",
+            "cpu/1/times/idle",
+            "cpu/0/usage",
+        ] {
+            collector
+                .publish(metric, TelemetryValue::Gauge(1.0), 0, HashMap::new())
+                .await;
+        }
+
+        let mut received = Vec::new();
+        while let Ok(Ok(sample)) = tokio::time::timeout(
+            std::time::Duration::from_millis(500),
+            subscriber.recv_async(),
+        )
+        .await
+        {
+            received.push(sample.key_expr().to_string());
+        }
+        assert_eq!(
+            received,
+            vec![
+                "zensight/sysinfo/filter-host/cpu/usage",
+                "zensight/sysinfo/filter-host/cpu/0/usage",
+            ]
+        );
+        assert_eq!(collector.health.snapshot().metrics_published, 2);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn published_metrics_carry_metadata() {
+        use zensight_common::SampleMetadata;
+
+        let mut zenoh_config = zenoh::Config::default();
+        zenoh_config
+            .insert_json5("scouting/multicast/enabled", "false")
+            .unwrap();
+        let session = Arc::new(zenoh::open(zenoh_config).await.unwrap());
+        let subscriber = session
+            .declare_subscriber("zensight/sysinfo/meta-host/**")
+            .await
+            .unwrap();
+
+        let config: SysinfoConfig = json5::from_str("{}").unwrap();
+        let publisher = test_publisher(&session).with_metadata("sysinfo", "1.2.3");
+        let collector = SystemCollector::new("meta-host".into(), config, publisher);
+        collector
+            .publish("cpu/usage", TelemetryValue::Gauge(1.0), 0, HashMap::new())
+            .await;
+
+        let sample =
+            tokio::time::timeout(std::time::Duration::from_secs(5), subscriber.recv_async())
+                .await
+                .expect("recv timed out")
+                .unwrap();
+        assert_eq!(
+            sample.key_expr().as_str(),
+            "zensight/sysinfo/meta-host/cpu/usage"
+        );
+        let attachment = sample.attachment().expect("sample has an attachment");
+        assert_eq!(
+            SampleMetadata::from_attachment(&attachment.to_bytes()),
+            Some(SampleMetadata::new("sysinfo", "1.2.3", Format::Json))
+        );
+    }
+
+    /// The only test here that builds a `SensorRunner`: it installs the global
+    /// tracing subscriber, which fails a second time in the same process.
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn runner_mirror_rules_apply_to_collector_metrics() {
+        use zensight_common::SampleMetadata;
+        use zensight_sensor_core::SensorRunner;
+
+        let config: crate::config::SysinfoSensorConfig = json5::from_str(
+            r#"{
+                zenoh: { mode: "peer", multicast_scouting: false, gossip_scouting: false },
+                sysinfo: {},
+                attach_metadata: true,
+                publish_mirror: [
+                    { key: "zensight/sysinfo/mirror-host/cpu/**", prefix: "staging/zensight" },
+                ],
+            }"#,
+        )
+        .unwrap();
+        let runner = SensorRunner::new("sysinfo", config.clone()).await.unwrap();
+        let mirror = runner
+            .session()
+            .declare_subscriber("staging/zensight/sysinfo/mirror-host/**")
+            .await
+            .unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(150)).await;
+
+        let collector =
+            SystemCollector::new("mirror-host".into(), config.sysinfo, runner.publisher());
+        for metric in ["cpu/usage", "memory/used"] {
+            collector
+                .publish(metric, TelemetryValue::Gauge(1.0), 0, HashMap::new())
+                .await;
+        }
+
+        let sample = tokio::time::timeout(std::time::Duration::from_secs(5), mirror.recv_async())
+            .await
+            .expect("recv timed out")
+            .unwrap();
+        assert_eq!(
+            sample.key_expr().as_str(),
+            "staging/zensight/sysinfo/mirror-host/cpu/usage"
+        );
+        let meta = sample
+            .attachment()
+            .and_then(|a| SampleMetadata::from_attachment(&a.to_bytes()))
+            .expect("mirrored sample carries metadata");
+        assert_eq!(meta.bridge, "sysinfo");
+        // Only the matching metric was mirrored.
+        assert!(
+            tokio::time::timeout(std::time::Duration::from_millis(300), mirror.recv_async())
+                .await
+                .is_err()
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn first_poll_after_warm_up_publishes_rates() {
+        let mut zenoh_config = zenoh::Config::default();
+        zenoh_config
+            .insert_json5("scouting/multicast/enabled", "false")
+            .unwrap();
+        let session = Arc::new(zenoh::open(zenoh_config).await.unwrap());
+        let subscriber = session
+            .declare_subscriber("zensight/sysinfo/warm-host/**")
+            .await
+            .unwrap();
+
+        // An hour-long interval: the rates can only come from the warm-up.
+        let config: SysinfoConfig = json5::from_str(
+            r#"{
+                poll_interval_secs: 3600,
+                network: { include: ["lo"] },
+                metrics: {
+                    include: ["cpu/usage", "cpu/times/user", "network/lo/rx_rate", "network/lo/tx_rate"],
+                },
+            }"#,
+        )
+        .unwrap();
+        let mut collector =
+            SystemCollector::new("warm-host".into(), config, test_publisher(&session));
+        tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            collector.warm_up().await;
+            collector.poll().await;
+        })
+        .await
+        .expect("first poll waited for the interval");
+
+        let mut received = Vec::new();
+        while let Ok(Ok(sample)) = tokio::time::timeout(
+            std::time::Duration::from_millis(500),
+            subscriber.recv_async(),
+        )
+        .await
+        {
+            received.push(sample.key_expr().to_string());
+        }
+        received.sort();
+        assert_eq!(
+            received,
+            vec![
+                "zensight/sysinfo/warm-host/cpu/times/user",
+                "zensight/sysinfo/warm-host/cpu/usage",
+                "zensight/sysinfo/warm-host/network/lo/rx_rate",
+                "zensight/sysinfo/warm-host/network/lo/tx_rate",
+            ]
+        );
+    }
+
+    #[test]
+    fn process_readings_leave_out_threads() {
+        // Park a few threads so the test process has some to list.
+        let (tx, rx) = std::sync::mpsc::channel::<()>();
+        let rx = Arc::new(std::sync::Mutex::new(rx));
+        let parked: Vec<_> = (0..3)
+            .map(|_| {
+                let rx = rx.clone();
+                std::thread::spawn(move || {
+                    let _ = rx.lock().unwrap().recv();
+                })
+            })
+            .collect();
+
+        let mut system = System::new();
+        system.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+        let own = system
+            .process(sysinfo::get_current_pid().unwrap())
+            .expect("own process is listed");
+        let name = own.name().to_string_lossy().to_string();
+
+        let readings = process_readings(&system);
+        let own_count = readings.iter().filter(|(n, ..)| *n == name).count();
+        assert_eq!(own_count, 1, "threads of {name} were counted as processes");
+
+        drop(tx);
+        for handle in parked {
+            handle.join().unwrap();
+        }
+    }
+
     #[test]
     fn test_network_filter_defaults() {
         // Default config has exclude_loopback: false (from Default trait)
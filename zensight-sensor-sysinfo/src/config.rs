@@ -36,6 +36,26 @@ pub struct SysinfoSensorConfig {
     /// Tier-2 directory-snapshot (`@/snapshot`) limits. Disabled by default.
     #[serde(default)]
     pub snapshot: zensight_sensor_core::SnapshotLimits,
+
+    /// Zenoh congestion control / priority per key or protocol (#410).
+    #[serde(default)]
+    pub publish_qos: zensight_sensor_core::PublishQosConfig,
+
+    /// Bounded queue + timed replay for publishes that fail (#412).
+    #[serde(default)]
+    pub publish_retry: zensight_sensor_core::PublishRetryConfig,
+
+    /// Re-publish matching telemetry under alternate prefixes (#447).
+    #[serde(default)]
+    pub publish_mirror: Vec<zensight_sensor_core::MirrorRule>,
+
+    /// Attach bridge name/version/encoding to each sample (#444).
+    #[serde(default)]
+    pub attach_metadata: bool,
+
+    /// Unix socket for runtime control commands (#445); off when unset.
+    #[serde(default)]
+    pub control_socket: Option<std::path::PathBuf>,
 }
 
 /// System information collection configuration.
@@ -66,6 +86,10 @@ pub struct SysinfoConfig {
     #[serde(default)]
     pub disk: DiskConfig,
 
+    /// Metric-name include/exclude globs, applied before publishing (#450).
+    #[serde(default)]
+    pub metrics: MetricFilterConfig,
+
     /// Threshold-based alerting (OOM / PSI / disk / FD / thermal / swap).
     #[serde(default)]
     pub alerts: crate::alerts::AlertsConfig,
@@ -140,6 +164,12 @@ pub struct CollectConfig {
     #[serde(default = "default_top_processes")]
     pub top_processes: usize,
 
+    /// Sum CPU and memory by executable name and publish the top
+    /// `top_processes` groups as `process_group/<name>/{cpu,memory,count}`
+    /// instead of per-PID metrics (#453). Default off.
+    #[serde(default)]
+    pub process_groups: bool,
+
     /// Collect Pressure Stall Information (`/proc/pressure/{cpu,memory,io}`).
     /// Only available on Linux 4.20+ with `CONFIG_PSI`. The #1 saturation
     /// signal (USE method). Absent file => skipped gracefully.
@@ -176,12 +206,27 @@ pub struct CollectConfig {
     #[serde(default)]
     pub cgroup_paths: Vec<String>,
 
+    /// Publish the sensor's own cgroup (v1 or v2) under `cgroup/` whenever it
+    /// has a memory or CPU limit, even with `cgroups` off (#451): inside a
+    /// container the host-level figures are misleading. Default on; a no-op
+    /// outside a limited cgroup.
+    #[serde(default = "default_true")]
+    pub cgroup_limits: bool,
+
     /// Collect thermal/power depth: RAPL energy->watts, hwmon fan RPM, battery
     /// capacity/status, kernel entropy pool. Default off (hardware-specific,
     /// higher cardinality). Missing hardware/files => skipped gracefully.
     #[serde(default)]
     pub power: bool,
 
+    /// Collect GPU utilization, VRAM, temperature and power (#452) from the
+    /// DRM sysfs (`/sys/class/drm/card*/device`, Linux) and, for NVIDIA cards,
+    /// from NVML (`libnvidia-ml`, loaded at runtime). Default off. Hosts
+    /// without a GPU or the library publish nothing. No-op unless built with
+    /// the `gpu` feature (default on).
+    #[serde(default)]
+    pub gpu: bool,
+
     /// Serve the on-demand per-process detail query channel
     /// (`@/query/processes?sort=cpu|mem|io&top=N`). Default on. The per-pid
     /// firehose is served only on query (P2); the small `system/processes_*`
@@ -249,13 +294,16 @@ impl Default for CollectConfig {
             tcp_states: false,
             processes: false,
             top_processes: 10,
+            process_groups: false,
             pressure: true,
             vmstat: true,
             fd_inode: true,
             net_dev_extended: true,
             cgroups: false,
             cgroup_paths: Vec::new(),
+            cgroup_limits: true,
             power: false,
+            gpu: false,
             process_query: true,
             netstat: true,
             softnet: true,
@@ -313,6 +361,54 @@ pub struct DiskConfig {
     pub exclude_pseudo: bool,
 }
 
+/// Metric-name filtering (#450), for trimming individual sub-metrics the
+/// `collect.*` switches can't reach. Patterns are matched against the metric
+/// path (e.g. `cpu/0/usage`): `*` matches within one segment, `**` across
+/// segments, `?` one character.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MetricFilterConfig {
+    /// Only publish metrics matching one of these patterns (empty = all).
+    #[serde(default)]
+    pub include: Vec<String>,
+
+    /// Never publish metrics matching one of these patterns.
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+impl MetricFilterConfig {
+    /// Whether `metric` passes the include and exclude lists.
+    pub fn allows(&self, metric: &str) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|p| glob_matches(p, metric)))
+            && !self.exclude.iter().any(|p| glob_matches(p, metric))
+    }
+}
+
+/// Match a metric path against a [`MetricFilterConfig`] glob.
+fn glob_matches(pattern: &str, metric: &str) -> bool {
+    fn matches(p: &[u8], m: &[u8]) -> bool {
+        match p {
+            [] => m.is_empty(),
+            [b'*', b'*', rest @ ..] => {
+                // `**/` may also match zero segments, but only resumes at a
+                // segment boundary.
+                match rest.strip_prefix(b"/") {
+                    Some(after) => (0..=m.len())
+                        .filter(|&i| i == 0 || m[i - 1] == b'/')
+                        .any(|i| matches(after, &m[i..])),
+                    None => (0..=m.len()).any(|i| matches(rest, &m[i..])),
+                }
+            }
+            [b'*', rest @ ..] => (0..=m.len())
+                .take_while(|&i| i == 0 || m[i - 1] != b'/')
+                .any(|i| matches(rest, &m[i..])),
+            [b'?', rest @ ..] => m.first().is_some_and(|&c| c != b'/') && matches(rest, &m[1..]),
+            [c, rest @ ..] => m.first() == Some(c) && matches(rest, &m[1..]),
+        }
+    }
+    matches(pattern.as_bytes(), metric.as_bytes())
+}
+
 // Re-export LoggingConfig from the framework (they're compatible)
 pub use zensight_sensor_core::LoggingConfig;
 
@@ -369,6 +465,10 @@ impl SysinfoSensorConfig {
 
 /// Implement SensorConfig trait for framework integration.
 impl SensorConfig for SysinfoSensorConfig {
+    fn default_config_json5() -> &'static str {
+        include_str!("../../configs/sysinfo.json5")
+    }
+
     fn zenoh(&self) -> &ZenohConfig {
         &self.zenoh
     }
@@ -389,6 +489,26 @@ impl SensorConfig for SysinfoSensorConfig {
         self.snapshot.clone()
     }
 
+    fn publish_qos(&self) -> zensight_sensor_core::PublishQosConfig {
+        self.publish_qos.clone()
+    }
+
+    fn publish_retry(&self) -> zensight_sensor_core::PublishRetryConfig {
+        self.publish_retry.clone()
+    }
+
+    fn publish_mirror(&self) -> Vec<zensight_sensor_core::MirrorRule> {
+        self.publish_mirror.clone()
+    }
+
+    fn attach_metadata(&self) -> bool {
+        self.attach_metadata
+    }
+
+    fn control_socket(&self) -> Option<std::path::PathBuf> {
+        self.control_socket.clone()
+    }
+
     fn validate(&self) -> zensight_sensor_core::Result<()> {
         // Call our existing validate method and convert the error
         Self::validate(self).map_err(|e| SensorError::validation(e.to_string()))
@@ -473,6 +593,37 @@ impl DiskConfig {
 mod tests {
     use super::*;
 
+    #[test]
+    fn metric_filter_globs() {
+        let filter = MetricFilterConfig {
+            include: Vec::new(),
+            exclude: vec!["cpu/*/times/*".into(), "disk/**/inodes_*".into()],
+        };
+        assert!(!filter.allows("cpu/0/times/user"));
+        assert!(filter.allows("cpu/times/user"));
+        assert!(filter.allows("cpu/0/times/user/extra"));
+        assert!(!filter.allows("disk/root/inodes_used"));
+        assert!(!filter.allows("disk/inodes_used"));
+        assert!(filter.allows("disk/root/used_percent"));
+
+        let filter = MetricFilterConfig {
+            include: vec!["memory/**".into(), "cpu/usage".into()],
+            exclude: vec!["memory/swap_?sed".into()],
+        };
+        assert!(filter.allows("cpu/usage"));
+        assert!(!filter.allows("cpu/0/usage"));
+        assert!(filter.allows("memory/used"));
+        assert!(!filter.allows("memory/swap_used"));
+    }
+
+    #[test]
+    fn printed_default_config_parses_back() {
+        let config: SysinfoSensorConfig =
+            json5::from_str(SysinfoSensorConfig::default_config_json5()).unwrap();
+        SensorConfig::validate(&config).unwrap();
+        assert_eq!(config.sysinfo.key_prefix, "zensight/sysinfo");
+    }
+
     #[test]
     fn test_parse_minimal_config() {
         let json = r#"{
@@ -628,4 +779,12 @@ mod tests {
         assert!(!config.should_include("/run", "tmpfs"));
         assert!(!config.should_include("/sys", "sysfs"));
     }
+
+    #[test]
+    fn printed_default_config_documents_every_field() {
+        assert_eq!(
+            zensight_sensor_core::undocumented_fields::<SysinfoSensorConfig>().unwrap(),
+            Vec::<String>::new()
+        );
+    }
 }
@@ -0,0 +1,47 @@
+//! NVIDIA GPU readings through NVML (#452).
+//!
+//! `libnvidia-ml` is loaded at runtime, so a host without the NVIDIA driver
+//! simply gets no NVML handle and publishes only what the DRM sysfs offers.
+
+use nvml_wrapper::Nvml;
+use nvml_wrapper::enum_wrappers::device::TemperatureSensor;
+use tracing::debug;
+
+use crate::map::{GpuSample, NvmlReadings, nvml_gpu_sample};
+
+/// Load NVML, or `None` (logged at debug) when the library is missing or the
+/// driver refuses to initialise.
+pub fn init_nvml() -> Option<Nvml> {
+    match Nvml::init() {
+        Ok(nvml) => Some(nvml),
+        Err(e) => {
+            debug!(error = %e, "NVML unavailable; NVIDIA GPUs are not collected");
+            None
+        }
+    }
+}
+
+/// Read every NVML device. A device that can't be opened is skipped, and one
+/// whose calls all fail yields no sample.
+pub fn collect_nvml_gpus(nvml: &Nvml) -> Vec<GpuSample> {
+    let count = match nvml.device_count() {
+        Ok(count) => count,
+        Err(e) => {
+            debug!(error = %e, "NVML device count failed");
+            return Vec::new();
+        }
+    };
+    (0..count)
+        .filter_map(|index| {
+            let device = nvml.device_by_index(index).ok()?;
+            let readings = NvmlReadings {
+                utilization_percent: device.utilization_rates().ok().map(|u| u.gpu),
+                memory: device.memory_info().ok().map(|m| (m.used, m.total)),
+                temperature_c: device.temperature(TemperatureSensor::Gpu).ok(),
+                power_mw: device.power_usage().ok(),
+            };
+            let sample = nvml_gpu_sample(index, &readings);
+            (!sample.is_empty()).then_some(sample)
+        })
+        .collect()
+}
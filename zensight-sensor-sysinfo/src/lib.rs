@@ -23,6 +23,8 @@
 pub mod alerts;
 pub mod collector;
 pub mod config;
+#[cfg(feature = "gpu")]
+pub mod gpu;
 pub mod map;
 pub mod query;
 pub mod saturation;
@@ -9,11 +9,13 @@
 use crate::map::{
     BatteryReading, CgroupSample, ConntrackSample, DiskSaturation, EdacSample, FanReading, FdStat,
     InodeStat, KernelDerivatives, MdArray, NetDevStat, NetstatSample, PressureSample, PsiSample,
-    RaplDomain, SchedstatSample, SockstatSample, SoftnetSample, VmStat, disk_saturation,
-    parse_cgroup_scalar, parse_conntrack, parse_file_nr, parse_flat_kv, parse_mdstat,
-    parse_net_dev, parse_netstat, parse_pressure_file, parse_schedstat, parse_sockstat,
-    parse_softnet, parse_vmstat,
+    RaplDomain, SchedstatSample, SockstatSample, SoftnetSample, VmStat, cgroup_v1_sample,
+    cgroup_v2_sample, disk_saturation, parse_conntrack, parse_file_nr, parse_mdstat, parse_net_dev,
+    parse_netstat, parse_own_cgroup_v1, parse_schedstat, parse_sockstat, parse_softnet,
+    parse_vmstat,
 };
+#[cfg(feature = "gpu")]
+use crate::map::{GpuSample, gpu_sample};
 use procfs::{Current, CurrentSI};
 use std::collections::HashMap;
 use tracing::warn;
@@ -669,56 +671,77 @@ fn read_cgroup_file(cgroup_path: &str, file: &str) -> Option<String> {
 }
 
 /// Collect the cgroup-v2 saturation sample for a single cgroup path. Reads
-/// `cpu.stat`, `memory.{current,max,events}`, and the `{cpu,memory,io}.pressure`
-/// PSI files. Every missing file degrades to `None` for that field.
+/// `cpu.{stat,max}`, `memory.{current,max,events}`, and the
+/// `{cpu,memory,io}.pressure` PSI files. Every missing file degrades to `None`
+/// for that field.
 pub fn collect_cgroup(cgroup_path: &str) -> CgroupSample {
-    let mut s = CgroupSample {
-        path: cgroup_path.to_string(),
-        ..Default::default()
-    };
+    cgroup_v2_sample(cgroup_path, |file| read_cgroup_file(cgroup_path, file))
+}
 
-    if let Some(cpu_stat) = read_cgroup_file(cgroup_path, "cpu.stat") {
-        let kv = parse_flat_kv(&cpu_stat);
-        s.cpu_nr_throttled = kv.get("nr_throttled").copied();
-        s.cpu_throttled_usec = kv.get("throttled_usec").copied();
-    }
-    if let Some(cur) = read_cgroup_file(cgroup_path, "memory.current") {
-        s.memory_current = parse_cgroup_scalar(&cur);
-    }
-    if let Some(max) = read_cgroup_file(cgroup_path, "memory.max") {
-        s.memory_max = parse_cgroup_scalar(&max);
-    }
-    if let Some(events) = read_cgroup_file(cgroup_path, "memory.events") {
-        let kv = parse_flat_kv(&events);
-        s.memory_oom_kills = kv.get("oom_kill").copied();
-        s.memory_oom = kv.get("oom").copied();
-    }
-    if let Some(p) = read_cgroup_file(cgroup_path, "cpu.pressure") {
-        let (some, _full) = parse_pressure_file(&p);
-        s.cpu_pressure_some = some;
-    }
-    if let Some(p) = read_cgroup_file(cgroup_path, "memory.pressure") {
-        let (some, full) = parse_pressure_file(&p);
-        s.memory_pressure_some = some;
-        s.memory_pressure_full = full;
-    }
-    if let Some(p) = read_cgroup_file(cgroup_path, "io.pressure") {
-        let (some, full) = parse_pressure_file(&p);
-        s.io_pressure_some = some;
-        s.io_pressure_full = full;
-    }
-    s
+/// Collect a cgroup-v1 sample (#451). v1 mounts one hierarchy per controller
+/// (`/sys/fs/cgroup/{cpu,memory}`), each with its own path for this process.
+fn collect_cgroup_v1(paths: &HashMap<&str, String>) -> CgroupSample {
+    collect_cgroup_v1_at(std::path::Path::new(CGROUP_ROOT), paths)
+}
+
+/// [`collect_cgroup_v1`] against the hierarchies mounted under `root`.
+fn collect_cgroup_v1_at(root: &std::path::Path, paths: &HashMap<&str, String>) -> CgroupSample {
+    let dirs: HashMap<&str, std::path::PathBuf> = paths
+        .iter()
+        .map(|(&controller, path)| (controller, cgroup_v1_dir(root, controller, path)))
+        .collect();
+    let label = paths.get("memory").or(paths.get("cpu"));
+    cgroup_v1_sample(label.map_or("/", String::as_str), |controller, file| {
+        std::fs::read_to_string(dirs.get(controller)?.join(file)).ok()
+    })
+}
+
+/// The directory holding `controller`'s files for the cgroup at `path` (as
+/// listed in `/proc/self/cgroup`). Inside a v1 container that path (e.g.
+/// `/docker/<id>`) is the host's view: the container's own cgroup is mounted
+/// at the controller root, so fall back to it when the joined path is missing.
+fn cgroup_v1_dir(root: &std::path::Path, controller: &str, path: &str) -> std::path::PathBuf {
+    let mount = root.join(controller);
+    let nested = mount.join(path.trim_start_matches('/'));
+    if nested.is_dir() { nested } else { mount }
+}
+
+/// This process's cgroup-v1 paths for the `cpu` and `memory` controllers, or
+/// `None` when neither hierarchy is mounted.
+fn own_cgroup_v1_paths() -> Option<HashMap<&'static str, String>> {
+    let content = std::fs::read_to_string("/proc/self/cgroup").ok()?;
+    let paths: HashMap<_, _> = ["cpu", "memory"]
+        .into_iter()
+        .filter(|c| std::path::Path::new(&format!("{CGROUP_ROOT}/{c}")).is_dir())
+        .filter_map(|c| Some((c, parse_own_cgroup_v1(&content, c)?)))
+        .collect();
+    (!paths.is_empty()).then_some(paths)
+}
+
+fn cgroup_v2_present() -> bool {
+    // Cheap presence check: the unified hierarchy exposes cgroup.controllers.
+    std::path::Path::new(&format!("{CGROUP_ROOT}/cgroup.controllers")).exists()
+}
+
+/// The sensor's own cgroup (v2, else v1) when it runs under a memory or CPU
+/// limit (#451), e.g. inside a container; `None` otherwise.
+pub fn collect_own_limited_cgroup() -> Option<CgroupSample> {
+    let sample = if cgroup_v2_present() {
+        collect_cgroup(&own_cgroup_path()?)
+    } else {
+        collect_cgroup_v1(&own_cgroup_v1_paths()?)
+    };
+    sample.has_limit().then_some(sample)
 }
 
 /// Collect cgroup samples for the configured set of cgroups. If `extra` is empty
 /// only the sensor's own cgroup is read; otherwise each configured path is read
-/// too. Returns `None` only when cgroup-v2 is not present at all (no own cgroup
-/// and no `cpu.stat` under the root), so the collector skips cleanly on
-/// non-container / cgroup-v1 hosts.
+/// too. On cgroup-v1 hosts only the sensor's own cgroup is read (#451).
+/// Returns `None` when no cgroup hierarchy is present, so the collector skips
+/// cleanly.
 pub fn collect_cgroups(extra: &[String]) -> Option<Vec<CgroupSample>> {
-    // Cheap presence check: the unified hierarchy exposes cgroup.controllers.
-    if !std::path::Path::new(&format!("{CGROUP_ROOT}/cgroup.controllers")).exists() {
-        return None;
+    if !cgroup_v2_present() {
+        return own_cgroup_v1_paths().map(|paths| vec![collect_cgroup_v1(&paths)]);
     }
     let mut out = Vec::new();
     if let Some(own) = own_cgroup_path() {
@@ -891,10 +914,85 @@ pub fn collect_mem_composition() -> Option<MemComposition> {
     }
 }
 
+// ---------------------------------------------------------------------------
+// I. GPU (DRM sysfs, #452)
+// ---------------------------------------------------------------------------
+
+/// Read every DRM card exposing at least one GPU reading. Connector entries
+/// (`card0-HDMI-A-1`) and cards with no readable files are skipped, so hosts
+/// without a (supported) GPU return an empty list.
+#[cfg(feature = "gpu")]
+pub fn collect_gpus() -> Vec<GpuSample> {
+    let Ok(entries) = std::fs::read_dir("/sys/class/drm") else {
+        return Vec::new();
+    };
+    let mut out = Vec::new();
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let Some(index) = name
+            .strip_prefix("card")
+            .and_then(|n| n.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        let device = entry.path().join("device");
+        let hwmon = std::fs::read_dir(device.join("hwmon"))
+            .ok()
+            .and_then(|mut dirs| dirs.next())
+            .and_then(|d| d.ok())
+            .map(|d| d.path());
+        let mut sample = gpu_sample(index, |file| {
+            let path = match file.strip_prefix("hwmon/") {
+                Some(f) => hwmon.as_ref()?.join(f),
+                None => device.join(file),
+            };
+            std::fs::read_to_string(path).ok()
+        });
+        if sample.is_empty() {
+            continue;
+        }
+        sample.driver = std::fs::read_link(device.join("driver"))
+            .ok()
+            .and_then(|p| p.file_name().map(|n| n.to_string_lossy().to_string()));
+        out.push(sample);
+    }
+    out.sort_by_key(|g| g.index);
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// A v1 container: `/proc/self/cgroup` says `/docker/<id>`, but the
+    /// container's cgroup is what is mounted at each controller root.
+    #[test]
+    fn cgroup_v1_in_container_reads_controller_root() {
+        let root = std::env::temp_dir().join(format!("zensight-cgv1-{}", std::process::id()));
+        let memory = root.join("memory");
+        std::fs::create_dir_all(&memory).unwrap();
+        std::fs::write(memory.join("memory.limit_in_bytes"), "536870912\n").unwrap();
+        std::fs::write(memory.join("memory.usage_in_bytes"), "1048576\n").unwrap();
+        let paths = HashMap::from([("memory", "/docker/abc123".to_string())]);
+
+        let sample = collect_cgroup_v1_at(&root, &paths);
+        assert_eq!(sample.memory_max, Some(536_870_912));
+        assert_eq!(sample.memory_current, Some(1_048_576));
+        assert_eq!(sample.path, "/docker/abc123");
+
+        // On the host the nested directory exists and wins.
+        let nested = memory.join("docker/abc123");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(nested.join("memory.limit_in_bytes"), "1073741824\n").unwrap();
+        assert_eq!(cgroup_v1_dir(&root, "memory", "/docker/abc123"), nested);
+        assert_eq!(
+            collect_cgroup_v1_at(&root, &paths).memory_max,
+            Some(1_073_741_824)
+        );
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
     #[test]
     fn test_linux_metrics_new() {
         let metrics = LinuxMetrics::new();
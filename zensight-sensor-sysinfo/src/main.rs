@@ -13,6 +13,10 @@ use zensight_sensor_sysinfo::config::SysinfoSensorConfig;
 async fn main() -> Result<()> {
     // Parse CLI arguments
     let args = SensorArgs::parse_with_default("sysinfo.json5");
+    if args.print_default_config {
+        print!("{}", SysinfoSensorConfig::default_config_json5());
+        return Ok(());
+    }
 
     // Load configuration using the framework's SensorConfig trait
     let config = SysinfoSensorConfig::load(&args.config).map_err(|e| anyhow::anyhow!("{}", e))?;
@@ -45,6 +49,7 @@ async fn main() -> Result<()> {
     // Get the config and publisher for the collector
     let sysinfo_config = runner.config().sysinfo.clone();
     let session = runner.session().clone();
+    let publisher = runner.publisher();
 
     tracing::info!(
         "Sysinfo sensor running (prefix: {}, interval: {}s, hostname: {})",
@@ -70,13 +75,8 @@ async fn main() -> Result<()> {
     // Threshold-based alerting: drive an AlertReporter → zensight/sysinfo/@/alerts/*
     // for OOM / PSI / disk / FD / thermal / swap saturation (mirrors the other
     // sensors). Late-joining GUIs seed their firing set via serve_alerts_query.
-    let mut collector = SystemCollector::new(
-        hostname.clone(),
-        sysinfo_config.clone(),
-        session,
-        Format::Json,
-    )
-    .with_health(runner.health());
+    let mut collector = SystemCollector::new(hostname.clone(), sysinfo_config.clone(), publisher)
+        .with_health(runner.health());
     if sysinfo_config.alerts.enabled {
         use std::sync::Arc;
         use std::time::Duration;
@@ -94,8 +94,17 @@ async fn main() -> Result<()> {
         collector = collector.with_alerts(evaluator);
         tracing::info!("Sysinfo threshold alerting enabled");
     }
-    runner.spawn(async move {
-        collector.run().await;
+
+    // Poll on the runner's periodic loop (#460), which stops on shutdown. The
+    // warm-up primes the rate baselines so the first tick is a full snapshot
+    // (#483).
+    collector.start();
+    collector.warm_up().await;
+    let interval = collector.interval();
+    let collector = std::sync::Arc::new(tokio::sync::Mutex::new(collector));
+    runner.spawn_periodic(interval, move || {
+        let collector = collector.clone();
+        async move { collector.lock().await.poll().await }
     });
 
     // Opt-in eBPF saturation histograms (#99): load runqlat/biolatency, serve
@@ -142,6 +151,13 @@ async fn main() -> Result<()> {
         );
     }
 
+    #[cfg(not(feature = "gpu"))]
+    if sysinfo_config.collect.gpu {
+        tracing::warn!(
+            "collect.gpu=true but this binary was built without the `gpu` feature; ignoring"
+        );
+    }
+
     // Build status metadata
     let metadata = serde_json::json!({
         "hostname": runner.config().get_hostname(),
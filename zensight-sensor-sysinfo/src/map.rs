@@ -432,6 +432,10 @@ pub struct CgroupSample {
     pub cpu_nr_throttled: Option<u64>,
     /// `cpu.stat` `throttled_usec` — total time the cgroup was throttled.
     pub cpu_throttled_usec: Option<u64>,
+    /// `cpu.max` quota in µs per period (`None` when unlimited).
+    pub cpu_quota_us: Option<u64>,
+    /// `cpu.max` enforcement period in µs.
+    pub cpu_period_us: Option<u64>,
     /// `memory.current` — current memory usage in bytes.
     pub memory_current: Option<u64>,
     /// `memory.max` — the hard limit in bytes (`None` when set to `max`).
@@ -452,6 +456,13 @@ pub struct CgroupSample {
     pub io_pressure_full: Option<PressureSample>,
 }
 
+impl CgroupSample {
+    /// Whether the cgroup is capped on memory or CPU (#451).
+    pub fn has_limit(&self) -> bool {
+        self.memory_max.is_some() || self.cpu_quota_us.is_some()
+    }
+}
+
 /// Parse a flat cgroup-v2 `key value` file (e.g. `cpu.stat`, `memory.events`)
 /// into a small lookup. Values that do not parse as `u64` are skipped.
 pub fn parse_flat_kv(content: &str) -> std::collections::HashMap<String, u64> {
@@ -477,6 +488,114 @@ pub fn parse_cgroup_scalar(content: &str) -> Option<u64> {
     t.parse::<u64>().ok()
 }
 
+/// Parse cgroup-v2 `cpu.max` (`<quota> <period>`, quota `max` when unlimited)
+/// into `(quota_us, period_us)`.
+pub fn parse_cpu_max(content: &str) -> (Option<u64>, Option<u64>) {
+    let mut parts = content.split_whitespace();
+    let quota = parts.next().and_then(parse_cgroup_scalar);
+    let period = parts.next().and_then(|p| p.parse().ok());
+    (quota, period)
+}
+
+/// Parse a cgroup-v1 limit (`memory.limit_in_bytes`, `cpu.cfs_quota_us`). v1
+/// has no `max`: unlimited is `-1` or a page-rounded `i64::MAX`, both `None`.
+pub fn parse_cgroup_v1_limit(content: &str) -> Option<u64> {
+    const UNLIMITED: u64 = 1 << 62;
+    content
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .filter(|&v| v < UNLIMITED)
+}
+
+/// Build a cgroup-v2 sample from its controller files (#451). `read` returns a
+/// file's contents relative to the cgroup directory, or `None` if absent.
+pub fn cgroup_v2_sample(path: &str, read: impl Fn(&str) -> Option<String>) -> CgroupSample {
+    let mut s = CgroupSample {
+        path: path.to_string(),
+        ..Default::default()
+    };
+
+    if let Some(cpu_stat) = read("cpu.stat") {
+        let kv = parse_flat_kv(&cpu_stat);
+        s.cpu_nr_throttled = kv.get("nr_throttled").copied();
+        s.cpu_throttled_usec = kv.get("throttled_usec").copied();
+    }
+    if let Some(cpu_max) = read("cpu.max") {
+        (s.cpu_quota_us, s.cpu_period_us) = parse_cpu_max(&cpu_max);
+    }
+    if let Some(cur) = read("memory.current") {
+        s.memory_current = parse_cgroup_scalar(&cur);
+    }
+    if let Some(max) = read("memory.max") {
+        s.memory_max = parse_cgroup_scalar(&max);
+    }
+    if let Some(events) = read("memory.events") {
+        let kv = parse_flat_kv(&events);
+        s.memory_oom_kills = kv.get("oom_kill").copied();
+        s.memory_oom = kv.get("oom").copied();
+    }
+    if let Some(p) = read("cpu.pressure") {
+        let (some, _full) = parse_pressure_file(&p);
+        s.cpu_pressure_some = some;
+    }
+    if let Some(p) = read("memory.pressure") {
+        let (some, full) = parse_pressure_file(&p);
+        s.memory_pressure_some = some;
+        s.memory_pressure_full = full;
+    }
+    if let Some(p) = read("io.pressure") {
+        let (some, full) = parse_pressure_file(&p);
+        s.io_pressure_some = some;
+        s.io_pressure_full = full;
+    }
+    s
+}
+
+/// Build a sample from cgroup-v1 controller files (#451), mapped onto the v2
+/// fields. `read(controller, file)` reads from that controller's hierarchy
+/// (`cpu` or `memory`). v1 has no PSI, and `oom_kill` only on kernels >= 4.13.
+pub fn cgroup_v1_sample(path: &str, read: impl Fn(&str, &str) -> Option<String>) -> CgroupSample {
+    let mut s = CgroupSample {
+        path: path.to_string(),
+        ..Default::default()
+    };
+
+    if let Some(cpu_stat) = read("cpu", "cpu.stat") {
+        let kv = parse_flat_kv(&cpu_stat);
+        s.cpu_nr_throttled = kv.get("nr_throttled").copied();
+        // v1 reports nanoseconds.
+        s.cpu_throttled_usec = kv.get("throttled_time").map(|ns| ns / 1_000);
+    }
+    s.cpu_quota_us = read("cpu", "cpu.cfs_quota_us").and_then(|q| parse_cgroup_v1_limit(&q));
+    if s.cpu_quota_us.is_some() {
+        s.cpu_period_us = read("cpu", "cpu.cfs_period_us").and_then(|p| p.trim().parse().ok());
+    }
+    if let Some(cur) = read("memory", "memory.usage_in_bytes") {
+        s.memory_current = cur.trim().parse().ok();
+    }
+    s.memory_max = read("memory", "memory.limit_in_bytes").and_then(|m| parse_cgroup_v1_limit(&m));
+    if let Some(oom) = read("memory", "memory.oom_control") {
+        s.memory_oom_kills = parse_flat_kv(&oom).get("oom_kill").copied();
+    }
+    s
+}
+
+/// Find `controller`'s path in a cgroup-v1 `/proc/self/cgroup`, where lines
+/// look like `4:cpu,cpuacct:/docker/abc`.
+pub fn parse_own_cgroup_v1(content: &str, controller: &str) -> Option<String> {
+    content.lines().find_map(|line| {
+        let mut parts = line.splitn(3, ':');
+        let _hid = parts.next()?;
+        let controllers = parts.next()?;
+        let path = parts.next()?;
+        controllers
+            .split(',')
+            .any(|c| c == controller)
+            .then(|| path.to_string())
+    })
+}
+
 /// Parse a kernel PSI-format file (`/proc/pressure/*` or a cgroup `*.pressure`).
 /// Lines look like `some avg10=0.00 avg60=0.00 avg300=0.00 total=12345`.
 /// Returns `(some, full)` where each is present only if its line exists.
@@ -521,6 +640,16 @@ pub fn map_cgroup(c: &CgroupSample) -> Vec<Metric> {
     if let Some(v) = c.cpu_throttled_usec {
         out.push(label(Metric::counter("cgroup/cpu/throttled_usec", v)));
     }
+    if let (Some(quota), Some(period)) = (c.cpu_quota_us, c.cpu_period_us)
+        && period > 0
+    {
+        out.push(label(Metric::gauge("cgroup/cpu/quota_us", quota as f64)));
+        out.push(label(Metric::gauge("cgroup/cpu/period_us", period as f64)));
+        out.push(label(Metric::gauge(
+            "cgroup/cpu/limit_cores",
+            quota as f64 / period as f64,
+        )));
+    }
     if let Some(v) = c.memory_current {
         out.push(label(Metric::gauge("cgroup/memory/current", v as f64)));
     }
@@ -1359,6 +1488,187 @@ pub fn build_histogram(counts: &[u64; MAX_SLOTS], unit: &str) -> Histogram {
     }
 }
 
+// ===========================================================================
+// J. Process groups (#453): per-executable sums instead of per-PID metrics
+// ===========================================================================
+
+/// Summed resources of every process sharing an executable name.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProcessGroup {
+    pub name: String,
+    /// Summed CPU usage, in percent of one core.
+    pub cpu: f64,
+    /// Summed resident memory, bytes.
+    pub memory: u64,
+    /// Number of processes in the group.
+    pub count: u64,
+}
+
+/// Group `(name, cpu, memory)` process readings by name, busiest first (ties
+/// broken by name so the order is stable).
+pub fn group_processes<'a>(
+    processes: impl IntoIterator<Item = (&'a str, f64, u64)>,
+) -> Vec<ProcessGroup> {
+    let mut groups: std::collections::HashMap<&str, ProcessGroup> =
+        std::collections::HashMap::new();
+    for (name, cpu, memory) in processes {
+        let g = groups.entry(name).or_insert_with(|| ProcessGroup {
+            name: name.to_string(),
+            ..Default::default()
+        });
+        g.cpu += cpu;
+        g.memory += memory;
+        g.count += 1;
+    }
+    let mut out: Vec<ProcessGroup> = groups.into_values().collect();
+    out.sort_by(|a, b| b.cpu.total_cmp(&a.cpu).then_with(|| a.name.cmp(&b.name)));
+    out
+}
+
+/// Map a process group to `process_group/<name>/{cpu,memory,count}`.
+pub fn map_process_group(g: &ProcessGroup) -> Vec<Metric> {
+    let key = sanitize_key(&g.name);
+    vec![
+        Metric::gauge(format!("process_group/{key}/cpu"), g.cpu).label("name", g.name.clone()),
+        Metric::gauge(format!("process_group/{key}/memory"), g.memory as f64)
+            .label("name", g.name.clone())
+            .label("unit", "bytes"),
+        Metric::gauge(format!("process_group/{key}/count"), g.count as f64)
+            .label("name", g.name.clone()),
+    ]
+}
+
+// ===========================================================================
+// I. GPU (#452): utilization, VRAM, temperature, power per DRM card
+// ===========================================================================
+
+/// One GPU's readings. Drivers expose different subsets, so every reading is
+/// optional and absent ones are not published.
+#[cfg(feature = "gpu")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GpuSample {
+    /// DRM card index (`card<N>`), used as the metric key segment.
+    pub index: u32,
+    /// Kernel driver (e.g. `amdgpu`), when known.
+    pub driver: Option<String>,
+    /// `gpu_busy_percent`.
+    pub utilization_percent: Option<f64>,
+    /// `mem_info_vram_used`, bytes.
+    pub memory_used: Option<u64>,
+    /// `mem_info_vram_total`, bytes.
+    pub memory_total: Option<u64>,
+    /// hwmon `temp1_input`, °C.
+    pub temperature_c: Option<f64>,
+    /// hwmon `power1_average` (or `power1_input`), watts.
+    pub power_watts: Option<f64>,
+}
+
+#[cfg(feature = "gpu")]
+impl GpuSample {
+    /// Whether any reading is present.
+    pub fn is_empty(&self) -> bool {
+        self.utilization_percent.is_none()
+            && self.memory_used.is_none()
+            && self.memory_total.is_none()
+            && self.temperature_c.is_none()
+            && self.power_watts.is_none()
+    }
+}
+
+/// Build a [`GpuSample`] from a DRM card's `device` files. `read` returns a
+/// file relative to the device directory; `hwmon/<file>` resolves into the
+/// card's hwmon directory.
+#[cfg(feature = "gpu")]
+pub fn gpu_sample(index: u32, read: impl Fn(&str) -> Option<String>) -> GpuSample {
+    let num = |file: &str| read(file).and_then(|s| s.trim().parse::<f64>().ok());
+    GpuSample {
+        index,
+        driver: None,
+        utilization_percent: num("gpu_busy_percent"),
+        memory_used: num("mem_info_vram_used").map(|v| v as u64),
+        memory_total: num("mem_info_vram_total").map(|v| v as u64),
+        // hwmon reports millidegrees and microwatts.
+        temperature_c: num("hwmon/temp1_input").map(|v| v / 1000.0),
+        power_watts: num("hwmon/power1_average")
+            .or_else(|| num("hwmon/power1_input"))
+            .map(|v| v / 1_000_000.0),
+    }
+}
+
+/// Raw NVML readings for one device; each is `None` when the call failed or
+/// the card doesn't support it.
+#[cfg(feature = "gpu")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NvmlReadings {
+    /// `utilization_rates().gpu`, percent.
+    pub utilization_percent: Option<u32>,
+    /// `memory_info()` as `(used, total)` bytes.
+    pub memory: Option<(u64, u64)>,
+    /// `temperature(Gpu)`, °C.
+    pub temperature_c: Option<u32>,
+    /// `power_usage()`, milliwatts.
+    pub power_mw: Option<u32>,
+}
+
+/// Build a [`GpuSample`] from NVML device `index`'s readings.
+#[cfg(feature = "gpu")]
+pub fn nvml_gpu_sample(index: u32, r: &NvmlReadings) -> GpuSample {
+    GpuSample {
+        index,
+        driver: Some("nvidia".to_string()),
+        utilization_percent: r.utilization_percent.map(f64::from),
+        memory_used: r.memory.map(|(used, _)| used),
+        memory_total: r.memory.map(|(_, total)| total),
+        temperature_c: r.temperature_c.map(f64::from),
+        power_watts: r.power_mw.map(|mw| f64::from(mw) / 1000.0),
+    }
+}
+
+/// Put the NVML samples after the DRM ones, renumbered past the highest DRM
+/// card index so the `gpu/<index>` keys of a mixed host stay distinct. On an
+/// NVIDIA-only host (whose DRM cards expose no readings) NVML keeps its own
+/// numbering.
+#[cfg(feature = "gpu")]
+pub fn merge_gpu_samples(mut drm: Vec<GpuSample>, nvml: Vec<GpuSample>) -> Vec<GpuSample> {
+    let first_free = drm.iter().map(|g| g.index + 1).max().unwrap_or(0);
+    drm.extend(nvml.into_iter().map(|mut g| {
+        g.index += first_free;
+        g
+    }));
+    drm
+}
+
+/// Map a GPU sample to `gpu/<index>/{utilization,memory_used,memory_total,
+/// temperature,power_watts}`, labelled with the index and driver.
+#[cfg(feature = "gpu")]
+pub fn map_gpu(g: &GpuSample) -> Vec<Metric> {
+    let label = |m: Metric| {
+        let m = m.label("gpu", g.index.to_string());
+        match &g.driver {
+            Some(driver) => m.label("driver", driver.clone()),
+            None => m,
+        }
+    };
+    let key = |name: &str| format!("gpu/{}/{name}", g.index);
+    let mut out = Vec::new();
+    if let Some(v) = g.utilization_percent {
+        out.push(label(Metric::gauge(key("utilization"), v)));
+    }
+    if let Some(v) = g.memory_used {
+        out.push(label(Metric::gauge(key("memory_used"), v as f64)));
+    }
+    if let Some(v) = g.memory_total {
+        out.push(label(Metric::gauge(key("memory_total"), v as f64)));
+    }
+    if let Some(v) = g.temperature_c {
+        out.push(label(Metric::gauge(key("temperature"), v)));
+    }
+    if let Some(v) = g.power_watts {
+        out.push(label(Metric::gauge(key("power_watts"), v)));
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1682,6 +1992,93 @@ mod tests {
         }));
     }
 
+    #[test]
+    fn test_cgroup_v2_files_to_metrics() {
+        let files: std::collections::HashMap<&str, &str> = [
+            ("memory.max", "536870912\n"),
+            ("memory.current", "134217728\n"),
+            ("cpu.max", "150000 100000\n"),
+            (
+                "cpu.stat",
+                "usage_usec 10\nnr_periods 40\nnr_throttled 3\nthrottled_usec 900\n",
+            ),
+        ]
+        .into_iter()
+        .collect();
+        let c = cgroup_v2_sample("/", |f| files.get(f).map(|s| s.to_string()));
+        assert!(c.has_limit());
+        let m = map_cgroup(&c);
+        let value = |name: &str| m.iter().find(|x| x.metric == name).map(|x| x.value.clone());
+        assert_eq!(
+            value("cgroup/memory/max"),
+            Some(TelemetryValue::Gauge(536870912.0))
+        );
+        assert_eq!(
+            value("cgroup/memory/current"),
+            Some(TelemetryValue::Gauge(134217728.0))
+        );
+        assert_eq!(
+            value("cgroup/memory/used_percent"),
+            Some(TelemetryValue::Gauge(25.0))
+        );
+        assert_eq!(
+            value("cgroup/cpu/limit_cores"),
+            Some(TelemetryValue::Gauge(1.5))
+        );
+        assert_eq!(
+            value("cgroup/cpu/nr_throttled"),
+            Some(TelemetryValue::Counter(3))
+        );
+    }
+
+    #[test]
+    fn test_cgroup_v2_unlimited_has_no_limit() {
+        let files: std::collections::HashMap<&str, &str> = [
+            ("memory.max", "max\n"),
+            ("memory.current", "4096\n"),
+            ("cpu.max", "max 100000\n"),
+        ]
+        .into_iter()
+        .collect();
+        let c = cgroup_v2_sample("/", |f| files.get(f).map(|s| s.to_string()));
+        assert!(!c.has_limit());
+        let m = map_cgroup(&c);
+        assert!(!m.iter().any(|x| x.metric == "cgroup/memory/max"));
+        assert!(!m.iter().any(|x| x.metric.starts_with("cgroup/cpu/")));
+    }
+
+    #[test]
+    fn test_cgroup_v1_files() {
+        let files: std::collections::HashMap<(&str, &str), &str> = [
+            (("memory", "memory.limit_in_bytes"), "268435456\n"),
+            (("memory", "memory.usage_in_bytes"), "67108864\n"),
+            (("cpu", "cpu.cfs_quota_us"), "50000\n"),
+            (("cpu", "cpu.cfs_period_us"), "100000\n"),
+            (
+                ("cpu", "cpu.stat"),
+                "nr_periods 10\nnr_throttled 2\nthrottled_time 5000000\n",
+            ),
+        ]
+        .into_iter()
+        .collect();
+        let c = cgroup_v1_sample("/docker/abc", |ctl, f| {
+            files.get(&(ctl, f)).map(|s| s.to_string())
+        });
+        assert_eq!(c.memory_max, Some(268435456));
+        assert_eq!(c.memory_current, Some(67108864));
+        assert_eq!(c.cpu_quota_us, Some(50000));
+        assert_eq!(c.cpu_throttled_usec, Some(5000));
+
+        // Unlimited v1 values.
+        assert_eq!(parse_cgroup_v1_limit("-1\n"), None);
+        assert_eq!(parse_cgroup_v1_limit("9223372036854771712\n"), None);
+        assert_eq!(
+            parse_own_cgroup_v1("12:memory:/docker/abc\n4:cpu,cpuacct:/docker/abc\n", "cpu")
+                .as_deref(),
+            Some("/docker/abc")
+        );
+    }
+
     #[test]
     fn test_map_cgroup_unlimited_memory_no_pct() {
         // memory.max == "max" => memory_max None => no used_percent emitted.
@@ -2269,4 +2666,154 @@ mod tests {
         assert!(!def.available);
         assert_eq!(def.runqlat.total, 0);
     }
+
+    // --- I. GPU ----------------------------------------------------------
+
+    #[cfg(feature = "gpu")]
+    #[test]
+    fn test_gpu_sample_and_map() {
+        let files: std::collections::HashMap<&str, &str> = [
+            ("gpu_busy_percent", "87\n"),
+            ("mem_info_vram_used", "2147483648\n"),
+            ("mem_info_vram_total", "8589934592\n"),
+            ("hwmon/temp1_input", "65000\n"),
+            ("hwmon/power1_average", "120500000\n"),
+        ]
+        .into_iter()
+        .collect();
+        let mut g = gpu_sample(1, |f| files.get(f).map(|s| s.to_string()));
+        g.driver = Some("amdgpu".to_string());
+        let m = map_gpu(&g);
+        let value = |name: &str| m.iter().find(|x| x.metric == name).map(|x| x.value.clone());
+        assert_eq!(
+            value("gpu/1/utilization"),
+            Some(TelemetryValue::Gauge(87.0))
+        );
+        assert_eq!(
+            value("gpu/1/memory_used"),
+            Some(TelemetryValue::Gauge(2147483648.0))
+        );
+        assert_eq!(
+            value("gpu/1/memory_total"),
+            Some(TelemetryValue::Gauge(8589934592.0))
+        );
+        assert_eq!(
+            value("gpu/1/temperature"),
+            Some(TelemetryValue::Gauge(65.0))
+        );
+        assert_eq!(
+            value("gpu/1/power_watts"),
+            Some(TelemetryValue::Gauge(120.5))
+        );
+        assert!(m.iter().all(|x| {
+            x.labels.contains(&("gpu", "1".to_string()))
+                && x.labels.contains(&("driver", "amdgpu".to_string()))
+        }));
+    }
+
+    #[cfg(feature = "gpu")]
+    #[test]
+    fn test_nvml_gpu_sample_and_map() {
+        let readings = NvmlReadings {
+            utilization_percent: Some(63),
+            memory: Some((2 << 30, 8 << 30)),
+            temperature_c: Some(71),
+            power_mw: Some(182_500),
+        };
+        let m = map_gpu(&nvml_gpu_sample(0, &readings));
+        let value = |name: &str| m.iter().find(|x| x.metric == name).map(|x| x.value.clone());
+        let gauge = |v: f64| Some(TelemetryValue::Gauge(v));
+        assert_eq!(value("gpu/0/utilization"), gauge(63.0));
+        assert_eq!(value("gpu/0/memory_used"), gauge(2147483648.0));
+        assert_eq!(value("gpu/0/memory_total"), gauge(8589934592.0));
+        assert_eq!(value("gpu/0/temperature"), gauge(71.0));
+        assert_eq!(value("gpu/0/power_watts"), gauge(182.5));
+        assert!(
+            m.iter()
+                .all(|x| x.labels.contains(&("driver", "nvidia".to_string())))
+        );
+
+        // Unsupported calls leave their metric out.
+        let partial = NvmlReadings {
+            temperature_c: Some(40),
+            ..Default::default()
+        };
+        let m = map_gpu(&nvml_gpu_sample(0, &partial));
+        assert_eq!(m.len(), 1);
+        assert_eq!(m[0].metric, "gpu/0/temperature");
+    }
+
+    #[cfg(feature = "gpu")]
+    #[test]
+    fn test_nvml_gpus_are_numbered_after_drm_cards() {
+        let nvml = |index| nvml_gpu_sample(index, &NvmlReadings::default());
+        let drm = |index| GpuSample {
+            index,
+            ..Default::default()
+        };
+
+        let merged = merge_gpu_samples(vec![drm(1)], vec![nvml(0), nvml(1)]);
+        let indices: Vec<u32> = merged.iter().map(|g| g.index).collect();
+        assert_eq!(indices, [1, 2, 3]);
+
+        let merged = merge_gpu_samples(Vec::new(), vec![nvml(0), nvml(1)]);
+        let indices: Vec<u32> = merged.iter().map(|g| g.index).collect();
+        assert_eq!(indices, [0, 1]);
+    }
+
+    #[cfg(feature = "gpu")]
+    #[test]
+    fn test_gpu_without_readings_is_empty() {
+        let g = gpu_sample(0, |_| None);
+        assert!(g.is_empty());
+        assert!(map_gpu(&g).is_empty());
+
+        // Only some files present: only those metrics.
+        let g = gpu_sample(0, |f| {
+            (f == "hwmon/power1_input").then(|| "5000000".to_string())
+        });
+        let m = map_gpu(&g);
+        assert_eq!(m.len(), 1);
+        assert_eq!(m[0].metric, "gpu/0/power_watts");
+        assert_eq!(m[0].value, TelemetryValue::Gauge(5.0));
+    }
+
+    // --- J. process groups -----------------------------------------------
+
+    #[test]
+    fn test_process_groups_sum_by_name() {
+        let groups = group_processes([
+            ("nginx", 1.5, 100),
+            ("postgres", 10.0, 1000),
+            ("nginx", 2.0, 200),
+            ("nginx", 0.5, 300),
+        ]);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].name, "postgres");
+        let nginx = &groups[1];
+        assert_eq!(
+            *nginx,
+            ProcessGroup {
+                name: "nginx".to_string(),
+                cpu: 4.0,
+                memory: 600,
+                count: 3,
+            }
+        );
+
+        let m = map_process_group(nginx);
+        let value = |name: &str| m.iter().find(|x| x.metric == name).map(|x| x.value.clone());
+        assert_eq!(
+            value("process_group/nginx/cpu"),
+            Some(TelemetryValue::Gauge(4.0))
+        );
+        assert_eq!(
+            value("process_group/nginx/memory"),
+            Some(TelemetryValue::Gauge(600.0))
+        );
+        assert_eq!(
+            value("process_group/nginx/count"),
+            Some(TelemetryValue::Gauge(3.0))
+        );
+    }
 }
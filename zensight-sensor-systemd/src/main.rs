@@ -14,6 +14,10 @@ use zensight_sensor_systemd::config::SystemdSensorConfig;
 #[tokio::main]
 async fn main() -> Result<()> {
     let args = SensorArgs::parse_with_default("systemd.json5");
+    if args.print_default_config {
+        print!("{}", SystemdSensorConfig::default_config_json5());
+        return Ok(());
+    }
 
     // Load configuration via the framework's SensorConfig trait.
     let config = SystemdSensorConfig::load(&args.config).map_err(|e| anyhow::anyhow!("{}", e))?;
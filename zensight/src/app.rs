@@ -17,6 +17,13 @@ use zensight_common::{
 /// Flush the metric store to redb every this many 1s ticks (#22).
 const STORE_FLUSH_EVERY_TICKS: u32 = 15;
 
+/// UI tick period while the window is focused.
+const TICK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// UI tick period while the window is unfocused and the app is set to slow
+/// down in the background (#470).
+const BLURRED_TICK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
 /// Evict aged-out buckets every this many flushes (~10 min at 15s/flush, #131).
 /// Pruning scans the whole table, so it runs far less often than flushing.
 const STORE_PRUNE_EVERY_FLUSHES: u32 = 40;
@@ -48,7 +55,8 @@ pub static DEVICE_SEARCH_ID: LazyLock<Id> = LazyLock::new(|| Id::new("device-sea
 use crate::message::{DeviceId, Message};
 use crate::mock;
 use crate::subscription::{
-    demo_subscription, keyboard_subscription, tick_subscription, zenoh_subscription,
+    SubscriptionFilter, demo_subscription, focus_subscription, keyboard_subscription,
+    tick_subscription, topology_layout_subscription, zenoh_subscription,
 };
 use crate::view::alerts::{AlertsState, alerts_view};
 use crate::view::dashboard::{DashboardState, DeviceState, dashboard_view};
@@ -105,14 +113,31 @@ impl AppTheme {
     }
 }
 
+/// A dashboard snapshot open for read-only viewing (#418), holding the live
+/// state it displaced so closing it resumes the live session.
+struct SnapshotView {
+    /// File the snapshot was loaded from.
+    path: String,
+    /// When the snapshot was taken (Unix epoch ms).
+    taken_at: i64,
+    live_devices: std::collections::HashMap<DeviceId, DeviceState>,
+    live_store: crate::store::MetricStore,
+    live_health: std::collections::HashMap<String, HealthSnapshot>,
+}
+
 /// The main ZenSight application.
 pub struct ZenSight {
     /// Zenoh configuration.
     zenoh_config: ZenohConfig,
+    /// Which telemetry keys to subscribe to (#413).
+    subscription_filter: SubscriptionFilter,
     /// Dashboard state.
     dashboard: DashboardState,
     /// Currently selected device (if any).
     selected_device: Option<DeviceDetailState>,
+    /// Secondary device shown in a split pane beside the selected one, for
+    /// side-by-side comparison. Has its own chart state.
+    split_device: Option<DeviceDetailState>,
     /// Settings state.
     settings: SettingsState,
     /// Alerts state.
@@ -132,6 +157,13 @@ pub struct ZenSight {
     current_view: CurrentView,
     /// Stale threshold in milliseconds (devices not updated within this time are marked unhealthy).
     stale_threshold_ms: i64,
+    /// Saved per-metric history cap (#419). Every device view's buffers are
+    /// held to it as telemetry is appended; only a saved change applies, not
+    /// an edit still pending in the settings form.
+    max_history: usize,
+    /// Opt-in history query API (#465): buffers numeric telemetry and, while
+    /// connected, serves it at `zensight/@gui/history/**`.
+    history_query: crate::history_query::HistoryQueryService,
     /// Demo mode (use mock data instead of Zenoh).
     demo_mode: bool,
     /// Current theme.
@@ -176,6 +208,9 @@ pub struct ZenSight {
     ticks_since_flush: u32,
     /// Flushes counted toward the next store prune (#131).
     flushes_since_prune: u32,
+    /// Whether the main window has focus; the tick slows down while it
+    /// doesn't (#470).
+    window_focused: bool,
     /// Timestamp (epoch ms) of the most recently received telemetry point, for
     /// the global Live/Stale/Paused freshness indicator (#23). `None` until the
     /// first point arrives.
@@ -195,6 +230,16 @@ pub struct ZenSight {
     /// Now the (indexed, O(device)) build runs at 1 Hz in `handle_tick`, and the
     /// render just clones this small truncated result.
     dashboard_sparks: crate::view::trend::DeviceSparks,
+    /// App-wide chart time window (#392), set from the device header picker.
+    /// `None` until chosen: charts start at their default window and the
+    /// dashboard sparklines span the whole hot ring.
+    global_time_window: Option<crate::view::chart::TimeWindow>,
+    /// Saved lead sparkline metric per protocol for dashboard cards (#420).
+    card_metrics: std::collections::BTreeMap<Protocol, String>,
+    /// The dashboard snapshot being viewed, if any (#418). While set, the
+    /// dashboard shows the snapshot; live telemetry and health keep flowing
+    /// into the live state parked in it, and alert rules keep running.
+    snapshot_view: Option<SnapshotView>,
 }
 
 impl ZenSight {
@@ -210,14 +255,20 @@ impl ZenSight {
             mode: persistent.zenoh_mode.clone(),
             connect: persistent.zenoh_connect.clone(),
             listen: persistent.zenoh_listen.clone(),
+            multicast_scouting: persistent.zenoh_multicast_scouting,
+            gossip_scouting: persistent.zenoh_gossip_scouting,
+            namespace: Some(persistent.zenoh_namespace.clone()).filter(|ns| !ns.is_empty()),
         }
         .with_env_overrides();
 
         let stale_threshold_ms = (persistent.stale_threshold_secs * 1000) as i64;
 
         let settings = persistent.to_state();
+        let max_history = settings.max_history_value();
 
         let mut dashboard = DashboardState::default();
+        dashboard.max_devices = settings.max_devices_value();
+        dashboard.max_metrics_per_device = settings.max_metrics_value();
 
         // In demo mode, pre-populate with mock data and mark as connected
         if demo_mode {
@@ -245,6 +296,11 @@ impl ZenSight {
         } else {
             AppTheme::Light
         };
+        crate::view::theme::set_color_palette(crate::view::theme::ColorPalette::for_setting(
+            persistent.color_blind_palette,
+        ));
+        crate::view::formatting::set_flow_volume_unit(persistent.flow_volume_unit);
+        crate::view::formatting::set_utc_timestamps(persistent.utc_timestamps);
 
         // Create alerts state with configured max
         let mut alerts = AlertsState::with_max_alerts(persistent.max_alerts);
@@ -273,8 +329,10 @@ impl ZenSight {
             expanded: persistent.overview_expanded,
         };
 
-        // Initialize topology state
-        let topology = TopologyState::default();
+        // Initialize topology state, restoring pinned node positions (#400)
+        let mut topology = TopologyState::default();
+        topology.restore_pinned_positions(persistent.topology_positions.clone());
+        topology.layout_config.max_fps = persistent.topology_max_fps;
 
         // Initialize syslog filter state
         let syslog_filter = SyslogFilterState::default();
@@ -284,8 +342,11 @@ impl ZenSight {
 
         let app = Self {
             zenoh_config,
+            subscription_filter: persistent.subscription_filter.clone(),
+            card_metrics: persistent.card_metrics.clone(),
             dashboard,
             selected_device: None,
+            split_device: None,
             settings,
             alerts,
             groups,
@@ -295,6 +356,10 @@ impl ZenSight {
             recent_logs: std::collections::VecDeque::new(),
             current_view,
             stale_threshold_ms,
+            max_history,
+            history_query: crate::history_query::HistoryQueryService::new(
+                persistent.serve_history_queries,
+            ),
             demo_mode,
             theme,
             sensor_health: std::collections::HashMap::new(),
@@ -322,6 +387,7 @@ impl ZenSight {
             },
             ticks_since_flush: 0,
             flushes_since_prune: 0,
+            window_focused: true,
             // Demo mode pre-loads mock points; treat the feed as fresh on boot.
             last_telemetry_ms: if demo_mode { Some(now_ms()) } else { None },
             global_search: crate::view::search::GlobalSearchState::default(),
@@ -329,11 +395,24 @@ impl ZenSight {
             help_open: false,
             favorites: persistent.favorite_metrics.iter().cloned().collect(),
             dashboard_sparks: crate::view::trend::DeviceSparks::new(),
+            global_time_window: None,
+            snapshot_view: None,
         };
 
         (app, Task::none())
     }
 
+    /// The UI tick period: [`BLURRED_TICK_INTERVAL`] while the window is
+    /// unfocused and the slow-down setting is on (#470), otherwise
+    /// [`TICK_INTERVAL`].
+    pub fn tick_interval(&self) -> std::time::Duration {
+        if !self.window_focused && self.settings.pause_when_unfocused {
+            BLURRED_TICK_INTERVAL
+        } else {
+            TICK_INTERVAL
+        }
+    }
+
     /// Get the window title.
     pub fn title(&self) -> String {
         let device_count = self.dashboard.devices.len();
@@ -344,6 +423,11 @@ impl ZenSight {
         }
     }
 
+    /// The three-state connection status the header shows (#427).
+    pub fn connection_status(&self) -> crate::view::dashboard::ConnectionStatus {
+        self.dashboard.connection_state.status()
+    }
+
     /// Handle incoming messages.
     /// #132: chart / metric-selection interactions, all scoped to the selected device.
     ///
@@ -417,12 +501,37 @@ impl ZenSight {
                 }
             }
 
+            Message::ToggleMetricAxis(metric_name) => {
+                if let Some(ref mut device) = self.selected_device {
+                    device.toggle_metric_axis(&metric_name);
+                }
+            }
+
+            Message::ToggleMetricInterpolation(metric_name) => {
+                if let Some(ref mut device) = self.selected_device {
+                    device.toggle_metric_interpolation(&metric_name);
+                }
+            }
+
             Message::SetChartTimeWindow(window) => {
                 if let Some(ref mut device) = self.selected_device {
                     device.set_time_window(window);
                 }
             }
 
+            Message::SetGlobalTimeWindow(window) => {
+                self.global_time_window = Some(window);
+                for device in [&mut self.selected_device, &mut self.split_device]
+                    .into_iter()
+                    .flatten()
+                {
+                    device.set_default_time_window(window);
+                }
+                if self.on_dashboard_grid() {
+                    self.rebuild_dashboard_sparks();
+                }
+            }
+
             Message::SetChartCustomMinutes(input) => {
                 if let Some(ref mut device) = self.selected_device {
                     device.set_chart_custom_minutes(input);
@@ -435,6 +544,48 @@ impl ZenSight {
                 }
             }
 
+            Message::ToggleMetricStats => {
+                if let Some(ref mut device) = self.selected_device {
+                    device.toggle_metric_stats();
+                }
+            }
+
+            Message::ToggleMetricFamily(metric) => {
+                if let Some(ref mut device) = self.selected_device {
+                    device.toggle_family(&metric);
+                }
+            }
+
+            Message::SetFamilyAggregate(aggregate) => {
+                if let Some(ref mut device) = self.selected_device {
+                    device.family_aggregate = aggregate;
+                }
+            }
+
+            Message::ToggleMetricTreeView => {
+                if let Some(ref mut device) = self.selected_device {
+                    device.toggle_metric_tree_view();
+                }
+            }
+
+            Message::ToggleMetricTreeNode(path) => {
+                if let Some(ref mut device) = self.selected_device {
+                    device.toggle_tree_node(&path);
+                }
+            }
+
+            Message::ToggleCounterRate => {
+                if let Some(ref mut device) = self.selected_device {
+                    device.toggle_counter_rate();
+                }
+            }
+
+            Message::ToggleChartInterpolation => {
+                if let Some(ref mut device) = self.selected_device {
+                    device.toggle_chart_interpolation();
+                }
+            }
+
             Message::SetChartRangeFrom(input) => {
                 if let Some(ref mut device) = self.selected_device {
                     device.chart_from_input = input;
@@ -654,6 +805,10 @@ impl ZenSight {
                 self.topology.clear_selection();
             }
 
+            Message::TopologyIsolateNode(node_id) => {
+                self.topology.isolate_node(node_id);
+            }
+
             Message::TopologyDragNodeStart(node_id, _x, _y) => {
                 self.topology.start_node_drag(&node_id);
             }
@@ -662,8 +817,11 @@ impl ZenSight {
                 self.topology.update_node_drag(&node_id, x, y);
             }
 
-            Message::TopologyDragNodeEnd(_node_id) => {
-                // Node stays pinned after drag
+            Message::TopologyDragNodeEnd(node_id) => {
+                // Node stays pinned after drag; remember where (#400)
+                if self.topology.end_node_drag(&node_id) {
+                    self.save_topology_positions();
+                }
             }
 
             Message::TopologyPanUpdate(dx, dy) => {
@@ -678,10 +836,22 @@ impl ZenSight {
                 self.topology.zoom_out();
             }
 
+            Message::TopologyZoomAt { zoom_in, anchor } => {
+                self.topology.zoom_at(zoom_in, anchor);
+            }
+
             Message::TopologyZoomReset => {
                 self.topology.reset_zoom();
             }
 
+            Message::TopologySetMinEdgeBytes(bytes) => {
+                self.topology.set_min_edge_bytes(bytes);
+            }
+
+            Message::TopologyLayoutFrame => {
+                self.topology.layout_frame(std::time::Instant::now());
+            }
+
             Message::TopologyToggleAutoLayout => {
                 self.topology.toggle_auto_layout();
             }
@@ -689,6 +859,15 @@ impl ZenSight {
             Message::TopologySetSearch(query) => {
                 self.topology.set_search(query);
             }
+
+            Message::ExportTopology(format) => {
+                let filename = format!(
+                    "zensight_topology_{}.{}",
+                    chrono_timestamp(),
+                    format.extension()
+                );
+                return ControlFlow::Break(export_dialog(filename, format.render(&self.topology)));
+            }
             other => return ControlFlow::Continue(other),
         }
         ControlFlow::Break(Task::none())
@@ -945,6 +1124,7 @@ impl ZenSight {
                 self.topology.update_from_devices(&self.dashboard.devices);
                 self.topology.apply_alerts(&self.alerts.external);
                 self.topology.apply_correlations(&self.correlations);
+                self.topology.apply_groups(&self.groups);
                 let node = hostname
                     .filter(|h| self.topology.nodes.contains_key(h))
                     .or_else(|| self.topology_ip_to_node().get(&ip).cloned());
@@ -1125,6 +1305,21 @@ impl ZenSight {
             ControlFlow::Break(t) => return t,
             ControlFlow::Continue(m) => m,
         };
+        // A snapshot is read-only (#418): live updates go to the parked live
+        // state instead, so nothing is missing once it is closed.
+        if self.snapshot_view.is_some()
+            && matches!(
+                message,
+                Message::TelemetryReceived(_)
+                    | Message::TelemetryBatch(_)
+                    | Message::ProvenanceReceived(_)
+                    | Message::HealthSnapshotReceived(_)
+                    | Message::DeviceLivenessReceived(..)
+                    | Message::TelemetryCsvImported(_)
+            )
+        {
+            return self.with_live_state(|app| app.update(message));
+        }
         match message {
             Message::TelemetryReceived(point) => {
                 self.handle_telemetry(point);
@@ -1136,6 +1331,21 @@ impl ZenSight {
                 }
             }
 
+            Message::ProvenanceReceived(entries) => {
+                for (device_id, metric, meta) in entries {
+                    for detail in [&mut self.selected_device, &mut self.split_device]
+                        .into_iter()
+                        .flatten()
+                        .filter(|d| d.device_id == device_id)
+                    {
+                        detail.provenance.insert(metric.clone(), meta.clone());
+                    }
+                    if let Some(device) = self.dashboard.devices.get_mut(&device_id) {
+                        device.provenance.insert(metric, meta);
+                    }
+                }
+            }
+
             Message::HealthSnapshotReceived(snapshot) => {
                 self.sensor_health.insert(snapshot.sensor.clone(), snapshot);
             }
@@ -1161,6 +1371,17 @@ impl ZenSight {
                 }
             }
 
+            Message::DiscoveryReceived(sensor, event) => {
+                tracing::info!(
+                    sensor = %sensor,
+                    device = %event.device,
+                    kind = %event.kind,
+                    item = ?event.item,
+                    "Sensor discovered a new source"
+                );
+                self.toasts.push(ToastSeverity::Info, event.summary());
+            }
+
             Message::SensorInfoReceived(info) => {
                 self.known_sensors.insert(info.name.clone(), info);
             }
@@ -1223,13 +1444,22 @@ impl ZenSight {
 
             Message::Connecting => {
                 tracing::info!("Connecting to Zenoh...");
-                self.dashboard.connection_state =
-                    crate::view::dashboard::ConnectionState::Connecting;
+                // Any attempt after the first — the subscription retrying a
+                // failure, or restarting on a settings change — is a reconnect.
+                self.dashboard.connection_state = if self.dashboard.connection_state
+                    == crate::view::dashboard::ConnectionState::Disconnected
+                    && self.dashboard.last_error.is_none()
+                {
+                    crate::view::dashboard::ConnectionState::Connecting
+                } else {
+                    crate::view::dashboard::ConnectionState::Reconnecting
+                };
             }
 
             Message::Connected(session) => {
                 tracing::info!("Connected to Zenoh");
                 self.session = session;
+                self.history_query.attach(self.session.as_deref());
                 self.dashboard.connected = true;
                 self.dashboard.connection_state =
                     crate::view::dashboard::ConnectionState::Connected;
@@ -1239,6 +1469,7 @@ impl ZenSight {
             Message::Disconnected(error) => {
                 tracing::warn!(error = %error, "Disconnected from Zenoh");
                 self.session = None;
+                self.history_query.detach();
                 self.dashboard.connected = false;
                 self.dashboard.connection_state =
                     crate::view::dashboard::ConnectionState::Disconnected;
@@ -1291,12 +1522,11 @@ impl ZenSight {
 
             Message::InvestigateAlert { device, metric } => {
                 // #35: alert → device → metric → chart in one hop.
-                self.global_search.close();
-                let task = self.select_device(device);
-                if let (Some(metric), Some(d)) = (metric, self.selected_device.as_mut()) {
-                    d.select_metric(metric);
-                }
-                return task;
+                return self.open_device_metric(device, metric);
+            }
+
+            Message::SelectDeviceMetric { device, metric } => {
+                return self.open_device_metric(device, Some(metric));
             }
 
             Message::SelectAdjacentDevice { forward } => {
@@ -1320,9 +1550,37 @@ impl ZenSight {
 
             Message::ClearSelection => {
                 self.selected_device = None;
+                self.split_device = None;
                 self.set_view(CurrentView::Dashboard);
             }
 
+            Message::OpenDeviceInSplit(device_id) => {
+                self.global_search.close();
+                if self.selected_device.is_none() {
+                    return self.select_device(device_id);
+                }
+                return self.open_split(device_id);
+            }
+
+            Message::CloseSplit => {
+                self.split_device = None;
+            }
+
+            Message::SplitPane(inner) => {
+                // Run the full update against the split pane's state by
+                // swapping it into the primary slot for the duration, with
+                // the primary parked so no handler can reach it. Whatever the
+                // pane's state became (a handler may have navigated or cleared
+                // it) goes back in the split slot, and the task's replies are
+                // routed to the pane too.
+                if let Some(split) = self.split_device.take() {
+                    let primary = std::mem::replace(&mut self.selected_device, Some(split));
+                    let task = self.update(*inner);
+                    self.split_device = std::mem::replace(&mut self.selected_device, primary);
+                    return task.map(|m| Message::SplitPane(Box::new(m)));
+                }
+            }
+
             Message::ToggleProtocolFilter(protocol) => {
                 self.dashboard.toggle_filter(protocol);
             }
@@ -1353,9 +1611,13 @@ impl ZenSight {
 
             Message::Tick => {
                 self.handle_tick();
+                // History queries (#465) are answered from the store here, on
+                // the thread that owns it.
+                self.history_query.answer_pending(&self.store);
                 // Periodically flush downsampled buckets to redb off the UI thread
                 // (every ~15 ticks ≈ 15s). Never block update()/view() on disk I/O.
-                self.ticks_since_flush += 1;
+                // Count in 1s units so a slowed tick (#470) keeps the ~15s cadence.
+                self.ticks_since_flush += self.tick_interval().as_secs().max(1) as u32;
                 if self.ticks_since_flush >= STORE_FLUSH_EVERY_TICKS {
                     self.ticks_since_flush = 0;
                     let metric_batch = self.store.take_flush_batch();
@@ -1415,6 +1677,11 @@ impl ZenSight {
             },
 
             Message::DeviceHistoryLoaded(device_id, series) => {
+                if let Some(ref mut split) = self.split_device
+                    && split.device_id == device_id
+                {
+                    split.seed_history(series.clone());
+                }
                 if let Some(ref mut selected) = self.selected_device
                     && selected.device_id == device_id
                 {
@@ -1425,6 +1692,7 @@ impl ZenSight {
             // Settings messages
             Message::OpenDashboard => {
                 self.selected_device = None;
+                self.split_device = None;
                 self.set_view(CurrentView::Dashboard);
             }
 
@@ -1508,6 +1776,34 @@ impl ZenSight {
                 self.settings.set_listen(endpoints);
             }
 
+            Message::SetZenohNamespace(namespace) => {
+                self.settings.set_namespace(namespace);
+            }
+
+            Message::SetMulticastScouting(enabled) => {
+                self.settings.set_multicast_scouting(enabled);
+            }
+
+            Message::SetGossipScouting(enabled) => {
+                self.settings.set_gossip_scouting(enabled);
+            }
+
+            Message::SetSubscribeAllow(allow) => {
+                self.settings.set_subscribe_allow(allow);
+            }
+
+            Message::SetSubscribeDeny(deny) => {
+                self.settings.set_subscribe_deny(deny);
+            }
+
+            Message::SetSubscribeMaxRate(max_rate) => {
+                self.settings.set_subscribe_max_rate(max_rate);
+            }
+
+            Message::SetCardMetrics(card_metrics) => {
+                self.settings.set_card_metrics(card_metrics);
+            }
+
             Message::SetStaleThreshold(threshold) => {
                 self.settings.set_stale_threshold(threshold);
             }
@@ -1520,6 +1816,18 @@ impl ZenSight {
                 self.settings.set_max_alerts(max_alerts);
             }
 
+            Message::SetMaxDevices(max_devices) => {
+                self.settings.set_max_devices(max_devices);
+            }
+
+            Message::SetMaxMetrics(max_metrics) => {
+                self.settings.set_max_metrics(max_metrics);
+            }
+
+            Message::SetTopologyMaxFps(max_fps) => {
+                self.settings.set_topology_max_fps(max_fps);
+            }
+
             Message::SaveSettings => {
                 self.save_settings();
             }
@@ -1564,6 +1872,14 @@ impl ZenSight {
                 self.alerts.set_new_rule_severity(severity);
             }
 
+            Message::SetAlertRuleKind(kind) => {
+                self.alerts.set_new_rule_kind(kind);
+            }
+
+            Message::SetAlertRuleWindow(window) => {
+                self.alerts.set_new_rule_window(window);
+            }
+
             Message::AddAlertRule => {
                 if let Err(e) = self.alerts.add_rule() {
                     tracing::warn!(error = %e, "Failed to add alert rule");
@@ -1590,6 +1906,45 @@ impl ZenSight {
                 let _ = self.alerts.test_rule(&metrics);
             }
 
+            Message::ExportAlertRules => {
+                return export_dialog(
+                    "zensight-alert-rules.json".to_string(),
+                    crate::view::alerts::rules_to_json(&self.alerts.rules),
+                );
+            }
+
+            Message::PickAlertRules(mode) => {
+                return pick_alert_rules(mode);
+            }
+
+            Message::ImportAlertRules(path, mode) => {
+                return Task::future(async move {
+                    let result = match tokio::fs::read_to_string(&path).await {
+                        Ok(contents) => crate::view::alerts::rules_from_json(&contents)
+                            .map(|rules| (path.display().to_string(), rules)),
+                        Err(e) => Err(e.to_string()),
+                    };
+                    Message::AlertRulesLoaded(result, mode)
+                });
+            }
+
+            Message::AlertRulesLoaded(result, mode) => match result {
+                Ok((path, rules)) => {
+                    let count = self.alerts.import_rules(rules, mode);
+                    self.save_alert_rules();
+                    tracing::info!(path = %path, count, ?mode, "Imported alert rules");
+                    self.toasts.push(
+                        ToastSeverity::Success,
+                        format!("Imported {count} alert rule(s) from {path}"),
+                    );
+                }
+                Err(e) => {
+                    tracing::error!(error = %e, "Alert rule import failed");
+                    self.toasts
+                        .push(ToastSeverity::Error, format!("Rule import failed: {e}"));
+                }
+            },
+
             Message::RemoveAlertRule(rule_id) => {
                 self.alerts.remove_rule(rule_id);
                 self.save_alert_rules();
@@ -1608,6 +1963,10 @@ impl ZenSight {
                 self.alerts.acknowledge_all();
             }
 
+            Message::AcknowledgeAlertsMatching(filter) => {
+                self.alerts.acknowledge_matching(&filter);
+            }
+
             Message::AcknowledgeExternalSource(source) => {
                 self.alerts.acknowledge_external_source(&source);
             }
@@ -1619,7 +1978,10 @@ impl ZenSight {
                 self.alerts.silence_source(&source, now_ms(), duration_ms);
                 self.toasts.push(
                     ToastSeverity::Info,
-                    format!("Silenced {source} for {}", fmt_duration_ms(duration_ms)),
+                    format!(
+                        "Silenced {source} for {}",
+                        crate::view::formatting::format_duration(duration_ms)
+                    ),
                 );
             }
             Message::UnsilenceSource(source) => {
@@ -1647,7 +2009,7 @@ impl ZenSight {
                 self.save_alert_filter_presets();
             }
 
-            Message::ToggleHelp => {
+            Message::ToggleHelpOverlay => {
                 self.help_open = !self.help_open;
             }
 
@@ -1700,6 +2062,16 @@ impl ZenSight {
                 }
             }
 
+            Message::ExportInventory(format) => {
+                let rows = crate::device_inventory::collect(&self.dashboard.devices, &self.alerts);
+                let filename = format!(
+                    "zensight_inventory_{}.{}",
+                    chrono_timestamp(),
+                    format.extension()
+                );
+                return export_dialog(filename, format.render(&rows));
+            }
+
             Message::ExportFinished(result) => match result {
                 Ok(Some(path)) => {
                     tracing::info!(path = %path, "Exported device data");
@@ -1715,6 +2087,80 @@ impl ZenSight {
                 }
             },
 
+            Message::PickTelemetryCsv => {
+                return pick_telemetry_csv();
+            }
+
+            Message::ImportTelemetryCsv(path) => {
+                return Task::future(async move {
+                    let result = match tokio::fs::read_to_string(&path).await {
+                        Ok(contents) => crate::import::parse_telemetry_csv(&contents)
+                            .map(|import| (path.display().to_string(), import)),
+                        Err(e) => Err(e.to_string()),
+                    };
+                    Message::TelemetryCsvImported(result)
+                });
+            }
+
+            Message::TelemetryCsvImported(result) => match result {
+                Ok((path, import)) => {
+                    let devices = self.import_telemetry(import.points);
+                    tracing::info!(path = %path, devices, skipped = import.skipped, "Imported telemetry CSV");
+                    let skipped = match import.skipped {
+                        0 => String::new(),
+                        n => format!(" ({n} rows skipped)"),
+                    };
+                    self.toasts.push(
+                        ToastSeverity::Success,
+                        format!("Imported {devices} devices from {path}{skipped}"),
+                    );
+                }
+                Err(e) => {
+                    tracing::error!(error = %e, "Telemetry CSV import failed");
+                    self.toasts
+                        .push(ToastSeverity::Error, format!("Import failed: {e}"));
+                }
+            },
+
+            Message::ExportSnapshot => {
+                return self.export_snapshot();
+            }
+
+            Message::PickSnapshot => {
+                return pick_snapshot();
+            }
+
+            Message::ImportSnapshot(path) => {
+                return Task::future(async move {
+                    let result = match tokio::fs::read_to_string(&path).await {
+                        Ok(contents) => crate::state_snapshot::StateSnapshot::from_json(&contents)
+                            .map(|snapshot| (path.display().to_string(), snapshot)),
+                        Err(e) => Err(e.to_string()),
+                    };
+                    Message::SnapshotLoaded(result)
+                });
+            }
+
+            Message::SnapshotLoaded(result) => match result {
+                Ok((path, snapshot)) => {
+                    tracing::info!(path = %path, devices = snapshot.devices.len(), "Opened dashboard snapshot");
+                    self.toasts.push(
+                        ToastSeverity::Info,
+                        format!("Viewing snapshot {path} (read-only)"),
+                    );
+                    self.open_snapshot(path, snapshot);
+                }
+                Err(e) => {
+                    tracing::error!(error = %e, "Snapshot import failed");
+                    self.toasts
+                        .push(ToastSeverity::Error, format!("Snapshot import failed: {e}"));
+                }
+            },
+
+            Message::CloseSnapshot => {
+                self.close_snapshot();
+            }
+
             // Debug-report download (#197)
             Message::DownloadDebugReport(key_prefix) => {
                 if let Some(task) = self.start_report_download(key_prefix) {
@@ -1881,6 +2327,51 @@ impl ZenSight {
                 self.save_notification_pref();
             }
 
+            Message::TogglePayloadInspector => {
+                self.settings.payload_inspector = !self.settings.payload_inspector;
+                self.save_inspector_pref();
+            }
+
+            Message::ToggleHistoryQueries => {
+                self.settings.serve_history_queries = !self.settings.serve_history_queries;
+                self.history_query
+                    .set_enabled(self.settings.serve_history_queries, self.session.as_deref());
+                self.save_history_query_pref();
+            }
+
+            Message::ToggleColorBlindPalette => {
+                self.settings.color_blind_palette = !self.settings.color_blind_palette;
+                crate::view::theme::set_color_palette(
+                    crate::view::theme::ColorPalette::for_setting(
+                        self.settings.color_blind_palette,
+                    ),
+                );
+                self.save_color_palette_pref();
+            }
+
+            Message::SetFlowVolumeUnit(unit) => {
+                self.settings.flow_volume_unit = unit;
+                crate::view::formatting::set_flow_volume_unit(unit);
+                // Topology edge labels are drawn into the canvas cache.
+                self.topology.cache.clear();
+                self.save_flow_volume_unit_pref();
+            }
+
+            Message::ToggleUtcTimestamps => {
+                self.settings.utc_timestamps = !self.settings.utc_timestamps;
+                crate::view::formatting::set_utc_timestamps(self.settings.utc_timestamps);
+                self.save_utc_timestamps_pref();
+            }
+
+            Message::TogglePauseWhenUnfocused => {
+                self.settings.pause_when_unfocused = !self.settings.pause_when_unfocused;
+                self.save_pause_when_unfocused_pref();
+            }
+
+            Message::WindowFocusChanged(focused) => {
+                self.window_focused = focused;
+            }
+
             // Keyboard shortcuts
             Message::FocusSearch => {
                 return self.focus_search();
@@ -1907,6 +2398,7 @@ impl ZenSight {
                 self.topology.update_from_devices(&self.dashboard.devices);
                 self.topology.apply_alerts(&self.alerts.external);
                 self.topology.apply_correlations(&self.correlations);
+                self.topology.apply_groups(&self.groups);
                 self.set_view(CurrentView::Topology);
                 self.save_current_view();
                 // Derive real edges from observed flows (#25) and netlink
@@ -2348,6 +2840,60 @@ impl ZenSight {
         }
     }
 
+    /// Persist the payload-inspector toggle (#414).
+    fn save_inspector_pref(&self) {
+        let mut persistent = PersistentSettings::load();
+        persistent.payload_inspector = self.settings.payload_inspector;
+        if let Err(e) = persistent.save() {
+            tracing::error!("Failed to save payload inspector preference: {}", e);
+        }
+    }
+
+    /// Persist the color-blind-safe palette toggle (#472).
+    fn save_color_palette_pref(&self) {
+        let mut persistent = PersistentSettings::load();
+        persistent.color_blind_palette = self.settings.color_blind_palette;
+        if let Err(e) = persistent.save() {
+            tracing::error!("Failed to save color palette preference: {}", e);
+        }
+    }
+
+    /// Persist the flow volume unit (#477).
+    fn save_flow_volume_unit_pref(&self) {
+        let mut persistent = PersistentSettings::load();
+        persistent.flow_volume_unit = self.settings.flow_volume_unit;
+        if let Err(e) = persistent.save() {
+            tracing::error!("Failed to save flow volume unit preference: {}", e);
+        }
+    }
+
+    /// Persist the UTC/local time toggle (#440).
+    fn save_utc_timestamps_pref(&self) {
+        let mut persistent = PersistentSettings::load();
+        persistent.utc_timestamps = self.settings.utc_timestamps;
+        if let Err(e) = persistent.save() {
+            tracing::error!("Failed to save UTC time preference: {}", e);
+        }
+    }
+
+    /// Persist the slow-down-when-unfocused toggle (#470).
+    fn save_pause_when_unfocused_pref(&self) {
+        let mut persistent = PersistentSettings::load();
+        persistent.pause_when_unfocused = self.settings.pause_when_unfocused;
+        if let Err(e) = persistent.save() {
+            tracing::error!("Failed to save unfocused-tick preference: {}", e);
+        }
+    }
+
+    /// Persist the history-query toggle (#465).
+    fn save_history_query_pref(&self) {
+        let mut persistent = PersistentSettings::load();
+        persistent.serve_history_queries = self.settings.serve_history_queries;
+        if let Err(e) = persistent.save() {
+            tracing::error!("Failed to save history query preference: {}", e);
+        }
+    }
+
     /// The favorited metric names for `device_id` (#27), projected out of the
     /// global `protocol/source/metric` favorites set.
     fn device_favorites(&self, device_id: &DeviceId) -> std::collections::HashSet<String> {
@@ -2367,6 +2913,15 @@ impl ZenSight {
         }
     }
 
+    /// Persist pinned topology node positions (#400).
+    fn save_topology_positions(&self) {
+        let mut persistent = PersistentSettings::load();
+        persistent.topology_positions = self.topology.pinned_positions().clone();
+        if let Err(e) = persistent.save() {
+            tracing::error!("Failed to save topology positions: {}", e);
+        }
+    }
+
     /// Save current view to persistent settings.
     fn save_current_view(&self) {
         let mut persistent = PersistentSettings::load();
@@ -2382,14 +2937,25 @@ impl ZenSight {
         // Populate card sparklines immediately on entering a grid view so they
         // don't blink empty for up to a tick (they're otherwise rebuilt at 1 Hz).
         if self.on_dashboard_grid() {
-            self.dashboard_sparks = crate::view::trend::build_device_sparks(
-                &self.store,
-                self.dashboard.devices.keys(),
-                2,
-            );
+            self.rebuild_dashboard_sparks();
         }
     }
 
+    /// Rebuild the dashboard-card sparklines from the store's hot ring, limited
+    /// to the global time window when one is set (#392).
+    fn rebuild_dashboard_sparks(&mut self) {
+        let since_ms = self
+            .global_time_window
+            .map(|window| now_ms() - window.duration_ms());
+        self.dashboard_sparks = crate::view::trend::build_device_sparks(
+            &self.store,
+            self.dashboard.devices.keys(),
+            2,
+            since_ms,
+            &self.card_metrics,
+        );
+    }
+
     /// Focus the appropriate search input based on current view.
     fn focus_search(&self) -> Task<Message> {
         match self.current_view {
@@ -3572,25 +4138,29 @@ impl ZenSight {
 
     /// Create subscriptions for Zenoh telemetry and periodic updates.
     pub fn subscription(&self) -> Subscription<Message> {
-        if self.demo_mode {
-            // In demo mode, use mock data generator instead of Zenoh
-            Subscription::batch([
-                demo_subscription(),
-                tick_subscription(),
-                keyboard_subscription(),
-            ])
-        } else {
-            Subscription::batch([
-                zenoh_subscription(self.zenoh_config.clone()),
-                tick_subscription(),
-                keyboard_subscription(),
-            ])
+        let mut subscriptions = vec![
+            if self.demo_mode {
+                // In demo mode, use mock data generator instead of Zenoh
+                demo_subscription()
+            } else {
+                zenoh_subscription(self.zenoh_config.clone(), self.subscription_filter.clone())
+            },
+            tick_subscription(self.tick_interval()),
+            keyboard_subscription(),
+            focus_subscription(),
+        ];
+        // Layout frames only while the open topology is still settling (#473).
+        if self.current_view == CurrentView::Topology && self.topology.needs_layout() {
+            subscriptions.push(topology_layout_subscription(
+                self.topology.layout_config.frame_interval(),
+            ));
         }
+        Subscription::batch(subscriptions)
     }
 
     /// Render the view.
     pub fn view(&self) -> Element<'_, Message> {
-        use iced::widget::{Stack, row};
+        use iced::widget::{Stack, column, row, rule};
 
         // Badge counts both unacknowledged rule alerts and active sensor-pushed
         // alerts (anomalies + expectation violations).
@@ -3671,12 +4241,37 @@ impl ZenSight {
                             active: d.id == device_state.device_id,
                         })
                         .collect();
-                    crate::view::device::host_detail_view(
+                    let primary = crate::view::device::host_detail_view(
                         device_state,
                         &self.syslog_filter,
                         &host_logs,
                         &facets,
-                    )
+                    );
+                    // #414: the raw-payload inspector docks under the device.
+                    let primary = if self.settings.payload_inspector {
+                        column![
+                            container(primary).height(Length::FillPortion(2)),
+                            rule::horizontal(1),
+                            container(crate::view::inspector::inspector_panel(device_state))
+                                .height(Length::FillPortion(1)),
+                        ]
+                        .into()
+                    } else {
+                        primary
+                    };
+                    // #391: a second device opened for comparison sits beside
+                    // the primary in an equal-width split.
+                    match self.split_device {
+                        Some(ref split) => row![
+                            container(primary).width(Length::FillPortion(1)),
+                            rule::vertical(1),
+                            container(crate::view::device::split_pane_view(split))
+                                .width(Length::FillPortion(1)),
+                        ]
+                        .height(Length::Fill)
+                        .into(),
+                        None => primary,
+                    }
                 } else {
                     dashboard_view(
                         &self.dashboard,
@@ -3700,6 +4295,16 @@ impl ZenSight {
             ),
         };
 
+        // #418: a loaded snapshot is flagged on every page, with the way out.
+        let main_view: Element<'_, Message> = match self.snapshot_view {
+            Some(ref snapshot) => column![
+                crate::view::dashboard::snapshot_banner(&snapshot.path, snapshot.taken_at),
+                main_view
+            ]
+            .into(),
+            None => main_view,
+        };
+
         // Wrap the page in the persistent shell (left nav rail + top bar with
         // breadcrumb, alert badge, and connection status visible on every screen).
         let device_name = self
@@ -3830,6 +4435,15 @@ impl ZenSight {
                 liveness.consecutive_failures,
                 liveness.last_error,
             );
+            // Keep open detail views' failure line current (#454).
+            let failure = device_state.failure_summary();
+            for detail in [&mut self.selected_device, &mut self.split_device]
+                .into_iter()
+                .flatten()
+                .filter(|d| d.device_id == device_id)
+            {
+                detail.failure = failure.clone();
+            }
         }
         // Note: We don't create new devices from liveness data alone
         // They should be created when telemetry arrives
@@ -3910,6 +4524,7 @@ impl ZenSight {
         let device_id = DeviceId::from_telemetry(&point);
 
         // Update dashboard device state
+        let is_new_device = !self.dashboard.devices.contains_key(&device_id);
         let device_state = self
             .dashboard
             .devices
@@ -3922,20 +4537,49 @@ impl ZenSight {
         // the latest point per metric would grow the device map without bound (one
         // entry per log line). They live in `recent_logs` instead; here we only
         // refresh liveness. All other telemetry keeps last-value-per-metric.
-        let is_log_event = point.protocol == zensight_common::Protocol::Logs
-            && point.metric.starts_with("events/");
-        if !is_log_event {
+        let is_log_event = is_log_event(&point);
+        let previous = if is_log_event {
+            None
+        } else {
             device_state
                 .metrics
-                .insert(point.metric.clone(), point.clone());
+                .insert(point.metric.clone(), point.clone())
+        };
+        let is_new_metric = !is_log_event && previous.is_none();
+        if let Some(previous) = &previous {
+            device_state.update_rate(previous, &point);
+            device_state.note_repeat(&point.metric);
         }
         device_state.metric_count = device_state.metrics.len();
 
-        // Check alert rules for numeric values
+        // Soft caps (#425): only growth can push past them, so only check then.
+        if is_new_metric {
+            self.dashboard.enforce_metric_cap(&device_id, &point.metric);
+        }
+        if is_new_device {
+            self.dashboard.enforce_device_cap(&device_id);
+        }
+
+        // Absence rules (#423) watch every report, numeric or not. They are
+        // judged against the local clock, so record when it arrived here.
+        self.alerts.note_seen(&device_id, &point.metric, now_ms());
+
+        // Check alert rules for numeric values. Rate-of-change rules read the
+        // metric's hot ring, which already holds this point.
+        let store = &self.store;
         if let Some(numeric_value) = telemetry_to_f64(&point.value)
-            && let Some(alert) =
-                self.alerts
-                    .check_metric(&device_id, &point.metric, numeric_value, point.timestamp)
+            && let Some(alert) = self.alerts.check_metric_with_history(
+                &device_id,
+                &point.metric,
+                numeric_value,
+                point.timestamp,
+                || {
+                    store.hot_samples(&format!(
+                        "{}/{}|{}",
+                        point.protocol, point.source, point.metric
+                    ))
+                },
+            )
         {
             tracing::warn!(
                 rule = %alert.rule_name,
@@ -3947,7 +4591,37 @@ impl ZenSight {
             );
         }
 
-        // Update selected device if this telemetry is for it. Per-line log events
+        // Compound rules (#488) read the device's other current metrics too.
+        if let Some(device) = self.dashboard.devices.get(&device_id) {
+            let value_of = |metric: &str| {
+                device
+                    .metrics
+                    .get(metric)
+                    .and_then(|p| telemetry_to_f64(&p.value))
+            };
+            for alert in
+                self.alerts
+                    .check_compound(&device_id, &point.metric, value_of, point.timestamp)
+            {
+                tracing::warn!(
+                    rule = %alert.rule_name,
+                    device = %alert.device_id,
+                    expression = %alert.metric,
+                    "Alert triggered"
+                );
+            }
+        }
+
+        // The split pane (if any) may show the same or another device; feed it
+        // first so the selected device can take the point by value.
+        if let Some(ref mut split) = self.split_device
+            && split.device_id == device_id
+            && !is_log_event
+        {
+            split.update(point.clone());
+        }
+
+        // Update selected device if this telemetry is for it. Per-line log events
         // are excluded for the same cardinality reason as above (#104).
         if let Some(ref mut selected) = self.selected_device
             && selected.device_id == device_id
@@ -3962,6 +4636,168 @@ impl ZenSight {
         }
     }
 
+    /// Load imported telemetry (#417) as an offline device set. Like a
+    /// snapshot restore (#418), the points go straight into device state and
+    /// the hot history rings — never persisted, never checked against alert
+    /// rules — and every device they touched is marked offline, since no
+    /// sensor stands behind it. Returns how many devices the import touched.
+    fn import_telemetry(&mut self, mut points: Vec<TelemetryPoint>) -> usize {
+        // Oldest first, so the newest point per metric ends up as its value.
+        points.sort_by_key(|point| point.timestamp);
+        let mut imported = std::collections::HashSet::new();
+        for point in points {
+            if let Some(value) = telemetry_to_f64(&point.value) {
+                self.store.restore_hot(
+                    point.protocol.as_str(),
+                    &point.source,
+                    &point.metric,
+                    &[crate::store::Sample {
+                        ts: point.timestamp,
+                        value,
+                    }],
+                );
+            }
+            let id = DeviceId::from_telemetry(&point);
+            let device = self
+                .dashboard
+                .devices
+                .entry(id.clone())
+                .or_insert_with(|| DeviceState::new(id.clone()));
+            device.last_update = device.last_update.max(point.timestamp);
+            if !is_log_event(&point) {
+                device.metrics.insert(point.metric.clone(), point);
+                device.metric_count = device.metrics.len();
+            }
+            imported.insert(id);
+        }
+        for id in &imported {
+            if let Some(device) = self.dashboard.devices.get_mut(id) {
+                device.sensor_status = zensight_common::DeviceStatus::Offline;
+                device.is_healthy = false;
+            }
+        }
+        imported.len()
+    }
+
+    /// Adopt a new per-metric history cap (#419), trimming the open device
+    /// views — primary and split — down to it right away.
+    fn apply_max_history(&mut self, max_history: usize) {
+        self.max_history = max_history;
+        for device in [&mut self.selected_device, &mut self.split_device]
+            .into_iter()
+            .flatten()
+        {
+            device.set_max_history(max_history);
+        }
+    }
+
+    /// Open `device`, pre-selecting `metric` (if any) so its chart shows
+    /// straight away. Closes the global search panel it may come from.
+    fn open_device_metric(&mut self, device: DeviceId, metric: Option<String>) -> Task<Message> {
+        self.global_search.close();
+        let task = self.select_device(device);
+        if let (Some(metric), Some(d)) = (metric, self.selected_device.as_mut()) {
+            d.select_metric(metric);
+        }
+        task
+    }
+
+    /// Export the dashboard — devices, their recent history and sensor health —
+    /// as a snapshot file via a native save dialog (#418).
+    fn export_snapshot(&self) -> Task<Message> {
+        let snapshot = crate::state_snapshot::StateSnapshot::capture(
+            &self.dashboard.devices,
+            &self.store,
+            &self.sensor_health,
+            now_ms(),
+        );
+        let filename = format!("zensight_snapshot_{}.json", chrono_timestamp());
+        export_dialog(filename, snapshot.to_json())
+    }
+
+    /// Swap `snapshot` in for the live dashboard (#418). Opening a second
+    /// snapshot replaces the first but keeps the original live state.
+    fn open_snapshot(&mut self, path: String, snapshot: crate::state_snapshot::StateSnapshot) {
+        let taken_at = snapshot.taken_at;
+        let restored = snapshot.restore();
+        let devices = std::mem::replace(&mut self.dashboard.devices, restored.devices);
+        let store = std::mem::replace(&mut self.store, restored.store);
+        let health = std::mem::replace(&mut self.sensor_health, restored.sensor_health);
+        let view = match self.snapshot_view.take() {
+            Some(previous) => SnapshotView {
+                path,
+                taken_at,
+                ..previous
+            },
+            None => SnapshotView {
+                path,
+                taken_at,
+                live_devices: devices,
+                live_store: store,
+                live_health: health,
+            },
+        };
+        self.snapshot_view = Some(view);
+        self.selected_device = None;
+        self.split_device = None;
+        self.set_view(CurrentView::Dashboard);
+    }
+
+    /// Run `f` against the live state while a snapshot is shown (#418): the
+    /// live devices, store and health are swapped back in for the call, and
+    /// the snapshot's device panes are set aside so live points don't reach
+    /// them. Without a snapshot, just runs `f`.
+    fn with_live_state<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> T {
+        let Some(mut view) = self.snapshot_view.take() else {
+            return f(self);
+        };
+        let swap = |app: &mut Self, view: &mut SnapshotView| {
+            std::mem::swap(&mut app.dashboard.devices, &mut view.live_devices);
+            std::mem::swap(&mut app.store, &mut view.live_store);
+            std::mem::swap(&mut app.sensor_health, &mut view.live_health);
+        };
+        swap(self, &mut view);
+        let selected = self.selected_device.take();
+        let split = self.split_device.take();
+        let out = f(self);
+        self.selected_device = selected;
+        self.split_device = split;
+        swap(self, &mut view);
+        self.snapshot_view = Some(view);
+        out
+    }
+
+    /// Leave snapshot viewing and put the live dashboard back (#418).
+    fn close_snapshot(&mut self) {
+        let Some(view) = self.snapshot_view.take() else {
+            return;
+        };
+        self.dashboard.devices = view.live_devices;
+        self.store = view.live_store;
+        self.sensor_health = view.live_health;
+        self.selected_device = None;
+        self.split_device = None;
+        self.set_view(CurrentView::Dashboard);
+    }
+
+    /// In snapshot mode nothing streams in, so fill a freshly opened detail
+    /// state from the snapshot: the latest points, then the captured history.
+    fn seed_from_snapshot(&self, detail: &mut DeviceDetailState) {
+        if self.snapshot_view.is_none() {
+            return;
+        }
+        let id = detail.device_id.clone();
+        if let Some(device) = self.dashboard.devices.get(&id) {
+            for point in device.metrics.values() {
+                detail.update(point.clone());
+            }
+        }
+        detail.seed_history(
+            self.store
+                .device_hot_samples(id.protocol.as_str(), &id.source),
+        );
+    }
+
     /// Select a device to view in detail. Returns a task that pre-loads this
     /// device's restart-survived history from the local store off the UI thread
     /// (#22), so the detail chart opens pre-populated with persisted trends.
@@ -3995,10 +4831,15 @@ impl ZenSight {
         tracing::info!(device = %device_id, "Selected device");
         // We don't have the full TelemetryPoints in the dashboard,
         // so the detail view will populate as new data arrives
-        let max_history = self.settings.max_history_value();
-        let mut detail_state = DeviceDetailState::with_max_history(device_id.clone(), max_history);
+        let mut detail_state =
+            DeviceDetailState::with_max_history(device_id.clone(), self.max_history);
         // Project this device's favorited metrics (#27) from the global set.
         detail_state.set_favorites(self.device_favorites(&device_id));
+        if let Some(window) = self.global_time_window {
+            detail_state.set_default_time_window(window);
+        }
+        self.seed_from_snapshot(&mut detail_state);
+        self.seed_from_dashboard(&mut detail_state);
         self.selected_device = Some(detail_state);
         self.set_view(CurrentView::Device);
         // Project firing anomalies for this source into the netring view (#253).
@@ -4008,43 +4849,74 @@ impl ZenSight {
         // pre-populated rather than Idle-until-clicked (#127).
         let prefetch = self.prefetch_on_open(&device_id);
 
+        let history = self.device_history_task(device_id);
+
+        Task::batch([history, prefetch])
+    }
+
+    /// Open `device_id` in the secondary split pane (#391) beside the selected
+    /// device. The primary detail is left untouched; the pane gets its own chart
+    /// state and the same persisted-history pre-load as a normal selection.
+    fn open_split(&mut self, device_id: DeviceId) -> Task<Message> {
+        tracing::info!(device = %device_id, "Opened device in split pane");
+        let mut detail_state =
+            DeviceDetailState::with_max_history(device_id.clone(), self.max_history);
+        detail_state.set_favorites(self.device_favorites(&device_id));
+        if let Some(window) = self.global_time_window {
+            detail_state.set_default_time_window(window);
+        }
+        self.seed_from_snapshot(&mut detail_state);
+        self.seed_from_dashboard(&mut detail_state);
+        self.split_device = Some(detail_state);
+        self.device_history_task(device_id)
+    }
+
+    /// Copy what the dashboard already knows about the detail's device: its
+    /// provenance (#444; the subscriber only re-sends it when it changes) and
+    /// its liveness failure streak (#454).
+    fn seed_from_dashboard(&self, detail: &mut DeviceDetailState) {
+        if let Some(device) = self.dashboard.devices.get(&detail.device_id) {
+            detail.provenance = device.provenance.clone();
+            detail.failure = device.failure_summary();
+        }
+    }
+
+    /// Pre-load `device_id`'s restart-survived history from the local store off
+    /// the UI thread (#22), answered with [`Message::DeviceHistoryLoaded`].
+    fn device_history_task(&self, device_id: DeviceId) -> Task<Message> {
         // Resolve the persisted metric ids for this device, then query the warm
         // (minute) tier off-thread. Last 24h of minute buckets is plenty to
         // pre-populate a chart without blocking the UI.
-        let history = 'history: {
-            let Some(store) = self.store.persistent() else {
-                break 'history Task::none();
-            };
-            let protocol = device_id.protocol.to_string();
-            let metric_ids = self.store.device_metric_ids(&protocol, &device_id.source);
-            if metric_ids.is_empty() {
-                break 'history Task::none();
-            }
-            let now = std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .map(|d| d.as_millis() as i64)
-                .unwrap_or(0);
-            let from = now - 24 * 3_600_000; // 24h window
-            Task::future(async move {
-                let series = tokio::task::spawn_blocking(move || {
-                    metric_ids
-                        .into_iter()
-                        .filter_map(|(name, id)| {
-                            store
-                                .query(id, crate::store::Tier::Minute, from, now)
-                                .ok()
-                                .filter(|s| !s.is_empty())
-                                .map(|samples| (name, samples))
-                        })
-                        .collect::<Vec<_>>()
-                })
-                .await
-                .unwrap_or_default();
-                Message::DeviceHistoryLoaded(device_id, series)
-            })
+        let Some(store) = self.store.persistent() else {
+            return Task::none();
         };
-
-        Task::batch([history, prefetch])
+        let protocol = device_id.protocol.to_string();
+        let metric_ids = self.store.device_metric_ids(&protocol, &device_id.source);
+        if metric_ids.is_empty() {
+            return Task::none();
+        }
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as i64)
+            .unwrap_or(0);
+        let from = now - 24 * 3_600_000; // 24h window
+        Task::future(async move {
+            let series = tokio::task::spawn_blocking(move || {
+                metric_ids
+                    .into_iter()
+                    .filter_map(|(name, id)| {
+                        store
+                            .query(id, crate::store::Tier::Minute, from, now)
+                            .ok()
+                            .filter(|s| !s.is_empty())
+                            .map(|samples| (name, samples))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .await
+            .unwrap_or_default();
+            Message::DeviceHistoryLoaded(device_id, series)
+        })
     }
 
     /// Range-query the store for an absolute `[from_ms, to_ms]` window (#36) and
@@ -4115,10 +4987,14 @@ impl ZenSight {
         // Apply max alerts setting
         self.alerts.set_max_alerts(self.settings.max_alerts_value());
 
-        // Apply max history to current device view if any
-        if let Some(ref mut device) = self.selected_device {
-            device.set_max_history(self.settings.max_history_value());
-        }
+        self.apply_max_history(self.settings.max_history_value());
+
+        // Tracking caps (#425) take effect at once, evicting if already over.
+        self.dashboard.set_limits(
+            self.settings.max_devices_value(),
+            self.settings.max_metrics_value(),
+        );
+        self.topology.layout_config.max_fps = self.settings.topology_max_fps_value();
 
         // Update the Zenoh config. The live subscription is keyed on this config
         // (`Subscription::run_with(zenoh_config, …)`), so changing it makes Iced
@@ -4128,17 +5004,33 @@ impl ZenSight {
         let new_mode = self.settings.zenoh_mode.as_str().to_string();
         let new_connect = self.settings.connect_endpoints();
         let new_listen = self.settings.listen_endpoints();
+        // The subscription filter (#413) is part of the same key, so changing
+        // it re-declares the telemetry subscribers the same way.
+        let new_filter = self.settings.subscription_filter();
+        let new_multicast = self.settings.multicast_scouting;
+        let new_gossip = self.settings.gossip_scouting;
+        // A namespace change (#446) moves every key, so it reconnects too.
+        let new_namespace = self.settings.namespace_value();
         let connection_changed = self.zenoh_config.mode != new_mode
             || self.zenoh_config.connect != new_connect
-            || self.zenoh_config.listen != new_listen;
+            || self.zenoh_config.listen != new_listen
+            || self.zenoh_config.multicast_scouting != new_multicast
+            || self.zenoh_config.gossip_scouting != new_gossip
+            || self.zenoh_config.namespace != new_namespace
+            || self.subscription_filter != new_filter;
         self.zenoh_config.mode = new_mode;
         self.zenoh_config.connect = new_connect;
         self.zenoh_config.listen = new_listen;
+        self.zenoh_config.multicast_scouting = new_multicast;
+        self.zenoh_config.gossip_scouting = new_gossip;
+        self.zenoh_config.namespace = new_namespace;
+        self.subscription_filter = new_filter;
+        self.card_metrics = self.settings.card_metrics_value();
 
         if connection_changed && !self.demo_mode {
             // Reflect the impending reconnect immediately; the restarted
             // subscription will drive Connecting → Connected/Disconnected.
-            self.dashboard.connection_state = crate::view::dashboard::ConnectionState::Connecting;
+            self.dashboard.connection_state = crate::view::dashboard::ConnectionState::Reconnecting;
             self.dashboard.connected = false;
             self.toasts.push(
                 ToastSeverity::Info,
@@ -4154,6 +5046,7 @@ impl ZenSight {
         persistent.favorite_metrics = self.favorites.iter().cloned().collect();
         persistent.overview_selected_protocol = self.overview.selected_protocol;
         persistent.overview_expanded = self.overview.expanded;
+        persistent.topology_positions = self.topology.pinned_positions().clone();
         if let Err(error) = persistent.save() {
             self.settings.set_error(error);
             return;
@@ -4221,19 +5114,18 @@ impl ZenSight {
             .map(|d| d.as_millis() as i64)
             .unwrap_or(0);
 
+        // Health scores (#490) also weigh each device's active alerts.
+        let mut alert_loads = self.alerts.alert_load_by_device();
         for device in self.dashboard.devices.values_mut() {
             device.update_health(now, self.stale_threshold_ms);
+            device.alert_load = alert_loads.remove(&device.id).unwrap_or_default();
         }
 
         // Rebuild the dashboard-card sparklines at 1 Hz (only when a card grid is
         // actually showing), so the per-frame render just clones the cached result
         // instead of rescanning the store on every redraw (startup-freeze fix).
         if self.on_dashboard_grid() {
-            self.dashboard_sparks = crate::view::trend::build_device_sparks(
-                &self.store,
-                self.dashboard.devices.keys(),
-                2,
-            );
+            self.rebuild_dashboard_sparks();
         } else if !self.dashboard_sparks.is_empty() {
             self.dashboard_sparks.clear();
         }
@@ -4250,6 +5142,27 @@ impl ZenSight {
         // Expire alert silences whose window has passed (#26).
         self.alerts.prune_silences(now);
 
+        // Unacknowledged alerts past their rule's escalation window (#489).
+        for alert in self.alerts.escalate_due(now) {
+            tracing::warn!(
+                rule = %alert.rule_name,
+                device = %alert.device_id,
+                severity = %alert.severity,
+                "Alert escalated"
+            );
+        }
+
+        // Watched metrics that stopped reporting (#423). Live reports keep
+        // feeding this while a snapshot is shown, so it runs regardless.
+        for alert in self.alerts.check_absent(now) {
+            tracing::warn!(
+                rule = %alert.rule_name,
+                device = %alert.device_id,
+                metric = %alert.metric,
+                "Metric stopped reporting"
+            );
+        }
+
         // Apply debounced search filter
         self.dashboard.apply_pending_search();
 
@@ -4257,6 +5170,9 @@ impl ZenSight {
         if let Some(ref mut device) = self.selected_device {
             device.update_chart_time();
         }
+        if let Some(ref mut device) = self.split_device {
+            device.update_chart_time();
+        }
 
         // Clean up expired toasts
         self.toasts.cleanup_expired();
@@ -4264,10 +5180,8 @@ impl ZenSight {
         // Update topology when viewing it
         if self.current_view == CurrentView::Topology {
             self.topology.update_from_devices(&self.dashboard.devices);
-            // Run layout algorithm if not stable
-            if !self.topology.layout_stable {
-                self.topology.run_layout_step();
-            }
+            self.topology.apply_groups(&self.groups);
+            // Layout steps run on their own throttled frames (#473).
         }
     }
 }
@@ -4315,14 +5229,10 @@ fn prefetch_channels(protocol: zensight_common::Protocol) -> Vec<Message> {
     }
 }
 
-/// Human duration for silence toasts: "1h" / "4h" / "24h" / "30m".
-fn fmt_duration_ms(ms: i64) -> String {
-    let mins = ms / 60_000;
-    if mins % 60 == 0 {
-        format!("{}h", mins / 60)
-    } else {
-        format!("{mins}m")
-    }
+/// Whether `point` is a per-line log event (#104), published under a unique
+/// `events/<uid>` metric that must not be tracked per key.
+pub(crate) fn is_log_event(point: &TelemetryPoint) -> bool {
+    point.protocol == zensight_common::Protocol::Logs && point.metric.starts_with("events/")
 }
 
 /// Convert a telemetry value to f64 for alert checking.
@@ -4408,6 +5318,54 @@ fn export_dialog(default_name: String, contents: String) -> Task<Message> {
     })
 }
 
+/// Native "Open…" for a telemetry CSV to import (#417), resolving to
+/// [`Message::ImportTelemetryCsv`]. Cancelling the dialog is a no-op.
+fn pick_telemetry_csv() -> Task<Message> {
+    Task::future(async move {
+        let mut dialog = rfd::AsyncFileDialog::new().add_filter("CSV", &["csv"]);
+        if let Some(dir) = dirs::download_dir().or_else(dirs::home_dir) {
+            dialog = dialog.set_directory(dir);
+        }
+        dialog
+            .pick_file()
+            .await
+            .map(|handle| Message::ImportTelemetryCsv(handle.path().to_path_buf()))
+    })
+    .and_then(Task::done)
+}
+
+/// Native "Open…" for an alert-rule library (#424), resolving to
+/// [`Message::ImportAlertRules`]. Cancelling the dialog is a no-op.
+fn pick_alert_rules(mode: crate::view::alerts::RuleImportMode) -> Task<Message> {
+    Task::future(async move {
+        let mut dialog = rfd::AsyncFileDialog::new().add_filter("JSON", &["json"]);
+        if let Some(dir) = dirs::download_dir().or_else(dirs::home_dir) {
+            dialog = dialog.set_directory(dir);
+        }
+        dialog
+            .pick_file()
+            .await
+            .map(|handle| Message::ImportAlertRules(handle.path().to_path_buf(), mode))
+    })
+    .and_then(Task::done)
+}
+
+/// Native "Open…" for a dashboard snapshot (#418), resolving to
+/// [`Message::ImportSnapshot`]. Cancelling the dialog is a no-op.
+fn pick_snapshot() -> Task<Message> {
+    Task::future(async move {
+        let mut dialog = rfd::AsyncFileDialog::new().add_filter("JSON", &["json"]);
+        if let Some(dir) = dirs::download_dir().or_else(dirs::home_dir) {
+            dialog = dialog.set_directory(dir);
+        }
+        dialog
+            .pick_file()
+            .await
+            .map(|handle| Message::ImportSnapshot(handle.path().to_path_buf()))
+    })
+    .and_then(Task::done)
+}
+
 /// Native "Save as…" for a downloaded debug report. Unlike [`export_dialog`],
 /// this **moves** an already-verified temp file to the chosen path (falling back
 /// to a streamed copy across filesystems), so a large blob is never read into
@@ -4527,6 +5485,62 @@ mod update_routing_tests {
         ZenSight::boot(true).0
     }
 
+    #[test]
+    fn unfocused_window_slows_the_tick() {
+        let mut a = app();
+        a.settings.pause_when_unfocused = true;
+        assert_eq!(a.tick_interval(), TICK_INTERVAL);
+
+        let _ = a.update(Message::WindowFocusChanged(false));
+        assert!(a.tick_interval() > TICK_INTERVAL);
+        assert_eq!(a.tick_interval(), BLURRED_TICK_INTERVAL);
+
+        let _ = a.update(Message::WindowFocusChanged(true));
+        assert_eq!(a.tick_interval(), TICK_INTERVAL);
+
+        // With the setting off, blur keeps the normal cadence.
+        a.settings.pause_when_unfocused = false;
+        let _ = a.update(Message::WindowFocusChanged(false));
+        assert_eq!(a.tick_interval(), TICK_INTERVAL);
+    }
+
+    #[test]
+    fn connection_status_tracks_reconnects() {
+        use crate::view::dashboard::{ConnectionState, ConnectionStatus};
+        use crate::view::shell::connection_label;
+
+        let mut a = app();
+        a.dashboard.connection_state = ConnectionState::Disconnected;
+        a.dashboard.last_error = None;
+
+        // The first attempt reads as connecting, with the in-flight icon.
+        let _ = a.update(Message::Connecting);
+        assert_eq!(a.connection_status(), ConnectionStatus::Reconnecting);
+        assert_eq!(
+            connection_label(a.dashboard.connection_state),
+            "Connecting…"
+        );
+
+        let _ = a.update(Message::Connected(None));
+        assert_eq!(a.connection_status(), ConnectionStatus::Connected);
+
+        let _ = a.update(Message::Disconnected("link down".to_string()));
+        assert_eq!(a.connection_status(), ConnectionStatus::Disconnected);
+        assert_eq!(
+            connection_label(a.dashboard.connection_state),
+            "Disconnected"
+        );
+
+        // The subscription's retry is a reconnect.
+        let _ = a.update(Message::Connecting);
+        assert_eq!(a.dashboard.connection_state, ConnectionState::Reconnecting);
+        assert_eq!(a.connection_status(), ConnectionStatus::Reconnecting);
+        assert_eq!(
+            connection_label(a.dashboard.connection_state),
+            "Reconnecting…"
+        );
+    }
+
     #[test]
     fn handler_claims_its_own_domain() {
         let mut a = app();
@@ -4575,4 +5589,507 @@ mod update_routing_tests {
         let _ = a.update(Message::ToggleTheme);
         assert_ne!(was_dark, matches!(a.theme, AppTheme::Dark));
     }
+
+    #[test]
+    fn help_overlay_toggles() {
+        let mut a = app();
+        assert!(!a.help_open);
+        let _ = a.update(Message::ToggleHelpOverlay);
+        assert!(a.help_open);
+        let _ = a.update(Message::ToggleHelpOverlay);
+        assert!(!a.help_open);
+    }
+
+    #[test]
+    fn open_in_split_keeps_primary_device_intact() {
+        use zensight_common::Protocol;
+        let mut a = app();
+        let primary = DeviceId::new(Protocol::Snmp, "router-a");
+        let secondary = DeviceId::new(Protocol::Sysinfo, "host-b");
+
+        let _ = a.update(Message::SelectDevice(primary.clone()));
+        let _ = a.update(Message::SelectMetricForChart("cpu".into()));
+        let _ = a.update(Message::OpenDeviceInSplit(secondary.clone()));
+
+        let sel = a.selected_device.as_ref().expect("primary still open");
+        assert_eq!(sel.device_id, primary);
+        assert_eq!(sel.selected_metric.as_deref(), Some("cpu"));
+        assert_eq!(a.current_view, CurrentView::Device);
+        let split = a.split_device.as_ref().expect("split pane open");
+        assert_eq!(split.device_id, secondary);
+
+        // A chart interaction from the pane only touches the pane's chart.
+        let _ = a.update(Message::SplitPane(Box::new(Message::SelectMetricForChart(
+            "mem".into(),
+        ))));
+        assert_eq!(
+            a.selected_device
+                .as_ref()
+                .unwrap()
+                .selected_metric
+                .as_deref(),
+            Some("cpu")
+        );
+        assert_eq!(
+            a.split_device.as_ref().unwrap().selected_metric.as_deref(),
+            Some("mem")
+        );
+
+        // Other device-state messages reach the pane as well.
+        let _ = a.update(Message::SplitPane(Box::new(Message::SystemdSetUnitFilter(
+            Some("failed".into()),
+        ))));
+        assert_eq!(
+            a.split_device
+                .as_ref()
+                .unwrap()
+                .systemd_detail
+                .unit_state_filter
+                .as_deref(),
+            Some("failed")
+        );
+        assert!(
+            a.selected_device
+                .as_ref()
+                .unwrap()
+                .systemd_detail
+                .unit_state_filter
+                .is_none()
+        );
+
+        let _ = a.update(Message::CloseSplit);
+        assert!(a.split_device.is_none());
+        assert_eq!(a.selected_device.as_ref().unwrap().device_id, primary);
+    }
+
+    #[test]
+    fn importing_csv_populates_offline_devices() {
+        use zensight_common::{DeviceStatus, Protocol};
+        let mut a = app();
+        let csv = "timestamp,protocol,source,metric,value,type,labels\n\
+                   1000,snmp,import-r1,ifInOctets,10,counter,ifName=eth0\n\
+                   2000,snmp,import-r1,ifInOctets,25,counter,ifName=eth0\n\
+                   2000,snmp,import-r1,sysUpTime,99.5,gauge,\n\
+                   2000,sysinfo,import-h1,cpu/usage,41.0,gauge,\n";
+        // Would fire on any live ifInOctets report.
+        a.alerts.rules.push(crate::view::alerts::AlertRule::new(
+            99,
+            "octets",
+            "ifInOctets",
+        ));
+        let import = crate::import::parse_telemetry_csv(csv).unwrap();
+        let _ = a.update(Message::TelemetryCsvImported(Ok((
+            "capture.csv".to_string(),
+            import,
+        ))));
+
+        let router = &a.dashboard.devices[&DeviceId::new(Protocol::Snmp, "import-r1")];
+        assert_eq!(router.metric_count, 2);
+        assert_eq!(
+            router.metrics["ifInOctets"].value,
+            TelemetryValue::Counter(25)
+        );
+        assert_eq!(router.metrics["ifInOctets"].labels["ifName"], "eth0");
+        assert_eq!(router.sensor_status, DeviceStatus::Offline);
+
+        let host = &a.dashboard.devices[&DeviceId::new(Protocol::Sysinfo, "import-h1")];
+        assert_eq!(host.metrics["cpu/usage"].value, TelemetryValue::Gauge(41.0));
+
+        // History is charted from the hot ring, but nothing is queued for the
+        // persistent store and no alert rule saw the imported values.
+        assert_eq!(a.store.hot_samples("snmp/import-r1|ifInOctets").len(), 2);
+        assert!(!a.store.has_pending());
+        assert!(a.alerts.alerts.is_empty());
+    }
+
+    #[test]
+    fn absence_is_judged_on_receipt_time_not_sensor_clock() {
+        use crate::view::alerts::{AlertCondition, AlertRule};
+        use zensight_common::Protocol;
+        let mut a = app();
+        let mut rule = AlertRule::new(1, "Uplink gone", "ifInOctets");
+        rule.condition = AlertCondition::Absent { stale_secs: 60 };
+        a.alerts.rules.push(rule);
+
+        // A sensor whose clock runs an hour behind: just received, not silent.
+        let mut point = TelemetryPoint::new(
+            "skewed-r1",
+            Protocol::Snmp,
+            "ifInOctets",
+            TelemetryValue::Counter(1),
+        );
+        point.timestamp = now_ms() - 3_600_000;
+        let _ = a.update(Message::TelemetryReceived(point));
+        let _ = a.update(Message::Tick);
+
+        let device = DeviceId::new(Protocol::Snmp, "skewed-r1");
+        assert!(!a.alerts.is_absent(&device, "ifInOctets"));
+        assert!(a.alerts.alerts.is_empty());
+    }
+
+    #[test]
+    fn global_search_hit_opens_device_with_metric_charted() {
+        use zensight_common::Protocol;
+
+        let mut a = app();
+        for (source, value) in [("gs-r1", 3.0), ("gs-r2", 7.0)] {
+            let point = TelemetryPoint::new(
+                source,
+                Protocol::Snmp,
+                "if/1/ifInErrors",
+                TelemetryValue::Gauge(value),
+            );
+            let _ = a.update(Message::TelemetryReceived(point));
+        }
+        let _ = a.update(Message::OpenGlobalSearch);
+        let _ = a.update(Message::SetGlobalSearch("gs-r2/if/1/ifinerrors".into()));
+
+        let hits =
+            crate::view::search::search(a.dashboard.devices.values(), &a.global_search.query);
+        let hit = hits.first().expect("gs-r2 matches");
+        assert_eq!(hit.device.source, "gs-r2");
+
+        let _ = a.update(Message::SelectDeviceMetric {
+            device: hit.device.clone(),
+            metric: hit.metric.clone(),
+        });
+        assert!(!a.global_search.open);
+        let sel = a.selected_device.as_ref().expect("device opened");
+        assert_eq!(sel.device_id.source, "gs-r2");
+        assert_eq!(sel.selected_metric.as_deref(), Some("if/1/ifInErrors"));
+    }
+
+    #[test]
+    fn inventory_export_has_one_row_per_device() {
+        use crate::device_inventory::{InventoryFormat, collect};
+        use zensight_common::{Alert, AlertKind, AlertSeverity, DeviceStatus, Protocol};
+
+        let mut a = app();
+        let mut point = TelemetryPoint::new(
+            "inv-r1",
+            Protocol::Snmp,
+            "ifInOctets",
+            TelemetryValue::Counter(10),
+        );
+        point.timestamp = 5_000;
+        let _ = a.update(Message::TelemetryReceived(point));
+        let host = TelemetryPoint::new(
+            "inv-h1",
+            Protocol::Sysinfo,
+            "cpu/usage",
+            TelemetryValue::Gauge(12.0),
+        );
+        let _ = a.update(Message::TelemetryReceived(host));
+        let _ = a.update(Message::AlertReceived(Alert::new(
+            "inv-r1",
+            Protocol::Snmp,
+            AlertKind::Anomaly,
+            "if_flap",
+            AlertSeverity::Warning,
+            "eth0 flapping",
+        )));
+
+        let rows = collect(&a.dashboard.devices, &a.alerts);
+        assert_eq!(rows.len(), a.dashboard.devices.len());
+
+        let router = rows.iter().find(|r| r.source == "inv-r1").unwrap();
+        assert_eq!(router.protocol, Protocol::Snmp);
+        assert_eq!(router.last_seen, 5_000);
+        assert_eq!(router.metric_count, 1);
+        assert_eq!(router.active_alerts, 1);
+        assert_ne!(router.health, DeviceStatus::Unknown);
+        let host = rows.iter().find(|r| r.source == "inv-h1").unwrap();
+        assert_eq!(host.protocol, Protocol::Sysinfo);
+        assert_eq!(host.active_alerts, 0);
+        assert!(host.last_seen > 0);
+
+        let csv = InventoryFormat::Csv.render(&rows);
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some("protocol,source,health,last_seen,metric_count,active_alerts,last_error")
+        );
+        assert_eq!(lines.count(), rows.len());
+        assert!(csv.contains("\nsnmp,inv-r1,"));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&InventoryFormat::Json.render(&rows)).unwrap();
+        let entries = json.as_array().unwrap();
+        assert_eq!(entries.len(), rows.len());
+        let entry = entries.iter().find(|e| e["source"] == "inv-r1").unwrap();
+        assert_eq!(entry["protocol"], "snmp");
+        assert_eq!(entry["active_alerts"], 1);
+        assert_eq!(entry["last_seen"], 5_000);
+    }
+
+    #[test]
+    fn snapshot_round_trip_reproduces_devices() {
+        use crate::state_snapshot::StateSnapshot;
+        use zensight_common::Protocol;
+        let mut live = app();
+        let mut point = TelemetryPoint::new(
+            "snap-r1",
+            Protocol::Snmp,
+            "ifInOctets",
+            TelemetryValue::Counter(10),
+        );
+        point.timestamp = 1_000;
+        let _ = live.update(Message::TelemetryReceived(point.clone()));
+        point.timestamp = 2_000;
+        point.value = TelemetryValue::Counter(30);
+        let _ = live.update(Message::TelemetryReceived(point));
+
+        let json = StateSnapshot::capture(
+            &live.dashboard.devices,
+            &live.store,
+            &live.sensor_health,
+            now_ms(),
+        )
+        .to_json();
+        let snapshot = StateSnapshot::from_json(&json).unwrap();
+
+        let mut viewer = app();
+        let _ = viewer.update(Message::SnapshotLoaded(Ok((
+            "support.json".to_string(),
+            snapshot,
+        ))));
+
+        let mut expected: Vec<_> = live.dashboard.devices.keys().collect();
+        let mut restored: Vec<_> = viewer.dashboard.devices.keys().collect();
+        expected.sort_by_key(|id| (id.protocol, id.source.clone()));
+        restored.sort_by_key(|id| (id.protocol, id.source.clone()));
+        assert_eq!(expected, restored);
+        for (id, device) in &live.dashboard.devices {
+            let copy = &viewer.dashboard.devices[id];
+            assert_eq!(copy.metric_count, device.metric_count);
+            for (metric, point) in &device.metrics {
+                assert_eq!(copy.metrics[metric].value, point.value, "{id}: {metric}");
+            }
+        }
+        let history = viewer.store.hot_samples("snmp/snap-r1|ifInOctets");
+        assert_eq!(
+            history.iter().map(|s| s.value).collect::<Vec<_>>(),
+            [10.0, 30.0]
+        );
+
+        // Read-only: live telemetry arriving meanwhile doesn't touch the
+        // snapshot, but lands in the live state and is checked by alert rules.
+        viewer
+            .alerts
+            .rules
+            .push(crate::view::alerts::AlertRule::new(7, "cpu", "cpu"));
+        let live = DeviceId::new(Protocol::Sysinfo, "live-h1");
+        let stray = TelemetryPoint::new(
+            "live-h1",
+            Protocol::Sysinfo,
+            "cpu",
+            TelemetryValue::Gauge(1.0),
+        );
+        let _ = viewer.update(Message::TelemetryReceived(stray));
+        assert!(!viewer.dashboard.devices.contains_key(&live));
+        assert!(viewer.store.hot_samples("sysinfo/live-h1|cpu").is_empty());
+        assert_eq!(viewer.alerts.alerts.len(), 1);
+
+        let _ = viewer.update(Message::CloseSnapshot);
+        assert!(viewer.snapshot_view.is_none());
+        assert!(
+            !viewer
+                .dashboard
+                .devices
+                .contains_key(&DeviceId::new(Protocol::Snmp, "snap-r1"))
+        );
+        assert_eq!(
+            viewer.dashboard.devices[&live].metrics["cpu"].value,
+            TelemetryValue::Gauge(1.0)
+        );
+        assert_eq!(viewer.store.hot_samples("sysinfo/live-h1|cpu").len(), 1);
+    }
+
+    #[test]
+    fn provenance_reaches_dashboard_and_detail_views() {
+        use zensight_common::{Format, Protocol, SampleMetadata};
+        let mut a = app();
+        let id = DeviceId::new(Protocol::Snmp, "prov-r1");
+        let point = TelemetryPoint::new(
+            "prov-r1",
+            Protocol::Snmp,
+            "ifInOctets",
+            TelemetryValue::Counter(1),
+        );
+        let _ = a.update(Message::TelemetryReceived(point));
+        let meta = SampleMetadata::new("snmp", "1.2.3", Format::Cbor);
+        let _ = a.update(Message::ProvenanceReceived(vec![(
+            id.clone(),
+            "ifInOctets".to_string(),
+            meta.clone(),
+        )]));
+        assert_eq!(
+            a.dashboard.devices[&id].provenance.get("ifInOctets"),
+            Some(&meta)
+        );
+
+        // A detail view opened later is seeded from the dashboard...
+        let _ = a.update(Message::SelectDevice(id.clone()));
+        assert_eq!(
+            a.selected_device
+                .as_ref()
+                .unwrap()
+                .provenance
+                .get("ifInOctets"),
+            Some(&meta)
+        );
+
+        // ...and an open one follows changes, per metric: a second bridge
+        // publishing another metric for the same device doesn't overwrite it.
+        let upgraded = SampleMetadata::new("snmp", "1.3.0", Format::Cbor);
+        let other = SampleMetadata::new("snmp-trap", "1.3.0", Format::Cbor);
+        let _ = a.update(Message::ProvenanceReceived(vec![
+            (id.clone(), "ifInOctets".to_string(), upgraded.clone()),
+            (id, "trap/linkDown".to_string(), other.clone()),
+        ]));
+        let detail = a.selected_device.as_ref().unwrap();
+        assert_eq!(detail.provenance.get("ifInOctets"), Some(&upgraded));
+        assert_eq!(detail.provenance.get("trap/linkDown"), Some(&other));
+    }
+
+    #[test]
+    fn failing_liveness_shows_error_and_streak() {
+        use zensight_common::{DeviceLiveness, DeviceStatus, Protocol};
+        let mut a = app();
+        let id = DeviceId::new(Protocol::Snmp, "flaky-r1");
+        let _ = a.update(Message::TelemetryReceived(TelemetryPoint::new(
+            "flaky-r1",
+            Protocol::Snmp,
+            "sysUpTime",
+            TelemetryValue::Counter(1),
+        )));
+        let _ = a.update(Message::SelectDevice(id.clone()));
+        assert_eq!(a.dashboard.devices[&id].failure_summary(), None);
+
+        let liveness = |status, consecutive_failures, last_error: Option<&str>| DeviceLiveness {
+            device: "flaky-r1".into(),
+            status,
+            last_seen: 0,
+            consecutive_failures,
+            last_error: last_error.map(String::from),
+        };
+        let _ = a.update(Message::DeviceLivenessReceived(
+            "snmp".into(),
+            liveness(DeviceStatus::Degraded, 3, Some("request timed out")),
+        ));
+
+        let summary = a.dashboard.devices[&id].failure_summary().unwrap();
+        assert_eq!(summary.consecutive_failures, 3);
+        assert_eq!(summary.describe(), "3 failures in a row: request timed out");
+        assert_eq!(a.selected_device.as_ref().unwrap().failure, Some(summary));
+
+        // Recovery clears it.
+        let _ = a.update(Message::DeviceLivenessReceived(
+            "snmp".into(),
+            liveness(DeviceStatus::Online, 0, None),
+        ));
+        assert_eq!(a.selected_device.as_ref().unwrap().failure, None);
+    }
+
+    #[test]
+    fn max_history_bounds_each_metric_buffer() {
+        use zensight_common::Protocol;
+        let mut a = app();
+        a.apply_max_history(100);
+        let id = DeviceId::new(Protocol::Snmp, "history-r1");
+        let _ = a.update(Message::SelectDevice(id.clone()));
+        let _ = a.update(Message::OpenDeviceInSplit(id));
+
+        for i in 0..200 {
+            let mut point = TelemetryPoint::new(
+                "history-r1",
+                Protocol::Snmp,
+                "ifInOctets",
+                TelemetryValue::Counter(i),
+            );
+            point.timestamp = i as i64;
+            let _ = a.update(Message::TelemetryReceived(point));
+        }
+
+        for detail in [&a.selected_device, &a.split_device] {
+            let history = &detail.as_ref().unwrap().history["ifInOctets"];
+            assert_eq!(history.len(), 100);
+            assert_eq!(history.front().unwrap().timestamp, 100);
+            assert_eq!(history.back().unwrap().timestamp, 199);
+        }
+
+        // Lowering the cap trims what's already buffered.
+        a.apply_max_history(10);
+        let history = &a.selected_device.as_ref().unwrap().history["ifInOctets"];
+        assert_eq!(history.len(), 10);
+        assert_eq!(history.front().unwrap().timestamp, 190);
+    }
+
+    #[test]
+    fn history_queries_read_the_chart_history() {
+        use zensight_common::Protocol;
+        let mut a = app();
+        assert!(!a.history_query.is_enabled());
+
+        for ts in 1..=5 {
+            let mut p = TelemetryPoint::new(
+                "hq-host",
+                Protocol::Sysinfo,
+                "cpu/usage",
+                TelemetryValue::Gauge(ts as f64),
+            );
+            p.timestamp = ts;
+            let _ = a.update(Message::TelemetryReceived(p));
+        }
+        let key = zenoh::key_expr::keyexpr::new("zensight/@gui/history/sysinfo/hq-host/cpu/usage")
+            .unwrap();
+        let replies = crate::history_query::answer(&a.store, key, Some(3));
+        assert_eq!(replies.len(), 1);
+        let ts: Vec<i64> = replies[0].1.iter().map(|p| p.timestamp).collect();
+        assert_eq!(ts, vec![3, 4, 5]);
+        assert_eq!(
+            a.store.hot_samples("sysinfo/hq-host|cpu/usage").len(),
+            5,
+            "served from the store, not a second copy"
+        );
+    }
+
+    #[test]
+    fn global_time_window_drives_selected_device_charts() {
+        use crate::view::chart::TimeWindow;
+        use zensight_common::Protocol;
+        let mut a = app();
+        let _ = a.update(Message::SelectDevice(DeviceId::new(
+            Protocol::Sysinfo,
+            "host-a",
+        )));
+        let _ = a.update(Message::SelectMetricForChart("cpu".into()));
+
+        let _ = a.update(Message::SetGlobalTimeWindow(TimeWindow::OneHour));
+        let device = a.selected_device.as_ref().unwrap();
+        assert_eq!(device.chart.time_window(), TimeWindow::OneHour);
+
+        // Charts opened afterwards start at the global window...
+        let _ = a.update(Message::SelectMetricForChart("mem".into()));
+        assert_eq!(
+            a.selected_device.as_ref().unwrap().chart.time_window(),
+            TimeWindow::OneHour
+        );
+        // ...and a per-chart choice still overrides it.
+        let _ = a.update(Message::SetChartTimeWindow(TimeWindow::OneMinute));
+        assert_eq!(
+            a.selected_device.as_ref().unwrap().chart.time_window(),
+            TimeWindow::OneMinute
+        );
+
+        // A newly selected device inherits the global window too.
+        let _ = a.update(Message::SelectDevice(DeviceId::new(
+            Protocol::Sysinfo,
+            "host-b",
+        )));
+        assert_eq!(
+            a.selected_device.as_ref().unwrap().chart.time_window(),
+            TimeWindow::OneHour
+        );
+    }
 }
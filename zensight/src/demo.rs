@@ -10,8 +10,8 @@ use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
 
 use zensight_common::{
-    Alert, AlertKind, AlertSeverity, DeviceLiveness, DeviceStatus, HealthSnapshot, HealthStatus,
-    Protocol, TelemetryPoint, TelemetryValue,
+    Alert, AlertKind, AlertSeverity, CorrelationEntry, DeviceLiveness, DeviceStatus, ErrorReport,
+    ErrorType, HealthSnapshot, HealthStatus, Protocol, TelemetryPoint, TelemetryValue,
 };
 
 /// Demo simulation state.
@@ -39,6 +39,10 @@ pub struct DemoSimulator {
     /// Currently-firing sensor alerts, keyed by `Alert::alert_key()`. Used to
     /// emit `Resolved` transitions when an anomaly clears.
     firing_alerts: HashMap<String, zensight_common::Alert>,
+    /// Which events get scheduled (#455).
+    scenario: DemoScenario,
+    /// Simulated ticks per call to [`tick`](Self::tick) (#457).
+    time_scale: u64,
 }
 
 /// A scheduled event that affects the simulation.
@@ -81,6 +85,186 @@ enum EventType {
     },
 }
 
+/// The kinds of event a scenario can schedule, without their parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EventKind {
+    CpuSpike,
+    MemoryLeak,
+    TrafficBurst,
+    InterfaceDown,
+    DiskFilling,
+    TemperatureSpike,
+    ErrorBurst,
+    PortScan,
+    Beaconing,
+    ServiceDown,
+}
+
+/// Which story the demo tells (#455): biases the events it schedules.
+///
+/// Selected with `ZENSIGHT_DEMO_SCENARIO` (`mixed`, `steady`,
+/// `cascading_failure`, `network_outage`, `capacity_exhaustion`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DemoScenario {
+    /// A bit of everything (the original demo).
+    #[default]
+    Mixed,
+    /// A quiet fleet: rare, mild CPU and traffic bumps.
+    Steady,
+    /// Load spreads: CPU, memory and error bursts with services dropping.
+    CascadingFailure,
+    /// Links flap: mostly interfaces going down.
+    NetworkOutage,
+    /// Resources run out: disks filling and memory leaking.
+    CapacityExhaustion,
+}
+
+impl DemoScenario {
+    /// Every scenario, in menu order.
+    pub const ALL: [DemoScenario; 5] = [
+        DemoScenario::Mixed,
+        DemoScenario::Steady,
+        DemoScenario::CascadingFailure,
+        DemoScenario::NetworkOutage,
+        DemoScenario::CapacityExhaustion,
+    ];
+
+    /// The scenario's config name.
+    pub fn name(self) -> &'static str {
+        match self {
+            DemoScenario::Mixed => "mixed",
+            DemoScenario::Steady => "steady",
+            DemoScenario::CascadingFailure => "cascading_failure",
+            DemoScenario::NetworkOutage => "network_outage",
+            DemoScenario::CapacityExhaustion => "capacity_exhaustion",
+        }
+    }
+
+    /// The scenario named by `ZENSIGHT_DEMO_SCENARIO`, or [`Mixed`] when unset
+    /// or unknown.
+    ///
+    /// [`Mixed`]: DemoScenario::Mixed
+    pub fn from_env() -> Self {
+        match std::env::var("ZENSIGHT_DEMO_SCENARIO") {
+            Ok(name) => name.parse().unwrap_or_else(|_| {
+                tracing::warn!(scenario = %name, "Unknown demo scenario, using mixed");
+                DemoScenario::Mixed
+            }),
+            Err(_) => DemoScenario::Mixed,
+        }
+    }
+
+    /// Relative weights of each event kind.
+    fn event_weights(self) -> &'static [(EventKind, u32)] {
+        use EventKind::*;
+        match self {
+            DemoScenario::Mixed => &[
+                (CpuSpike, 3),
+                (MemoryLeak, 1),
+                (TrafficBurst, 2),
+                (InterfaceDown, 1),
+                (DiskFilling, 1),
+                (TemperatureSpike, 1),
+                (ErrorBurst, 1),
+                (PortScan, 1),
+                (Beaconing, 1),
+                (ServiceDown, 1),
+            ],
+            DemoScenario::Steady => &[(CpuSpike, 1), (TrafficBurst, 1)],
+            DemoScenario::CascadingFailure => &[
+                (CpuSpike, 3),
+                (MemoryLeak, 3),
+                (ErrorBurst, 3),
+                (ServiceDown, 3),
+                (DiskFilling, 1),
+                (InterfaceDown, 1),
+            ],
+            DemoScenario::NetworkOutage => {
+                &[(InterfaceDown, 8), (TrafficBurst, 2), (ServiceDown, 1)]
+            }
+            DemoScenario::CapacityExhaustion => &[
+                (DiskFilling, 4),
+                (MemoryLeak, 4),
+                (CpuSpike, 2),
+                (TemperatureSpike, 1),
+            ],
+        }
+    }
+}
+
+impl std::fmt::Display for DemoScenario {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for DemoScenario {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_ascii_lowercase().replace('-', "_");
+        DemoScenario::ALL
+            .into_iter()
+            .find(|scenario| scenario.name() == s)
+            .ok_or_else(|| format!("unknown demo scenario `{s}`"))
+    }
+}
+
+/// How far back a fast-forwarding [`DemoClock`] starts: one hour, so a
+/// screenshot can show an hour-long chart (#457).
+pub const FAST_FORWARD_SPAN_MS: i64 = 60 * 60 * 1000;
+
+/// The demo's simulated wall clock (#457).
+///
+/// With a speed above 1 (`ZENSIGHT_DEMO_SPEED`), the clock starts
+/// [`FAST_FORWARD_SPAN_MS`] in the past and advances `speed` times faster than
+/// real time until it catches up, then runs in real time. Point timestamps
+/// stay within the charts' window, which ends at the real "now".
+#[derive(Debug, Clone)]
+pub struct DemoClock {
+    now: i64,
+    speed: u64,
+}
+
+impl DemoClock {
+    /// A clock starting at `real_now`, or an hour before it when fast-forwarding.
+    pub fn new(real_now: i64, speed: u64) -> Self {
+        let speed = speed.max(1);
+        let now = if speed > 1 {
+            real_now - FAST_FORWARD_SPAN_MS
+        } else {
+            real_now
+        };
+        Self { now, speed }
+    }
+
+    /// The speed named by `ZENSIGHT_DEMO_SPEED`, or 1 when unset or invalid.
+    pub fn speed_from_env() -> u64 {
+        match std::env::var("ZENSIGHT_DEMO_SPEED") {
+            Ok(value) => value.parse().ok().filter(|&s| s >= 1).unwrap_or_else(|| {
+                tracing::warn!(speed = %value, "Invalid demo speed, using 1");
+                1
+            }),
+            Err(_) => 1,
+        }
+    }
+
+    /// The current speed: 1 once the clock has caught up.
+    pub fn speed(&self) -> u64 {
+        self.speed
+    }
+
+    /// Advance by `real_step_ms` of real time and return the simulated time.
+    pub fn advance(&mut self, real_step_ms: i64, real_now: i64) -> i64 {
+        self.now += real_step_ms * self.speed as i64;
+        if self.speed == 1 || self.now >= real_now {
+            self.now = real_now;
+            self.speed = 1;
+        }
+        self.now
+    }
+}
+
 /// An active anomaly affecting values.
 #[derive(Debug, Clone)]
 struct Anomaly {
@@ -136,8 +320,13 @@ enum AnomalyType {
 }
 
 impl DemoSimulator {
-    /// Create a new demo simulator.
+    /// Create a new demo simulator running the mixed scenario.
     pub fn new() -> Self {
+        Self::with_scenario(DemoScenario::Mixed)
+    }
+
+    /// Create a demo simulator whose events follow `scenario` (#455).
+    pub fn with_scenario(scenario: DemoScenario) -> Self {
         let mut sim = Self {
             rng: SmallRng::from_os_rng(),
             tick: 0,
@@ -149,6 +338,8 @@ impl DemoSimulator {
             metrics_published: HashMap::new(),
             errors_per_sensor: HashMap::new(),
             firing_alerts: HashMap::new(),
+            scenario,
+            time_scale: 1,
         };
 
         // Initialize base values for servers
@@ -160,6 +351,13 @@ impl DemoSimulator {
         sim
     }
 
+    /// Make each [`tick`](Self::tick) cover `scale` simulated ticks (#457):
+    /// the tick counter and counter increments advance `scale` times as far,
+    /// so counters progress as if the skipped ticks had run.
+    pub fn set_time_scale(&mut self, scale: u64) {
+        self.time_scale = scale.max(1);
+    }
+
     /// Initialize base values for various metrics.
     fn init_base_values(&mut self) {
         // Server CPU baselines (different servers have different loads)
@@ -200,37 +398,36 @@ impl DemoSimulator {
         let network_devices = ["router01", "switch01"];
         let plcs = ["plc01", "plc02"];
 
-        // Schedule 3-6 events in the given range
-        let num_events = self.rng.random_range(3..=6);
+        // Schedule 3-6 events in the given range (fewer when steady)
+        let num_events = match self.scenario {
+            DemoScenario::Steady => self.rng.random_range(1..=2),
+            _ => self.rng.random_range(3..=6),
+        };
 
         for _ in 0..num_events {
             let tick = start_tick + self.rng.random_range(10..range);
-            let event_type = match self.rng.random_range(0..13) {
-                0..=2 => {
-                    // CPU spike (most common)
+            let event_type = match self.pick_event_kind() {
+                EventKind::CpuSpike => {
                     let server = servers[self.rng.random_range(0..servers.len())];
                     EventType::CpuSpike {
                         server: server.to_string(),
                         intensity: self.rng.random_range(75.0..98.0),
                     }
                 }
-                3 => {
-                    // Memory leak
+                EventKind::MemoryLeak => {
                     let server = servers[self.rng.random_range(0..servers.len())];
                     EventType::MemoryLeak {
                         server: server.to_string(),
                     }
                 }
-                4..=5 => {
-                    // Traffic burst
+                EventKind::TrafficBurst => {
                     let device = network_devices[self.rng.random_range(0..network_devices.len())];
                     EventType::TrafficBurst {
                         device: device.to_string(),
                         interface: self.rng.random_range(1..=4),
                     }
                 }
-                6 => {
-                    // Interface down
+                EventKind::InterfaceDown => {
                     let device = network_devices[self.rng.random_range(0..network_devices.len())];
                     let interface = self.rng.random_range(1..=4);
                     // Schedule it to come back up
@@ -246,42 +443,39 @@ impl DemoSimulator {
                         interface,
                     }
                 }
-                7 => {
-                    // Disk filling
+                EventKind::DiskFilling => {
                     let server = servers[self.rng.random_range(0..servers.len())];
                     EventType::DiskFilling {
                         server: server.to_string(),
                     }
                 }
-                8 => {
-                    // Temperature spike
+                EventKind::TemperatureSpike => {
                     let plc = plcs[self.rng.random_range(0..plcs.len())];
                     EventType::TemperatureSpike {
                         plc: plc.to_string(),
                     }
                 }
-                9 => {
-                    // Error burst
+                EventKind::ErrorBurst => {
                     let server = servers[self.rng.random_range(0..servers.len())];
                     EventType::ErrorBurst {
                         server: server.to_string(),
                     }
                 }
-                10 => {
+                EventKind::PortScan => {
                     // Port scan seen by the netring probe
                     let host = self.rng.random_range(20..250);
                     EventType::PortScan {
                         src_ip: format!("198.51.100.{host}"),
                     }
                 }
-                11 => {
+                EventKind::Beaconing => {
                     // C2 beaconing seen by the netring probe
                     let host = self.rng.random_range(2..250);
                     EventType::Beaconing {
                         dst_ip: format!("203.0.113.{host}"),
                     }
                 }
-                _ => {
+                EventKind::ServiceDown => {
                     // A monitored service stops listening on a host (netlink)
                     let host = servers[self.rng.random_range(0..servers.len())];
                     let (service, port) = [("sshd", 22u16), ("nginx", 443), ("postgres", 5432)]
@@ -301,6 +495,20 @@ impl DemoSimulator {
         self.events.sort_by_key(|e| e.tick);
     }
 
+    /// Draw the next event kind from the scenario's weights (#455).
+    fn pick_event_kind(&mut self) -> EventKind {
+        let weights = self.scenario.event_weights();
+        let total: u32 = weights.iter().map(|(_, w)| w).sum();
+        let mut roll = self.rng.random_range(0..total);
+        for &(kind, weight) in weights {
+            if roll < weight {
+                return kind;
+            }
+            roll -= weight;
+        }
+        weights[0].0
+    }
+
     /// Process events for the current tick.
     fn process_events(&mut self) {
         // Find events that should trigger
@@ -433,8 +641,10 @@ impl DemoSimulator {
         *self.counters.entry(key.to_string()).or_insert(initial)
     }
 
-    /// Increment a counter and return the new value.
+    /// Increment a counter by `amount` per simulated tick and return the new
+    /// value.
     fn increment_counter(&mut self, key: &str, amount: u64) -> u64 {
+        let amount = amount.saturating_mul(self.time_scale);
         let counter = self.counters.entry(key.to_string()).or_insert(0);
         *counter = counter.saturating_add(amount);
         *counter
@@ -463,7 +673,7 @@ impl DemoSimulator {
 
     /// Generate a tick of telemetry data.
     pub fn tick(&mut self, timestamp: i64) -> Vec<TelemetryPoint> {
-        self.tick += 1;
+        self.tick += self.time_scale;
         self.process_events();
 
         let mut points = Vec::new();
@@ -2141,6 +2351,11 @@ impl DemoSimulator {
                     last_poll_duration_ms: self.rng.random_range(50..200),
                     errors_last_hour: errors,
                     metrics_published: metrics,
+                    counters: Default::default(),
+                    gauges: Default::default(),
+                    restart_count: 0,
+                    restarts_last_hour: 0,
+                    last_start_ms: None,
                 }
             })
             .collect()
@@ -2290,6 +2505,99 @@ impl DemoSimulator {
         (DeviceStatus::Online, 0, None)
     }
 
+    /// Generate IP↔hostname correlation entries for the simulated fleet, as
+    /// the correlation registry would publish them (#456). Each device's
+    /// address is fixed so the related-devices view stays stable.
+    pub fn generate_correlations(&self, timestamp: i64) -> Vec<CorrelationEntry> {
+        let devices: [(&str, &str, &[&str]); 8] = [
+            ("10.0.1.11", "server01", &["sysinfo", "netlink"]),
+            ("10.0.1.12", "server02", &["sysinfo", "netlink"]),
+            ("10.0.1.13", "server03", &["sysinfo", "netlink"]),
+            ("10.0.1.20", "database01", &["sysinfo", "logs", "netlink"]),
+            ("10.0.0.1", "router01", &["snmp", "gnmi"]),
+            ("10.0.0.2", "switch01", &["snmp", "gnmi"]),
+            ("10.0.2.11", "plc01", &["modbus"]),
+            ("10.0.2.12", "plc02", &["modbus"]),
+        ];
+
+        devices
+            .into_iter()
+            .map(|(ip, host, sensors)| CorrelationEntry {
+                ip: ip.to_string(),
+                hostnames: vec![host.to_string()],
+                sensors: sensors.iter().map(|s| s.to_string()).collect(),
+                sources: sensors
+                    .iter()
+                    .map(|s| (s.to_string(), host.to_string()))
+                    .collect(),
+                last_updated: timestamp,
+            })
+            .collect()
+    }
+
+    /// Generate sensor error reports for anomalies that started this tick
+    /// (#456), keyed by the reporting sensor. Each is also counted in that
+    /// sensor's health snapshot.
+    pub fn generate_error_reports(&mut self, timestamp: i64) -> Vec<(String, ErrorReport)> {
+        let mut reports = Vec::new();
+
+        for anomaly in &self.active_anomalies {
+            if anomaly.start_tick != self.tick {
+                continue;
+            }
+            let (sensor, device, error_type, message, retryable) = match &anomaly.anomaly_type {
+                AnomalyType::InterfaceDown { device, interface } => (
+                    "snmp",
+                    device,
+                    ErrorType::Timeout,
+                    format!("SNMP poll timed out (interface {interface} down)"),
+                    true,
+                ),
+                AnomalyType::ServiceDown {
+                    host,
+                    service,
+                    port,
+                } => (
+                    "netlink",
+                    host,
+                    ErrorType::ConnectionRefused,
+                    format!("{service} refused connection on port {port}"),
+                    true,
+                ),
+                AnomalyType::TemperatureHigh { plc, .. } => (
+                    "modbus",
+                    plc,
+                    ErrorType::ProtocolError,
+                    "Modbus exception 0x04 (slave device failure)".to_string(),
+                    true,
+                ),
+                AnomalyType::ErrorBurst { server } => (
+                    "logs",
+                    server,
+                    ErrorType::ParseError,
+                    "Unparseable syslog line dropped".to_string(),
+                    false,
+                ),
+                _ => continue,
+            };
+            reports.push((
+                sensor.to_string(),
+                ErrorReport {
+                    timestamp,
+                    device: Some(device.clone()),
+                    error_type,
+                    message,
+                    retryable,
+                },
+            ));
+        }
+
+        for (sensor, _) in &reports {
+            self.record_error(sensor);
+        }
+        reports
+    }
+
     /// Record metrics published for a sensor.
     pub fn record_metrics(&mut self, sensor: &str, count: u64) {
         *self
@@ -2400,6 +2708,48 @@ mod tests {
         assert!(protocols.contains(&Protocol::Gnmi));
     }
 
+    #[test]
+    fn network_outage_schedules_mostly_interface_downs() {
+        let mut sim = DemoSimulator::with_scenario(DemoScenario::NetworkOutage);
+        sim.events.clear();
+        for start in 0..50 {
+            sim.schedule_random_events(start * 100, 100);
+        }
+        // InterfaceUp is the scheduled recovery of an InterfaceDown, not a pick.
+        let picked: Vec<_> = sim
+            .events
+            .iter()
+            .filter(|e| !matches!(e.event_type, EventType::InterfaceUp { .. }))
+            .collect();
+        let downs = picked
+            .iter()
+            .filter(|e| matches!(e.event_type, EventType::InterfaceDown { .. }))
+            .count();
+        assert!(
+            downs * 2 > picked.len(),
+            "{downs} of {} events were interface-down",
+            picked.len()
+        );
+        assert!(picked.iter().all(|e| matches!(
+            e.event_type,
+            EventType::InterfaceDown { .. }
+                | EventType::TrafficBurst { .. }
+                | EventType::ServiceDown { .. }
+        )));
+    }
+
+    #[test]
+    fn scenario_names_round_trip() {
+        for scenario in DemoScenario::ALL {
+            assert_eq!(scenario.name().parse::<DemoScenario>(), Ok(scenario));
+        }
+        assert_eq!(
+            "Network-Outage".parse::<DemoScenario>(),
+            Ok(DemoScenario::NetworkOutage)
+        );
+        assert!("apocalypse".parse::<DemoScenario>().is_err());
+    }
+
     #[test]
     fn test_demo_health_covers_all_sensors() {
         let mut sim = DemoSimulator::new();
@@ -2447,6 +2797,105 @@ mod tests {
         );
     }
 
+    #[test]
+    fn demo_run_yields_correlations_and_error_reports() {
+        let mut sim = DemoSimulator::with_scenario(DemoScenario::NetworkOutage);
+        let timestamp = 1700000000000;
+
+        let correlations = sim.generate_correlations(timestamp);
+        let router = correlations
+            .iter()
+            .find(|c| c.hostnames.contains(&"router01".to_string()))
+            .expect("router01 is correlated");
+        assert_eq!(router.sensors, vec!["snmp", "gnmi"]);
+        assert_eq!(
+            router.sources.get("gnmi").map(String::as_str),
+            Some("router01")
+        );
+
+        let mut reports = Vec::new();
+        for i in 0..500 {
+            sim.tick(timestamp + i * 600);
+            reports.extend(sim.generate_error_reports(timestamp + i * 600));
+        }
+        let (sensor, report) = reports.first().expect("at least one error report");
+        assert!(report.device.is_some());
+        // Reports feed the sensor's error count in its health snapshot.
+        assert!(sim.errors_per_sensor.get(sensor).copied().unwrap_or(0) > 0);
+    }
+
+    /// The summed value of every `Counter` point matching `metric`.
+    fn counter_total(points: &[TelemetryPoint], metric: &str) -> u64 {
+        points
+            .iter()
+            .filter(|p| p.metric == metric)
+            .map(|p| match p.value {
+                TelemetryValue::Counter(v) => v,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    #[test]
+    fn time_scale_advances_counters_like_many_ticks() {
+        let mut normal = DemoSimulator::with_scenario(DemoScenario::Steady);
+        let mut fast = DemoSimulator::with_scenario(DemoScenario::Steady);
+        fast.set_time_scale(60);
+        let ts = 1700000000000;
+
+        let (normal_start, fast_start) = (normal.tick(ts), fast.tick(ts));
+        let mut normal_end = Vec::new();
+        for _ in 0..60 {
+            normal_end = normal.tick(ts);
+        }
+        let fast_end = fast.tick(ts);
+
+        // Uptime is a pure function of the tick: exactly 60 normal ticks.
+        let growth = |a: &[TelemetryPoint], b: &[TelemetryPoint], m: &str| {
+            counter_total(b, m) - counter_total(a, m)
+        };
+        assert_eq!(
+            growth(&fast_start, &fast_end, "system/uptime"),
+            growth(&normal_start, &normal_end, "system/uptime")
+        );
+
+        // Incremented counters add one delta per simulated tick.
+        for _ in 0..60 {
+            normal.increment_counter("octets", 1500);
+        }
+        assert_eq!(
+            fast.increment_counter("octets", 1500),
+            normal.get_counter("octets", 0)
+        );
+    }
+
+    #[test]
+    fn fast_forward_clock_catches_up_with_real_time() {
+        let real_start = 1700000000000;
+        let mut clock = DemoClock::new(real_start, 60);
+        assert_eq!(
+            clock.advance(0, real_start),
+            real_start - FAST_FORWARD_SPAN_MS
+        );
+
+        // One real second covers a simulated minute.
+        let t = clock.advance(1000, real_start + 1000);
+        assert_eq!(t, real_start - FAST_FORWARD_SPAN_MS + 60_000);
+        assert_eq!(clock.speed(), 60);
+
+        // After ~61 real seconds the hour is filled and time runs normally.
+        let caught_up = real_start + 62_000;
+        assert_eq!(clock.advance(61_000, caught_up), caught_up);
+        assert_eq!(clock.speed(), 1);
+        assert_eq!(clock.advance(600, caught_up + 600), caught_up + 600);
+
+        // Without fast-forward the clock is just real time.
+        assert_eq!(
+            DemoClock::new(real_start, 1).advance(600, real_start + 600),
+            real_start + 600
+        );
+    }
+
     #[test]
     fn test_generate_alerts_firing_then_resolved() {
         let mut sim = DemoSimulator::new();
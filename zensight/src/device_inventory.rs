@@ -0,0 +1,100 @@
+//! Device inventory export for asset tracking (#437).
+//!
+//! One row per device the dashboard currently knows, with its protocol,
+//! source, effective health, last-seen time and how many alerts are active on
+//! it, written as CSV or JSON through the usual save dialog.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use zensight_common::{DeviceStatus, Protocol};
+
+use crate::message::DeviceId;
+use crate::view::alerts::AlertsState;
+use crate::view::dashboard::DeviceState;
+use crate::view::device::escape_csv;
+
+/// File format for [`Message::ExportInventory`](crate::message::Message::ExportInventory).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InventoryFormat {
+    Csv,
+    Json,
+}
+
+impl InventoryFormat {
+    /// File extension for the save dialog's default name.
+    pub fn extension(self) -> &'static str {
+        match self {
+            InventoryFormat::Csv => "csv",
+            InventoryFormat::Json => "json",
+        }
+    }
+
+    /// Serialize `rows` in this format.
+    pub fn render(self, rows: &[InventoryRow]) -> String {
+        match self {
+            InventoryFormat::Csv => to_csv(rows),
+            InventoryFormat::Json => {
+                serde_json::to_string_pretty(rows).unwrap_or_else(|_| "[]".to_string())
+            }
+        }
+    }
+}
+
+/// One device in the inventory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InventoryRow {
+    pub protocol: Protocol,
+    pub source: String,
+    /// Effective status (sensor liveness, else local staleness).
+    pub health: DeviceStatus,
+    /// Last telemetry received (Unix epoch ms).
+    pub last_seen: i64,
+    /// Metrics currently tracked for the device.
+    pub metric_count: usize,
+    /// Active alerts on the device (rule-triggered and sensor-pushed).
+    pub active_alerts: usize,
+    /// Last error the sensor reported for the device.
+    pub last_error: Option<String>,
+}
+
+/// Build the inventory from the dashboard and alert states, sorted by
+/// protocol then source.
+pub fn collect(
+    devices: &HashMap<DeviceId, DeviceState>,
+    alerts: &AlertsState,
+) -> Vec<InventoryRow> {
+    let mut rows: Vec<InventoryRow> = devices
+        .values()
+        .map(|device| InventoryRow {
+            protocol: device.id.protocol,
+            source: device.id.source.clone(),
+            health: device.effective_status(),
+            last_seen: device.last_update,
+            metric_count: device.metrics.len(),
+            active_alerts: alerts.active_count_for(&device.id),
+            last_error: device.last_error.clone(),
+        })
+        .collect();
+    rows.sort_by(|a, b| (a.protocol, &a.source).cmp(&(b.protocol, &b.source)));
+    rows
+}
+
+fn to_csv(rows: &[InventoryRow]) -> String {
+    let mut csv =
+        String::from("protocol,source,health,last_seen,metric_count,active_alerts,last_error\n");
+    for row in rows {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            row.protocol,
+            escape_csv(&row.source),
+            row.health,
+            row.last_seen,
+            row.metric_count,
+            row.active_alerts,
+            escape_csv(row.last_error.as_deref().unwrap_or("")),
+        ));
+    }
+    csv
+}
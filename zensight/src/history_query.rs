@@ -0,0 +1,274 @@
+//! Zenoh query API over the history ZenSight has aggregated (#465).
+//!
+//! When enabled, the frontend declares a queryable at
+//! `zensight/@gui/history/**` and answers with the numeric history it holds
+//! for every matching metric — the metric store's hot rings, the same history
+//! behind the charts. Reply keys mirror the telemetry keys under that prefix
+//! (`zensight/@gui/history/<protocol>/<source>/<metric>`), one reply per
+//! metric, each a JSON `Vec<DataPoint>` oldest-first. A `last=N` selector
+//! parameter trims each reply to the newest `N` points, so a late-joining tool
+//! can seed its charts with e.g.
+//! `zensight/@gui/history/sysinfo/*/cpu/usage?last=60`.
+//!
+//! The store belongs to the UI thread, so queries are queued and answered on
+//! the next UI tick; nothing on the telemetry path changes.
+//!
+//! The `@gui` chunk is verbatim, so `zensight/**` telemetry subscriptions
+//! never see these keys.
+
+use zenoh::Wait;
+use zenoh::key_expr::keyexpr;
+use zenoh::query::{Query, Queryable};
+
+use crate::store::MetricStore;
+use crate::view::chart::DataPoint;
+
+/// Key prefix the history queryable is declared under.
+pub const HISTORY_QUERY_PREFIX: &str = "zensight/@gui/history";
+
+/// Queries held for the next UI tick; beyond this Zenoh drops new ones.
+const QUERY_BACKLOG: usize = 64;
+
+/// The store's series whose reply key intersects `query`, as
+/// `(reply key, points)` sorted by key. `last` keeps only the newest points
+/// of each series. Metrics whose names aren't valid key expressions can't be
+/// replied under and are skipped.
+pub fn answer(
+    store: &MetricStore,
+    query: &keyexpr,
+    last: Option<usize>,
+) -> Vec<(String, Vec<DataPoint>)> {
+    let mut replies: Vec<(String, Vec<DataPoint>)> = store
+        .hot_series()
+        .filter_map(|(path, ring)| {
+            let (device, metric) = path.split_once('|')?;
+            let key = format!("{HISTORY_QUERY_PREFIX}/{device}/{metric}");
+            let ke = keyexpr::new(key.as_str()).ok()?;
+            if ring.is_empty() || !query.intersects(ke) {
+                return None;
+            }
+            let skip = last.map_or(0, |n| ring.len().saturating_sub(n));
+            let points = ring
+                .iter()
+                .skip(skip)
+                .map(|sample| DataPoint::new(sample.ts, sample.value))
+                .collect();
+            Some((key, points))
+        })
+        .collect();
+    replies.sort_by(|a, b| a.0.cmp(&b.0));
+    replies
+}
+
+/// Reply to `query` with one JSON `Vec<DataPoint>` per matching metric.
+fn reply(store: &MetricStore, query: &Query) {
+    let last = query
+        .parameters()
+        .get("last")
+        .and_then(|v| v.parse::<usize>().ok());
+    for (key, points) in answer(store, query.key_expr(), last) {
+        match serde_json::to_vec(&points) {
+            Ok(payload) => {
+                if let Err(e) = query.reply(key, payload).wait() {
+                    tracing::warn!(error = %e, "history query reply failed");
+                }
+            }
+            Err(e) => tracing::warn!(error = %e, "history query serialize failed"),
+        }
+    }
+}
+
+/// The opt-in history query service: while enabled and connected, the
+/// queryable whose queries [`answer_pending`](Self::answer_pending) serves.
+pub struct HistoryQueryService {
+    enabled: bool,
+    queryable: Option<Queryable<flume::Receiver<Query>>>,
+}
+
+impl HistoryQueryService {
+    /// A service, initially `enabled` or not.
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            queryable: None,
+        }
+    }
+
+    /// Whether history is served.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Whether the queryable is currently declared.
+    pub fn is_serving(&self) -> bool {
+        self.queryable.is_some()
+    }
+
+    /// Turn the service on or off. Turning it on declares the queryable on
+    /// `session` (when connected); turning it off undeclares it.
+    pub fn set_enabled(&mut self, enabled: bool, session: Option<&zenoh::Session>) {
+        self.enabled = enabled;
+        if enabled {
+            self.attach(session);
+        } else {
+            self.detach();
+        }
+    }
+
+    /// Declare the queryable on a (re)established session, if enabled.
+    pub fn attach(&mut self, session: Option<&zenoh::Session>) {
+        let Some(session) = session.filter(|_| self.enabled) else {
+            return;
+        };
+        self.detach();
+        let key = format!("{HISTORY_QUERY_PREFIX}/**");
+        match session
+            .declare_queryable(&key)
+            .with(flume::bounded(QUERY_BACKLOG))
+            .wait()
+        {
+            Ok(queryable) => {
+                tracing::info!(key = %key, "history query API ready");
+                self.queryable = Some(queryable);
+            }
+            Err(e) => tracing::error!(error = %e, key = %key, "declare history queryable failed"),
+        }
+    }
+
+    /// Undeclare the queryable (the session went away or the service was
+    /// turned off).
+    pub fn detach(&mut self) {
+        self.queryable = None;
+    }
+
+    /// Answer the queries that arrived since the last call from `store`.
+    /// Returns how many were answered.
+    pub fn answer_pending(&self, store: &MetricStore) -> usize {
+        let Some(queryable) = &self.queryable else {
+            return 0;
+        };
+        let mut answered = 0;
+        while let Ok(query) = queryable.handler().try_recv() {
+            reply(store, &query);
+            answered += 1;
+        }
+        answered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use zensight_common::{Protocol, TelemetryPoint, TelemetryValue};
+
+    use super::*;
+
+    fn point(source: &str, metric: &str, ts: i64, value: f64) -> TelemetryPoint {
+        let mut p = TelemetryPoint::new(
+            source,
+            Protocol::Sysinfo,
+            metric,
+            TelemetryValue::Gauge(value),
+        );
+        p.timestamp = ts;
+        p
+    }
+
+    fn isolated_config() -> zenoh::Config {
+        let mut config = zenoh::Config::default();
+        config
+            .insert_json5("scouting/multicast/enabled", "false")
+            .unwrap();
+        config
+            .insert_json5("scouting/gossip/enabled", "false")
+            .unwrap();
+        config
+    }
+
+    #[test]
+    fn answer_matches_keys_and_honours_last() {
+        let mut store = MetricStore::new(3, None);
+        for ts in 1..=5 {
+            store.record(&point("web01", "cpu/usage", ts, ts as f64));
+        }
+        store.record(&point("db01", "cpu/usage", 10, 42.0));
+        store.record(&point("web01", "memory/used", 10, 7.0));
+        let mut text = point("web01", "os/name", 10, 0.0);
+        text.value = TelemetryValue::Text("linux".into());
+        store.record(&text);
+
+        // Bounded by the hot ring, oldest-first.
+        let exact = keyexpr::new("zensight/@gui/history/sysinfo/web01/cpu/usage").unwrap();
+        let got = answer(&store, exact, None);
+        assert_eq!(got.len(), 1);
+        let ts: Vec<i64> = got[0].1.iter().map(|p| p.timestamp).collect();
+        assert_eq!(ts, vec![3, 4, 5]);
+
+        let got = answer(&store, exact, Some(1));
+        assert_eq!(got[0].1.len(), 1);
+        assert_eq!(got[0].1[0].value, 5.0);
+
+        // Wildcards fan out to one reply per metric; text has no history.
+        let any_metric = keyexpr::new("zensight/@gui/history/sysinfo/**").unwrap();
+        let keys: Vec<String> = answer(&store, any_metric, None)
+            .into_iter()
+            .map(|(k, _)| k)
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                "zensight/@gui/history/sysinfo/db01/cpu/usage",
+                "zensight/@gui/history/sysinfo/web01/cpu/usage",
+                "zensight/@gui/history/sysinfo/web01/memory/used",
+            ]
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn query_is_answered_from_the_store() {
+        let session = zenoh::open(isolated_config()).await.expect("open zenoh");
+        let mut store = MetricStore::new(100, None);
+        for ts in 1..=3 {
+            store.record(&point("host_history", "cpu/usage", ts, ts as f64 * 10.0));
+        }
+        let mut service = HistoryQueryService::new(true);
+        service.attach(Some(&session));
+        assert!(service.is_serving());
+        tokio::time::sleep(Duration::from_millis(150)).await;
+
+        let replies = session
+            .get("zensight/@gui/history/sysinfo/host_history/cpu/usage")
+            .await
+            .expect("get");
+        // Stand in for the UI tick.
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+        while service.answer_pending(&store) == 0 {
+            assert!(
+                tokio::time::Instant::now() < deadline,
+                "query never arrived"
+            );
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        let reply = tokio::time::timeout(Duration::from_secs(5), replies.recv_async())
+            .await
+            .expect("reply timed out")
+            .expect("reply");
+        let sample = reply.result().expect("ok reply");
+        assert_eq!(
+            sample.key_expr().as_str(),
+            "zensight/@gui/history/sysinfo/host_history/cpu/usage"
+        );
+        let points: Vec<DataPoint> =
+            serde_json::from_slice(&sample.payload().to_bytes()).expect("decode");
+        let values: Vec<f64> = points.iter().map(|p| p.value).collect();
+        assert_eq!(values, vec![10.0, 20.0, 30.0]);
+
+        // Turning the service off undeclares the queryable.
+        service.set_enabled(false, Some(&session));
+        assert!(!service.is_serving());
+        assert_eq!(service.answer_pending(&store), 0);
+
+        session.close().await.unwrap();
+    }
+}
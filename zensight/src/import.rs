@@ -0,0 +1,193 @@
+//! CSV import of telemetry for offline analysis (#417).
+//!
+//! Reads back the CSVs the device view exports (#37) — or any CSV with
+//! `timestamp,protocol,source,metric,value` columns plus optional `type` and
+//! `labels` — into [`TelemetryPoint`]s, so a capture can be charted without a
+//! live bridge. Columns are located by header name, so both the snapshot and
+//! the history export layouts load. Labels use the export's `k=v;k=v` form.
+
+use std::collections::HashMap;
+
+use zensight_common::{Protocol, TelemetryPoint, TelemetryValue};
+
+/// Result of parsing an import file.
+#[derive(Debug, Clone, Default)]
+pub struct CsvImport {
+    /// Points parsed, in file order.
+    pub points: Vec<TelemetryPoint>,
+    /// Rows skipped because a field didn't parse (unknown protocol, a value
+    /// that doesn't match its `type`, a histogram, …).
+    pub skipped: usize,
+}
+
+/// Header positions of the columns an import reads.
+struct Columns {
+    timestamp: usize,
+    protocol: usize,
+    source: usize,
+    metric: usize,
+    value: usize,
+    kind: Option<usize>,
+    labels: Option<usize>,
+}
+
+impl Columns {
+    fn from_header(header: &[String]) -> Result<Self, String> {
+        let column = |name: &str| {
+            header
+                .iter()
+                .position(|h| h.trim().eq_ignore_ascii_case(name))
+        };
+        let required = |name: &str| column(name).ok_or(format!("missing `{name}` column"));
+        Ok(Self {
+            timestamp: required("timestamp")?,
+            protocol: required("protocol")?,
+            source: required("source")?,
+            metric: required("metric")?,
+            value: required("value")?,
+            kind: column("type"),
+            labels: column("labels"),
+        })
+    }
+
+    /// The point in `record`, or `None` if any field doesn't parse.
+    fn point(&self, record: &[String]) -> Option<TelemetryPoint> {
+        let field = |i: usize| record.get(i).map(String::as_str);
+        Some(TelemetryPoint {
+            timestamp: field(self.timestamp)?.trim().parse().ok()?,
+            source: field(self.source)?.to_string(),
+            protocol: field(self.protocol)?.trim().parse::<Protocol>().ok()?,
+            metric: field(self.metric)?.to_string(),
+            value: parse_value(field(self.value)?, self.kind.and_then(field))?,
+            labels: self
+                .labels
+                .and_then(field)
+                .map(parse_labels)
+                .unwrap_or_default(),
+        })
+    }
+}
+
+/// Parse a telemetry CSV. Fails only when the header is missing a required
+/// column; bad rows are counted in [`CsvImport::skipped`] instead.
+pub fn parse_telemetry_csv(input: &str) -> Result<CsvImport, String> {
+    let mut records = split_records(input).into_iter();
+    let header = records.next().ok_or("empty file")?;
+    let columns = Columns::from_header(&header)?;
+
+    let mut import = CsvImport::default();
+    for record in records {
+        // Blank lines (e.g. a trailing newline) aren't rows.
+        if record.len() == 1 && record[0].trim().is_empty() {
+            continue;
+        }
+        match columns.point(&record) {
+            Some(point) => import.points.push(point),
+            None => import.skipped += 1,
+        }
+    }
+    Ok(import)
+}
+
+/// Parse `raw` as the export's `type` says, or infer it when there's no type
+/// column: `true`/`false` are booleans, numbers are gauges, anything else is
+/// text.
+fn parse_value(raw: &str, kind: Option<&str>) -> Option<TelemetryValue> {
+    match kind.map(str::trim) {
+        Some("counter") => raw.trim().parse().ok().map(TelemetryValue::Counter),
+        Some("gauge") => raw.trim().parse().ok().map(TelemetryValue::Gauge),
+        Some("bool") => raw.trim().parse().ok().map(TelemetryValue::Boolean),
+        Some("text") => Some(TelemetryValue::Text(raw.to_string())),
+        Some("bytes") => decode_hex(raw.trim()).map(TelemetryValue::Bytes),
+        // `binary` exports only a length and `histogram` a summary; neither
+        // round-trips.
+        Some(_) => None,
+        None => Some(if let Ok(b) = raw.trim().parse::<bool>() {
+            TelemetryValue::Boolean(b)
+        } else if let Ok(v) = raw.trim().parse::<f64>() {
+            TelemetryValue::Gauge(v)
+        } else {
+            TelemetryValue::Text(raw.to_string())
+        }),
+    }
+}
+
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    if !s.is_ascii() || s.len() % 2 != 0 {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+        .collect()
+}
+
+/// `k=v;k=v` → map. Entries without `=` are ignored.
+fn parse_labels(raw: &str) -> HashMap<String, String> {
+    raw.split(';')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+/// Split CSV text into records of fields, honouring the export's quoting:
+/// a quoted field may contain commas, newlines and `""`-escaped quotes.
+fn split_records(input: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (quoted, c) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            (true, '"') => quoted = false,
+            (true, c) => field.push(c),
+            (false, '"') if field.is_empty() => quoted = true,
+            (false, ',') => record.push(std::mem::take(&mut field)),
+            (false, '\r') if chars.peek() == Some(&'\n') => {}
+            (false, '\n') => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            (false, c) => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_export_layout_with_quoting() {
+        let csv = "timestamp,protocol,source,metric,value,type,labels\n\
+                   1000,snmp,router01,ifInOctets,42,counter,ifName=eth0;ifIndex=1\n\
+                   1000,snmp,router01,sysDescr,\"Cisco, \"\"IOS\"\"\",text,\n\
+                   1000,snmp,router01,hist,sum=1 +Inf:1,histogram,\n\
+                   1000,nope,router01,cpu,1,gauge,\n";
+        let import = parse_telemetry_csv(csv).unwrap();
+        assert_eq!(import.points.len(), 2);
+        assert_eq!(import.skipped, 2);
+
+        let octets = &import.points[0];
+        assert_eq!(octets.protocol, Protocol::Snmp);
+        assert_eq!(octets.value, TelemetryValue::Counter(42));
+        assert_eq!(octets.labels["ifName"], "eth0");
+        assert_eq!(octets.labels["ifIndex"], "1");
+        assert_eq!(
+            import.points[1].value,
+            TelemetryValue::Text("Cisco, \"IOS\"".to_string())
+        );
+
+        assert!(parse_telemetry_csv("timestamp,source\n").is_err());
+    }
+}
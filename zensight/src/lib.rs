@@ -4,8 +4,13 @@
 
 pub mod app;
 pub mod demo;
+pub mod device_inventory;
+pub mod history_query;
+pub mod import;
 pub mod message;
+pub mod metric_docs;
 pub mod mock;
+pub mod state_snapshot;
 pub mod store;
 pub mod subscription;
 pub mod view;
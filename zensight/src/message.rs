@@ -1,9 +1,11 @@
 use zensight_common::{
-    Alert, CorrelationEntry, DeviceLiveness, DeviceStatus, ErrorReport, HealthSnapshot, Protocol,
-    SensorInfo, TelemetryPoint,
+    Alert, CorrelationEntry, DeviceLiveness, DeviceStatus, DiscoveryEvent, ErrorReport,
+    HealthSnapshot, Protocol, SampleMetadata, SensorInfo, TelemetryPoint,
 };
 
-use crate::view::alerts::{ComparisonOp, Severity};
+use crate::view::alerts::{
+    AlertAckFilter, AlertRule, ComparisonOp, ConditionKind, RuleImportMode, Severity,
+};
 use crate::view::chart::TimeWindow;
 use crate::view::settings::ZenohMode;
 
@@ -17,6 +19,10 @@ pub enum Message {
     /// spikes) — one iced update instead of one per sample.
     TelemetryBatch(Vec<TelemetryPoint>),
 
+    /// Bridge provenance read from sample attachments (#444), one entry per
+    /// metric whose metadata is new or changed.
+    ProvenanceReceived(Vec<(DeviceId, String, SampleMetadata)>),
+
     /// A periodic off-thread store flush finished. Payload is the number of
     /// downsampled buckets persisted (or `Err` with a message on failure). #22.
     StoreFlushed(Result<usize, String>),
@@ -38,6 +44,10 @@ pub enum Message {
     /// Sensor error report received (with the publishing sensor/protocol name).
     ErrorReportReceived(String, ErrorReport),
 
+    /// A sensor announced a previously unseen device/interface/table row on
+    /// `zensight/<protocol>/@/discovery` (#436).
+    DiscoveryReceived(String, DiscoveryEvent),
+
     /// Sensor discovery/info received.
     SensorInfoReceived(SensorInfo),
 
@@ -338,6 +348,13 @@ pub enum Message {
         metric: Option<String>,
     },
 
+    /// Open a global-search hit (#438): its device, with the matched metric
+    /// selected for charting.
+    SelectDeviceMetric {
+        device: DeviceId,
+        metric: String,
+    },
+
     /// Navigate to the previous/next device within the current filtered set
     /// (#35 cross-device navigation on the device detail view).
     SelectAdjacentDevice {
@@ -347,6 +364,18 @@ pub enum Message {
     /// User cleared device selection (back to dashboard).
     ClearSelection,
 
+    /// Open a second device detail in a split pane beside the selected one,
+    /// for side-by-side comparison. Falls back to a plain selection when no
+    /// device is open yet.
+    OpenDeviceInSplit(DeviceId),
+
+    /// Close the secondary split pane, keeping the primary device open.
+    CloseSplit,
+
+    /// A message originating in the split pane. Routed to the secondary
+    /// device's own state so both panes stay independent.
+    SplitPane(Box<Message>),
+
     /// User toggled protocol filter.
     ToggleProtocolFilter(Protocol),
 
@@ -393,12 +422,23 @@ pub enum Message {
     /// Toggle visibility of a metric series in the chart.
     ToggleMetricVisibility(String),
 
+    /// Move a comparison series between the left and right Y-axis (#394).
+    ToggleMetricAxis(String),
+
+    /// Switch a comparison series between linear and step drawing (#416).
+    ToggleMetricInterpolation(String),
+
     /// Toggle a metric's favorite/pin state on the selected device (#27).
     ToggleMetricFavorite(String),
 
     /// User changed the chart time window.
     SetChartTimeWindow(TimeWindow),
 
+    /// User changed the app-wide time window (#392): applied to every open
+    /// device chart and the dashboard sparklines, and used as the starting
+    /// window for charts opened afterwards.
+    SetGlobalTimeWindow(TimeWindow),
+
     /// User typed a custom relative window (minutes) for the chart (#36).
     SetChartCustomMinutes(String),
 
@@ -415,6 +455,21 @@ pub enum Message {
 
     /// Toggle the chart panel between default and expanded height (#36).
     ToggleChartExpand,
+    /// Toggle the per-metric min/max/avg strip in the device metrics table (#441).
+    ToggleMetricStats,
+    /// Expand or collapse a labeled metric family in the device view (#469).
+    ToggleMetricFamily(String),
+    /// Choose how metric-family rows aggregate their variants (#469).
+    SetFamilyAggregate(crate::view::device::FamilyAggregate),
+    /// Switch the device metrics between the flat table and the tree (#471).
+    ToggleMetricTreeView,
+    /// Expand or collapse a metric-tree branch, by path (#471).
+    ToggleMetricTreeNode(String),
+    /// Toggle charting counters as per-second rates in the device view (#442).
+    ToggleCounterRate,
+
+    /// Switch the single-metric chart between linear and step drawing (#416).
+    ToggleChartInterpolation,
 
     /// Zoom in on the chart.
     ChartZoomIn,
@@ -475,6 +530,25 @@ pub enum Message {
     /// Set Zenoh listen endpoints.
     SetZenohListen(String),
 
+    /// Set the deployment/tenant key namespace (#446).
+    SetZenohNamespace(String),
+
+    /// Enable or disable multicast scouting (#426).
+    SetMulticastScouting(bool),
+
+    /// Enable or disable gossip scouting (#426).
+    SetGossipScouting(bool),
+
+    /// Set the telemetry subscription allowlist.
+    SetSubscribeAllow(String),
+
+    /// Set the telemetry subscription denylist.
+    SetSubscribeDeny(String),
+    /// Set the per-metric update rate cap (#484).
+    SetSubscribeMaxRate(String),
+    /// Update the per-protocol card sparkline metrics (#420).
+    SetCardMetrics(String),
+
     /// Set stale threshold.
     SetStaleThreshold(String),
 
@@ -484,6 +558,15 @@ pub enum Message {
     /// Set max alerts to keep.
     SetMaxAlerts(String),
 
+    /// Set the tracked-device cap (#425).
+    SetMaxDevices(String),
+
+    /// Set the per-device metric cap (#425).
+    SetMaxMetrics(String),
+
+    /// Set the topology layout frame cap (#473).
+    SetTopologyMaxFps(String),
+
     /// Save settings.
     SaveSettings,
 
@@ -512,6 +595,12 @@ pub enum Message {
     /// Set new rule severity.
     SetAlertRuleSeverity(Severity),
 
+    /// Set what the new rule compares: value, rate (#422) or absence (#423).
+    SetAlertRuleKind(ConditionKind),
+
+    /// Set the new rule's rate-of-change or staleness window, in seconds.
+    SetAlertRuleWindow(String),
+
     /// Add a new alert rule.
     AddAlertRule,
 
@@ -521,6 +610,16 @@ pub enum Message {
     /// Remove an alert rule.
     RemoveAlertRule(u32),
 
+    // Alert rule library files (#424)
+    /// Export the alert rules alone to a JSON file.
+    ExportAlertRules,
+    /// Pick a rule-library file to import with a native open dialog.
+    PickAlertRules(RuleImportMode),
+    /// Import the alert rules in this file, merging or replacing.
+    ImportAlertRules(std::path::PathBuf, RuleImportMode),
+    /// A rule-library file finished reading: its path and rules, or an error.
+    AlertRulesLoaded(Result<(String, Vec<AlertRule>), String>, RuleImportMode),
+
     /// Toggle an alert rule's enabled state.
     ToggleAlertRule(u32),
 
@@ -530,6 +629,9 @@ pub enum Message {
     /// Acknowledge all alerts.
     AcknowledgeAllAlerts,
 
+    /// Acknowledge every alert matching a severity / device / rule filter (#486).
+    AcknowledgeAlertsMatching(AlertAckFilter),
+
     /// Acknowledge all firing external (sensor-pushed) alerts from one source.
     AcknowledgeExternalSource(String),
     /// Acknowledge all firing external alerts.
@@ -540,6 +642,22 @@ pub enum Message {
 
     /// Toggle the opt-in desktop-notifications setting (#26) and persist it.
     ToggleDesktopNotifications,
+
+    /// Toggle the raw-payload inspector in the device view (#414).
+    TogglePayloadInspector,
+    /// Toggle serving buffered history over Zenoh queries (#465) and persist it.
+    ToggleHistoryQueries,
+    /// Toggle the color-blind-safe palette (#472) and persist it.
+    ToggleColorBlindPalette,
+    /// Pick the unit flow volumes are shown in (#477) and persist it.
+    SetFlowVolumeUnit(crate::view::formatting::FlowVolumeUnit),
+    /// Toggle showing absolute times in UTC (#440) and persist it.
+    ToggleUtcTimestamps,
+    /// Toggle slowing the UI tick while the window is unfocused (#470) and
+    /// persist it.
+    TogglePauseWhenUnfocused,
+    /// The main window gained (`true`) or lost (`false`) focus (#470).
+    WindowFocusChanged(bool),
     /// Lift a silence on a source (#26).
     UnsilenceSource(String),
 
@@ -555,7 +673,7 @@ pub enum Message {
     DeleteAlertFilterPreset(usize),
 
     /// Toggle the keyboard-shortcuts help overlay (#28).
-    ToggleHelp,
+    ToggleHelpOverlay,
 
     /// Open the command palette (#28).
     OpenCommandPalette,
@@ -583,10 +701,35 @@ pub enum Message {
     /// Export device metrics to JSON.
     ExportToJson,
 
+    /// Export every known device with its health, last-seen time and active
+    /// alert count, for asset tracking (#437).
+    ExportInventory(crate::device_inventory::InventoryFormat),
+
     /// Outcome of an export save dialog (#37): `Ok(Some(path))` wrote the file,
     /// `Ok(None)` the user cancelled the dialog, `Err(msg)` the write failed.
     ExportFinished(Result<Option<String>, String>),
 
+    // Offline CSV import (#417)
+    /// Pick a telemetry CSV to import with a native open dialog.
+    PickTelemetryCsv,
+    /// Import telemetry from this CSV file as an offline device set.
+    ImportTelemetryCsv(std::path::PathBuf),
+    /// A CSV import finished reading: the path and its parsed points, or an
+    /// error (unreadable file, missing column).
+    TelemetryCsvImported(Result<(String, crate::import::CsvImport), String>),
+
+    // Dashboard snapshots for support bundles (#418)
+    /// Export devices, recent history and sensor health to a snapshot file.
+    ExportSnapshot,
+    /// Pick a snapshot file to open with a native open dialog.
+    PickSnapshot,
+    /// Load this snapshot file into read-only viewing mode.
+    ImportSnapshot(std::path::PathBuf),
+    /// A snapshot file finished loading: its path and contents, or an error.
+    SnapshotLoaded(Result<(String, crate::state_snapshot::StateSnapshot), String>),
+    /// Leave snapshot viewing and return to the live dashboard.
+    CloseSnapshot,
+
     // Debug-report download messages (#197)
     /// Request + download a debug report from the sensor at this key prefix
     /// (e.g. `zensight/netlink`).
@@ -734,6 +877,10 @@ pub enum Message {
     /// Clear topology selection.
     TopologyClearSelection,
 
+    /// Dim everything not directly connected to a topology node (#476);
+    /// isolating the same node again shows the whole graph.
+    TopologyIsolateNode(String),
+
     /// Start dragging a node.
     TopologyDragNodeStart(String, f32, f32),
 
@@ -752,15 +899,29 @@ pub enum Message {
     /// Zoom out on topology.
     TopologyZoomOut,
 
+    /// Wheel-zoom the topology around the cursor (#474); `anchor` is the
+    /// cursor's offset from the canvas center in pixels.
+    TopologyZoomAt {
+        zoom_in: bool,
+        anchor: (f32, f32),
+    },
+
     /// Reset topology zoom.
     TopologyZoomReset,
 
     /// Toggle auto-layout.
     TopologyToggleAutoLayout,
+    /// Hide topology edges that carried fewer bytes (#475); 0 shows all.
+    TopologySetMinEdgeBytes(u64),
+    /// Run a throttled layout frame while the topology settles (#473).
+    TopologyLayoutFrame,
 
     /// Set topology search query.
     TopologySetSearch(String),
 
+    /// Export the current topology graph via a save dialog (#399).
+    ExportTopology(crate::view::topology::TopologyExportFormat),
+
     // Syslog filter messages
     /// Toggle syslog filter panel visibility.
     ToggleSyslogFilterPanel,
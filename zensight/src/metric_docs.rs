@@ -0,0 +1,253 @@
+//! Built-in descriptions of well-known metrics (#485).
+//!
+//! Operators don't always know what `hrProcessorLoad` or `ifInErrors` mean,
+//! so the device view shows a short explanation as a tooltip on the metric
+//! name. [`METRIC_DOCS`] is the single registry it reads from; metrics it
+//! doesn't list simply get no tooltip.
+//!
+//! A pattern without `/` names a metric's last path segment, which suits
+//! MIB objects (`ifInErrors` matches `if/3/ifInErrors`). A numeric instance
+//! suffix is ignored, since resolved SNMP names carry one (`ifInErrors.3`,
+//! `sysUpTime.0`). A pattern with `/`
+//! is matched segment by segment, `*` standing for any one segment
+//! (`cpu/*/usage` matches `cpu/0/usage`).
+
+use zensight_common::Protocol;
+
+/// `(protocol, pattern, description)` for every documented metric.
+pub const METRIC_DOCS: &[(Protocol, &str, &str)] = &[
+    // SNMPv2-MIB system group
+    (
+        Protocol::Snmp,
+        "sysDescr",
+        "Textual description of the device: hardware, OS and software version.",
+    ),
+    (
+        Protocol::Snmp,
+        "sysUpTime",
+        "Time since the SNMP agent last restarted, in hundredths of a second.",
+    ),
+    (
+        Protocol::Snmp,
+        "sysName",
+        "Administratively assigned name of the device.",
+    ),
+    (
+        Protocol::Snmp,
+        "sysLocation",
+        "Physical location of the device, as configured by the operator.",
+    ),
+    (
+        Protocol::Snmp,
+        "sysContact",
+        "Person or team responsible for the device.",
+    ),
+    // IF-MIB interface table
+    (
+        Protocol::Snmp,
+        "ifDescr",
+        "Name of the interface as reported by the device.",
+    ),
+    (
+        Protocol::Snmp,
+        "ifOperStatus",
+        "Current operational state of the interface: 1 up, 2 down, 3 testing.",
+    ),
+    (
+        Protocol::Snmp,
+        "ifAdminStatus",
+        "State the interface is configured to be in: 1 up, 2 down, 3 testing.",
+    ),
+    (
+        Protocol::Snmp,
+        "ifSpeed",
+        "Nominal interface bandwidth in bits per second (caps at 4.29 Gbit/s).",
+    ),
+    (
+        Protocol::Snmp,
+        "ifHighSpeed",
+        "Nominal interface bandwidth in megabits per second.",
+    ),
+    (
+        Protocol::Snmp,
+        "ifInOctets",
+        "Bytes received on the interface (32-bit counter, wraps quickly on fast links).",
+    ),
+    (
+        Protocol::Snmp,
+        "ifOutOctets",
+        "Bytes transmitted on the interface (32-bit counter, wraps quickly on fast links).",
+    ),
+    (
+        Protocol::Snmp,
+        "ifHCInOctets",
+        "Bytes received on the interface (64-bit counter).",
+    ),
+    (
+        Protocol::Snmp,
+        "ifHCOutOctets",
+        "Bytes transmitted on the interface (64-bit counter).",
+    ),
+    (
+        Protocol::Snmp,
+        "ifInUcastPkts",
+        "Unicast packets received on the interface.",
+    ),
+    (
+        Protocol::Snmp,
+        "ifOutUcastPkts",
+        "Unicast packets transmitted on the interface.",
+    ),
+    (
+        Protocol::Snmp,
+        "ifInErrors",
+        "Inbound packets dropped because they contained errors (CRC, framing, runts). \
+         A rising count usually points at cabling, optics or a duplex mismatch.",
+    ),
+    (
+        Protocol::Snmp,
+        "ifOutErrors",
+        "Outbound packets that could not be transmitted because of errors.",
+    ),
+    (
+        Protocol::Snmp,
+        "ifInDiscards",
+        "Error-free inbound packets dropped anyway, typically for lack of buffer space.",
+    ),
+    (
+        Protocol::Snmp,
+        "ifOutDiscards",
+        "Error-free outbound packets dropped anyway, typically because of queue congestion.",
+    ),
+    // HOST-RESOURCES-MIB
+    (
+        Protocol::Snmp,
+        "hrProcessorLoad",
+        "Average percentage of time the processor was busy over the last minute.",
+    ),
+    (
+        Protocol::Snmp,
+        "hrStorageSize",
+        "Size of the storage area (memory or disk), in allocation units.",
+    ),
+    (
+        Protocol::Snmp,
+        "hrStorageUsed",
+        "Part of the storage area currently in use, in allocation units.",
+    ),
+    (
+        Protocol::Snmp,
+        "hrSystemUptime",
+        "Time since the host itself last booted, in hundredths of a second.",
+    ),
+    // Sysinfo
+    (
+        Protocol::Sysinfo,
+        "cpu/usage",
+        "Share of CPU time spent busy across all cores, in percent.",
+    ),
+    (
+        Protocol::Sysinfo,
+        "cpu/*/usage",
+        "Share of this core's time spent busy, in percent.",
+    ),
+    (
+        Protocol::Sysinfo,
+        "memory/usage_percent",
+        "Share of physical memory in use, in percent.",
+    ),
+    (
+        Protocol::Sysinfo,
+        "network/*/rx_rate",
+        "Bytes per second received on the interface since the previous poll.",
+    ),
+    (
+        Protocol::Sysinfo,
+        "network/*/tx_rate",
+        "Bytes per second transmitted on the interface since the previous poll.",
+    ),
+    (
+        Protocol::Sysinfo,
+        "system/load",
+        "Run-queue length averaged over the period in the `period` label; compare with the core count.",
+    ),
+    (
+        Protocol::Sysinfo,
+        "system/uptime",
+        "Seconds since the host booted.",
+    ),
+];
+
+/// The description of `metric` as reported by a `protocol` bridge, if the
+/// registry documents it.
+pub fn metric_description(protocol: Protocol, metric: &str) -> Option<&'static str> {
+    METRIC_DOCS
+        .iter()
+        .find(|(p, pattern, _)| *p == protocol && pattern_matches(pattern, metric))
+        .map(|(_, _, description)| *description)
+}
+
+fn pattern_matches(pattern: &str, metric: &str) -> bool {
+    if !pattern.contains('/') {
+        return metric.rsplit('/').next().map(strip_instance) == Some(pattern);
+    }
+    let mut segments = metric.split('/');
+    pattern
+        .split('/')
+        .all(|p| segments.next().is_some_and(|s| p == "*" || p == s))
+        && segments.next().is_none()
+}
+
+/// `name` without a trailing numeric instance (`ifInErrors.3` → `ifInErrors`,
+/// `ipAdEntAddr.10.0.0.1` → `ipAdEntAddr`).
+fn strip_instance(name: &str) -> &str {
+    match name.split_once('.') {
+        Some((object, instance))
+            if !instance.is_empty()
+                && instance
+                    .split('.')
+                    .all(|p| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit())) =>
+        {
+            object
+        }
+        _ => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_snmp_metric_is_described_and_unknown_is_not() {
+        let description = metric_description(Protocol::Snmp, "if/3/ifInErrors").unwrap();
+        assert!(description.starts_with("Inbound packets dropped"));
+        assert_eq!(
+            metric_description(Protocol::Snmp, "host/hrProcessorLoad"),
+            metric_description(Protocol::Snmp, "hrProcessorLoad"),
+        );
+
+        assert_eq!(metric_description(Protocol::Snmp, "if/3/ifFooBar"), None);
+
+        // Names as the SNMP bridge resolves them carry the instance index.
+        assert_eq!(
+            metric_description(Protocol::Snmp, "ifInErrors.3"),
+            metric_description(Protocol::Snmp, "ifInErrors"),
+        );
+        assert!(metric_description(Protocol::Snmp, "sysUpTime.0").is_some());
+        assert_eq!(metric_description(Protocol::Snmp, "ifInErrors.x"), None);
+        // Protocol context matters: the same name from another bridge is unknown.
+        assert_eq!(metric_description(Protocol::Gnmi, "if/3/ifInErrors"), None);
+    }
+
+    #[test]
+    fn path_patterns_match_whole_segments() {
+        assert!(metric_description(Protocol::Sysinfo, "cpu/0/usage").is_some());
+        assert!(metric_description(Protocol::Sysinfo, "cpu/usage").is_some());
+        assert_eq!(
+            metric_description(Protocol::Sysinfo, "cpu/0/usage/extra"),
+            None
+        );
+        assert_eq!(metric_description(Protocol::Sysinfo, "gpu/0/usage"), None);
+    }
+}
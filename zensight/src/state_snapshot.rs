@@ -0,0 +1,166 @@
+//! Dashboard state snapshots for support bundles (#418).
+//!
+//! When a user reports a problem, "what were you looking at" is hard to
+//! reconstruct. A [`StateSnapshot`] captures the dashboard as it stands —
+//! every device's latest points and status, the recent (hot-ring) history
+//! behind its charts, and the bridge's sensor health — as one JSON file. The
+//! app can load such a file into a read-only viewing mode, where live
+//! telemetry is held off and the snapshot is browsed like a live session.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use zensight_common::{DeviceStatus, HealthSnapshot, Protocol, TelemetryPoint};
+
+use crate::message::DeviceId;
+use crate::store::{MetricStore, Sample};
+use crate::view::dashboard::DeviceState;
+
+/// Snapshot format version; bumped on incompatible changes.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// One device as captured.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeviceSnapshot {
+    pub protocol: Protocol,
+    pub source: String,
+    /// Last update timestamp (Unix epoch ms).
+    pub last_update: i64,
+    /// Status from sensor liveness tracking.
+    pub sensor_status: DeviceStatus,
+    #[serde(default)]
+    pub consecutive_failures: u32,
+    #[serde(default)]
+    pub last_error: Option<String>,
+    /// Latest point per metric, sorted by metric name.
+    pub metrics: Vec<TelemetryPoint>,
+    /// Recent numeric history per metric: `(timestamp_ms, value)` pairs,
+    /// oldest first.
+    #[serde(default)]
+    pub history: BTreeMap<String, Vec<(i64, f64)>>,
+}
+
+/// The full dashboard state, as written to a snapshot file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StateSnapshot {
+    pub version: u32,
+    /// When the snapshot was taken (Unix epoch ms).
+    pub taken_at: i64,
+    /// Devices, sorted by protocol then source.
+    pub devices: Vec<DeviceSnapshot>,
+    /// Bridge health: the latest snapshot from each sensor, sorted by name.
+    #[serde(default)]
+    pub sensor_health: Vec<HealthSnapshot>,
+}
+
+/// Dashboard state rebuilt from a snapshot, ready to swap into the app.
+pub struct RestoredState {
+    pub devices: HashMap<DeviceId, DeviceState>,
+    /// An in-memory store holding the captured history.
+    pub store: MetricStore,
+    pub sensor_health: HashMap<String, HealthSnapshot>,
+}
+
+impl StateSnapshot {
+    /// Capture `devices`, their hot history from `store`, and `sensor_health`.
+    pub fn capture(
+        devices: &HashMap<DeviceId, DeviceState>,
+        store: &MetricStore,
+        sensor_health: &HashMap<String, HealthSnapshot>,
+        taken_at: i64,
+    ) -> Self {
+        let mut devices: Vec<DeviceSnapshot> = devices
+            .values()
+            .map(|device| {
+                let mut metrics: Vec<TelemetryPoint> = device.metrics.values().cloned().collect();
+                metrics.sort_by(|a, b| a.metric.cmp(&b.metric));
+                let history = store
+                    .device_hot_samples(device.id.protocol.as_str(), &device.id.source)
+                    .into_iter()
+                    .filter(|(_, samples)| !samples.is_empty())
+                    .map(|(metric, samples)| {
+                        let pairs = samples.iter().map(|s| (s.ts, s.value)).collect();
+                        (metric, pairs)
+                    })
+                    .collect();
+                DeviceSnapshot {
+                    protocol: device.id.protocol,
+                    source: device.id.source.clone(),
+                    last_update: device.last_update,
+                    sensor_status: device.sensor_status,
+                    consecutive_failures: device.consecutive_failures,
+                    last_error: device.last_error.clone(),
+                    metrics,
+                    history,
+                }
+            })
+            .collect();
+        devices.sort_by(|a, b| (a.protocol, &a.source).cmp(&(b.protocol, &b.source)));
+
+        let mut sensor_health: Vec<HealthSnapshot> = sensor_health.values().cloned().collect();
+        sensor_health.sort_by(|a, b| a.sensor.cmp(&b.sensor));
+
+        Self {
+            version: SNAPSHOT_VERSION,
+            taken_at,
+            devices,
+            sensor_health,
+        }
+    }
+
+    /// Pretty-printed JSON, for a file a human may also open.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    /// Parse a snapshot file, rejecting versions this build doesn't know.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let snapshot: Self = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        if snapshot.version > SNAPSHOT_VERSION {
+            return Err(format!(
+                "snapshot version {} is newer than this build supports ({SNAPSHOT_VERSION})",
+                snapshot.version
+            ));
+        }
+        Ok(snapshot)
+    }
+
+    /// Rebuild device state, history and sensor health from the snapshot.
+    pub fn restore(self) -> RestoredState {
+        let mut store = MetricStore::new(crate::store::DEFAULT_HOT_CAPACITY, None);
+        let mut devices = HashMap::new();
+        for device in self.devices {
+            let id = DeviceId::new(device.protocol, &device.source);
+            for (metric, samples) in &device.history {
+                let samples: Vec<Sample> = samples
+                    .iter()
+                    .map(|&(ts, value)| Sample { ts, value })
+                    .collect();
+                store.restore_hot(device.protocol.as_str(), &device.source, metric, &samples);
+            }
+            let mut state = DeviceState::new(id.clone());
+            state.last_update = device.last_update;
+            state.sensor_status = device.sensor_status;
+            state.consecutive_failures = device.consecutive_failures;
+            state.last_error = device.last_error;
+            state.metrics = device
+                .metrics
+                .into_iter()
+                .map(|p| (p.metric.clone(), p))
+                .collect();
+            state.metric_count = state.metrics.len();
+            devices.insert(id, state);
+        }
+        let sensor_health = self
+            .sensor_health
+            .into_iter()
+            .map(|h| (h.sensor.clone(), h))
+            .collect();
+        RestoredState {
+            devices,
+            store,
+            sensor_health,
+        }
+    }
+}
@@ -147,8 +147,10 @@ pub fn telemetry_to_f64(value: &TelemetryValue) -> Option<f64> {
         // Booleans become a 0/1 step series (#126) so flap-prone signals (iface
         // up/carrier, route present, wg up) get history + trend, not a snapshot.
         TelemetryValue::Boolean(b) => Some(if *b { 1.0 } else { 0.0 }),
+        // A histogram (#404) trends as its mean; an empty one has no value yet.
+        TelemetryValue::Histogram { sum, count, .. } => (*count > 0).then(|| sum / *count as f64),
         // Text/binary aren't numeric series — skip, don't fake a 0.
-        TelemetryValue::Text(_) | TelemetryValue::Binary(_) => None,
+        TelemetryValue::Text(_) | TelemetryValue::Binary(_) | TelemetryValue::Bytes(_) => None,
     }
 }
 
@@ -833,6 +835,23 @@ impl MetricStore {
         series.pending.push(sample);
     }
 
+    /// Load previously captured samples into a metric's hot ring, e.g. from a
+    /// dashboard snapshot (#418). They are not queued for flush, so restored
+    /// history never reaches the persistent tiers.
+    pub fn restore_hot(&mut self, protocol: &str, source: &str, metric: &str, samples: &[Sample]) {
+        let id = self
+            .interner
+            .intern(&format!("{protocol}/{source}|{metric}"));
+        let capacity = self.hot_capacity;
+        let series = self.series.entry(id).or_insert_with(|| MetricSeries {
+            hot: RingBuffer::new(capacity),
+            pending: Vec::new(),
+        });
+        for &sample in samples {
+            series.hot.push(sample);
+        }
+    }
+
     /// Whether there are pending samples awaiting flush.
     pub fn has_pending(&self) -> bool {
         self.series.values().any(|s| !s.pending.is_empty())
@@ -897,6 +916,15 @@ impl MetricStore {
             .unwrap_or_default()
     }
 
+    /// Every metric's hot ring, keyed by its `"<protocol>/<source>|<metric>"`
+    /// path. Reads only memory, so the history query API (#465) can answer
+    /// from it on the UI thread.
+    pub fn hot_series(&self) -> impl Iterator<Item = (&str, &RingBuffer)> {
+        self.series
+            .iter()
+            .filter_map(|(id, series)| Some((self.interner.resolve(*id)?, &series.hot)))
+    }
+
     /// Hot (in-memory) samples for every metric of a device, oldest-first.
     /// Returns `(metric_suffix, samples)` pairs. Reads only the in-memory ring
     /// (no disk), so it's cheap to call per dashboard render (#24 sparklines).
@@ -1,15 +1,23 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
 use iced::Subscription;
 use iced::keyboard::{self, Key, key};
 
+use serde::{Deserialize, Serialize};
+use zenoh::key_expr::{OwnedKeyExpr, keyexpr};
 use zenoh::sample::SampleKind;
 use zenoh_ext::{AdvancedSubscriberBuilderExt, HistoryConfig, RecoveryConfig};
 
 use zensight_common::{
-    Alert, CorrelationEntry, DeviceLiveness, ErrorReport, HealthSnapshot, SensorInfo,
-    TelemetryPoint, ZenohConfig, all_telemetry_wildcard, decode_auto,
+    Alert, CorrelationEntry, DeviceLiveness, DiscoveryEvent, ErrorReport, HealthSnapshot,
+    KeyExprBuilder, Protocol, SampleMetadata, SensorInfo, TelemetryPoint, ZenohConfig,
+    all_telemetry_wildcard, decode_auto,
 };
 
-use crate::message::Message;
+use crate::app::is_log_event;
+use crate::message::{DeviceId, Message};
+use crate::view::device::label_signature;
 
 /// Key expression for sensor liveliness tokens.
 const SENSOR_LIVELINESS_EXPR: &str = "zensight/*/@/alive";
@@ -17,10 +25,181 @@ const SENSOR_LIVELINESS_EXPR: &str = "zensight/*/@/alive";
 /// Key expression for device liveliness tokens.
 const DEVICE_LIVELINESS_EXPR: &str = "zensight/*/@/devices/*/alive";
 
+/// Which telemetry keys the GUI subscribes to (#413).
+///
+/// On a busy fabric, subscribing to all of `zensight/**` ingests every
+/// bridge's telemetry. Each `allow` entry is a protocol name (`snmp` →
+/// `zensight/snmp/**`) or a Zenoh key expression, and only those are
+/// declared. Zenoh has no negative key expressions, so `deny` entries (same
+/// syntax) are dropped on receipt instead. Both empty means everything.
+/// Control-plane keys (`@/health`, `@/alerts`, …) are not filtered.
+///
+/// `max_rate` caps how many updates per second each metric may deliver
+/// (#484); see [`Decimator`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SubscriptionFilter {
+    /// Protocols / key expressions to subscribe to; empty for all.
+    #[serde(default)]
+    pub allow: Vec<String>,
+    /// Protocols / key expressions to drop from the allowed set.
+    #[serde(default)]
+    pub deny: Vec<String>,
+    /// Maximum updates per second kept per metric; 0 for no limit.
+    #[serde(default)]
+    pub max_rate: u32,
+}
+
+impl SubscriptionFilter {
+    /// Telemetry key expressions to declare, one subscriber each.
+    pub fn key_exprs(&self) -> Vec<String> {
+        if self.allow.is_empty() {
+            return vec![all_telemetry_wildcard()];
+        }
+        let mut exprs: Vec<String> = Vec::new();
+        for expr in self.allow.iter().map(|e| expand_filter_entry(e)) {
+            if !exprs.contains(&expr) {
+                exprs.push(expr);
+            }
+        }
+        exprs
+    }
+
+    /// The `deny` entries parsed into key expressions, once per
+    /// subscription rather than per sample. Entries that don't parse are
+    /// skipped; [`validate`](Self::validate) rejects them up front.
+    pub fn deny_list(&self) -> DenyList {
+        DenyList(
+            self.deny
+                .iter()
+                .filter_map(|entry| OwnedKeyExpr::new(expand_filter_entry(entry)).ok())
+                .collect(),
+        )
+    }
+
+    /// Reject entries Zenoh wouldn't accept as key expressions.
+    pub fn validate(&self) -> Result<(), String> {
+        for entry in self.allow.iter().chain(&self.deny) {
+            keyexpr::new(expand_filter_entry(entry).as_str())
+                .map_err(|e| format!("Invalid subscription filter `{}`: {}", entry, e))?;
+        }
+        Ok(())
+    }
+}
+
+/// A [`SubscriptionFilter`]'s parsed `deny` entries (#413).
+#[derive(Debug, Clone, Default)]
+pub struct DenyList(Vec<OwnedKeyExpr>);
+
+impl DenyList {
+    /// Whether a sample on `key` should be dropped.
+    pub fn denies(&self, key: &str) -> bool {
+        if self.0.is_empty() {
+            return false;
+        }
+        let Ok(key) = keyexpr::new(key) else {
+            return false;
+        };
+        self.0.iter().any(|pattern| pattern.includes(key))
+    }
+}
+
+/// Per-metric rate cap on the telemetry stream (#484).
+///
+/// gNMI on-change streams can deliver thousands of updates per second for a
+/// single metric, more than the UI can chart. With a cap of `n`/s, points
+/// are held for a `1/n` s window and only the latest per metric is released
+/// when it closes, so each metric updates at most `n` times a second while
+/// its last value (and so the trend) is never lost. Log events aren't a
+/// series, so they pass straight through, as does the startup history replay
+/// (see [`decimate`]).
+#[derive(Debug)]
+pub struct Decimator {
+    window: Duration,
+    /// When the open window closes; `None` while nothing is held.
+    closes_at: Option<Instant>,
+    /// Latest point per `<protocol>/<source>/<metric>` and label set in the
+    /// open window, so label variants (#469) are each kept.
+    pending: HashMap<String, TelemetryPoint>,
+}
+
+impl Decimator {
+    /// A decimator keeping at most `max_rate` updates per second per metric,
+    /// or `None` when `max_rate` is 0 (no limit).
+    pub fn new(max_rate: u32) -> Option<Self> {
+        (max_rate > 0).then(|| Self {
+            window: Duration::from_secs(1) / max_rate,
+            closes_at: None,
+            pending: HashMap::new(),
+        })
+    }
+
+    /// Hold `point` until the window open at `now` closes, replacing any
+    /// earlier point of the same metric and labels. A log event is handed
+    /// back to be delivered now.
+    pub fn admit(&mut self, point: TelemetryPoint, now: Instant) -> Option<TelemetryPoint> {
+        if is_log_event(&point) {
+            return Some(point);
+        }
+        self.closes_at.get_or_insert(now + self.window);
+        let key = format!(
+            "{}/{}/{}|{}",
+            point.protocol,
+            point.source,
+            point.metric,
+            label_signature(&point.labels)
+        );
+        self.pending.insert(key, point);
+        None
+    }
+
+    /// When the open window closes, if one is open.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.closes_at
+    }
+
+    /// Close the window, releasing the latest point of each metric.
+    pub fn flush(&mut self) -> Vec<TelemetryPoint> {
+        self.closes_at = None;
+        self.pending.drain().map(|(_, point)| point).collect()
+    }
+}
+
+/// Pass a batch through `decimator`, returning what to deliver now. The first
+/// `replayed` points are the startup history replay and are all kept: they
+/// are distinct past samples, not a burst of updates to thin out.
+fn decimate(
+    decimator: &mut Decimator,
+    mut points: Vec<TelemetryPoint>,
+    replayed: usize,
+    now: Instant,
+) -> Vec<TelemetryPoint> {
+    let live = points.split_off(replayed.min(points.len()));
+    points.extend(
+        live.into_iter()
+            .filter_map(|point| decimator.admit(point, now)),
+    );
+    points
+}
+
+/// A protocol name becomes its `zensight/<protocol>/**` wildcard; anything
+/// else is taken as a key expression.
+fn expand_filter_entry(entry: &str) -> String {
+    match entry.parse::<Protocol>() {
+        Ok(protocol) => KeyExprBuilder::new(protocol).protocol_wildcard(),
+        Err(()) => entry.to_string(),
+    }
+}
+
 /// Create a subscription that connects to Zenoh and receives telemetry.
-pub fn zenoh_subscription(config: ZenohConfig) -> Subscription<Message> {
-    Subscription::run_with(config, move |config| {
+///
+/// Changing `config` or `filter` restarts the subscription.
+pub fn zenoh_subscription(
+    config: ZenohConfig,
+    filter: SubscriptionFilter,
+) -> Subscription<Message> {
+    Subscription::run_with((config, filter), move |(config, filter)| {
         let config = config.clone();
+        let filter = filter.clone();
         async_stream::stream! {
             // Signal that we're attempting to connect
             yield Message::Connecting;
@@ -162,56 +341,110 @@ pub fn zenoh_subscription(config: ZenohConfig) -> Subscription<Message> {
             // caches; "Advanced subscriber created" never logged). Memory stays
             // bounded in practice: publisher caches are finite and the drain
             // loop batches the backlog away as soon as the declare completes.
-            let key_expr = all_telemetry_wildcard();
-            let subscriber = match session
-                .declare_subscriber(&key_expr)
-                .with(flume::unbounded())
-                .history(HistoryConfig::default().detect_late_publishers())
-                .recovery(RecoveryConfig::default())
-                .subscriber_detection()
-                .await
-            {
-                Ok(sub) => sub,
-                Err(e) => {
-                    tracing::error!(error = %e, "Failed to create advanced subscriber");
-                    yield Message::Disconnected(e.to_string());
-                    return;
+            //
+            // With a subscription allowlist (#413) there is one subscriber per
+            // allowed key expression, all feeding the same channel.
+            let (tx, samples) = flume::unbounded();
+            let mut subscribers = Vec::new();
+            for key_expr in filter.key_exprs() {
+                match session
+                    .declare_subscriber(&key_expr)
+                    .with((tx.clone(), samples.clone()))
+                    .history(HistoryConfig::default().detect_late_publishers())
+                    .recovery(RecoveryConfig::default())
+                    .subscriber_detection()
+                    .await
+                {
+                    Ok(sub) => subscribers.push(sub),
+                    Err(e) => {
+                        tracing::error!(
+                            error = %e,
+                            key_expr = %key_expr,
+                            "Failed to create advanced subscriber"
+                        );
+                        yield Message::Disconnected(e.to_string());
+                        return;
+                    }
                 }
-            };
-
-            tracing::info!("Advanced subscriber created with history and recovery");
+            }
+            // Only the subscribers hold senders now, so the channel reports
+            // disconnection once they are all gone.
+            drop(tx);
+
+            // Declaring waits for the publishers' history, so whatever is
+            // queued now is the startup replay, which the rate cap leaves
+            // alone (#484).
+            let mut replay = samples.len();
+
+            tracing::info!(
+                key_exprs = ?filter.key_exprs(),
+                "Advanced subscriber created with history and recovery"
+            );
+
+            // Last provenance seen per metric (#444): forwarded only when it
+            // changes, so attachments don't add a message per sample. Keyed by
+            // device and metric name like the dashboard's metric map; per-line
+            // log events are skipped so they can't grow it.
+            let mut provenance: HashMap<(DeviceId, String), SampleMetadata> = HashMap::new();
+
+            // Optional per-metric rate cap (#484).
+            let mut decimator = Decimator::new(filter.max_rate);
+            let deny = filter.deny_list();
 
             // Process incoming samples from all subscriptions
             loop {
+                let flush_at = decimator.as_ref().and_then(Decimator::deadline);
                 tokio::select! {
                     // Telemetry subscription. One awaited sample, then an
                     // opportunistic drain of whatever else is already queued:
                     // a startup history burst (or any streaming spike) becomes
                     // ONE batched message per iced update instead of thousands
                     // of per-sample updates starving the UI thread.
-                    result = subscriber.recv_async() => {
+                    result = samples.recv_async() => {
                         match result {
                             Ok(sample) => {
                                 let mut telemetry: Vec<TelemetryPoint> = Vec::new();
                                 let mut others: Vec<Message> = Vec::new();
-                                if let Some(msg) = sample_to_message(&sample) {
-                                    push_sorted(msg, &mut telemetry, &mut others);
-                                }
-                                while telemetry.len() < TELEMETRY_BATCH_MAX {
-                                    match subscriber.try_recv() {
-                                        Ok(s) => {
-                                            if let Some(msg) = sample_to_message(&s) {
-                                                push_sorted(msg, &mut telemetry, &mut others);
+                                let mut changed = Vec::new();
+                                // History samples come first in the channel, so
+                                // these lead `telemetry`.
+                                let mut replayed = 0;
+                                let mut next = Some(sample);
+                                while let Some(s) = next.take() {
+                                    let from_history = replay > 0;
+                                    replay = replay.saturating_sub(1);
+                                    if let Some(msg) = sample_to_message(&s, &deny) {
+                                        if from_history
+                                            && matches!(msg, Message::TelemetryReceived(_))
+                                        {
+                                            replayed += 1;
+                                        }
+                                        if let Message::TelemetryReceived(point) = &msg
+                                            && !is_log_event(point)
+                                            && let Some(meta) = sample_provenance(&s)
+                                        {
+                                            let metric =
+                                                (DeviceId::from_telemetry(point), point.metric.clone());
+                                            if provenance.get(&metric) != Some(&meta) {
+                                                provenance.insert(metric.clone(), meta.clone());
+                                                changed.push((metric.0, metric.1, meta));
                                             }
                                         }
-                                        Err(_) => break,
+                                        push_sorted(msg, &mut telemetry, &mut others);
                                     }
+                                    if telemetry.len() < TELEMETRY_BATCH_MAX {
+                                        next = samples.try_recv().ok();
+                                    }
+                                }
+                                if let Some(decimator) = decimator.as_mut() {
+                                    telemetry =
+                                        decimate(decimator, telemetry, replayed, Instant::now());
+                                }
+                                if let Some(msg) = telemetry_message(telemetry) {
+                                    yield msg;
                                 }
-                                match telemetry.len() {
-                                    0 => {}
-                                    1 => yield Message::TelemetryReceived(
-                                        telemetry.pop().expect("len checked")),
-                                    _ => yield Message::TelemetryBatch(telemetry),
+                                if !changed.is_empty() {
+                                    yield Message::ProvenanceReceived(changed);
                                 }
                                 for msg in others {
                                     yield msg;
@@ -225,6 +458,20 @@ pub fn zenoh_subscription(config: ZenohConfig) -> Subscription<Message> {
                         }
                     }
 
+                    // Decimation window closed: release what it held.
+                    _ = async {
+                        match flush_at {
+                            Some(at) => tokio::time::sleep_until(at.into()).await,
+                            None => std::future::pending().await,
+                        }
+                    } => {
+                        if let Some(decimator) = decimator.as_mut()
+                            && let Some(msg) = telemetry_message(decimator.flush())
+                        {
+                            yield msg;
+                        }
+                    }
+
                     // Sensor liveliness subscription
                     result = async {
                         match &sensor_liveliness {
@@ -290,8 +537,12 @@ pub fn zenoh_subscription(config: ZenohConfig) -> Subscription<Message> {
 const TELEMETRY_BATCH_MAX: usize = 512;
 
 /// Decode one subscriber sample into a message (Delete = alert tombstone).
-fn sample_to_message(sample: &zenoh::sample::Sample) -> Option<Message> {
+/// Samples on denied keys yield nothing.
+fn sample_to_message(sample: &zenoh::sample::Sample, deny: &DenyList) -> Option<Message> {
     let key = sample.key_expr().as_str();
+    if deny.denies(key) {
+        return None;
+    }
     if sample.kind() == SampleKind::Delete {
         parse_alert_cleared(key)
     } else {
@@ -299,6 +550,20 @@ fn sample_to_message(sample: &zenoh::sample::Sample) -> Option<Message> {
     }
 }
 
+/// Bridge metadata attached to a sample (#444), if any.
+fn sample_provenance(sample: &zenoh::sample::Sample) -> Option<SampleMetadata> {
+    SampleMetadata::from_attachment(&sample.attachment()?.to_bytes())
+}
+
+/// One message for a batch of telemetry points, `None` when empty.
+fn telemetry_message(mut points: Vec<TelemetryPoint>) -> Option<Message> {
+    match points.len() {
+        0 => None,
+        1 => points.pop().map(Message::TelemetryReceived),
+        _ => Some(Message::TelemetryBatch(points)),
+    }
+}
+
 /// Route a decoded message into the telemetry batch or the pass-through list.
 fn push_sorted(msg: Message, telemetry: &mut Vec<TelemetryPoint>, others: &mut Vec<Message>) {
     match msg {
@@ -374,6 +639,7 @@ fn parse_alert_cleared(key: &str) -> Option<Message> {
 /// - `zensight/<protocol>/@/health` -> HealthSnapshot
 /// - `zensight/<protocol>/@/devices/<device>/liveness` -> DeviceLiveness
 /// - `zensight/<protocol>/@/errors` -> ErrorReport
+/// - `zensight/<protocol>/@/discovery` -> DiscoveryEvent
 /// - `zensight/_meta/sensors/<name>` -> SensorInfo
 /// - `zensight/_meta/correlation/<ip>` -> CorrelationEntry
 /// - `zensight/<protocol>/<source>/<metric>` -> TelemetryPoint
@@ -430,6 +696,14 @@ fn decode_sample(key: &str, payload: &[u8]) -> Option<Message> {
                     None
                 }
             };
+        } else if segment3 == "discovery" {
+            return match decode_auto::<DiscoveryEvent>(payload) {
+                Ok(event) => Some(Message::DiscoveryReceived(segment1.to_string(), event)),
+                Err(e) => {
+                    tracing::warn!(error = %e, key = %key, "Failed to decode DiscoveryEvent");
+                    None
+                }
+            };
         } else if segment3 == "devices"
             && let Some((device, suffix)) = rest_after_seg3.split_once('/')
             && suffix == "liveness"
@@ -470,34 +744,16 @@ fn decode_sample(key: &str, payload: &[u8]) -> Option<Message> {
 
 /// Connect to Zenoh using the provided configuration.
 async fn connect_zenoh(config: &ZenohConfig) -> anyhow::Result<zenoh::Session> {
-    let mut zenoh_config = zenoh::Config::default();
-
-    // Set mode
-    let mode_str = format!("\"{}\"", config.mode);
-    zenoh_config
-        .insert_json5("mode", &mode_str)
-        .map_err(|e| anyhow::anyhow!("Failed to set mode: {}", e))?;
-
-    // Set connect endpoints
-    if !config.connect.is_empty() {
-        let endpoints_json = serde_json::to_string(&config.connect)?;
-        zenoh_config
-            .insert_json5("connect/endpoints", &endpoints_json)
-            .map_err(|e| anyhow::anyhow!("Failed to set connect endpoints: {}", e))?;
-    }
-
-    // Set listen endpoints
-    if !config.listen.is_empty() {
-        let endpoints_json = serde_json::to_string(&config.listen)?;
-        zenoh_config
-            .insert_json5("listen/endpoints", &endpoints_json)
-            .map_err(|e| anyhow::anyhow!("Failed to set listen endpoints: {}", e))?;
-    }
+    // Mode, endpoints and scouting (#426), built the same way as the sensors'.
+    let zenoh_config = zensight_common::session_config(config)?;
 
     tracing::info!(
         mode = %config.mode,
         connect = ?config.connect,
         listen = ?config.listen,
+        multicast_scouting = config.multicast_scouting,
+        gossip_scouting = config.gossip_scouting,
+        namespace = ?config.namespace(),
         "Connecting to Zenoh"
     );
 
@@ -510,9 +766,26 @@ async fn connect_zenoh(config: &ZenohConfig) -> anyhow::Result<zenoh::Session> {
     Ok(session)
 }
 
-/// Create a tick subscription for periodic UI updates.
-pub fn tick_subscription() -> Subscription<Message> {
-    iced::time::every(std::time::Duration::from_secs(1)).map(|_| Message::Tick)
+/// Create a tick subscription for periodic UI updates, firing every
+/// `interval` (slowed down while the window is unfocused, #470).
+pub fn tick_subscription(interval: std::time::Duration) -> Subscription<Message> {
+    iced::time::every(interval).map(|_| Message::Tick)
+}
+
+/// Drive topology layout frames every `interval` (#473). Only subscribed while
+/// the topology is open and still settling, so a stable graph costs nothing.
+pub fn topology_layout_subscription(interval: std::time::Duration) -> Subscription<Message> {
+    iced::time::every(interval).map(|_| Message::TopologyLayoutFrame)
+}
+
+/// Report window focus changes (#470), so the app can slow its tick while
+/// it's in the background.
+pub fn focus_subscription() -> Subscription<Message> {
+    iced::window::events().filter_map(|(_, event)| match event {
+        iced::window::Event::Focused => Some(Message::WindowFocusChanged(true)),
+        iced::window::Event::Unfocused => Some(Message::WindowFocusChanged(false)),
+        _ => None,
+    })
 }
 
 /// Create a keyboard subscription for global shortcuts.
@@ -520,6 +793,8 @@ pub fn tick_subscription() -> Subscription<Message> {
 /// Handles:
 /// - Ctrl+F: Focus search input
 /// - Escape: Close dialogs, clear selections
+///
+/// Keep `view::help::SHORTCUTS` in step when adding a binding here (#401).
 pub fn keyboard_subscription() -> Subscription<Message> {
     keyboard::listen()
         .map(|event| {
@@ -536,7 +811,7 @@ pub fn keyboard_subscription() -> Subscription<Message> {
 
                     // "?": Toggle the keyboard-shortcuts help overlay (#28).
                     // No modifier check — "?" is itself Shift+/ on most layouts.
-                    Key::Character("?") if !modifiers.control() => Some(Message::ToggleHelp),
+                    Key::Character("?") if !modifiers.control() => Some(Message::ToggleHelpOverlay),
 
                     // Escape: Close/back
                     Key::Named(key::Named::Escape) => Some(Message::EscapePressed),
@@ -559,23 +834,40 @@ pub fn keyboard_subscription() -> Subscription<Message> {
 pub fn demo_subscription() -> Subscription<Message> {
     Subscription::run(|| {
         async_stream::stream! {
-            use crate::demo::DemoSimulator;
+            use crate::demo::{DemoClock, DemoScenario, DemoSimulator};
 
             // Signal connected state (demo mode has no real session)
             yield Message::Connected(None);
 
-            // Create the demo simulator
-            let mut simulator = DemoSimulator::new();
+            // Create the demo simulator, running the scenario picked via
+            // ZENSIGHT_DEMO_SCENARIO (#455)
+            let scenario = DemoScenario::from_env();
+            tracing::info!(%scenario, "Demo scenario");
+            let mut simulator = DemoSimulator::with_scenario(scenario);
             let mut tick_count = 0u64;
 
+            // Cross-protocol correlations for the related-devices view (#456)
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_millis() as i64)
+                .unwrap_or(0);
+            for entry in simulator.generate_correlations(now) {
+                yield Message::CorrelationReceived(entry);
+            }
+
+            // ZENSIGHT_DEMO_SPEED fast-forwards through the last hour (#457)
+            let mut clock = DemoClock::new(now, DemoClock::speed_from_env());
+
             loop {
                 // Update interval (500-800ms for responsive UI)
                 tokio::time::sleep(std::time::Duration::from_millis(600)).await;
 
-                let now = std::time::SystemTime::now()
+                let real_now = std::time::SystemTime::now()
                     .duration_since(std::time::UNIX_EPOCH)
                     .map(|d| d.as_millis() as i64)
                     .unwrap_or(0);
+                let now = clock.advance(600, real_now);
+                simulator.set_time_scale(clock.speed());
 
                 // Generate a tick of telemetry
                 let points = simulator.tick(now);
@@ -622,6 +914,11 @@ pub fn demo_subscription() -> Subscription<Message> {
                     yield Message::AlertReceived(alert);
                 }
 
+                // Sensor error reports for anomalies that just started
+                for (sensor, report) in simulator.generate_error_reports(now) {
+                    yield Message::ErrorReportReceived(sensor, report);
+                }
+
                 // Every 5 ticks (~3 seconds), generate health snapshots
                 if tick_count.is_multiple_of(5) {
                     for snapshot in simulator.generate_health_snapshots() {
@@ -647,6 +944,157 @@ pub fn demo_subscription() -> Subscription<Message> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_subscription_key_exprs_follow_allowlist() {
+        assert_eq!(
+            SubscriptionFilter::default().key_exprs(),
+            vec!["zensight/**"]
+        );
+
+        let filter = SubscriptionFilter {
+            allow: vec![
+                "snmp".to_string(),
+                "zensight/netflow/edge-*/**".to_string(),
+                "SNMP".to_string(),
+            ],
+            deny: vec!["zensight/snmp/lab-router/**".to_string()],
+            ..Default::default()
+        };
+        filter.validate().unwrap();
+        assert_eq!(
+            filter.key_exprs(),
+            vec!["zensight/snmp/**", "zensight/netflow/edge-*/**"]
+        );
+        let deny = filter.deny_list();
+        assert!(deny.denies("zensight/snmp/lab-router/sysUpTime"));
+        assert!(!deny.denies("zensight/snmp/core-router/sysUpTime"));
+        assert!(
+            !SubscriptionFilter::default()
+                .deny_list()
+                .denies("zensight/snmp/r/m")
+        );
+
+        let bad = SubscriptionFilter {
+            allow: vec!["zensight//snmp".to_string()],
+            ..Default::default()
+        };
+        assert!(bad.validate().is_err());
+    }
+
+    #[test]
+    fn decimator_keeps_latest_point_per_window() {
+        assert!(Decimator::new(0).is_none());
+        let mut decimator = Decimator::new(10).unwrap();
+        let start = Instant::now();
+        assert_eq!(decimator.deadline(), None);
+
+        // 100 updates of one metric inside a single 100 ms window.
+        for i in 0..100u32 {
+            let point = TelemetryPoint::new(
+                "router01",
+                Protocol::Gnmi,
+                "interfaces/eth0/in-octets",
+                zensight_common::TelemetryValue::Counter(i.into()),
+            );
+            let held = decimator.admit(point, start + Duration::from_micros(i as u64 * 500));
+            assert!(held.is_none());
+        }
+        assert_eq!(
+            decimator.deadline(),
+            Some(start + Duration::from_millis(100))
+        );
+
+        let kept = decimator.flush();
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].value, zensight_common::TelemetryValue::Counter(99));
+        assert_eq!(decimator.deadline(), None);
+        assert!(decimator.flush().is_empty());
+    }
+
+    #[test]
+    fn decimator_passes_log_events_and_history_through() {
+        let mut decimator = Decimator::new(10).unwrap();
+        let start = Instant::now();
+        let event = |uid: &str| {
+            TelemetryPoint::new(
+                "web01",
+                Protocol::Logs,
+                format!("events/{uid}"),
+                zensight_common::TelemetryValue::Text("disk full".into()),
+            )
+        };
+        let update = |v: u64| {
+            TelemetryPoint::new(
+                "router01",
+                Protocol::Gnmi,
+                "interfaces/eth0/in-octets",
+                zensight_common::TelemetryValue::Counter(v),
+            )
+        };
+        let values = |points: Vec<TelemetryPoint>| -> Vec<zensight_common::TelemetryValue> {
+            points.into_iter().map(|p| p.value).collect()
+        };
+
+        // Two log events in one window both arrive, immediately.
+        let first = decimator.admit(event("a"), start).expect("delivered now");
+        let second = decimator.admit(event("b"), start).expect("delivered now");
+        assert_eq!(
+            (first.metric.as_str(), second.metric.as_str()),
+            ("events/a", "events/b")
+        );
+        assert_eq!(decimator.deadline(), None);
+
+        // A history replay of three samples is delivered whole; the live
+        // updates after it are held for the window.
+        let batch = (1..=5).map(update).collect();
+        let now = decimate(&mut decimator, batch, 3, start);
+        assert_eq!(
+            values(now),
+            (1..=3)
+                .map(zensight_common::TelemetryValue::Counter)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            values(decimator.flush()),
+            vec![zensight_common::TelemetryValue::Counter(5)]
+        );
+    }
+
+    #[test]
+    fn decimator_keeps_each_label_variant() {
+        let mut decimator = Decimator::new(10).unwrap();
+        let start = Instant::now();
+        // sysinfo publishes `system/load` once per period label each poll.
+        for round in 0..3 {
+            for period in ["1m", "5m", "15m"] {
+                let point = TelemetryPoint::new(
+                    "web01",
+                    Protocol::Sysinfo,
+                    "system/load",
+                    zensight_common::TelemetryValue::Gauge(round as f64),
+                )
+                .with_label("period", period);
+                let _ = decimator.admit(point, start);
+            }
+        }
+
+        let mut kept: Vec<(String, zensight_common::TelemetryValue)> = decimator
+            .flush()
+            .into_iter()
+            .map(|p| (p.labels["period"].clone(), p.value))
+            .collect();
+        kept.sort_by(|a, b| a.0.cmp(&b.0));
+        let latest = zensight_common::TelemetryValue::Gauge(2.0);
+        assert_eq!(
+            kept,
+            vec![
+                ("15m".to_string(), latest.clone()),
+                ("1m".to_string(), latest.clone()),
+                ("5m".to_string(), latest),
+            ]
+        );
+    }
+
     #[test]
     fn test_parse_sensor_liveliness_online() {
         let key = "zensight/snmp/@/alive";
@@ -742,4 +1190,23 @@ mod tests {
             other => panic!("expected AlertReceived, got {other:?}"),
         }
     }
+
+    #[test]
+    fn test_decode_sample_discovery() {
+        let event = DiscoveryEvent {
+            device: "router01".into(),
+            kind: zensight_common::DiscoveryKind::Interface,
+            item: Some("if/5".into()),
+            label: Some("Gi0/5".into()),
+            first_seen: 1_700_000_000_000,
+        };
+        let payload = zensight_common::encode(&event, zensight_common::Format::Json).unwrap();
+        match decode_sample("zensight/snmp/@/discovery", &payload) {
+            Some(Message::DiscoveryReceived(sensor, got)) => {
+                assert_eq!(sensor, "snmp");
+                assert_eq!(got, event);
+            }
+            other => panic!("expected DiscoveryReceived, got {other:?}"),
+        }
+    }
 }
@@ -11,8 +11,10 @@ use iced_anim::widget::button;
 use zensight_common::{Alert as SensorAlert, AlertState as SensorAlertState, Protocol};
 
 use crate::message::{DeviceId, Message};
+use crate::store::Sample;
 use crate::view::components::{badge, empty_state, section_header};
-use crate::view::formatting::{format_timestamp, format_value};
+use crate::view::formatting::{format_relative_time, format_value};
+use crate::view::health::AlertLoad;
 use crate::view::icons::{self, IconSize};
 use crate::view::tokens::{font, space};
 
@@ -41,12 +43,218 @@ pub struct AlertRule {
     pub operator: ComparisonOp,
     /// Threshold value.
     pub threshold: f64,
+    /// What is compared against the threshold: the value itself, or its
+    /// slope (#422).
+    #[serde(default)]
+    pub condition: AlertCondition,
+    /// A boolean expression over several of a device's metrics (#488). When
+    /// set it replaces the single metric/operator/threshold test.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compound: Option<CompoundCondition>,
     /// Severity level for triggered alerts.
     pub severity: Severity,
+    /// Severity an alert of this rule escalates to once it has stayed
+    /// unacknowledged for `escalate_after_secs` (#489). `None` never escalates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub escalate_to: Option<Severity>,
+    /// How long an alert may stay unacknowledged before escalating.
+    #[serde(default)]
+    pub escalate_after_secs: u64,
     /// Whether this rule is enabled.
     pub enabled: bool,
 }
 
+/// What an [`AlertRule`] compares against its threshold (#422).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AlertCondition {
+    /// The metric's current value.
+    #[default]
+    Threshold,
+    /// The metric's slope in units per minute, fitted over the buffered
+    /// samples of the last `window_secs` seconds. Catches gradual problems
+    /// (a disk filling) that an absolute threshold only sees too late.
+    RateOfChange { window_secs: u64 },
+    /// Fires when a matching metric a device has reported goes `stale_secs`
+    /// without an update (#423). Operator and threshold are unused.
+    Absent { stale_secs: u64 },
+}
+
+/// The kinds of [`AlertCondition`], for the rule form's picker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConditionKind {
+    #[default]
+    Value,
+    Rate,
+    Absent,
+}
+
+impl ConditionKind {
+    pub const ALL: &'static [ConditionKind] = &[
+        ConditionKind::Value,
+        ConditionKind::Rate,
+        ConditionKind::Absent,
+    ];
+}
+
+impl std::fmt::Display for ConditionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ConditionKind::Value => "Value",
+            ConditionKind::Rate => "Rate/min",
+            ConditionKind::Absent => "Absent",
+        })
+    }
+}
+
+impl AlertCondition {
+    /// `metric` as this condition reads it, e.g. `disk/usage Δ/min (5m)`.
+    pub fn describe(&self, metric: &str) -> String {
+        match self {
+            AlertCondition::Threshold => metric.to_string(),
+            AlertCondition::RateOfChange { window_secs } => {
+                format!("{metric} Δ/min ({})", format_window(*window_secs))
+            }
+            AlertCondition::Absent { stale_secs } => {
+                format!("{metric} silent > {}", format_window(*stale_secs))
+            }
+        }
+    }
+}
+
+fn format_window(secs: u64) -> String {
+    if secs >= 60 && secs % 60 == 0 {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{secs}s")
+    }
+}
+
+/// How a [`CompoundCondition`] combines its clauses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BoolOp {
+    /// Every clause must hold.
+    #[default]
+    And,
+    /// At least one clause must hold.
+    Or,
+}
+
+impl BoolOp {
+    fn keyword(&self) -> &'static str {
+        match self {
+            BoolOp::And => "AND",
+            BoolOp::Or => "OR",
+        }
+    }
+}
+
+/// One `metric <op> threshold` test of a [`CompoundCondition`].
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Clause {
+    /// Exact metric name on the device, e.g. `cpu/usage`.
+    pub metric: String,
+    pub operator: ComparisonOp,
+    pub threshold: f64,
+}
+
+/// A boolean expression over a device's current metrics (#488), e.g.
+/// `cpu/usage > 90 AND memory/usage_percent > 85`. Lets a rule fire on a
+/// combination that no single threshold captures.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CompoundCondition {
+    #[serde(default)]
+    pub op: BoolOp,
+    pub clauses: Vec<Clause>,
+}
+
+impl CompoundCondition {
+    /// Whether the expression holds, reading each clause's metric through
+    /// `value_of`. A clause whose metric the device hasn't reported (or
+    /// isn't numeric) is false; an empty expression never holds.
+    pub fn holds(&self, value_of: impl Fn(&str) -> Option<f64>) -> bool {
+        let clause_holds =
+            |c: &Clause| value_of(&c.metric).is_some_and(|v| c.operator.evaluate(v, c.threshold));
+        if self.clauses.is_empty() {
+            return false;
+        }
+        match self.op {
+            BoolOp::And => self.clauses.iter().all(clause_holds),
+            BoolOp::Or => self.clauses.iter().any(clause_holds),
+        }
+    }
+
+    /// Whether any clause reads `metric`.
+    pub fn reads(&self, metric: &str) -> bool {
+        self.clauses.iter().any(|c| c.metric == metric)
+    }
+
+    /// The expression as text, e.g. `cpu/usage > 90 AND memory/usage_percent > 85`.
+    pub fn describe(&self) -> String {
+        self.clauses
+            .iter()
+            .map(|c| {
+                format!(
+                    "{} {} {}",
+                    c.metric,
+                    c.operator.symbol(),
+                    format_value(c.threshold)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(&format!(" {} ", self.op.keyword()))
+    }
+}
+
+/// Default rate-of-change window offered by the rule form.
+pub const DEFAULT_RATE_WINDOW_SECS: u64 = 300;
+
+/// Least-squares slope of `samples` in units per minute, over those no older
+/// than `window_ms` before `now`. `None` with fewer than two samples in the
+/// window or when they all share a timestamp.
+pub fn slope_per_minute(samples: &[Sample], window_ms: i64, now: i64) -> Option<f64> {
+    let window: Vec<&Sample> = samples
+        .iter()
+        .filter(|s| s.ts >= now - window_ms && s.ts <= now)
+        .collect();
+    if window.len() < 2 {
+        return None;
+    }
+    let n = window.len() as f64;
+    // Center time on the first sample so epoch-ms values don't lose precision.
+    let t0 = window[0].ts;
+    let minutes = |s: &Sample| (s.ts - t0) as f64 / 60_000.0;
+    let mean_t = window.iter().map(|&s| minutes(s)).sum::<f64>() / n;
+    let mean_v = window.iter().map(|s| s.value).sum::<f64>() / n;
+    let (mut cov, mut var) = (0.0, 0.0);
+    for &s in &window {
+        let dt = minutes(s) - mean_t;
+        cov += dt * (s.value - mean_v);
+        var += dt * dt;
+    }
+    (var > 0.0).then(|| cov / var)
+}
+
+/// How imported alert rules combine with the existing ones (#424).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleImportMode {
+    /// Keep existing rules and append the imported ones.
+    Merge,
+    /// Drop existing rules in favour of the imported ones.
+    Replace,
+}
+
+/// Serialize `rules` as a standalone rule-library file (#424).
+pub fn rules_to_json(rules: &[AlertRule]) -> String {
+    serde_json::to_string_pretty(rules).unwrap_or_else(|_| "[]".to_string())
+}
+
+/// Parse a rule-library file: a JSON array of [`AlertRule`]s.
+pub fn rules_from_json(json: &str) -> Result<Vec<AlertRule>, String> {
+    serde_json::from_str(json).map_err(|e| e.to_string())
+}
+
 /// A saved external-alert filter combination (#27). Applying it sets both the
 /// severity and source filters at once; persisted in `PersistentSettings`.
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -72,7 +280,11 @@ impl AlertRule {
             metric_pattern: metric_pattern.into(),
             operator: ComparisonOp::GreaterThan,
             threshold: 0.0,
+            condition: AlertCondition::Threshold,
+            compound: None,
             severity: Severity::Warning,
+            escalate_to: None,
+            escalate_after_secs: 0,
             enabled: true,
         }
     }
@@ -83,8 +295,17 @@ impl AlertRule {
         self
     }
 
-    /// Check if a metric matches this rule.
-    pub fn matches(&self, device_id: &DeviceId, metric: &str) -> bool {
+    /// Escalate this rule's alerts to `severity` after `after_secs`
+    /// unacknowledged (builder pattern).
+    pub fn with_escalation(mut self, severity: Severity, after_secs: u64) -> Self {
+        self.escalate_to = Some(severity);
+        self.escalate_after_secs = after_secs;
+        self
+    }
+
+    /// Check if a device passes this rule's protocol filter and device
+    /// pattern. Shared by single-metric and compound (#488) rules.
+    pub fn matches_device(&self, device_id: &DeviceId) -> bool {
         // Check protocol filter
         if let Some(ref proto) = self.protocol
             && device_id.protocol != *proto
@@ -99,8 +320,28 @@ impl AlertRule {
             return false;
         }
 
+        true
+    }
+
+    /// Check if a metric matches this rule.
+    pub fn matches(&self, device_id: &DeviceId, metric: &str) -> bool {
         // Check metric pattern (simple contains match)
-        metric.contains(&self.metric_pattern)
+        self.matches_device(device_id) && metric.contains(&self.metric_pattern)
+    }
+
+    /// The value this rule compares for a new sample: the sample itself for a
+    /// threshold rule, or the slope over `history` (which should already
+    /// include the sample) for a rate-of-change rule. `None` when there isn't
+    /// enough history yet.
+    pub fn observed(&self, value: f64, timestamp: i64, history: &[Sample]) -> Option<f64> {
+        match self.condition {
+            AlertCondition::Threshold => Some(value),
+            AlertCondition::RateOfChange { window_secs } => {
+                slope_per_minute(history, window_secs as i64 * 1000, timestamp)
+            }
+            // Judged on the tick by `AlertsState::check_absent`, not per sample.
+            AlertCondition::Absent { .. } => None,
+        }
     }
 
     /// Evaluate if the value triggers this rule.
@@ -120,8 +361,19 @@ impl AlertRule {
     }
 }
 
-/// Alert severity levels.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+/// Alert severity levels, least severe first.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Default,
+    serde::Serialize,
+    serde::Deserialize,
+)]
 pub enum Severity {
     /// Informational alert.
     Info,
@@ -196,12 +448,25 @@ pub struct Alert {
     pub threshold: f64,
     /// Operator.
     pub operator: ComparisonOp,
+    /// Whether `value` is the metric value or its per-minute rate.
+    pub condition: AlertCondition,
+    /// Whether a compound rule raised this alert; `metric` then holds the
+    /// rule's expression (#488).
+    pub compound: bool,
     /// Severity level.
     pub severity: Severity,
     /// When the alert was triggered (Unix epoch ms).
     pub timestamp: i64,
     /// Whether this alert has been acknowledged.
     pub acknowledged: bool,
+    /// How many breaches this alert stands for (#487). Repeat breaches of
+    /// the same rule on the same device and metric update the open alert
+    /// instead of adding another.
+    pub breach_count: u32,
+    /// Pending escalation (#489): the severity to take and the time (Unix
+    /// epoch ms) it applies if the alert is still unacknowledged. Counted
+    /// from the first breach; cleared once applied.
+    pub escalation: Option<(Severity, i64)>,
 }
 
 impl Alert {
@@ -223,19 +488,41 @@ impl Alert {
             value,
             threshold: rule.threshold,
             operator: rule.operator,
+            condition: rule.condition,
+            compound: rule.compound.is_some(),
             severity: rule.severity,
             timestamp,
             acknowledged: false,
+            breach_count: 1,
+            escalation: rule
+                .escalate_to
+                .filter(|&to| to > rule.severity)
+                .map(|to| (to, timestamp + rule.escalate_after_secs as i64 * 1000)),
         }
     }
 
     /// Format the alert message.
     pub fn message(&self) -> String {
+        if self.compound {
+            return format!(
+                "{}/{}: {}",
+                self.device_id.protocol, self.device_id.source, self.metric
+            );
+        }
+        if let AlertCondition::Absent { .. } = self.condition {
+            return format!(
+                "{}/{}: {} (last seen {}s ago)",
+                self.device_id.protocol,
+                self.device_id.source,
+                self.condition.describe(&self.metric),
+                format_value(self.value)
+            );
+        }
         format!(
             "{}/{}: {} {} {} (threshold: {})",
             self.device_id.protocol,
             self.device_id.source,
-            self.metric,
+            self.condition.describe(&self.metric),
             self.operator.symbol(),
             format_value(self.value),
             format_value(self.threshold)
@@ -243,6 +530,35 @@ impl Alert {
     }
 }
 
+/// Which triggered alerts a bulk acknowledge applies to (#486). Unset
+/// criteria match any alert; an alert must match every set one.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AlertAckFilter {
+    /// Only alerts of this severity.
+    pub severity: Option<Severity>,
+    /// Only alerts raised for this device.
+    pub device: Option<DeviceId>,
+    /// Only alerts raised by this rule.
+    pub rule_id: Option<u32>,
+}
+
+impl AlertAckFilter {
+    /// Every alert of `severity`.
+    pub fn severity(severity: Severity) -> Self {
+        Self {
+            severity: Some(severity),
+            ..Default::default()
+        }
+    }
+
+    /// Whether `alert` meets every set criterion.
+    pub fn matches(&self, alert: &Alert) -> bool {
+        self.severity.is_none_or(|s| s == alert.severity)
+            && self.device.as_ref().is_none_or(|d| *d == alert.device_id)
+            && self.rule_id.is_none_or(|id| id == alert.rule_id)
+    }
+}
+
 /// State for the alerts system.
 #[derive(Debug, Default)]
 pub struct AlertsState {
@@ -270,6 +586,17 @@ pub struct AlertsState {
     pub new_rule_operator: ComparisonOp,
     /// Form state for severity.
     pub new_rule_severity: Severity,
+    /// Form state: what the rule compares — value, rate (#422) or absence
+    /// (#423).
+    pub new_rule_kind: ConditionKind,
+    /// Form state for the rate-of-change or staleness window, in seconds.
+    pub new_rule_window: String,
+    /// When each (device, metric) matched by an absence rule last reported
+    /// (#423). Only metrics some absence rule watches are tracked.
+    last_seen: HashMap<(DeviceId, String), i64>,
+    /// (device, metric) pairs whose absence alert has fired and not yet
+    /// cleared by the metric returning.
+    absent: HashSet<(DeviceId, String)>,
     /// Number of unacknowledged alerts.
     pub unacknowledged_count: usize,
     /// Test result message (None if not tested, Some(result) if tested).
@@ -355,6 +682,10 @@ impl AlertsState {
             new_rule_threshold: String::new(),
             new_rule_operator: ComparisonOp::GreaterThan,
             new_rule_severity: Severity::Warning,
+            new_rule_kind: ConditionKind::Value,
+            new_rule_window: DEFAULT_RATE_WINDOW_SECS.to_string(),
+            last_seen: HashMap::new(),
+            absent: HashSet::new(),
             unacknowledged_count: 0,
             test_result: None,
             external: HashMap::new(),
@@ -670,6 +1001,49 @@ impl AlertsState {
             .count()
     }
 
+    /// Active alerts on `device` (#437): its un-acknowledged rule alerts plus
+    /// its firing, un-acknowledged sensor alerts.
+    pub fn active_count_for(&self, device: &DeviceId) -> usize {
+        let rule = self
+            .alerts
+            .iter()
+            .filter(|a| !a.acknowledged && a.device_id == *device)
+            .count();
+        let external = self
+            .external
+            .values()
+            .filter(|a| {
+                a.protocol == device.protocol
+                    && a.source == device.source
+                    && !self.acknowledged_external.contains(&a.alert_key())
+            })
+            .count();
+        rule + external
+    }
+
+    /// Active alerts per device by severity (#490), counted like
+    /// [`active_count_for`](Self::active_count_for), for the health score.
+    pub fn alert_load_by_device(&self) -> HashMap<DeviceId, AlertLoad> {
+        let mut loads: HashMap<DeviceId, AlertLoad> = HashMap::new();
+        for alert in self.alerts.iter().filter(|a| !a.acknowledged) {
+            loads
+                .entry(alert.device_id.clone())
+                .or_default()
+                .add(alert.severity);
+        }
+        for alert in self
+            .external
+            .values()
+            .filter(|a| !self.acknowledged_external.contains(&a.alert_key()))
+        {
+            loads
+                .entry(DeviceId::new(alert.protocol, alert.source.clone()))
+                .or_default()
+                .add(alert.severity.into());
+        }
+        loads
+    }
+
     /// Update the max alerts setting.
     pub fn set_max_alerts(&mut self, max_alerts: usize) {
         self.max_alerts = max_alerts;
@@ -693,10 +1067,32 @@ impl AlertsState {
             return Err("Metric pattern is required".to_string());
         }
 
-        let threshold: f64 = self
-            .new_rule_threshold
-            .parse()
-            .map_err(|_| "Threshold must be a number".to_string())?;
+        // Absence rules have no threshold to compare.
+        let threshold: f64 = if self.new_rule_kind == ConditionKind::Absent {
+            0.0
+        } else {
+            self.new_rule_threshold
+                .parse()
+                .map_err(|_| "Threshold must be a number".to_string())?
+        };
+
+        let window = || {
+            self.new_rule_window
+                .trim()
+                .parse::<u64>()
+                .ok()
+                .filter(|&w| w > 0)
+                .ok_or_else(|| "Window must be a positive number of seconds".to_string())
+        };
+        let condition = match self.new_rule_kind {
+            ConditionKind::Value => AlertCondition::Threshold,
+            ConditionKind::Rate => AlertCondition::RateOfChange {
+                window_secs: window()?,
+            },
+            ConditionKind::Absent => AlertCondition::Absent {
+                stale_secs: window()?,
+            },
+        };
 
         let rule = AlertRule {
             id: self.next_rule_id,
@@ -706,7 +1102,11 @@ impl AlertsState {
             metric_pattern: self.new_rule_metric.trim().to_string(),
             operator: self.new_rule_operator,
             threshold,
+            condition,
+            compound: None,
             severity: self.new_rule_severity,
+            escalate_to: None,
+            escalate_after_secs: 0,
             enabled: true,
         };
 
@@ -719,10 +1119,36 @@ impl AlertsState {
         self.new_rule_threshold.clear();
         self.new_rule_operator = ComparisonOp::GreaterThan;
         self.new_rule_severity = Severity::Warning;
+        self.new_rule_kind = ConditionKind::Value;
+        self.new_rule_window = DEFAULT_RATE_WINDOW_SECS.to_string();
 
         Ok(())
     }
 
+    /// Add rules from a rule-library file (#424), merging with or replacing
+    /// the existing ones. Imported rules get fresh IDs above every rule kept,
+    /// so IDs from another install never collide. Returns how many were added.
+    pub fn import_rules(&mut self, rules: Vec<AlertRule>, mode: RuleImportMode) -> usize {
+        if mode == RuleImportMode::Replace {
+            self.rules.clear();
+        }
+        let mut next_id = self
+            .rules
+            .iter()
+            .map(|r| r.id + 1)
+            .max()
+            .unwrap_or(1)
+            .max(self.next_rule_id);
+        let count = rules.len();
+        for mut rule in rules {
+            rule.id = next_id;
+            next_id += 1;
+            self.rules.push(rule);
+        }
+        self.next_rule_id = next_id;
+        count
+    }
+
     /// Test the current form rule against provided metrics.
     /// Returns the number of metrics that would match.
     pub fn test_rule(&mut self, metrics: &[(String, String, f64)]) -> Result<(), String> {
@@ -732,6 +1158,15 @@ impl AlertsState {
             return Err("Metric pattern is required".to_string());
         }
 
+        if self.new_rule_kind != ConditionKind::Value {
+            // The form only has each metric's latest value, not its history.
+            self.test_result = Some(
+                "Error: Rate and absence rules need history; add the rule to evaluate it live"
+                    .to_string(),
+            );
+            return Err("Only value rules can be tested".to_string());
+        }
+
         let threshold: f64 = self.new_rule_threshold.parse().map_err(|e| {
             self.test_result = Some(format!("Error: Invalid threshold - {}", e));
             format!("Threshold must be a number: {}", e)
@@ -808,6 +1243,20 @@ impl AlertsState {
         metric: &str,
         value: f64,
         timestamp: i64,
+    ) -> Option<Alert> {
+        self.check_metric_with_history(device_id, metric, value, timestamp, Vec::new)
+    }
+
+    /// [`check_metric`](Self::check_metric), with the metric's buffered
+    /// samples for rate-of-change rules (#422). `history` is only called when
+    /// a matching rule needs it.
+    pub fn check_metric_with_history(
+        &mut self,
+        device_id: &DeviceId,
+        metric: &str,
+        value: f64,
+        timestamp: i64,
+        history: impl FnOnce() -> Vec<Sample>,
     ) -> Option<Alert> {
         // Check cooldown
         let key = format!("{}/{}/{}", device_id.protocol, device_id.source, metric);
@@ -817,34 +1266,36 @@ impl AlertsState {
             return None;
         }
 
+        let mut history = Some(history);
+        let mut samples = Vec::new();
+
         // Find matching rule that triggers
         for rule in &self.rules {
-            if rule.matches(device_id, metric) && rule.evaluate(value) {
+            if !rule.enabled || rule.compound.is_some() || !rule.matches(device_id, metric) {
+                continue;
+            }
+            if matches!(rule.condition, AlertCondition::RateOfChange { .. })
+                && let Some(load) = history.take()
+            {
+                samples = load();
+            }
+            if let Some(observed) = rule.observed(value, timestamp, &samples)
+                && rule.evaluate(observed)
+            {
                 let alert = Alert::new(
                     self.next_alert_id,
                     rule,
                     device_id.clone(),
                     metric.to_string(),
-                    value,
+                    observed,
                     timestamp,
                 );
 
-                self.next_alert_id += 1;
-                self.alerts.insert(0, alert.clone());
-                self.unacknowledged_count += 1;
+                let alert = self.record_breach(alert);
 
                 // Update cooldown
                 self.recent_alerts.insert(key, timestamp);
 
-                // Trim old alerts
-                while self.alerts.len() > self.max_alerts {
-                    if let Some(removed) = self.alerts.pop()
-                        && !removed.acknowledged
-                    {
-                        self.unacknowledged_count = self.unacknowledged_count.saturating_sub(1);
-                    }
-                }
-
                 return Some(alert);
             }
         }
@@ -852,6 +1303,190 @@ impl AlertsState {
         None
     }
 
+    /// Evaluate the compound rules (#488) that read `metric`, which `device_id`
+    /// just reported, against the device's current metrics as returned by
+    /// `value_of`. Each holding rule raises (or re-breaches) an alert whose
+    /// metric is the rule's expression and whose value is `metric`'s.
+    /// Returns the alerts raised.
+    pub fn check_compound(
+        &mut self,
+        device_id: &DeviceId,
+        metric: &str,
+        value_of: impl Fn(&str) -> Option<f64>,
+        timestamp: i64,
+    ) -> Vec<Alert> {
+        let mut fired = Vec::new();
+        for rule in &self.rules {
+            let Some(compound) = rule.compound.as_ref() else {
+                continue;
+            };
+            if !rule.enabled || !compound.reads(metric) || !rule.matches_device(device_id) {
+                continue;
+            }
+            let key = format!("{}/{}/#{}", device_id.protocol, device_id.source, rule.id);
+            if let Some(&last_alert) = self.recent_alerts.get(&key)
+                && timestamp - last_alert < self.alert_cooldown_ms
+            {
+                continue;
+            }
+            if compound.holds(&value_of) {
+                let value = value_of(metric).unwrap_or_default();
+                fired.push((
+                    key,
+                    Alert::new(
+                        self.next_alert_id,
+                        rule,
+                        device_id.clone(),
+                        compound.describe(),
+                        value,
+                        timestamp,
+                    ),
+                ));
+            }
+        }
+        fired
+            .into_iter()
+            .map(|(key, alert)| {
+                self.recent_alerts.insert(key, timestamp);
+                self.record_breach(alert)
+            })
+            .collect()
+    }
+
+    /// Record that `device_id` reported `metric` (#423). Tracked only when an
+    /// enabled absence rule matches; a metric whose absence alert fired is
+    /// cleared, so a later silence alerts again.
+    ///
+    /// `received_ms` is local receipt time, not the point's own timestamp:
+    /// [`check_absent`](Self::check_absent) compares it with the GUI's clock,
+    /// and a sensor whose clock is off would otherwise look silent (or never).
+    pub fn note_seen(&mut self, device_id: &DeviceId, metric: &str, received_ms: i64) {
+        let watched = self.rules.iter().any(|r| {
+            r.enabled
+                && matches!(r.condition, AlertCondition::Absent { .. })
+                && r.matches(device_id, metric)
+        });
+        if !watched {
+            return;
+        }
+        let key = (device_id.clone(), metric.to_string());
+        self.absent.remove(&key);
+        let last = self.last_seen.entry(key).or_insert(received_ms);
+        *last = (*last).max(received_ms);
+    }
+
+    /// Whether `metric` on `device_id` is currently alerting as absent.
+    pub fn is_absent(&self, device_id: &DeviceId, metric: &str) -> bool {
+        self.absent
+            .contains(&(device_id.clone(), metric.to_string()))
+    }
+
+    /// Fire absence alerts for watched metrics silent past their rule's window
+    /// (#423). Called on the tick; each silence alerts once until the metric
+    /// returns. Returns the alerts raised.
+    pub fn check_absent(&mut self, now: i64) -> Vec<Alert> {
+        let mut fired = Vec::new();
+        let rules = &self.rules;
+        // Forget metrics no absence rule watches any more (rule removed or
+        // disabled), so the map stays bounded by what's watched.
+        self.last_seen.retain(|(device_id, metric), _| {
+            rules.iter().any(|r| {
+                r.enabled
+                    && matches!(r.condition, AlertCondition::Absent { .. })
+                    && r.matches(device_id, metric)
+            })
+        });
+        let mut silent: Vec<(&(DeviceId, String), i64)> = self
+            .last_seen
+            .iter()
+            .filter(|(key, _)| !self.absent.contains(*key))
+            .map(|(key, &last)| (key, last))
+            .collect();
+        silent.sort_by(|a, b| a.0.1.cmp(&b.0.1).then(a.1.cmp(&b.1)));
+        let mut newly_absent = Vec::new();
+        for ((device_id, metric), last) in silent {
+            let Some(rule) = rules.iter().find(|r| {
+                r.enabled
+                    && matches!(r.condition, AlertCondition::Absent { stale_secs }
+                        if now - last > stale_secs as i64 * 1000)
+                    && r.matches(device_id, metric)
+            }) else {
+                continue;
+            };
+            let alert = Alert::new(
+                self.next_alert_id,
+                rule,
+                device_id.clone(),
+                metric.clone(),
+                ((now - last) / 1000) as f64,
+                now,
+            );
+            newly_absent.push((device_id.clone(), metric.clone()));
+            fired.push(alert);
+        }
+        self.absent.extend(newly_absent);
+        fired
+            .into_iter()
+            .map(|alert| self.record_breach(alert))
+            .collect()
+    }
+
+    /// Raise the severity of unacknowledged alerts whose rule escalation
+    /// (#489) is due at `now`. Called on the tick; returns the alerts
+    /// escalated.
+    pub fn escalate_due(&mut self, now: i64) -> Vec<Alert> {
+        let mut escalated = Vec::new();
+        for alert in self.alerts.iter_mut().filter(|a| !a.acknowledged) {
+            if let Some((severity, at)) = alert.escalation
+                && now >= at
+            {
+                alert.severity = severity;
+                alert.escalation = None;
+                escalated.push(alert.clone());
+            }
+        }
+        escalated
+    }
+
+    /// Record a rule breach (#487). An unacknowledged alert for the same rule,
+    /// device and metric takes the new value and timestamp, counts the breach
+    /// and moves back to the top, so a flapping metric keeps one alert rather
+    /// than stacking near-duplicates. Otherwise `alert` is added under the
+    /// next id. Returns the alert as stored.
+    fn record_breach(&mut self, mut alert: Alert) -> Alert {
+        let open = self.alerts.iter().position(|a| {
+            !a.acknowledged
+                && a.rule_id == alert.rule_id
+                && a.device_id == alert.device_id
+                && a.metric == alert.metric
+        });
+        if let Some(index) = open {
+            let mut existing = self.alerts.remove(index);
+            existing.value = alert.value;
+            existing.timestamp = alert.timestamp;
+            existing.breach_count += 1;
+            self.alerts.insert(0, existing.clone());
+            return existing;
+        }
+        alert.id = self.next_alert_id;
+        self.next_alert_id += 1;
+        self.push_alert(alert.clone());
+        alert
+    }
+
+    /// Record a triggered alert, newest first, within `max_alerts`.
+    fn push_alert(&mut self, alert: Alert) {
+        self.alerts.insert(0, alert);
+        self.unacknowledged_count += 1;
+        while self.alerts.len() > self.max_alerts {
+            if let Some(removed) = self.alerts.pop()
+                && !removed.acknowledged
+            {
+                self.unacknowledged_count = self.unacknowledged_count.saturating_sub(1);
+            }
+        }
+    }
+
     /// Acknowledge an alert.
     pub fn acknowledge(&mut self, alert_id: u64) {
         if let Some(alert) = self.alerts.iter_mut().find(|a| a.id == alert_id)
@@ -862,6 +1497,20 @@ impl AlertsState {
         }
     }
 
+    /// Acknowledge every alert matching `filter` (#486), returning how many
+    /// were newly acknowledged.
+    pub fn acknowledge_matching(&mut self, filter: &AlertAckFilter) -> usize {
+        let mut acked = 0;
+        for alert in &mut self.alerts {
+            if !alert.acknowledged && filter.matches(alert) {
+                alert.acknowledged = true;
+                acked += 1;
+            }
+        }
+        self.unacknowledged_count = self.unacknowledged_count.saturating_sub(acked);
+        acked
+    }
+
     /// Acknowledge all alerts.
     pub fn acknowledge_all(&mut self) {
         for alert in &mut self.alerts {
@@ -896,6 +1545,14 @@ impl AlertsState {
     pub fn set_new_rule_severity(&mut self, severity: Severity) {
         self.new_rule_severity = severity;
     }
+
+    pub fn set_new_rule_kind(&mut self, kind: ConditionKind) {
+        self.new_rule_kind = kind;
+    }
+
+    pub fn set_new_rule_window(&mut self, window: String) {
+        self.new_rule_window = window;
+    }
 }
 
 /// Render the alerts view.
@@ -1019,6 +1676,13 @@ fn render_new_rule_form(state: &AlertsState) -> Element<'_, Message> {
         Message::SetAlertRuleSeverity,
     );
 
+    // Value, per-minute rate over a window (#422), or absence (#423).
+    let kind_picker = pick_list(
+        ConditionKind::ALL,
+        Some(state.new_rule_kind),
+        Message::SetAlertRuleKind,
+    );
+
     let test_button = button(text("Test").size(14))
         .on_press(Message::TestAlertRule)
         .style(iced::widget::button::secondary);
@@ -1027,17 +1691,24 @@ fn render_new_rule_form(state: &AlertsState) -> Element<'_, Message> {
         .on_press(Message::AddAlertRule)
         .style(iced::widget::button::primary);
 
-    let form_row = row![
-        name_input,
-        metric_input,
-        operator_picker,
-        threshold_input,
-        severity_picker,
-        test_button,
-        add_button
-    ]
-    .spacing(10)
-    .align_y(Alignment::Center);
+    let mut form_row = row![name_input, metric_input, kind_picker]
+        .spacing(10)
+        .align_y(Alignment::Center);
+    if state.new_rule_kind != ConditionKind::Absent {
+        form_row = form_row.push(operator_picker).push(threshold_input);
+    }
+    if state.new_rule_kind != ConditionKind::Value {
+        form_row = form_row.push(
+            text_input("Window (s)", &state.new_rule_window)
+                .on_input(Message::SetAlertRuleWindow)
+                .padding(8)
+                .width(Length::Fixed(90.0)),
+        );
+    }
+    let form_row = form_row
+        .push(severity_picker)
+        .push(test_button)
+        .push(add_button);
 
     // Show test result if available
     let mut form_content = Column::new().spacing(10).push(section_title).push(form_row);
@@ -1079,7 +1750,30 @@ fn render_new_rule_form(state: &AlertsState) -> Element<'_, Message> {
 
 /// Render the rules section.
 fn render_rules_section(state: &AlertsState) -> Element<'_, Message> {
-    let section_title = text(format!("Rules ({})", state.rules.len())).size(18);
+    // Rule library import/export (#424).
+    let library_button = |label, message| {
+        button(text(label).size(12))
+            .on_press(message)
+            .style(iced::widget::button::secondary)
+    };
+    let mut export_button = button(text("Export").size(12)).style(iced::widget::button::secondary);
+    if !state.rules.is_empty() {
+        export_button = export_button.on_press(Message::ExportAlertRules);
+    }
+    let section_title = row![
+        text(format!("Rules ({})", state.rules.len())).size(18),
+        export_button,
+        library_button(
+            "Import (merge)",
+            Message::PickAlertRules(RuleImportMode::Merge)
+        ),
+        library_button(
+            "Import (replace)",
+            Message::PickAlertRules(RuleImportMode::Replace)
+        ),
+    ]
+    .spacing(10)
+    .align_y(Alignment::Center);
 
     if state.rules.is_empty() {
         return column![section_title, empty_state("No alert rules defined", None)]
@@ -1110,17 +1804,33 @@ fn render_rule_row(rule: &AlertRule) -> Element<'_, Message> {
     // Severity as a color+label badge (#28 L5): never color alone.
     let severity_badge = badge(rule.severity.color(), rule.severity.name());
 
-    let condition = text(format!(
-        "{} {} {}",
-        rule.metric_pattern,
-        rule.operator.symbol(),
-        format_value(rule.threshold)
-    ))
+    let condition = text(match (&rule.compound, rule.condition) {
+        (Some(compound), _) => compound.describe(),
+        (None, AlertCondition::Absent { .. }) => rule.condition.describe(&rule.metric_pattern),
+        (None, _) => format!(
+            "{} {} {}",
+            rule.condition.describe(&rule.metric_pattern),
+            rule.operator.symbol(),
+            format_value(rule.threshold)
+        ),
+    })
     .size(12)
     .style(|theme: &Theme| text::Style {
         color: Some(crate::view::theme::colors(theme).text_muted()),
     });
 
+    let escalation = rule.escalate_to.map(|to| {
+        text(format!(
+            "→ {} after {}",
+            to.name(),
+            format_window(rule.escalate_after_secs)
+        ))
+        .size(11)
+        .style(move |_: &Theme| text::Style {
+            color: Some(to.color()),
+        })
+    });
+
     let toggle_label = if rule.enabled { "Disable" } else { "Enable" };
     let toggle_button = button(text(toggle_label).size(11))
         .on_press(Message::ToggleAlertRule(rule.id))
@@ -1134,17 +1844,16 @@ fn render_rule_row(rule: &AlertRule) -> Element<'_, Message> {
     .on_press(Message::RemoveAlertRule(rule.id))
     .style(iced::widget::button::danger);
 
-    row![
-        status,
-        name,
-        severity_badge,
-        condition,
-        toggle_button,
-        remove_button
-    ]
-    .spacing(10)
-    .align_y(Alignment::Center)
-    .into()
+    let mut rule_row = row![status, name, severity_badge, condition];
+    if let Some(escalation) = escalation {
+        rule_row = rule_row.push(escalation);
+    }
+    rule_row
+        .push(toggle_button)
+        .push(remove_button)
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .into()
 }
 
 /// Render the alerts section.
@@ -1430,7 +2139,7 @@ fn render_timeline<'a>(events: &[TransitionEvent]) -> Element<'a, Message> {
                 SensorAlertState::Firing => "Firing",
                 SensorAlertState::Resolved => "Resolved",
             };
-            format!("{state} {}", format_timestamp(e.at))
+            format!("{state} {}", format_relative_time(e.at))
         })
         .collect();
     text(format!("  {}", parts.join(" → ")))
@@ -1480,7 +2189,7 @@ fn render_external_alert_row<'a>(alert: &'a SensorAlert, acked: bool) -> Element
             color: Some(crate::view::theme::colors(theme).text_dimmed()),
         });
 
-    let time = text(format_timestamp(alert.timestamp))
+    let time = text(format_relative_time(alert.timestamp))
         .size(11)
         .style(|theme: &Theme| text::Style {
             color: Some(crate::view::theme::colors(theme).text_dimmed()),
@@ -1501,15 +2210,34 @@ fn render_external_alert_row<'a>(alert: &'a SensorAlert, acked: bool) -> Element
 fn render_alerts_section(state: &AlertsState) -> Element<'_, Message> {
     let section_title = text(format!("Alert History ({})", state.alerts.len())).size(18);
 
-    let actions = row![
+    let mut actions = row![
         button(text("Acknowledge All").size(12))
             .on_press(Message::AcknowledgeAllAlerts)
             .style(iced::widget::button::secondary),
+    ]
+    .spacing(10);
+    // Bulk acknowledge by severity (#486), for severities with open alerts.
+    for &severity in Severity::ALL.iter().rev() {
+        let open = state
+            .alerts
+            .iter()
+            .filter(|a| !a.acknowledged && a.severity == severity)
+            .count();
+        if open > 0 {
+            actions = actions.push(
+                button(text(format!("Ack {} ({open})", severity.name())).size(12))
+                    .on_press(Message::AcknowledgeAlertsMatching(
+                        AlertAckFilter::severity(severity),
+                    ))
+                    .style(iced::widget::button::secondary),
+            );
+        }
+    }
+    let actions = actions.push(
         button(text("Clear All").size(12))
             .on_press(Message::ClearAlerts)
             .style(iced::widget::button::secondary),
-    ]
-    .spacing(10);
+    );
 
     let header = row![section_title, actions]
         .spacing(20)
@@ -1565,7 +2293,7 @@ fn render_alert_row(alert: &Alert) -> Element<'_, Message> {
         text(full_message).size(13).into()
     };
 
-    let time = text(format_timestamp(alert.timestamp))
+    let time = text(format_relative_time(alert.timestamp))
         .size(11)
         .style(|theme: &Theme| text::Style {
             color: Some(crate::view::theme::colors(theme).text_dimmed()),
@@ -1584,9 +2312,16 @@ fn render_alert_row(alert: &Alert) -> Element<'_, Message> {
         .push(status)
         .push(severity_badge)
         .push(message)
-        .push(time)
-        .push(investigate)
         .spacing(10);
+    // Repeat breaches folded into this alert (#487).
+    if alert.breach_count > 1 {
+        row_content = row_content.push(text(format!("×{}", alert.breach_count)).size(11).style(
+            |theme: &Theme| text::Style {
+                color: Some(crate::view::theme::colors(theme).warning()),
+            },
+        ));
+    }
+    row_content = row_content.push(time).push(investigate);
 
     if !alert.acknowledged {
         let ack_button = button(
@@ -1636,6 +2371,172 @@ mod tests {
         assert!(rule.evaluate(50.0));
     }
 
+    fn compound_rule(op: BoolOp) -> AlertRule {
+        let mut rule = AlertRule::new(1, "Overloaded", "");
+        rule.compound = Some(CompoundCondition {
+            op,
+            clauses: vec![
+                Clause {
+                    metric: "cpu/usage".to_string(),
+                    operator: ComparisonOp::GreaterThan,
+                    threshold: 90.0,
+                },
+                Clause {
+                    metric: "memory/usage_percent".to_string(),
+                    operator: ComparisonOp::GreaterThan,
+                    threshold: 85.0,
+                },
+            ],
+        });
+        rule
+    }
+
+    #[test]
+    fn compound_and_fires_only_when_both_clauses_hold() {
+        let mut state = AlertsState::new();
+        state.rules.push(compound_rule(BoolOp::And));
+        let device = DeviceId {
+            protocol: Protocol::Sysinfo,
+            source: "web01".to_string(),
+        };
+        let metrics = |cpu: f64, mem: f64| {
+            move |m: &str| match m {
+                "cpu/usage" => Some(cpu),
+                "memory/usage_percent" => Some(mem),
+                _ => None,
+            }
+        };
+
+        assert!(
+            state
+                .check_compound(&device, "cpu/usage", metrics(95.0, 50.0), 1000)
+                .is_empty()
+        );
+        assert!(
+            state
+                .check_compound(&device, "memory/usage_percent", metrics(50.0, 90.0), 2000)
+                .is_empty()
+        );
+        // A metric no clause reads doesn't re-evaluate the rule.
+        assert!(
+            state
+                .check_compound(&device, "disk/usage", metrics(95.0, 90.0), 3000)
+                .is_empty()
+        );
+
+        let fired = state.check_compound(&device, "cpu/usage", metrics(95.0, 90.0), 4000);
+        assert_eq!(fired.len(), 1);
+        assert_eq!(
+            fired[0].metric,
+            "cpu/usage > 90 AND memory/usage_percent > 85"
+        );
+        assert_eq!(fired[0].value, 95.0);
+        assert_eq!(
+            fired[0].message(),
+            "sysinfo/web01: cpu/usage > 90 AND memory/usage_percent > 85"
+        );
+        assert_eq!(state.alerts.len(), 1);
+
+        // Compound rules don't fire through the single-metric path.
+        assert!(
+            state
+                .check_metric(&device, "cpu/usage", 95.0, 5000)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn compound_rules_share_the_device_filters() {
+        let mut rule = compound_rule(BoolOp::Or);
+        rule.protocol = Some(Protocol::Sysinfo);
+        rule.device_pattern = Some("web".to_string());
+        let mut state = AlertsState::new();
+        state.rules.push(rule);
+        let hot = |_: &str| Some(99.0);
+
+        for device in [
+            DeviceId::new(Protocol::Sysinfo, "db01"),
+            DeviceId::new(Protocol::Snmp, "web01"),
+        ] {
+            assert!(!state.rules[0].matches_device(&device));
+            assert!(
+                state
+                    .check_compound(&device, "cpu/usage", hot, 1000)
+                    .is_empty()
+            );
+        }
+        let web = DeviceId::new(Protocol::Sysinfo, "web01");
+        assert!(state.rules[0].matches_device(&web));
+        assert_eq!(state.check_compound(&web, "cpu/usage", hot, 1000).len(), 1);
+    }
+
+    #[test]
+    fn compound_or_fires_when_either_clause_holds() {
+        let rule = compound_rule(BoolOp::Or);
+        let compound = rule.compound.as_ref().unwrap();
+        let only_cpu = |m: &str| (m == "cpu/usage").then_some(95.0);
+        let only_mem = |m: &str| (m == "memory/usage_percent").then_some(90.0);
+        let neither = |m: &str| match m {
+            "cpu/usage" => Some(10.0),
+            "memory/usage_percent" => Some(20.0),
+            _ => None,
+        };
+        assert!(compound.holds(only_cpu));
+        assert!(compound.holds(only_mem));
+        assert!(!compound.holds(neither));
+        assert!(!compound.holds(|_: &str| None));
+
+        let mut state = AlertsState::new();
+        state.rules.push(rule);
+        let device = DeviceId {
+            protocol: Protocol::Sysinfo,
+            source: "web01".to_string(),
+        };
+        let fired = state.check_compound(&device, "memory/usage_percent", only_mem, 1000);
+        assert_eq!(fired.len(), 1);
+        assert_eq!(
+            fired[0].metric,
+            "cpu/usage > 90 OR memory/usage_percent > 85"
+        );
+    }
+
+    #[test]
+    fn unacknowledged_warning_escalates_unless_acknowledged() {
+        let mut state = AlertsState::new();
+        let mut rule =
+            AlertRule::new(1, "Disk", "disk/usage").with_escalation(Severity::Critical, 600);
+        rule.threshold = 90.0;
+        state.rules.push(rule);
+        let web = DeviceId {
+            protocol: Protocol::Sysinfo,
+            source: "web01".to_string(),
+        };
+        let db = DeviceId {
+            protocol: Protocol::Sysinfo,
+            source: "db01".to_string(),
+        };
+
+        let alert = state.check_metric(&web, "disk/usage", 95.0, 0).unwrap();
+        assert_eq!(alert.severity, Severity::Warning);
+        let acked = state.check_metric(&db, "disk/usage", 95.0, 0).unwrap();
+        state.acknowledge(acked.id);
+
+        // Not yet due.
+        assert!(state.escalate_due(599_999).is_empty());
+        assert!(state.alerts.iter().all(|a| a.severity == Severity::Warning));
+
+        let escalated = state.escalate_due(600_000);
+        assert_eq!(escalated.len(), 1);
+        assert_eq!(escalated[0].id, alert.id);
+        let severity_of = |id| state.alerts.iter().find(|a| a.id == id).unwrap().severity;
+        assert_eq!(severity_of(alert.id), Severity::Critical);
+        // The acknowledged alert stays a warning.
+        assert_eq!(severity_of(acked.id), Severity::Warning);
+
+        // Escalation applies once.
+        assert!(state.escalate_due(1_200_000).is_empty());
+    }
+
     #[test]
     fn test_alerts_state_check_metric() {
         let mut state = AlertsState::new();
@@ -1664,10 +2565,53 @@ mod tests {
         let alert = state.check_metric(&device, "if/1/errors", 200.0, 3000);
         assert!(alert.is_none());
 
-        // Should trigger after cooldown
+        // Should trigger after cooldown, updating the open alert (#487)
         let alert = state.check_metric(&device, "if/1/errors", 200.0, 100000);
         assert!(alert.is_some());
+        assert_eq!(state.alerts.len(), 1);
+    }
+
+    #[test]
+    fn repeated_breaches_update_one_alert() {
+        let mut state = AlertsState::new();
+        let mut rule = AlertRule::new(1, "High Errors", "errors");
+        rule.threshold = 100.0;
+        state.rules.push(rule);
+        let device = DeviceId {
+            protocol: Protocol::Snmp,
+            source: "router01".to_string(),
+        };
+        let other = DeviceId {
+            protocol: Protocol::Snmp,
+            source: "router02".to_string(),
+        };
+
+        for (i, value) in [150.0, 180.0, 210.0].into_iter().enumerate() {
+            let at = 1_000 + i as i64 * 100_000;
+            state.check_metric(&device, "if/1/errors", value, at);
+        }
+        assert_eq!(state.alerts.len(), 1);
+        let alert = &state.alerts[0];
+        assert_eq!(alert.breach_count, 3);
+        assert_eq!(alert.value, 210.0);
+        assert_eq!(alert.timestamp, 201_000);
+        assert_eq!(state.unacknowledged_count, 1);
+
+        // Another device is its own alert, on top.
+        state.check_metric(&other, "if/1/errors", 150.0, 250_000);
         assert_eq!(state.alerts.len(), 2);
+        assert_eq!(state.alerts[0].device_id, other);
+
+        // A breach after acknowledging opens a fresh alert.
+        let first_id = state.alerts[1].id;
+        state.acknowledge(first_id);
+        let alert = state
+            .check_metric(&device, "if/1/errors", 300.0, 400_000)
+            .unwrap();
+        assert_ne!(alert.id, first_id);
+        assert_eq!(alert.breach_count, 1);
+        assert_eq!(state.alerts.len(), 3);
+        assert_eq!(state.unacknowledged_count, 2);
     }
 
     #[test]
@@ -1690,6 +2634,195 @@ mod tests {
         assert!(state.alerts[0].acknowledged);
     }
 
+    #[test]
+    fn acknowledge_by_severity_leaves_other_severities_active() {
+        let mut state = AlertsState::new();
+        let mut warning = AlertRule::new(1, "Errors", "errors");
+        warning.threshold = 0.0;
+        warning.severity = Severity::Warning;
+        let mut critical = AlertRule::new(2, "Drops", "drops");
+        critical.threshold = 0.0;
+        critical.severity = Severity::Critical;
+        state.rules.extend([warning, critical]);
+
+        for source in ["r1", "r2"] {
+            let device = DeviceId {
+                protocol: Protocol::Snmp,
+                source: source.to_string(),
+            };
+            state.check_metric(&device, "errors", 5.0, 1000);
+            state.check_metric(&device, "drops", 5.0, 1000);
+        }
+        assert_eq!(state.unacknowledged_count, 4);
+
+        let acked = state.acknowledge_matching(&AlertAckFilter::severity(Severity::Warning));
+        assert_eq!(acked, 2);
+        assert_eq!(state.unacknowledged_count, 2);
+        for alert in &state.alerts {
+            assert_eq!(alert.acknowledged, alert.severity == Severity::Warning);
+        }
+
+        // Device and rule criteria combine; already-acked alerts aren't recounted.
+        let filter = AlertAckFilter {
+            device: Some(DeviceId {
+                protocol: Protocol::Snmp,
+                source: "r1".to_string(),
+            }),
+            rule_id: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(state.acknowledge_matching(&filter), 1);
+        assert_eq!(state.acknowledge_matching(&filter), 0);
+        assert_eq!(state.unacknowledged_count, 1);
+    }
+
+    fn ramp(start: i64, step_ms: i64, values: &[f64]) -> Vec<Sample> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, &value)| Sample {
+                ts: start + i as i64 * step_ms,
+                value,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn rate_of_change_fires_on_steep_ramp_only() {
+        let mut state = AlertsState::new();
+        let mut rule = AlertRule::new(1, "Disk filling", "disk/root/usage_percent");
+        rule.condition = AlertCondition::RateOfChange { window_secs: 300 };
+        rule.threshold = 1.0; // percent per minute
+        state.rules.push(rule);
+        let device = DeviceId {
+            protocol: Protocol::Sysinfo,
+            source: "host".to_string(),
+        };
+        let metric = "disk/root/usage_percent";
+
+        // A steady 70% over five minutes: slope 0, no alert — even though the
+        // absolute value is far above the threshold.
+        let steady = ramp(0, 30_000, &[70.0; 11]);
+        let now = steady.last().unwrap().ts;
+        assert_eq!(slope_per_minute(&steady, 300_000, now), Some(0.0));
+        assert!(
+            state
+                .check_metric_with_history(&device, metric, 70.0, now, || steady.clone())
+                .is_none()
+        );
+
+        // +2 per 30 s is 4%/min: fires, and reports the slope.
+        let values: Vec<f64> = (0..11).map(|i| 50.0 + 2.0 * i as f64).collect();
+        let steep = ramp(1_000_000, 30_000, &values);
+        let now = steep.last().unwrap().ts;
+        let alert = state
+            .check_metric_with_history(&device, metric, 70.0, now, || steep.clone())
+            .expect("steep ramp should fire");
+        assert!((alert.value - 4.0).abs() < 1e-9);
+        assert!(alert.message().contains("Δ/min (5m)"));
+
+        // Samples outside the window are ignored; one sample isn't a slope.
+        assert_eq!(slope_per_minute(&steep, 10_000, now), None);
+    }
+
+    #[test]
+    fn rate_rule_form_requires_window() {
+        let mut state = AlertsState::new();
+        state.set_new_rule_name("Fill".to_string());
+        state.set_new_rule_metric("disk".to_string());
+        state.set_new_rule_threshold("1".to_string());
+        state.set_new_rule_kind(ConditionKind::Rate);
+        state.set_new_rule_window("0".to_string());
+        assert!(state.add_rule().is_err());
+
+        state.set_new_rule_window("120".to_string());
+        state.add_rule().unwrap();
+        assert_eq!(
+            state.rules[0].condition,
+            AlertCondition::RateOfChange { window_secs: 120 }
+        );
+        assert_eq!(state.new_rule_kind, ConditionKind::Value);
+    }
+
+    #[test]
+    fn absent_metric_fires_after_window_and_clears_on_return() {
+        let mut state = AlertsState::new();
+        let mut rule = AlertRule::new(1, "Uplink gone", "ifInOctets");
+        rule.condition = AlertCondition::Absent { stale_secs: 60 };
+        state.rules.push(rule);
+        let device = DeviceId {
+            protocol: Protocol::Snmp,
+            source: "router01".to_string(),
+        };
+
+        // Unwatched metrics aren't tracked; watched ones are.
+        state.note_seen(&device, "sysUpTime", 0);
+        state.note_seen(&device, "if/1/ifInOctets", 0);
+        assert!(state.check_absent(30_000).is_empty());
+        assert!(state.check_absent(60_000).is_empty());
+
+        // Silent beyond the window: fires once.
+        let fired = state.check_absent(61_000);
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].metric, "if/1/ifInOctets");
+        assert_eq!(fired[0].value, 61.0);
+        assert!(fired[0].message().contains("silent > 1m"));
+        assert!(state.is_absent(&device, "if/1/ifInOctets"));
+        assert!(state.check_absent(120_000).is_empty());
+        assert_eq!(state.alerts.len(), 1);
+
+        // It returns: the absence clears, and a later silence fires again.
+        state.note_seen(&device, "if/1/ifInOctets", 130_000);
+        assert!(!state.is_absent(&device, "if/1/ifInOctets"));
+        assert!(state.check_absent(150_000).is_empty());
+        assert_eq!(state.check_absent(191_000).len(), 1);
+
+        // Absence rules never fire on a sample.
+        assert!(
+            state
+                .check_metric(&device, "if/1/ifInOctets", 1.0, 200_000)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn import_merge_keeps_rules_and_assigns_fresh_ids() {
+        let mut state = AlertsState::new();
+        state.rules.push(AlertRule::new(1, "CPU", "cpu"));
+        state.rules.push(AlertRule::new(7, "Memory", "memory"));
+
+        // A library from another install, reusing IDs that collide here.
+        let library = rules_to_json(&[
+            AlertRule::new(1, "Errors", "ifInErrors"),
+            AlertRule::new(7, "Uplink", "ifOperStatus"),
+        ]);
+        let imported = rules_from_json(&library).unwrap();
+        assert_eq!(state.import_rules(imported, RuleImportMode::Merge), 2);
+
+        let ids: Vec<(u32, &str)> = state
+            .rules
+            .iter()
+            .map(|r| (r.id, r.name.as_str()))
+            .collect();
+        assert_eq!(
+            ids,
+            [(1, "CPU"), (7, "Memory"), (8, "Errors"), (9, "Uplink")]
+        );
+
+        // Rules added from the form afterwards don't collide either.
+        state.set_new_rule_name("Disk".to_string());
+        state.set_new_rule_metric("disk".to_string());
+        state.set_new_rule_threshold("90".to_string());
+        state.add_rule().unwrap();
+        assert_eq!(state.rules.last().unwrap().id, 10);
+
+        let replaced = rules_from_json(&library).unwrap();
+        state.import_rules(replaced, RuleImportMode::Replace);
+        assert_eq!(state.rules.len(), 2);
+        assert_eq!(state.rules[0].name, "Errors");
+        assert!(rules_from_json("{}").is_err());
+    }
+
     #[test]
     fn test_comparison_operators() {
         assert_eq!(ComparisonOp::GreaterThan.symbol(), ">");
@@ -1,5 +1,7 @@
 //! Time-series chart component using Iced canvas.
 
+use std::cell::Cell;
+
 use iced::keyboard;
 use iced::mouse;
 use iced::widget::canvas::{
@@ -11,7 +13,7 @@ use zensight_common::TelemetryValue;
 
 use super::components::kit;
 use super::formatting::{format_time_offset, format_value};
-use super::theme;
+use super::theme::{self, ChartPalette};
 
 /// Map a timestamp to a horizontal fraction `[0,1]` of the plot area, centering
 /// the point when the time range is zero (single sample / all-same timestamp).
@@ -36,6 +38,36 @@ fn y_fraction(value: f64, value_min: f64, value_range: f64) -> f32 {
     }
 }
 
+/// The vertices a series is drawn through. In [`Interpolation::Step`] mode
+/// each value holds flat until the next sample, then jumps:
+/// `(x0,y0) (x1,y0) (x1,y1) …`.
+fn line_vertices(points: &[Point], interpolation: Interpolation) -> Vec<Point> {
+    if interpolation == Interpolation::Linear {
+        return points.to_vec();
+    }
+    let mut vertices = Vec::with_capacity(points.len() * 2);
+    for (i, point) in points.iter().enumerate() {
+        if i > 0 {
+            vertices.push(Point::new(point.x, points[i - 1].y));
+        }
+        vertices.push(*point);
+    }
+    vertices
+}
+
+/// Stroke path through `points` in the given interpolation mode.
+fn polyline(points: &[Point], interpolation: Interpolation) -> Path {
+    let mut builder = canvas::path::Builder::new();
+    for (i, vertex) in line_vertices(points, interpolation).into_iter().enumerate() {
+        if i == 0 {
+            builder.move_to(vertex);
+        } else {
+            builder.line_to(vertex);
+        }
+    }
+    builder.build()
+}
+
 /// Minimum zoom level (100% = no zoom).
 pub const MIN_ZOOM: f32 = 1.0;
 /// Maximum zoom level (10x zoom).
@@ -44,7 +76,9 @@ pub const MAX_ZOOM: f32 = 10.0;
 pub const ZOOM_STEP: f32 = 0.25;
 
 /// A data point for the chart.
-#[derive(Debug, Clone)]
+///
+/// Serializable so the history query API (#465) can serve buffered series.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct DataPoint {
     /// Timestamp in milliseconds.
     pub timestamp: i64,
@@ -80,7 +114,8 @@ impl DataPoint {
     }
 }
 
-/// Predefined colors for chart series.
+/// Predefined colors for chart series. Series keep these as their identity;
+/// the color-blind-safe palette swaps them at draw time (#472).
 pub const SERIES_COLORS: &[(f32, f32, f32)] = &[
     (0.2, 0.7, 1.0), // Blue (primary)
     (1.0, 0.5, 0.2), // Orange
@@ -92,6 +127,65 @@ pub const SERIES_COLORS: &[(f32, f32, f32)] = &[
     (0.9, 0.5, 0.5), // Coral
 ];
 
+/// Which Y-axis a series is scaled against (#394). Series on different axes get
+/// independent min/max so a small-range metric isn't flattened by a large one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum YAxis {
+    /// Left axis (default; shared with single-series data).
+    #[default]
+    Left,
+    /// Right axis, drawn only when a visible series is assigned to it.
+    Right,
+}
+
+/// How a series is drawn between samples (#416).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Interpolation {
+    /// Straight lines between samples (default).
+    #[default]
+    Linear,
+    /// Hold each value until the next sample, then jump vertically. Right
+    /// for state metrics and booleans, where a diagonal would show values
+    /// that never existed.
+    Step,
+}
+
+impl Interpolation {
+    /// The other mode.
+    pub fn toggled(self) -> Self {
+        match self {
+            Interpolation::Linear => Interpolation::Step,
+            Interpolation::Step => Interpolation::Linear,
+        }
+    }
+
+    /// Short label for chart controls.
+    pub fn label(self) -> &'static str {
+        match self {
+            Interpolation::Linear => "linear",
+            Interpolation::Step => "step",
+        }
+    }
+}
+
+impl YAxis {
+    /// The other axis.
+    pub fn toggled(self) -> Self {
+        match self {
+            YAxis::Left => YAxis::Right,
+            YAxis::Right => YAxis::Left,
+        }
+    }
+
+    /// Short label for legends ("L" / "R").
+    pub fn label(self) -> &'static str {
+        match self {
+            YAxis::Left => "L",
+            YAxis::Right => "R",
+        }
+    }
+}
+
 /// A data series for multi-metric comparison.
 #[derive(Debug, Clone)]
 pub struct DataSeries {
@@ -103,6 +197,10 @@ pub struct DataSeries {
     pub color: (f32, f32, f32),
     /// Whether this series is visible.
     pub visible: bool,
+    /// Y-axis this series is scaled against (#394).
+    pub axis: YAxis,
+    /// How the series is drawn between samples (#416).
+    pub interpolation: Interpolation,
 }
 
 impl DataSeries {
@@ -113,9 +211,17 @@ impl DataSeries {
             data: Vec::new(),
             color,
             visible: true,
+            axis: YAxis::Left,
+            interpolation: Interpolation::Linear,
         }
     }
 
+    /// Assign the series to `axis`.
+    pub fn with_axis(mut self, axis: YAxis) -> Self {
+        self.axis = axis;
+        self
+    }
+
     /// Create a new data series with auto-assigned color.
     pub fn with_index(name: impl Into<String>, index: usize) -> Self {
         let color = SERIES_COLORS[index % SERIES_COLORS.len()];
@@ -206,9 +312,41 @@ impl TimeWindow {
     }
 }
 
+impl std::fmt::Display for TimeWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
 /// Pan step as fraction of visible range.
 pub const PAN_STEP: f64 = 0.25;
 
+/// Default gap after which the newest point counts as stale (#395) — matches
+/// the device view's per-metric stale threshold.
+pub const DEFAULT_STALE_AFTER_MS: i64 = 60_000;
+
+/// What a [`ThresholdLine`] marks, which decides its color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThresholdKind {
+    /// Critical limit (red).
+    Critical,
+    /// Warning limit (orange).
+    Warning,
+    /// Baseline/target (green).
+    Baseline,
+}
+
+impl ThresholdKind {
+    /// This kind's line color in `palette`.
+    pub fn color(self, palette: &theme::Palette) -> (f32, f32, f32) {
+        match self {
+            ThresholdKind::Critical => palette.threshold_critical,
+            ThresholdKind::Warning => palette.threshold_warning,
+            ThresholdKind::Baseline => palette.threshold_baseline,
+        }
+    }
+}
+
 /// A horizontal threshold line on the chart.
 #[derive(Debug, Clone)]
 pub struct ThresholdLine {
@@ -216,8 +354,9 @@ pub struct ThresholdLine {
     pub value: f64,
     /// Label for the threshold.
     pub label: String,
-    /// Color of the line (RGB).
-    pub color: (f32, f32, f32),
+    /// What the line marks. Its color is looked up in the active palette when
+    /// drawn, so open charts follow the color-blind setting (#472).
+    pub kind: ThresholdKind,
     /// Whether this is a warning (dashed) or critical (solid) threshold.
     pub is_warning: bool,
 }
@@ -228,7 +367,7 @@ impl ThresholdLine {
         Self {
             value,
             label: label.into(),
-            color: (1.0, 0.3, 0.3),
+            kind: ThresholdKind::Critical,
             is_warning: false,
         }
     }
@@ -238,7 +377,7 @@ impl ThresholdLine {
         Self {
             value,
             label: label.into(),
-            color: (1.0, 0.7, 0.2),
+            kind: ThresholdKind::Warning,
             is_warning: true,
         }
     }
@@ -248,7 +387,7 @@ impl ThresholdLine {
         Self {
             value,
             label: label.into(),
-            color: (0.3, 0.8, 0.3),
+            kind: ThresholdKind::Baseline,
             is_warning: true,
         }
     }
@@ -267,10 +406,16 @@ pub struct ChartState {
     title: String,
     /// Cache for the chart geometry.
     cache: Cache,
-    /// Minimum value in the data.
+    /// Theme darkness and color-blind palette the cached geometry was drawn
+    /// with (#393, #472); a mismatch at draw time clears the cache so a theme
+    /// or palette toggle recolors the chart.
+    drawn_colors: Cell<Option<(bool, bool)>>,
+    /// Minimum value in the data (left axis).
     min_value: f64,
-    /// Maximum value in the data.
+    /// Maximum value in the data (left axis).
     max_value: f64,
+    /// Right-axis `(min, max)` (#394), `None` when no visible series uses it.
+    right_bounds: Option<(f64, f64)>,
     /// Current timestamp (for calculating visible range).
     current_time: i64,
     /// Current zoom level (1.0 = 100%, 2.0 = 200%, etc.).
@@ -300,6 +445,11 @@ pub struct ChartState {
     /// ignoring `current_time`/pan/zoom, so the chart shows exactly that slice
     /// loaded from the store. Cleared by choosing a preset or a custom duration.
     absolute_range: Option<(i64, i64)>,
+    /// Gap between the newest visible point and "now" past which the chart
+    /// draws a "no recent data" region (#395).
+    stale_after_ms: i64,
+    /// How the single series is drawn between samples (#416).
+    interpolation: Interpolation,
 }
 
 impl ChartState {
@@ -311,8 +461,10 @@ impl ChartState {
             time_window: TimeWindow::default(),
             title: title.into(),
             cache: Cache::new(),
+            drawn_colors: Cell::new(None),
             min_value: 0.0,
             max_value: 1.0,
+            right_bounds: None,
             current_time: current_timestamp(),
             zoom_level: 1.0,
             pan_offset: 0.0,
@@ -325,9 +477,17 @@ impl ChartState {
             thresholds: Vec::new(),
             custom_duration_ms: None,
             absolute_range: None,
+            stale_after_ms: DEFAULT_STALE_AFTER_MS,
+            interpolation: Interpolation::Linear,
         }
     }
 
+    /// Replace the chart title, e.g. to show the unit in rate mode (#442).
+    pub fn set_title(&mut self, title: impl Into<String>) {
+        self.title = title.into();
+        self.cache.clear();
+    }
+
     /// Add a threshold line to the chart.
     pub fn add_threshold(&mut self, threshold: ThresholdLine) {
         self.thresholds.push(threshold);
@@ -425,10 +585,38 @@ impl ChartState {
     pub fn toggle_series_visibility(&mut self, name: &str) {
         if let Some(series) = self.series.iter_mut().find(|s| s.name == name) {
             series.toggle_visibility();
+            self.recalculate_bounds();
             self.cache.clear();
         }
     }
 
+    /// Assign a series to the left or right Y-axis (#394).
+    pub fn set_series_axis(&mut self, name: &str, axis: YAxis) {
+        if let Some(series) = self.series.iter_mut().find(|s| s.name == name)
+            && series.axis != axis
+        {
+            series.axis = axis;
+            self.recalculate_bounds();
+            self.cache.clear();
+        }
+    }
+
+    /// Move a series to the other Y-axis (#394).
+    pub fn toggle_series_axis(&mut self, name: &str) {
+        if let Some(axis) = self.series.iter().find(|s| s.name == name).map(|s| s.axis) {
+            self.set_series_axis(name, axis.toggled());
+        }
+    }
+
+    /// Value bounds `(min, max)` for `axis`, padded for display (#394). The
+    /// right axis reports `None` when no visible series is assigned to it.
+    pub fn axis_bounds(&self, axis: YAxis) -> Option<(f64, f64)> {
+        match axis {
+            YAxis::Left => Some((self.min_value, self.max_value)),
+            YAxis::Right => self.right_bounds,
+        }
+    }
+
     /// Push a data point to a specific series.
     pub fn push_to_series(&mut self, name: &str, point: DataPoint) {
         if let Some(series) = self.series.iter_mut().find(|s| s.name == name) {
@@ -683,6 +871,80 @@ impl ChartState {
         }
     }
 
+    /// Set how the single series is drawn between samples (#416).
+    pub fn set_interpolation(&mut self, interpolation: Interpolation) {
+        self.interpolation = interpolation;
+        self.cache.clear();
+    }
+
+    /// How the single series is drawn between samples.
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// Switch the single series between linear and step.
+    pub fn toggle_interpolation(&mut self) {
+        self.set_interpolation(self.interpolation.toggled());
+    }
+
+    /// Set how the named series is drawn between samples (#416).
+    pub fn set_series_interpolation(&mut self, name: &str, interpolation: Interpolation) {
+        if let Some(series) = self.series.iter_mut().find(|s| s.name == name) {
+            series.interpolation = interpolation;
+            self.cache.clear();
+        }
+    }
+
+    /// Switch the named series between linear and step.
+    pub fn toggle_series_interpolation(&mut self, name: &str) {
+        if let Some(current) = self
+            .series
+            .iter()
+            .find(|s| s.name == name)
+            .map(|s| s.interpolation)
+        {
+            self.set_series_interpolation(name, current.toggled());
+        }
+    }
+
+    /// Set the staleness threshold for the "no recent data" overlay (#395).
+    pub fn set_stale_after_ms(&mut self, ms: i64) {
+        self.stale_after_ms = ms.max(0);
+        self.cache.clear();
+    }
+
+    /// The `(from_ms, to_ms)` span to mark as "no recent data" (#395): from the
+    /// newest visible point up to "now", when that gap exceeds the staleness
+    /// threshold. `None` while data is fresh, when nothing is visible, or when
+    /// the view doesn't reach "now": a pinned absolute window or a pan into
+    /// history isn't expected to be current.
+    pub fn stale_region(&self) -> Option<(i64, i64)> {
+        if self.absolute_range.is_some() || self.is_panned() {
+            return None;
+        }
+        let (start, end) = self.visible_time_range();
+        if end < self.current_time {
+            return None;
+        }
+        let newest = self
+            .data
+            .iter()
+            .chain(
+                self.series
+                    .iter()
+                    .filter(|s| s.visible)
+                    .flat_map(|s| s.data.iter()),
+            )
+            .map(|p| p.timestamp)
+            .filter(|&ts| ts >= start && ts <= end)
+            .max()?;
+        if self.current_time - newest > self.stale_after_ms {
+            Some((newest, self.current_time))
+        } else {
+            None
+        }
+    }
+
     /// Add a data point.
     pub fn push(&mut self, point: DataPoint) {
         self.data.push(point);
@@ -740,90 +1002,52 @@ impl ChartState {
             .filter(move |p| p.timestamp >= start && p.timestamp <= end)
     }
 
-    /// Recalculate min/max bounds.
+    /// Recalculate min/max bounds, independently per Y-axis (#394).
     fn recalculate_bounds(&mut self) {
         let (start, end) = self.visible_time_range();
+        let in_range = |p: &&DataPoint| p.timestamp >= start && p.timestamp <= end;
 
-        let mut min_val = f64::INFINITY;
-        let mut max_val = f64::NEG_INFINITY;
+        let mut left = (f64::INFINITY, f64::NEG_INFINITY);
+        let mut right = (f64::INFINITY, f64::NEG_INFINITY);
+        let mut has_right = false;
 
-        // Check single-series data
-        for p in self
-            .data
-            .iter()
-            .filter(|p| p.timestamp >= start && p.timestamp <= end)
-        {
-            min_val = min_val.min(p.value);
-            max_val = max_val.max(p.value);
+        // Single-series data always scales against the left axis.
+        for p in self.data.iter().filter(in_range) {
+            left = (left.0.min(p.value), left.1.max(p.value));
         }
 
-        // Check multi-series data (only visible series)
-        for series in &self.series {
-            if !series.visible {
-                continue;
-            }
-            for p in series
-                .data
-                .iter()
-                .filter(|p| p.timestamp >= start && p.timestamp <= end)
-            {
-                min_val = min_val.min(p.value);
-                max_val = max_val.max(p.value);
+        // Multi-series data (only visible series), per assigned axis.
+        for series in self.series.iter().filter(|s| s.visible) {
+            let bounds = match series.axis {
+                YAxis::Left => &mut left,
+                YAxis::Right => {
+                    has_right = true;
+                    &mut right
+                }
+            };
+            for p in series.data.iter().filter(in_range) {
+                *bounds = (bounds.0.min(p.value), bounds.1.max(p.value));
             }
         }
 
-        if min_val == f64::INFINITY || max_val == f64::NEG_INFINITY {
-            self.min_value = 0.0;
-            self.max_value = 1.0;
-            return;
-        }
-
-        self.min_value = min_val;
-        self.max_value = max_val;
-
-        // Add some padding
-        let range = self.max_value - self.min_value;
-        if range < 0.001 {
-            // Very small range, add artificial padding
-            self.min_value -= 0.5;
-            self.max_value += 0.5;
-        } else {
-            let padding = range * 0.1;
-            self.min_value -= padding;
-            self.max_value += padding;
-        }
+        (self.min_value, self.max_value) = padded_bounds(left.0, left.1);
+        self.right_bounds = has_right.then(|| padded_bounds(right.0, right.1));
     }
 
     /// Get statistics for the visible data.
     pub fn stats(&self) -> ChartStats {
-        let visible: Vec<_> = self.visible_data().collect();
-
-        if visible.is_empty() {
-            return ChartStats::default();
-        }
-
-        let sum: f64 = visible.iter().map(|p| p.value).sum();
-        let count = visible.len();
-        let avg = sum / count as f64;
-
-        let min = visible
-            .iter()
-            .map(|p| p.value)
-            .fold(f64::INFINITY, f64::min);
-        let max = visible
-            .iter()
-            .map(|p| p.value)
-            .fold(f64::NEG_INFINITY, f64::max);
-
-        let current = visible.last().map(|p| p.value);
+        ChartStats::from_points(self.visible_data())
+    }
 
-        ChartStats {
-            min,
-            max,
-            avg,
-            current,
-            count,
-        }
+    /// Statistics for `points` over this chart's visible window, so the device
+    /// table's per-metric strip (#441) agrees with the chart it sits under.
+    pub fn window_stats(&self, points: &[DataPoint]) -> ChartStats {
+        let (start, end) = self.visible_time_range();
+        ChartStats::from_points(
+            points
+                .iter()
+                .filter(|p| p.timestamp >= start && p.timestamp <= end),
+        )
     }
 }
 
@@ -842,26 +1066,39 @@ pub struct ChartStats {
     pub count: usize,
 }
 
-/// Chart widget that renders the time-series data.
+impl ChartStats {
+    /// Compute statistics over `points` (oldest first); all-zero when empty.
+    pub fn from_points<'a>(points: impl IntoIterator<Item = &'a DataPoint>) -> Self {
+        let values: Vec<f64> = points.into_iter().map(|p| p.value).collect();
+        if values.is_empty() {
+            return Self::default();
+        }
+
+        let count = values.len();
+        let avg = values.iter().sum::<f64>() / count as f64;
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+        Self {
+            min,
+            max,
+            avg,
+            current: values.last().copied(),
+            count,
+        }
+    }
+}
+
+/// Chart widget that renders the time-series data. Colors come from the
+/// active [`Theme`] at draw time (#393), so there is no dark/light switch here.
 pub struct Chart<'a> {
     state: &'a ChartState,
-    /// Whether the theme is dark (affects colors).
-    is_dark: bool,
 }
 
 impl<'a> Chart<'a> {
     /// Create a new chart widget.
     pub fn new(state: &'a ChartState) -> Self {
-        Self {
-            state,
-            is_dark: true, // Default to dark
-        }
-    }
-
-    /// Set whether the theme is dark.
-    pub fn dark(mut self, is_dark: bool) -> Self {
-        self.is_dark = is_dark;
-        self
+        Self { state }
     }
 }
 
@@ -990,12 +1227,21 @@ impl<'a> canvas::Program<crate::message::Message> for Chart<'a> {
         &self,
         _state: &Self::State,
         renderer: &Renderer,
-        _theme: &Theme,
+        theme: &Theme,
         bounds: Rectangle,
         _cursor: mouse::Cursor,
     ) -> Vec<Geometry> {
+        // The cached geometry bakes in colors, so a theme or palette switch
+        // must redraw.
+        let colors = (
+            theme.extended_palette().is_dark,
+            theme::palette().color_blind_safe,
+        );
+        if self.state.drawn_colors.replace(Some(colors)) != Some(colors) {
+            self.state.cache.clear();
+        }
         let geometry = self.state.cache.draw(renderer, bounds.size(), |frame| {
-            self.draw_chart(frame, bounds.size());
+            self.draw_chart(frame, bounds.size(), theme);
         });
 
         vec![geometry]
@@ -1003,66 +1249,9 @@ impl<'a> canvas::Program<crate::message::Message> for Chart<'a> {
 }
 
 impl<'a> Chart<'a> {
-    // Theme-aware color helpers — all sourced from `theme::colors()` so the
-    // chart shares the single color source of truth (D2, #28).
-    fn theme(&self) -> Theme {
-        if self.is_dark {
-            Theme::Dark
-        } else {
-            Theme::Light
-        }
-    }
-
-    fn background_color(&self) -> Color {
-        theme::colors(&self.theme()).chart_outer_background()
-    }
-
-    fn chart_background_color(&self) -> Color {
-        theme::colors(&self.theme()).chart_background()
-    }
-
-    fn text_color(&self) -> Color {
-        theme::colors(&self.theme()).text()
-    }
-
-    fn muted_text_color(&self) -> Color {
-        theme::colors(&self.theme()).text_muted()
-    }
-
-    fn grid_color(&self) -> Color {
-        theme::colors(&self.theme()).chart_grid()
-    }
-
-    fn label_color(&self) -> Color {
-        theme::colors(&self.theme()).chart_label()
-    }
-
-    fn tooltip_background(&self) -> Color {
-        theme::colors(&self.theme()).chart_tooltip_background()
-    }
-
-    fn highlight_color(&self) -> Color {
-        theme::colors(&self.theme()).chart_highlight()
-    }
-
-    fn dimmed_series_color(&self) -> Color {
-        theme::colors(&self.theme()).text_dimmed()
-    }
-
-    fn legend_text_color(&self) -> Color {
-        theme::colors(&self.theme()).text_muted()
-    }
-
-    fn legend_text_hidden_color(&self) -> Color {
-        theme::colors(&self.theme()).text_dimmed()
-    }
-
-    fn stats_text_color(&self) -> Color {
-        theme::colors(&self.theme()).text_muted()
-    }
-
-    /// Draw the chart onto the frame.
-    fn draw_chart(&self, frame: &mut Frame, size: Size) {
+    /// Draw the chart onto the frame, colored for `theme`.
+    fn draw_chart(&self, frame: &mut Frame, size: Size, theme: &Theme) {
+        let palette = &ChartPalette::for_theme(theme);
         let padding = 50.0;
         let chart_width = size.width - padding * 2.0;
         let chart_height = size.height - padding * 2.0;
@@ -1073,20 +1262,20 @@ impl<'a> Chart<'a> {
 
         // Draw background
         let background = Path::rectangle(Point::ORIGIN, size);
-        frame.fill(&background, self.background_color());
+        frame.fill(&background, palette.background);
 
         // Draw chart area background
         let chart_bg = Path::rectangle(
             Point::new(padding, padding),
             Size::new(chart_width, chart_height),
         );
-        frame.fill(&chart_bg, self.chart_background_color());
+        frame.fill(&chart_bg, palette.plot_background);
 
         // Draw title
         let title = Text {
             content: self.state.title.clone(),
             position: Point::new(padding, 10.0),
-            color: self.text_color(),
+            color: palette.text,
             size: 14.0.into(),
             ..Text::default()
         };
@@ -1101,7 +1290,7 @@ impl<'a> Chart<'a> {
                 zoom_pct
             ),
             position: Point::new(size.width - padding - 140.0, 10.0),
-            color: self.muted_text_color(),
+            color: palette.text_muted,
             size: 12.0.into(),
             ..Text::default()
         };
@@ -1109,17 +1298,17 @@ impl<'a> Chart<'a> {
 
         // Draw zoom feedback overlay if recently changed
         if self.state.show_zoom_feedback {
-            self.draw_zoom_feedback(frame, size, zoom_pct);
+            self.draw_zoom_feedback(frame, size, zoom_pct, palette);
         }
 
         // Draw pan feedback overlay if recently changed
         if self.state.show_pan_feedback {
-            self.draw_pan_feedback(frame, size);
+            self.draw_pan_feedback(frame, size, palette);
         }
 
         // Draw "panned" indicator if not viewing current time
         if self.state.is_panned() {
-            self.draw_pan_indicator(frame, size, padding);
+            self.draw_pan_indicator(frame, size, padding, palette);
         }
 
         // Calculate time range (with zoom)
@@ -1140,7 +1329,7 @@ impl<'a> Chart<'a> {
             let no_data = Text {
                 content: "No data".to_string(),
                 position: Point::new(size.width / 2.0 - 30.0, size.height / 2.0),
-                color: self.label_color(),
+                color: palette.label,
                 size: 16.0.into(),
                 ..Text::default()
             };
@@ -1156,6 +1345,7 @@ impl<'a> Chart<'a> {
             chart_height,
             value_min,
             value_max,
+            palette,
         );
 
         // Draw threshold lines
@@ -1180,37 +1370,32 @@ impl<'a> Chart<'a> {
                 time_range,
                 value_min,
                 value_range,
+                palette,
             );
 
             // Draw legend for multi-series
-            self.draw_legend(frame, padding, chart_height);
+            self.draw_legend(frame, padding, chart_height, palette);
         } else {
             // Draw single-series data (backward compatibility)
             let visible_data: Vec<_> = self.state.visible_data().collect();
 
             if visible_data.len() >= 2 {
-                let mut path_builder = canvas::path::Builder::new();
-                let mut first = true;
-
-                for point in &visible_data {
-                    let x =
-                        padding + x_fraction(point.timestamp, time_start, time_range) * chart_width;
-                    let y = padding + chart_height
-                        - y_fraction(point.value, value_min, value_range) * chart_height;
-
-                    if first {
-                        path_builder.move_to(Point::new(x, y));
-                        first = false;
-                    } else {
-                        path_builder.line_to(Point::new(x, y));
-                    }
-                }
-
-                let path = path_builder.build();
+                let vertices: Vec<Point> = visible_data
+                    .iter()
+                    .map(|point| {
+                        Point::new(
+                            padding
+                                + x_fraction(point.timestamp, time_start, time_range) * chart_width,
+                            padding + chart_height
+                                - y_fraction(point.value, value_min, value_range) * chart_height,
+                        )
+                    })
+                    .collect();
+                let path = polyline(&vertices, self.state.interpolation);
                 frame.stroke(
                     &path,
                     Stroke::default()
-                        .with_color(self.highlight_color())
+                        .with_color(palette.highlight)
                         .with_width(2.0),
                 );
             }
@@ -1222,16 +1407,76 @@ impl<'a> Chart<'a> {
                     - y_fraction(point.value, value_min, value_range) * chart_height;
 
                 let dot = Path::circle(Point::new(x, y), 3.0);
-                frame.fill(&dot, self.highlight_color());
+                frame.fill(&dot, palette.highlight);
             }
 
             // Draw stats for single series
             let stats = self.state.stats();
-            self.draw_stats(frame, size, padding, &stats);
+            self.draw_stats(frame, size, padding, &stats, palette);
+        }
+
+        if let Some((from, to)) = self.state.stale_region() {
+            let x_from = padding + x_fraction(from, time_start, time_range) * chart_width;
+            let x_to = padding + x_fraction(to, time_start, time_range) * chart_width;
+            let gap_ms = self.state.current_time - from;
+            self.draw_stale_region(
+                frame,
+                padding,
+                chart_height,
+                (x_from, x_to),
+                gap_ms,
+                palette,
+            );
         }
     }
 
+    /// Shade the "no recent data" span (#395) with a dashed border and a label,
+    /// so a silent device doesn't read as a flat-lining but healthy metric.
+    fn draw_stale_region(
+        &self,
+        frame: &mut Frame,
+        padding: f32,
+        chart_height: f32,
+        (x_from, x_to): (f32, f32),
+        gap_ms: i64,
+        palette: &ChartPalette,
+    ) {
+        let width = x_to - x_from;
+        if width <= 0.0 {
+            return;
+        }
+
+        let region = Path::rectangle(Point::new(x_from, padding), Size::new(width, chart_height));
+        frame.fill(
+            &region,
+            Color {
+                a: 0.12,
+                ..palette.stale
+            },
+        );
+        frame.stroke(
+            &region,
+            Stroke {
+                line_dash: canvas::LineDash {
+                    segments: &[4.0, 4.0],
+                    offset: 0,
+                },
+                ..Stroke::default().with_color(palette.stale).with_width(1.5)
+            },
+        );
+
+        let label = Text {
+            content: format!("No recent data ({})", format_time_offset(gap_ms)),
+            position: Point::new(x_from + 4.0, padding + 4.0),
+            color: palette.stale,
+            size: 10.0.into(),
+            ..Text::default()
+        };
+        frame.fill_text(label);
+    }
+
     /// Draw grid lines and labels.
+    #[allow(clippy::too_many_arguments)]
     fn draw_grid(
         &self,
         frame: &mut Frame,
@@ -1240,13 +1485,16 @@ impl<'a> Chart<'a> {
         chart_height: f32,
         value_min: f64,
         value_max: f64,
+        palette: &ChartPalette,
     ) {
-        let grid_color = self.grid_color();
-        let label_color = self.label_color();
+        let grid_color = palette.grid;
+        let label_color = palette.label;
 
         // Horizontal grid lines (value axis)
         let num_h_lines = 5;
         let value_range = value_max - value_min;
+        // Right-axis labels share the grid lines but carry their own scale (#394).
+        let right = self.state.right_bounds.map(|(lo, hi)| (hi, hi - lo));
 
         for i in 0..=num_h_lines {
             let y = padding + (i as f32 / num_h_lines as f32) * chart_height;
@@ -1268,6 +1516,18 @@ impl<'a> Chart<'a> {
                 ..Text::default()
             };
             frame.fill_text(label);
+
+            if let Some((right_max, right_range)) = right {
+                let right_value = right_max - (i as f64 / num_h_lines as f64) * right_range;
+                let label = Text {
+                    content: format_value(right_value),
+                    position: Point::new(padding + chart_width + 4.0, y - 6.0),
+                    color: label_color,
+                    size: 10.0.into(),
+                    ..Text::default()
+                };
+                frame.fill_text(label);
+            }
         }
 
         // Vertical grid lines (time axis)
@@ -1303,7 +1563,13 @@ impl<'a> Chart<'a> {
     }
 
     /// Draw zoom feedback overlay.
-    fn draw_zoom_feedback(&self, frame: &mut Frame, size: Size, zoom_pct: i32) {
+    fn draw_zoom_feedback(
+        &self,
+        frame: &mut Frame,
+        size: Size,
+        zoom_pct: i32,
+        palette: &ChartPalette,
+    ) {
         // Semi-transparent background box
         let box_width = 120.0;
         let box_height = 50.0;
@@ -1311,13 +1577,13 @@ impl<'a> Chart<'a> {
         let box_y = (size.height - box_height) / 2.0;
 
         let bg = Path::rectangle(Point::new(box_x, box_y), Size::new(box_width, box_height));
-        frame.fill(&bg, self.tooltip_background());
+        frame.fill(&bg, palette.tooltip_background);
 
         // Border
         frame.stroke(
             &bg,
             Stroke::default()
-                .with_color(self.highlight_color())
+                .with_color(palette.highlight)
                 .with_width(2.0),
         );
 
@@ -1332,7 +1598,7 @@ impl<'a> Chart<'a> {
         let zoom_text = Text {
             content: format!("{}{}%", icon_text, zoom_pct),
             position: Point::new(box_x + box_width / 2.0 - 25.0, box_y + 15.0),
-            color: self.text_color(),
+            color: palette.text,
             size: 20.0.into(),
             ..Text::default()
         };
@@ -1342,7 +1608,7 @@ impl<'a> Chart<'a> {
         let hint = Text {
             content: "Ctrl+Scroll or +/-".to_string(),
             position: Point::new(box_x + 10.0, box_y + box_height - 15.0),
-            color: self.muted_text_color(),
+            color: palette.text_muted,
             size: 9.0.into(),
             ..Text::default()
         };
@@ -1350,19 +1616,19 @@ impl<'a> Chart<'a> {
     }
 
     /// Draw pan feedback overlay.
-    fn draw_pan_feedback(&self, frame: &mut Frame, size: Size) {
+    fn draw_pan_feedback(&self, frame: &mut Frame, size: Size, palette: &ChartPalette) {
         let box_width = 140.0;
         let box_height = 50.0;
         let box_x = (size.width - box_width) / 2.0;
         let box_y = (size.height - box_height) / 2.0;
 
         let bg = Path::rectangle(Point::new(box_x, box_y), Size::new(box_width, box_height));
-        frame.fill(&bg, self.tooltip_background());
+        frame.fill(&bg, palette.tooltip_background);
 
         frame.stroke(
             &bg,
             Stroke::default()
-                .with_color(self.highlight_color())
+                .with_color(palette.highlight)
                 .with_width(2.0),
         );
 
@@ -1376,7 +1642,7 @@ impl<'a> Chart<'a> {
         let pan_text = Text {
             content: format!("{} {}", icon, label),
             position: Point::new(box_x + 15.0, box_y + 15.0),
-            color: self.text_color(),
+            color: palette.text,
             size: 16.0.into(),
             ..Text::default()
         };
@@ -1385,7 +1651,7 @@ impl<'a> Chart<'a> {
         let hint = Text {
             content: "Drag or Arrow keys".to_string(),
             position: Point::new(box_x + 15.0, box_y + box_height - 15.0),
-            color: self.muted_text_color(),
+            color: palette.text_muted,
             size: 9.0.into(),
             ..Text::default()
         };
@@ -1393,7 +1659,13 @@ impl<'a> Chart<'a> {
     }
 
     /// Draw pan indicator when viewing historical data.
-    fn draw_pan_indicator(&self, frame: &mut Frame, _size: Size, padding: f32) {
+    fn draw_pan_indicator(
+        &self,
+        frame: &mut Frame,
+        _size: Size,
+        padding: f32,
+        palette: &ChartPalette,
+    ) {
         // Draw a "PAUSED - Viewing History" badge at top left
         let badge_width = 150.0;
         let badge_height = 22.0;
@@ -1405,7 +1677,7 @@ impl<'a> Chart<'a> {
             Size::new(badge_width, badge_height),
         );
         // Warning badge color stays the same (amber/orange for visibility)
-        frame.fill(&bg, theme::colors(&self.theme()).chart_feedback());
+        frame.fill(&bg, palette.feedback);
 
         let text = Text {
             content: "PAUSED - Viewing Past".to_string(),
@@ -1420,7 +1692,7 @@ impl<'a> Chart<'a> {
         let hint = Text {
             content: "(Home to reset)".to_string(),
             position: Point::new(badge_x + badge_width + 10.0, badge_y + 4.0),
-            color: self.muted_text_color(),
+            color: palette.text_muted,
             size: 10.0.into(),
             ..Text::default()
         };
@@ -1452,7 +1724,7 @@ impl<'a> Chart<'a> {
             let y = padding + chart_height
                 - ((threshold.value - value_min) / value_range) as f32 * chart_height;
 
-            let color = kit::rgb(threshold.color);
+            let color = kit::rgb(threshold.kind.color(theme::palette()));
 
             // Draw the line
             let line = Path::line(Point::new(padding, y), Point::new(padding + chart_width, y));
@@ -1492,6 +1764,7 @@ impl<'a> Chart<'a> {
         time_range: f64,
         value_min: f64,
         value_range: f64,
+        palette: &ChartPalette,
     ) {
         for series in &self.state.series {
             if !series.visible {
@@ -1504,25 +1777,25 @@ impl<'a> Chart<'a> {
                 continue;
             }
 
-            // Draw the line
-            let mut path_builder = canvas::path::Builder::new();
-            let mut first = true;
-
-            for point in &visible_data {
-                let x = padding + x_fraction(point.timestamp, time_start, time_range) * chart_width;
-                let y = padding + chart_height
-                    - y_fraction(point.value, value_min, value_range) * chart_height;
-
-                if first {
-                    path_builder.move_to(Point::new(x, y));
-                    first = false;
-                } else {
-                    path_builder.line_to(Point::new(x, y));
-                }
-            }
+            // Right-axis series scale against their own bounds (#394).
+            let (value_min, value_range) = match (series.axis, self.state.right_bounds) {
+                (YAxis::Right, Some((lo, hi))) => (lo, hi - lo),
+                _ => (value_min, value_range),
+            };
 
-            let path = path_builder.build();
-            let color = kit::rgb(series.color);
+            // Draw the line
+            let vertices: Vec<Point> = visible_data
+                .iter()
+                .map(|point| {
+                    Point::new(
+                        padding + x_fraction(point.timestamp, time_start, time_range) * chart_width,
+                        padding + chart_height
+                            - y_fraction(point.value, value_min, value_range) * chart_height,
+                    )
+                })
+                .collect();
+            let path = polyline(&vertices, series.interpolation);
+            let color = palette.series(series.color);
             frame.stroke(&path, Stroke::default().with_color(color).with_width(2.0));
 
             // Draw data points
@@ -1532,7 +1805,7 @@ impl<'a> Chart<'a> {
                     - y_fraction(point.value, value_min, value_range) * chart_height;
 
                 // Slightly brighter color for points
-                let point_color = kit::rgb((
+                let point_color = palette.series((
                     (series.color.0 + 0.1).min(1.0),
                     (series.color.1 + 0.1).min(1.0),
                     (series.color.2 + 0.1).min(1.0),
@@ -1544,7 +1817,13 @@ impl<'a> Chart<'a> {
     }
 
     /// Draw legend for multi-series chart.
-    fn draw_legend(&self, frame: &mut Frame, padding: f32, chart_height: f32) {
+    fn draw_legend(
+        &self,
+        frame: &mut Frame,
+        padding: f32,
+        chart_height: f32,
+        palette: &ChartPalette,
+    ) {
         let legend_x = padding + 10.0;
         let legend_y = padding + chart_height + 25.0;
         let item_width = 120.0;
@@ -1558,24 +1837,27 @@ impl<'a> Chart<'a> {
 
             // Color indicator box
             let color = if series.visible {
-                kit::rgb(series.color)
+                palette.series(series.color)
             } else {
-                self.dimmed_series_color()
+                palette.dimmed
             };
             let box_path = Path::rectangle(Point::new(x, y), Size::new(12.0, 12.0));
             frame.fill(&box_path, color);
 
-            // Series name (truncated if needed)
-            let name = if series.name.len() > 12 {
+            // Series name (truncated if needed), tagged when on the right axis
+            let mut name = if series.name.len() > 12 {
                 format!("{}...", &series.name[..9])
             } else {
                 series.name.clone()
             };
+            if series.axis == YAxis::Right {
+                name.push_str(" (R)");
+            }
 
             let text_color = if series.visible {
-                self.legend_text_color()
+                palette.text_muted
             } else {
-                self.legend_text_hidden_color()
+                palette.dimmed
             };
 
             let label = Text {
@@ -1589,7 +1871,14 @@ impl<'a> Chart<'a> {
         }
     }
 
-    fn draw_stats(&self, frame: &mut Frame, size: Size, padding: f32, stats: &ChartStats) {
+    fn draw_stats(
+        &self,
+        frame: &mut Frame,
+        size: Size,
+        padding: f32,
+        stats: &ChartStats,
+        palette: &ChartPalette,
+    ) {
         let stats_x = size.width - padding - 100.0;
         let stats_y = padding + 10.0;
         let line_height = 14.0;
@@ -1609,7 +1898,7 @@ impl<'a> Chart<'a> {
             let text = Text {
                 content: line.clone(),
                 position: Point::new(stats_x, stats_y + i as f32 * line_height),
-                color: self.stats_text_color(),
+                color: palette.text_muted,
                 size: 11.0.into(),
                 ..Text::default()
             };
@@ -1627,6 +1916,22 @@ pub fn chart_view(state: &ChartState, height: f32) -> Element<'_, crate::message
         .into()
 }
 
+/// Pad a raw `(min, max)` for display: 10% headroom, a unit range around a
+/// flat line, and `(0, 1)` when there was no data at all.
+fn padded_bounds(min: f64, max: f64) -> (f64, f64) {
+    if min == f64::INFINITY || max == f64::NEG_INFINITY {
+        return (0.0, 1.0);
+    }
+    let range = max - min;
+    if range < 0.001 {
+        // Very small range, add artificial padding
+        (min - 0.5, max + 0.5)
+    } else {
+        let padding = range * 0.1;
+        (min - padding, max + padding)
+    }
+}
+
 /// Get the current timestamp in milliseconds.
 fn current_timestamp() -> i64 {
     std::time::SystemTime::now()
@@ -1654,9 +1959,10 @@ pub fn parse_datetime_to_ms(input: &str) -> Option<i64> {
 // (the only D2-clean path to a `Color`); structural colors come from `theme`.
 
 /// A palette entry for categorical charts (donut slices, ranked bars). Wraps the
-/// shared [`SERIES_COLORS`] so callers don't touch raw tuples.
+/// active palette's series colors (#472) so callers don't touch raw tuples.
 fn category_color(index: usize) -> Color {
-    kit::rgb(SERIES_COLORS[index % SERIES_COLORS.len()])
+    let series = theme::palette().series;
+    kit::rgb(series[index % series.len()])
 }
 
 /// Horizontal **ranked bar chart** from `(label, value)` pairs. Rows are shown
@@ -2112,6 +2418,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_boolean_series_steps_between_samples() {
+        // #415: a boolean flap yields chartable 0/1 points...
+        let up = DataPoint::from_telemetry(1000, &TelemetryValue::Boolean(true)).unwrap();
+        let down = DataPoint::from_telemetry(2000, &TelemetryValue::Boolean(false)).unwrap();
+        assert_eq!((up.value, down.value), (1.0, 0.0));
+
+        // ...drawn as a step: hold the old value, then drop vertically.
+        let points = [
+            Point::new(0.0, 10.0),
+            Point::new(5.0, 0.0),
+            Point::new(8.0, 10.0),
+        ];
+        assert_eq!(
+            line_vertices(&points, Interpolation::Step),
+            vec![
+                Point::new(0.0, 10.0),
+                Point::new(5.0, 10.0),
+                Point::new(5.0, 0.0),
+                Point::new(8.0, 0.0),
+                Point::new(8.0, 10.0),
+            ]
+        );
+        assert_eq!(
+            line_vertices(&points, Interpolation::Linear),
+            points.to_vec()
+        );
+    }
+
+    #[test]
+    fn test_step_interpolation_segments() {
+        // #416: a rising counter in step mode is a staircase, horizontal
+        // segment first, alternating with vertical ones.
+        let points = [
+            Point::new(0.0, 30.0),
+            Point::new(10.0, 20.0),
+            Point::new(20.0, 20.0),
+            Point::new(30.0, 5.0),
+        ];
+        let vertices = line_vertices(&points, Interpolation::Step);
+        assert_eq!(vertices.len(), 2 * points.len() - 1);
+        for (i, segment) in vertices.windows(2).enumerate() {
+            if i % 2 == 0 {
+                assert_eq!(segment[0].y, segment[1].y, "segment {i} is horizontal");
+            } else {
+                assert_eq!(segment[0].x, segment[1].x, "segment {i} is vertical");
+            }
+        }
+        assert_eq!(vertices.last(), points.last());
+
+        // Selectable per chart and per series.
+        let mut chart = ChartState::new("Metric Comparison");
+        assert_eq!(chart.interpolation(), Interpolation::Linear);
+        chart.toggle_interpolation();
+        assert_eq!(chart.interpolation(), Interpolation::Step);
+        chart.add_series("a");
+        chart.add_series("b");
+        chart.toggle_series_interpolation("b");
+        assert_eq!(chart.series()[0].interpolation, Interpolation::Linear);
+        assert_eq!(chart.series()[1].interpolation, Interpolation::Step);
+    }
+
     #[test]
     fn test_chart_state_push() {
         let mut chart = ChartState::new("test");
@@ -2328,6 +2696,14 @@ mod tests {
         assert_eq!(warning.value, 80.0);
         assert!(warning.is_warning);
 
+        // Colors come from whichever palette is active when drawing (#472).
+        let cb = &theme::COLOR_BLIND_SAFE_PALETTE;
+        assert_eq!(critical.kind.color(cb), cb.threshold_critical);
+        assert_eq!(
+            chart.thresholds()[2].kind.color(&theme::STANDARD_PALETTE),
+            theme::STANDARD_PALETTE.threshold_baseline
+        );
+
         // Clear thresholds
         chart.clear_thresholds();
         assert!(chart.thresholds().is_empty());
@@ -2460,6 +2836,79 @@ mod tests {
         assert_ne!(c1, c3);
     }
 
+    #[test]
+    fn test_dual_axis_bounds_are_independent() {
+        let mut chart = ChartState::new("comparison");
+        let now = chart.current_time;
+        let cpu = (0..5)
+            .map(|i| DataPoint::new(now - i * 10_000, 10.0 + i as f64 * 10.0))
+            .collect();
+        let bytes = (0..5)
+            .map(|i| DataPoint::new(now - i * 10_000, 1e9 + i as f64 * 1e8))
+            .collect();
+        chart.add_series_with_data("cpu", cpu);
+        chart.add_series_with_data("rx_bytes", bytes);
+
+        // Both on the left: the byte counter dominates the shared scale.
+        assert_eq!(chart.axis_bounds(YAxis::Right), None);
+        let (_, shared_max) = chart.axis_bounds(YAxis::Left).unwrap();
+        assert!(shared_max > 1e9);
+
+        chart.toggle_series_axis("rx_bytes");
+        assert_eq!(chart.series()[1].axis, YAxis::Right);
+
+        // CPU 10..50 padded by 10% of its range; bytes 1.0e9..1.4e9 likewise.
+        let (l_min, l_max) = chart.axis_bounds(YAxis::Left).unwrap();
+        assert!((l_min - 6.0).abs() < 1e-9 && (l_max - 54.0).abs() < 1e-9);
+        let (r_min, r_max) = chart.axis_bounds(YAxis::Right).unwrap();
+        assert!((r_min - 0.96e9).abs() < 1.0 && (r_max - 1.44e9).abs() < 1.0);
+
+        // Hiding the only right-axis series drops the right axis.
+        chart.toggle_series_visibility("rx_bytes");
+        assert_eq!(chart.axis_bounds(YAxis::Right), None);
+    }
+
+    #[test]
+    fn test_stale_region_triggers_past_threshold() {
+        let mut chart = ChartState::new("stale");
+        let now = chart.current_time;
+
+        // Fresh: newest point 10s old, well inside the 60s default.
+        chart.push(DataPoint::new(now - 30_000, 1.0));
+        chart.push(DataPoint::new(now - 10_000, 2.0));
+        assert_eq!(chart.stale_region(), None);
+
+        // Device went quiet: newest point is 2m old.
+        chart.set_data(vec![
+            DataPoint::new(now - 150_000, 1.0),
+            DataPoint::new(now - 120_000, 2.0),
+        ]);
+        assert_eq!(chart.stale_region(), Some((now - 120_000, now)));
+
+        // A looser threshold absorbs the same gap.
+        chart.set_stale_after_ms(180_000);
+        assert_eq!(chart.stale_region(), None);
+    }
+
+    #[test]
+    fn test_stale_region_hidden_when_panned_into_history() {
+        let mut chart = ChartState::new("stale");
+        let now = chart.current_time;
+        chart.set_data(vec![
+            DataPoint::new(now - 150_000, 1.0),
+            DataPoint::new(now - 120_000, 2.0),
+        ]);
+        assert!(chart.stale_region().is_some());
+
+        // Looking back in time: the view no longer ends at "now".
+        chart.pan_left();
+        assert!(chart.is_panned());
+        assert_eq!(chart.stale_region(), None);
+
+        chart.reset_pan();
+        assert!(chart.stale_region().is_some());
+    }
+
     #[test]
     fn test_multi_series_clear() {
         let mut chart = ChartState::new("comparison");
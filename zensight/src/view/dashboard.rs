@@ -1,6 +1,6 @@
 //! Dashboard view showing all monitored devices.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use iced::widget::{
     Column, column, container, grid, mouse_area, row, rule, scrollable, table, text, text_input,
@@ -10,9 +10,14 @@ use iced::{Alignment, Color, Element, Length, Theme};
 use iced_anim::widget::button;
 use iced_anim::{AnimationBuilder, Easing};
 
-use zensight_common::{DeviceStatus, HealthSnapshot, HealthStatus, Protocol, TelemetryPoint};
+use zensight_common::{
+    DeviceStatus, HealthSnapshot, HealthStatus, Protocol, SampleMetadata, TelemetryPoint,
+    TelemetryValue,
+};
 
+use crate::view::chart::DataPoint;
 use crate::view::components::{badge, empty_state};
+use crate::view::formatting::counter_rate;
 
 /// Dashboard view mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -45,6 +50,7 @@ fn current_timestamp() -> i64 {
 use crate::app::{AppTheme, DASHBOARD_SEARCH_ID};
 use crate::message::{DeviceId, Message};
 use crate::view::groups::{GroupTag, GroupsState, device_group_tags, group_filter_bar};
+use crate::view::health::AlertLoad;
 use crate::view::icons::{self, IconSize};
 use crate::view::overview::{OverviewState, overview_section};
 
@@ -59,6 +65,11 @@ pub struct DeviceState {
     pub metric_count: usize,
     /// Most recent metric values (metric name -> full telemetry point).
     pub metrics: HashMap<String, TelemetryPoint>,
+    /// Per-second rate of each counter metric between its last two reports
+    /// (#439), kept by [`DeviceState::update_rate`].
+    pub rates: HashMap<String, f64>,
+    /// Bridge provenance from sample attachments (#444), by metric name.
+    pub provenance: HashMap<String, SampleMetadata>,
     /// Whether this device is healthy (received recent updates).
     /// This is based on local staleness detection.
     pub is_healthy: bool,
@@ -69,6 +80,17 @@ pub struct DeviceState {
     pub consecutive_failures: u32,
     /// Last error message from sensor (if any).
     pub last_error: Option<String>,
+    /// Active alerts on this device by severity, refreshed on the tick for
+    /// the health score (#490).
+    pub alert_load: AlertLoad,
+    /// Metrics reported more than once, i.e. with a cadence that going
+    /// quiet can break (#490). One-shot keys (per-flow netflow metrics,
+    /// short-lived process slots) never join, so their churn isn't staleness.
+    pub recurring: HashSet<String>,
+    /// How many recently active recurring metrics went stale, and how many
+    /// were weighed, as of the last [`update_health`](Self::update_health).
+    pub stale_metrics: usize,
+    pub weighed_metrics: usize,
 }
 
 impl DeviceState {
@@ -79,16 +101,86 @@ impl DeviceState {
             last_update: 0,
             metric_count: 0,
             metrics: HashMap::new(),
+            rates: HashMap::new(),
+            provenance: HashMap::new(),
             is_healthy: true,
             sensor_status: DeviceStatus::Unknown,
             consecutive_failures: 0,
             last_error: None,
+            alert_load: AlertLoad::default(),
+            stale_metrics: 0,
+            weighed_metrics: 0,
+            recurring: HashSet::new(),
+        }
+    }
+
+    /// Refresh `point`'s rate from the report it replaced. Only counters have
+    /// one; a reset or repeated timestamp keeps the last known rate.
+    pub fn update_rate(&mut self, previous: &TelemetryPoint, point: &TelemetryPoint) {
+        if !matches!(point.value, TelemetryValue::Counter(_)) {
+            return;
+        }
+        let history: Vec<DataPoint> = [previous, point]
+            .into_iter()
+            .filter_map(|p| DataPoint::from_telemetry(p.timestamp, &p.value))
+            .collect();
+        if let Some(rate) = counter_rate(&history) {
+            self.rates.insert(point.metric.clone(), rate);
+        }
+    }
+
+    /// Sum of the rates of every metric matching `network/*/<suffix>` — the
+    /// host's aggregate throughput across interfaces (#439).
+    pub fn network_rate(&self, suffix: &str) -> Option<f64> {
+        let mut matching = self
+            .rates
+            .iter()
+            .filter(|(name, _)| name.starts_with("network/") && name.ends_with(suffix))
+            .map(|(_, rate)| *rate)
+            .peekable();
+        matching.peek()?;
+        Some(matching.sum())
+    }
+
+    /// Record that `metric` reported again, giving it a cadence (#490).
+    pub fn note_repeat(&mut self, metric: &str) {
+        if !self.recurring.contains(metric) {
+            self.recurring.insert(metric.to_string());
         }
     }
 
-    /// Update health status based on last update time.
+    /// Update health status based on last update time, and count the
+    /// recurring metrics not refreshed within the threshold (#490). Only
+    /// metrics heard from in the last [`FRESHNESS_WINDOW_FACTOR`] thresholds
+    /// are weighed: one that stopped for good (a process that exited) drops
+    /// out instead of counting as stale forever.
     pub fn update_health(&mut self, now: i64, stale_threshold_ms: i64) {
         self.is_healthy = (now - self.last_update) < stale_threshold_ms;
+        let window = stale_threshold_ms.saturating_mul(FRESHNESS_WINDOW_FACTOR);
+        let (mut stale, mut weighed) = (0, 0);
+        for metric in &self.recurring {
+            let Some(point) = self.metrics.get(metric) else {
+                continue;
+            };
+            let age = now - point.timestamp;
+            if age < window {
+                weighed += 1;
+                if age >= stale_threshold_ms {
+                    stale += 1;
+                }
+            }
+        }
+        self.stale_metrics = stale;
+        self.weighed_metrics = weighed;
+    }
+
+    /// Share of this device's recently active recurring metrics that went
+    /// stale (`0.0..=1.0`).
+    pub fn stale_fraction(&self) -> f64 {
+        if self.weighed_metrics == 0 {
+            return 0.0;
+        }
+        self.stale_metrics as f64 / self.weighed_metrics as f64
     }
 
     /// Update device status from sensor liveness data.
@@ -103,6 +195,23 @@ impl DeviceState {
         self.last_error = last_error;
     }
 
+    /// The failure streak and last error to show while the device is degraded
+    /// or offline (#454); `None` when it's fine or the sensor reported neither.
+    pub fn failure_summary(&self) -> Option<FailureSummary> {
+        let status = self.effective_status();
+        if !matches!(status, DeviceStatus::Degraded | DeviceStatus::Offline) {
+            return None;
+        }
+        if self.consecutive_failures == 0 && self.last_error.is_none() {
+            return None;
+        }
+        Some(FailureSummary {
+            status,
+            consecutive_failures: self.consecutive_failures,
+            last_error: self.last_error.clone(),
+        })
+    }
+
     /// Get the effective status for display.
     ///
     /// Combines local staleness detection with sensor liveness status.
@@ -122,6 +231,46 @@ impl DeviceState {
     }
 }
 
+/// Why a device is degraded or offline, from sensor liveness (#454).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailureSummary {
+    /// The device's effective status (`Degraded` or `Offline`).
+    pub status: DeviceStatus,
+    /// Consecutive failed polls reported by the sensor.
+    pub consecutive_failures: u32,
+    /// The sensor's last error message, if any.
+    pub last_error: Option<String>,
+}
+
+impl FailureSummary {
+    /// Short streak indicator, e.g. `"3 failures in a row"`.
+    pub fn streak_label(&self) -> String {
+        match self.consecutive_failures {
+            0 => "Failing".to_string(),
+            1 => "1 failure".to_string(),
+            n => format!("{n} failures in a row"),
+        }
+    }
+
+    /// Streak plus error text, for tooltips and the card line.
+    pub fn describe(&self) -> String {
+        match &self.last_error {
+            Some(error) => format!("{}: {}", self.streak_label(), error),
+            None => self.streak_label(),
+        }
+    }
+}
+
+/// Danger-coloured failure line (#454), shared by the card and detail header.
+pub fn failure_text<'a, M: 'a>(summary: &FailureSummary, size: f32) -> Element<'a, M> {
+    text(summary.describe())
+        .size(size)
+        .style(|theme: &Theme| text::Style {
+            color: Some(crate::view::theme::colors(theme).danger()),
+        })
+        .into()
+}
+
 /// Default number of devices per page.
 pub const DEFAULT_DEVICES_PER_PAGE: usize = 20;
 
@@ -133,6 +282,12 @@ pub const SEARCH_DEBOUNCE_MS: i64 = 300;
 /// that known-down devices remain visible; only long-gone ones are reaped.
 pub const DEVICE_EVICTION_AGE_MS: i64 = 24 * 60 * 60 * 1000;
 
+/// Default soft cap on tracked devices (#425).
+pub const DEFAULT_MAX_DEVICES: usize = 5_000;
+
+/// Default soft cap on metrics tracked per device (#425).
+pub const DEFAULT_MAX_METRICS_PER_DEVICE: usize = 2_000;
+
 /// Connection state for Zenoh session.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ConnectionState {
@@ -141,10 +296,35 @@ pub enum ConnectionState {
     Disconnected,
     /// Actively connecting to Zenoh.
     Connecting,
+    /// Retrying after a lost or failed connection, or after a settings
+    /// change restarted the session (#427).
+    Reconnecting,
     /// Successfully connected.
     Connected,
 }
 
+/// The three-state connection summary the header shows (#427).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionStatus {
+    Connected,
+    /// A connection attempt is in flight (first or retry).
+    Reconnecting,
+    Disconnected,
+}
+
+impl ConnectionState {
+    /// Collapse to what the header indicator distinguishes.
+    pub fn status(self) -> ConnectionStatus {
+        match self {
+            ConnectionState::Connected => ConnectionStatus::Connected,
+            ConnectionState::Connecting | ConnectionState::Reconnecting => {
+                ConnectionStatus::Reconnecting
+            }
+            ConnectionState::Disconnected => ConnectionStatus::Disconnected,
+        }
+    }
+}
+
 /// Dashboard view state.
 #[derive(Debug)]
 pub struct DashboardState {
@@ -173,6 +353,16 @@ pub struct DashboardState {
     /// Active status filter (None = show all). Driven by the fleet summary
     /// chips so a click on "3 Offline" narrows the grid to the problems (#34).
     pub status_filter: Option<DeviceStatus>,
+    /// Soft cap on tracked devices; past it the least-recently-updated
+    /// device is evicted (#425).
+    pub max_devices: usize,
+    /// Soft cap on metrics per device; past it the least-recently-updated
+    /// metric is evicted.
+    pub max_metrics_per_device: usize,
+    /// Devices evicted by the cap this session, for the warning banner.
+    pub evicted_devices: u64,
+    /// Metrics evicted by the per-device cap this session.
+    pub evicted_metrics: u64,
 }
 
 impl Default for DashboardState {
@@ -190,10 +380,18 @@ impl Default for DashboardState {
             devices_per_page: DEFAULT_DEVICES_PER_PAGE,
             view_mode: DashboardViewMode::default(),
             status_filter: None,
+            max_devices: DEFAULT_MAX_DEVICES,
+            max_metrics_per_device: DEFAULT_MAX_METRICS_PER_DEVICE,
+            evicted_devices: 0,
+            evicted_metrics: 0,
         }
     }
 }
 
+/// How many stale thresholds back a recurring metric still counts toward a
+/// device's freshness (#490).
+pub const FRESHNESS_WINDOW_FACTOR: i64 = 5;
+
 /// Sort rank for device status — problems first (#34). Lower sorts earlier.
 pub(crate) fn status_rank(status: DeviceStatus) -> u8 {
     match status {
@@ -262,6 +460,76 @@ impl DashboardState {
         before - self.devices.len()
     }
 
+    /// Evict least-recently-updated devices until the map is within
+    /// `max_devices` (#425). `keep` (the device that just reported) is never
+    /// the one evicted. Returns the number of devices removed.
+    pub fn enforce_device_cap(&mut self, keep: &DeviceId) -> usize {
+        let mut removed = 0;
+        while self.devices.len() > self.max_devices {
+            let Some(oldest) = self
+                .devices
+                .values()
+                .filter(|d| d.id != *keep)
+                .min_by_key(|d| d.last_update)
+                .map(|d| d.id.clone())
+            else {
+                break;
+            };
+            self.devices.remove(&oldest);
+            removed += 1;
+        }
+        self.evicted_devices += removed as u64;
+        removed
+    }
+
+    /// Evict `id`'s least-recently-updated metrics until it has at most
+    /// `max_metrics_per_device` (#425), sparing `keep`. Returns the number of
+    /// metrics removed.
+    pub fn enforce_metric_cap(&mut self, id: &DeviceId, keep: &str) -> usize {
+        let max = self.max_metrics_per_device;
+        let Some(device) = self.devices.get_mut(id) else {
+            return 0;
+        };
+        let mut removed = 0;
+        while device.metrics.len() > max {
+            let Some(oldest) = device
+                .metrics
+                .values()
+                .filter(|p| p.metric != keep)
+                .min_by_key(|p| p.timestamp)
+                .map(|p| p.metric.clone())
+            else {
+                break;
+            };
+            device.metrics.remove(&oldest);
+            device.rates.remove(&oldest);
+            device.recurring.remove(&oldest);
+            removed += 1;
+        }
+        device.metric_count = device.metrics.len();
+        self.evicted_metrics += removed as u64;
+        removed
+    }
+
+    /// Apply new caps, evicting immediately if the maps are already over.
+    pub fn set_limits(&mut self, max_devices: usize, max_metrics_per_device: usize) {
+        self.max_devices = max_devices;
+        self.max_metrics_per_device = max_metrics_per_device;
+        // The newest device is the one to keep.
+        if let Some(newest) = self
+            .devices
+            .values()
+            .max_by_key(|d| d.last_update)
+            .map(|d| d.id.clone())
+        {
+            self.enforce_device_cap(&newest);
+        }
+        let ids: Vec<DeviceId> = self.devices.keys().cloned().collect();
+        for id in ids {
+            self.enforce_metric_cap(&id, "");
+        }
+    }
+
     /// Set (or clear) the status filter, resetting pagination (#34).
     pub fn set_status_filter(&mut self, status: Option<DeviceStatus>) {
         // Toggle off if the same chip is clicked again.
@@ -410,6 +678,20 @@ pub fn dashboard_view<'a>(
     let filtered = state.filtered_devices();
 
     let header = render_header(state, theme, unacknowledged_alerts);
+
+    // Nothing to show yet: explain why instead of a blank page (#468).
+    if state.devices.is_empty() {
+        return container(
+            column![header, rule::horizontal(1), render_empty_state(state)]
+                .spacing(10)
+                .padding(20),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into();
+    }
+
+    let limits = render_limits_banner(state);
     let fleet_summary = render_fleet_summary(state, unacknowledged_alerts);
     let health_overview = render_health_overview(state);
     let sensor_summary = render_sensor_health_summary(sensor_health);
@@ -420,6 +702,7 @@ pub fn dashboard_view<'a>(
 
     let content = column![
         header,
+        limits,
         fleet_summary,
         health_overview,
         sensor_summary,
@@ -438,6 +721,72 @@ pub fn dashboard_view<'a>(
         .into()
 }
 
+/// The dashboard's empty state (#468): shown while no device has reported.
+/// Says whether Zenoh is reachable, what to check next, and how to try the UI
+/// without sensors.
+fn render_empty_state(state: &DashboardState) -> Element<'_, Message> {
+    let dimmed = |theme: &Theme| text::Style {
+        color: Some(crate::view::theme::colors(theme).text_dimmed()),
+    };
+
+    let (headline, hint) = match state.connection_state {
+        ConnectionState::Connected => (
+            "Connected to Zenoh — waiting for telemetry data...",
+            "No sensor has published under zensight/** yet. Start one (e.g. \
+             zensight-sensor-sysinfo) on a reachable peer, or check the subscription \
+             filters in Settings.",
+        ),
+        ConnectionState::Connecting | ConnectionState::Reconnecting => (
+            "Connecting to Zenoh…",
+            "Devices appear here as soon as the session is up and sensors publish.",
+        ),
+        ConnectionState::Disconnected => (
+            "Not connected to Zenoh",
+            "Zenoh is not reachable. Check the mode and connect endpoints in Settings: \
+             client mode needs a running router, peer mode needs multicast scouting or \
+             explicit endpoints.",
+        ),
+    };
+
+    let mut content = column![
+        text("No devices yet").size(20),
+        text(headline).size(16),
+        text(hint).size(14).style(dimmed),
+    ]
+    .spacing(8)
+    .align_x(Alignment::Center)
+    .max_width(560);
+
+    if let Some(error) = &state.last_error {
+        content = content.push(
+            text(format!("Last error: {error}"))
+                .size(font_caption())
+                .style(|theme: &Theme| text::Style {
+                    color: Some(crate::view::theme::colors(theme).danger()),
+                }),
+        );
+    }
+
+    content = content
+        .push(
+            text("To explore the UI without sensors, restart with `zensight --demo`.")
+                .size(14)
+                .style(dimmed),
+        )
+        .push(
+            button(text("Open Settings").size(14))
+                .on_press(Message::OpenSettings)
+                .style(iced::widget::button::secondary),
+        );
+
+    container(content)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x(Length::Fill)
+        .center_y(Length::Fill)
+        .into()
+}
+
 /// Render the fleet-health summary bar: a click-to-filter rollup of how many
 /// devices are offline / degraded / unknown / online, plus a firing-alert chip
 /// (#34). Answers "what's wrong right now?" at the top of the dashboard.
@@ -587,6 +936,83 @@ fn render_health_overview(state: &DashboardState) -> Element<'_, Message> {
     .into()
 }
 
+/// Banner shown above every page while a dashboard snapshot is open (#418):
+/// what is being viewed, when it was taken, and the way back to live data.
+pub fn snapshot_banner<'a>(path: &str, taken_at: i64) -> Element<'a, Message> {
+    let label = text(format!(
+        "Viewing snapshot {path} taken {} (read-only; live telemetry paused)",
+        crate::view::formatting::format_relative_time(taken_at)
+    ))
+    .size(14);
+    let close = button(text("Back to live").size(14))
+        .on_press(Message::CloseSnapshot)
+        .style(iced::widget::button::secondary);
+
+    container(row![label, close].spacing(20).align_y(Alignment::Center))
+        .padding([8, 16])
+        .width(Length::Fill)
+        .style(|theme: &Theme| {
+            let colors = crate::view::theme::colors(theme);
+            container::Style {
+                background: Some(iced::Background::Color(colors.card_background())),
+                border: iced::Border {
+                    color: colors.warning(),
+                    width: 1.0,
+                    radius: 4.0.into(),
+                },
+                ..Default::default()
+            }
+        })
+        .into()
+}
+
+/// Warning shown once the device or metric caps have evicted anything (#425),
+/// so a truncated view never passes for the whole fabric.
+fn render_limits_banner(state: &DashboardState) -> Element<'_, Message> {
+    if state.evicted_devices == 0 && state.evicted_metrics == 0 {
+        return row![].into();
+    }
+    let mut parts = Vec::new();
+    if state.evicted_devices > 0 {
+        parts.push(format!(
+            "{} device(s) evicted (cap {})",
+            state.evicted_devices, state.max_devices
+        ));
+    }
+    if state.evicted_metrics > 0 {
+        parts.push(format!(
+            "{} metric(s) evicted (cap {} per device)",
+            state.evicted_metrics, state.max_metrics_per_device
+        ));
+    }
+    let label = text(format!(
+        "Tracking limits reached: {}. Least-recently-updated entries are dropped; raise the caps in Settings.",
+        parts.join(", ")
+    ))
+    .size(13);
+
+    container(
+        row![icons::status_warning(IconSize::Small), label]
+            .spacing(8)
+            .align_y(Alignment::Center),
+    )
+    .padding([8, 16])
+    .width(Length::Fill)
+    .style(|theme: &Theme| {
+        let colors = crate::view::theme::colors(theme);
+        container::Style {
+            background: Some(iced::Background::Color(colors.card_background())),
+            border: iced::Border {
+                color: colors.warning(),
+                width: 1.0,
+                radius: 4.0.into(),
+            },
+            ..Default::default()
+        }
+    })
+    .into()
+}
+
 /// Render the header with connection status.
 fn render_header(
     state: &DashboardState,
@@ -635,6 +1061,15 @@ fn render_header(
     .on_press(Message::OpenGlobalSearch)
     .style(iced::widget::button::secondary);
 
+    // Load an exported CSV as an offline device set (#417).
+    let import_button = button(
+        row![icons::export(IconSize::Medium), text("Import CSV").size(14)]
+            .spacing(6)
+            .align_y(Alignment::Center),
+    )
+    .on_press(Message::PickTelemetryCsv)
+    .style(iced::widget::button::secondary);
+
     // Connection status + primary navigation (Alerts/Topology/Settings) now live
     // in the persistent app shell (view/shell.rs), so the dashboard header keeps
     // only its page-local controls.
@@ -642,6 +1077,7 @@ fn render_header(
         title,
         device_count,
         search_button,
+        import_button,
         view_mode_button,
         theme_button
     ]
@@ -788,12 +1224,8 @@ fn render_device_grid<'a>(
         .collect();
 
     if all_devices.is_empty() {
-        let message = if state.devices.is_empty() {
-            "Waiting for telemetry data..."
-        } else {
-            "No devices match the current filters"
-        };
-        return container(text(message).size(16))
+        // An empty fleet gets the full empty state in `dashboard_view` (#468).
+        return container(text("No devices match the current filters").size(16))
             .width(Length::Fill)
             .height(Length::Fill)
             .center_x(Length::Fill)
@@ -989,6 +1421,22 @@ fn render_host_card<'a>(
     }
 
     let mut card_content = column![header, facet_row.wrap()].spacing(6);
+    // Why a facet is degraded/offline (#454), one line per failing facet.
+    for facet in &host.facets {
+        if let Some(summary) = facet.failure_summary() {
+            let line: Element<'a, Message> = if host.facets.len() > 1 {
+                row![
+                    text(facet.id.protocol.display_name()).size(11),
+                    failure_text(&summary, 11.0)
+                ]
+                .spacing(4)
+                .into()
+            } else {
+                failure_text(&summary, 11.0)
+            };
+            card_content = card_content.push(line);
+        }
+    }
     if let Some(sparks) = sparks.filter(|s| !s.is_empty()) {
         let mut spark_col = Column::new().spacing(2);
         for spark in sparks {
@@ -1024,13 +1472,24 @@ fn render_device_table(devices: Vec<&DeviceState>) -> Element<'_, Message> {
                 DeviceStatus::Unknown => "Unknown",
             };
 
-            row![
+            let cell = row![
                 animated_status_indicator(status, 10.0),
                 text(label).size(11)
             ]
             .spacing(6)
-            .align_y(Alignment::Center)
-            .into()
+            .align_y(Alignment::Center);
+            // Degraded/offline rows explain themselves on hover (#454).
+            match device.failure_summary() {
+                Some(summary) => tooltip(
+                    cell,
+                    container(failure_text(&summary, 11.0))
+                        .padding(6)
+                        .style(container::rounded_box),
+                    tooltip::Position::Right,
+                )
+                .into(),
+                None => cell.into(),
+            }
         },
     )
     .width(80);
@@ -1077,7 +1536,7 @@ fn render_device_table(devices: Vec<&DeviceState>) -> Element<'_, Message> {
     let update_column = table::column(
         text("Last Update").size(12),
         |device: &DeviceState| -> Element<'_, Message> {
-            let ago = format_time_ago(device.last_update);
+            let ago = crate::view::formatting::format_relative_time(device.last_update);
             text(ago)
                 .size(11)
                 .style(|t: &Theme| text::Style {
@@ -1120,33 +1579,6 @@ fn render_device_table(devices: Vec<&DeviceState>) -> Element<'_, Message> {
     container(tbl).width(Length::Fill).padding(10).into()
 }
 
-/// Format a timestamp as "X ago" string.
-fn format_time_ago(timestamp: i64) -> String {
-    let now = current_timestamp();
-    let diff_ms = now - timestamp;
-
-    if diff_ms < 0 {
-        return "just now".to_string();
-    }
-
-    let seconds = diff_ms / 1000;
-    let minutes = seconds / 60;
-    let hours = minutes / 60;
-    let days = hours / 24;
-
-    if days > 0 {
-        format!("{}d ago", days)
-    } else if hours > 0 {
-        format!("{}h ago", hours)
-    } else if minutes > 0 {
-        format!("{}m ago", minutes)
-    } else if seconds > 0 {
-        format!("{}s ago", seconds)
-    } else {
-        "just now".to_string()
-    }
-}
-
 /// Render pagination controls with explicit count (for group-filtered lists).
 fn render_pagination_controls_with_count(
     current_page: usize,
@@ -1245,9 +1677,9 @@ fn calculate_visible_pages(current: usize, total: usize) -> Vec<usize> {
 /// Get the color for a device status (shared status palette, D2).
 fn status_color(status: DeviceStatus) -> Color {
     match status {
-        DeviceStatus::Online => crate::view::theme::STATUS_ONLINE,
-        DeviceStatus::Degraded => crate::view::theme::STATUS_DEGRADED,
-        DeviceStatus::Offline => crate::view::theme::STATUS_OFFLINE,
+        DeviceStatus::Online => crate::view::theme::palette().online,
+        DeviceStatus::Degraded => crate::view::theme::palette().degraded,
+        DeviceStatus::Offline => crate::view::theme::palette().offline,
         DeviceStatus::Unknown => crate::view::theme::STATUS_UNKNOWN,
     }
 }
@@ -1292,6 +1724,74 @@ mod tests {
         d
     }
 
+    #[test]
+    fn network_rate_sums_interface_counter_rates() {
+        let mut device = device_with_status("host", DeviceStatus::Online);
+        let counter = |metric: &str, ts: i64, v: u64| {
+            let mut p = TelemetryPoint::new(
+                "host",
+                Protocol::Sysinfo,
+                metric,
+                TelemetryValue::Counter(v),
+            );
+            p.timestamp = ts;
+            p
+        };
+        for (metric, per_sec) in [
+            ("network/eth0/rx_bytes", 100),
+            ("network/eth1/rx_bytes", 50),
+        ] {
+            device.update_rate(&counter(metric, 0, 0), &counter(metric, 2000, 2 * per_sec));
+        }
+        // Gauges never get a rate.
+        let gauge = |ts| {
+            let mut p = TelemetryPoint::new(
+                "host",
+                Protocol::Sysinfo,
+                "cpu/usage",
+                TelemetryValue::Gauge(1.0),
+            );
+            p.timestamp = ts;
+            p
+        };
+        device.update_rate(&gauge(0), &gauge(1000));
+
+        assert_eq!(device.network_rate("/rx_bytes"), Some(150.0));
+        assert_eq!(device.network_rate("/tx_bytes"), None);
+        assert!(!device.rates.contains_key("cpu/usage"));
+    }
+
+    #[test]
+    fn device_cap_evicts_least_recently_updated() {
+        let mut state = create_test_state_with_devices(3);
+        for (i, device) in state.devices.values_mut().enumerate() {
+            device.last_update = 1_000 * (i as i64 + 1);
+        }
+        let stalest = state
+            .devices
+            .values()
+            .min_by_key(|d| d.last_update)
+            .unwrap()
+            .id
+            .clone();
+        state.max_devices = 3;
+
+        let newcomer = DeviceId::new(Protocol::Snmp, "newcomer");
+        state
+            .devices
+            .insert(newcomer.clone(), DeviceState::new(newcomer.clone()));
+        // The newcomer hasn't set `last_update` yet, but it's never evicted.
+        assert_eq!(state.enforce_device_cap(&newcomer), 1);
+        assert_eq!(state.devices.len(), 3);
+        assert!(!state.devices.contains_key(&stalest));
+        assert!(state.devices.contains_key(&newcomer));
+        assert_eq!(state.evicted_devices, 1);
+
+        // Within the cap nothing happens.
+        assert_eq!(state.enforce_device_cap(&newcomer), 0);
+        assert_eq!(state.evicted_devices, 1);
+    }
+
     #[test]
     fn test_status_counts_and_problem_first_sort() {
         let mut state = DashboardState::default();
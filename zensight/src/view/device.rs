@@ -1,20 +1,22 @@
 //! Device detail view showing all metrics for a selected device.
 
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 
 use iced::widget::{
-    Row, column, container, row, rule, scrollable, table, text, text_input, tooltip,
+    Row, column, container, pick_list, row, rule, scrollable, table, text, text_input, tooltip,
 };
 use iced::{Alignment, Element, Length, Theme};
 use iced_anim::widget::button;
 
-use zensight_common::{DeviceStatus, Protocol, TelemetryPoint, TelemetryValue};
+use zensight_common::{DeviceStatus, Protocol, SampleMetadata, TelemetryPoint, TelemetryValue};
 
 use crate::app::DEVICE_SEARCH_ID;
 use crate::message::{DeviceId, Message};
-use crate::view::chart::{ChartState, DataPoint, TimeWindow, chart_view};
+use crate::view::chart::{
+    ChartState, ChartStats, DataPoint, Interpolation, TimeWindow, chart_view,
+};
 use crate::view::components::empty_state;
-use crate::view::formatting::{format_timestamp, format_value};
+use crate::view::formatting::{counter_rate, format_relative_time, format_value, rate_series};
 use crate::view::icons::{self, IconSize};
 use crate::view::specialized;
 
@@ -24,18 +26,240 @@ const SEARCH_DEBOUNCE_MS: i64 = 300;
 /// Threshold for marking individual metrics as stale (60 seconds in ms).
 const METRIC_STALE_THRESHOLD_MS: i64 = 60_000;
 
+/// How many recent samples the "recently changed" highlight looks back (#443).
+const RECENT_CHANGE_SAMPLES: usize = 5;
+
+/// Relative change over those samples that flags a metric (0.2 = 20%).
+const RECENT_CHANGE_THRESHOLD: f64 = 0.2;
+
+/// Distinct label sets kept per metric for family rows (#469); further label
+/// sets are ignored so a high-cardinality label can't grow the view unbounded.
+const MAX_LABEL_VARIANTS: usize = 32;
+
+/// How a metric-family row sums up its labeled variants (#469).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FamilyAggregate {
+    /// The most recently updated variant.
+    #[default]
+    Latest,
+    Sum,
+    Avg,
+    Min,
+    Max,
+}
+
+impl FamilyAggregate {
+    /// Every aggregate, in picker order.
+    pub const ALL: [FamilyAggregate; 5] = [
+        FamilyAggregate::Latest,
+        FamilyAggregate::Sum,
+        FamilyAggregate::Avg,
+        FamilyAggregate::Min,
+        FamilyAggregate::Max,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            FamilyAggregate::Latest => "latest",
+            FamilyAggregate::Sum => "sum",
+            FamilyAggregate::Avg => "avg",
+            FamilyAggregate::Min => "min",
+            FamilyAggregate::Max => "max",
+        }
+    }
+
+    /// Aggregate the numeric variants; `None` when none is numeric.
+    pub fn apply(self, variants: &[&TelemetryPoint]) -> Option<f64> {
+        fn numeric(value: &TelemetryValue) -> Option<f64> {
+            match value {
+                TelemetryValue::Counter(v) => Some(*v as f64),
+                TelemetryValue::Gauge(v) => Some(*v),
+                _ => None,
+            }
+        }
+        let values: Vec<f64> = variants.iter().filter_map(|p| numeric(&p.value)).collect();
+        if values.is_empty() {
+            return None;
+        }
+        Some(match self {
+            FamilyAggregate::Latest => variants
+                .iter()
+                .filter_map(|p| Some((p.timestamp, numeric(&p.value)?)))
+                .max_by_key(|(ts, _)| *ts)
+                .map(|(_, v)| v)?,
+            FamilyAggregate::Sum => values.iter().sum(),
+            FamilyAggregate::Avg => values.iter().sum::<f64>() / values.len() as f64,
+            FamilyAggregate::Min => values.iter().copied().fold(f64::INFINITY, f64::min),
+            FamilyAggregate::Max => values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        })
+    }
+}
+
+impl std::fmt::Display for FamilyAggregate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// Points sharing a metric name but differing by labels (#469), e.g.
+/// `system/load` with `period=1m|5m|15m`.
+#[derive(Debug, Clone)]
+pub struct MetricFamily<'a> {
+    /// The shared metric name.
+    pub name: &'a str,
+    /// The newest point per distinct label set, ordered by label signature.
+    pub variants: Vec<&'a TelemetryPoint>,
+}
+
+/// A label set as `k=v, k=v`, sorted by key.
+pub fn label_signature(labels: &HashMap<String, String>) -> String {
+    let sorted: BTreeMap<_, _> = labels.iter().collect();
+    sorted
+        .into_iter()
+        .map(|(k, v)| format!("{k}={v}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Group `points` into one family per metric name (#469), keeping the newest
+/// point for each distinct label set. Families come out sorted by name.
+pub fn group_metric_families<'a>(
+    points: impl IntoIterator<Item = &'a TelemetryPoint>,
+) -> Vec<MetricFamily<'a>> {
+    let mut families: BTreeMap<&str, BTreeMap<String, &TelemetryPoint>> = BTreeMap::new();
+    for point in points {
+        let variants = families.entry(point.metric.as_str()).or_default();
+        let newest = variants
+            .entry(label_signature(&point.labels))
+            .or_insert(point);
+        if point.timestamp > newest.timestamp {
+            *newest = point;
+        }
+    }
+    families
+        .into_iter()
+        .map(|(name, variants)| MetricFamily {
+            name,
+            variants: variants.into_values().collect(),
+        })
+        .collect()
+}
+
+/// One `/`-separated segment in the metric explorer tree (#471).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetricTreeNode {
+    /// This node's segment, e.g. `eth0`.
+    pub segment: String,
+    /// The full path up to and including this segment, e.g. `network/eth0`.
+    pub path: String,
+    /// The metric keys at this path, as published. Usually just `path`, but
+    /// empty segments are skipped, so `a//b` lands on `a/b` and keeps its own
+    /// key here. Empty for a pure branch.
+    pub metrics: Vec<String>,
+    /// Child segments, sorted by name.
+    pub children: Vec<MetricTreeNode>,
+}
+
+impl MetricTreeNode {
+    /// Whether some metric sits at this path (it may also have children).
+    pub fn is_metric(&self) -> bool {
+        !self.metrics.is_empty()
+    }
+
+    /// Number of metrics at or below this node.
+    pub fn metric_count(&self) -> usize {
+        self.metrics.len()
+            + self
+                .children
+                .iter()
+                .map(MetricTreeNode::metric_count)
+                .sum::<usize>()
+    }
+}
+
+/// Nest metric names by their `/`-separated segments (#471), so
+/// `network/eth0/rx_bytes` and `network/eth0/tx_bytes` share the
+/// `network` → `network/eth0` branch. Siblings come out sorted by segment.
+pub fn build_metric_tree<'a>(names: impl IntoIterator<Item = &'a str>) -> Vec<MetricTreeNode> {
+    #[derive(Default)]
+    struct Branch {
+        metrics: Vec<String>,
+        children: BTreeMap<String, Branch>,
+    }
+
+    fn into_nodes(children: BTreeMap<String, Branch>, parent: &str) -> Vec<MetricTreeNode> {
+        children
+            .into_iter()
+            .map(|(segment, branch)| {
+                let path = if parent.is_empty() {
+                    segment.clone()
+                } else {
+                    format!("{parent}/{segment}")
+                };
+                MetricTreeNode {
+                    children: into_nodes(branch.children, &path),
+                    segment,
+                    path,
+                    metrics: branch.metrics,
+                }
+            })
+            .collect()
+    }
+
+    let mut root = Branch::default();
+    for name in names {
+        let mut node = &mut root;
+        for segment in name.split('/').filter(|s| !s.is_empty()) {
+            node = node.children.entry(segment.to_string()).or_default();
+        }
+        node.metrics.push(name.to_string());
+    }
+    into_nodes(root.children, "")
+}
+
+/// A metrics-table row's place in the metric explorer tree (#471).
+#[derive(Debug, Clone)]
+enum TreeCell {
+    /// Flat table mode.
+    None,
+    /// A collapsible path prefix holding `metrics` metrics.
+    Branch {
+        segment: String,
+        depth: usize,
+        expanded: bool,
+        metrics: usize,
+    },
+    /// A metric, shown by its last segment under its branch.
+    Leaf { segment: String, depth: usize },
+}
+
+/// A metrics-table row's place in a labeled family (#469).
+#[derive(Debug, Clone)]
+enum FamilyCell {
+    /// A plain metric.
+    None,
+    /// The collapsed/expanded head of a family with `variants` label sets.
+    Head { variants: usize, expanded: bool },
+    /// One labeled variant under an expanded head.
+    Variant { labels: String },
+}
+
 /// A row in the metrics table, containing pre-formatted data for display.
 /// This struct is Clone so it can be used with the table widget.
 #[derive(Debug, Clone)]
 struct MetricTableRow {
     /// Metric name.
     name: String,
+    /// What the metric means, from the built-in registry (#485).
+    description: Option<&'static str>,
     /// Formatted value for display.
     value: String,
     /// Full value (if truncated).
     full_value: Option<String>,
     /// Type name (Counter, Gauge, Text, etc.).
     type_name: String,
+    /// Bridge that produced the metric, when it attaches metadata (#444).
+    provenance: Option<String>,
     /// Formatted timestamp.
     timestamp: String,
     /// Whether this metric is chartable (numeric).
@@ -46,12 +270,41 @@ struct MetricTableRow {
     is_favorite: bool,
     /// Trend indicator: "up", "down", "stable", or empty.
     trend: String,
+    /// Per-second rate for counters (#439), or empty.
+    rate: String,
+    /// Relative change over the last few samples when it crossed the
+    /// highlight threshold (#443).
+    recent_change: Option<f64>,
+    /// Min/max/avg strip over the chart window, when enabled (#441).
+    stats: Option<String>,
     /// Whether this metric is stale (not updated recently).
     is_stale: bool,
     /// The device this metric belongs to (for the promote-to-alert action, #50).
     device_id: DeviceId,
     /// Current numeric value, if the metric is numeric (#50).
     numeric_value: Option<f64>,
+    /// Family head or labeled variant, for metrics with several label sets (#469).
+    family: FamilyCell,
+    /// Branch or leaf when the metric explorer tree is shown (#471).
+    tree: TreeCell,
+}
+
+/// Metrics a protocol's device detail charts when it opens (#421). A name
+/// matches a metric equal to it or ending in `/<name>`, so `ifInOctets` picks
+/// up `if/1/ifInOctets`; the first matching point to arrive is charted.
+pub fn default_chart_metrics(protocol: Protocol) -> &'static [&'static str] {
+    match protocol {
+        Protocol::Snmp => &["ifInOctets", "ifOutOctets"],
+        Protocol::Sysinfo => &["cpu/usage", "memory/usage_percent"],
+        _ => &[],
+    }
+}
+
+/// Whether `metric` is the one a default-layout `name` asks for.
+fn matches_default_metric(metric: &str, name: &str) -> bool {
+    metric
+        .strip_suffix(name)
+        .is_some_and(|prefix| prefix.is_empty() || prefix.ends_with('/'))
 }
 
 /// Get the current timestamp in milliseconds.
@@ -81,6 +334,10 @@ pub struct DeviceDetailState {
     pub selected_metric: Option<String>,
     /// Chart state for the selected metric.
     pub chart: ChartState,
+    /// Window every freshly built chart starts with, driven by the app-wide
+    /// time-range picker (#392). The chart's own window buttons still override
+    /// it per chart.
+    pub default_time_window: TimeWindow,
     /// Search filter for metrics (applied after debounce).
     pub metric_filter: String,
     /// Pending search filter (user input).
@@ -98,6 +355,12 @@ pub struct DeviceDetailState {
     pub sysinfo_detail: crate::view::specialized::sysinfo_detail::SysinfoDetailState,
     /// Whether the chart panel is expanded to a taller height (#36).
     pub chart_expanded: bool,
+    /// Whether each metrics-table row shows a min/max/avg strip over the
+    /// chart's active window (#441). Off by default to keep the table dense.
+    pub show_metric_stats: bool,
+    /// Chart counters as their per-second rate instead of the raw,
+    /// ever-growing value (#442). Display only: history stays raw.
+    pub counters_as_rate: bool,
     /// Text-input buffer for the custom relative window in minutes (#36).
     pub chart_custom_input: String,
     /// Text-input buffers for the absolute `from`/`to` range picker (#36),
@@ -111,6 +374,28 @@ pub struct DeviceDetailState {
     /// Active tab of the tabbed specialized view (#243), remembered per device.
     /// Defaults to `Overview`.
     pub specialized_tab: crate::view::specialized::SpecializedTab,
+    /// Protocol default-layout metrics (#421) not yet charted. Each is added
+    /// to the chart as its first point arrives; any chart change by the user
+    /// clears the list, so the defaults never fight a manual selection.
+    pub default_metrics: Vec<&'static str>,
+    /// Bridge provenance from sample attachments (#444), by metric name.
+    /// Empty unless the bridge runs with `attach_metadata` enabled.
+    pub provenance: HashMap<String, SampleMetadata>,
+    /// Failure streak and last error while the device is degraded or offline
+    /// (#454), mirrored from the dashboard's liveness-updated `DeviceState`.
+    pub failure: Option<crate::view::dashboard::FailureSummary>,
+    /// Newest point per label set for labeled metrics (#469), keyed by metric
+    /// then [`label_signature`]. `metrics` keeps only the latest of them.
+    pub label_variants: HashMap<String, HashMap<String, TelemetryPoint>>,
+    /// Metric families expanded to show their labeled variants (#469).
+    pub expanded_families: HashSet<String>,
+    /// How family rows sum up their variants (#469).
+    pub family_aggregate: FamilyAggregate,
+    /// Show the metrics as a tree of key segments instead of a flat table
+    /// (#471).
+    pub metric_tree_view: bool,
+    /// Tree branches (by path) the user has expanded (#471).
+    pub expanded_tree_nodes: HashSet<String>,
 }
 
 impl DeviceDetailState {
@@ -129,6 +414,7 @@ impl DeviceDetailState {
             max_history,
             selected_metric: None,
             chart: ChartState::new(format!("{}", device_id)),
+            default_time_window: TimeWindow::default(),
             metric_filter: String::new(),
             pending_filter: String::new(),
             pending_filter_time: 0,
@@ -137,11 +423,21 @@ impl DeviceDetailState {
             systemd_detail: Default::default(),
             sysinfo_detail: Default::default(),
             chart_expanded: false,
+            show_metric_stats: false,
+            counters_as_rate: false,
             chart_custom_input: String::new(),
             chart_from_input: String::new(),
             chart_to_input: String::new(),
             favorites: HashSet::new(),
             specialized_tab: Default::default(),
+            default_metrics: default_chart_metrics(device_id.protocol).to_vec(),
+            provenance: HashMap::new(),
+            failure: None,
+            label_variants: HashMap::new(),
+            expanded_families: HashSet::new(),
+            family_aggregate: FamilyAggregate::default(),
+            metric_tree_view: false,
+            expanded_tree_nodes: HashSet::new(),
         }
     }
 
@@ -171,6 +467,126 @@ impl DeviceDetailState {
         self.chart_expanded = !self.chart_expanded;
     }
 
+    /// Toggle the per-metric min/max/avg strip in the metrics table (#441).
+    pub fn toggle_metric_stats(&mut self) {
+        self.show_metric_stats = !self.show_metric_stats;
+    }
+
+    /// Expand or collapse a metric family's labeled variants (#469).
+    pub fn toggle_family(&mut self, metric: &str) {
+        if !self.expanded_families.remove(metric) {
+            self.expanded_families.insert(metric.to_string());
+        }
+    }
+
+    /// Switch the metrics list between the flat table and the tree (#471).
+    pub fn toggle_metric_tree_view(&mut self) {
+        self.metric_tree_view = !self.metric_tree_view;
+    }
+
+    /// Expand or collapse a metric-tree branch (#471).
+    pub fn toggle_tree_node(&mut self, path: &str) {
+        if !self.expanded_tree_nodes.remove(path) {
+            self.expanded_tree_nodes.insert(path.to_string());
+        }
+    }
+
+    /// Whether a tree branch shows its children. Every branch opens while a
+    /// search filter is active, so matches aren't hidden in collapsed levels.
+    pub fn is_tree_node_expanded(&self, path: &str) -> bool {
+        !self.metric_filter.is_empty() || self.expanded_tree_nodes.contains(path)
+    }
+
+    /// `metric`'s labeled variants when it has more than one label set (#469).
+    pub fn metric_family(&self, metric: &str) -> Option<MetricFamily<'_>> {
+        let variants = self.label_variants.get(metric)?;
+        if variants.len() < 2 {
+            return None;
+        }
+        group_metric_families(variants.values()).into_iter().next()
+    }
+
+    /// Whether any metric on this device has several label sets (#469).
+    pub fn has_metric_families(&self) -> bool {
+        self.label_variants.values().any(|v| v.len() > 1)
+    }
+
+    /// Min/max/avg of `metric` over the chart's active window (#441), from the
+    /// same points the chart would plot. `None` without data in the window.
+    pub fn metric_stats(&self, metric: &str) -> Option<ChartStats> {
+        let stats = self.chart.window_stats(&self.charted_points(metric));
+        (stats.count > 0).then_some(stats)
+    }
+
+    /// Switch counters between raw and rate display (#442), redrawing the
+    /// open chart in the new unit without touching zoom or window.
+    pub fn toggle_counter_rate(&mut self) {
+        self.counters_as_rate = !self.counters_as_rate;
+        if let Some(metric) = self.selected_metric.clone() {
+            let (title, points) = (self.chart_title(&metric), self.charted_points(&metric));
+            self.chart.set_title(title);
+            self.chart.set_data(points);
+        }
+        let series: Vec<String> = self
+            .chart
+            .series_names()
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        for metric in series {
+            let points = self.live_points(&metric);
+            self.chart.add_series_with_data(&metric, points);
+        }
+    }
+
+    /// Whether `metric` is a counter currently charted as a rate (#442).
+    fn shows_rate(&self, metric: &str) -> bool {
+        self.counters_as_rate
+            && matches!(
+                self.metrics.get(metric).map(|p| &p.value),
+                Some(TelemetryValue::Counter(_))
+            )
+    }
+
+    /// `metric`'s chart title, with the per-second unit in rate mode (#442).
+    fn chart_title(&self, metric: &str) -> String {
+        if self.shows_rate(metric) {
+            format!("{metric} (/s)")
+        } else {
+            metric.to_string()
+        }
+    }
+
+    /// Convert raw `points` of `metric` into what the chart shows: rates for
+    /// counters in rate mode (#442), the raw values otherwise.
+    fn for_display(&self, metric: &str, points: Vec<DataPoint>) -> Vec<DataPoint> {
+        if self.shows_rate(metric) {
+            rate_series(&points)
+        } else {
+            points
+        }
+    }
+
+    /// Points the single-metric chart plots for `metric` (seeded + live).
+    fn charted_points(&self, metric: &str) -> Vec<DataPoint> {
+        self.for_display(metric, self.chart_points_for(metric))
+    }
+
+    /// Points a comparison series plots for `metric` (live history only).
+    fn live_points(&self, metric: &str) -> Vec<DataPoint> {
+        let points = self
+            .history
+            .get(metric)
+            .map(|history| {
+                history
+                    .iter()
+                    .filter_map(|p| DataPoint::from_telemetry(p.timestamp, &p.value))
+                    .collect()
+            })
+            .unwrap_or_default();
+        self.for_display(metric, points)
+    }
+
     /// Apply a custom relative window from the text input (#36). Empty input or
     /// an unparseable value clears the custom window.
     pub fn set_chart_custom_minutes(&mut self, input: String) {
@@ -224,6 +640,21 @@ impl DeviceDetailState {
 
         // Update current value (one clone — the snapshot map needs its own copy).
         self.metrics.insert(metric_name.clone(), point.clone());
+        self.record_label_variant(&point);
+        self.chart_default_metric(&metric_name);
+
+        // In rate mode (#442) a counter charts its rate since the previous
+        // report; the raw point still goes to history below.
+        let data_point = match data_point {
+            Some(dp) if self.shows_rate(&metric_name) => self
+                .history
+                .get(&metric_name)
+                .and_then(|h| h.back())
+                .and_then(|prev| DataPoint::from_telemetry(prev.timestamp, &prev.value))
+                .and_then(|prev| counter_rate(&[prev, dp.clone()]))
+                .map(|rate| DataPoint::new(dp.timestamp, rate)),
+            other => other,
+        };
 
         // Update the chart while we still hold `metric_name`.
         if let Some(dp) = data_point {
@@ -241,24 +672,61 @@ impl DeviceDetailState {
         let history = self.history.entry(metric_name).or_default();
         history.push_back(point);
 
-        // Trim history if needed.
-        if history.len() > self.max_history {
+        // Trim to the cap (#419); a loop, so a buffer is never left over it.
+        while history.len() > self.max_history {
             history.pop_front();
         }
     }
 
+    /// Keep the newest point per label set of a labeled metric (#469).
+    fn record_label_variant(&mut self, point: &TelemetryPoint) {
+        if point.labels.is_empty() {
+            return;
+        }
+        let variants = self.label_variants.entry(point.metric.clone()).or_default();
+        let signature = label_signature(&point.labels);
+        if variants.len() < MAX_LABEL_VARIANTS || variants.contains_key(&signature) {
+            variants.insert(signature, point.clone());
+        }
+    }
+
+    /// Chart `metric` if it's a pending default-layout metric (#421). Runs
+    /// before the point is pushed, so the series starts with it.
+    fn chart_default_metric(&mut self, metric: &str) {
+        let Some(pos) = self
+            .default_metrics
+            .iter()
+            .position(|name| matches_default_metric(metric, name))
+        else {
+            return;
+        };
+        if !self.is_metric_chartable(metric) {
+            return;
+        }
+        // `add_metric_to_chart` drops pending defaults as a user action would,
+        // so hold the rest aside.
+        let mut pending = std::mem::take(&mut self.default_metrics);
+        pending.remove(pos);
+        self.add_metric_to_chart(metric.to_string());
+        self.default_metrics = pending;
+    }
+
     /// Select a metric for charting (single-metric mode).
     pub fn select_metric(&mut self, metric_name: String) {
+        self.default_metrics.clear();
         // If already in multi-series mode with this metric, just switch to single mode
         if self.chart.is_multi_series() {
             self.chart.clear_series();
         }
 
         self.selected_metric = Some(metric_name.clone());
-        self.chart = ChartState::new(&metric_name);
+        self.chart = ChartState::new(self.chart_title(&metric_name));
+        self.chart.set_time_window(self.default_time_window);
+        self.chart
+            .set_interpolation(self.default_interpolation(&metric_name));
 
         // Populate chart with stored history (pre-restart) + live history.
-        let data_points = self.chart_points_for(&metric_name);
+        let data_points = self.charted_points(&metric_name);
         if !data_points.is_empty() {
             self.chart.set_data(data_points);
         }
@@ -292,6 +760,24 @@ impl DeviceDetailState {
         points
     }
 
+    /// Per-second rate of a counter-like `metric` (counter or gauge) from its
+    /// last two live reports, via the shared [`counter_rate`] (#439).
+    pub fn metric_rate(&self, metric: &str) -> Option<f64> {
+        let history = self.history.get(metric)?;
+        let recent: Vec<DataPoint> = history
+            .iter()
+            .skip(history.len().saturating_sub(2))
+            .filter(|p| {
+                matches!(
+                    p.value,
+                    TelemetryValue::Counter(_) | TelemetryValue::Gauge(_)
+                )
+            })
+            .filter_map(|p| DataPoint::from_telemetry(p.timestamp, &p.value))
+            .collect();
+        counter_rate(&recent)
+    }
+
     /// The last `max` numeric history values for `metric` (seeded + live,
     /// oldest-first), for inline sparklines in specialized views (#44). Empty
     /// when the metric has no numeric history.
@@ -311,20 +797,41 @@ impl DeviceDetailState {
             self.seeded_history.insert(metric.clone(), samples);
             // If this metric's chart is already open, refresh it with the seed.
             if self.selected_metric.as_deref() == Some(metric.as_str()) {
-                let points = self.chart_points_for(&metric);
+                let points = self.charted_points(&metric);
                 self.chart.set_data(points);
             }
         }
     }
 
+    /// How `metric` is charted until the user picks otherwise: booleans as a
+    /// 0/1 step plot (#415), everything else linear.
+    fn default_interpolation(&self, metric: &str) -> Interpolation {
+        match self.metrics.get(metric).map(|p| &p.value) {
+            Some(TelemetryValue::Boolean(_)) => Interpolation::Step,
+            _ => Interpolation::Linear,
+        }
+    }
+
+    /// Switch the single-metric chart between linear and step (#416).
+    pub fn toggle_chart_interpolation(&mut self) {
+        self.chart.toggle_interpolation();
+    }
+
+    /// Switch a comparison series between linear and step (#416).
+    pub fn toggle_metric_interpolation(&mut self, metric_name: &str) {
+        self.chart.toggle_series_interpolation(metric_name);
+    }
+
     /// Clear the chart selection.
     pub fn clear_chart_selection(&mut self) {
+        self.default_metrics.clear();
         self.selected_metric = None;
         self.chart.clear_series();
     }
 
     /// Add a metric to the comparison chart (multi-series mode).
     pub fn add_metric_to_chart(&mut self, metric_name: String) {
+        self.default_metrics.clear();
         // Check if metric is chartable
         if !self.is_metric_chartable(&metric_name) {
             return;
@@ -334,39 +841,41 @@ impl DeviceDetailState {
         // set a generic title
         if !self.chart.is_multi_series() && self.selected_metric.is_none() {
             self.chart = ChartState::new("Metric Comparison");
+            self.chart.set_time_window(self.default_time_window);
         }
 
         // Clear single-series data when switching to multi-series
         if self.selected_metric.is_some() && !self.chart.is_multi_series() {
             // Convert current single metric to a series
             if let Some(ref current_metric) = self.selected_metric
-                && let Some(history) = self.history.get(current_metric)
+                && self.history.contains_key(current_metric)
             {
-                let data_points: Vec<DataPoint> = history
-                    .iter()
-                    .filter_map(|p| DataPoint::from_telemetry(p.timestamp, &p.value))
-                    .collect();
+                let data_points = self.live_points(current_metric);
                 self.chart
                     .add_series_with_data(current_metric.clone(), data_points);
+                self.chart.set_series_interpolation(
+                    current_metric,
+                    self.default_interpolation(current_metric),
+                );
             }
             self.selected_metric = None;
             self.chart.set_data(Vec::new()); // Clear single-series data
         }
 
         // Add new series with historical data
-        if let Some(history) = self.history.get(&metric_name) {
-            let data_points: Vec<DataPoint> = history
-                .iter()
-                .filter_map(|p| DataPoint::from_telemetry(p.timestamp, &p.value))
-                .collect();
+        if self.history.contains_key(&metric_name) {
+            let data_points = self.live_points(&metric_name);
             self.chart.add_series_with_data(&metric_name, data_points);
         } else {
             self.chart.add_series(&metric_name);
         }
+        self.chart
+            .set_series_interpolation(&metric_name, self.default_interpolation(&metric_name));
     }
 
     /// Remove a metric from the comparison chart.
     pub fn remove_metric_from_chart(&mut self, metric_name: &str) {
+        self.default_metrics.clear();
         self.chart.remove_series(metric_name);
 
         // If only one series left, could switch back to single mode (optional)
@@ -378,6 +887,11 @@ impl DeviceDetailState {
         self.chart.toggle_series_visibility(metric_name);
     }
 
+    /// Move a comparison metric to the other Y-axis (#394).
+    pub fn toggle_metric_axis(&mut self, metric_name: &str) {
+        self.chart.toggle_series_axis(metric_name);
+    }
+
     /// Check if a metric is currently in the chart (single or multi-series).
     pub fn is_metric_in_chart(&self, metric_name: &str) -> bool {
         if self.chart.is_multi_series() {
@@ -402,6 +916,15 @@ impl DeviceDetailState {
         self.chart.set_time_window(window);
     }
 
+    /// Apply the app-wide time window (#392): it becomes the default for charts
+    /// opened later and replaces the current chart's window (including any
+    /// custom or absolute range).
+    pub fn set_default_time_window(&mut self, window: TimeWindow) {
+        self.default_time_window = window;
+        self.chart_custom_input.clear();
+        self.chart.set_time_window(window);
+    }
+
     /// Zoom in on the chart.
     pub fn zoom_in(&mut self) {
         self.chart.zoom_in();
@@ -616,7 +1139,7 @@ impl DeviceDetailState {
 }
 
 /// Escape a string for CSV (handle commas and quotes).
-fn escape_csv(s: &str) -> String {
+pub(crate) fn escape_csv(s: &str) -> String {
     if s.contains(',') || s.contains('"') || s.contains('\n') {
         format!("\"{}\"", s.replace('"', "\"\""))
     } else {
@@ -632,6 +1155,18 @@ fn format_value_for_export(value: &TelemetryValue) -> String {
         TelemetryValue::Text(s) => s.clone(),
         TelemetryValue::Boolean(b) => b.to_string(),
         TelemetryValue::Binary(data) => format!("<{} bytes>", data.len()),
+        // Full payload as hex so the export is lossless.
+        TelemetryValue::Bytes(data) => data.iter().map(|b| format!("{b:02x}")).collect(),
+        TelemetryValue::Histogram {
+            buckets,
+            sum,
+            count,
+        } => {
+            // Full distribution for export: `le:count` pairs, then +Inf.
+            let mut out: Vec<String> = buckets.iter().map(|(le, n)| format!("{le}:{n}")).collect();
+            out.push(format!("+Inf:{count}"));
+            format!("sum={sum} {}", out.join(" "))
+        }
     }
 }
 
@@ -657,9 +1192,9 @@ pub struct FacetTab {
 /// status palette (D2).
 fn facet_status_color(status: DeviceStatus) -> iced::Color {
     match status {
-        DeviceStatus::Online => crate::view::theme::STATUS_ONLINE,
-        DeviceStatus::Degraded => crate::view::theme::STATUS_DEGRADED,
-        DeviceStatus::Offline => crate::view::theme::STATUS_OFFLINE,
+        DeviceStatus::Online => crate::view::theme::palette().online,
+        DeviceStatus::Degraded => crate::view::theme::palette().degraded,
+        DeviceStatus::Offline => crate::view::theme::palette().offline,
         DeviceStatus::Unknown => crate::view::theme::STATUS_UNKNOWN,
     }
 }
@@ -805,6 +1340,55 @@ pub fn generic_device_view(state: &DeviceDetailState) -> Element<'_, Message> {
         .into()
 }
 
+/// Render the secondary split pane (#391): a compact header with a close
+/// button over the generic chart + metrics list. Interactions inside the pane
+/// are wrapped in [`Message::SplitPane`] so they drive this pane's own state
+/// rather than the primary device's.
+pub fn split_pane_view(state: &DeviceDetailState) -> Element<'_, Message> {
+    let close_button = button(
+        row![icons::close(IconSize::Small), text("Close split").size(12)]
+            .spacing(4)
+            .align_y(Alignment::Center),
+    )
+    .on_press(Message::CloseSplit)
+    .style(iced::widget::button::secondary);
+
+    let header = row![
+        icons::protocol_icon(state.device_id.protocol, IconSize::Large),
+        text(&state.device_id.source).size(20),
+        text(format!("{} metrics", state.metrics.len())).size(14),
+        container(text("")).width(Length::Fill),
+        close_button,
+    ]
+    .spacing(12)
+    .align_y(Alignment::Center);
+
+    let chart_section = if let Some(ref metric_name) = state.selected_metric {
+        render_chart_section(state, Some(metric_name))
+    } else if state.is_comparison_mode() {
+        render_chart_section(state, None)
+    } else {
+        column![].into()
+    };
+
+    let body: Element<'_, Message> = column![chart_section, render_metrics_list(state)]
+        .spacing(10)
+        .into();
+
+    container(
+        column![
+            header,
+            rule::horizontal(1),
+            body.map(|m| Message::SplitPane(Box::new(m)))
+        ]
+        .spacing(10)
+        .padding(20),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .into()
+}
+
 /// Render the header with back button and device info.
 fn render_header(state: &DeviceDetailState) -> Element<'_, Message> {
     let back_button = button(
@@ -846,7 +1430,21 @@ fn render_header(state: &DeviceDetailState) -> Element<'_, Message> {
     .on_press(Message::ExportToJson)
     .style(iced::widget::button::secondary);
 
-    row![
+    // #392: one picker drives every chart's window (and the dashboard
+    // sparklines); the chart's own window buttons still override per chart.
+    let global_window = row![
+        text("All charts").size(12),
+        pick_list(
+            TimeWindow::all(),
+            Some(state.default_time_window),
+            Message::SetGlobalTimeWindow,
+        )
+        .text_size(12),
+    ]
+    .spacing(6)
+    .align_y(Alignment::Center);
+
+    let header = row![
         back_button,
         prev_button,
         next_button,
@@ -854,11 +1452,18 @@ fn render_header(state: &DeviceDetailState) -> Element<'_, Message> {
         device_name,
         metric_count,
         csv_button,
-        json_button
+        json_button,
+        global_window
     ]
     .spacing(15)
-    .align_y(Alignment::Center)
-    .into()
+    .align_y(Alignment::Center);
+
+    match &state.failure {
+        Some(summary) => column![header, crate::view::dashboard::failure_text(summary, 13.0)]
+            .spacing(4)
+            .into(),
+        None => header.into(),
+    }
 }
 
 /// Render the chart section.
@@ -875,7 +1480,7 @@ fn render_chart_section<'a>(
     let title_text = if state.is_comparison_mode() {
         format!("Comparing {} metrics", state.comparison_count())
     } else if let Some(name) = metric_name {
-        name.to_string()
+        state.chart_title(name)
     } else {
         "Chart".to_string()
     };
@@ -925,10 +1530,48 @@ fn render_chart_section<'a>(
     .on_press(Message::ToggleChartExpand)
     .style(iced::widget::button::secondary);
 
+    // Linear / step interpolation for the single-metric chart (#416); each
+    // comparison series has its own toggle in the legend.
+    let interpolation_button: Element<'_, Message> = if state.is_comparison_mode() {
+        column![].into()
+    } else {
+        button(text(state.chart.interpolation().label()).size(11))
+            .on_press(Message::ToggleChartInterpolation)
+            .style(iced::widget::button::secondary)
+            .into()
+    };
+
+    // Raw / per-second display for charted counters (#442).
+    let is_counter = |metric: &str| {
+        matches!(
+            state.metrics.get(metric).map(|p| &p.value),
+            Some(TelemetryValue::Counter(_))
+        )
+    };
+    let charts_counter = state.selected_metric.as_deref().is_some_and(is_counter)
+        || state.chart.series_names().into_iter().any(is_counter);
+    let rate_button: Element<'_, Message> = if charts_counter {
+        button(
+            text(if state.counters_as_rate {
+                "Rate /s"
+            } else {
+                "Raw"
+            })
+            .size(11),
+        )
+        .on_press(Message::ToggleCounterRate)
+        .style(iced::widget::button::secondary)
+        .into()
+    } else {
+        column![].into()
+    };
+
     let header = row![
         chart_title,
         time_buttons,
         custom_window,
+        interpolation_button,
+        rate_button,
         expand_button,
         close_button
     ]
@@ -990,13 +1633,26 @@ fn render_chart_section<'a>(
             let toggle = button(text(if series.visible { "shown" } else { "hidden" }).size(10))
                 .on_press(Message::ToggleMetricVisibility(name.clone()))
                 .style(iced::widget::button::text);
+            let axis = button(text(format!("axis {}", series.axis.label())).size(10))
+                .on_press(Message::ToggleMetricAxis(name.clone()))
+                .style(iced::widget::button::text);
+            let interpolation = button(text(series.interpolation.label()).size(10))
+                .on_press(Message::ToggleMetricInterpolation(name.clone()))
+                .style(iced::widget::button::text);
             let remove = button(text("×").size(12))
                 .on_press(Message::RemoveMetricFromChart(name.clone()))
                 .style(iced::widget::button::text);
             legend_row = legend_row.push(
-                row![swatch, text(name).size(11), toggle, remove]
-                    .spacing(4)
-                    .align_y(Alignment::Center),
+                row![
+                    swatch,
+                    text(name).size(11),
+                    toggle,
+                    axis,
+                    interpolation,
+                    remove
+                ]
+                .spacing(4)
+                .align_y(Alignment::Center),
             );
         }
         legend_row.into()
@@ -1048,45 +1704,213 @@ fn render_chart_section<'a>(
         .into()
 }
 
-/// Convert metrics to table rows.
+/// Convert metrics to table rows. A metric with several label sets becomes a
+/// family head summarizing them, followed by one row per variant when
+/// expanded (#469).
 fn build_metric_table_rows(state: &DeviceDetailState) -> Vec<MetricTableRow> {
-    state
-        .sorted_metrics()
-        .into_iter()
-        .map(|(name, point)| {
-            let (value, full_value) = format_value_display_with_full(&point.value);
-            let trend = if let Some(history) = state.history.get(name) {
-                if history.len() > 1 {
-                    compute_trend(history)
-                } else {
-                    String::new()
+    let mut rows = Vec::new();
+    for (name, point) in state.sorted_metrics() {
+        let mut row = build_metric_table_row(state, name, point);
+        let Some(family) = state.metric_family(name) else {
+            rows.push(row);
+            continue;
+        };
+        let expanded = state.expanded_families.contains(name.as_str());
+        if let Some(value) = state.family_aggregate.apply(&family.variants) {
+            row.value = format!("{} {}", state.family_aggregate, format_value(value));
+            row.full_value = None;
+            row.numeric_value = Some(value);
+        }
+        row.family = FamilyCell::Head {
+            variants: family.variants.len(),
+            expanded,
+        };
+        rows.push(row);
+        if expanded {
+            rows.extend(family.variants.iter().map(|variant| {
+                let (value, full_value) = format_value_display_with_full(&variant.value);
+                MetricTableRow {
+                    name: name.to_string(),
+                    description: None,
+                    value,
+                    full_value,
+                    type_name: value_type_name(&variant.value).to_string(),
+                    provenance: None,
+                    timestamp: format_relative_time(variant.timestamp),
+                    is_chartable: false,
+                    is_in_chart: false,
+                    is_favorite: false,
+                    trend: String::new(),
+                    rate: String::new(),
+                    recent_change: None,
+                    stats: None,
+                    is_stale: (current_timestamp() - variant.timestamp) > METRIC_STALE_THRESHOLD_MS,
+                    device_id: state.device_id.clone(),
+                    numeric_value: None,
+                    family: FamilyCell::Variant {
+                        labels: label_signature(&variant.labels),
+                    },
+                    tree: TreeCell::None,
                 }
-            } else {
-                String::new()
-            };
+            }));
+        }
+    }
+    rows
+}
 
-            let is_stale = (current_timestamp() - point.timestamp) > METRIC_STALE_THRESHOLD_MS;
-
-            MetricTableRow {
-                name: name.to_string(),
-                value,
-                full_value,
-                type_name: value_type_name(&point.value).to_string(),
-                timestamp: format_timestamp(point.timestamp),
-                is_chartable: state.is_metric_chartable(name),
-                is_in_chart: state.is_metric_in_chart(name),
-                is_favorite: state.is_favorite(name),
-                trend,
-                is_stale,
+/// Convert metrics to tree rows (#471): a branch row per path prefix, and
+/// the metrics under each expanded branch indented beneath it.
+fn build_metric_tree_rows(state: &DeviceDetailState) -> Vec<MetricTableRow> {
+    fn push_rows(
+        state: &DeviceDetailState,
+        nodes: &[MetricTreeNode],
+        depth: usize,
+        rows: &mut Vec<MetricTableRow>,
+    ) {
+        for node in nodes {
+            for name in &node.metrics {
+                let Some(point) = state.metrics.get(name) else {
+                    continue;
+                };
+                let mut row = build_metric_table_row(state, name, point);
+                row.tree = TreeCell::Leaf {
+                    segment: node.segment.clone(),
+                    depth,
+                };
+                rows.push(row);
+            }
+            if node.children.is_empty() {
+                continue;
+            }
+            let expanded = state.is_tree_node_expanded(&node.path);
+            rows.push(MetricTableRow {
+                name: node.path.clone(),
+                description: None,
+                value: String::new(),
+                full_value: None,
+                type_name: String::new(),
+                provenance: None,
+                timestamp: String::new(),
+                is_chartable: false,
+                is_in_chart: false,
+                is_favorite: false,
+                trend: String::new(),
+                rate: String::new(),
+                recent_change: None,
+                stats: None,
+                is_stale: false,
                 device_id: state.device_id.clone(),
-                numeric_value: match &point.value {
-                    TelemetryValue::Counter(v) => Some(*v as f64),
-                    TelemetryValue::Gauge(v) => Some(*v),
-                    _ => None,
+                numeric_value: None,
+                family: FamilyCell::None,
+                tree: TreeCell::Branch {
+                    segment: node.segment.clone(),
+                    depth,
+                    expanded,
+                    metrics: node.metric_count(),
                 },
+            });
+            if expanded {
+                push_rows(state, &node.children, depth + 1, rows);
             }
+        }
+    }
+
+    let metrics = state.sorted_metrics();
+    let tree = build_metric_tree(metrics.iter().map(|(name, _)| name.as_str()));
+    let mut rows = Vec::new();
+    push_rows(state, &tree, 0, &mut rows);
+    rows
+}
+
+/// One metric's table row.
+fn build_metric_table_row(
+    state: &DeviceDetailState,
+    name: &str,
+    point: &TelemetryPoint,
+) -> MetricTableRow {
+    let (value, full_value) = format_value_display_with_full(&point.value);
+    let trend = if let Some(history) = state.history.get(name) {
+        if history.len() > 1 {
+            compute_trend(history)
+        } else {
+            String::new()
+        }
+    } else {
+        String::new()
+    };
+
+    let is_stale = (current_timestamp() - point.timestamp) > METRIC_STALE_THRESHOLD_MS;
+    let rate = match point.value {
+        TelemetryValue::Counter(_) => state
+            .metric_rate(name)
+            .map(|r| format!("{}/s", format_value(r)))
+            .unwrap_or_default(),
+        _ => String::new(),
+    };
+
+    let stats = if state.show_metric_stats {
+        state.metric_stats(name).map(|s| {
+            format!(
+                "min {} · max {} · avg {}",
+                format_value(s.min),
+                format_value(s.max),
+                format_value(s.avg)
+            )
         })
-        .collect()
+    } else {
+        None
+    };
+
+    MetricTableRow {
+        name: name.to_string(),
+        description: crate::metric_docs::metric_description(state.device_id.protocol, name),
+        value,
+        full_value,
+        type_name: value_type_name(&point.value).to_string(),
+        provenance: state.provenance.get(name).map(|m| format!("from {m}")),
+        timestamp: format_relative_time(point.timestamp),
+        is_chartable: state.is_metric_chartable(name),
+        is_in_chart: state.is_metric_in_chart(name),
+        is_favorite: state.is_favorite(name),
+        trend,
+        rate,
+        recent_change: state
+            .history
+            .get(name)
+            .and_then(|h| recent_change(h, RECENT_CHANGE_SAMPLES))
+            .filter(|change| change.abs() >= RECENT_CHANGE_THRESHOLD),
+        stats,
+        is_stale,
+        device_id: state.device_id.clone(),
+        numeric_value: match &point.value {
+            TelemetryValue::Counter(v) => Some(*v as f64),
+            TelemetryValue::Gauge(v) => Some(*v),
+            _ => None,
+        },
+        family: FamilyCell::None,
+        tree: TreeCell::None,
+    }
+}
+
+/// Relative change of a gauge across its last `samples` history points (#443):
+/// `(last - first) / |first|`, with a move off zero counted as ±100%.
+/// Counters only ever grow, so they (and non-numeric metrics) are skipped;
+/// `None` with fewer than two points.
+fn recent_change(history: &VecDeque<TelemetryPoint>, samples: usize) -> Option<f64> {
+    let gauge = |p: &TelemetryPoint| match p.value {
+        TelemetryValue::Gauge(v) => Some(v),
+        _ => None,
+    };
+    let window = history.iter().skip(history.len().saturating_sub(samples));
+    let values: Vec<f64> = window.map(gauge).collect::<Option<_>>()?;
+    if values.len() < 2 {
+        return None;
+    }
+    let (first, last) = (values[0], values[values.len() - 1]);
+    if first == 0.0 {
+        return Some(if last == 0.0 { 0.0 } else { last.signum() });
+    }
+    Some((last - first) / first.abs())
 }
 
 /// Compute trend direction from history. Panic-proof: reads the last two points
@@ -1124,8 +1948,19 @@ fn compute_trend(history: &VecDeque<TelemetryPoint>) -> String {
 /// Render the list of all metrics using a table widget.
 fn render_metrics_list(state: &DeviceDetailState) -> Element<'_, Message> {
     let total_count = state.total_metric_count();
-    let table_rows = build_metric_table_rows(state);
-    let filtered_count = table_rows.len();
+    let table_rows = if state.metric_tree_view {
+        build_metric_tree_rows(state)
+    } else {
+        build_metric_table_rows(state)
+    };
+    let filtered_count = if state.metric_tree_view {
+        table_rows
+            .iter()
+            .filter(|row| !matches!(row.tree, TreeCell::Branch { .. }))
+            .count()
+    } else {
+        table_rows.len()
+    };
 
     // Search filter input (with ID for keyboard focus)
     let search_input = text_input("Search metrics... (Ctrl+F)", state.filter_input())
@@ -1142,10 +1977,60 @@ fn render_metrics_list(state: &DeviceDetailState) -> Element<'_, Message> {
         text(format!("{} of {} metrics", filtered_count, total_count)).size(12)
     };
 
-    let search_row = row![search_input, count_text]
+    // Min/max/avg strip per row (#441), off by default to avoid clutter.
+    let stats_button = button(
+        text(if state.show_metric_stats {
+            "Hide stats"
+        } else {
+            "Show stats"
+        })
+        .size(11),
+    )
+    .on_press(Message::ToggleMetricStats)
+    .style(iced::widget::button::secondary);
+
+    // Flat table or key-segment tree (#471).
+    let tree_button = button(
+        row![
+            if state.metric_tree_view {
+                icons::table(IconSize::Small)
+            } else {
+                icons::tree(IconSize::Small)
+            },
+            text(if state.metric_tree_view {
+                "Table"
+            } else {
+                "Tree"
+            })
+            .size(11)
+        ]
+        .spacing(4)
+        .align_y(Alignment::Center),
+    )
+    .on_press(Message::ToggleMetricTreeView)
+    .style(iced::widget::button::secondary);
+
+    let mut search_row = row![search_input, count_text, stats_button, tree_button]
         .spacing(15)
         .align_y(Alignment::Center);
 
+    // Labeled families summarize their variants with a selectable aggregate (#469).
+    if state.has_metric_families() {
+        search_row = search_row.push(
+            row![
+                text("Families:").size(11),
+                pick_list(
+                    FamilyAggregate::ALL,
+                    Some(state.family_aggregate),
+                    Message::SetFamilyAggregate,
+                )
+                .text_size(11),
+            ]
+            .spacing(6)
+            .align_y(Alignment::Center),
+        );
+    }
+
     if total_count == 0 {
         return column![search_row, empty_state("No metrics received yet…", None)]
             .spacing(10)
@@ -1166,6 +2051,11 @@ fn render_metrics_list(state: &DeviceDetailState) -> Element<'_, Message> {
     let favorite_column = table::column(
         text("").size(12),
         |row: MetricTableRow| -> Element<'_, Message> {
+            if matches!(row.family, FamilyCell::Variant { .. })
+                || matches!(row.tree, TreeCell::Branch { .. })
+            {
+                return text("").into();
+            }
             let is_fav = row.is_favorite;
             let glyph = if is_fav { "★" } else { "☆" };
             button(
@@ -1190,10 +2080,91 @@ fn render_metrics_list(state: &DeviceDetailState) -> Element<'_, Message> {
     let name_column = table::column(
         text("Metric").size(12),
         |row: MetricTableRow| -> Element<'_, Message> {
+            // Tree rows (#471): branches toggle their level open, leaves show
+            // their last segment, each indented by depth.
+            let indent = match &row.tree {
+                TreeCell::None => 0,
+                TreeCell::Branch { depth, .. } | TreeCell::Leaf { depth, .. } => *depth,
+            };
+            let indent = iced::widget::Space::new().width(Length::Fixed(16.0 * indent as f32));
+            match row.tree {
+                TreeCell::Branch {
+                    segment,
+                    expanded,
+                    metrics,
+                    ..
+                } => {
+                    let glyph = if expanded { "▾" } else { "▸" };
+                    return row![
+                        indent,
+                        button(text(format!("{glyph} {segment}/ ({metrics})")).size(12))
+                            .on_press(Message::ToggleMetricTreeNode(row.name))
+                            .style(iced::widget::button::text)
+                            .padding(0)
+                    ]
+                    .align_y(Alignment::Center)
+                    .into();
+                }
+                TreeCell::Leaf { segment, .. } => {
+                    let name_widget: Element<'_, Message> = if row.is_chartable {
+                        button(text(segment).size(12))
+                            .on_press(Message::SelectMetricForChart(row.name))
+                            .style(if row.is_in_chart {
+                                iced::widget::button::primary
+                            } else {
+                                iced::widget::button::text
+                            })
+                            .padding(0)
+                            .into()
+                    } else {
+                        text(segment).size(12).into()
+                    };
+                    return row![indent, name_widget].align_y(Alignment::Center).into();
+                }
+                TreeCell::None => {}
+            }
+
+            // Family rows (#469): the head toggles its variants, which render
+            // as their label set, indented under it.
+            match row.family {
+                FamilyCell::Head { variants, expanded } => {
+                    let glyph = if expanded { "▾" } else { "▸" };
+                    let toggle = button(text(format!("{glyph} ({variants})")).size(12))
+                        .on_press(Message::ToggleMetricFamily(row.name.clone()))
+                        .style(iced::widget::button::text)
+                        .padding(0);
+                    let name_widget: Element<'_, Message> = if row.is_chartable {
+                        button(text(row.name.clone()).size(12))
+                            .on_press(Message::SelectMetricForChart(row.name))
+                            .style(if row.is_in_chart {
+                                iced::widget::button::primary
+                            } else {
+                                iced::widget::button::text
+                            })
+                            .padding(0)
+                            .into()
+                    } else {
+                        text(row.name).size(12).into()
+                    };
+                    return row![name_widget, toggle]
+                        .spacing(6)
+                        .align_y(Alignment::Center)
+                        .into();
+                }
+                FamilyCell::Variant { labels } => {
+                    return text(format!("    {labels}"))
+                        .size(11)
+                        .style(|theme: &Theme| text::Style {
+                            color: Some(crate::view::theme::colors(theme).text_dimmed()),
+                        })
+                        .into();
+                }
+                FamilyCell::None => {}
+            }
             let name = row.name.clone();
             let name_display = row.name;
             // Make the name clickable to select for chart
-            if row.is_chartable {
+            let name_widget: Element<'_, Message> = if row.is_chartable {
                 button(text(name_display).size(12))
                     .on_press(Message::SelectMetricForChart(name))
                     .style(if row.is_in_chart {
@@ -1205,6 +2176,33 @@ fn render_metrics_list(state: &DeviceDetailState) -> Element<'_, Message> {
                     .into()
             } else {
                 text(name_display).size(12).into()
+            };
+            let name_widget: Element<'_, Message> = match row.description {
+                Some(description) => tooltip(
+                    name_widget,
+                    container(text(description).size(11))
+                        .padding(6)
+                        .max_width(320.0)
+                        .style(container::rounded_box),
+                    tooltip::Position::Bottom,
+                )
+                .into(),
+                None => name_widget,
+            };
+            // Recently moving metrics get a colored change badge (#443).
+            match row.recent_change {
+                Some(change) => row![
+                    name_widget,
+                    text(format!("{:+.0}%", change * 100.0))
+                        .size(10)
+                        .style(|theme: &Theme| text::Style {
+                            color: Some(crate::view::theme::colors(theme).warning()),
+                        })
+                ]
+                .spacing(6)
+                .align_y(Alignment::Center)
+                .into(),
+                None => name_widget,
             }
         },
     )
@@ -1224,7 +2222,7 @@ fn render_metrics_list(state: &DeviceDetailState) -> Element<'_, Message> {
                     text::Style::default()
                 }
             });
-            if let Some(full) = row.full_value {
+            let value_element: Element<'_, Message> = if let Some(full) = row.full_value {
                 tooltip(
                     value_widget,
                     container(text(full).size(11))
@@ -1236,6 +2234,17 @@ fn render_metrics_list(state: &DeviceDetailState) -> Element<'_, Message> {
                 .into()
             } else {
                 value_widget.into()
+            };
+            match row.stats {
+                Some(stats) => column![
+                    value_element,
+                    text(stats).size(9).style(|theme: &Theme| text::Style {
+                        color: Some(crate::view::theme::colors(theme).text_dimmed()),
+                    })
+                ]
+                .spacing(2)
+                .into(),
+                None => value_element,
             }
         },
     )
@@ -1244,13 +2253,22 @@ fn render_metrics_list(state: &DeviceDetailState) -> Element<'_, Message> {
     let type_column = table::column(
         text("Type").size(12),
         |row: MetricTableRow| -> Element<'_, Message> {
-            let type_name = row.type_name;
-            text(type_name)
+            let type_widget = text(row.type_name)
                 .size(11)
                 .style(|theme: &Theme| text::Style {
                     color: Some(crate::view::theme::colors(theme).text_dimmed()),
-                })
-                .into()
+                });
+            match row.provenance {
+                Some(provenance) => tooltip(
+                    type_widget,
+                    container(text(provenance).size(11))
+                        .padding(6)
+                        .style(container::rounded_box),
+                    tooltip::Position::Bottom,
+                )
+                .into(),
+                None => type_widget.into(),
+            }
         },
     )
     .width(80);
@@ -1260,8 +2278,8 @@ fn render_metrics_list(state: &DeviceDetailState) -> Element<'_, Message> {
         |row: MetricTableRow| -> Element<'_, Message> {
             let trend = row.trend;
             let color = match trend.as_str() {
-                "↑" => crate::view::theme::STATUS_ONLINE,
-                "↓" => crate::view::theme::STATUS_OFFLINE,
+                "↑" => crate::view::theme::palette().online,
+                "↓" => crate::view::theme::palette().offline,
                 _ => crate::view::theme::STATUS_UNKNOWN,
             };
             text(trend)
@@ -1272,6 +2290,19 @@ fn render_metrics_list(state: &DeviceDetailState) -> Element<'_, Message> {
     )
     .width(50);
 
+    let rate_column = table::column(
+        text("Rate").size(12),
+        |row: MetricTableRow| -> Element<'_, Message> {
+            text(row.rate)
+                .size(11)
+                .style(|theme: &Theme| text::Style {
+                    color: Some(crate::view::theme::colors(theme).text_dimmed()),
+                })
+                .into()
+        },
+    )
+    .width(80);
+
     let time_column = table::column(
         text("Updated").size(12),
         |row: MetricTableRow| -> Element<'_, Message> {
@@ -1345,6 +2376,7 @@ fn render_metrics_list(state: &DeviceDetailState) -> Element<'_, Message> {
             value_column,
             type_column,
             trend_column,
+            rate_column,
             time_column,
             actions_column,
         ],
@@ -1385,6 +2417,11 @@ fn format_value_display_with_full(value: &TelemetryValue) -> (String, Option<Str
         }
         TelemetryValue::Boolean(b) => (if *b { "true" } else { "false" }.to_string(), None),
         TelemetryValue::Binary(data) => (format!("<{} bytes>", data.len()), None),
+        TelemetryValue::Bytes(data) => (crate::view::formatting::format_hex_preview(data), None),
+        TelemetryValue::Histogram { sum, count, .. } => (
+            crate::view::formatting::format_histogram(*sum, *count),
+            None,
+        ),
     }
 }
 
@@ -1396,6 +2433,8 @@ fn value_type_name(value: &TelemetryValue) -> &'static str {
         TelemetryValue::Text(_) => "text",
         TelemetryValue::Boolean(_) => "bool",
         TelemetryValue::Binary(_) => "binary",
+        TelemetryValue::Bytes(_) => "bytes",
+        TelemetryValue::Histogram { .. } => "histogram",
     }
 }
 
@@ -1480,6 +2519,154 @@ mod tests {
         assert!(state.chart_from_input.is_empty());
     }
 
+    /// #441: the per-metric strip agrees with the chart's own stats over the
+    /// same window, and points outside the window are left out.
+    #[test]
+    fn metric_stats_match_chart_stats_for_window() {
+        let mut state = DeviceDetailState::new(DeviceId {
+            protocol: Protocol::Sysinfo,
+            source: "host".to_string(),
+        });
+        let now = current_timestamp();
+        let samples = [
+            (now - 2 * 86_400_000, 500.0), // long before any default window
+            (now - 3_000, 10.0),
+            (now - 2_000, 30.0),
+            (now - 1_000, 20.0),
+        ];
+        for (timestamp, v) in samples {
+            state.update(TelemetryPoint {
+                timestamp,
+                value: TelemetryValue::Gauge(v),
+                ..make_test_point("cpu/usage")
+            });
+        }
+
+        let stats = state.metric_stats("cpu/usage").expect("points in window");
+        assert_eq!((stats.min, stats.max, stats.avg), (10.0, 30.0, 20.0));
+        assert_eq!(stats.count, 3);
+
+        let mut chart = ChartState::new("cpu/usage");
+        chart.set_data(samples.iter().map(|&(t, v)| DataPoint::new(t, v)).collect());
+        let expected = chart.stats();
+        assert_eq!(
+            (stats.min, stats.max, stats.avg, stats.current, stats.count),
+            (
+                expected.min,
+                expected.max,
+                expected.avg,
+                expected.current,
+                expected.count
+            )
+        );
+
+        // Off by default; the toggle flips it.
+        assert!(!state.show_metric_stats);
+        state.toggle_metric_stats();
+        assert!(state.show_metric_stats);
+        assert!(state.metric_stats("unknown").is_none());
+    }
+
+    /// #442: rate mode charts a counter's per-second deltas (live points
+    /// included) while the stored history stays raw.
+    #[test]
+    fn counter_rate_mode_charts_manual_deltas() {
+        let mut state = DeviceDetailState::new(DeviceId {
+            protocol: Protocol::Snmp,
+            source: "sw1".to_string(),
+        });
+        let now = current_timestamp();
+        let samples = [
+            (now - 4_000, 1_000u64),
+            (now - 3_000, 1_500),
+            (now - 1_000, 2_500),
+        ];
+        let counter = |(timestamp, v): (i64, u64)| TelemetryPoint {
+            timestamp,
+            value: TelemetryValue::Counter(v),
+            ..make_test_point("if/1/ifInOctets")
+        };
+        for sample in samples {
+            state.update(counter(sample));
+        }
+        state.select_metric("if/1/ifInOctets".to_string());
+        assert_eq!(state.chart.data().len(), 3, "raw values by default");
+
+        state.toggle_counter_rate();
+        let manual: Vec<(i64, f64)> = samples
+            .windows(2)
+            .map(|w| {
+                (
+                    w[1].0,
+                    (w[1].1 - w[0].1) as f64 / ((w[1].0 - w[0].0) as f64 / 1000.0),
+                )
+            })
+            .collect();
+        let charted = |state: &DeviceDetailState| -> Vec<(i64, f64)> {
+            state
+                .chart
+                .data()
+                .iter()
+                .map(|p| (p.timestamp, p.value))
+                .collect()
+        };
+        assert_eq!(charted(&state), manual);
+        assert_eq!(manual, vec![(now - 3_000, 500.0), (now - 1_000, 500.0)]);
+
+        // A live report extends the rate series; history keeps raw values.
+        state.update(counter((now, 3_500)));
+        assert_eq!(charted(&state).last(), Some(&(now, 1_000.0)));
+        assert!(matches!(
+            state.history["if/1/ifInOctets"].back().map(|p| &p.value),
+            Some(TelemetryValue::Counter(3_500))
+        ));
+
+        // Toggling back restores the raw series.
+        state.toggle_counter_rate();
+        assert_eq!(charted(&state).last(), Some(&(now, 3_500.0)));
+    }
+
+    /// #443: a gauge that jumped 50% is flagged; a flat one and a steadily
+    /// growing counter are not.
+    #[test]
+    fn recent_change_flags_jumps_and_ignores_flat_metrics() {
+        let series = |values: &[TelemetryValue]| -> VecDeque<TelemetryPoint> {
+            values
+                .iter()
+                .enumerate()
+                .map(|(i, value)| TelemetryPoint {
+                    timestamp: i as i64 * 1000,
+                    value: value.clone(),
+                    ..make_test_point("m")
+                })
+                .collect()
+        };
+        let gauges = |values: &[f64]| {
+            series(
+                &values
+                    .iter()
+                    .map(|&v| TelemetryValue::Gauge(v))
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        let jumped = gauges(&[100.0, 100.0, 100.0, 150.0]);
+        let change = recent_change(&jumped, RECENT_CHANGE_SAMPLES).unwrap();
+        assert!((change - 0.5).abs() < 1e-9);
+        assert!(change >= RECENT_CHANGE_THRESHOLD);
+
+        let flat = gauges(&[42.0, 42.0, 42.0, 42.0]);
+        assert_eq!(recent_change(&flat, RECENT_CHANGE_SAMPLES), Some(0.0));
+
+        // Only the last few samples count: an old jump has aged out.
+        let settled = gauges(&[10.0, 50.0, 50.0, 50.0, 50.0, 50.0]);
+        assert_eq!(recent_change(&settled, RECENT_CHANGE_SAMPLES), Some(0.0));
+
+        let counter = series(&[TelemetryValue::Counter(100), TelemetryValue::Counter(900)]);
+        assert_eq!(recent_change(&counter, RECENT_CHANGE_SAMPLES), None);
+        assert_eq!(recent_change(&gauges(&[1.0]), RECENT_CHANGE_SAMPLES), None);
+    }
+
     fn make_test_point(metric: &str) -> TelemetryPoint {
         TelemetryPoint {
             timestamp: 1000,
@@ -1515,6 +2702,49 @@ mod tests {
         assert!(state.is_metric_chartable("rx/bytes"));
         assert!(!state.is_metric_chartable("daemon/info"));
         assert!(!state.is_metric_chartable("unknown"));
+
+        // #415: a selected boolean metric charts its 0/1 values as steps.
+        state.select_metric("iface/eth0/up".to_string());
+        assert_eq!(state.chart.interpolation(), Interpolation::Step);
+        state.select_metric("cpu/usage".to_string());
+        assert_eq!(state.chart.interpolation(), Interpolation::Linear);
+    }
+
+    #[test]
+    fn sysinfo_detail_opens_with_default_metrics_charted() {
+        let mut state = DeviceDetailState::new(DeviceId {
+            protocol: Protocol::Sysinfo,
+            source: "h".to_string(),
+        });
+        assert_eq!(state.default_metrics, ["cpu/usage", "memory/usage_percent"]);
+
+        for metric in [
+            "disk/sda/usage_percent",
+            "cpu/usage",
+            "memory/usage_percent",
+        ] {
+            state.update(make_test_point(metric));
+        }
+        assert!(state.is_comparison_mode());
+        assert!(state.is_metric_in_chart("cpu/usage"));
+        assert!(state.is_metric_in_chart("memory/usage_percent"));
+        assert!(!state.is_metric_in_chart("disk/sda/usage_percent"));
+        assert!(state.default_metrics.is_empty());
+
+        // A user selection wins over anything still pending.
+        let mut state = DeviceDetailState::new(DeviceId {
+            protocol: Protocol::Sysinfo,
+            source: "h".to_string(),
+        });
+        state.update(make_test_point("cpu/usage"));
+        state.select_metric("disk/io".to_string());
+        state.update(make_test_point("memory/usage_percent"));
+        assert!(!state.is_comparison_mode());
+        assert_eq!(state.selected_metric.as_deref(), Some("disk/io"));
+
+        // SNMP defaults match per-interface metric names.
+        assert!(matches_default_metric("if/1/ifInOctets", "ifInOctets"));
+        assert!(!matches_default_metric("if/1/xifInOctets", "ifInOctets"));
     }
 
     #[test]
@@ -1753,4 +2983,118 @@ mod tests {
         )]);
         assert_eq!(state.chart.data().len(), 2);
     }
+
+    fn load_point(period: &str, value: f64, ts: i64) -> TelemetryPoint {
+        let mut p = make_test_point("system/load");
+        p.labels.insert("period".to_string(), period.to_string());
+        p.value = TelemetryValue::Gauge(value);
+        p.timestamp = ts;
+        p
+    }
+
+    /// #469: labeled variants of one metric group into a single family,
+    /// keeping the newest point per label set.
+    #[test]
+    fn labeled_variants_group_into_families() {
+        let points = vec![
+            load_point("1m", 0.5, 1_000),
+            load_point("5m", 0.75, 1_000),
+            load_point("15m", 1.0, 1_000),
+            load_point("1m", 2.0, 2_000),
+            make_test_point("cpu"),
+        ];
+        let families = group_metric_families(&points);
+        let names: Vec<&str> = families.iter().map(|f| f.name).collect();
+        assert_eq!(names, vec!["cpu", "system/load"]);
+        let load = &families[1];
+        assert_eq!(load.variants.len(), 3);
+        let signatures: Vec<String> = load
+            .variants
+            .iter()
+            .map(|p| label_signature(&p.labels))
+            .collect();
+        assert_eq!(signatures, vec!["period=15m", "period=1m", "period=5m"]);
+        // The newer 1m point replaced the older one.
+        assert_eq!(load.variants[1].value, TelemetryValue::Gauge(2.0));
+
+        assert_eq!(FamilyAggregate::Latest.apply(&load.variants), Some(2.0));
+        assert_eq!(FamilyAggregate::Sum.apply(&load.variants), Some(3.75));
+        assert_eq!(FamilyAggregate::Avg.apply(&load.variants), Some(1.25));
+        assert_eq!(FamilyAggregate::Min.apply(&load.variants), Some(0.75));
+        assert_eq!(FamilyAggregate::Max.apply(&load.variants), Some(2.0));
+    }
+
+    #[test]
+    fn device_state_tracks_metric_families() {
+        let mut state = DeviceDetailState::new(DeviceId {
+            protocol: Protocol::Snmp,
+            source: "test".to_string(),
+        });
+        state.update(make_test_point("cpu"));
+        state.update(load_point("1m", 0.5, 1_000));
+        assert!(!state.has_metric_families());
+        state.update(load_point("5m", 0.75, 1_100));
+        assert!(state.has_metric_families());
+        assert_eq!(
+            state.metric_family("system/load").unwrap().variants.len(),
+            2
+        );
+        assert!(state.metric_family("cpu").is_none());
+    }
+
+    /// #471: metric names nest by segment under shared branches.
+    #[test]
+    fn metric_tree_nests_shared_prefixes() {
+        let tree = build_metric_tree([
+            "network/eth0/tx_bytes",
+            "network/eth0/rx_bytes",
+            "cpu",
+            "cpu/usage",
+        ]);
+        let top: Vec<&str> = tree.iter().map(|n| n.path.as_str()).collect();
+        assert_eq!(top, vec!["cpu", "network"]);
+
+        // `cpu` is both a metric and a branch.
+        assert!(tree[0].is_metric());
+        assert_eq!(tree[0].children[0].path, "cpu/usage");
+
+        let network = &tree[1];
+        assert!(!network.is_metric());
+        assert_eq!(network.metric_count(), 2);
+        assert_eq!(network.children.len(), 1);
+        let eth0 = &network.children[0];
+        assert_eq!(eth0.segment, "eth0");
+        assert_eq!(eth0.path, "network/eth0");
+        let leaves: Vec<(&str, &str)> = eth0
+            .children
+            .iter()
+            .map(|n| (n.segment.as_str(), n.path.as_str()))
+            .collect();
+        assert_eq!(
+            leaves,
+            vec![
+                ("rx_bytes", "network/eth0/rx_bytes"),
+                ("tx_bytes", "network/eth0/tx_bytes"),
+            ]
+        );
+        assert!(
+            eth0.children
+                .iter()
+                .all(|n| n.is_metric() && n.children.is_empty())
+        );
+    }
+
+    /// #471: empty segments don't nest, but the metric keeps its own key.
+    #[test]
+    fn metric_tree_keeps_keys_with_empty_segments() {
+        let tree = build_metric_tree(["a//b", "a/b", "/c"]);
+        let top: Vec<&str> = tree.iter().map(|n| n.path.as_str()).collect();
+        assert_eq!(top, vec!["a", "c"]);
+
+        let b = &tree[0].children[0];
+        assert_eq!(b.path, "a/b");
+        assert_eq!(b.metrics, vec!["a//b", "a/b"]);
+        assert_eq!(tree[0].metric_count(), 2);
+        assert_eq!(tree[1].metrics, vec!["/c"]);
+    }
 }
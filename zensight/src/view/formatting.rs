@@ -1,5 +1,9 @@
 //! Shared formatting utilities for the ZenSight views.
 
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+use serde::{Deserialize, Serialize};
+
 /// Format a numeric value for display with appropriate scale suffix.
 ///
 /// - Values >= 1M display as "X.XM"
@@ -18,6 +22,39 @@ pub fn format_value(value: f64) -> String {
     }
 }
 
+/// Summarize a histogram value (#404) as its sample count and mean, e.g.
+/// "n=13, mean 0.48". The buckets themselves don't fit a table cell.
+pub fn format_histogram(sum: f64, count: u64) -> String {
+    if count == 0 {
+        return "n=0".to_string();
+    }
+    format!("n={count}, mean {}", format_value(sum / count as f64))
+}
+
+/// Hex preview of an opaque binary value (#405): the first 16 bytes as
+/// space-separated pairs, an ellipsis if there are more, then the length —
+/// e.g. "de ad be ef (4 bytes)".
+pub fn format_hex_preview(bytes: &[u8]) -> String {
+    const PREVIEW: usize = 16;
+    let mut hex: Vec<String> = bytes
+        .iter()
+        .take(PREVIEW)
+        .map(|b| format!("{b:02x}"))
+        .collect();
+    if bytes.len() > PREVIEW {
+        hex.push("…".to_string());
+    }
+    let len = match bytes.len() {
+        1 => "1 byte".to_string(),
+        n => format!("{n} bytes"),
+    };
+    if hex.is_empty() {
+        len
+    } else {
+        format!("{} ({len})", hex.join(" "))
+    }
+}
+
 /// Format a byte count with a binary scale suffix (B / KB / MB / GB / TB).
 ///
 /// Used across the flow/talker/bandwidth tables so large transfers read as
@@ -46,6 +83,150 @@ pub fn format_rate(bytes_per_sec: f64) -> String {
     format!("{}/s", format_bytes(bytes_per_sec))
 }
 
+/// Format a bit count with a decimal (SI) scale suffix, the way link speeds
+/// are quoted: "512 bit", "1.5 kbit", "1 Mbit", "2.5 Gbit" (#477).
+pub fn format_bits(bits: f64) -> String {
+    let (value, unit) = if bits >= 1e12 {
+        (bits / 1e12, "Tbit")
+    } else if bits >= 1e9 {
+        (bits / 1e9, "Gbit")
+    } else if bits >= 1e6 {
+        (bits / 1e6, "Mbit")
+    } else if bits >= 1e3 {
+        (bits / 1e3, "kbit")
+    } else {
+        return format!("{bits:.0} bit");
+    };
+    let value = format!("{value:.1}");
+    format!("{} {unit}", value.strip_suffix(".0").unwrap_or(&value))
+}
+
+/// How flow volumes are shown in the NetFlow view and on topology edges
+/// (#477).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FlowVolumeUnit {
+    /// Total bytes carried ("1.4 MB").
+    #[default]
+    BytesTotal,
+    /// Average bytes per second over the flow's duration ("180.0 KB/s").
+    BytesPerSecond,
+    /// Total bits carried ("11.5 Mbit").
+    BitsTotal,
+    /// Average bits per second over the flow's duration ("1.4 Mbit/s").
+    BitsPerSecond,
+}
+
+impl FlowVolumeUnit {
+    /// All units, in settings order.
+    pub const ALL: &'static [FlowVolumeUnit] = &[
+        FlowVolumeUnit::BytesTotal,
+        FlowVolumeUnit::BytesPerSecond,
+        FlowVolumeUnit::BitsTotal,
+        FlowVolumeUnit::BitsPerSecond,
+    ];
+
+    /// Format a flow that carried `bytes` over `duration_ms`. A per-second
+    /// unit falls back to the total when the duration is unknown (0).
+    pub fn format(self, bytes: u64, duration_ms: u64) -> String {
+        let bytes = bytes as f64;
+        let secs = duration_ms as f64 / 1000.0;
+        match self {
+            FlowVolumeUnit::BytesPerSecond if secs > 0.0 => format_rate(bytes / secs),
+            FlowVolumeUnit::BitsPerSecond if secs > 0.0 => {
+                format!("{}/s", format_bits(bytes * 8.0 / secs))
+            }
+            FlowVolumeUnit::BytesTotal | FlowVolumeUnit::BytesPerSecond => format_bytes(bytes),
+            FlowVolumeUnit::BitsTotal | FlowVolumeUnit::BitsPerSecond => format_bits(bytes * 8.0),
+        }
+    }
+
+    fn from_index(index: u8) -> Self {
+        Self::ALL.get(index as usize).copied().unwrap_or_default()
+    }
+}
+
+impl std::fmt::Display for FlowVolumeUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            FlowVolumeUnit::BytesTotal => "Bytes (total)",
+            FlowVolumeUnit::BytesPerSecond => "Bytes per second",
+            FlowVolumeUnit::BitsTotal => "Bits (total)",
+            FlowVolumeUnit::BitsPerSecond => "Bits per second",
+        })
+    }
+}
+
+static FLOW_VOLUME_UNIT: AtomicU8 = AtomicU8::new(0);
+
+/// Select the unit flow volumes are shown in (#477).
+pub fn set_flow_volume_unit(unit: FlowVolumeUnit) {
+    let index = FlowVolumeUnit::ALL
+        .iter()
+        .position(|u| *u == unit)
+        .unwrap_or(0);
+    FLOW_VOLUME_UNIT.store(index as u8, Ordering::Relaxed);
+}
+
+/// The unit flow volumes are currently shown in.
+pub fn flow_volume_unit() -> FlowVolumeUnit {
+    FlowVolumeUnit::from_index(FLOW_VOLUME_UNIT.load(Ordering::Relaxed))
+}
+
+static UTC_TIMESTAMPS: AtomicBool = AtomicBool::new(false);
+
+/// Show absolute times in UTC (`true`) or local time (#440).
+pub fn set_utc_timestamps(utc: bool) {
+    UTC_TIMESTAMPS.store(utc, Ordering::Relaxed);
+}
+
+/// Whether absolute times are currently shown in UTC; pass this as the
+/// `utc` argument of [`format_absolute_time`].
+pub fn utc_timestamps() -> bool {
+    UTC_TIMESTAMPS.load(Ordering::Relaxed)
+}
+
+/// Per-second rate of a monotonic counter from the two most recent points of
+/// its history (#439).
+///
+/// A value that went backwards is a 32-bit wrap when the wrapped delta is
+/// plausible (under half the `u32` range, e.g. an SNMP `Counter32` octet
+/// counter rolling over) and a reset otherwise. Returns `None` on fewer than
+/// two points, a non-increasing timestamp, or a reset.
+pub fn counter_rate(history: &[crate::view::chart::DataPoint]) -> Option<f64> {
+    let [prev, last] = history.last_chunk::<2>()?;
+    let dt = (last.timestamp - prev.timestamp) as f64 / 1000.0;
+    if dt <= 0.0 {
+        return None;
+    }
+    let delta = if last.value >= prev.value {
+        last.value - prev.value
+    } else {
+        const RANGE: f64 = u32::MAX as f64 + 1.0;
+        let wrapped = RANGE - prev.value + last.value;
+        if prev.value >= RANGE || wrapped >= RANGE / 2.0 {
+            return None;
+        }
+        wrapped
+    };
+    Some(delta / dt)
+}
+
+/// Convert a counter series to per-second rates (#442), one point per
+/// consecutive pair stamped at the later one. Wraps are handled as in
+/// [`counter_rate`]; a reset leaves a gap rather than a negative spike.
+pub fn rate_series(
+    history: &[crate::view::chart::DataPoint],
+) -> Vec<crate::view::chart::DataPoint> {
+    history
+        .windows(2)
+        .filter_map(|pair| {
+            counter_rate(pair)
+                .map(|rate| crate::view::chart::DataPoint::new(pair[1].timestamp, rate))
+        })
+        .collect()
+}
+
 /// Format an integer count with a decimal scale suffix (K / M / B).
 ///
 /// Keeps small counts exact ("942") and scales large ones ("1.2M") so packet /
@@ -62,28 +243,83 @@ pub fn format_count(count: u64) -> String {
     }
 }
 
-/// Format a Unix timestamp (milliseconds) as a relative time string.
-///
-/// Returns strings like "just now", "5s ago", "3m ago", "2h ago".
-pub fn format_timestamp(timestamp_ms: i64) -> String {
-    let now = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .map(|d| d.as_millis() as i64)
-        .unwrap_or(0);
+/// Format a duration as its two largest units: "45s", "12m 5s", "1h 23m",
+/// "3d 4h" (#440). Zero trailing units are dropped ("2h"); negative
+/// durations read as "0s".
+pub fn format_duration(duration_ms: i64) -> String {
+    let secs = duration_ms.max(0) / 1000;
+    let (days, hours, mins, secs) = (secs / 86_400, secs / 3600 % 24, secs / 60 % 60, secs % 60);
+    let (major, minor) = if days > 0 {
+        ((days, 'd'), (hours, 'h'))
+    } else if hours > 0 {
+        ((hours, 'h'), (mins, 'm'))
+    } else if mins > 0 {
+        ((mins, 'm'), (secs, 's'))
+    } else {
+        return format!("{secs}s");
+    };
+    if minor.0 == 0 {
+        format!("{}{}", major.0, major.1)
+    } else {
+        format!("{}{} {}{}", major.0, major.1, minor.0, minor.1)
+    }
+}
+
+/// Format a Unix timestamp (milliseconds) relative to now: "just now",
+/// "5s ago", "3m ago", "2h ago", "4d ago", or "in 5m" for future times.
+pub fn format_relative_time(timestamp_ms: i64) -> String {
+    format_age(current_time_ms() - timestamp_ms)
+}
 
-    let diff_ms = now - timestamp_ms;
+/// Format an age (now - timestamp, milliseconds) the way
+/// [`format_relative_time`] does; negative ages are in the future. Pure (no
+/// clock read).
+pub fn format_age(age_ms: i64) -> String {
+    if age_ms.abs() < 1000 {
+        return "just now".to_string();
+    }
+    let secs = age_ms.abs() / 1000;
+    let span = if secs < 60 {
+        format!("{secs}s")
+    } else if secs < 3600 {
+        format!("{}m", secs / 60)
+    } else if secs < 86_400 {
+        format!("{}h", secs / 3600)
+    } else {
+        format!("{}d", secs / 86_400)
+    };
+    if age_ms < 0 {
+        format!("in {span}")
+    } else {
+        format!("{span} ago")
+    }
+}
 
-    if diff_ms < 1000 {
-        "just now".to_string()
-    } else if diff_ms < 60_000 {
-        format!("{}s ago", diff_ms / 1000)
-    } else if diff_ms < 3_600_000 {
-        format!("{}m ago", diff_ms / 60_000)
+/// Format a Unix timestamp (milliseconds) as a wall-clock date and time,
+/// in local time or, with `utc`, UTC ("2024-01-05 14:30:00 UTC").
+pub fn format_absolute_time(timestamp_ms: i64, utc: bool) -> String {
+    use chrono::TimeZone;
+    const FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+    if utc {
+        match chrono::Utc.timestamp_millis_opt(timestamp_ms) {
+            chrono::LocalResult::Single(dt) => format!("{} UTC", dt.format(FORMAT)),
+            _ => timestamp_ms.to_string(),
+        }
     } else {
-        format!("{}h ago", diff_ms / 3_600_000)
+        match chrono::Local.timestamp_millis_opt(timestamp_ms) {
+            chrono::LocalResult::Single(dt) => dt.format(FORMAT).to_string(),
+            _ => timestamp_ms.to_string(),
+        }
     }
 }
 
+fn current_time_ms() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0)
+}
+
 /// Format a time offset for chart axis labels.
 ///
 /// Returns strings like "now", "-30s", "-5m", "-1h", "-2d".
@@ -125,6 +361,61 @@ mod tests {
         assert_eq!(format_rate(2_097_152.0), "2.0 MB/s");
     }
 
+    #[test]
+    fn flow_volume_units() {
+        // 1,000,000 bytes over 8 s is 125 kB/s, or 1 Mbit/s.
+        assert_eq!(
+            FlowVolumeUnit::BitsPerSecond.format(1_000_000, 8_000),
+            "1 Mbit/s"
+        );
+        assert_eq!(FlowVolumeUnit::BitsTotal.format(1_000_000, 8_000), "8 Mbit");
+        assert_eq!(
+            FlowVolumeUnit::BytesPerSecond.format(1_000_000, 8_000),
+            "122.1 KB/s"
+        );
+        assert_eq!(
+            FlowVolumeUnit::BytesTotal.format(1_000_000, 8_000),
+            "976.6 KB"
+        );
+        // No duration: per-second units show the total.
+        assert_eq!(FlowVolumeUnit::BitsPerSecond.format(1_000_000, 0), "8 Mbit");
+        assert_eq!(format_bits(512.0), "512 bit");
+        assert_eq!(format_bits(1_500.0), "1.5 kbit");
+    }
+
+    fn points(samples: &[(i64, f64)]) -> Vec<crate::view::chart::DataPoint> {
+        samples
+            .iter()
+            .map(|&(t, v)| crate::view::chart::DataPoint::new(t, v))
+            .collect()
+    }
+
+    #[test]
+    fn counter_rate_uses_the_last_delta() {
+        let history = points(&[(0, 0.0), (1000, 500.0), (3000, 2500.0)]);
+        assert_eq!(counter_rate(&history), Some(1000.0));
+    }
+
+    #[test]
+    fn counter_rate_handles_32_bit_wrap() {
+        let max = u32::MAX as f64;
+        // 100 bytes before the wrap, 900 after: 1000 bytes in 2s.
+        let history = points(&[(0, max - 99.0), (2000, 900.0)]);
+        assert_eq!(counter_rate(&history), Some(500.0));
+    }
+
+    #[test]
+    fn counter_rate_needs_two_points_and_skips_resets() {
+        assert_eq!(counter_rate(&[]), None);
+        assert_eq!(counter_rate(&points(&[(0, 10.0)])), None);
+        // Same timestamp twice.
+        assert_eq!(counter_rate(&points(&[(1000, 10.0), (1000, 20.0)])), None);
+        // A small counter dropping to near zero is a restart, not a wrap.
+        assert_eq!(counter_rate(&points(&[(0, 5000.0), (1000, 10.0)])), None);
+        // So is a 64-bit counter going backwards.
+        assert_eq!(counter_rate(&points(&[(0, 1e12), (1000, 10.0)])), None);
+    }
+
     #[test]
     fn test_format_count() {
         assert_eq!(format_count(0), "0");
@@ -134,6 +425,56 @@ mod tests {
         assert_eq!(format_count(3_200_000_000), "3.2B");
     }
 
+    #[test]
+    fn rate_series_skips_resets() {
+        let history = points(&[(0, 0.0), (1000, 100.0), (2000, 50.0), (4000, 250.0)]);
+        let rates: Vec<(i64, f64)> = rate_series(&history)
+            .iter()
+            .map(|p| (p.timestamp, p.value))
+            .collect();
+        assert_eq!(rates, vec![(1000, 100.0), (4000, 100.0)]);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(0), "0s");
+        assert_eq!(format_duration(999), "0s");
+        assert_eq!(format_duration(59_999), "59s");
+        assert_eq!(format_duration(60_000), "1m");
+        assert_eq!(format_duration(725_000), "12m 5s");
+        assert_eq!(format_duration(3_600_000), "1h");
+        assert_eq!(format_duration(4_980_000), "1h 23m");
+        assert_eq!(format_duration(86_400_000), "1d");
+        assert_eq!(format_duration(273_600_000), "3d 4h");
+        assert_eq!(format_duration(-5_000), "0s");
+    }
+
+    #[test]
+    fn test_format_age() {
+        assert_eq!(format_age(0), "just now");
+        assert_eq!(format_age(999), "just now");
+        assert_eq!(format_age(1_000), "1s ago");
+        assert_eq!(format_age(59_000), "59s ago");
+        assert_eq!(format_age(60_000), "1m ago");
+        assert_eq!(format_age(3_599_000), "59m ago");
+        assert_eq!(format_age(3_600_000), "1h ago");
+        assert_eq!(format_age(86_399_000), "23h ago");
+        assert_eq!(format_age(172_800_000), "2d ago");
+        // Future timestamps (clock skew, scheduled silences).
+        assert_eq!(format_age(-500), "just now");
+        assert_eq!(format_age(-300_000), "in 5m");
+        assert_eq!(format_age(-86_400_000), "in 1d");
+    }
+
+    #[test]
+    fn test_format_absolute_time_utc() {
+        assert_eq!(format_absolute_time(0, true), "1970-01-01 00:00:00 UTC");
+        assert_eq!(
+            format_absolute_time(1_704_465_000_000, true),
+            "2024-01-05 14:30:00 UTC"
+        );
+    }
+
     #[test]
     fn test_format_time_offset() {
         assert_eq!(format_time_offset(0), "now");
@@ -143,4 +484,19 @@ mod tests {
         assert_eq!(format_time_offset(86_400_000), "-1d");
         assert_eq!(format_time_offset(172_800_000), "-2d");
     }
+
+    #[test]
+    fn test_format_hex_preview() {
+        assert_eq!(format_hex_preview(&[]), "0 bytes");
+        assert_eq!(format_hex_preview(&[0x0a]), "0a (1 byte)");
+        assert_eq!(
+            format_hex_preview(&[0xde, 0xad, 0xbe, 0xef]),
+            "de ad be ef (4 bytes)"
+        );
+        let long: Vec<u8> = (0..20).collect();
+        assert_eq!(
+            format_hex_preview(&long),
+            "00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f … (20 bytes)"
+        );
+    }
 }
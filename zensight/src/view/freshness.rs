@@ -16,6 +16,7 @@ use iced::widget::{row, text};
 use iced::{Alignment, Element, Theme};
 
 use crate::message::Message;
+use crate::view::formatting::format_age;
 use crate::view::theme;
 use crate::view::tokens::{font, space};
 
@@ -87,22 +88,6 @@ pub fn as_of_clock(last_update_ms: Option<i64>) -> Option<String> {
     Some(format!("{h:02}:{m:02}:{s:02}"))
 }
 
-/// Format a data age (now - ts) as a compact "Ns ago" / "Nm ago" / "Nh ago"
-/// string. Negative/zero ages read "just now". Pure (no clock read).
-pub fn age_string(age_ms: i64) -> String {
-    if age_ms < 1000 {
-        "just now".to_string()
-    } else if age_ms < 60_000 {
-        format!("{}s ago", age_ms / 1000)
-    } else if age_ms < 3_600_000 {
-        format!("{}m ago", age_ms / 60_000)
-    } else if age_ms < 86_400_000 {
-        format!("{}h ago", age_ms / 3_600_000)
-    } else {
-        format!("{}d ago", age_ms / 86_400_000)
-    }
-}
-
 /// The global freshness indicator for the top bar: a colored dot, the verdict
 /// label, and (when there is data) an "as of HH:MM:SS" stamp.
 pub fn freshness_indicator<'a>(
@@ -146,7 +131,7 @@ pub fn age_label<'a>(
 ) -> Element<'a, Message> {
     let age = now_ms.saturating_sub(last_update_ms);
     let is_stale = age >= stale_after_ms;
-    text(age_string(age))
+    text(format_age(age))
         .size(font::CAPTION)
         .style(move |theme: &Theme| {
             let c = theme::colors(theme);
@@ -219,14 +204,4 @@ mod tests {
         // Wraps past 24h.
         assert_eq!(as_of_clock(Some(90_000_000)).as_deref(), Some("01:00:00"));
     }
-
-    #[test]
-    fn age_string_buckets() {
-        assert_eq!(age_string(0), "just now");
-        assert_eq!(age_string(500), "just now");
-        assert_eq!(age_string(5_000), "5s ago");
-        assert_eq!(age_string(120_000), "2m ago");
-        assert_eq!(age_string(7_200_000), "2h ago");
-        assert_eq!(age_string(172_800_000), "2d ago");
-    }
 }
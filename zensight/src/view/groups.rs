@@ -321,7 +321,7 @@ pub fn color_indicator(color: (f32, f32, f32), size: f32) -> Element<'static, Me
 }
 
 /// A lightweight group tag for rendering (owned data).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GroupTag {
     pub name: String,
     pub color: (f32, f32, f32),
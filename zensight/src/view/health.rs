@@ -2,19 +2,21 @@
 //!
 //! Folds the signals already on a dashboard [`DeviceState`] — sensor liveness,
 //! the sysinfo saturation/health-state (#97, USE), the logs units-in-failure
-//! gauge (RED-ish), and any netring anomaly count — into one 0–100 number
+//! gauge (RED-ish), any netring anomaly count, the device's active alerts and
+//! how much of its metric set has gone stale (#490) — into one 0–100 number
 //! (higher = healthier) and a coarse band for at-a-glance fleet triage.
 //!
 //! The scoring is a pure function over extracted inputs so it is unit-tested
-//! without constructing widgets. Per-protocol devices each get their own score
-//! today; a true cross-protocol host rollup arrives with the Host aggregate
-//! (#128). Firing-alert severity will join the inputs once incidents are wired
-//! (#129).
+//! without constructing widgets. Per-protocol devices each get their own score;
+//! the Host aggregate (#128) folds them into the worst facet's.
+
+use std::cmp::Ordering;
 
 use iced::Color;
 
 use zensight_common::{DeviceStatus, TelemetryValue};
 
+use crate::view::alerts::Severity;
 use crate::view::dashboard::DeviceState;
 
 /// Coarse health band for tinting and grouping.
@@ -39,9 +41,9 @@ impl HealthBand {
     /// Triage color: green / amber / red / gray (shared status palette, D2).
     pub fn color(self) -> Color {
         match self {
-            HealthBand::Healthy => crate::view::theme::STATUS_ONLINE,
-            HealthBand::Degraded => crate::view::theme::STATUS_DEGRADED,
-            HealthBand::Critical => crate::view::theme::STATUS_OFFLINE,
+            HealthBand::Healthy => crate::view::theme::palette().online,
+            HealthBand::Degraded => crate::view::theme::palette().degraded,
+            HealthBand::Critical => crate::view::theme::palette().offline,
             HealthBand::Unknown => crate::view::theme::STATUS_UNKNOWN,
         }
     }
@@ -55,6 +57,14 @@ pub struct HealthScore {
 }
 
 impl HealthScore {
+    /// Worst-first triage order (#490): the worse band first, then the lower
+    /// score. `Unknown` sorts last as the least informative.
+    pub fn triage_cmp(&self, other: &Self) -> Ordering {
+        band_rank(self.band)
+            .cmp(&band_rank(other.band))
+            .then(self.value.cmp(&other.value))
+    }
+
     /// A short badge label, e.g. `"health 82"` or `"health —"` when unknown.
     pub fn label(self) -> String {
         match self.band {
@@ -64,6 +74,29 @@ impl HealthScore {
     }
 }
 
+/// A device's unacknowledged alerts by severity (#490), rule-raised and
+/// sensor-pushed alike. Info alerts don't affect the score.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AlertLoad {
+    pub critical: usize,
+    pub warning: usize,
+}
+
+impl AlertLoad {
+    /// Count one more active alert of `severity`.
+    pub fn add(&mut self, severity: Severity) {
+        match severity {
+            Severity::Critical => self.critical += 1,
+            Severity::Warning => self.warning += 1,
+            Severity::Info => {}
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.critical == 0 && self.warning == 0
+    }
+}
+
 /// Map a 0–100 score to a band (Unknown is decided separately by `compute`).
 fn band_for(value: u8) -> HealthBand {
     if value >= 70 {
@@ -80,19 +113,24 @@ fn band_for(value: u8) -> HealthBand {
 /// Starts at 100 and applies penalties: liveness (Offline is fatal, Degraded
 /// heavy), sysinfo health-state (`crit`/`warn`, falling back to the numeric
 /// saturation score when no categorical state is published), logs units in
-/// failure, and netring active anomalies. A device with `Unknown` liveness and
-/// no telemetry-derived signal scores `Unknown` rather than a misleading number.
+/// failure, netring active anomalies, active alerts (a critical one weighs like
+/// a degraded sensor) and the share of metrics gone stale (`0.0..=1.0`). A
+/// device with `Unknown` liveness and no other signal scores `Unknown` rather
+/// than a misleading number.
 pub fn compute(
     status: DeviceStatus,
     health_state: Option<&str>,
     saturation: Option<f64>,
     log_units_failing: Option<f64>,
     netring_anomalies: Option<f64>,
+    alerts: AlertLoad,
+    stale_fraction: f64,
 ) -> HealthScore {
     let has_signal = health_state.is_some()
         || saturation.is_some()
         || log_units_failing.is_some()
-        || netring_anomalies.is_some();
+        || netring_anomalies.is_some()
+        || !alerts.is_empty();
 
     if status == DeviceStatus::Unknown && !has_signal {
         return HealthScore {
@@ -133,6 +171,17 @@ pub fn compute(
     {
         penalty += (15.0 + a * 5.0).min(40.0);
     }
+    // Active alerts (#490): the first of a severity costs the most, further
+    // ones less, capped per severity.
+    if alerts.critical > 0 {
+        penalty += (40.0 + (alerts.critical - 1) as f64 * 10.0).min(60.0);
+    }
+    if alerts.warning > 0 {
+        penalty += (15.0 + (alerts.warning - 1) as f64 * 5.0).min(30.0);
+    }
+    // Metric freshness (#490): a device that keeps reporting some metrics
+    // but not others is half-broken even while its liveness looks fine.
+    penalty += stale_fraction.clamp(0.0, 1.0) * 30.0;
 
     let value = (100.0 - penalty).clamp(0.0, 100.0) as u8;
     HealthScore {
@@ -169,6 +218,8 @@ pub fn score_device(d: &DeviceState) -> HealthScore {
         // build; either is treated as the anomaly signal.
         metric_num(d, "security/anomalies_active")
             .or_else(|| metric_num(d, "flow/anomalies_active")),
+        d.alert_load,
+        d.stale_fraction(),
     )
 }
 
@@ -191,15 +242,8 @@ pub fn score_host<'a>(facets: impl IntoIterator<Item = &'a DeviceState>) -> Heal
     for f in facets {
         let s = score_device(f);
         worst = Some(match worst {
-            None => s,
-            Some(w) => {
-                let (wr, sr) = (band_rank(w.band), band_rank(s.band));
-                if sr < wr || (sr == wr && s.value < w.value) {
-                    s
-                } else {
-                    w
-                }
-            }
+            Some(w) if s.triage_cmp(&w).is_ge() => w,
+            _ => s,
         });
     }
     worst.unwrap_or(HealthScore {
@@ -220,6 +264,8 @@ mod tests {
             Some(5.0),
             Some(0.0),
             Some(0.0),
+            AlertLoad::default(),
+            0.0,
         );
         assert_eq!(s.value, 100);
         assert_eq!(s.band, HealthBand::Healthy);
@@ -227,14 +273,30 @@ mod tests {
 
     #[test]
     fn offline_is_critical_zero() {
-        let s = compute(DeviceStatus::Offline, None, None, None, None);
+        let s = compute(
+            DeviceStatus::Offline,
+            None,
+            None,
+            None,
+            None,
+            AlertLoad::default(),
+            0.0,
+        );
         assert_eq!(s.value, 0);
         assert_eq!(s.band, HealthBand::Critical);
     }
 
     #[test]
     fn unknown_with_no_signal_is_unknown() {
-        let s = compute(DeviceStatus::Unknown, None, None, None, None);
+        let s = compute(
+            DeviceStatus::Unknown,
+            None,
+            None,
+            None,
+            None,
+            AlertLoad::default(),
+            0.0,
+        );
         assert_eq!(s.band, HealthBand::Unknown);
         assert_eq!(s.label(), "health —");
     }
@@ -242,7 +304,15 @@ mod tests {
     #[test]
     fn degraded_and_crit_saturation_stacks_into_critical() {
         // Degraded (-40) + crit health-state (-50) = 10 → Critical.
-        let s = compute(DeviceStatus::Degraded, Some("crit"), None, None, None);
+        let s = compute(
+            DeviceStatus::Degraded,
+            Some("crit"),
+            None,
+            None,
+            None,
+            AlertLoad::default(),
+            0.0,
+        );
         assert_eq!(s.value, 10);
         assert_eq!(s.band, HealthBand::Critical);
     }
@@ -250,7 +320,15 @@ mod tests {
     #[test]
     fn warn_saturation_lands_in_degraded_band() {
         // Online, warn (-25) → 75 healthy; add a failing log unit to cross down.
-        let s = compute(DeviceStatus::Online, Some("warn"), None, Some(2.0), None);
+        let s = compute(
+            DeviceStatus::Online,
+            Some("warn"),
+            None,
+            Some(2.0),
+            None,
+            AlertLoad::default(),
+            0.0,
+        );
         // 100 - 25 - (10 + 2*10=30) = 45 → Degraded.
         assert_eq!(s.value, 45);
         assert_eq!(s.band, HealthBand::Degraded);
@@ -259,11 +337,115 @@ mod tests {
     #[test]
     fn numeric_saturation_used_when_no_categorical_state() {
         // No health_state → fall back to saturation 80 → -40 → 60 Degraded.
-        let s = compute(DeviceStatus::Online, None, Some(80.0), None, None);
+        let s = compute(
+            DeviceStatus::Online,
+            None,
+            Some(80.0),
+            None,
+            None,
+            AlertLoad::default(),
+            0.0,
+        );
         assert_eq!(s.value, 60);
         assert_eq!(s.band, HealthBand::Degraded);
     }
 
+    #[test]
+    fn critical_alert_and_stale_data_score_below_healthy() {
+        let healthy = compute(
+            DeviceStatus::Online,
+            Some("ok"),
+            None,
+            None,
+            None,
+            AlertLoad::default(),
+            0.0,
+        );
+        let mut alerts = AlertLoad::default();
+        alerts.add(Severity::Critical);
+        alerts.add(Severity::Info);
+        // Online, critical alert (-40), half the metrics stale (-15) = 45.
+        let troubled = compute(
+            DeviceStatus::Online,
+            Some("ok"),
+            None,
+            None,
+            None,
+            alerts,
+            0.5,
+        );
+        assert_eq!(troubled.value, 45);
+        assert_eq!(troubled.band, HealthBand::Degraded);
+        assert!(troubled.value < healthy.value);
+        assert!(troubled.triage_cmp(&healthy).is_lt());
+
+        // An alert is a signal even while liveness is unknown.
+        let s = compute(DeviceStatus::Unknown, None, None, None, None, alerts, 0.0);
+        assert_eq!(s.value, 60);
+    }
+
+    #[test]
+    fn device_score_reads_alerts_and_metric_freshness() {
+        use crate::message::DeviceId;
+        use zensight_common::{Protocol, TelemetryPoint};
+
+        let mut d = DeviceState::new(DeviceId::new(Protocol::Sysinfo, "h"));
+        d.update_from_liveness(DeviceStatus::Online, 0, None);
+        for (metric, ts) in [("cpu/usage", 100_000), ("disk/usage", 1_000)] {
+            let mut p =
+                TelemetryPoint::new("h", Protocol::Sysinfo, metric, TelemetryValue::Gauge(1.0));
+            p.timestamp = ts;
+            d.metrics.insert(metric.to_string(), p);
+            d.note_repeat(metric);
+        }
+        d.last_update = 100_000;
+        assert_eq!(score_device(&d).value, 100);
+
+        // disk/usage hasn't reported for 99s against a 30s threshold.
+        d.update_health(100_000, 30_000);
+        assert_eq!(d.stale_fraction(), 0.5);
+        assert_eq!(score_device(&d).value, 85);
+
+        d.alert_load.add(Severity::Warning);
+        assert_eq!(score_device(&d).value, 70);
+
+        // Silent past the freshness window, disk/usage stops counting.
+        d.metrics.get_mut("cpu/usage").unwrap().timestamp = 1_000_000;
+        d.update_health(1_000_000, 30_000);
+        assert_eq!(d.stale_fraction(), 0.0);
+    }
+
+    #[test]
+    fn churned_one_shot_metrics_do_not_penalize_online_device() {
+        use crate::message::DeviceId;
+        use zensight_common::{Protocol, TelemetryPoint};
+
+        let mut d = DeviceState::new(DeviceId::new(Protocol::Netflow, "exporter"));
+        d.update_from_liveness(DeviceStatus::Online, 0, None);
+        let mut insert = |metric: String, ts: i64| {
+            let mut p = TelemetryPoint::new(
+                "exporter",
+                Protocol::Netflow,
+                metric.as_str(),
+                TelemetryValue::Counter(1),
+            );
+            p.timestamp = ts;
+            d.metrics.insert(metric, p);
+        };
+        // One flow metric per src/dst/proto, each reported once and gone.
+        for i in 0..50 {
+            insert(format!("flow/10.0.0.{i}/10.0.1.1/tcp/bytes"), 60_000 + i);
+        }
+        // The exporter's own counter keeps its cadence.
+        insert("exporter/packets".to_string(), 100_000);
+        d.note_repeat("exporter/packets");
+        d.last_update = 100_000;
+
+        d.update_health(100_000, 30_000);
+        assert_eq!(d.stale_fraction(), 0.0);
+        assert_eq!(score_device(&d).value, 100);
+    }
+
     /// #128: a host's composite score is the worst of its facets; an empty host
     /// or all-Unknown facets score Unknown.
     #[test]
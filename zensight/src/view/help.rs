@@ -1,8 +1,11 @@
 //! Keyboard-shortcuts help overlay (#28).
 //!
-//! A small, centered cheat-sheet of the app's global shortcuts, toggled with
-//! `?` (and dismissed with `?` again, `Esc`, or the Close button). Mirrors the
+//! A small, centered cheat-sheet of the app's shortcuts, toggled with `?` (and
+//! dismissed with `?` again, `Esc`, or the Close button). Mirrors the
 //! global-search overlay's card styling so the two feel consistent.
+//!
+//! [`SHORTCUTS`] is the single registry the overlay renders from, grouped by
+//! the context a binding applies in (#401).
 
 use iced::widget::{Column, column, container, row, text};
 use iced::{Element, Length};
@@ -10,48 +13,178 @@ use iced::{Element, Length};
 use crate::message::Message;
 use crate::view::tokens::{font, space};
 
-/// The shortcuts shown in the overlay, as `(keys, description)` rows. Kept here
-/// next to the overlay so the cheat-sheet and the real bindings live together;
-/// the bindings themselves are in `subscription::keyboard_subscription`.
-const SHORTCUTS: &[(&str, &str)] = &[
-    ("?", "Toggle this help"),
-    ("Ctrl + P", "Open the command palette"),
-    ("Ctrl + K", "Search metrics across all devices"),
-    ("Ctrl + F", "Focus the device search"),
-    ("Esc", "Close dialog / go back"),
-    ("+ / -", "Zoom a chart in / out (when focused)"),
-    ("← / →", "Pan a focused chart"),
-    ("Home", "Reset a focused chart's view"),
+/// Where a shortcut applies, used to group the overlay (#401).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShortcutContext {
+    /// Anywhere in the app (`subscription::keyboard_subscription`).
+    Global,
+    /// A focused metric chart (`chart::Chart`).
+    Chart,
+    /// The focused topology graph (`topology::graph`).
+    Topology,
+}
+
+impl ShortcutContext {
+    /// All contexts, in overlay order.
+    pub const ALL: [ShortcutContext; 3] = [
+        ShortcutContext::Global,
+        ShortcutContext::Chart,
+        ShortcutContext::Topology,
+    ];
+
+    /// Section heading in the overlay.
+    pub fn title(self) -> &'static str {
+        match self {
+            ShortcutContext::Global => "Navigation",
+            ShortcutContext::Chart => "Charts",
+            ShortcutContext::Topology => "Topology",
+        }
+    }
+}
+
+/// One row of the cheat-sheet.
+#[derive(Debug, Clone, Copy)]
+pub struct Shortcut {
+    /// Section the row is listed under.
+    pub context: ShortcutContext,
+    /// Key combination as displayed, e.g. `"Ctrl + K"`.
+    pub keys: &'static str,
+    /// What the binding does.
+    pub description: &'static str,
+}
+
+const fn shortcut(
+    context: ShortcutContext,
+    keys: &'static str,
+    description: &'static str,
+) -> Shortcut {
+    Shortcut {
+        context,
+        keys,
+        description,
+    }
+}
+
+/// Every shortcut the app binds. The bindings themselves live with their
+/// handlers (`keyboard_subscription`, the chart and topology canvases); add a
+/// row here whenever one of those gains a key.
+pub const SHORTCUTS: &[Shortcut] = &[
+    shortcut(ShortcutContext::Global, "?", "Toggle this help"),
+    shortcut(
+        ShortcutContext::Global,
+        "Ctrl + P",
+        "Open the command palette",
+    ),
+    shortcut(
+        ShortcutContext::Global,
+        "Ctrl + K",
+        "Search metrics across all devices",
+    ),
+    shortcut(
+        ShortcutContext::Global,
+        "Ctrl + F",
+        "Focus the device search",
+    ),
+    shortcut(ShortcutContext::Global, "Esc", "Close dialog / go back"),
+    shortcut(ShortcutContext::Chart, "+ / -", "Zoom in / out"),
+    shortcut(ShortcutContext::Chart, "0", "Reset zoom"),
+    shortcut(ShortcutContext::Chart, "← / →", "Pan left / right"),
+    shortcut(ShortcutContext::Chart, "Home", "Reset pan"),
+    shortcut(
+        ShortcutContext::Chart,
+        "Ctrl + Scroll",
+        "Zoom at the cursor",
+    ),
+    shortcut(ShortcutContext::Topology, "+ / -", "Zoom in / out"),
+    shortcut(ShortcutContext::Topology, "0", "Reset zoom"),
+    shortcut(ShortcutContext::Topology, "Esc", "Clear selection"),
 ];
 
+/// Shortcuts for one context, in registry order.
+pub fn shortcuts_for(context: ShortcutContext) -> impl Iterator<Item = &'static Shortcut> {
+    SHORTCUTS.iter().filter(move |s| s.context == context)
+}
+
 /// Render the centered keyboard-shortcuts overlay card.
 pub fn help_overlay<'a>() -> Element<'a, Message> {
     let header = row![
         text("Keyboard Shortcuts").size(font::SECTION),
         container(text("")).width(Length::Fill),
         iced_anim::widget::button(text("Close").size(font::CAPTION))
-            .on_press(Message::ToggleHelp)
+            .on_press(Message::ToggleHelpOverlay)
             .padding([space::XS, space::SM])
             .style(iced::widget::button::secondary),
     ]
     .align_y(iced::Alignment::Center)
     .spacing(space::SM);
 
-    let mut rows = Column::new().spacing(space::XS);
-    for (keys, desc) in SHORTCUTS {
-        rows = rows.push(
-            row![
-                container(text(*keys).size(font::CAPTION).font(iced::Font::MONOSPACE))
+    let mut sections = Column::new().spacing(space::SM);
+    for context in ShortcutContext::ALL {
+        let mut rows = Column::new()
+            .spacing(space::XS)
+            .push(text(context.title()).size(font::BODY));
+        for shortcut in shortcuts_for(context) {
+            rows = rows.push(
+                row![
+                    container(
+                        text(shortcut.keys)
+                            .size(font::CAPTION)
+                            .font(iced::Font::MONOSPACE)
+                    )
                     .width(Length::Fixed(110.0)),
-                text(*desc).size(font::CAPTION),
-            ]
+                    text(shortcut.description).size(font::CAPTION),
+                ]
+                .spacing(space::SM)
+                .align_y(iced::Alignment::Center),
+            );
+        }
+        sections = sections.push(rows);
+    }
+
+    container(
+        column![header, sections]
             .spacing(space::SM)
-            .align_y(iced::Alignment::Center),
-        );
+            .padding(space::MD),
+    )
+    .width(Length::Fixed(420.0))
+    .style(iced::widget::container::rounded_box)
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn has(context: ShortcutContext, keys: &str) -> bool {
+        shortcuts_for(context).any(|s| s.keys == keys)
     }
 
-    container(column![header, rows].spacing(space::SM).padding(space::MD))
-        .width(Length::Fixed(420.0))
-        .style(iced::widget::container::rounded_box)
-        .into()
+    #[test]
+    fn registry_covers_chart_and_navigation_bindings() {
+        for keys in ["?", "Ctrl + P", "Ctrl + K", "Ctrl + F", "Esc"] {
+            assert!(has(ShortcutContext::Global, keys), "missing global {keys}");
+        }
+        for keys in ["+ / -", "0", "← / →", "Home"] {
+            assert!(has(ShortcutContext::Chart, keys), "missing chart {keys}");
+        }
+        for keys in ["+ / -", "0", "Esc"] {
+            assert!(
+                has(ShortcutContext::Topology, keys),
+                "missing topology {keys}"
+            );
+        }
+        // Every context has a section, and every row belongs to one.
+        assert!(
+            ShortcutContext::ALL
+                .iter()
+                .all(|&c| shortcuts_for(c).next().is_some())
+        );
+        assert_eq!(
+            ShortcutContext::ALL
+                .iter()
+                .map(|&c| shortcuts_for(c).count())
+                .sum::<usize>(),
+            SHORTCUTS.len()
+        );
+    }
 }
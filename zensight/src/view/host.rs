@@ -63,7 +63,8 @@ impl<'a> Host<'a> {
     }
 }
 
-/// Group device refs by `source` into facet-sorted, problem-first hosts (#128).
+/// Group device refs by `source` into facet-sorted hosts, worst health first
+/// (#128, #490).
 /// Pure + testable — feeds both the host-card grid and the fleet rollups.
 pub fn aggregate<'a>(devices: &[&'a DeviceState]) -> Vec<Host<'a>> {
     let mut by_source: BTreeMap<&str, Vec<&DeviceState>> = BTreeMap::new();
@@ -71,7 +72,7 @@ pub fn aggregate<'a>(devices: &[&'a DeviceState]) -> Vec<Host<'a>> {
         by_source.entry(d.id.source.as_str()).or_default().push(d);
     }
 
-    let mut hosts: Vec<Host<'a>> = by_source
+    let hosts: Vec<Host<'a>> = by_source
         .into_iter()
         .map(|(source, mut facets)| {
             // Primary-first: identity priority, then protocol for stability.
@@ -84,13 +85,12 @@ pub fn aggregate<'a>(devices: &[&'a DeviceState]) -> Vec<Host<'a>> {
         })
         .collect();
 
-    // Problem-first ordering, then host name (matches the device grid's #34 sort).
-    hosts.sort_by(|a, b| {
-        status_rank(a.effective_status())
-            .cmp(&status_rank(b.effective_status()))
-            .then(a.source.cmp(b.source))
-    });
-    hosts
+    // Worst health first (#490), then host name. Scores once per host rather
+    // than per comparison.
+    let mut scored: Vec<(HealthScore, Host<'a>)> =
+        hosts.into_iter().map(|h| (h.health(), h)).collect();
+    scored.sort_by(|(sa, a), (sb, b)| sa.triage_cmp(sb).then(a.source.cmp(b.source)));
+    scored.into_iter().map(|(_, h)| h).collect()
 }
 
 #[cfg(test)]
@@ -134,4 +134,19 @@ mod tests {
         assert_eq!(hosts[0].effective_status(), DeviceStatus::Offline);
         assert_eq!(hosts[1].source, "good");
     }
+
+    #[test]
+    fn hosts_sort_worst_health_first() {
+        let calm = facet(Protocol::Sysinfo, "a-calm", DeviceStatus::Online);
+        let mut alerting = facet(Protocol::Sysinfo, "b-alerting", DeviceStatus::Online);
+        alerting
+            .alert_load
+            .add(crate::view::alerts::Severity::Critical);
+        let devices = vec![&calm, &alerting];
+
+        // Both online, but the critical alert drags "b-alerting" to the top.
+        let hosts = aggregate(&devices);
+        assert_eq!(hosts[0].source, "b-alerting");
+        assert_eq!(hosts[1].source, "a-calm");
+    }
 }
@@ -200,6 +200,27 @@ pub fn disconnected<Message: 'static>(size: IconSize) -> Element<'static, Messag
     svg_icon(include_bytes!("disconnected.svg"), size)
 }
 
+/// Reconnecting icon (amber circular arrows) that pulses while a connection
+/// attempt is in flight (#427). Flip `bright` on each tick; the opacity eases
+/// between the two levels rather than blinking.
+pub fn reconnecting<Message: Clone + 'static>(
+    size: IconSize,
+    bright: bool,
+) -> Element<'static, Message> {
+    let target: f32 = if bright { 1.0 } else { 0.35 };
+    iced_anim::AnimationBuilder::new(target, move |opacity| {
+        Svg::new(Handle::from_memory(
+            include_bytes!("reconnecting.svg").as_slice(),
+        ))
+        .width(Length::Fixed(size.pixels()))
+        .height(Length::Fixed(size.pixels()))
+        .opacity(opacity)
+        .into()
+    })
+    .animation(iced_anim::Easing::EASE_IN_OUT.slow())
+    .into()
+}
+
 // ============================================================================
 // Protocol Icons
 // ============================================================================
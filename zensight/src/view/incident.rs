@@ -23,7 +23,7 @@ use zensight_common::{Alert as SensorAlert, AlertSeverity, AlertState, Protocol}
 use crate::message::{DeviceId, Message};
 use crate::view::alerts::{AlertsState, Severity};
 use crate::view::components::{badge, card, empty_state, section_header};
-use crate::view::formatting::format_timestamp;
+use crate::view::formatting::format_relative_time;
 use crate::view::theme;
 use crate::view::tokens::{font, space};
 
@@ -214,7 +214,7 @@ fn render_incident<'a>(inc: &Incident, state: &IncidentsState) -> Element<'a, Me
         text(format!(
             "{} alert(s) · started {}",
             inc.alert_keys.len(),
-            format_timestamp(inc.started)
+            format_relative_time(inc.started)
         ))
         .size(font::CAPTION)
         .style(dim),
@@ -256,7 +256,7 @@ fn render_timeline<'a>(inc: &Incident) -> Element<'a, Message> {
         };
         col = col.push(
             row![
-                text(format_timestamp(ev.at))
+                text(format_relative_time(ev.at))
                     .size(font::CAPTION)
                     .width(Length::Fixed(90.0))
                     .style(dim),
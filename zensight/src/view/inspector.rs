@@ -0,0 +1,135 @@
+//! Raw-payload inspector (#414).
+//!
+//! When a metric renders oddly, the fastest check is the point itself. With
+//! the inspector enabled in settings, the device view gains a panel showing,
+//! for the selected metric (or every metric, capped), the key expression the
+//! point is published on and the full decoded [`TelemetryPoint`] as
+//! pretty-printed JSON — labels, timestamp and all.
+
+use iced::widget::{Column, column, container, rule, scrollable, text};
+use iced::{Element, Length, Theme};
+
+use zensight_common::{KeyExprBuilder, TelemetryPoint};
+
+use crate::message::Message;
+use crate::view::device::DeviceDetailState;
+use crate::view::theme;
+use crate::view::tokens::{font, space};
+
+/// Most points shown when no metric is selected, so a device with thousands
+/// of metrics doesn't build thousands of text blocks.
+const MAX_INSPECTED: usize = 50;
+
+/// The key expression `point` is published on.
+pub fn point_key_expr(point: &TelemetryPoint) -> String {
+    KeyExprBuilder::new(point.protocol).build(&point.source, &point.metric)
+}
+
+/// Pretty-printed JSON of `point`, exactly as it decodes off the wire.
+pub fn format_point_json(point: &TelemetryPoint) -> String {
+    serde_json::to_string_pretty(point)
+        .unwrap_or_else(|e| format!("<failed to serialize point: {e}>"))
+}
+
+/// Render the inspector panel for `state`'s device.
+pub fn inspector_panel(state: &DeviceDetailState) -> Element<'_, Message> {
+    let points: Vec<&TelemetryPoint> = match state
+        .selected_metric
+        .as_ref()
+        .and_then(|m| state.metrics.get(m))
+    {
+        Some(point) => vec![point],
+        None => {
+            let mut all: Vec<&TelemetryPoint> = state.metrics.values().collect();
+            all.sort_by(|a, b| a.metric.cmp(&b.metric));
+            all
+        }
+    };
+
+    let dimmed = |theme: &Theme| text::Style {
+        color: Some(theme::colors(theme).text_dimmed()),
+    };
+
+    let mut entries = Column::new().spacing(space::MD);
+    for point in points.iter().take(MAX_INSPECTED) {
+        entries = entries.push(
+            column![
+                text(point_key_expr(point))
+                    .size(font::CAPTION)
+                    .font(iced::Font::MONOSPACE)
+                    .style(dimmed),
+                text(format_point_json(point))
+                    .size(font::CAPTION)
+                    .font(iced::Font::MONOSPACE),
+            ]
+            .spacing(space::XS),
+        );
+    }
+    if points.len() > MAX_INSPECTED {
+        entries = entries.push(
+            text(format!(
+                "… {} more; select a metric to inspect it",
+                points.len() - MAX_INSPECTED
+            ))
+            .size(font::CAPTION)
+            .style(dimmed),
+        );
+    }
+
+    let title = text(match points.len() {
+        1 => "Raw payload".to_string(),
+        n => format!("Raw payloads ({n})"),
+    })
+    .size(font::EMPHASIS);
+
+    container(
+        column![
+            title,
+            rule::horizontal(1),
+            scrollable(entries).height(Length::Fill)
+        ]
+        .spacing(space::SM),
+    )
+    .padding(space::MD)
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use zensight_common::{Protocol, TelemetryValue};
+
+    use super::*;
+
+    #[test]
+    fn test_format_point_json_with_labels() {
+        let mut labels = HashMap::new();
+        labels.insert("ifName".to_string(), "eth0".to_string());
+        let point = TelemetryPoint {
+            timestamp: 1_700_000_000_000,
+            source: "router01".to_string(),
+            protocol: Protocol::Snmp,
+            metric: "if/1/ifInOctets".to_string(),
+            value: TelemetryValue::Counter(42),
+            labels,
+        };
+
+        let json = format_point_json(&point);
+        // Pretty-printed: one field per line, indented.
+        assert!(json.starts_with("{\n  "));
+        assert!(json.contains("\"timestamp\": 1700000000000"));
+        assert!(json.contains("\"ifName\": \"eth0\""));
+
+        let back: TelemetryPoint = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.labels["ifName"], "eth0");
+        assert_eq!(back.metric, point.metric);
+
+        assert_eq!(
+            point_key_expr(&point),
+            "zensight/snmp/router01/if/1/ifInOctets"
+        );
+    }
+}
@@ -18,7 +18,7 @@ use zensight_common::{AssetRecord, Ja4hRecord, QuicRecord, SshRecord, TlsRecord}
 
 use crate::message::Message;
 use crate::view::components::{card, empty_state, section_header};
-use crate::view::formatting::format_timestamp;
+use crate::view::formatting::format_relative_time;
 use crate::view::theme;
 use crate::view::tokens::{font, space};
 
@@ -372,7 +372,7 @@ fn render_assets(state: &InventoryState) -> Element<'_, Message> {
                 cell(r.platform.as_deref().unwrap_or("-"), 150),
                 cell(&join_or_dash(&r.capabilities), 120),
                 cell(&join_or_dash(&r.seen_via), 100),
-                cell(&format_timestamp(r.last_seen), 90),
+                cell(&format_relative_time(r.last_seen), 90),
             ]
             .spacing(8),
         );
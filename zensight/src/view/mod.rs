@@ -17,6 +17,7 @@ pub mod help;
 pub mod host;
 pub mod icons;
 pub mod incident;
+pub mod inspector;
 pub mod inventory;
 pub mod overview;
 pub mod palette;
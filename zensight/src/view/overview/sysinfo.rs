@@ -10,6 +10,7 @@ use zensight_common::TelemetryValue;
 use crate::message::{DeviceId, Message};
 use crate::view::components::{StatusLed, StatusLedState, empty_state};
 use crate::view::dashboard::DeviceState;
+use crate::view::formatting::format_rate;
 use crate::view::theme;
 
 /// Host resource summary.
@@ -18,6 +19,10 @@ struct HostSummary {
     cpu_usage: Option<f64>,
     memory_used: Option<f64>,
     memory_total: Option<f64>,
+    /// Receive throughput across interfaces (bytes/sec, #439).
+    net_rx: Option<f64>,
+    /// Transmit throughput across interfaces (bytes/sec, #439).
+    net_tx: Option<f64>,
     is_healthy: bool,
 }
 
@@ -60,6 +65,8 @@ pub fn sysinfo_overview<'a>(devices: &HashMap<&DeviceId, &DeviceState>) -> Eleme
         render_stat("Avg Memory", format!("{:.1}%", avg_mem)),
         render_alert_stat("High CPU (>80%)", high_cpu_count),
         render_alert_stat("High Memory (>80%)", high_mem_count),
+        render_stat("Net RX", fleet_rate(&summaries, |s| s.net_rx)),
+        render_stat("Net TX", fleet_rate(&summaries, |s| s.net_tx)),
     ]
     .spacing(30)
     .align_y(Alignment::Center);
@@ -84,10 +91,25 @@ fn extract_host_summary(id: &DeviceId, state: &DeviceState) -> HostSummary {
         cpu_usage,
         memory_used,
         memory_total,
+        net_rx: state.network_rate("/rx_bytes"),
+        net_tx: state.network_rate("/tx_bytes"),
         is_healthy: state.is_healthy,
     }
 }
 
+/// Total fleet throughput, or "-" until some host has a rate.
+fn fleet_rate(
+    summaries: &[HostSummary],
+    extractor: impl Fn(&HostSummary) -> Option<f64>,
+) -> String {
+    let rates: Vec<f64> = summaries.iter().filter_map(extractor).collect();
+    if rates.is_empty() {
+        "-".to_string()
+    } else {
+        format_rate(rates.iter().sum())
+    }
+}
+
 /// Get a numeric metric value from device state.
 fn get_metric_value(state: &DeviceState, metric: &str) -> Option<f64> {
     state
@@ -256,7 +278,7 @@ fn render_mini_bar<'a>(value: Option<f64>, _label: &str) -> Element<'a, Message>
     } else if pct > 80.0 {
         theme::SEVERITY_WARNING
     } else {
-        theme::STATUS_ONLINE
+        theme::palette().online
     };
 
     let filled_bar = container(text(""))
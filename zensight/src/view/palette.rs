@@ -107,7 +107,7 @@ pub fn commands() -> Vec<Command> {
         },
         Command {
             label: "Keyboard shortcuts help",
-            message: Message::ToggleHelp,
+            message: Message::ToggleHelpOverlay,
         },
         Command {
             label: "Clear triggered alerts",
@@ -121,6 +121,34 @@ pub fn commands() -> Vec<Command> {
             label: "Export device data (JSON)",
             message: Message::ExportToJson,
         },
+        Command {
+            label: "Export device inventory (CSV)",
+            message: Message::ExportInventory(crate::device_inventory::InventoryFormat::Csv),
+        },
+        Command {
+            label: "Export device inventory (JSON)",
+            message: Message::ExportInventory(crate::device_inventory::InventoryFormat::Json),
+        },
+        Command {
+            label: "Import telemetry CSV",
+            message: Message::PickTelemetryCsv,
+        },
+        Command {
+            label: "Export alert rules",
+            message: Message::ExportAlertRules,
+        },
+        Command {
+            label: "Import alert rules (merge)",
+            message: Message::PickAlertRules(crate::view::alerts::RuleImportMode::Merge),
+        },
+        Command {
+            label: "Export dashboard snapshot",
+            message: Message::ExportSnapshot,
+        },
+        Command {
+            label: "Open dashboard snapshot (read-only)",
+            message: Message::PickSnapshot,
+        },
     ]
 }
 
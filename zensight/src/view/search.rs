@@ -35,6 +35,10 @@ fn value_label(value: &zensight_common::TelemetryValue) -> String {
         TelemetryValue::Boolean(b) => b.to_string(),
         TelemetryValue::Text(s) => s.clone(),
         TelemetryValue::Binary(b) => format!("{} bytes", b.len()),
+        TelemetryValue::Bytes(b) => crate::view::formatting::format_hex_preview(b),
+        TelemetryValue::Histogram { sum, count, .. } => {
+            crate::view::formatting::format_histogram(*sum, *count)
+        }
     }
 }
 
@@ -192,8 +196,8 @@ pub fn search<'a>(devices: impl Iterator<Item = &'a DeviceState>, query: &str) -
 }
 
 /// Render the global search panel: an input + a results list. Clicking a result
-/// selects its device. Built from a precomputed `hits` slice so the view stays
-/// pure of the device map's lifetime.
+/// opens its device with the metric charted (#438). Built from a precomputed
+/// `hits` slice so the view stays pure of the device map's lifetime.
 pub fn global_search_panel<'a>(
     state: &'a GlobalSearchState,
     hits: Vec<SearchHit>,
@@ -229,12 +233,25 @@ pub fn global_search_panel<'a>(
             "{}/{} · {} = {}",
             hit.device.protocol, hit.device.source, hit.metric, hit.value
         );
+        // #391: "Split" opens the hit beside the current device for comparison.
+        let split = button(text("Split").size(font::CAPTION))
+            .on_press(Message::OpenDeviceInSplit(hit.device.clone()))
+            .padding([space::XS, space::SM])
+            .style(iced::widget::button::secondary);
         list = list.push(
-            button(text(label).size(font::CAPTION))
-                .on_press(Message::SelectDevice(hit.device))
-                .width(Length::Fill)
-                .padding([space::XS, space::SM])
-                .style(iced::widget::button::text),
+            row![
+                button(text(label).size(font::CAPTION))
+                    .on_press(Message::SelectDeviceMetric {
+                        device: hit.device,
+                        metric: hit.metric,
+                    })
+                    .width(Length::Fill)
+                    .padding([space::XS, space::SM])
+                    .style(iced::widget::button::text),
+                split,
+            ]
+            .align_y(iced::Alignment::Center)
+            .spacing(space::XS),
         );
     }
 
@@ -301,6 +318,37 @@ mod tests {
         assert!(metrics.contains(&"input/queue_len"));
     }
 
+    #[test]
+    fn metric_name_finds_every_device_carrying_it() {
+        let a = dev(
+            "r1",
+            Protocol::Snmp,
+            &[("if/1/ifInErrors", 3.0), ("cpu", 1.0)],
+        );
+        let b = dev("r2", Protocol::Snmp, &[("if/1/ifInErrors", 7.0)]);
+        let c = dev("h1", Protocol::Sysinfo, &[("mem/used", 2.0)]);
+        let hits = search([&a, &b, &c].into_iter(), "ifinerrors");
+
+        let found: Vec<(&str, &str, &str)> = hits
+            .iter()
+            .map(|h| {
+                (
+                    h.device.source.as_str(),
+                    h.metric.as_str(),
+                    h.value.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("r1", "if/1/ifInErrors", "3"),
+                ("r2", "if/1/ifInErrors", "7")
+            ]
+        );
+        assert!(hits.iter().all(|h| h.device.protocol == Protocol::Snmp));
+    }
+
     #[test]
     fn matches_on_source_and_protocol() {
         let a = dev("router01", Protocol::Snmp, &[("cpu", 1.0)]);
@@ -1,6 +1,6 @@
 //! Sensors view — surfaces sensor health that was previously collected into app
 //! state (`sensor_health`) but never shown. One card per sensor with a health
-//! badge, device counts, last-poll latency, error rate, and throughput.
+//! badge, device counts, last-poll latency, error rate, throughput, and restarts.
 //!
 //! See docs/plans/gui/04-features-and-stubs.md (F2).
 
@@ -15,7 +15,7 @@ use crate::message::Message;
 use crate::view::blob_fetch::{BlobFetch, download_section};
 use crate::view::components::{card, empty_state, section_header};
 use crate::view::dir_fetch::{DirFetch, dir_section};
-use crate::view::formatting::format_timestamp;
+use crate::view::formatting::{format_duration, format_relative_time};
 use crate::view::theme;
 use crate::view::tokens::{font, space};
 
@@ -84,13 +84,28 @@ fn sensor_card<'a>(
         stat("Last poll", format!("{} ms", snap.last_poll_duration_ms)),
         stat("Errors/hr", format!("{}", snap.errors_last_hour)),
         stat("Metrics", format!("{}", snap.metrics_published)),
-        stat("Uptime", human_uptime(snap.uptime_secs)),
+        stat("Uptime", format_duration(snap.uptime_secs as i64 * 1000)),
+        stat("Restarts", format!("{}", snap.restart_count)),
     ]
     .spacing(space::LG)
     .align_y(Alignment::Center);
 
     let mut col = column![header, stats].spacing(space::SM);
 
+    // A bridge systemd keeps restarting looks healthy between crashes (#435).
+    if snap.is_crash_looping() {
+        col = col.push(
+            text(format!(
+                "Crash-looping: {} restarts in the last hour",
+                snap.restarts_last_hour
+            ))
+            .size(font::CAPTION)
+            .style(|theme: &Theme| text::Style {
+                color: Some(theme::colors(theme).danger()),
+            }),
+        );
+    }
+
     // Debug-report download control (#197). The key prefix is `zensight/<sensor>`.
     let key_prefix = format!("zensight/{}", snap.sensor);
     col = col.push(download_section(blob_fetch, &key_prefix, active_prefix));
@@ -111,7 +126,7 @@ fn sensor_card<'a>(
                 }),
         );
         for report in errors.iter().rev().take(5) {
-            let when = format_timestamp(report.timestamp);
+            let when = format_relative_time(report.timestamp);
             let dev = report.device.as_deref().unwrap_or("-");
             let line = format!(
                 "{when}  [{:?}] {dev}: {}",
@@ -175,29 +190,3 @@ fn stat<'a>(label: &'a str, value: String) -> Element<'a, Message> {
     .spacing(2)
     .into()
 }
-
-/// Compact uptime: `42s` / `12m` / `3h` / `2d`.
-fn human_uptime(secs: u64) -> String {
-    if secs < 60 {
-        format!("{secs}s")
-    } else if secs < 3600 {
-        format!("{}m", secs / 60)
-    } else if secs < 86400 {
-        format!("{}h", secs / 3600)
-    } else {
-        format!("{}d", secs / 86400)
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn uptime_formats() {
-        assert_eq!(human_uptime(45), "45s");
-        assert_eq!(human_uptime(120), "2m");
-        assert_eq!(human_uptime(7200), "2h");
-        assert_eq!(human_uptime(172800), "2d");
-    }
-}
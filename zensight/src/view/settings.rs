@@ -1,5 +1,6 @@
 //! Settings view for application configuration.
 
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 use iced::widget::{Column, column, container, pick_list, row, rule, scrollable, text, text_input};
@@ -9,7 +10,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::app::CurrentView;
 use crate::message::Message;
+use crate::subscription::SubscriptionFilter;
 use crate::view::alerts::{AlertFilterPreset, AlertRule};
+use crate::view::formatting::FlowVolumeUnit;
 use crate::view::groups::GroupsState;
 use crate::view::icons::{self, IconSize};
 use zensight_common::Protocol;
@@ -23,21 +26,59 @@ pub struct PersistentSettings {
     pub zenoh_connect: Vec<String>,
     /// Zenoh endpoints to listen on.
     pub zenoh_listen: Vec<String>,
+    /// Discover peers by multicast scouting (#426).
+    #[serde(default = "default_true")]
+    pub zenoh_multicast_scouting: bool,
+    /// Learn of peers through gossip (#426).
+    #[serde(default = "default_true")]
+    pub zenoh_gossip_scouting: bool,
+    /// Deployment/tenant namespace (#446); empty for none.
+    #[serde(default)]
+    pub zenoh_namespace: String,
     /// Stale threshold in seconds.
     pub stale_threshold_secs: u64,
     /// Use dark theme (true) or light theme (false).
     #[serde(default = "default_dark_theme")]
     pub dark_theme: bool,
+    /// Draw charts and status indicators with the color-blind-safe palette
+    /// (#472).
+    #[serde(default)]
+    pub color_blind_palette: bool,
+    /// Unit for NetFlow and topology flow volumes (#477).
+    #[serde(default)]
+    pub flow_volume_unit: FlowVolumeUnit,
+    /// Show absolute times in UTC instead of local time (#440).
+    #[serde(default)]
+    pub utc_timestamps: bool,
     /// Show a desktop notification on CRITICAL alert firing transitions (#26).
     /// Off by default (opt-in) to avoid alert fatigue.
     #[serde(default)]
     pub desktop_notifications: bool,
+    /// Show the raw-payload inspector in the device view (#414).
+    #[serde(default)]
+    pub payload_inspector: bool,
+    /// Serve buffered history at `zensight/@gui/history/**` (#465). Off by
+    /// default (opt-in): it makes the frontend answer queries on the bus.
+    #[serde(default)]
+    pub serve_history_queries: bool,
+    /// Slow the UI tick while the window is unfocused (#470).
+    #[serde(default = "default_true")]
+    pub pause_when_unfocused: bool,
     /// Maximum number of metric history entries per device.
     #[serde(default = "default_max_history")]
     pub max_history: usize,
     /// Maximum number of alerts to keep.
     #[serde(default = "default_max_alerts")]
     pub max_alerts: usize,
+    /// Soft cap on tracked devices (#425).
+    #[serde(default = "default_max_devices")]
+    pub max_devices: usize,
+    /// Soft cap on metrics tracked per device (#425).
+    #[serde(default = "default_max_metrics_per_device")]
+    pub max_metrics_per_device: usize,
+    /// Most topology layout frames per second while it settles (#473).
+    #[serde(default = "default_topology_max_fps")]
+    pub topology_max_fps: u32,
     /// Device groups configuration.
     #[serde(default)]
     pub groups: GroupsState,
@@ -59,6 +100,16 @@ pub struct PersistentSettings {
     /// Last active view (Dashboard, Alerts, or Topology).
     #[serde(default)]
     pub current_view: CurrentView,
+    /// Pinned topology node positions keyed by node id (#400).
+    #[serde(default)]
+    pub topology_positions: BTreeMap<String, (f32, f32)>,
+    /// Telemetry subscription allowlist / denylist (#413).
+    #[serde(default)]
+    pub subscription_filter: SubscriptionFilter,
+    /// Metric each protocol's dashboard card leads its sparklines with (#420);
+    /// protocols not listed use the built-in choice.
+    #[serde(default)]
+    pub card_metrics: BTreeMap<Protocol, String>,
 }
 
 fn default_overview_expanded() -> bool {
@@ -69,6 +120,10 @@ fn default_dark_theme() -> bool {
     true
 }
 
+fn default_true() -> bool {
+    true
+}
+
 fn default_max_history() -> usize {
     500
 }
@@ -77,17 +132,41 @@ fn default_max_alerts() -> usize {
     100
 }
 
+fn default_max_devices() -> usize {
+    crate::view::dashboard::DEFAULT_MAX_DEVICES
+}
+
+fn default_max_metrics_per_device() -> usize {
+    crate::view::dashboard::DEFAULT_MAX_METRICS_PER_DEVICE
+}
+
+fn default_topology_max_fps() -> u32 {
+    crate::view::topology::layout::DEFAULT_MAX_FPS
+}
+
 impl Default for PersistentSettings {
     fn default() -> Self {
         Self {
             zenoh_mode: "peer".to_string(),
             zenoh_connect: vec![],
             zenoh_listen: vec![],
+            zenoh_multicast_scouting: true,
+            zenoh_gossip_scouting: true,
+            zenoh_namespace: String::new(),
             stale_threshold_secs: 120,
             dark_theme: true,
+            color_blind_palette: false,
+            flow_volume_unit: FlowVolumeUnit::BytesTotal,
+            utc_timestamps: false,
             desktop_notifications: false,
+            payload_inspector: false,
+            serve_history_queries: false,
+            pause_when_unfocused: true,
             max_history: default_max_history(),
             max_alerts: default_max_alerts(),
+            max_devices: default_max_devices(),
+            max_metrics_per_device: default_max_metrics_per_device(),
+            topology_max_fps: default_topology_max_fps(),
             groups: GroupsState::default(),
             alert_rules: Vec::new(),
             alert_filter_presets: Vec::new(),
@@ -95,6 +174,9 @@ impl Default for PersistentSettings {
             overview_selected_protocol: None,
             overview_expanded: default_overview_expanded(),
             current_view: CurrentView::default(),
+            topology_positions: BTreeMap::new(),
+            subscription_filter: SubscriptionFilter::default(),
+            card_metrics: BTreeMap::new(),
         }
     }
 }
@@ -176,6 +258,27 @@ impl PersistentSettings {
             self.max_alerts,
         );
         state.desktop_notifications = self.desktop_notifications;
+        state.color_blind_palette = self.color_blind_palette;
+        state.flow_volume_unit = self.flow_volume_unit;
+        state.utc_timestamps = self.utc_timestamps;
+        state.payload_inspector = self.payload_inspector;
+        state.serve_history_queries = self.serve_history_queries;
+        state.pause_when_unfocused = self.pause_when_unfocused;
+        state.multicast_scouting = self.zenoh_multicast_scouting;
+        state.gossip_scouting = self.zenoh_gossip_scouting;
+        state.namespace = self.zenoh_namespace.clone();
+        state.max_devices = self.max_devices.to_string();
+        state.max_metrics = self.max_metrics_per_device.to_string();
+        state.topology_max_fps = self.topology_max_fps.to_string();
+        state.subscribe_allow = self.subscription_filter.allow.join(", ");
+        state.subscribe_deny = self.subscription_filter.deny.join(", ");
+        state.subscribe_max_rate = self.subscription_filter.max_rate.to_string();
+        state.card_metrics = self
+            .card_metrics
+            .iter()
+            .map(|(protocol, metric)| format!("{protocol}={metric}"))
+            .collect::<Vec<_>>()
+            .join(", ");
         state
     }
 
@@ -186,11 +289,23 @@ impl PersistentSettings {
             zenoh_mode: state.zenoh_mode.as_str().to_string(),
             zenoh_connect: state.connect_endpoints(),
             zenoh_listen: state.listen_endpoints(),
+            zenoh_multicast_scouting: state.multicast_scouting,
+            zenoh_gossip_scouting: state.gossip_scouting,
+            zenoh_namespace: state.namespace_value().unwrap_or_default(),
             stale_threshold_secs: state.stale_threshold_secs.parse().unwrap_or(120),
             dark_theme: state.dark_theme,
+            color_blind_palette: state.color_blind_palette,
+            flow_volume_unit: state.flow_volume_unit,
+            utc_timestamps: state.utc_timestamps,
             desktop_notifications: state.desktop_notifications,
+            payload_inspector: state.payload_inspector,
+            serve_history_queries: state.serve_history_queries,
+            pause_when_unfocused: state.pause_when_unfocused,
             max_history: state.max_history.parse().unwrap_or(default_max_history()),
             max_alerts: state.max_alerts.parse().unwrap_or(default_max_alerts()),
+            max_devices: state.max_devices_value(),
+            max_metrics_per_device: state.max_metrics_value(),
+            topology_max_fps: state.topology_max_fps_value(),
             groups: GroupsState::default(),
             alert_rules: Vec::new(),
             alert_filter_presets: Vec::new(),
@@ -198,6 +313,9 @@ impl PersistentSettings {
             overview_selected_protocol: None,
             overview_expanded: default_overview_expanded(),
             current_view: CurrentView::default(),
+            topology_positions: BTreeMap::new(),
+            subscription_filter: state.subscription_filter(),
+            card_metrics: state.card_metrics_value(),
         }
     }
 }
@@ -211,16 +329,48 @@ pub struct SettingsState {
     pub zenoh_connect: String,
     /// Zenoh endpoints to listen on.
     pub zenoh_listen: String,
+    /// Discover peers by multicast scouting (#426).
+    pub multicast_scouting: bool,
+    /// Learn of peers through gossip (#426).
+    pub gossip_scouting: bool,
+    /// Deployment/tenant namespace (#446); empty for none.
+    pub namespace: String,
     /// Stale threshold in seconds (devices not updated are marked unhealthy).
     pub stale_threshold_secs: String,
     /// Use dark theme.
     pub dark_theme: bool,
+    /// Use the color-blind-safe palette (#472).
+    pub color_blind_palette: bool,
+    /// Unit for flow volumes (#477).
+    pub flow_volume_unit: FlowVolumeUnit,
+    /// Show absolute times in UTC (#440).
+    pub utc_timestamps: bool,
     /// Show a desktop notification on CRITICAL alert firing transitions (#26).
     pub desktop_notifications: bool,
+    /// Show the raw-payload inspector in the device view (#414).
+    pub payload_inspector: bool,
+    /// Serve buffered history over Zenoh queries (#465).
+    pub serve_history_queries: bool,
+    /// Slow the UI tick while the window is unfocused (#470).
+    pub pause_when_unfocused: bool,
     /// Maximum metric history entries per device.
     pub max_history: String,
     /// Maximum alerts to keep.
     pub max_alerts: String,
+    /// Soft cap on tracked devices (#425).
+    pub max_devices: String,
+    /// Soft cap on metrics per device.
+    pub max_metrics: String,
+    /// Topology layout frame cap (#473).
+    pub topology_max_fps: String,
+    /// Comma-separated protocols / key expressions to subscribe to (#413).
+    pub subscribe_allow: String,
+    /// Comma-separated protocols / key expressions to drop.
+    pub subscribe_deny: String,
+    /// Maximum updates per second per metric, 0 for no limit (#484).
+    pub subscribe_max_rate: String,
+    /// Comma-separated `protocol=metric` card sparkline choices (#420).
+    pub card_metrics: String,
     /// Whether settings have been modified.
     pub modified: bool,
     /// Last error message (if any).
@@ -235,11 +385,27 @@ impl Default for SettingsState {
             zenoh_mode: ZenohMode::Peer,
             zenoh_connect: String::new(),
             zenoh_listen: String::new(),
+            multicast_scouting: true,
+            gossip_scouting: true,
+            namespace: String::new(),
             stale_threshold_secs: "120".to_string(),
             dark_theme: true,
+            color_blind_palette: false,
+            flow_volume_unit: FlowVolumeUnit::BytesTotal,
+            utc_timestamps: false,
             desktop_notifications: false,
+            payload_inspector: false,
+            serve_history_queries: false,
+            pause_when_unfocused: true,
             max_history: "500".to_string(),
             max_alerts: "100".to_string(),
+            max_devices: default_max_devices().to_string(),
+            max_metrics: default_max_metrics_per_device().to_string(),
+            topology_max_fps: default_topology_max_fps().to_string(),
+            subscribe_allow: String::new(),
+            subscribe_deny: String::new(),
+            subscribe_max_rate: "0".to_string(),
+            card_metrics: String::new(),
             modified: false,
             error: None,
             success: None,
@@ -262,11 +428,27 @@ impl SettingsState {
             zenoh_mode: ZenohMode::parse(mode),
             zenoh_connect: connect.join(", "),
             zenoh_listen: listen.join(", "),
+            multicast_scouting: true,
+            gossip_scouting: true,
+            namespace: String::new(),
             stale_threshold_secs: (stale_threshold_ms / 1000).to_string(),
             dark_theme,
+            color_blind_palette: false,
+            flow_volume_unit: FlowVolumeUnit::BytesTotal,
+            utc_timestamps: false,
             desktop_notifications: false,
+            payload_inspector: false,
+            serve_history_queries: false,
+            pause_when_unfocused: true,
             max_history: max_history.to_string(),
             max_alerts: max_alerts.to_string(),
+            max_devices: default_max_devices().to_string(),
+            max_metrics: default_max_metrics_per_device().to_string(),
+            topology_max_fps: default_topology_max_fps().to_string(),
+            subscribe_allow: String::new(),
+            subscribe_deny: String::new(),
+            subscribe_max_rate: "0".to_string(),
+            card_metrics: String::new(),
             modified: false,
             error: None,
             success: None,
@@ -294,6 +476,27 @@ impl SettingsState {
         self.clear_messages();
     }
 
+    /// Update the namespace.
+    pub fn set_namespace(&mut self, namespace: String) {
+        self.namespace = namespace;
+        self.modified = true;
+        self.clear_messages();
+    }
+
+    /// Enable or disable multicast scouting.
+    pub fn set_multicast_scouting(&mut self, enabled: bool) {
+        self.multicast_scouting = enabled;
+        self.modified = true;
+        self.clear_messages();
+    }
+
+    /// Enable or disable gossip scouting.
+    pub fn set_gossip_scouting(&mut self, enabled: bool) {
+        self.gossip_scouting = enabled;
+        self.modified = true;
+        self.clear_messages();
+    }
+
     /// Update stale threshold.
     pub fn set_stale_threshold(&mut self, threshold: String) {
         self.stale_threshold_secs = threshold;
@@ -315,6 +518,55 @@ impl SettingsState {
         self.clear_messages();
     }
 
+    /// Update the tracked-device cap.
+    pub fn set_max_devices(&mut self, max_devices: String) {
+        self.max_devices = max_devices;
+        self.modified = true;
+        self.clear_messages();
+    }
+
+    /// Update the per-device metric cap.
+    pub fn set_max_metrics(&mut self, max_metrics: String) {
+        self.max_metrics = max_metrics;
+        self.modified = true;
+        self.clear_messages();
+    }
+
+    /// Update the topology layout frame cap.
+    pub fn set_topology_max_fps(&mut self, max_fps: String) {
+        self.topology_max_fps = max_fps;
+        self.modified = true;
+        self.clear_messages();
+    }
+
+    /// Update the subscription allowlist.
+    pub fn set_subscribe_allow(&mut self, allow: String) {
+        self.subscribe_allow = allow;
+        self.modified = true;
+        self.clear_messages();
+    }
+
+    /// Update the subscription denylist.
+    pub fn set_subscribe_deny(&mut self, deny: String) {
+        self.subscribe_deny = deny;
+        self.modified = true;
+        self.clear_messages();
+    }
+
+    /// Update the per-metric update rate cap.
+    pub fn set_subscribe_max_rate(&mut self, max_rate: String) {
+        self.subscribe_max_rate = max_rate;
+        self.modified = true;
+        self.clear_messages();
+    }
+
+    /// Update the per-protocol card sparkline metrics.
+    pub fn set_card_metrics(&mut self, card_metrics: String) {
+        self.card_metrics = card_metrics;
+        self.modified = true;
+        self.clear_messages();
+    }
+
     /// Validate the settings.
     pub fn validate(&self) -> Result<(), String> {
         // Validate stale threshold
@@ -344,6 +596,12 @@ impl SettingsState {
             }
         }
 
+        if let Some(namespace) = self.namespace_value()
+            && namespace.contains(['*', '$', '?', '#'])
+        {
+            return Err(format!("Namespace cannot contain wildcards: {}", namespace));
+        }
+
         // Validate max history
         let max_history: usize = self
             .max_history
@@ -372,6 +630,48 @@ impl SettingsState {
             return Err("Max alerts cannot exceed 1000".to_string());
         }
 
+        // Validate tracking caps
+        let max_devices: usize = self
+            .max_devices
+            .parse()
+            .map_err(|_| "Max devices must be a number".to_string())?;
+
+        if !(10..=1_000_000).contains(&max_devices) {
+            return Err("Max devices must be between 10 and 1000000".to_string());
+        }
+
+        let max_metrics: usize = self
+            .max_metrics
+            .parse()
+            .map_err(|_| "Max metrics per device must be a number".to_string())?;
+
+        if !(10..=1_000_000).contains(&max_metrics) {
+            return Err("Max metrics per device must be between 10 and 1000000".to_string());
+        }
+
+        let max_fps: u32 = self
+            .topology_max_fps
+            .trim()
+            .parse()
+            .map_err(|_| "Topology max FPS must be a number".to_string())?;
+
+        if !(1..=120).contains(&max_fps) {
+            return Err("Topology max FPS must be between 1 and 120".to_string());
+        }
+
+        let max_rate: u32 = self
+            .subscribe_max_rate
+            .trim()
+            .parse()
+            .map_err(|_| "Max updates per second must be a number".to_string())?;
+
+        if max_rate > 1000 {
+            return Err("Max updates per second cannot exceed 1000".to_string());
+        }
+
+        self.subscription_filter().validate()?;
+        self.parse_card_metrics()?;
+
         Ok(())
     }
 
@@ -394,6 +694,43 @@ impl SettingsState {
         self.parse_endpoints(&self.zenoh_listen)
     }
 
+    /// The namespace without surrounding slashes, `None` when empty.
+    pub fn namespace_value(&self) -> Option<String> {
+        Some(self.namespace.trim().trim_matches('/').to_string()).filter(|ns| !ns.is_empty())
+    }
+
+    /// Get the telemetry subscription filter.
+    pub fn subscription_filter(&self) -> SubscriptionFilter {
+        SubscriptionFilter {
+            allow: self.parse_endpoints(&self.subscribe_allow),
+            deny: self.parse_endpoints(&self.subscribe_deny),
+            max_rate: self.subscribe_max_rate.trim().parse().unwrap_or(0),
+        }
+    }
+
+    /// Parse the `protocol=metric` card sparkline list.
+    fn parse_card_metrics(&self) -> Result<BTreeMap<Protocol, String>, String> {
+        self.parse_endpoints(&self.card_metrics)
+            .into_iter()
+            .map(|entry| {
+                let (protocol, metric) = entry
+                    .split_once('=')
+                    .map(|(p, m)| (p.trim(), m.trim()))
+                    .filter(|(_, m)| !m.is_empty())
+                    .ok_or_else(|| format!("Card metric must be protocol=metric: {entry}"))?;
+                let protocol = protocol
+                    .parse::<Protocol>()
+                    .map_err(|_| format!("Unknown protocol in card metrics: {protocol}"))?;
+                Ok((protocol, metric.to_string()))
+            })
+            .collect()
+    }
+
+    /// Get the per-protocol card sparkline metrics; invalid input yields none.
+    pub fn card_metrics_value(&self) -> BTreeMap<Protocol, String> {
+        self.parse_card_metrics().unwrap_or_default()
+    }
+
     /// Get stale threshold in milliseconds.
     pub fn stale_threshold_ms(&self) -> i64 {
         self.stale_threshold_secs.parse::<i64>().unwrap_or(120) * 1000
@@ -409,6 +746,26 @@ impl SettingsState {
         self.max_alerts.parse().unwrap_or(100)
     }
 
+    /// Get the tracked-device cap.
+    pub fn max_devices_value(&self) -> usize {
+        self.max_devices.parse().unwrap_or(default_max_devices())
+    }
+
+    /// Get the per-device metric cap.
+    pub fn max_metrics_value(&self) -> usize {
+        self.max_metrics
+            .parse()
+            .unwrap_or(default_max_metrics_per_device())
+    }
+
+    /// Get the topology layout frame cap.
+    pub fn topology_max_fps_value(&self) -> u32 {
+        self.topology_max_fps
+            .trim()
+            .parse()
+            .unwrap_or(default_topology_max_fps())
+    }
+
     /// Mark settings as saved.
     pub fn mark_saved(&mut self) {
         self.modified = false;
@@ -587,6 +944,78 @@ fn render_zenoh_section(state: &SettingsState) -> Element<'_, Message> {
             color: Some(crate::view::theme::colors(theme).text_dimmed()),
         });
 
+    // Scouting (#426): multicast is often blocked on locked-down networks;
+    // with it off, peers are found only through the connect endpoints.
+    let scouting_row = row![
+        text("Multicast scouting:").size(14),
+        iced::widget::toggler(state.multicast_scouting)
+            .on_toggle(Message::SetMulticastScouting)
+            .size(18),
+        text("Gossip scouting:").size(14),
+        iced::widget::toggler(state.gossip_scouting)
+            .on_toggle(Message::SetGossipScouting)
+            .size(18),
+    ]
+    .spacing(10)
+    .align_y(Alignment::Center);
+    let scouting_help = text(
+        "Disable multicast where it is blocked and list peers or routers under Connect endpoints",
+    )
+    .size(11)
+    .style(|theme: &Theme| text::Style {
+        color: Some(crate::view::theme::colors(theme).text_dimmed()),
+    });
+
+    // Namespace (#446): must match the bridges' `zenoh.namespace`.
+    let namespace_label = text("Namespace:").size(14);
+    let namespace_input = text_input("tenantA", &state.namespace)
+        .on_input(Message::SetZenohNamespace)
+        .padding(8)
+        .width(Length::Fixed(400.0));
+    let namespace_help =
+        text("Prefix for every key (tenantA/zensight/...); must match the bridges' namespace")
+            .size(11)
+            .style(|theme: &Theme| text::Style {
+                color: Some(crate::view::theme::colors(theme).text_dimmed()),
+            });
+
+    // Telemetry subscription allowlist / denylist
+    let allow_label = text("Subscribe to:").size(14);
+    let allow_input = text_input("snmp, zensight/netflow/edge-*/**", &state.subscribe_allow)
+        .on_input(Message::SetSubscribeAllow)
+        .padding(8)
+        .width(Length::Fixed(400.0));
+
+    let deny_label = text("Ignore:").size(14);
+    let deny_input = text_input("zensight/snmp/lab-*/**", &state.subscribe_deny)
+        .on_input(Message::SetSubscribeDeny)
+        .padding(8)
+        .width(Length::Fixed(400.0));
+
+    let filter_help =
+        text("Comma-separated protocols or key expressions; leave empty to receive all telemetry")
+            .size(11)
+            .style(|theme: &Theme| text::Style {
+                color: Some(crate::view::theme::colors(theme).text_dimmed()),
+            });
+
+    // Per-metric decimation (#484)
+    let max_rate_row = row![
+        text("Max updates per second per metric:").size(14),
+        text_input("0", &state.subscribe_max_rate)
+            .on_input(Message::SetSubscribeMaxRate)
+            .padding(8)
+            .width(Length::Fixed(100.0)),
+    ]
+    .spacing(10)
+    .align_y(Alignment::Center);
+    let max_rate_help =
+        text("Faster streams keep only the latest value per window (0-1000, 0 for no limit)")
+            .size(11)
+            .style(|theme: &Theme| text::Style {
+                color: Some(crate::view::theme::colors(theme).text_dimmed()),
+            });
+
     column![
         section_title,
         mode_row,
@@ -597,6 +1026,18 @@ fn render_zenoh_section(state: &SettingsState) -> Element<'_, Message> {
         listen_label,
         listen_input,
         listen_help,
+        scouting_row,
+        scouting_help,
+        namespace_label,
+        namespace_input,
+        namespace_help,
+        allow_label,
+        allow_input,
+        deny_label,
+        deny_input,
+        filter_help,
+        max_rate_row,
+        max_rate_help,
     ]
     .spacing(8)
     .into()
@@ -657,6 +1098,44 @@ fn render_display_section(state: &SettingsState) -> Element<'_, Message> {
         .spacing(10)
         .align_y(Alignment::Center);
 
+    // Tracking caps (#425): keep a huge fabric from hanging the UI.
+    let limits_row = row![
+        text("Max devices:").size(14),
+        text_input("5000", &state.max_devices)
+            .on_input(Message::SetMaxDevices)
+            .padding(8)
+            .width(Length::Fixed(100.0)),
+        text("Max metrics per device:").size(14),
+        text_input("2000", &state.max_metrics)
+            .on_input(Message::SetMaxMetrics)
+            .padding(8)
+            .width(Length::Fixed(100.0)),
+    ]
+    .spacing(10)
+    .align_y(Alignment::Center);
+    let limits_help =
+        text("Past these caps the least-recently-updated devices / metrics are dropped")
+            .size(11)
+            .style(|theme: &Theme| text::Style {
+                color: Some(crate::view::theme::colors(theme).text_dimmed()),
+            });
+
+    // Topology layout frame cap (#473).
+    let fps_row = row![
+        text("Topology max FPS:").size(14),
+        text_input("30", &state.topology_max_fps)
+            .on_input(Message::SetTopologyMaxFps)
+            .padding(8)
+            .width(Length::Fixed(100.0)),
+    ]
+    .spacing(10)
+    .align_y(Alignment::Center);
+    let fps_help = text("Layout frames per second while the graph settles (1-120)")
+        .size(11)
+        .style(|theme: &Theme| text::Style {
+            color: Some(crate::view::theme::colors(theme).text_dimmed()),
+        });
+
     // Desktop notifications (#26): opt-in, CRITICAL firing transitions only.
     let notif_toggle = iced::widget::toggler(state.desktop_notifications)
         .on_toggle(|_| Message::ToggleDesktopNotifications)
@@ -670,6 +1149,111 @@ fn render_display_section(state: &SettingsState) -> Element<'_, Message> {
             color: Some(crate::view::theme::colors(theme).text_dimmed()),
         });
 
+    // Raw-payload inspector (#414): debugging aid for odd-looking telemetry.
+    let inspector_toggle = iced::widget::toggler(state.payload_inspector)
+        .on_toggle(|_| Message::TogglePayloadInspector)
+        .size(18);
+    let inspector_row = row![text("Payload inspector:").size(14), inspector_toggle]
+        .spacing(10)
+        .align_y(Alignment::Center);
+    let inspector_help =
+        text("Show each metric's key expression and decoded JSON in the device view")
+            .size(11)
+            .style(|theme: &Theme| text::Style {
+                color: Some(crate::view::theme::colors(theme).text_dimmed()),
+            });
+
+    // History query API (#465): let other tools pull what ZenSight has buffered.
+    let history_query_toggle = iced::widget::toggler(state.serve_history_queries)
+        .on_toggle(|_| Message::ToggleHistoryQueries)
+        .size(18);
+    let history_query_row = row![
+        text("Serve history queries:").size(14),
+        history_query_toggle
+    ]
+    .spacing(10)
+    .align_y(Alignment::Center);
+    let history_query_help = text("Answer Zenoh queries on zensight/@gui/history/** (opt-in)")
+        .size(11)
+        .style(|theme: &Theme| text::Style {
+            color: Some(crate::view::theme::colors(theme).text_dimmed()),
+        });
+
+    // Background throttling (#470): don't burn CPU redrawing an unwatched window.
+    let blur_toggle = iced::widget::toggler(state.pause_when_unfocused)
+        .on_toggle(|_| Message::TogglePauseWhenUnfocused)
+        .size(18);
+    let blur_row = row![text("Slow down when unfocused:").size(14), blur_toggle]
+        .spacing(10)
+        .align_y(Alignment::Center);
+    let blur_help = text("Refresh health and sparklines every 5s instead of every second while the window is in the background")
+        .size(11)
+        .style(|theme: &Theme| text::Style {
+            color: Some(crate::view::theme::colors(theme).text_dimmed()),
+        });
+
+    // Color-blind-safe palette (#472) for chart series, status LEDs and
+    // threshold lines.
+    let palette_toggle = iced::widget::toggler(state.color_blind_palette)
+        .on_toggle(|_| Message::ToggleColorBlindPalette)
+        .size(18);
+    let palette_row = row![text("Color-blind-safe palette:").size(14), palette_toggle]
+        .spacing(10)
+        .align_y(Alignment::Center);
+    let palette_help =
+        text("Use blue/orange instead of green/red for status and distinct chart colors")
+            .size(11)
+            .style(|theme: &Theme| text::Style {
+                color: Some(crate::view::theme::colors(theme).text_dimmed()),
+            });
+
+    // Flow volume unit (#477), shared by the NetFlow view and topology edges.
+    let flow_unit_picker = pick_list(
+        FlowVolumeUnit::ALL,
+        Some(state.flow_volume_unit),
+        Message::SetFlowVolumeUnit,
+    );
+    let flow_unit_row = row![text("Flow volumes:").size(14), flow_unit_picker]
+        .spacing(10)
+        .align_y(Alignment::Center);
+    let flow_unit_help = text(
+        "How NetFlow traffic and topology links are sized; per-second units average over the \
+         flow's duration",
+    )
+    .size(11)
+    .style(|theme: &Theme| text::Style {
+        color: Some(crate::view::theme::colors(theme).text_dimmed()),
+    });
+
+    // Absolute times (boot time, unit timestamps) in UTC or local time (#440).
+    let utc_toggle = iced::widget::toggler(state.utc_timestamps)
+        .on_toggle(|_| Message::ToggleUtcTimestamps)
+        .size(18);
+    let utc_row = row![text("Show times in UTC:").size(14), utc_toggle]
+        .spacing(10)
+        .align_y(Alignment::Center);
+    let utc_help = text("Show dates and times in UTC instead of the local time zone")
+        .size(11)
+        .style(|theme: &Theme| text::Style {
+            color: Some(crate::view::theme::colors(theme).text_dimmed()),
+        });
+
+    // Card sparkline lead metric per protocol (#420).
+    let card_metrics_label = text("Card sparkline metrics:").size(14);
+    let card_metrics_input =
+        text_input("sysinfo=cpu/usage, modbus=temperature", &state.card_metrics)
+            .on_input(Message::SetCardMetrics)
+            .padding(8)
+            .width(Length::Fixed(400.0));
+    let card_metrics_help = text(
+        "Comma-separated protocol=metric pairs; a card leads with the first metric containing \
+         the name. Unlisted protocols use CPU, temperature or log throughput",
+    )
+    .size(11)
+    .style(|theme: &Theme| text::Style {
+        color: Some(crate::view::theme::colors(theme).text_dimmed()),
+    });
+
     column![
         section_title,
         threshold_row,
@@ -678,8 +1262,27 @@ fn render_display_section(state: &SettingsState) -> Element<'_, Message> {
         history_help,
         alerts_row,
         alerts_help,
+        limits_row,
+        limits_help,
+        fps_row,
+        fps_help,
         notif_row,
         notif_help,
+        inspector_row,
+        inspector_help,
+        history_query_row,
+        history_query_help,
+        blur_row,
+        blur_help,
+        palette_row,
+        palette_help,
+        flow_unit_row,
+        flow_unit_help,
+        utc_row,
+        utc_help,
+        card_metrics_label,
+        card_metrics_input,
+        card_metrics_help,
     ]
     .spacing(8)
     .into()
@@ -769,6 +1372,17 @@ mod tests {
 
         settings.stale_threshold_secs = "60".to_string();
         assert!(settings.validate().is_ok());
+
+        // Topology frame cap (#473).
+        settings.topology_max_fps = "0".to_string();
+        assert!(settings.validate().is_err());
+
+        settings.topology_max_fps = "500".to_string();
+        assert!(settings.validate().is_err());
+
+        settings.topology_max_fps = "10".to_string();
+        assert!(settings.validate().is_ok());
+        assert_eq!(settings.topology_max_fps_value(), 10);
     }
 
     #[test]
@@ -798,11 +1412,23 @@ mod tests {
             zenoh_mode: "router".to_string(),
             zenoh_connect: vec!["tcp/localhost:7447".to_string()],
             zenoh_listen: vec!["tcp/0.0.0.0:7448".to_string()],
+            zenoh_multicast_scouting: true,
+            zenoh_gossip_scouting: true,
+            zenoh_namespace: String::new(),
             stale_threshold_secs: 60,
             dark_theme: true,
+            color_blind_palette: false,
+            flow_volume_unit: FlowVolumeUnit::BytesTotal,
+            utc_timestamps: false,
             desktop_notifications: false,
+            payload_inspector: false,
+            serve_history_queries: false,
+            pause_when_unfocused: true,
             max_history: 1000,
             max_alerts: 200,
+            max_devices: 5000,
+            max_metrics_per_device: 2000,
+            topology_max_fps: 60,
             groups: GroupsState::default(),
             alert_rules: Vec::new(),
             alert_filter_presets: Vec::new(),
@@ -810,6 +1436,9 @@ mod tests {
             overview_selected_protocol: None,
             overview_expanded: true,
             current_view: CurrentView::default(),
+            topology_positions: BTreeMap::new(),
+            subscription_filter: SubscriptionFilter::default(),
+            card_metrics: BTreeMap::new(),
         };
 
         // Serialize to JSON
@@ -824,6 +1453,7 @@ mod tests {
         assert_eq!(restored.stale_threshold_secs, 60);
         assert_eq!(restored.max_history, 1000);
         assert_eq!(restored.max_alerts, 200);
+        assert_eq!(restored.topology_max_fps, 60);
     }
 
     #[test]
@@ -832,11 +1462,23 @@ mod tests {
             zenoh_mode: "client".to_string(),
             zenoh_connect: vec!["tcp/router:7447".to_string()],
             zenoh_listen: vec![],
+            zenoh_multicast_scouting: false,
+            zenoh_gossip_scouting: true,
+            zenoh_namespace: "tenantA".to_string(),
             stale_threshold_secs: 90,
             dark_theme: false,
+            color_blind_palette: true,
+            flow_volume_unit: FlowVolumeUnit::BitsPerSecond,
+            utc_timestamps: true,
             desktop_notifications: true,
+            payload_inspector: false,
+            serve_history_queries: false,
+            pause_when_unfocused: false,
             max_history: 750,
             max_alerts: 150,
+            max_devices: 250,
+            max_metrics_per_device: 400,
+            topology_max_fps: 15,
             groups: GroupsState::default(),
             alert_rules: Vec::new(),
             alert_filter_presets: Vec::new(),
@@ -844,6 +1486,12 @@ mod tests {
             overview_selected_protocol: None,
             overview_expanded: true,
             current_view: CurrentView::default(),
+            topology_positions: BTreeMap::new(),
+            subscription_filter: SubscriptionFilter {
+                max_rate: 20,
+                ..Default::default()
+            },
+            card_metrics: BTreeMap::new(),
         };
 
         // Convert to UI state
@@ -854,8 +1502,17 @@ mod tests {
         assert_eq!(state.stale_threshold_secs, "90");
         assert_eq!(state.max_history, "750");
         assert_eq!(state.max_alerts, "150");
+        assert_eq!(state.max_devices, "250");
+        assert_eq!(state.topology_max_fps, "15");
         // The opt-in notification flag survives the persistent→state hop (#26).
         assert!(state.desktop_notifications);
+        assert!(!state.multicast_scouting);
+        assert_eq!(state.namespace, "tenantA");
+        assert!(!state.pause_when_unfocused);
+        assert!(state.color_blind_palette);
+        assert_eq!(state.flow_volume_unit, FlowVolumeUnit::BitsPerSecond);
+        assert!(state.utc_timestamps);
+        assert_eq!(state.subscribe_max_rate, "20");
 
         // Convert back to persistent
         let restored = PersistentSettings::from_state(&state);
@@ -865,6 +1522,16 @@ mod tests {
         assert_eq!(restored.stale_threshold_secs, 90);
         assert_eq!(restored.max_history, 750);
         assert_eq!(restored.max_alerts, 150);
+        assert_eq!(restored.max_metrics_per_device, 400);
+        assert_eq!(restored.topology_max_fps, 15);
+        assert!(!restored.zenoh_multicast_scouting);
+        assert!(restored.zenoh_gossip_scouting);
+        assert!(!restored.pause_when_unfocused);
+        assert!(restored.color_blind_palette);
+        assert_eq!(restored.flow_volume_unit, FlowVolumeUnit::BitsPerSecond);
+        assert!(restored.utc_timestamps);
+        assert_eq!(restored.zenoh_namespace, "tenantA");
         assert!(restored.desktop_notifications);
+        assert_eq!(restored.subscription_filter.max_rate, 20);
     }
 }
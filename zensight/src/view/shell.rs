@@ -10,7 +10,7 @@ use iced::{Alignment, Background, Border, Element, Length, Theme};
 
 use crate::app::CurrentView;
 use crate::message::Message;
-use crate::view::dashboard::ConnectionState;
+use crate::view::dashboard::{ConnectionState, ConnectionStatus};
 use crate::view::icons::{self, IconSize};
 use crate::view::theme;
 use crate::view::tokens::{font, space};
@@ -169,22 +169,38 @@ fn breadcrumb<'a>(current: CurrentView, device: Option<&'a str>) -> Element<'a,
     }
 }
 
-/// The connection status indicator (right side of the top bar).
-fn connection_status<'a>(connection: ConnectionState) -> Element<'a, Message> {
-    let (icon, label) = match connection {
-        ConnectionState::Connected => (icons::connected(IconSize::Small), "Connected"),
-        ConnectionState::Connecting => (icons::disconnected(IconSize::Small), "Connecting…"),
-        ConnectionState::Disconnected => (icons::disconnected(IconSize::Small), "Disconnected"),
+/// The header label for a connection state.
+pub(crate) fn connection_label(connection: ConnectionState) -> &'static str {
+    match connection {
+        ConnectionState::Connected => "Connected",
+        ConnectionState::Connecting => "Connecting…",
+        ConnectionState::Reconnecting => "Reconnecting…",
+        ConnectionState::Disconnected => "Disconnected",
+    }
+}
+
+/// The connection status indicator (right side of the top bar). While an
+/// attempt is in flight the icon pulses, stepped by the 1 Hz tick (#427).
+fn connection_status<'a>(connection: ConnectionState, now_ms: i64) -> Element<'a, Message> {
+    let status = connection.status();
+    let icon = match status {
+        ConnectionStatus::Connected => icons::connected(IconSize::Small),
+        ConnectionStatus::Reconnecting => {
+            icons::reconnecting(IconSize::Small, (now_ms / 1000) % 2 == 0)
+        }
+        ConnectionStatus::Disconnected => icons::disconnected(IconSize::Small),
     };
-    let label = text(label).size(font::CAPTION).style(move |theme: &Theme| {
-        let c = theme::colors(theme);
-        let color = match connection {
-            ConnectionState::Connected => c.status_connected(),
-            ConnectionState::Connecting => c.warning(),
-            ConnectionState::Disconnected => c.status_disconnected(),
-        };
-        text::Style { color: Some(color) }
-    });
+    let label = text(connection_label(connection))
+        .size(font::CAPTION)
+        .style(move |theme: &Theme| {
+            let c = theme::colors(theme);
+            let color = match status {
+                ConnectionStatus::Connected => c.status_connected(),
+                ConnectionStatus::Reconnecting => c.warning(),
+                ConnectionStatus::Disconnected => c.status_disconnected(),
+            };
+            text::Style { color: Some(color) }
+        });
     row![icon, label]
         .spacing(space::XS)
         .align_y(Alignment::Center)
@@ -229,11 +245,11 @@ fn top_bar<'a>(
     // Keyboard-shortcuts help (#28); also bound to "?".
     right = right.push(
         button(text("?").size(font::BODY))
-            .on_press(Message::ToggleHelp)
+            .on_press(Message::ToggleHelpOverlay)
             .padding([space::XS, space::SM])
             .style(iced::widget::button::secondary),
     );
-    right = right.push(connection_status(connection));
+    right = right.push(connection_status(connection, now_ms));
 
     container(
         row![breadcrumb(current, device), spacer, right]
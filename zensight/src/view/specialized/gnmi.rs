@@ -264,6 +264,10 @@ fn format_value(value: &TelemetryValue) -> String {
         TelemetryValue::Text(s) => s.clone(),
         TelemetryValue::Boolean(b) => if *b { "true" } else { "false" }.to_string(),
         TelemetryValue::Binary(data) => format!("<{} bytes>", data.len()),
+        TelemetryValue::Bytes(data) => crate::view::formatting::format_hex_preview(data),
+        TelemetryValue::Histogram { sum, count, .. } => {
+            crate::view::formatting::format_histogram(*sum, *count)
+        }
     }
 }
 
@@ -14,7 +14,7 @@ use zensight_common::TelemetryValue;
 use crate::message::Message;
 use crate::view::components::{card, empty_state};
 use crate::view::device::DeviceDetailState;
-use crate::view::formatting::{format_bytes, format_count};
+use crate::view::formatting::{flow_volume_unit, format_count};
 use crate::view::icons::{self, IconSize};
 use crate::view::theme;
 use crate::view::tokens::space;
@@ -29,6 +29,8 @@ struct FlowRecord {
     protocol: u8,
     bytes: u64,
     packets: u64,
+    /// Flow duration from its start/end fields, 0 when absent (#477).
+    duration_ms: u64,
     timestamp: i64,
 }
 
@@ -96,6 +98,7 @@ fn render_summary(state: &DeviceDetailState) -> Element<'_, Message> {
     let flows = parse_flows(state);
 
     let total_bytes: u64 = flows.iter().map(|f| f.bytes).sum();
+    let longest_ms = flows.iter().map(|f| f.duration_ms).max().unwrap_or(0);
     let total_packets: u64 = flows.iter().map(|f| f.packets).sum();
     let unique_sources: usize = flows
         .iter()
@@ -109,7 +112,10 @@ fn render_summary(state: &DeviceDetailState) -> Element<'_, Message> {
         .len();
 
     let items = vec![
-        format!("Total: {}", format_bytes(total_bytes as f64)),
+        format!(
+            "Total: {}",
+            flow_volume_unit().format(total_bytes, longest_ms)
+        ),
         format!("Packets: {}", format_count(total_packets)),
         format!("Sources: {}", unique_sources),
         format!("Destinations: {}", unique_dests),
@@ -136,20 +142,23 @@ fn render_top_talkers(state: &DeviceDetailState) -> Element<'_, Message> {
 
     let flows = parse_flows(state);
 
-    // Aggregate by source->destination pair
-    let mut talkers: HashMap<(String, String), u64> = HashMap::new();
+    // Aggregate by source->destination pair: total bytes and the longest
+    // flow's duration, for per-second units (#477)
+    let mut talkers: HashMap<(String, String), (u64, u64)> = HashMap::new();
     for flow in &flows {
         let key = (flow.src_ip.clone(), flow.dst_ip.clone());
-        *talkers.entry(key).or_insert(0) += flow.bytes;
+        let entry = talkers.entry(key).or_insert((0, 0));
+        entry.0 += flow.bytes;
+        entry.1 = entry.1.max(flow.duration_ms);
     }
 
     let mut sorted_talkers: Vec<_> = talkers.into_iter().collect();
-    sorted_talkers.sort_by_key(|b| std::cmp::Reverse(b.1));
+    sorted_talkers.sort_by_key(|b| std::cmp::Reverse(b.1.0));
 
     let is_empty = sorted_talkers.is_empty();
     let mut rows = Column::new().spacing(4);
 
-    for (i, ((src, dst), bytes)) in sorted_talkers.into_iter().take(10).enumerate() {
+    for (i, ((src, dst), (bytes, duration_ms))) in sorted_talkers.into_iter().take(10).enumerate() {
         let rank = text(format!("{}.", i + 1))
             .size(11)
             .width(Length::Fixed(25.0));
@@ -160,9 +169,9 @@ fn render_top_talkers(state: &DeviceDetailState) -> Element<'_, Message> {
 
         let dst_text = text(dst).size(11).width(Length::Fixed(120.0));
 
-        let bytes_text = text(format_bytes(bytes as f64))
+        let bytes_text = text(flow_volume_unit().format(bytes, duration_ms))
             .size(11)
-            .width(Length::Fixed(80.0))
+            .width(Length::Fixed(90.0))
             .style(|t: &Theme| text::Style {
                 color: Some(theme::colors(t).primary()),
             });
@@ -279,7 +288,7 @@ fn render_flow_table(state: &DeviceDetailState) -> Element<'_, Message> {
             text("Dest").size(10).width(Length::Fixed(100.0)),
             text("Dst Port").size(10).width(Length::Fixed(60.0)),
             text("Proto").size(10).width(Length::Fixed(50.0)),
-            text("Bytes").size(10).width(Length::Fixed(70.0)),
+            text("Volume").size(10).width(Length::Fixed(80.0)),
             text("Packets").size(10).width(Length::Fixed(60.0)),
         ]
         .spacing(8)
@@ -310,9 +319,9 @@ fn render_flow_table(state: &DeviceDetailState) -> Element<'_, Message> {
                 .size(10)
                 .width(Length::Fixed(60.0)),
             text(proto_name).size(10).width(Length::Fixed(50.0)),
-            text(format_bytes(flow.bytes as f64))
+            text(flow_volume_unit().format(flow.bytes, flow.duration_ms))
                 .size(10)
-                .width(Length::Fixed(70.0)),
+                .width(Length::Fixed(80.0)),
             text(format!("{}", flow.packets))
                 .size(10)
                 .width(Length::Fixed(60.0)),
@@ -402,6 +411,7 @@ fn parse_flows(state: &DeviceDetailState) -> Vec<FlowRecord> {
             protocol,
             bytes,
             packets,
+            duration_ms: flow_duration_ms(&point.labels),
             timestamp: point.timestamp,
         });
     }
@@ -409,6 +419,20 @@ fn parse_flows(state: &DeviceDetailState) -> Vec<FlowRecord> {
     flows
 }
 
+/// Flow duration in milliseconds from the record's start/end labels (#477):
+/// IPFIX `flowStart/End{Milli,}Seconds`, or the uptime-relative v5/v7
+/// `first`/`last` and v9 `FirstSwitched`/`LastSwitched`. 0 when neither pair
+/// is present.
+fn flow_duration_ms(labels: &HashMap<String, String>) -> u64 {
+    let field = |name: &str| labels.get(name).and_then(|v| v.parse::<u64>().ok());
+    let span = |start: &str, end: &str| Some(field(end)?.saturating_sub(field(start)?));
+    span("flowstartmilliseconds", "flowendmilliseconds")
+        .or_else(|| span("flowstartseconds", "flowendseconds").map(|s| s * 1000))
+        .or_else(|| span("first", "last"))
+        .or_else(|| span("firstswitched", "lastswitched"))
+        .unwrap_or(0)
+}
+
 fn section_style(t: &Theme) -> container::Style {
     container::Style {
         background: Some(iced::Background::Color(theme::colors(t).card_background())),
@@ -437,11 +461,34 @@ mod tests {
             protocol: 6,
             bytes: 1000,
             packets: 10,
+            duration_ms: 0,
             timestamp: 0,
         };
         assert_eq!(flow.protocol_name(), "TCP");
     }
 
+    #[test]
+    fn test_flow_duration_from_labels() {
+        let labels = |pairs: &[(&str, &str)]| -> HashMap<String, String> {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        };
+        assert_eq!(
+            flow_duration_ms(&labels(&[("first", "1000"), ("last", "9000")])),
+            8_000
+        );
+        assert_eq!(
+            flow_duration_ms(&labels(&[
+                ("flowstartseconds", "100"),
+                ("flowendseconds", "108"),
+            ])),
+            8_000
+        );
+        assert_eq!(flow_duration_ms(&labels(&[("last", "9000")])), 0);
+    }
+
     #[test]
     fn test_netflow_view_renders() {
         let device_id = DeviceId::new(Protocol::Netflow, "router01");
@@ -245,7 +245,8 @@ fn render_iface_card<'a>(
 
 /// A throughput trend tile: per-second rate (from history) + counter sparkline.
 fn tput_tile<'a>(state: &'a DeviceDetailState, label: &str, key: &str) -> Element<'a, Message> {
-    let rate = counter_rate(state, key)
+    let rate = state
+        .metric_rate(key)
         .map(|r| format!("{}/s", fmt_bytes(r.max(0.0) as u64)))
         .unwrap_or_else(|| num(state.metrics.get(key).map(|p| &p.value)));
     column![
@@ -725,11 +726,11 @@ fn render_qdisc_card<'a>(
     let health_chip = match health {
         Some(h) => {
             let color = if h >= 0.7 {
-                theme::STATUS_ONLINE
+                theme::palette().online
             } else if h >= 0.4 {
-                theme::STATUS_DEGRADED
+                theme::palette().degraded
             } else {
-                theme::STATUS_OFFLINE
+                theme::palette().offline
             };
             badge(color, format!("health {h:.2}"))
         }
@@ -999,37 +1000,12 @@ fn metric_tile<'a>(
 /// Format a counter's per-second rate from the last two history points (#259),
 /// falling back to the raw value when there isn't enough history yet.
 fn rate_str(state: &DeviceDetailState, metric: &str) -> String {
-    match counter_rate(state, metric) {
+    match state.metric_rate(metric) {
         Some(r) => format!("{r:.1}"),
         None => num(state.metrics.get(metric).map(|p| &p.value)),
     }
 }
 
-/// Per-second rate of a monotonic counter from its two most-recent history
-/// points; `None` on insufficient history, zero dt, or a counter reset.
-fn counter_rate(state: &DeviceDetailState, metric: &str) -> Option<f64> {
-    let hist = state.history.get(metric)?;
-    if hist.len() < 2 {
-        return None;
-    }
-    let last = hist.back()?;
-    let prev = &hist[hist.len() - 2];
-    let dt = (last.timestamp - prev.timestamp) as f64 / 1000.0;
-    if dt <= 0.0 {
-        return None;
-    }
-    let v = |p: &zensight_common::TelemetryPoint| match &p.value {
-        TelemetryValue::Counter(c) => Some(*c as f64),
-        TelemetryValue::Gauge(g) => Some(*g),
-        _ => None,
-    };
-    let (a, b) = (v(last)?, v(prev)?);
-    if a < b {
-        return None; // counter reset
-    }
-    Some((a - b) / dt)
-}
-
 /// The raw numeric value of a metric (counter/gauge/bool→0|1), if present.
 fn fval(state: &DeviceDetailState, metric: &str) -> Option<f64> {
     match state.metrics.get(metric).map(|p| &p.value) {
@@ -1051,8 +1027,8 @@ fn iface_up(stats: &BTreeMap<String, &TelemetryValue>) -> Option<bool> {
 /// A colored interface chip for the Overview status strip.
 fn iface_chip<'a>(name: &str, up: Option<bool>) -> Element<'a, Message> {
     let color = match up {
-        Some(true) => theme::STATUS_ONLINE,
-        Some(false) => theme::STATUS_OFFLINE,
+        Some(true) => theme::palette().online,
+        Some(false) => theme::palette().offline,
         None => theme::STATUS_UNKNOWN,
     };
     badge(color, name.to_string())
@@ -1079,9 +1055,9 @@ fn render_route_neighbor_chips(state: &DeviceDetailState) -> Option<Element<'_,
             .get("routes/default_v4_gw")
             .and_then(|p| text_val(&&p.value));
         let color = if present {
-            theme::STATUS_ONLINE
+            theme::palette().online
         } else {
-            theme::STATUS_OFFLINE
+            theme::palette().offline
         };
         let label = match gw {
             Some(g) if present => format!("default → {g}"),
@@ -1092,16 +1068,19 @@ fn render_route_neighbor_chips(state: &DeviceDetailState) -> Option<Element<'_,
         if let Some(f) = fval(state, "routes/default_v4_flaps_total")
             && f > 0.0
         {
-            r = r.push(badge(theme::STATUS_DEGRADED, format!("{} flaps", f as u64)));
+            r = r.push(badge(
+                theme::palette().degraded,
+                format!("{} flaps", f as u64),
+            ));
         }
     }
     if has_neigh {
         let total = fval(state, "neighbors/total").unwrap_or(0.0) as u64;
         let failed = fval(state, "neighbors/by_state/failed").unwrap_or(0.0) as u64;
         let color = if failed > 0 {
-            theme::STATUS_DEGRADED
+            theme::palette().degraded
         } else {
-            theme::STATUS_ONLINE
+            theme::palette().online
         };
         r = r.push(badge(
             color,
@@ -1395,7 +1374,7 @@ fn addresses_columns<'a>() -> Vec<DataColumn<'a, AddressRecord, Message>> {
 fn route_changes_columns<'a>() -> Vec<DataColumn<'a, RouteChangeRecord, Message>> {
     vec![
         DataColumn::fixed("when", 160.0, |c: &RouteChangeRecord| {
-            text(crate::view::formatting::format_timestamp(
+            text(crate::view::formatting::format_relative_time(
                 c.ts_unix as i64 * 1000,
             ))
             .size(font::CAPTION)
@@ -1459,7 +1438,7 @@ fn render_events_tab(state: &DeviceDetailState) -> Column<'_, Message> {
 fn events_columns<'a>() -> Vec<DataColumn<'a, EventRecord, Message>> {
     vec![
         DataColumn::fixed("when", 160.0, |e: &EventRecord| {
-            text(crate::view::formatting::format_timestamp(
+            text(crate::view::formatting::format_relative_time(
                 e.ts_unix as i64 * 1000,
             ))
             .size(font::CAPTION)
@@ -1509,9 +1488,9 @@ fn event_family_totals(state: &DeviceDetailState) -> Vec<(String, f64)> {
 fn family_color(family: &str) -> iced::Color {
     match family {
         "link" => theme::SEVERITY_INFO,
-        "addr" | "address" => theme::STATUS_ONLINE,
+        "addr" | "address" => theme::palette().online,
         "route" => theme::ACCENT_GOLD,
-        "neigh" | "neighbor" => theme::STATUS_DEGRADED,
+        "neigh" | "neighbor" => theme::palette().degraded,
         "ipsec" | "xfrm" => theme::SEVERITY_CRITICAL,
         _ => theme::STATUS_UNKNOWN,
     }
@@ -1795,9 +1774,9 @@ fn render_wg_peer<'a>(
     );
     let up_chip = badge(
         if up {
-            theme::STATUS_ONLINE
+            theme::palette().online
         } else {
-            theme::STATUS_OFFLINE
+            theme::palette().offline
         },
         if up { "up" } else { "stale" }.to_string(),
     );
@@ -1805,13 +1784,19 @@ fn render_wg_peer<'a>(
     let hs_chip = match stats.get("last_handshake_age_s").map(|p| &p.value) {
         Some(TelemetryValue::Gauge(a)) => {
             let color = if *a < 180.0 {
-                theme::STATUS_ONLINE
+                theme::palette().online
             } else if *a < 900.0 {
-                theme::STATUS_DEGRADED
+                theme::palette().degraded
             } else {
-                theme::STATUS_OFFLINE
+                theme::palette().offline
             };
-            badge(color, format!("handshake {a:.0}s ago"))
+            badge(
+                color,
+                format!(
+                    "handshake {}",
+                    crate::view::formatting::format_age((*a * 1000.0) as i64)
+                ),
+            )
         }
         _ => badge(theme::STATUS_UNKNOWN, "handshake never".to_string()),
     };
@@ -13,7 +13,7 @@ use crate::view::components::{
     Gauge, ProgressBar, StatusLed, StatusLedState, empty_state, section_header,
 };
 use crate::view::device::DeviceDetailState;
-use crate::view::formatting::format_timestamp;
+use crate::view::formatting::{format_absolute_time, format_duration, utc_timestamps};
 use crate::view::icons::{self, IconSize};
 use crate::view::specialized::sysinfo_detail::ProcessSort;
 use crate::view::theme;
@@ -118,7 +118,7 @@ fn render_header(state: &DeviceDetailState) -> Element<'_, Message> {
         let color = match st.as_str() {
             "crit" => theme::SEVERITY_CRITICAL,
             "warn" => theme::SEVERITY_WARNING,
-            _ => theme::STATUS_ONLINE,
+            _ => theme::palette().online,
         };
         let label = if st.is_empty() {
             format!("health {score:.0}")
@@ -136,11 +136,7 @@ fn render_system_overview(state: &DeviceDetailState) -> Element<'_, Message> {
 
     // Uptime
     if let Some(uptime) = get_metric_value(state, "system/uptime") {
-        let uptime_secs = uptime as u64;
-        let days = uptime_secs / 86400;
-        let hours = (uptime_secs % 86400) / 3600;
-        let mins = (uptime_secs % 3600) / 60;
-        let uptime_str = format!("{}d {}h {}m", days, hours, mins);
+        let uptime_str = format_duration(uptime as i64 * 1000);
 
         info_items.push(
             row![
@@ -177,7 +173,8 @@ fn render_system_overview(state: &DeviceDetailState) -> Element<'_, Message> {
 
     // Boot time
     if let Some(boot_time) = get_metric_value(state, "system/boot_time") {
-        let boot_str = format_timestamp(boot_time as i64);
+        // Published in seconds since the epoch.
+        let boot_str = format_absolute_time(boot_time as i64 * 1000, utc_timestamps());
         info_items.push(
             row![text("Boot:").size(12), text(boot_str).size(12)]
                 .spacing(8)
@@ -14,7 +14,7 @@ use zensight_common::{TelemetryPoint, TelemetryValue};
 use crate::message::Message;
 use crate::view::components::{Sparkline, card};
 use crate::view::device::DeviceDetailState;
-use crate::view::formatting::format_timestamp;
+use crate::view::formatting::format_relative_time;
 use crate::view::icons::{self, IconSize};
 use crate::view::theme;
 use crate::view::tokens::space;
@@ -1045,7 +1045,7 @@ fn render_log_stream<'a>(
             msg.message.clone()
         };
         let cells = row![
-            muted_cell(format_timestamp(msg.timestamp), COL_TIME),
+            muted_cell(format_relative_time(msg.timestamp), COL_TIME),
             text(msg.severity.label())
                 .size(10)
                 .width(Length::Fixed(COL_SEV))
@@ -1108,7 +1108,7 @@ fn render_log_detail(msg: &SyslogMessage) -> Element<'static, Message> {
         .into()
     };
 
-    let mut col = column![line("time".into(), format_timestamp(msg.timestamp))].spacing(3);
+    let mut col = column![line("time".into(), format_relative_time(msg.timestamp))].spacing(3);
     col = col.push(line("severity".into(), msg.severity.label().to_string()));
     col = col.push(line("source".into(), msg.source_kind.label().to_string()));
     col = col.push(line("host".into(), msg.hostname.clone()));
@@ -583,11 +583,8 @@ fn fmt_usec(usec: u64) -> String {
 }
 
 fn fmt_unix(secs: u64) -> String {
-    use chrono::TimeZone;
-    match chrono::Local.timestamp_opt(secs as i64, 0) {
-        chrono::offset::LocalResult::Single(dt) => dt.format("%Y-%m-%d %H:%M:%S").to_string(),
-        _ => secs.to_string(),
-    }
+    use crate::view::formatting::{format_absolute_time, utc_timestamps};
+    format_absolute_time(secs as i64 * 1000, utc_timestamps())
 }
 
 fn opt_bytes(v: Option<u64>) -> String {
@@ -3,6 +3,8 @@
 //! This module provides semantic colors that automatically adapt to the current theme.
 //! Use these instead of hardcoded Color::from_rgb() values.
 
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use iced::{Color, Theme};
 
 /// Get colors from the theme's extended palette.
@@ -179,7 +181,9 @@ impl<'a> ThemeColors<'a> {
     /// Chart axis labels.
     pub fn chart_label(&self) -> Color {
         if self.is_dark() {
-            Color::from_rgb(0.5, 0.5, 0.5)
+            // 0.55 (not 0.5) so value labels drawn over the outer background
+            // still clear 4.5:1 (#393).
+            Color::from_rgb(0.55, 0.55, 0.55)
         } else {
             Color::from_rgb(0.4, 0.4, 0.4)
         }
@@ -349,6 +353,9 @@ impl<'a> ThemeColors<'a> {
     /// contrast against light surfaces (WCAG graphic minimum) — see the
     /// contrast unit test.
     pub fn status_connected(&self) -> Color {
+        if palette().color_blind_safe {
+            return self.color_blind_good();
+        }
         if self.is_dark() {
             Color::from_rgb(0.2, 0.8, 0.2)
         } else {
@@ -358,6 +365,9 @@ impl<'a> ThemeColors<'a> {
 
     /// Disconnected/offline status.
     pub fn status_disconnected(&self) -> Color {
+        if palette().color_blind_safe {
+            return self.color_blind_bad();
+        }
         if self.is_dark() {
             Color::from_rgb(0.8, 0.2, 0.2)
         } else {
@@ -367,6 +377,9 @@ impl<'a> ThemeColors<'a> {
 
     /// Healthy status.
     pub fn status_healthy(&self) -> Color {
+        if palette().color_blind_safe {
+            return self.color_blind_good();
+        }
         if self.is_dark() {
             Color::from_rgb(0.2, 0.8, 0.3)
         } else {
@@ -385,6 +398,9 @@ impl<'a> ThemeColors<'a> {
 
     /// Error/critical status.
     pub fn status_error(&self) -> Color {
+        if palette().color_blind_safe {
+            return self.color_blind_bad();
+        }
         if self.is_dark() {
             Color::from_rgb(0.9, 0.2, 0.2)
         } else {
@@ -403,9 +419,32 @@ impl<'a> ThemeColors<'a> {
 
     /// Degraded status (amber/orange, between healthy and error).
     pub fn status_degraded(&self) -> Color {
+        if palette().color_blind_safe {
+            return palette().degraded;
+        }
         Color::from_rgb(0.9, 0.6, 0.2)
     }
 
+    /// "Good" status under the color-blind-safe palette (#472): blue instead
+    /// of green, darkened on the light theme for contrast.
+    fn color_blind_good(&self) -> Color {
+        if self.is_dark() {
+            Color::from_rgb(0.34, 0.71, 0.91)
+        } else {
+            Color::from_rgb(0.0, 0.45, 0.70)
+        }
+    }
+
+    /// "Bad" status under the color-blind-safe palette (#472): vermillion
+    /// instead of red.
+    fn color_blind_bad(&self) -> Color {
+        if self.is_dark() {
+            Color::from_rgb(0.84, 0.37, 0.0)
+        } else {
+            Color::from_rgb(0.7, 0.3, 0.0)
+        }
+    }
+
     /// Online status (bright green, same as connected).
     pub fn status_online(&self) -> Color {
         self.status_connected()
@@ -538,6 +577,91 @@ pub fn colors(theme: &Theme) -> ThemeColors<'_> {
     ThemeColors::new(theme)
 }
 
+/// Resolved color set for the canvas time-series chart (#393).
+///
+/// Canvas geometry can't restyle itself per theme the way widgets do, so the
+/// chart resolves one of these from the active [`Theme`] at draw time and
+/// passes it down instead of looking colors up piecemeal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChartPalette {
+    /// Outer canvas background (padding, axis label gutter).
+    pub background: Color,
+    /// Plot-area background behind the series.
+    pub plot_background: Color,
+    /// Grid lines.
+    pub grid: Color,
+    /// Axis value/time labels.
+    pub label: Color,
+    /// Title and overlay text.
+    pub text: Color,
+    /// Secondary text (window label, hints, legend, stats).
+    pub text_muted: Color,
+    /// Hidden series in the legend.
+    pub dimmed: Color,
+    /// Zoom/pan feedback overlay background.
+    pub tooltip_background: Color,
+    /// Single-series line and overlay borders.
+    pub highlight: Color,
+    /// "Viewing past" badge.
+    pub feedback: Color,
+    /// "No recent data" region and label (#395).
+    pub stale: Color,
+    /// Series colors of the active [`ColorPalette`] (#472).
+    series_colors: &'static [(f32, f32, f32)],
+    is_dark: bool,
+}
+
+impl ChartPalette {
+    /// Pick the chart palette for `theme`.
+    pub fn for_theme(theme: &Theme) -> Self {
+        let c = colors(theme);
+        Self {
+            background: c.chart_outer_background(),
+            plot_background: c.chart_background(),
+            grid: c.chart_grid(),
+            label: c.chart_label(),
+            text: c.text(),
+            text_muted: c.text_muted(),
+            dimmed: c.text_dimmed(),
+            tooltip_background: c.chart_tooltip_background(),
+            highlight: c.chart_highlight(),
+            feedback: c.chart_feedback(),
+            stale: ACCENT_STALE,
+            series_colors: palette().series,
+            is_dark: c.is_dark(),
+        }
+    }
+
+    /// Whether this palette was resolved from a dark theme.
+    pub fn is_dark(&self) -> bool {
+        self.is_dark
+    }
+
+    /// A series color adjusted to stay legible on [`Self::plot_background`].
+    ///
+    /// The shared series palette is tuned for dark plots; on a light plot the
+    /// brighter entries (yellow, cyan, green) wash out, so they are darkened
+    /// to keep >= 3:1 graphic contrast while preserving hue.
+    ///
+    /// Series are assigned [`SERIES_COLORS`](crate::view::chart::SERIES_COLORS)
+    /// entries; under the color-blind-safe palette (#472) each is swapped for
+    /// the same slot of its series colors at draw time, so open charts follow
+    /// the setting without reassigning colors.
+    pub fn series(&self, rgb: (f32, f32, f32)) -> Color {
+        let (r, g, b) = crate::view::chart::SERIES_COLORS
+            .iter()
+            .position(|&c| c == rgb)
+            .and_then(|i| self.series_colors.get(i).copied())
+            .unwrap_or(rgb);
+        if self.is_dark {
+            Color::from_rgb(r, g, b)
+        } else {
+            const LIGHT_SCALE: f32 = 0.6;
+            Color::from_rgb(r * LIGHT_SCALE, g * LIGHT_SCALE, b * LIGHT_SCALE)
+        }
+    }
+}
+
 // ============================================================================
 // Categorical palettes (theme-independent, D2 single source of truth, #28)
 //
@@ -547,6 +671,8 @@ pub fn colors(theme: &Theme) -> ThemeColors<'_> {
 // ============================================================================
 
 /// Device / health status palette (Online / Degraded / Offline / Unknown).
+/// These are the standard palette's; views read the active one through
+/// [`palette()`] (#472).
 pub const STATUS_ONLINE: Color = Color::from_rgb(0.40, 0.75, 0.45);
 /// See [`STATUS_ONLINE`].
 pub const STATUS_DEGRADED: Color = Color::from_rgb(0.90, 0.70, 0.20);
@@ -555,6 +681,112 @@ pub const STATUS_OFFLINE: Color = Color::from_rgb(0.90, 0.30, 0.30);
 /// See [`STATUS_ONLINE`].
 pub const STATUS_UNKNOWN: Color = Color::from_rgb(0.55, 0.55, 0.55);
 
+// ============================================================================
+// Color-blind-safe palette (#472)
+//
+// Red/green status and the default series colors are hard to tell apart with
+// the common color-vision deficiencies. The alternate palette is built from
+// the Okabe-Ito set: blue for "good", vermillion for "bad", yellow for
+// "degraded". The choice is process-wide because canvas charts and the
+// categorical constants above have no app state to read it from.
+// ============================================================================
+
+/// Which colors data and status indicators use (#472).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorPalette {
+    /// The default red/green palette.
+    #[default]
+    Standard,
+    /// Okabe-Ito colors, distinguishable with red-green color blindness.
+    ColorBlindSafe,
+}
+
+impl ColorPalette {
+    /// The palette for the color-blind-safe setting.
+    pub fn for_setting(color_blind_safe: bool) -> Self {
+        if color_blind_safe {
+            ColorPalette::ColorBlindSafe
+        } else {
+            ColorPalette::Standard
+        }
+    }
+
+    /// The colors of this palette.
+    pub fn colors(self) -> &'static Palette {
+        match self {
+            ColorPalette::Standard => &STANDARD_PALETTE,
+            ColorPalette::ColorBlindSafe => &COLOR_BLIND_SAFE_PALETTE,
+        }
+    }
+}
+
+/// The data and status colors of a [`ColorPalette`].
+#[derive(Debug, PartialEq)]
+pub struct Palette {
+    /// Whether this is the color-blind-safe palette.
+    pub color_blind_safe: bool,
+    /// Chart series colors, indexed like
+    /// [`SERIES_COLORS`](crate::view::chart::SERIES_COLORS).
+    pub series: &'static [(f32, f32, f32)],
+    /// Online / healthy status LEDs.
+    pub online: Color,
+    /// Degraded status LEDs.
+    pub degraded: Color,
+    /// Offline / critical status LEDs.
+    pub offline: Color,
+    /// Critical threshold lines.
+    pub threshold_critical: (f32, f32, f32),
+    /// Warning threshold lines.
+    pub threshold_warning: (f32, f32, f32),
+    /// Baseline/target threshold lines.
+    pub threshold_baseline: (f32, f32, f32),
+}
+
+/// The default palette.
+pub const STANDARD_PALETTE: Palette = Palette {
+    color_blind_safe: false,
+    series: crate::view::chart::SERIES_COLORS,
+    online: STATUS_ONLINE,
+    degraded: STATUS_DEGRADED,
+    offline: STATUS_OFFLINE,
+    threshold_critical: (1.0, 0.3, 0.3),
+    threshold_warning: (1.0, 0.7, 0.2),
+    threshold_baseline: (0.3, 0.8, 0.3),
+};
+
+/// The color-blind-safe palette (Okabe-Ito).
+pub const COLOR_BLIND_SAFE_PALETTE: Palette = Palette {
+    color_blind_safe: true,
+    series: &[
+        (0.34, 0.71, 0.91), // Sky blue
+        (0.90, 0.62, 0.0),  // Orange
+        (0.0, 0.62, 0.45),  // Bluish green
+        (0.80, 0.47, 0.65), // Reddish purple
+        (0.94, 0.89, 0.26), // Yellow
+        (0.0, 0.45, 0.70),  // Blue
+        (0.84, 0.37, 0.0),  // Vermillion
+        (0.6, 0.6, 0.6),    // Grey
+    ],
+    online: Color::from_rgb(0.34, 0.71, 0.91),
+    degraded: Color::from_rgb(0.94, 0.89, 0.26),
+    offline: Color::from_rgb(0.84, 0.37, 0.0),
+    threshold_critical: (0.84, 0.37, 0.0),
+    threshold_warning: (0.94, 0.89, 0.26),
+    threshold_baseline: (0.34, 0.71, 0.91),
+};
+
+static COLOR_BLIND_SAFE: AtomicBool = AtomicBool::new(false);
+
+/// Select the palette every view draws with (#472).
+pub fn set_color_palette(palette: ColorPalette) {
+    COLOR_BLIND_SAFE.store(palette == ColorPalette::ColorBlindSafe, Ordering::Relaxed);
+}
+
+/// The active palette's colors (#472).
+pub fn palette() -> &'static Palette {
+    ColorPalette::for_setting(COLOR_BLIND_SAFE.load(Ordering::Relaxed)).colors()
+}
+
 /// Alert-severity palette (Info / Warning / Critical), shared with
 /// [`ThemeColors::alert_severity`].
 pub const SEVERITY_INFO: Color = Color::from_rgb(0.3, 0.6, 1.0);
@@ -677,4 +909,82 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn chart_palette_contrast_per_theme() {
+        use crate::view::chart::SERIES_COLORS;
+
+        for theme in both_themes() {
+            let p = ChartPalette::for_theme(&theme);
+            assert_eq!(p.is_dark(), matches!(theme, Theme::Dark));
+
+            // The background actually follows the theme.
+            let lum = relative_luminance(p.background);
+            if p.is_dark() {
+                assert!(lum < 0.1, "dark chart background too bright ({lum:.3})");
+            } else {
+                assert!(lum > 0.8, "light chart background too dark ({lum:.3})");
+            }
+
+            for (name, fg, bg) in [
+                ("title text", p.text, p.background),
+                ("axis label", p.label, p.background),
+                ("axis label (plot)", p.label, p.plot_background),
+            ] {
+                let r = contrast_ratio(fg, bg);
+                assert!(
+                    r >= TEXT_MIN,
+                    "{name} is {r:.2}:1 (< {TEXT_MIN}) in {theme:?}"
+                );
+            }
+
+            // Grid is visible but stays subordinate to the labels.
+            let grid = contrast_ratio(p.grid, p.plot_background);
+            assert!(grid > 1.1, "grid invisible ({grid:.2}:1) in {theme:?}");
+            assert!(grid < contrast_ratio(p.label, p.plot_background));
+
+            for (i, &rgb) in SERIES_COLORS.iter().enumerate() {
+                let r = contrast_ratio(p.series(rgb), p.plot_background);
+                assert!(
+                    r >= GRAPHIC_MIN,
+                    "series {i} is {r:.2}:1 (< {GRAPHIC_MIN}) in {theme:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn color_blind_setting_selects_distinct_palette() {
+        assert_eq!(ColorPalette::for_setting(false), ColorPalette::Standard);
+        let cb = ColorPalette::for_setting(true);
+        assert_eq!(cb, ColorPalette::ColorBlindSafe);
+        let colors = cb.colors();
+        assert!(colors.color_blind_safe);
+        assert_ne!(colors, ColorPalette::Standard.colors());
+
+        // As many series slots as the standard palette, all distinct.
+        assert_eq!(colors.series.len(), crate::view::chart::SERIES_COLORS.len());
+        for (i, a) in colors.series.iter().enumerate() {
+            for b in &colors.series[i + 1..] {
+                assert_ne!(a, b, "duplicate series color");
+            }
+        }
+        // Good and bad status aren't a red/green pair.
+        assert_ne!(colors.online, STATUS_ONLINE);
+        assert_ne!(colors.offline, STATUS_OFFLINE);
+
+        // The alternate series stay legible on both plot backgrounds, as
+        // drawn: standard slot colors swapped and adjusted by the palette.
+        for theme in both_themes() {
+            let p = ChartPalette {
+                series_colors: colors.series,
+                ..ChartPalette::for_theme(&theme)
+            };
+            for &rgb in crate::view::chart::SERIES_COLORS {
+                let c = p.series(rgb);
+                let ratio = contrast_ratio(c, p.plot_background);
+                assert!(ratio >= GRAPHIC_MIN, "{ratio:.2}:1 in {theme:?}");
+            }
+        }
+    }
 }
@@ -0,0 +1,249 @@
+//! Topology export to Graphviz DOT and GraphML (#399).
+//!
+//! Serializes the current node/edge set for documentation, with node type,
+//! health and alert state, and per-edge bandwidth as attributes. Output is
+//! deterministic (nodes sorted by id, edges in graph order) so exports diff
+//! cleanly. Pure — the save dialog lives in the app.
+
+use std::fmt::Write;
+
+use super::graph::format_bytes;
+use super::{Node, NodeType, TopologyState};
+
+/// Export format for [`crate::message::Message::ExportTopology`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TopologyExportFormat {
+    /// Graphviz DOT (`.dot`).
+    Dot,
+    /// GraphML XML (`.graphml`).
+    GraphMl,
+}
+
+impl TopologyExportFormat {
+    /// File extension for the save dialog's default name.
+    pub fn extension(self) -> &'static str {
+        match self {
+            TopologyExportFormat::Dot => "dot",
+            TopologyExportFormat::GraphMl => "graphml",
+        }
+    }
+
+    /// Serialize `state` in this format.
+    pub fn render(self, state: &TopologyState) -> String {
+        match self {
+            TopologyExportFormat::Dot => to_dot(state),
+            TopologyExportFormat::GraphMl => to_graphml(state),
+        }
+    }
+}
+
+impl NodeType {
+    /// Stable lowercase name, used as an export attribute.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            NodeType::Host => "host",
+            NodeType::Router => "router",
+            NodeType::Switch => "switch",
+            NodeType::Unknown => "unknown",
+        }
+    }
+}
+
+fn sorted_nodes(state: &TopologyState) -> Vec<&Node> {
+    let mut nodes: Vec<&Node> = state.nodes.values().collect();
+    nodes.sort_by(|a, b| a.id.cmp(&b.id));
+    nodes
+}
+
+/// Quote a DOT identifier / string attribute.
+fn dot_quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Serialize the topology as an undirected Graphviz graph. Edges are
+/// aggregated per unordered node pair, so `--` rather than `->`.
+pub fn to_dot(state: &TopologyState) -> String {
+    let mut out = String::from("graph zensight {\n");
+    for node in sorted_nodes(state) {
+        let _ = write!(
+            out,
+            "  {} [label={}, type={}, healthy={}",
+            dot_quote(&node.id),
+            dot_quote(&node.label),
+            dot_quote(node.node_type.as_str()),
+            node.is_healthy,
+        );
+        if let Some(sev) = node.alert {
+            let _ = write!(out, ", alert={}", dot_quote(sev.as_str()));
+        }
+        out.push_str("];\n");
+    }
+    for edge in &state.edges {
+        let _ = write!(
+            out,
+            "  {} -- {} [bytes={}, packets={}, label={}",
+            dot_quote(&edge.from),
+            dot_quote(&edge.to),
+            edge.bytes,
+            edge.packets,
+            dot_quote(&format_bytes(edge.bytes)),
+        );
+        if let Some(protocol) = &edge.protocol {
+            let _ = write!(out, ", protocol={}", dot_quote(protocol));
+        }
+        out.push_str("];\n");
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Escape text for an XML attribute or element body.
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// A `u64` counter as a GraphML `long`, saturating at `i64::MAX` so the
+/// value always parses.
+fn graphml_long(value: u64) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
+}
+
+/// GraphML preamble: typed `<key>` declarations and the open graph element.
+/// Byte and packet counters are declared `long`, which tools read as
+/// integers; see [`graphml_long`] for values past its signed range.
+const GRAPHML_HEADER: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="label" for="node" attr.name="label" attr.type="string"/>
+  <key id="type" for="node" attr.name="type" attr.type="string"/>
+  <key id="healthy" for="node" attr.name="healthy" attr.type="boolean"/>
+  <key id="alert" for="node" attr.name="alert" attr.type="string"/>
+  <key id="bytes" for="edge" attr.name="bytes" attr.type="long"/>
+  <key id="packets" for="edge" attr.name="packets" attr.type="long"/>
+  <key id="protocol" for="edge" attr.name="protocol" attr.type="string"/>
+  <graph id="zensight" edgedefault="undirected">
+"#;
+
+/// Serialize the topology as GraphML with typed `<key>` declarations for the
+/// same attributes as the DOT export.
+pub fn to_graphml(state: &TopologyState) -> String {
+    let mut out = String::from(GRAPHML_HEADER);
+    for node in sorted_nodes(state) {
+        let _ = writeln!(out, "    <node id=\"{}\">", xml_escape(&node.id));
+        let _ = writeln!(
+            out,
+            "      <data key=\"label\">{}</data>",
+            xml_escape(&node.label)
+        );
+        let _ = writeln!(
+            out,
+            "      <data key=\"type\">{}</data>",
+            node.node_type.as_str()
+        );
+        let _ = writeln!(
+            out,
+            "      <data key=\"healthy\">{}</data>",
+            node.is_healthy
+        );
+        if let Some(sev) = node.alert {
+            let _ = writeln!(out, "      <data key=\"alert\">{}</data>", sev.as_str());
+        }
+        out.push_str("    </node>\n");
+    }
+    for edge in &state.edges {
+        let _ = writeln!(
+            out,
+            "    <edge source=\"{}\" target=\"{}\">",
+            xml_escape(&edge.from),
+            xml_escape(&edge.to)
+        );
+        let _ = writeln!(
+            out,
+            "      <data key=\"bytes\">{}</data>",
+            graphml_long(edge.bytes)
+        );
+        let _ = writeln!(
+            out,
+            "      <data key=\"packets\">{}</data>",
+            graphml_long(edge.packets)
+        );
+        if let Some(protocol) = &edge.protocol {
+            let _ = writeln!(
+                out,
+                "      <data key=\"protocol\">{}</data>",
+                xml_escape(protocol)
+            );
+        }
+        out.push_str("    </edge>\n");
+    }
+    out.push_str("  </graph>\n</graphml>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::topology::Edge;
+
+    fn small_graph() -> TopologyState {
+        let mut state = TopologyState::default();
+        for (id, healthy) in [("web-01", true), ("db \"primary\"", false)] {
+            state.nodes.insert(
+                id.to_string(),
+                Node {
+                    id: id.to_string(),
+                    label: id.to_string(),
+                    is_healthy: healthy,
+                    ..Default::default()
+                },
+            );
+        }
+        state.nodes.get_mut("db \"primary\"").unwrap().node_type = NodeType::Router;
+        state.edges.push(Edge {
+            from: "web-01".to_string(),
+            to: "db \"primary\"".to_string(),
+            bytes: 1_500_000,
+            packets: 1200,
+            duration_ms: 0,
+            protocol: Some("TCP".to_string()),
+            last_seen: 0,
+            alert: None,
+        });
+        state
+    }
+
+    #[test]
+    fn dot_export_declares_nodes_and_edges() {
+        let dot = to_dot(&small_graph());
+
+        assert!(dot.starts_with("graph zensight {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert!(dot.contains("  \"web-01\" [label=\"web-01\", type=\"host\", healthy=true];\n"));
+        // Quotes in ids are escaped; node type and health carried through.
+        assert!(dot.contains(
+            "  \"db \\\"primary\\\"\" [label=\"db \\\"primary\\\"\", type=\"router\", healthy=false];\n"
+        ));
+        assert!(dot.contains(
+            "  \"web-01\" -- \"db \\\"primary\\\"\" [bytes=1500000, packets=1200, label=\"1.5 MB\", protocol=\"TCP\"];\n"
+        ));
+        // Sorted by id: the `d…` node is declared before `w…`.
+        assert!(dot.find("\"db").unwrap() < dot.find("\"web-01\" [").unwrap());
+    }
+
+    #[test]
+    fn graphml_export_escapes_and_types_attributes() {
+        let xml = TopologyExportFormat::GraphMl.render(&small_graph());
+
+        assert!(xml.contains("<graph id=\"zensight\" edgedefault=\"undirected\">"));
+        assert!(xml.contains("<node id=\"db &quot;primary&quot;\">"));
+        assert!(xml.contains("<data key=\"type\">router</data>"));
+        assert!(xml.contains("<edge source=\"web-01\" target=\"db &quot;primary&quot;\">"));
+        assert!(xml.contains("<data key=\"bytes\">1500000</data>"));
+        assert!(xml.contains("attr.name=\"bytes\" attr.type=\"long\""));
+        assert!(xml.contains("attr.name=\"packets\" attr.type=\"long\""));
+        assert_eq!(graphml_long(u64::MAX), i64::MAX);
+    }
+}
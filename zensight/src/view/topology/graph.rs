@@ -6,6 +6,8 @@ use iced::{Color, Element, Length, Point, Rectangle, Renderer, Theme};
 
 use super::{NodeType, TopologyState};
 use crate::message::Message;
+use crate::view::components::kit;
+use crate::view::formatting::flow_volume_unit;
 use crate::view::theme;
 
 /// Interactive topology graph widget.
@@ -231,10 +233,17 @@ impl<'a> TopologyGraphProgram<'a> {
                     mouse::ScrollDelta::Pixels { y, .. } => *y / 50.0,
                 };
 
-                if scroll > 0.0 {
-                    return Some(canvas::Action::publish(Message::TopologyZoomIn));
-                } else if scroll < 0.0 {
-                    return Some(canvas::Action::publish(Message::TopologyZoomOut));
+                // Zoom around the cursor (#474): pass its offset from the
+                // canvas center so the point under it stays put.
+                let anchor = cursor
+                    .position_in(bounds)
+                    .map(|p| (p.x - bounds.width / 2.0, p.y - bounds.height / 2.0))
+                    .unwrap_or((0.0, 0.0));
+                if scroll != 0.0 {
+                    return Some(canvas::Action::publish(Message::TopologyZoomAt {
+                        zoom_in: scroll > 0.0,
+                        anchor,
+                    }));
                 }
             }
             _ => {}
@@ -278,14 +287,27 @@ impl<'a> TopologyGraphProgram<'a> {
             self.background_color(),
         );
 
-        // Draw edges first (behind nodes)
-        for edge in &self.state.edges {
-            self.draw_edge(frame, edge, center);
+        // Group cluster hulls sit behind everything else (#397)
+        self.draw_cluster_hulls(frame, center);
+
+        // With a node isolated, everything outside its neighborhood is
+        // dimmed (#476).
+        let focus = self.state.isolated_neighborhood();
+
+        // Draw edges first (behind nodes), minus those under the bandwidth
+        // filter (#475)
+        for (index, edge) in self.state.edges.iter().enumerate() {
+            if !self.state.is_edge_visible(edge) {
+                continue;
+            }
+            let dimmed = focus.as_ref().is_some_and(|f| !f.edges.contains(&index));
+            self.draw_edge(frame, edge, center, dimmed);
         }
 
         // Draw nodes
         for node in self.state.nodes.values() {
-            self.draw_node(frame, node, center);
+            let dimmed = focus.as_ref().is_some_and(|f| !f.nodes.contains(&node.id));
+            self.draw_node(frame, node, center, dimmed);
         }
 
         // Draw "empty state" message if no nodes
@@ -313,8 +335,57 @@ impl<'a> TopologyGraphProgram<'a> {
         frame.fill_text(zoom_text);
     }
 
+    /// Draw a translucent group-colored hull around each device-group cluster
+    /// (#397): the smallest centroid-centered circle enclosing its members,
+    /// labelled with the group name. The default (ungrouped) cluster gets none.
+    fn draw_cluster_hulls(&self, frame: &mut Frame, center: Point) {
+        let node_radius = (25.0 * self.state.zoom).max(15.0);
+
+        for (group_id, tag) in &self.state.cluster_tags {
+            let members: Vec<Point> = self
+                .state
+                .nodes
+                .values()
+                .filter(|n| n.group == Some(*group_id))
+                .map(|n| self.apply_transform(n.position, center))
+                .collect();
+            if members.is_empty() {
+                continue;
+            }
+
+            let n = members.len() as f32;
+            let cx = members.iter().map(|p| p.x).sum::<f32>() / n;
+            let cy = members.iter().map(|p| p.y).sum::<f32>() / n;
+            let spread = members
+                .iter()
+                .map(|p| ((p.x - cx).powi(2) + (p.y - cy).powi(2)).sqrt())
+                .fold(0.0, f32::max);
+            // Leave room for the node circle and its label underneath.
+            let radius = spread + node_radius + 30.0 * self.state.zoom.max(0.5);
+
+            let hull = Path::circle(Point::new(cx, cy), radius);
+            frame.fill(&hull, kit::rgba(tag.color, 0.08));
+            frame.stroke(
+                &hull,
+                Stroke::default()
+                    .with_color(kit::rgba(tag.color, 0.5))
+                    .with_width(1.5),
+            );
+
+            let label = Text {
+                content: tag.name.clone(),
+                position: Point::new(cx, cy - radius - 16.0),
+                color: kit::rgb(tag.color),
+                size: (12.0 * self.state.zoom).max(10.0).into(),
+                align_x: iced::alignment::Horizontal::Center.into(),
+                ..Text::default()
+            };
+            frame.fill_text(label);
+        }
+    }
+
     /// Draw a single node.
-    fn draw_node(&self, frame: &mut Frame, node: &super::Node, center: Point) {
+    fn draw_node(&self, frame: &mut Frame, node: &super::Node, center: Point, dimmed: bool) {
         let pos = self.apply_transform(node.position, center);
         // Node radius scales with zoom but has a minimum size
         let radius = (25.0 * self.state.zoom).max(15.0);
@@ -335,6 +406,8 @@ impl<'a> TopologyGraphProgram<'a> {
                 NodeType::Unknown => self.node_unknown_color(),
             },
         };
+        let base_color = dim(base_color, dimmed);
+        let label_color = dim(self.node_label_color(), dimmed);
 
         // Highlight if selected
         let is_selected = self.state.selected_node.as_ref() == Some(&node.id);
@@ -377,7 +450,7 @@ impl<'a> TopologyGraphProgram<'a> {
         let label = Text {
             content: node.label.clone(),
             position: Point::new(pos.x, pos.y + radius + 14.0),
-            color: self.node_label_color(),
+            color: label_color,
             size: (14.0 * self.state.zoom).max(11.0).into(),
             align_x: iced::alignment::Horizontal::Center.into(),
             ..Text::default()
@@ -390,7 +463,7 @@ impl<'a> TopologyGraphProgram<'a> {
                 let cpu_text = Text {
                     content: format!("CPU: {:.0}%", cpu),
                     position: Point::new(pos.x, pos.y - 5.0),
-                    color: self.node_label_color(),
+                    color: label_color,
                     size: (10.0 * self.state.zoom).max(9.0).into(),
                     align_x: iced::alignment::Horizontal::Center.into(),
                     ..Text::default()
@@ -402,7 +475,7 @@ impl<'a> TopologyGraphProgram<'a> {
                 let mem_text = Text {
                     content: format!("Mem: {:.0}%", mem),
                     position: Point::new(pos.x, pos.y + 5.0),
-                    color: self.node_label_color(),
+                    color: label_color,
                     size: (10.0 * self.state.zoom).max(9.0).into(),
                     align_x: iced::alignment::Horizontal::Center.into(),
                     ..Text::default()
@@ -413,7 +486,7 @@ impl<'a> TopologyGraphProgram<'a> {
     }
 
     /// Draw an edge between two nodes.
-    fn draw_edge(&self, frame: &mut Frame, edge: &super::Edge, center: Point) {
+    fn draw_edge(&self, frame: &mut Frame, edge: &super::Edge, center: Point, dimmed: bool) {
         let from_node = match self.state.nodes.get(&edge.from) {
             Some(n) => n,
             None => return,
@@ -426,11 +499,8 @@ impl<'a> TopologyGraphProgram<'a> {
         let from_pos = self.apply_transform(from_node.position, center);
         let to_pos = self.apply_transform(to_node.position, center);
 
-        // Edge width based on bandwidth
-        let base_width = 2.0;
-        let max_width = 10.0;
-        let bandwidth_factor = (edge.bytes as f32 / 1_000_000.0).clamp(0.0, 1.0);
-        let width = base_width + bandwidth_factor * (max_width - base_width);
+        // Edge width based on bandwidth (log-scaled, #398)
+        let width = edge_width(edge.bytes);
 
         // Edge color
         let is_selected = self.state.selected_edge
@@ -451,6 +521,7 @@ impl<'a> TopologyGraphProgram<'a> {
         } else {
             self.edge_default_color()
         };
+        let color = dim(color, dimmed);
 
         // Draw edge line
         let mut path = canvas::path::Builder::new();
@@ -465,13 +536,21 @@ impl<'a> TopologyGraphProgram<'a> {
                 .with_width(width * self.state.zoom),
         );
 
-        // Draw bandwidth label at midpoint
-        if edge.bytes > 0 {
+        // Bandwidth label at the midpoint once zoomed in enough to read it; a
+        // selected edge always shows full detail (#398).
+        let content = if is_selected {
+            Some(edge_detail_label(edge))
+        } else if edge.bytes > 0 && self.state.zoom >= EDGE_LABEL_MIN_ZOOM {
+            Some(flow_volume_unit().format(edge.bytes, edge.duration_ms))
+        } else {
+            None
+        };
+        if let Some(content) = content {
             let mid = Point::new((from_pos.x + to_pos.x) / 2.0, (from_pos.y + to_pos.y) / 2.0);
             let label = Text {
-                content: format_bytes(edge.bytes),
+                content,
                 position: Point::new(mid.x, mid.y - 8.0),
-                color: self.edge_label_color(),
+                color: dim(self.edge_label_color(), dimmed),
                 size: (10.0 * self.state.zoom).max(8.0).into(),
                 align_x: iced::alignment::Horizontal::Center.into(),
                 ..Text::default()
@@ -500,6 +579,59 @@ impl<'a> TopologyGraphProgram<'a> {
     }
 }
 
+/// Thinnest edge stroke (unzoomed), for links with little or no traffic.
+pub const EDGE_MIN_WIDTH: f32 = 1.5;
+/// Thickest edge stroke (unzoomed), reached at 10 GB.
+pub const EDGE_MAX_WIDTH: f32 = 10.0;
+/// Bytes at or below which an edge stays at [`EDGE_MIN_WIDTH`].
+const EDGE_WIDTH_MIN_BYTES: f64 = 1e3;
+/// Bytes at or above which an edge is drawn at [`EDGE_MAX_WIDTH`].
+const EDGE_WIDTH_MAX_BYTES: f64 = 1e10;
+/// Opacity factor for nodes and edges outside the isolated neighborhood (#476).
+const ISOLATION_DIM_ALPHA: f32 = 0.2;
+
+/// Fade `color` when it's outside the isolated neighborhood.
+fn dim(color: Color, dimmed: bool) -> Color {
+    if dimmed {
+        Color {
+            a: color.a * ISOLATION_DIM_ALPHA,
+            ..color
+        }
+    } else {
+        color
+    }
+}
+
+/// Zoom level from which non-selected edges get a bandwidth label.
+pub const EDGE_LABEL_MIN_ZOOM: f32 = 0.8;
+
+/// Edge stroke width (before zoom) for a link that carried `bytes` (#398).
+///
+/// Log-scaled between 1 KB and 10 GB so a chatty link doesn't dwarf the rest
+/// while a 1 MB vs 1 GB difference is still visible. Monotonic and bounded to
+/// [`EDGE_MIN_WIDTH`]..=[`EDGE_MAX_WIDTH`].
+pub fn edge_width(bytes: u64) -> f32 {
+    let lo = EDGE_WIDTH_MIN_BYTES.log10();
+    let hi = EDGE_WIDTH_MAX_BYTES.log10();
+    let t = (((bytes as f64).max(1.0).log10() - lo) / (hi - lo)).clamp(0.0, 1.0);
+    EDGE_MIN_WIDTH + t as f32 * (EDGE_MAX_WIDTH - EDGE_MIN_WIDTH)
+}
+
+/// Full label for a selected edge: volume (in the flow unit setting, #477),
+/// packets and protocol when known.
+fn edge_detail_label(edge: &super::Edge) -> String {
+    let mut label = format!(
+        "{} · {} pkts",
+        flow_volume_unit().format(edge.bytes, edge.duration_ms),
+        edge.packets
+    );
+    if let Some(protocol) = &edge.protocol {
+        label.push_str(" · ");
+        label.push_str(protocol);
+    }
+    label
+}
+
 /// Format bytes as human-readable string.
 pub fn format_bytes(bytes: u64) -> String {
     if bytes >= 1_000_000_000 {
@@ -537,6 +669,23 @@ pub fn screen_to_graph_coords(
     )
 }
 
+/// The pan that keeps the graph point under `anchor` stationary when zooming
+/// from `zoom` to `new_zoom` (#474). `anchor` is the cursor's offset from the
+/// canvas center in screen pixels.
+///
+/// A graph point `g` is drawn at `(g + pan) * zoom` from the center, so the
+/// point under the anchor is `anchor / zoom - pan`; solving for the pan that
+/// puts it back under the anchor at `new_zoom` gives the result.
+pub fn zoom_around_anchor(
+    zoom: f32,
+    pan: (f32, f32),
+    anchor: (f32, f32),
+    new_zoom: f32,
+) -> (f32, f32) {
+    let shift = 1.0 / new_zoom - 1.0 / zoom;
+    (pan.0 + anchor.0 * shift, pan.1 + anchor.1 * shift)
+}
+
 /// Find a node at the given graph position.
 ///
 /// Returns the node ID if a node is found within the hit radius.
@@ -572,6 +721,39 @@ mod tests {
         assert_eq!(format_bytes(1_500_000_000), "1.5 GB");
     }
 
+    #[test]
+    fn test_edge_width_monotonic_and_bounded() {
+        let samples = [
+            0u64,
+            1,
+            999,
+            1_000,
+            50_000,
+            1_000_000,
+            250_000_000,
+            1_000_000_000,
+            10_000_000_000,
+            u64::MAX,
+        ];
+        let widths: Vec<f32> = samples.iter().map(|&b| edge_width(b)).collect();
+
+        for w in &widths {
+            assert!(
+                (EDGE_MIN_WIDTH..=EDGE_MAX_WIDTH).contains(w),
+                "width {w} out of range"
+            );
+        }
+        for pair in widths.windows(2) {
+            assert!(pair[0] <= pair[1], "widths not monotonic: {widths:?}");
+        }
+
+        // Idle links sit at the floor, huge links at the ceiling...
+        assert_eq!(edge_width(0), EDGE_MIN_WIDTH);
+        assert_eq!(edge_width(u64::MAX), EDGE_MAX_WIDTH);
+        // ...and the log scale still separates 1 MB from 1 GB.
+        assert!(edge_width(1_000_000_000) - edge_width(1_000_000) > 2.0);
+    }
+
     // ========================================================================
     // Coordinate conversion tests
     // ========================================================================
@@ -844,4 +1026,29 @@ mod tests {
             graph_pos.y
         );
     }
+
+    /// #474: zooming around the cursor keeps the world point under it fixed.
+    #[test]
+    fn test_zoom_around_anchor_keeps_point_under_cursor() {
+        let bounds = Rectangle::new(Point::new(20.0, 40.0), iced::Size::new(800.0, 600.0));
+        let cursor = Point::new(650.0, 120.0);
+        let anchor = (
+            cursor.x - bounds.x - bounds.width / 2.0,
+            cursor.y - bounds.y - bounds.height / 2.0,
+        );
+        let (zoom, pan) = (1.2, (35.0, -80.0));
+        let before = screen_to_graph_coords(cursor, bounds, zoom, pan);
+
+        for new_zoom in [zoom * 1.2, zoom / 1.2, 3.0, 0.3] {
+            let new_pan = zoom_around_anchor(zoom, pan, anchor, new_zoom);
+            let after = screen_to_graph_coords(cursor, bounds, new_zoom, new_pan);
+            assert!(
+                (after.x - before.x).abs() < 1e-3 && (after.y - before.y).abs() < 1e-3,
+                "at zoom {new_zoom}: {before:?} moved to {after:?}"
+            );
+        }
+
+        // Zooming around the center leaves the pan alone.
+        assert_eq!(zoom_around_anchor(zoom, pan, (0.0, 0.0), 2.0), pan);
+    }
 }
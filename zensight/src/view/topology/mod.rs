@@ -3,27 +3,40 @@
 //! Displays interconnections between VMs/hosts as an interactive graph,
 //! showing network bandwidth between each link.
 
+pub mod export;
 pub mod graph;
 pub mod layout;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::time::Instant;
 
 use iced::widget::canvas::Cache;
-use iced::widget::{column, container, row, text, text_input};
+use iced::widget::{column, container, row, slider, text, text_input};
 use iced::{Alignment, Element, Length};
 use iced_anim::widget::button;
 
 use crate::app::AppTheme;
 use crate::message::{DeviceId, Message};
 use crate::view::dashboard::DeviceState;
+use crate::view::groups::{GroupTag, GroupsState};
 use crate::view::icons::{self, IconSize};
 
+pub use export::TopologyExportFormat;
 pub use graph::TopologyGraph;
 pub use layout::{LayoutConfig, arrange_circle, center_layout, layout_step};
 
 /// Unique identifier for a topology node.
 pub type NodeId = String;
 
+/// A node and everything directly connected to it (#476).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Neighborhood {
+    /// The node itself and its adjacent nodes.
+    pub nodes: HashSet<NodeId>,
+    /// Indices into [`TopologyState::edges`] of the edges touching the node.
+    pub edges: HashSet<usize>,
+}
+
 /// State for the topology view.
 #[derive(Debug)]
 pub struct TopologyState {
@@ -49,12 +62,29 @@ pub struct TopologyState {
     pub layout_config: LayoutConfig,
     /// Whether the layout is currently stable.
     pub layout_stable: bool,
+    /// Total kinetic energy after the last layout step (#396).
+    pub layout_energy: f32,
+    /// Name/color of each device group that has at least one node, keyed by
+    /// group id (#397). Drives the cluster hulls drawn behind member nodes.
+    pub cluster_tags: HashMap<u32, GroupTag>,
+    /// Pinned node positions keyed by node id (#400), persisted across
+    /// sessions. Kept for hosts not currently in the graph so their spot is
+    /// waiting when they reappear.
+    pinned_positions: BTreeMap<NodeId, (f32, f32)>,
     /// Last netring flows fetched, kept so the edge set can be rebuilt when the
     /// netlink neighbor table arrives separately (#49).
     last_flows: Vec<zensight_common::FlowRecord>,
     /// Last netlink neighbor (ARP/NDP) table fetched, merged into the edge set
     /// as adjacency links (#49).
     last_neighbors: Vec<zensight_common::NeighborRecord>,
+    /// When the last throttled layout frame ran (#473).
+    last_layout_frame: Option<Instant>,
+    /// Edges that carried fewer bytes are hidden and exert no layout force
+    /// (#475). 0 shows every edge.
+    pub min_edge_bytes: u64,
+    /// Node whose neighborhood is in focus (#476); everything not directly
+    /// connected to it is drawn dimmed.
+    pub isolated_node: Option<NodeId>,
 }
 
 impl Default for TopologyState {
@@ -71,8 +101,14 @@ impl Default for TopologyState {
             search_query: String::new(),
             layout_config: LayoutConfig::default(),
             layout_stable: true,
+            layout_energy: 0.0,
+            cluster_tags: HashMap::new(),
+            pinned_positions: BTreeMap::new(),
             last_flows: Vec::new(),
             last_neighbors: Vec::new(),
+            last_layout_frame: None,
+            min_edge_bytes: 0,
+            isolated_node: None,
         }
     }
 }
@@ -100,13 +136,17 @@ impl TopologyState {
             let node_id = device_id.source.clone();
 
             if !self.nodes.contains_key(&node_id) {
-                // Create new node - position will be set by arrange_in_circle
+                // Create new node - a saved pinned spot wins (#400), otherwise
+                // the position will be set by arrange_in_circle
+                let saved = self.pinned_positions.get(&node_id).copied();
                 self.nodes.insert(
                     node_id.clone(),
                     Node {
                         id: node_id.clone(),
                         label: device_id.source.clone(),
                         is_healthy: device_state.is_healthy,
+                        position: saved.unwrap_or_default(),
+                        pinned: saved.is_some(),
                         ..Default::default()
                     },
                 );
@@ -118,6 +158,14 @@ impl TopologyState {
                 node.protocols.insert(device_id.protocol);
                 node.metric_count += device_state.metric_count;
                 node.update_from_metrics(&device_state.metrics);
+                // Throughput comes from the counters' rates, summed over the
+                // host's interfaces (#439).
+                if let Some(rx) = device_state.network_rate("/rx_bytes") {
+                    node.network_rx = Some(rx);
+                }
+                if let Some(tx) = device_state.network_rate("/tx_bytes") {
+                    node.network_tx = Some(tx);
+                }
             }
         }
 
@@ -185,6 +233,56 @@ impl TopologyState {
         self.cache.clear();
     }
 
+    /// Cluster nodes by their assigned device group (#397). Group assignments
+    /// are per `protocol/source` device, so a host is clustered under the
+    /// lowest-id group any of its facets belongs to; hosts with no group stay
+    /// in the default (`None`) cluster. Re-settles the layout when membership
+    /// changes.
+    pub fn apply_groups(&mut self, groups: &GroupsState) {
+        let mut by_source: HashMap<&str, u32> = HashMap::new();
+        for (device_key, group_ids) in &groups.assignments {
+            let Some((_, source)) = device_key.split_once('/') else {
+                continue;
+            };
+            let Some(&group_id) = group_ids
+                .iter()
+                .filter(|id| groups.groups.contains_key(id))
+                .min()
+            else {
+                continue;
+            };
+            by_source
+                .entry(source)
+                .and_modify(|g| *g = (*g).min(group_id))
+                .or_insert(group_id);
+        }
+
+        let mut changed = false;
+        for node in self.nodes.values_mut() {
+            let group = by_source.get(node.id.as_str()).copied();
+            if node.group != group {
+                node.group = group;
+                changed = true;
+            }
+        }
+
+        let tags: HashMap<u32, GroupTag> = self
+            .nodes
+            .values()
+            .filter_map(|n| n.group)
+            .filter_map(|id| Some((id, GroupTag::from_group(groups.groups.get(&id)?))))
+            .collect();
+
+        if changed {
+            self.layout_stable = false;
+        }
+        // Runs every tick while the view is open; only redraw on a real change.
+        if changed || tags != self.cluster_tags {
+            self.cluster_tags = tags;
+            self.cache.clear();
+        }
+    }
+
     /// Replace the edge set with edges derived from *observed* netring flow
     /// records (#25). `ip_to_node` maps an endpoint IP to a topology node id
     /// (built from correlations / node sources). Flows whose src and dst both
@@ -289,6 +387,74 @@ impl TopologyState {
         self.cache.clear();
     }
 
+    /// Whether `edge` passes the minimum-bandwidth filter (#475).
+    pub fn is_edge_visible(&self, edge: &Edge) -> bool {
+        edge.bytes >= self.min_edge_bytes
+    }
+
+    /// The edges drawn and fed to the layout (#475).
+    pub fn visible_edges(&self) -> impl Iterator<Item = &Edge> {
+        self.edges.iter().filter(|edge| self.is_edge_visible(edge))
+    }
+
+    /// Hide edges that carried fewer than `bytes` (#475). The layout
+    /// re-settles without their springs, and a hidden selected edge is
+    /// deselected.
+    pub fn set_min_edge_bytes(&mut self, bytes: u64) {
+        if bytes == self.min_edge_bytes {
+            return;
+        }
+        self.min_edge_bytes = bytes;
+        if let Some(edge) = self.selected_edge.and_then(|i| self.edges.get(i))
+            && !self.is_edge_visible(edge)
+        {
+            self.selected_edge = None;
+        }
+        self.layout_stable = false;
+        self.cache.clear();
+    }
+
+    /// The nodes and visible edges directly connected to `node_id`, or `None`
+    /// when the node isn't in the graph (#476).
+    pub fn neighborhood(&self, node_id: &str) -> Option<Neighborhood> {
+        if !self.nodes.contains_key(node_id) {
+            return None;
+        }
+        let mut neighborhood = Neighborhood::default();
+        neighborhood.nodes.insert(node_id.to_string());
+        for (index, edge) in self.edges.iter().enumerate() {
+            if !self.is_edge_visible(edge) {
+                continue;
+            }
+            let neighbor = if edge.from == node_id {
+                &edge.to
+            } else if edge.to == node_id {
+                &edge.from
+            } else {
+                continue;
+            };
+            neighborhood.nodes.insert(neighbor.clone());
+            neighborhood.edges.insert(index);
+        }
+        Some(neighborhood)
+    }
+
+    /// Neighborhood of the isolated node, if one is set and still present.
+    pub fn isolated_neighborhood(&self) -> Option<Neighborhood> {
+        self.neighborhood(self.isolated_node.as_deref()?)
+    }
+
+    /// Focus on `node_id` and its direct connections (#476). Isolating the
+    /// already-isolated node turns isolation off again.
+    pub fn isolate_node(&mut self, node_id: NodeId) {
+        if self.isolated_node.as_ref() == Some(&node_id) {
+            self.isolated_node = None;
+        } else {
+            self.isolated_node = Some(node_id);
+        }
+        self.cache.clear();
+    }
+
     /// Clear selection.
     pub fn clear_selection(&mut self) {
         self.selected_node = None;
@@ -308,6 +474,18 @@ impl TopologyState {
         self.cache.clear();
     }
 
+    /// Zoom in or out one step around `anchor`, the cursor's offset from the
+    /// canvas center, keeping the point under it in place (#474).
+    pub fn zoom_at(&mut self, zoom_in: bool, anchor: (f32, f32)) {
+        let zoom = self.zoom;
+        if zoom_in {
+            self.zoom_in();
+        } else {
+            self.zoom_out();
+        }
+        self.pan = graph::zoom_around_anchor(zoom, self.pan, anchor, self.zoom);
+    }
+
     /// Reset zoom to 100%.
     pub fn reset_zoom(&mut self) {
         self.zoom = 1.0;
@@ -327,13 +505,40 @@ impl TopologyState {
         if let Some(node) = self.nodes.get_mut(node_id) {
             node.position = (x, y);
             node.velocity = (0.0, 0.0);
+            // The rest of the graph re-settles around the moved node (#473).
+            self.layout_stable = false;
             self.cache.clear();
         }
     }
 
-    /// End node drag.
-    pub fn end_node_drag(&mut self, _node_id: &NodeId) {
-        // Node stays pinned after drag
+    /// End node drag. The node stays pinned, and its final position is
+    /// remembered (#400). Returns true if the remembered position changed, so
+    /// the caller knows to persist it.
+    pub fn end_node_drag(&mut self, node_id: &NodeId) -> bool {
+        let Some(node) = self.nodes.get(node_id).filter(|n| n.pinned) else {
+            return false;
+        };
+        self.pinned_positions.insert(node_id.clone(), node.position) != Some(node.position)
+    }
+
+    /// Pinned node positions to persist (#400).
+    pub fn pinned_positions(&self) -> &BTreeMap<NodeId, (f32, f32)> {
+        &self.pinned_positions
+    }
+
+    /// Restore persisted pinned positions (#400): nodes already in the graph
+    /// snap to their saved spot and pin; the rest apply when the node appears.
+    pub fn restore_pinned_positions(&mut self, positions: BTreeMap<NodeId, (f32, f32)>) {
+        for (id, &position) in &positions {
+            if let Some(node) = self.nodes.get_mut(id) {
+                node.position = position;
+                node.velocity = (0.0, 0.0);
+                node.pinned = true;
+            }
+        }
+        self.pinned_positions = positions;
+        self.layout_stable = false;
+        self.cache.clear();
     }
 
     /// Update pan offset.
@@ -358,12 +563,13 @@ impl TopologyState {
         }
     }
 
-    /// Run layout iterations for smooth convergence.
+    /// Run up to `layout_config.steps_per_tick` layout iterations, stopping
+    /// early once the system's kinetic energy says it has settled.
     /// Returns true if the layout is stable.
     pub fn run_layout_step(&mut self) -> bool {
-        // Run 3 iterations per frame - balance between speed and smoothness
-        for _ in 0..3 {
-            self.layout_stable = layout_step(self, &self.layout_config.clone());
+        let config = self.layout_config.clone();
+        for _ in 0..config.steps_per_tick.max(1) {
+            self.layout_stable = layout_step(self, &config);
             if self.layout_stable {
                 break;
             }
@@ -371,6 +577,31 @@ impl TopologyState {
         self.layout_stable
     }
 
+    /// Whether layout frames should be scheduled (#473): auto-layout is on and
+    /// the graph hasn't settled. Once stable nothing is requested until new
+    /// nodes, a drag or a group change wakes the layout again.
+    pub fn needs_layout(&self) -> bool {
+        self.auto_layout && !self.layout_stable
+    }
+
+    /// Run one layout frame at `now`, throttled to
+    /// [`LayoutConfig::max_fps`] (#473). Returns true if a frame ran; frames
+    /// arriving early, or after the layout settled, are skipped.
+    pub fn layout_frame(&mut self, now: Instant) -> bool {
+        if !self.needs_layout() {
+            return false;
+        }
+        if let Some(last) = self.last_layout_frame
+            && now.saturating_duration_since(last) < self.layout_config.frame_interval()
+        {
+            return false;
+        }
+        self.last_layout_frame = Some(now);
+        self.run_layout_step();
+        self.cache.clear();
+        true
+    }
+
     /// Arrange nodes in a circle (useful for initial layout).
     pub fn arrange_in_circle(&mut self, radius: f32) {
         arrange_circle(self, radius);
@@ -421,10 +652,10 @@ pub struct Node {
     pub cpu_usage: Option<f64>,
     /// Memory usage percentage (0-100). From sysinfo.
     pub memory_usage: Option<f64>,
-    /// Network RX bytes/sec. From sysinfo.
-    pub network_rx: Option<u64>,
-    /// Network TX bytes/sec. From sysinfo.
-    pub network_tx: Option<u64>,
+    /// Network RX bytes/sec across all interfaces. From sysinfo.
+    pub network_rx: Option<f64>,
+    /// Network TX bytes/sec across all interfaces. From sysinfo.
+    pub network_tx: Option<f64>,
     /// Interfaces up / total, from netlink `iface/<n>/up` (#83).
     pub iface_up: Option<u32>,
     pub iface_total: Option<u32>,
@@ -450,6 +681,9 @@ pub struct Node {
     /// protocol-agnostic signal so nodes whose protocol has no dedicated panel
     /// section (netflow / snmp / modbus / gnmi) still show something useful.
     pub metric_count: usize,
+    /// Device group this host is clustered under (#397); `None` is the default
+    /// cluster of ungrouped hosts. Set by [`TopologyState::apply_groups`].
+    pub group: Option<u32>,
 }
 
 impl Node {
@@ -501,18 +735,8 @@ impl Node {
                     }
                 }
                 _ => {
-                    // sysinfo network counters
-                    if name.starts_with("network/") && name.ends_with("/rx_bytes") {
-                        if let TelemetryValue::Counter(v) = &point.value {
-                            self.network_rx = Some(*v);
-                        }
-                    } else if name.starts_with("network/")
-                        && name.ends_with("/tx_bytes")
-                        && let TelemetryValue::Counter(v) = &point.value
-                    {
-                        self.network_tx = Some(*v);
-                    } else if name.starts_with("iface/") && name.ends_with("/up") {
-                        // netlink per-interface up/down
+                    // netlink per-interface up/down
+                    if name.starts_with("iface/") && name.ends_with("/up") {
                         saw_iface = true;
                         iface_total += 1;
                         if let TelemetryValue::Boolean(true) = &point.value {
@@ -555,6 +779,9 @@ pub struct Edge {
     pub bytes: u64,
     /// Packets transferred.
     pub packets: u64,
+    /// How long the traffic took, for per-second volumes (#477): the longest
+    /// contributing flow. 0 when unknown.
+    pub duration_ms: u64,
     /// Protocol (TCP, UDP, etc.).
     pub protocol: Option<String>,
     /// Last seen timestamp.
@@ -583,15 +810,16 @@ pub fn endpoint_ip(endpoint: &str) -> &str {
 
 /// Aggregate observed flows into topology edges (#25). One edge per unordered
 /// pair of *distinct* known nodes, summing bytes/packets; the protocol of the
-/// highest-volume contributing flow labels the edge. Flows touching an unknown
-/// IP or a self-loop are skipped. Pure — the unit of testing for edge derivation.
+/// highest-volume contributing flow labels the edge and the longest one sets its
+/// duration (#477). Flows touching an unknown IP or a self-loop are skipped.
+/// Pure — the unit of testing for edge derivation.
 pub fn edges_from_flows(
     flows: &[zensight_common::FlowRecord],
     ip_to_node: &HashMap<String, NodeId>,
     now_ms: i64,
 ) -> Vec<Edge> {
     // Keyed by ordered node pair so (a,b) and (b,a) aggregate together.
-    let mut acc: HashMap<(NodeId, NodeId), (u64, u64, String, u64)> = HashMap::new();
+    let mut acc: HashMap<(NodeId, NodeId), (u64, u64, u64, String, u64)> = HashMap::new();
     for f in flows {
         let src_node = ip_to_node.get(endpoint_ip(&f.src));
         let dst_node = ip_to_node.get(endpoint_ip(&f.dst));
@@ -606,26 +834,30 @@ pub fn edges_from_flows(
         } else {
             (b.clone(), a.clone())
         };
-        let entry = acc.entry(key).or_insert((0, 0, f.proto.clone(), 0));
+        let entry = acc.entry(key).or_insert((0, 0, 0, f.proto.clone(), 0));
         entry.0 += f.bytes;
         entry.1 += f.packets;
+        entry.2 = entry.2.max(f.duration_ms);
         // Label the edge with the protocol of its largest single flow.
-        if f.bytes > entry.3 {
-            entry.2 = f.proto.clone();
-            entry.3 = f.bytes;
+        if f.bytes > entry.4 {
+            entry.3 = f.proto.clone();
+            entry.4 = f.bytes;
         }
     }
     let mut edges: Vec<Edge> = acc
         .into_iter()
-        .map(|((from, to), (bytes, packets, protocol, _))| Edge {
-            from,
-            to,
-            bytes,
-            packets,
-            protocol: Some(protocol),
-            last_seen: now_ms,
-            alert: None,
-        })
+        .map(
+            |((from, to), (bytes, packets, duration_ms, protocol, _))| Edge {
+                from,
+                to,
+                bytes,
+                packets,
+                duration_ms,
+                protocol: Some(protocol),
+                last_seen: now_ms,
+                alert: None,
+            },
+        )
         .collect();
     // Stable order: heaviest edges first, then by endpoints.
     edges.sort_by(|a, b| {
@@ -657,7 +889,7 @@ pub fn edges_from_neighbors(
     ip_to_node: &HashMap<String, NodeId>,
     now_ms: i64,
 ) -> (Vec<Edge>, std::collections::BTreeSet<NodeId>) {
-    use std::collections::{BTreeMap, BTreeSet};
+    use std::collections::BTreeSet;
 
     let mut pairs: BTreeSet<(NodeId, NodeId)> = BTreeSet::new();
     let mut routers: BTreeSet<NodeId> = BTreeSet::new();
@@ -688,6 +920,7 @@ pub fn edges_from_neighbors(
             to,
             bytes: 0,
             packets: 0,
+            duration_ms: 0,
             protocol: None,
             last_seen: now_ms,
             alert: None,
@@ -705,7 +938,8 @@ pub fn topology_view<'a>(state: &'a TopologyState, theme: AppTheme) -> Element<'
     // Show the node panel, or the edge detail panel (#25), beside the graph.
     let main_content: Element<'a, Message> = if let Some(ref node_id) = state.selected_node {
         if let Some(node) = state.nodes.get(node_id) {
-            let panel = render_node_info_panel(node);
+            let isolated = state.isolated_node.as_ref() == Some(node_id);
+            let panel = render_node_info_panel(node, isolated);
             row![graph, panel].spacing(10).into()
         } else {
             graph
@@ -767,8 +1001,10 @@ fn primary_protocol(node: &Node) -> zensight_common::Protocol {
     }
 }
 
-/// Render the node info panel (shown when a node is selected).
-fn render_node_info_panel(node: &Node) -> Element<'_, Message> {
+/// Render the node info panel (shown when a node is selected). `isolated`
+/// says whether this node is the isolation focus (#476).
+fn render_node_info_panel(node: &Node, isolated: bool) -> Element<'_, Message> {
+    use crate::view::formatting::format_rate;
     use iced::widget::rule;
 
     // Header with a protocol-aware icon and name (#83).
@@ -848,18 +1084,15 @@ fn render_node_info_panel(node: &Node) -> Element<'_, Message> {
         info_items = info_items.push(text("Network I/O").size(12));
 
         if let Some(rx) = node.network_rx {
-            info_items =
-                info_items.push(text(format!("  RX: {}", graph::format_bytes(rx))).size(11));
+            info_items = info_items.push(text(format!("  RX: {}", format_rate(rx))).size(11));
         }
         if let Some(tx) = node.network_tx {
-            info_items =
-                info_items.push(text(format!("  TX: {}", graph::format_bytes(tx))).size(11));
+            info_items = info_items.push(text(format!("  TX: {}", format_rate(tx))).size(11));
         }
         // Total
-        let total = node.network_rx.unwrap_or(0) + node.network_tx.unwrap_or(0);
-        if total > 0 {
-            info_items =
-                info_items.push(text(format!("  Total: {}", graph::format_bytes(total))).size(11));
+        let total = node.network_rx.unwrap_or(0.0) + node.network_tx.unwrap_or(0.0);
+        if total > 0.0 {
+            info_items = info_items.push(text(format!("  Total: {}", format_rate(total))).size(11));
         }
     }
 
@@ -934,6 +1167,17 @@ fn render_node_info_panel(node: &Node) -> Element<'_, Message> {
     .width(Length::Fill);
     info_items = info_items.push(view_btn);
 
+    let isolate_label = if isolated {
+        "Show All Nodes"
+    } else {
+        "Isolate Node"
+    };
+    let isolate_btn = button(text(isolate_label).size(11))
+        .on_press(Message::TopologyIsolateNode(node.id.clone()))
+        .style(iced::widget::button::secondary)
+        .width(Length::Fill);
+    info_items = info_items.push(isolate_btn);
+
     let clear_btn = button(text("Clear Selection").size(11))
         .on_press(Message::TopologyClearSelection)
         .style(iced::widget::button::secondary)
@@ -950,8 +1194,7 @@ fn render_node_info_panel(node: &Node) -> Element<'_, Message> {
 /// Render the edge detail panel (#25): src→dst, protocol, observed bytes/packets,
 /// and when last seen. Shown when an edge is selected.
 fn render_edge_info_panel(edge: &Edge) -> Element<'_, Message> {
-    use crate::view::formatting::format_timestamp;
-    use crate::view::topology::graph::format_bytes;
+    use crate::view::formatting::{flow_volume_unit, format_relative_time};
     use iced::widget::rule;
 
     let header = row![
@@ -967,9 +1210,17 @@ fn render_edge_info_panel(edge: &Edge) -> Element<'_, Message> {
         rule::horizontal(1),
         text("Observed flow").size(12),
         text(format!("Protocol: {proto}")).size(11),
-        text(format!("Bytes: {}", format_bytes(edge.bytes))).size(11),
+        text(format!(
+            "Traffic: {}",
+            flow_volume_unit().format(edge.bytes, edge.duration_ms)
+        ))
+        .size(11),
         text(format!("Packets: {}", edge.packets)).size(11),
-        text(format!("Last seen: {}", format_timestamp(edge.last_seen))).size(11),
+        text(format!(
+            "Last seen: {}",
+            format_relative_time(edge.last_seen)
+        ))
+        .size(11),
     ]
     .spacing(8);
 
@@ -988,6 +1239,28 @@ fn render_edge_info_panel(edge: &Edge) -> Element<'_, Message> {
         .into()
 }
 
+/// Top of the edge-filter slider: 10^9 bytes (1 GB).
+const MAX_EDGE_FILTER_EXPONENT: f32 = 9.0;
+
+/// The byte threshold for a slider position (#475): `10^exponent`, with the
+/// far left (0) meaning no filter.
+fn min_edge_bytes_for(exponent: f32) -> u64 {
+    if exponent <= 0.0 {
+        0
+    } else {
+        10f64.powf(exponent as f64).round() as u64
+    }
+}
+
+/// The slider position for a byte threshold, inverse of [`min_edge_bytes_for`].
+fn edge_filter_exponent(bytes: u64) -> f32 {
+    if bytes <= 1 {
+        0.0
+    } else {
+        ((bytes as f64).log10() as f32).min(MAX_EDGE_FILTER_EXPONENT)
+    }
+}
+
 /// Render the topology header.
 fn render_header(state: &TopologyState) -> Element<'_, Message> {
     let back_button = button(
@@ -1001,7 +1274,32 @@ fn render_header(state: &TopologyState) -> Element<'_, Message> {
     let title = text("Network Topology").size(24);
 
     let node_count = text(format!("{} nodes", state.nodes.len())).size(14);
-    let edge_count = text(format!("{} connections", state.edges.len())).size(14);
+    let edge_count = if state.min_edge_bytes > 0 {
+        let visible = state.visible_edges().count();
+        text(format!("{visible} of {} connections", state.edges.len())).size(14)
+    } else {
+        text(format!("{} connections", state.edges.len())).size(14)
+    };
+
+    // Minimum-bandwidth edge filter (#475), log-scaled from 1 B to 1 GB.
+    let bandwidth_label = if state.min_edge_bytes == 0 {
+        "All links".to_string()
+    } else {
+        format!("≥ {}", graph::format_bytes(state.min_edge_bytes))
+    };
+    let bandwidth_filter = row![
+        text("Min bandwidth:").size(12),
+        slider(
+            0.0..=MAX_EDGE_FILTER_EXPONENT,
+            edge_filter_exponent(state.min_edge_bytes),
+            |exponent| Message::TopologySetMinEdgeBytes(min_edge_bytes_for(exponent)),
+        )
+        .step(0.25)
+        .width(Length::Fixed(120.0)),
+        text(bandwidth_label).size(12),
+    ]
+    .spacing(6)
+    .align_y(Alignment::Center);
 
     // Show layout status
     let layout_status = if !state.auto_layout {
@@ -1009,7 +1307,11 @@ fn render_header(state: &TopologyState) -> Element<'_, Message> {
     } else if state.layout_stable {
         text("Layout: Stable").size(10)
     } else {
-        text("Layout: Adjusting...").size(10)
+        text(format!(
+            "Layout: Adjusting... (energy {:.1})",
+            state.layout_energy
+        ))
+        .size(10)
     };
 
     // Show search match count if searching
@@ -1057,6 +1359,16 @@ fn render_header(state: &TopologyState) -> Element<'_, Message> {
         iced::widget::button::secondary
     });
 
+    // Export for documentation (#399); disabled until there is a graph.
+    let export_btn = |label: &'static str, format: TopologyExportFormat| {
+        let btn = button(text(label).size(12)).style(iced::widget::button::secondary);
+        if state.nodes.is_empty() {
+            btn
+        } else {
+            btn.on_press(Message::ExportTopology(format))
+        }
+    };
+
     // Search input
     let search_input = text_input("Search nodes...", &state.search_query)
         .on_input(Message::TopologySetSearch)
@@ -1087,7 +1399,10 @@ fn render_header(state: &TopologyState) -> Element<'_, Message> {
         .push(zoom_label)
         .push(zoom_in_btn)
         .push(reset_btn)
-        .push(auto_layout_btn);
+        .push(bandwidth_filter)
+        .push(auto_layout_btn)
+        .push(export_btn("Export DOT", TopologyExportFormat::Dot))
+        .push(export_btn("Export GraphML", TopologyExportFormat::GraphMl));
 
     header.into()
 }
@@ -1401,6 +1716,7 @@ mod tests {
             to: "b".to_string(),
             bytes: 10,
             packets: 1,
+            duration_ms: 0,
             protocol: None,
             last_seen: 0,
             alert: None,
@@ -1571,4 +1887,136 @@ mod tests {
         state.apply_alerts(&HashMap::new());
         assert!(state.nodes["host1"].alerts.is_empty());
     }
+
+    #[test]
+    fn test_pinned_position_survives_save_and_reload() {
+        use crate::view::settings::PersistentSettings;
+        use zensight_common::Protocol;
+
+        let mut devices: HashMap<DeviceId, DeviceState> = HashMap::new();
+        for source in ["server01", "server02", "server03"] {
+            let id = DeviceId::new(Protocol::Sysinfo, source);
+            devices.insert(id.clone(), DeviceState::new(id));
+        }
+        let server01 = "server01".to_string();
+
+        // Drag server01 to an exact spot and drop it.
+        let mut state = TopologyState::default();
+        state.update_from_devices(&devices);
+        state.start_node_drag(&server01);
+        state.update_node_drag(&server01, 123.25, -45.5);
+        assert!(state.end_node_drag(&server01));
+        // Dropping again in the same spot is not a change.
+        assert!(!state.end_node_drag(&server01));
+
+        // Round-trip through the on-disk settings format.
+        let mut persistent = PersistentSettings::default();
+        persistent.topology_positions = state.pinned_positions().clone();
+        let json = serde_json::to_string_pretty(&persistent).expect("serialize");
+        let reloaded: PersistentSettings = json5::from_str(&json).expect("deserialize");
+
+        // A fresh session rebuilds the topology around the restored pin.
+        let mut state = TopologyState::default();
+        state.restore_pinned_positions(reloaded.topology_positions);
+        state.update_from_devices(&devices);
+
+        let node = &state.nodes[&server01];
+        assert!(node.pinned);
+        assert_eq!(node.position, (123.25, -45.5));
+        // The others still auto-layout.
+        assert!(!state.nodes["server02"].pinned);
+        assert!(!state.nodes["server03"].pinned);
+    }
+
+    /// #473: a settled layout requests no more frames until something changes.
+    #[test]
+    fn test_stable_layout_requests_no_frames() {
+        use zensight_common::Protocol;
+
+        let mut devices: HashMap<DeviceId, DeviceState> = HashMap::new();
+        for source in ["server01", "server02"] {
+            let id = DeviceId::new(Protocol::Sysinfo, source);
+            devices.insert(id.clone(), DeviceState::new(id));
+        }
+        let mut state = TopologyState::default();
+        state.update_from_devices(&devices);
+        assert!(state.needs_layout());
+
+        // Frames are throttled to max_fps.
+        let start = Instant::now();
+        let interval = state.layout_config.frame_interval();
+        assert!(state.layout_frame(start));
+        assert!(!state.layout_frame(start + interval / 2));
+
+        let mut now = start;
+        let settled = (0..2000).any(|_| {
+            now += interval;
+            state.layout_frame(now);
+            !state.needs_layout()
+        });
+        assert!(settled, "layout should settle");
+
+        // Stable and unchanged: no further frames, even on later ticks.
+        for _ in 0..10 {
+            now += interval;
+            state.update_from_devices(&devices);
+            assert!(!state.needs_layout());
+            assert!(!state.layout_frame(now));
+        }
+
+        // Dragging a node wakes the layout again.
+        state.update_node_drag(&"server01".to_string(), 10.0, 10.0);
+        assert!(state.needs_layout());
+    }
+
+    #[test]
+    fn test_neighborhood_of_isolated_node() {
+        let mut state = TopologyState::default();
+        for id in ["a", "b", "c", "d"] {
+            state.nodes.insert(
+                id.to_string(),
+                Node {
+                    id: id.to_string(),
+                    label: id.to_string(),
+                    ..Default::default()
+                },
+            );
+        }
+        let edge = |from: &str, to: &str, bytes: u64| Edge {
+            from: from.to_string(),
+            to: to.to_string(),
+            bytes,
+            packets: 0,
+            duration_ms: 0,
+            protocol: None,
+            last_seen: 0,
+            alert: None,
+        };
+        state.edges = vec![
+            edge("a", "b", 5_000),
+            edge("c", "a", 5_000),
+            edge("b", "d", 5_000),
+            edge("a", "d", 10),
+        ];
+
+        let hood = state.neighborhood("a").unwrap();
+        let nodes: HashSet<NodeId> = ["a", "b", "c", "d"].map(String::from).into();
+        assert_eq!(hood.nodes, nodes);
+        assert_eq!(hood.edges, HashSet::from([0, 1, 3]));
+
+        // Edges hidden by the bandwidth filter don't connect anything.
+        state.set_min_edge_bytes(1_000);
+        let hood = state.neighborhood("a").unwrap();
+        let nodes: HashSet<NodeId> = ["a", "b", "c"].map(String::from).into();
+        assert_eq!(hood.nodes, nodes);
+        assert_eq!(hood.edges, HashSet::from([0, 1]));
+        assert!(state.neighborhood("missing").is_none());
+
+        // Isolating toggles.
+        assert!(state.isolated_neighborhood().is_none());
+        state.isolate_node("a".to_string());
+        assert_eq!(state.isolated_neighborhood(), Some(hood));
+        state.isolate_node("a".to_string());
+        assert!(state.isolated_node.is_none());
+    }
 }
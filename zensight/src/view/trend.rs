@@ -6,10 +6,11 @@
 //! math is pure and unit-tested; rendering is redundant (arrow glyph + sign +
 //! text, never color alone).
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use iced::widget::{row, text};
 use iced::{Alignment, Element, Theme};
+use zensight_common::Protocol;
 
 use crate::message::DeviceId;
 use crate::store::{MetricStore, Sample};
@@ -123,31 +124,119 @@ pub struct MetricSpark {
 /// Dashboard-card preview: a few key metrics' sparks for one device.
 pub type DeviceSparks = HashMap<DeviceId, Vec<MetricSpark>>;
 
+/// Built-in lead metric for a protocol's dashboard card (#420): CPU for hosts
+/// and SNMP devices, temperature for PLCs, message throughput for logs.
+/// Matched as in [`representative_metric`].
+pub fn default_card_metric(protocol: Protocol) -> Option<&'static str> {
+    match protocol {
+        Protocol::Sysinfo => Some("cpu/usage"),
+        Protocol::Snmp => Some("cpu"),
+        Protocol::Modbus => Some("temperature"),
+        Protocol::Logs => Some("logs/ingest/received_total"),
+        _ => None,
+    }
+}
+
+/// The metric a card's lead sparkline follows (#420): the configured name for
+/// `protocol` (from `overrides`, else [`default_card_metric`]) if the device
+/// has it exactly, otherwise the first metric, by name, that contains it
+/// (case-insensitive). `None` when nothing matches.
+pub fn representative_metric<'a>(
+    protocol: Protocol,
+    metrics: impl IntoIterator<Item = &'a str>,
+    overrides: &BTreeMap<Protocol, String>,
+) -> Option<&'a str> {
+    let wanted = overrides
+        .get(&protocol)
+        .map(String::as_str)
+        .or_else(|| default_card_metric(protocol))?;
+    let needle = wanted.to_lowercase();
+    let mut partial: Option<&str> = None;
+    for metric in metrics {
+        if metric == wanted {
+            return Some(metric);
+        }
+        if metric.to_lowercase().contains(&needle) && partial.is_none_or(|p| metric < p) {
+            partial = Some(metric);
+        }
+    }
+    partial
+}
+
+/// Per-second rate of a monotonic counter's samples. A drop (counter reset)
+/// yields no point rather than a negative rate.
+fn counter_rate(samples: &[Sample]) -> Vec<Sample> {
+    samples
+        .windows(2)
+        .filter_map(|w| {
+            let dt = w[1].ts - w[0].ts;
+            let dv = w[1].value - w[0].value;
+            (dt > 0 && dv >= 0.0).then(|| Sample {
+                ts: w[1].ts,
+                value: dv * 1000.0 / dt as f64,
+            })
+        })
+        .collect()
+}
+
 /// Build per-device spark previews from the store's hot ring for the given
-/// devices. Picks up to `per_device` metrics (those with the most samples) so a
-/// card shows a couple of meaningful sparklines, not noise. Pure given the
-/// store snapshot; cheap (reads the in-memory ring, no disk).
+/// devices. The protocol's representative metric (#420, see
+/// [`representative_metric`]) leads; the rest of the `per_device` slots go to
+/// the metrics with the most samples, so a card shows a couple of meaningful
+/// sparklines, not noise. A `_total` counter leading the card is drawn as a
+/// per-second rate. `since_ms` trims samples older than the global time
+/// window (#392); `None` keeps the whole ring. Pure given the store snapshot;
+/// cheap (reads the in-memory ring, no disk).
 pub fn build_device_sparks<'a>(
     store: &MetricStore,
     devices: impl Iterator<Item = &'a DeviceId>,
     per_device: usize,
+    since_ms: Option<i64>,
+    card_metrics: &BTreeMap<Protocol, String>,
 ) -> DeviceSparks {
     let mut out = DeviceSparks::new();
     for id in devices {
         let protocol = id.protocol.to_string();
-        let mut metrics: Vec<MetricSpark> = store
+        let series: Vec<(String, Vec<Sample>)> = store
             .device_hot_samples(&protocol, &id.source)
             .into_iter()
-            .filter(|(_, samples)| samples.len() >= 2)
-            .map(|(metric, samples)| {
-                let trend = compute(&samples);
-                MetricSpark {
-                    metric,
-                    values: samples.into_iter().map(|s| s.value).collect(),
-                    trend,
+            .map(|(metric, mut samples)| {
+                if let Some(since) = since_ms {
+                    samples.retain(|s| s.ts >= since);
                 }
+                (metric, samples)
             })
             .collect();
+        let lead = representative_metric(
+            id.protocol,
+            series.iter().map(|(metric, _)| metric.as_str()),
+            card_metrics,
+        )
+        .map(str::to_string);
+
+        let mut lead_spark = None;
+        let mut metrics: Vec<MetricSpark> = Vec::new();
+        for (metric, samples) in series {
+            let is_lead = lead.as_deref() == Some(metric.as_str());
+            let (metric, samples) = if is_lead && metric.ends_with("_total") {
+                (format!("{metric}/s"), counter_rate(&samples))
+            } else {
+                (metric, samples)
+            };
+            if samples.len() < 2 {
+                continue;
+            }
+            let spark = MetricSpark {
+                metric,
+                trend: compute(&samples),
+                values: samples.into_iter().map(|s| s.value).collect(),
+            };
+            if is_lead {
+                lead_spark = Some(spark);
+            } else {
+                metrics.push(spark);
+            }
+        }
         // Most-sampled metrics first, then by name for stability.
         metrics.sort_by(|a, b| {
             b.values
@@ -155,6 +244,9 @@ pub fn build_device_sparks<'a>(
                 .cmp(&a.values.len())
                 .then_with(|| a.metric.cmp(&b.metric))
         });
+        if let Some(spark) = lead_spark {
+            metrics.insert(0, spark);
+        }
         metrics.truncate(per_device);
         if !metrics.is_empty() {
             out.insert(id.clone(), metrics);
@@ -227,6 +319,51 @@ mod tests {
         assert_eq!(t.dir, TrendDir::Flat);
     }
 
+    #[test]
+    fn representative_metric_per_protocol() {
+        let defaults = BTreeMap::new();
+        let host = ["cpu/core0", "cpu/usage", "memory/used"];
+        assert_eq!(
+            representative_metric(Protocol::Sysinfo, host, &defaults),
+            Some("cpu/usage")
+        );
+        let router = ["system/sysUpTime", "hr/cpu/1/load", "hr/cpu/0/load"];
+        assert_eq!(
+            representative_metric(Protocol::Snmp, router, &defaults),
+            Some("hr/cpu/0/load")
+        );
+        let plc = ["holding/0", "holding/Temperature"];
+        assert_eq!(
+            representative_metric(Protocol::Modbus, plc, &defaults),
+            Some("holding/Temperature")
+        );
+        // A syslog-only sensor has no journald counters.
+        let logs = [
+            "logs/errors_total",
+            "logs/ingest/dropped_total",
+            "logs/ingest/received_total",
+        ];
+        assert_eq!(
+            representative_metric(Protocol::Logs, logs, &defaults),
+            Some("logs/ingest/received_total")
+        );
+        assert_eq!(
+            representative_metric(Protocol::Netflow, ["flows"], &defaults),
+            None
+        );
+
+        // A configured metric replaces the built-in one.
+        let overrides = BTreeMap::from([(Protocol::Modbus, "holding/0".to_string())]);
+        assert_eq!(
+            representative_metric(Protocol::Modbus, plc, &overrides),
+            Some("holding/0")
+        );
+        assert_eq!(
+            representative_metric(Protocol::Modbus, ["coil/0"], &overrides),
+            None
+        );
+    }
+
     #[test]
     fn arrows_distinct() {
         assert_eq!(TrendDir::Up.arrow(), "\u{2191}");
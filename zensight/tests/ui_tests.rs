@@ -29,7 +29,36 @@ use zensight_common::Protocol;
 /// Test that the dashboard view renders correctly with no devices.
 #[test]
 fn test_dashboard_empty() {
-    let state = DashboardState::default();
+    let mut state = DashboardState::default();
+    state.connected = true;
+    state.connection_state = ConnectionState::Connected;
+    let groups = GroupsState::default();
+    let overview = OverviewState::default();
+    let sensor_health = HashMap::new();
+    let mut ui = simulator(dashboard_view(
+        &state,
+        AppTheme::Dark,
+        0,
+        &groups,
+        &overview,
+        &sensor_health,
+        zensight::view::trend::DeviceSparks::new(),
+    ));
+
+    // Connected but nothing published yet.
+    assert!(
+        ui.find("Connected to Zenoh — waiting for telemetry data...")
+            .is_ok()
+    );
+}
+
+/// With no devices and no session, the dashboard explains the connection
+/// state and how to try demo mode instead of rendering blank (#468).
+#[test]
+fn test_dashboard_empty_state_when_disconnected() {
+    let mut state = DashboardState::default();
+    state.connection_state = ConnectionState::Disconnected;
+    state.last_error = Some("no route to router".to_string());
     let groups = GroupsState::default();
     let overview = OverviewState::default();
     let sensor_health = HashMap::new();
@@ -43,8 +72,18 @@ fn test_dashboard_empty() {
         zensight::view::trend::DeviceSparks::new(),
     ));
 
-    // Should show "Waiting for telemetry data..." message
-    assert!(ui.find("Waiting for telemetry data...").is_ok());
+    assert!(ui.find("No devices yet").is_ok());
+    assert!(ui.find("Not connected to Zenoh").is_ok());
+    assert!(ui.find("Last error: no route to router").is_ok());
+    assert!(
+        ui.find("To explore the UI without sensors, restart with `zensight --demo`.")
+            .is_ok()
+    );
+    assert!(ui.click("Open Settings").is_ok());
+    assert!(matches!(
+        ui.into_messages().next(),
+        Some(Message::OpenSettings)
+    ));
 }
 
 /// Test that the dashboard shows devices when populated.
@@ -268,7 +307,11 @@ fn test_shell_help_button() {
     let mut ui = shell_ui();
     let _ = ui.click("?");
     let messages: Vec<Message> = ui.into_messages().collect();
-    assert!(messages.iter().any(|m| matches!(m, Message::ToggleHelp)));
+    assert!(
+        messages
+            .iter()
+            .any(|m| matches!(m, Message::ToggleHelpOverlay))
+    );
 }
 
 /// The help overlay lists shortcuts and offers a Close action (#28).
@@ -279,7 +322,11 @@ fn test_help_overlay_lists_shortcuts() {
     assert!(ui.find("Search metrics across all devices").is_ok());
     let _ = ui.click("Close");
     let messages: Vec<Message> = ui.into_messages().collect();
-    assert!(messages.iter().any(|m| matches!(m, Message::ToggleHelp)));
+    assert!(
+        messages
+            .iter()
+            .any(|m| matches!(m, Message::ToggleHelpOverlay))
+    );
 }
 
 /// The command palette renders its commands and dispatches the chosen one (#28).
@@ -1774,6 +1821,11 @@ fn test_sensors_view() {
             last_poll_duration_ms: 42,
             errors_last_hour: 3,
             metrics_published: 1234,
+            counters: Default::default(),
+            gauges: Default::default(),
+            restart_count: 5,
+            restarts_last_hour: 4,
+            last_start_ms: Some(1_700_000_000_000),
         },
     );
     // ...with a recent error report.
@@ -1795,6 +1847,12 @@ fn test_sensors_view() {
     assert!(ui.find("Degraded").is_ok());
     assert!(ui.find("Responding").is_ok());
     assert!(ui.find("Recent errors (1)").is_ok());
+    // A sensor restarting repeatedly is flagged (#435).
+    assert!(ui.find("Restarts").is_ok());
+    assert!(
+        ui.find("Crash-looping: 4 restarts in the last hour")
+            .is_ok()
+    );
     // The per-sensor debug-report download control is present (#197).
     assert!(ui.find("Download debug report").is_ok());
 
@@ -1840,6 +1898,11 @@ fn test_sensors_snapshot_dirs() {
             last_poll_duration_ms: 5,
             errors_last_hour: 0,
             metrics_published: 10,
+            counters: Default::default(),
+            gauges: Default::default(),
+            restart_count: 0,
+            restarts_last_hour: 0,
+            last_start_ms: None,
         },
     );
 